@@ -4,6 +4,7 @@ pub mod field;
 pub mod fmt;
 mod opcode;
 pub mod pbs_macro;
+pub mod pbs_user;
 
 use lazy_static::lazy_static;
 use std::collections::HashMap;
@@ -100,7 +101,7 @@ impl DigitParameters {
 /// Provides a set of method to reason about pbs
 #[enum_dispatch]
 pub trait PbsLut {
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
     fn gid(&self) -> PbsGid;
     fn lut_nb(&self) -> u8;
     fn lut_lg(&self) -> u8;
@@ -115,6 +116,7 @@ pub trait PbsLut {
 use crate::{impl_pbs, pbs};
 use enum_dispatch::enum_dispatch;
 use pbs_macro::{CMP_EQUAL, CMP_INFERIOR, CMP_SUPERIOR};
+pub use pbs_user::PbsUser;
 
 pbs!(
 ["None" => 0 [
@@ -1426,6 +1428,12 @@ pbs!(
         };
     }
 ]],
+["MsgSignBit" => 76 [ // Extract the msb of the message, i.e. the sign bit of a two's complement integer
+    @0 =>{
+        |params: &DigitParameters, val | ((val & params.msg_mask()) >> (params.msg_w - 1)) & 1;
+        |_params: &DigitParameters, _deg| 1;
+    }
+]],
 );
 
 pub(crate) fn ceil_ilog2(value: &u8) -> u8 {
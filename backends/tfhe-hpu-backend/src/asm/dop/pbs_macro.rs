@@ -89,6 +89,7 @@ macro_rules! pbs {
             #[enum_dispatch(PbsLut)]
             pub enum Pbs{
                     $([< $pbs:camel >]([< Pbs $pbs:camel >]),)*
+                    User(PbsUser),
                 }
 
             impl std::fmt::Display for Pbs {
@@ -103,6 +104,13 @@ macro_rules! pbs {
                 fn from_str(name: &str) -> Result<Self, Self::Err> {
                     if let Some(lut) = PBS_LUT.asm.get(name) {
                         Ok(lut.clone())
+                    } else if let Some(lut) = pbs_user::PBS_USER
+                        .read()
+                        .unwrap()
+                        .values()
+                        .find(|lut| lut.name() == name)
+                    {
+                        Ok(Pbs::User(lut.clone()))
                     } else {
                         Err(ParsingError::Unmatch(format!("Pbs{name} unknown")))
                     }
@@ -114,13 +122,21 @@ macro_rules! pbs {
                 pub fn from_hex(gid: PbsGid) -> Result<Self, ParsingError> {
                     if let Some(pbs) = PBS_LUT.hex.get(&gid) {
                         Ok(pbs.clone())
+                    } else if let Some(lut) = pbs_user::PBS_USER.read().unwrap().get(&gid) {
+                        Ok(Pbs::User(lut.clone()))
                     } else {
                         Err(ParsingError::Unmatch(format!("Pbs {gid:?} unknown")))
                     }
                 }
 
+                /// List builtin and registered user Pbs
                 pub fn list_all() -> Vec<Self> {
-                    PBS_LUT.hex.values().map(|pbs| pbs.clone()).collect::<Vec<_>>()
+                    PBS_LUT
+                        .hex
+                        .values()
+                        .cloned()
+                        .chain(pbs_user::PBS_USER.read().unwrap().values().cloned().map(Pbs::User))
+                        .collect::<Vec<_>>()
                 }
             }
 
@@ -141,6 +157,8 @@ macro_rules! pbs {
 
                 $(
                     let pbs = Pbs::[< $pbs:camel >]([< Pbs $pbs >]::default());
+                    assert!(pbs.gid().0 < pbs_user::USER_GID_LB, "Error with {}: gid {} is in user range",
+stringify!([< $pbs:camel >]), pbs.gid());
                     pbs_from_arg.asm.insert(stringify!([< $pbs:camel >]).to_string(), pbs.clone());
                     let evicted = pbs_from_arg.hex.insert(pbs.gid(), pbs.clone());
                     assert!(evicted.is_none(), "Error with {}: gid {} is already use by {:?}",
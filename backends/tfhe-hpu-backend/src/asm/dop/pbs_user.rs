@@ -0,0 +1,121 @@
+//!
+//! User defined Pbs
+//!
+//! Builtin Pbs are statically defined with the `pbs!` macro and use the lowest gid.
+//! Gid in USER_GID_LB..=USER_GID_UB are free for user defined lookup tables.
+//! Those are tabulated from user functions and registered at runtime, they could then be used as
+//! any other Pbs (i.e. in custom IOp, for Glwe lut generation, asm parsing, ...)
+
+use super::*;
+use std::sync::RwLock;
+
+pub const USER_GID_LB: u16 = 0xc0;
+pub const USER_GID_UB: u16 = 0xff;
+
+/// Lookup table defined at runtime
+/// Store the function evaluation for each input value and for each sub-lut (i.e. ManyLut)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PbsUser {
+    name: String,
+    gid: PbsGid,
+    lut: Vec<Vec<usize>>,
+}
+
+impl PbsUser {
+    /// Tabulate the given functions
+    /// Using more than one function generates a ManyLut Pbs. The function `i` is then applied on
+    /// the output `i`.
+    pub fn new(
+        name: impl Into<String>,
+        gid: PbsGid,
+        params: &DigitParameters,
+        funcs: &[&dyn Fn(&DigitParameters, usize) -> usize],
+    ) -> Self {
+        let name = name.into();
+        assert!(
+            !funcs.is_empty(),
+            "Error: PbsUser {name} required at least one function"
+        );
+        let lut = funcs
+            .iter()
+            .map(|func| {
+                (0..=params.data_mask())
+                    .map(|val| func(params, val))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        Self { name, gid, lut }
+    }
+
+    /// Build from already tabulated functions
+    /// `lut[i][v]` is the output of the function `i` for input value `v`
+    pub fn from_table(name: impl Into<String>, gid: PbsGid, lut: Vec<Vec<usize>>) -> Self {
+        let name = name.into();
+        assert!(
+            !lut.is_empty() && lut.iter().all(|func| !func.is_empty()),
+            "Error: PbsUser {name} required at least one non-empty function"
+        );
+        Self { name, gid, lut }
+    }
+}
+
+impl PbsLut for PbsUser {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn gid(&self) -> PbsGid {
+        self.gid
+    }
+    fn lut_nb(&self) -> u8 {
+        self.lut.len() as u8
+    }
+    fn lut_lg(&self) -> u8 {
+        ceil_ilog2(&self.lut_nb())
+    }
+    fn fn_at(&self, pos: usize, _params: &DigitParameters, val: usize) -> usize {
+        self.lut[pos][val]
+    }
+    fn deg_at(&self, pos: usize, _params: &DigitParameters, deg: usize) -> usize {
+        // Output degree is bounded by the max output value reachable with the input degree
+        let reachable = std::cmp::min(deg, self.lut[pos].len() - 1);
+        self.lut[pos][..=reachable]
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Registration error
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum RegisterError {
+    #[error("Pbs gid {0} isn't in user range")]
+    Gid(PbsGid),
+    #[error("Pbs gid {0} is already used by {1}")]
+    GidUsed(PbsGid, String),
+    #[error("Pbs name {0} is already used")]
+    NameUsed(String),
+}
+
+lazy_static! {
+    pub(super) static ref PBS_USER: RwLock<HashMap<PbsGid, PbsUser>> = RwLock::new(HashMap::new());
+}
+
+/// Register a user defined Pbs
+/// NB: Registered Pbs are uploaded on Hpu at device initialisation. Thus they must be registered
+/// before the device init.
+pub fn register(lut: PbsUser) -> Result<Pbs, RegisterError> {
+    if !(USER_GID_LB..=USER_GID_UB).contains(&lut.gid.0) {
+        return Err(RegisterError::Gid(lut.gid));
+    }
+    if lut.name.parse::<Pbs>().is_ok() {
+        return Err(RegisterError::NameUsed(lut.name.clone()));
+    }
+
+    let mut user_lut = PBS_USER.write().unwrap();
+    if let Some(used) = user_lut.get(&lut.gid) {
+        return Err(RegisterError::GidUsed(lut.gid, used.name.clone()));
+    }
+    user_lut.insert(lut.gid, lut.clone());
+    Ok(Pbs::User(lut))
+}
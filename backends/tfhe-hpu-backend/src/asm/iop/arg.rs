@@ -7,6 +7,7 @@ use field::{
     FwMode, IOpHeader, IOpcode, ImmBundle, Immediat, Operand, OperandBlock, OperandBundle,
 };
 use lazy_static::lazy_static;
+use std::sync::RwLock;
 
 pub const ASM_OPCODE_WIDTH: usize = 8;
 
@@ -27,6 +28,8 @@ pub enum ParsingError {
     InvalidArg(String),
     #[error("Empty line")]
     Empty,
+    #[error("IOp {0} is already registered")]
+    Registered(String),
 }
 
 // Asm arguments are slightly different that hex word
@@ -49,20 +52,62 @@ pub struct AsmIOpcode {
     pub(crate) format: Option<IOpFormat>,
 }
 
+lazy_static! {
+    /// User defined IOp format
+    /// Enable to name custom IOp and to specify their prototype
+    static ref USER_IOP: RwLock<HashMap<IOpcode, IOpFormat>> = RwLock::new(HashMap::new());
+}
+
+/// Retrieved the format of a Fw generated or user registered IOp
+fn format_from_opcode(opcode: &IOpcode) -> Option<IOpFormat> {
+    IOP_LUT
+        .hex
+        .get(opcode)
+        .cloned()
+        .or_else(|| USER_IOP.read().unwrap().get(opcode).cloned())
+}
+
 impl AsmIOpcode {
     pub fn from_opcode(opcode: IOpcode) -> Self {
-        if let Some(alias) = IOP_LUT.hex.get(&opcode) {
-            Self {
-                opcode,
-                format: Some(alias.clone()),
-            }
-        } else {
-            Self {
-                opcode,
-                format: None,
-            }
+        Self {
+            opcode,
+            format: format_from_opcode(&opcode),
         }
     }
+
+    /// Register the format of a user custom IOp
+    /// Once registered, the IOp could be referenced by name and used as any Fw generated one
+    pub fn register_user(name: &str, opcode: u8, proto: IOpProto) -> Result<Self, ParsingError> {
+        if !(opcode::USER_RANGE_LB..=opcode::USER_RANGE_UB).contains(&opcode) {
+            return Err(ParsingError::Opcode(opcode));
+        }
+        let name = name.to_uppercase();
+        if IOP_LUT.asm.contains_key(&name) {
+            return Err(ParsingError::Registered(name));
+        }
+
+        let mut user_iop = USER_IOP.write().unwrap();
+        if let Some(used) = user_iop
+            .values()
+            .find(|fmt| (fmt.name == name) || (fmt.opcode.0 == opcode))
+        {
+            return Err(ParsingError::Registered(format!(
+                "{}[0x{:x}]",
+                used.name, used.opcode.0
+            )));
+        }
+        let format = IOpFormat {
+            name,
+            opcode: IOpcode(opcode),
+            proto,
+        };
+        user_iop.insert(IOpcode(opcode), format.clone());
+
+        Ok(Self {
+            opcode: IOpcode(opcode),
+            format: Some(format),
+        })
+    }
     pub fn opcode(&self) -> IOpcode {
         self.opcode
     }
@@ -93,17 +138,7 @@ impl std::fmt::Display for AsmIOpcode {
 /// Extract AsmOpcode from IOpcode
 impl From<IOpcode> for AsmIOpcode {
     fn from(opcode: IOpcode) -> Self {
-        if let Some(alias) = IOP_LUT.hex.get(&opcode) {
-            Self {
-                opcode,
-                format: Some(alias.clone()),
-            }
-        } else {
-            Self {
-                opcode,
-                format: None,
-            }
-        }
+        Self::from_opcode(opcode)
     }
 }
 
@@ -141,18 +176,23 @@ impl std::str::FromStr for AsmIOpcode {
                         .map_err(|err| ParsingError::InvalidArg(err.to_string()))?
                 };
                 if (opcode::USER_RANGE_LB..=opcode::USER_RANGE_UB).contains(&value) {
-                    Ok(AsmIOpcode {
-                        opcode: IOpcode(value),
-                        format: None,
-                    })
+                    Ok(AsmIOpcode::from_opcode(IOpcode(value)))
                 } else {
                     Err(ParsingError::Opcode(value))
                 }
             } else if let Some(alias) = caps.name("alias") {
-                if let Some(alias) = IOP_LUT.asm.get(alias.as_str()) {
+                let user_alias = || {
+                    USER_IOP
+                        .read()
+                        .unwrap()
+                        .values()
+                        .find(|fmt| fmt.name == alias.as_str())
+                        .cloned()
+                };
+                if let Some(alias) = IOP_LUT.asm.get(alias.as_str()).cloned().or_else(user_alias) {
                     Ok(AsmIOpcode {
                         opcode: alias.opcode,
-                        format: Some(alias.clone()),
+                        format: Some(alias),
                     })
                 } else {
                     Err(ParsingError::Opalias(alias.as_str().to_string()))
@@ -195,11 +195,15 @@ iop!(
     [IOP_CT_F_CT_SCALAR -> "SHIFTS_L", opcode::SHIFTS_L],
     [IOP_CT_F_CT_SCALAR -> "ROTS_R", opcode::ROTS_R],
     [IOP_CT_F_CT_SCALAR -> "ROTS_L", opcode::ROTS_L],
+    [IOP_CT_F_CT_SCALAR -> "BWS_AND", opcode::BWS_AND],
+    [IOP_CT_F_CT_SCALAR -> "BWS_OR", opcode::BWS_OR],
+    [IOP_CT_F_CT_SCALAR -> "BWS_XOR", opcode::BWS_XOR],
     [IOP_CT_F_2CT -> "ADD", opcode::ADD],
     [IOP_CT_F_2CT -> "SUB", opcode::SUB],
     [IOP_CT_F_2CT -> "MUL", opcode::MUL],
     [IOP_2CT_F_2CT -> "DIV", opcode::DIV],
     [IOP_CT_F_2CT -> "MOD", opcode::MOD],
+    [IOP_2CT_F_2CT -> "SDIV", opcode::SDIV],
     [IOP_CT_BOOL_F_2CT -> "OVF_ADD", opcode::OVF_ADD],
     [IOP_CT_BOOL_F_2CT -> "OVF_SUB", opcode::OVF_SUB],
     [IOP_CT_BOOL_F_2CT -> "OVF_MUL", opcode::OVF_MUL],
@@ -216,6 +220,14 @@ iop!(
     [IOP_CMP -> "CMP_LTE", opcode::CMP_LTE],
     [IOP_CMP -> "CMP_EQ", opcode::CMP_EQ],
     [IOP_CMP -> "CMP_NEQ", opcode::CMP_NEQ],
+    [IOP_CMP -> "CMP_SGT", opcode::CMP_SGT],
+    [IOP_CMP -> "CMP_SGTE", opcode::CMP_SGTE],
+    [IOP_CMP -> "CMP_SLT", opcode::CMP_SLT],
+    [IOP_CMP -> "CMP_SLTE", opcode::CMP_SLTE],
+    [IOP_CT_F_2CT -> "MIN", opcode::MIN],
+    [IOP_CT_F_2CT -> "MAX", opcode::MAX],
+    [IOP_CT_F_2CT -> "SMIN", opcode::SMIN],
+    [IOP_CT_F_2CT -> "SMAX", opcode::SMAX],
     [IOP_CT_F_CT_BOOL -> "IF_THEN_ZERO", opcode::IF_THEN_ZERO],
     [IOP_CT_F_2CT_BOOL -> "IF_THEN_ELSE", opcode::IF_THEN_ELSE],
     [IOP_2CT_F_3CT -> "ERC_20", opcode::ERC_20],
@@ -227,4 +239,6 @@ iop!(
     [IOP_CT_F_CT -> "LEAD1", opcode::LEAD1],
     [IOP_CT_F_CT -> "TRAIL0", opcode::TRAIL0],
     [IOP_CT_F_CT -> "TRAIL1", opcode::TRAIL1],
+    [IOP_CT_F_CT -> "NEG", opcode::NEG],
+    [IOP_CT_F_CT -> "ABS", opcode::ABS],
 );
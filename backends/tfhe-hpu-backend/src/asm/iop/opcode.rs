@@ -33,6 +33,11 @@ pub const SHIFTS_L: u8 = 0xAD;
 pub const ROTS_R: u8 = 0xAE;
 pub const ROTS_L: u8 = 0xAF;
 
+// BW operations
+pub const BWS_AND: u8 = 0xB0;
+pub const BWS_OR: u8 = 0xB1;
+pub const BWS_XOR: u8 = 0xB2;
+
 // Ct x Ct -------------------------------------------------------------------
 // Arith operations
 pub const ADD: u8 = 0xE0;
@@ -40,6 +45,8 @@ pub const SUB: u8 = 0xE2;
 pub const MUL: u8 = 0xE4;
 pub const DIV: u8 = 0xE5;
 pub const MOD: u8 = 0xE6;
+// Signed division, quotient is rounded toward zero and remain has the sign of the dividend
+pub const SDIV: u8 = 0xE7;
 
 // Overflowing Arith
 pub const OVF_ADD: u8 = 0xE8;
@@ -64,6 +71,12 @@ pub const CMP_LT: u8 = 0xC2;
 pub const CMP_LTE: u8 = 0xC3;
 pub const CMP_EQ: u8 = 0xC4;
 pub const CMP_NEQ: u8 = 0xC5;
+// Signed Cmp operations
+// Operands are interpreted as two's complement integers
+pub const CMP_SGT: u8 = 0xC6;
+pub const CMP_SGTE: u8 = 0xC7;
+pub const CMP_SLT: u8 = 0xC8;
+pub const CMP_SLTE: u8 = 0xC9;
 
 // Ternary operations
 // IfThenZero -> Select or force to 0
@@ -73,6 +86,13 @@ pub const IF_THEN_ZERO: u8 = 0xCA;
 // Take 2Ct and a Boolean Ct as input
 pub const IF_THEN_ELSE: u8 = 0xCB;
 
+// Min/Max operations
+// Signed variants interpret operands as two's complement integers
+pub const MIN: u8 = 0xCC;
+pub const MAX: u8 = 0xCD;
+pub const SMIN: u8 = 0xCE;
+pub const SMAX: u8 = 0xCF;
+
 // Custom algorithm
 // ERC20 -> Found xfer algorithm
 // 2Ct <- func(3Ct)
@@ -87,6 +107,10 @@ pub const LEAD1: u8 = 0x85;
 pub const TRAIL0: u8 = 0x86;
 pub const TRAIL1: u8 = 0x87;
 
+// Two's complement operations
+pub const NEG: u8 = 0x88;
+pub const ABS: u8 = 0x89;
+
 // Utility operations
 // Used to handle real clone of ciphertext already uploaded in the Hpu memory
 pub const MEMCPY: u8 = 0xFF;
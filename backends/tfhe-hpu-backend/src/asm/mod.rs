@@ -1,6 +1,6 @@
 pub mod dop;
 pub use dop::arg::Arg as DOpArg;
-pub use dop::{DOp, DigitParameters, ImmId, MemId, Pbs, PbsGid, PbsLut, RegId, ToHex};
+pub use dop::{DOp, DigitParameters, ImmId, MemId, Pbs, PbsGid, PbsLut, PbsUser, RegId, ToHex};
 pub mod iop;
 pub use iop::{AsmIOpcode, IOp, IOpProto, IOpcode, OperandKind};
 
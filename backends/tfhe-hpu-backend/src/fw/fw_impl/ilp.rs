@@ -20,6 +20,7 @@ crate::impl_fw!("Ilp" [
     MUL => fw_impl::ilp::iop_mul;
     DIV => fw_impl::ilp_div::iop_div;
     MOD => fw_impl::ilp_div::iop_mod;
+    SDIV => fw_impl::ilp_div::iop_sdiv;
 
     OVF_ADD => fw_impl::ilp::iop_overflow_add;
     OVF_SUB => fw_impl::ilp::iop_overflow_sub;
@@ -51,6 +52,10 @@ crate::impl_fw!("Ilp" [
     BW_OR  => (|prog| {fw_impl::ilp::iop_bw(prog, asm::dop::PbsBwOr::default().into())});
     BW_XOR => (|prog| {fw_impl::ilp::iop_bw(prog, asm::dop::PbsBwXor::default().into())});
 
+    BWS_AND => (|prog| {fw_impl::ilp::iop_bws(prog, asm::dop::PbsBwAnd::default().into())});
+    BWS_OR  => (|prog| {fw_impl::ilp::iop_bws(prog, asm::dop::PbsBwOr::default().into())});
+    BWS_XOR => (|prog| {fw_impl::ilp::iop_bws(prog, asm::dop::PbsBwXor::default().into())});
+
     CMP_GT  => (|prog| {fw_impl::ilp::iop_cmp(prog, asm::dop::PbsCmpGt::default().into())});
     CMP_GTE => (|prog| {fw_impl::ilp::iop_cmp(prog, asm::dop::PbsCmpGte::default().into())});
     CMP_LT  => (|prog| {fw_impl::ilp::iop_cmp(prog, asm::dop::PbsCmpLt::default().into())});
@@ -58,6 +63,16 @@ crate::impl_fw!("Ilp" [
     CMP_EQ  => (|prog| {fw_impl::ilp::iop_cmp(prog, asm::dop::PbsCmpEq::default().into())});
     CMP_NEQ => (|prog| {fw_impl::ilp::iop_cmp(prog, asm::dop::PbsCmpNeq::default().into())});
 
+    CMP_SGT  => (|prog| {fw_impl::ilp::iop_scmp(prog, asm::dop::PbsCmpGt::default().into())});
+    CMP_SGTE => (|prog| {fw_impl::ilp::iop_scmp(prog, asm::dop::PbsCmpGte::default().into())});
+    CMP_SLT  => (|prog| {fw_impl::ilp::iop_scmp(prog, asm::dop::PbsCmpLt::default().into())});
+    CMP_SLTE => (|prog| {fw_impl::ilp::iop_scmp(prog, asm::dop::PbsCmpLte::default().into())});
+
+    MIN  => (|prog| {fw_impl::ilp::iop_min_max(prog, asm::dop::PbsCmpLt::default().into(), false)});
+    MAX  => (|prog| {fw_impl::ilp::iop_min_max(prog, asm::dop::PbsCmpGt::default().into(), false)});
+    SMIN => (|prog| {fw_impl::ilp::iop_min_max(prog, asm::dop::PbsCmpLt::default().into(), true)});
+    SMAX => (|prog| {fw_impl::ilp::iop_min_max(prog, asm::dop::PbsCmpGt::default().into(), true)});
+
     IF_THEN_ZERO => fw_impl::ilp::iop_if_then_zero;
     IF_THEN_ELSE => fw_impl::ilp::iop_if_then_else;
 
@@ -72,6 +87,9 @@ crate::impl_fw!("Ilp" [
     LEAD1  => fw_impl::ilp_log::iop_lead1;
     TRAIL0 => fw_impl::ilp_log::iop_trail0;
     TRAIL1 => fw_impl::ilp_log::iop_trail1;
+
+    NEG => fw_impl::ilp::iop_neg;
+    ABS => fw_impl::ilp::iop_abs;
 ]);
 
 #[instrument(level = "trace", skip(prog))]
@@ -412,6 +430,70 @@ pub fn iop_overflow_ssub(prog: &mut Program) {
     iop_ssubx(prog, &mut dst, Some(&mut flag[0]), &src_a, &src_b);
 }
 
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_neg(prog: &mut Program) {
+    // Allocate metavariables:
+    // Dest -> Operand
+    let mut dst = prog.iop_template_var(OperandKind::Dst, 0);
+    // SrcA -> Operand
+    let src = prog.iop_template_var(OperandKind::Src, 0);
+
+    // Add Comment header
+    prog.push_comment("NEG Operand::Dst Operand::Src".to_string());
+    // Deferred implementation to generic negx function
+    iop_negx(prog, &mut dst, &src);
+}
+
+/// Generic two's complement negation
+/// Computed as 0 - src with the generic subx function
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_negx(
+    prog: &mut Program,
+    dst: &mut [metavar::MetaVarCell],
+    src: &[metavar::MetaVarCell],
+) {
+    let zero = (0..src.len()).map(|_| prog.new_imm(0)).collect::<Vec<_>>();
+    iop_subx(prog, dst, None, &zero, src);
+}
+
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_abs(prog: &mut Program) {
+    // Allocate metavariables:
+    // Dest -> Operand
+    let mut dst = prog.iop_template_var(OperandKind::Dst, 0);
+    // SrcA -> Operand
+    let src = prog.iop_template_var(OperandKind::Src, 0);
+
+    // Add Comment header
+    prog.push_comment("ABS Operand::Dst Operand::Src".to_string());
+    // Deferred implementation to generic absx function
+    iop_absx(prog, &mut dst, &src);
+}
+
+/// Generic absolute value of a two's complement integer
+/// Source is negated when its sign bit is set
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_absx(
+    prog: &mut Program,
+    dst: &mut [metavar::MetaVarCell],
+    src: &[metavar::MetaVarCell],
+) {
+    let sign = iop_sign_bit(prog, src);
+
+    let mut neg = (0..src.len()).map(|_| prog.new_var()).collect::<Vec<_>>();
+    iop_negx(prog, &mut neg, src);
+    iop_if_then_elsex(prog, dst, &neg, src, &sign);
+}
+
+/// Extract the sign bit of a two's complement integer in a boolean block
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_sign_bit(prog: &mut Program, src: &[metavar::MetaVarCell]) -> metavar::MetaVarCell {
+    let pbs_sign_bit = new_pbs!(prog, "MsgSignBit");
+    src.last()
+        .expect("Sign extraction required at least one block")
+        .pbs(&pbs_sign_bit, false)
+}
+
 #[instrument(level = "trace", skip(prog))]
 pub fn iop_mul(prog: &mut Program) {
     // Allocate metavariables:
@@ -1095,6 +1177,43 @@ pub fn iop_bw(prog: &mut Program, bw_op: Pbs) {
         });
 }
 
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_bws(prog: &mut Program, bw_op: Pbs) {
+    // Dest -> Operand
+    let dst = prog.iop_template_var(OperandKind::Dst, 0);
+    // SrcA -> Operand
+    let src_a = prog.iop_template_var(OperandKind::Src, 0);
+    // SrcB -> Immediat
+    let src_b = prog.iop_template_var(OperandKind::Imm, 0);
+
+    // Add Comment header
+    prog.push_comment(format!(
+        "BWS_{bw_op} Operand::Dst Operand::Src Operand::Immediat"
+    ));
+
+    let props = prog.params();
+    let tfhe_params: asm::DigitParameters = props.clone().into();
+
+    // Wrapped given bw_op lookup table in MetaVar
+    let bw_op = prog.var_from(Some(metavar::VarPos::Pbs(bw_op)));
+
+    // NB: Immediat is merged in the message part of the packed block
+    itertools::izip!(dst, src_a, src_b)
+        .enumerate()
+        .chunks(props.pbs_batch_w)
+        .into_iter()
+        .for_each(|chunk| {
+            let chunk_pack = chunk
+                .into_iter()
+                .map(|(pos, (d, a, b))| (pos, d, a.mac(tfhe_params.msg_range() as u8, &b)))
+                .collect::<Vec<_>>();
+            chunk_pack.into_iter().for_each(|(pos, mut d, mut pack)| {
+                pack.pbs_assign(&bw_op, pos == props.blk_w() - 1);
+                d <<= pack;
+            });
+        });
+}
+
 #[instrument(level = "trace", skip(prog))]
 pub fn iop_cmp(prog: &mut Program, cmp_op: Pbs) {
     // Dest -> Operand
@@ -1192,6 +1311,57 @@ pub fn iop_cmpx(
     dst.mv_assign(&cmp);
 }
 
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_scmp(prog: &mut Program, cmp_op: Pbs) {
+    // Dest -> Operand
+    let mut dst = prog.iop_template_var(OperandKind::Dst, 0);
+    // SrcA -> Operand
+    let src_a = prog.iop_template_var(OperandKind::Src, 0);
+    // SrcB -> Operand
+    let src_b = prog.iop_template_var(OperandKind::Src, 1);
+
+    // Add Comment header
+    prog.push_comment(format!(
+        "CMP_S{cmp_op} Operand::Dst Operand::Src Operand::Src"
+    ));
+
+    // Deferred implementation to generic scmpx function
+    iop_scmpx(prog, &mut dst[0], &src_a, &src_b, cmp_op);
+}
+
+/// Generic signed Cmp operation
+/// Flipping the sign bit of both operands maps two's complement order on unsigned order,
+/// the comparison is then deferred to the generic cmpx function
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_scmpx(
+    prog: &mut Program,
+    dst: &mut metavar::MetaVarCell,
+    src_a: &[metavar::MetaVarCell],
+    src_b: &[metavar::MetaVarCell],
+    cmp_op: Pbs,
+) {
+    let src_a = iop_flip_sign(prog, src_a);
+    let src_b = iop_flip_sign(prog, src_b);
+    iop_cmpx(prog, dst, &src_a, &src_b, cmp_op);
+}
+
+/// Flip the sign bit of a two's complement integer
+/// Only the msb block is updated, others are shared with the source
+#[instrument(level = "trace", skip(prog))]
+fn iop_flip_sign(prog: &mut Program, src: &[metavar::MetaVarCell]) -> Vec<metavar::MetaVarCell> {
+    let props = prog.params();
+    let tfhe_params: asm::DigitParameters = props.clone().into();
+
+    let pbs_msg = new_pbs!(prog, "MsgOnly");
+    let cst_sign = prog.new_imm(tfhe_params.msg_range() / 2);
+
+    let mut flipped = src.to_vec();
+    if let Some(msb) = flipped.last_mut() {
+        *msb = (&*msb + &cst_sign).pbs(&pbs_msg, false);
+    }
+    flipped
+}
+
 #[instrument(level = "trace", skip(prog))]
 pub fn iop_if_then_zero(prog: &mut Program) {
     // Allocate metavariables:
@@ -1240,7 +1410,7 @@ pub fn iop_if_then_zero(prog: &mut Program) {
 pub fn iop_if_then_else(prog: &mut Program) {
     // Allocate metavariables:
     // Dest -> Operand
-    let dst = prog.iop_template_var(OperandKind::Dst, 0);
+    let mut dst = prog.iop_template_var(OperandKind::Dst, 0);
     // SrcA -> Operand
     let src_a = prog.iop_template_var(OperandKind::Src, 0);
     // SrcB -> Operand
@@ -1258,6 +1428,20 @@ pub fn iop_if_then_else(prog: &mut Program) {
         "IF_THEN_ELSE Operand::Dst Operand::Src Operand::Src Operand::Src[Condition]".to_string(),
     );
 
+    // Deferred implementation to generic if_then_elsex function
+    iop_if_then_elsex(prog, &mut dst, &src_a, &src_b, &cond);
+}
+
+/// Generic IfThenElse operation
+/// Select src_a when cond is true and src_b otherwise
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_if_then_elsex(
+    prog: &mut Program,
+    dst: &mut [metavar::MetaVarCell],
+    src_a: &[metavar::MetaVarCell],
+    src_b: &[metavar::MetaVarCell],
+    cond: &metavar::MetaVarCell,
+) {
     let props = prog.params();
     let tfhe_params: asm::DigitParameters = props.clone().into();
 
@@ -1265,7 +1449,7 @@ pub fn iop_if_then_else(prog: &mut Program) {
     let pbs_if_true_zeroed = new_pbs!(prog, "IfTrueZeroed");
     let pbs_if_false_zeroed = new_pbs!(prog, "IfFalseZeroed");
 
-    itertools::izip!(dst, src_a, src_b)
+    itertools::izip!(dst.iter_mut(), src_a, src_b)
         .enumerate()
         .chunks(props.pbs_batch_w)
         .into_iter()
@@ -1277,21 +1461,56 @@ pub fn iop_if_then_else(prog: &mut Program) {
                     (
                         pos,
                         d,
-                        cond.mac(tfhe_params.msg_range() as u8, &a),
-                        cond.mac(tfhe_params.msg_range() as u8, &b),
+                        cond.mac(tfhe_params.msg_range() as u8, a),
+                        cond.mac(tfhe_params.msg_range() as u8, b),
                     )
                 })
                 .collect::<Vec<_>>();
             chunk_pack
                 .into_iter()
-                .for_each(|(pos, mut d, mut cond_a, mut cond_b)| {
+                .for_each(|(pos, d, mut cond_a, mut cond_b)| {
                     cond_a.pbs_assign(&pbs_if_false_zeroed, false);
                     cond_b.pbs_assign(&pbs_if_true_zeroed, pos == props.blk_w() - 1);
-                    d <<= &cond_a + &cond_b;
+                    // NB: At most one of cond_a/cond_b is non-null
+                    // -> Sum degree is bounded by the max of their degree
+                    let degree = std::cmp::max(cond_a.get_degree(), cond_b.get_degree());
+                    let sel = &cond_a + &cond_b;
+                    sel.updt_degree(degree);
+                    *d <<= sel;
                 });
         });
 }
 
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_min_max(prog: &mut Program, cmp_op: Pbs, signed: bool) {
+    // Allocate metavariables:
+    // Dest -> Operand
+    let mut dst = prog.iop_template_var(OperandKind::Dst, 0);
+    // SrcA -> Operand
+    let src_a = prog.iop_template_var(OperandKind::Src, 0);
+    // SrcB -> Operand
+    let src_b = prog.iop_template_var(OperandKind::Src, 1);
+
+    // Add Comment header
+    let (sign, op) = match (signed, &cmp_op) {
+        (false, Pbs::CmpLt(_)) => ("", "MIN"),
+        (false, _) => ("", "MAX"),
+        (true, Pbs::CmpLt(_)) => ("S", "MIN"),
+        (true, _) => ("S", "MAX"),
+    };
+    prog.push_comment(format!("{sign}{op} Operand::Dst Operand::Src Operand::Src"));
+
+    // Select src_a if cmp_op(src_a, src_b) is true, src_b otherwise
+    // NB: Use CmpLt for min and CmpGt for max
+    let mut cond = prog.new_var();
+    if signed {
+        iop_scmpx(prog, &mut cond, &src_a, &src_b, cmp_op);
+    } else {
+        iop_cmpx(prog, &mut cond, &src_a, &src_b, cmp_op);
+    }
+    iop_if_then_elsex(prog, &mut dst, &src_a, &src_b, &cond);
+}
+
 /// Implement erc_20 fund xfer
 /// Targeted algorithm is as follow:
 /// 1. Check that from has enough funds
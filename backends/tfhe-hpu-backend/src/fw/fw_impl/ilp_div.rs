@@ -7,7 +7,7 @@ use std::collections::VecDeque;
 
 use super::*;
 use crate::asm::{self, OperandKind};
-use crate::fw::fw_impl::ilp_log;
+use crate::fw::fw_impl::{ilp, ilp_log};
 use crate::fw::program::Program;
 use tracing::{instrument, warn};
 
@@ -73,6 +73,97 @@ pub fn iop_divx(
     });
 }
 
+#[instrument(level = "trace", skip(prog))]
+pub fn iop_sdiv(prog: &mut Program) {
+    // Allocate metavariables:
+    // Dest -> Operand
+    let mut dst_quotient = prog.iop_template_var(OperandKind::Dst, 0);
+    let mut dst_remain = prog.iop_template_var(OperandKind::Dst, 1);
+    // SrcA -> Operand
+    let src_a = prog.iop_template_var(OperandKind::Src, 0);
+    // SrcB -> Operand
+    let src_b = prog.iop_template_var(OperandKind::Src, 1);
+
+    // Add Comment header
+    prog.push_comment("SDIV Operand::Dst Operand::Src Operand::Src".to_string());
+    // Deferred implementation to generic sdivx function
+    iop_sdivx(prog, &mut dst_quotient, &mut dst_remain, &src_a, &src_b);
+}
+
+/// Generic signed div operation
+/// Division is done on absolute values with the unsigned division core, then:
+/// * quotient is negated when operands have different signs,
+/// * remainder is negated when the dividend is negative (i.e. it has the dividend sign).
+///
+/// NB: Division by zero follows the Cpu behavior, i.e. the quotient is -1 for a positive
+/// dividend and 1 otherwise, and the remainder is the dividend.
+pub fn iop_sdivx(
+    prog: &mut Program,
+    dst_quotient: &mut [metavar::MetaVarCell],
+    dst_remain: &mut [metavar::MetaVarCell],
+    src_a: &[metavar::MetaVarCell],
+    src_b: &[metavar::MetaVarCell],
+) {
+    let props = prog.params();
+    let tfhe_params: asm::DigitParameters = props.clone().into();
+    let blk_w = props.blk_w();
+
+    let pbs_msg = new_pbs!(prog, "MsgOnly");
+    let pbs_xor = new_pbs!(prog, "BwXor");
+
+    // Extract signs and compute the quotient sign
+    let sign_a = ilp::iop_sign_bit(prog, src_a);
+    let sign_b = ilp::iop_sign_bit(prog, src_b);
+    let sign_q = sign_a
+        .mac(tfhe_params.msg_range() as u8, &sign_b)
+        .pbs(&pbs_xor, false);
+
+    // Compute absolute values
+    // NB: |MIN| is correctly represented when read as unsigned
+    let mut abs_a = (0..blk_w).map(|_| prog.new_var()).collect::<Vec<_>>();
+    ilp::iop_absx(prog, &mut abs_a, src_a);
+    let mut abs_b = (0..blk_w).map(|_| prog.new_var()).collect::<Vec<_>>();
+    ilp::iop_absx(prog, &mut abs_b, src_b);
+
+    // Null divider detection
+    let div_is_null = ilp_log::iop_propagate_msb_to_lsb_blockv(
+        prog,
+        src_b,
+        &Some(ilp_log::BitType::One),
+        &Some(true),
+        &Some(false),
+    )[0]
+    .clone();
+
+    // Unsigned division
+    // NB: Unsigned core outputs a null quotient on null divider. Force it to all ones to
+    // match the Cpu behavior (i.e. -1 for positive dividend and 1 otherwise after sign restoration)
+    let result = iop_div_corev(prog, &abs_a, &abs_b, Some(DivCoreOutput::All));
+    let quotient_a = result[0]
+        .iter()
+        .map(|ct| {
+            div_is_null
+                .mac(tfhe_params.msg_mask() as u8, ct)
+                .pbs(&pbs_msg, false)
+        })
+        .collect::<Vec<_>>();
+    // NB: Remain blocks are clean but with a conservative degree
+    // -> Extract msg to enable their negation
+    let remain_a = result[1]
+        .iter()
+        .map(|ct| ct.pbs(&pbs_msg, false))
+        .collect::<Vec<_>>();
+
+    // Restore signs
+    let mut neg_quotient = (0..blk_w).map(|_| prog.new_var()).collect::<Vec<_>>();
+    ilp::iop_negx(prog, &mut neg_quotient, &quotient_a);
+    ilp::iop_if_then_elsex(prog, dst_quotient, &neg_quotient, &quotient_a, &sign_q);
+
+    let mut neg_remain = (0..blk_w).map(|_| prog.new_var()).collect::<Vec<_>>();
+    ilp::iop_negx(prog, &mut neg_remain, &remain_a);
+    ilp::iop_if_then_elsex(prog, dst_remain, &neg_remain, &remain_a, &sign_a);
+}
+
 #[instrument(level = "trace", skip(prog))]
 pub fn iop_mod(prog: &mut Program) {
     // Allocate metavariables:
@@ -22,6 +22,7 @@ crate::impl_fw!("Llt" [
     MUL => fw_impl::llt::iop_mul;
     DIV => fw_impl::ilp_div::iop_div;
     MOD => fw_impl::ilp_div::iop_mod;
+    SDIV => fw_impl::ilp_div::iop_sdiv;
 
     // NB: fallback to ilp
     // TODO: Add dedicated llt implementation
@@ -61,6 +62,12 @@ crate::impl_fw!("Llt" [
     BW_OR  => (|prog| {fw_impl::ilp::iop_bw(prog, asm::dop::PbsBwOr::default().into())});
     BW_XOR => (|prog| {fw_impl::ilp::iop_bw(prog, asm::dop::PbsBwXor::default().into())});
 
+    // NB: fallback to ilp
+    // TODO: Add dedicated llt implementation
+    BWS_AND => (|prog| {fw_impl::ilp::iop_bws(prog, asm::dop::PbsBwAnd::default().into())});
+    BWS_OR  => (|prog| {fw_impl::ilp::iop_bws(prog, asm::dop::PbsBwOr::default().into())});
+    BWS_XOR => (|prog| {fw_impl::ilp::iop_bws(prog, asm::dop::PbsBwXor::default().into())});
+
     CMP_GT  => (|prog| {fw_impl::llt::iop_cmp(prog, pbs_by_name!("CmpGtMrg"), pbs_by_name!("CmpGt"))});
     CMP_GTE => (|prog| {fw_impl::llt::iop_cmp(prog, pbs_by_name!("CmpGteMrg"), pbs_by_name!("CmpGte"))});
     CMP_LT  => (|prog| {fw_impl::llt::iop_cmp(prog, pbs_by_name!("CmpLtMrg"), pbs_by_name!("CmpLt"))});
@@ -68,6 +75,18 @@ crate::impl_fw!("Llt" [
     CMP_EQ  => (|prog| {fw_impl::llt::iop_cmp(prog, pbs_by_name!("CmpEqMrg"), pbs_by_name!("CmpEq"))});
     CMP_NEQ => (|prog| {fw_impl::llt::iop_cmp(prog, pbs_by_name!("CmpNeqMrg"), pbs_by_name!("CmpNeq"))});
 
+    // NB: fallback to ilp
+    // TODO: Add dedicated llt implementation
+    CMP_SGT  => (|prog| {fw_impl::ilp::iop_scmp(prog, asm::dop::PbsCmpGt::default().into())});
+    CMP_SGTE => (|prog| {fw_impl::ilp::iop_scmp(prog, asm::dop::PbsCmpGte::default().into())});
+    CMP_SLT  => (|prog| {fw_impl::ilp::iop_scmp(prog, asm::dop::PbsCmpLt::default().into())});
+    CMP_SLTE => (|prog| {fw_impl::ilp::iop_scmp(prog, asm::dop::PbsCmpLte::default().into())});
+
+    MIN  => (|prog| {fw_impl::ilp::iop_min_max(prog, asm::dop::PbsCmpLt::default().into(), false)});
+    MAX  => (|prog| {fw_impl::ilp::iop_min_max(prog, asm::dop::PbsCmpGt::default().into(), false)});
+    SMIN => (|prog| {fw_impl::ilp::iop_min_max(prog, asm::dop::PbsCmpLt::default().into(), true)});
+    SMAX => (|prog| {fw_impl::ilp::iop_min_max(prog, asm::dop::PbsCmpGt::default().into(), true)});
+
     IF_THEN_ZERO => fw_impl::ilp::iop_if_then_zero;
     IF_THEN_ELSE => fw_impl::ilp::iop_if_then_else;
 
@@ -81,6 +100,9 @@ crate::impl_fw!("Llt" [
     LEAD1 => fw_impl::ilp_log::iop_lead1;
     TRAIL0 => fw_impl::ilp_log::iop_trail0;
     TRAIL1 => fw_impl::ilp_log::iop_trail1;
+
+    NEG => fw_impl::ilp::iop_neg;
+    ABS => fw_impl::ilp::iop_abs;
 ]);
 
 // ----------------------------------------------------------------------------
@@ -0,0 +1,546 @@
+//!
+//! Export of Isc execution traces in Chrome trace-event format
+//!
+//! Generated files could be opened in `chrome://tracing` or in https://ui.perfetto.dev to visually
+//! inspect the DOps flow and the pipeline stalls.
+//! Each processing element is depicted as a process with one thread per in-flight DOps. IOp are
+//! annotated on a dedicated lane, and the Isc pool occupancy and per-PE utilization are exported as
+//! counters.
+//! NB: Keyswitch and Pbs are executed by the same processing element, thus they share a lane.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use serde::Serialize;
+
+use super::*;
+
+/// Lanes of the exported trace
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lane {
+    IOp,
+    Isc,
+    Mem,
+    Alu,
+    KsPbs,
+}
+
+impl Lane {
+    const ALL: [Lane; 5] = [Lane::IOp, Lane::Isc, Lane::Mem, Lane::Alu, Lane::KsPbs];
+
+    fn pid(&self) -> usize {
+        *self as usize
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Lane::IOp => "IOp",
+            Lane::Isc => "ISC",
+            Lane::Mem => "MEM",
+            Lane::Alu => "ALU",
+            Lane::KsPbs => "KS_PBS",
+        }
+    }
+}
+
+impl From<InstructionKind> for Lane {
+    fn from(kind: InstructionKind) -> Self {
+        match kind {
+            InstructionKind::MemLd | InstructionKind::MemSt => Lane::Mem,
+            InstructionKind::Arith => Lane::Alu,
+            InstructionKind::Pbs => Lane::KsPbs,
+            _ => Lane::Isc,
+        }
+    }
+}
+
+/// Value of trace event arguments
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum ArgValue {
+    Num(usize),
+    Str(String),
+}
+
+/// Trace event as defined by the Chrome trace-event format
+#[derive(Debug, Clone, Serialize)]
+pub struct ChromeEvent {
+    name: String,
+    cat: String,
+    ph: &'static str,
+    /// Timestamp in us
+    ts: f64,
+    /// Duration in us, only used by complete events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dur: Option<f64>,
+    pid: usize,
+    tid: usize,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    args: BTreeMap<String, ArgValue>,
+}
+
+/// Chrome trace-event file content
+#[derive(Debug, Clone, Serialize)]
+pub struct ChromeTrace {
+    #[serde(rename = "traceEvents")]
+    trace_events: Vec<ChromeEvent>,
+    #[serde(rename = "displayTimeUnit")]
+    display_time_unit: &'static str,
+    /// Utilization summary
+    #[serde(rename = "otherData")]
+    other_data: BTreeMap<String, String>,
+}
+
+impl ChromeTrace {
+    pub fn events(&self) -> &[ChromeEvent] {
+        &self.trace_events
+    }
+
+    /// Execution summary (i.e. duration, DOps count and usage of each lane)
+    pub fn summary(&self) -> &BTreeMap<String, String> {
+        &self.other_data
+    }
+}
+
+/// Asm representation of the DOp without alignment padding
+fn dop_name(dop: &asm::DOp) -> String {
+    dop.to_string()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// DOp issued but not retired yet
+#[derive(Debug)]
+struct InFlight {
+    kind: InstructionKind,
+    issue: usize,
+    rd_unlock: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+struct LaneState {
+    /// Executed intervals of each thread of the lane
+    threads: Vec<Vec<(usize, usize)>>,
+    in_flight: usize,
+    dops: usize,
+}
+
+impl LaneState {
+    /// Find the first thread without overlap with the given interval
+    fn thread_for(&mut self, start: usize, end: usize) -> usize {
+        let tid = self
+            .threads
+            .iter()
+            .position(|itv| itv.iter().all(|(s, e)| (end <= *s) || (start >= *e)))
+            .unwrap_or_else(|| {
+                self.threads.push(Vec::new());
+                self.threads.len() - 1
+            });
+        self.threads[tid].push((start, end));
+        tid
+    }
+
+    /// Number of cycles with at least one DOp in flight
+    fn busy_cycles(&self) -> usize {
+        let mut itv = self.threads.iter().flatten().copied().collect::<Vec<_>>();
+        itv.sort();
+        let mut busy = 0;
+        let mut cur: Option<(usize, usize)> = None;
+        for (s, e) in itv {
+            cur = match cur {
+                Some((cs, ce)) if s <= ce => Some((cs, std::cmp::max(ce, e))),
+                Some((cs, ce)) => {
+                    busy += ce - cs;
+                    Some((s, e))
+                }
+                None => Some((s, e)),
+            };
+        }
+        if let Some((cs, ce)) = cur {
+            busy += ce - cs;
+        }
+        busy
+    }
+}
+
+/// Build a ChromeTrace from a stream of Isc events
+/// Events must be pushed in chronological order with their timestamp expressed in cycles
+#[derive(Debug)]
+pub struct ChromeTraceBuilder {
+    freq_mhz: usize,
+    origin: Option<usize>,
+    last: usize,
+    in_flight: HashMap<String, VecDeque<InFlight>>,
+    lanes: BTreeMap<Lane, LaneState>,
+    pool_occupancy: usize,
+    iop_names: VecDeque<String>,
+    iop_start: Option<usize>,
+    iop_nb: usize,
+    events: Vec<ChromeEvent>,
+}
+
+impl ChromeTraceBuilder {
+    pub fn new(freq_mhz: usize) -> Self {
+        Self {
+            freq_mhz,
+            origin: None,
+            last: 0,
+            in_flight: HashMap::new(),
+            lanes: BTreeMap::new(),
+            pool_occupancy: 0,
+            iop_names: VecDeque::new(),
+            iop_start: None,
+            iop_nb: 0,
+            events: Vec::new(),
+        }
+    }
+
+    /// Name the next IOp of the stream
+    /// IOp are delimited by Sync, unnamed one are labelled with their index in the stream
+    pub fn push_iop_name(&mut self, name: impl Into<String>) {
+        self.iop_names.push_back(name.into());
+    }
+
+    fn ts(&self, cycle: usize) -> f64 {
+        cycle.saturating_sub(self.origin.unwrap_or(cycle)) as f64 / self.freq_mhz as f64
+    }
+
+    fn update_time(&mut self, cycle: usize) {
+        if self.origin.is_none() {
+            self.origin = Some(cycle);
+        }
+        self.last = std::cmp::max(self.last, cycle);
+        if self.iop_start.is_none() {
+            self.iop_start = Some(cycle);
+        }
+    }
+
+    fn push_counter(&mut self, lane: Lane, name: &str, cycle: usize, value: usize) {
+        self.events.push(ChromeEvent {
+            name: name.to_string(),
+            cat: lane.name().to_string(),
+            ph: "C",
+            ts: self.ts(cycle),
+            dur: None,
+            pid: lane.pid(),
+            tid: 0,
+            args: BTreeMap::from([(name.to_string(), ArgValue::Num(value))]),
+        });
+    }
+
+    /// Register a punctual event
+    pub fn push_instant(
+        &mut self,
+        cycle: usize,
+        lane: Lane,
+        name: impl Into<String>,
+        args: BTreeMap<String, usize>,
+    ) {
+        self.update_time(cycle);
+        self.events.push(ChromeEvent {
+            name: name.into(),
+            cat: lane.name().to_string(),
+            ph: "i",
+            ts: self.ts(cycle),
+            dur: None,
+            pid: lane.pid(),
+            tid: 0,
+            args: args
+                .into_iter()
+                .map(|(k, v)| (k, ArgValue::Num(v)))
+                .collect(),
+        });
+    }
+
+    /// DOp inserted in the Isc pool
+    pub fn push_refill(&mut self, cycle: usize) {
+        self.update_time(cycle);
+        self.pool_occupancy += 1;
+        self.push_counter(Lane::Isc, "pool", cycle, self.pool_occupancy);
+    }
+
+    /// DOp issued on its processing element
+    pub fn push_issue(&mut self, cycle: usize, dop: &asm::DOp) {
+        self.update_time(cycle);
+        let kind = InstructionKind::from(dop);
+
+        if kind == InstructionKind::Sync {
+            // Sync are directly retired and delimit IOps
+            self.pool_occupancy = self.pool_occupancy.saturating_sub(1);
+            self.push_counter(Lane::Isc, "pool", cycle, self.pool_occupancy);
+            self.push_instant(cycle, Lane::Isc, dop_name(dop), BTreeMap::new());
+            self.close_iop(cycle);
+            return;
+        }
+
+        self.in_flight
+            .entry(dop_name(dop))
+            .or_default()
+            .push_back(InFlight {
+                kind,
+                issue: cycle,
+                rd_unlock: None,
+            });
+        let lane = Lane::from(kind);
+        let in_flight = {
+            let state = self.lanes.entry(lane).or_default();
+            state.in_flight += 1;
+            state.in_flight
+        };
+        self.push_counter(lane, "in_flight", cycle, in_flight);
+    }
+
+    /// DOp has read its sources
+    pub fn push_rd_unlock(&mut self, cycle: usize, dop: &asm::DOp) {
+        self.update_time(cycle);
+        if let Some(entry) = self
+            .in_flight
+            .get_mut(&dop_name(dop))
+            .and_then(|queue| queue.iter_mut().find(|e| e.rd_unlock.is_none()))
+        {
+            entry.rd_unlock = Some(cycle);
+        }
+    }
+
+    /// DOp has written its destination and leave the Isc pool
+    pub fn push_retire(&mut self, cycle: usize, dop: &asm::DOp) {
+        self.update_time(cycle);
+        self.pool_occupancy = self.pool_occupancy.saturating_sub(1);
+        self.push_counter(Lane::Isc, "pool", cycle, self.pool_occupancy);
+
+        let name = dop_name(dop);
+        let Some(InFlight {
+            kind,
+            issue,
+            rd_unlock,
+        }) = self.in_flight.get_mut(&name).and_then(|q| q.pop_front())
+        else {
+            tracing::warn!("Retire of {name} without matching issue. Skipped");
+            return;
+        };
+
+        let lane = Lane::from(kind);
+        let (tid, in_flight) = {
+            let state = self.lanes.entry(lane).or_default();
+            state.in_flight = state.in_flight.saturating_sub(1);
+            state.dops += 1;
+            (state.thread_for(issue, cycle), state.in_flight)
+        };
+
+        let mut args = BTreeMap::from([
+            ("issue".to_string(), ArgValue::Num(issue)),
+            ("retire".to_string(), ArgValue::Num(cycle)),
+        ]);
+        if let Some(rd) = rd_unlock {
+            args.insert("rd_unlock".to_string(), ArgValue::Num(rd));
+        }
+        self.events.push(ChromeEvent {
+            name,
+            cat: kind.to_string(),
+            ph: "X",
+            ts: self.ts(issue),
+            dur: Some((cycle - issue) as f64 / self.freq_mhz as f64),
+            pid: lane.pid(),
+            tid,
+            args,
+        });
+        self.push_counter(lane, "in_flight", cycle, in_flight);
+    }
+
+    /// Close current IOp and register the associated annotation
+    fn close_iop(&mut self, cycle: usize) {
+        let start = self.iop_start.take().unwrap_or(cycle);
+        let name = self
+            .iop_names
+            .pop_front()
+            .unwrap_or_else(|| format!("IOp[{}]", self.iop_nb));
+        self.events.push(ChromeEvent {
+            name,
+            cat: Lane::IOp.name().to_string(),
+            ph: "X",
+            ts: self.ts(start),
+            dur: Some((cycle - start) as f64 / self.freq_mhz as f64),
+            pid: Lane::IOp.pid(),
+            tid: 0,
+            args: BTreeMap::from([
+                ("start".to_string(), ArgValue::Num(start)),
+                ("end".to_string(), ArgValue::Num(cycle)),
+            ]),
+        });
+        self.iop_nb += 1;
+    }
+
+    /// Push the content of an isc_sim execution trace
+    pub fn push_isc_sim(&mut self, trace: &[Trace]) {
+        for Trace { timestamp, event } in trace.iter() {
+            match event {
+                TraceEvent::Query { cmd, slot } => match cmd {
+                    Query::Refill => self.push_refill(*timestamp),
+                    Query::Issue => self.push_issue(*timestamp, &slot.inst.op),
+                    Query::RdUnlock => self.push_rd_unlock(*timestamp, &slot.inst.op),
+                    Query::Retire => self.push_retire(*timestamp, &slot.inst.op),
+                },
+                TraceEvent::Timeout => {
+                    self.push_instant(*timestamp, Lane::KsPbs, "BpipTimeout", BTreeMap::new())
+                }
+                TraceEvent::BatchStart { pe_id, issued } => self.push_instant(
+                    *timestamp,
+                    Lane::KsPbs,
+                    "BatchStart",
+                    BTreeMap::from([
+                        ("pe_id".to_string(), *pe_id),
+                        ("issued".to_string(), *issued),
+                    ]),
+                ),
+                TraceEvent::ReqTimeout(_) | TraceEvent::DelTimeout => {}
+            }
+        }
+    }
+
+    pub fn build(mut self) -> ChromeTrace {
+        // Close the pending IOp if any
+        if self.iop_start.is_some() {
+            self.close_iop(self.last);
+        }
+
+        let cycles = self.last - self.origin.unwrap_or(self.last);
+        let mut other_data = BTreeMap::from([
+            ("freq_mhz".to_string(), self.freq_mhz.to_string()),
+            ("cycles".to_string(), cycles.to_string()),
+            ("iops".to_string(), self.iop_nb.to_string()),
+        ]);
+
+        // Lane description and utilization
+        let mut trace_events = Vec::new();
+        for lane in Lane::ALL.iter() {
+            trace_events.push(ChromeEvent {
+                name: "process_name".to_string(),
+                cat: "__metadata".to_string(),
+                ph: "M",
+                ts: 0.0,
+                dur: None,
+                pid: lane.pid(),
+                tid: 0,
+                args: BTreeMap::from([(
+                    "name".to_string(),
+                    ArgValue::Str(lane.name().to_string()),
+                )]),
+            });
+            if let Some(state) = self.lanes.get(lane) {
+                let usage = if cycles != 0 {
+                    100.0 * state.busy_cycles() as f64 / cycles as f64
+                } else {
+                    0.0
+                };
+                other_data.insert(format!("{}.dops", lane.name()), state.dops.to_string());
+                other_data.insert(format!("{}.usage", lane.name()), format!("{usage:.2}%"));
+            }
+        }
+        trace_events.extend(self.events);
+
+        ChromeTrace {
+            trace_events,
+            display_time_unit: "ns",
+            other_data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn lane_packing() {
+        let mut lane = LaneState::default();
+
+        // Contiguous intervals share a thread, overlapping ones are moved on a new one
+        assert_eq!(lane.thread_for(0, 10), 0);
+        assert_eq!(lane.thread_for(10, 20), 0);
+        assert_eq!(lane.thread_for(5, 15), 1);
+        assert_eq!(lane.thread_for(8, 12), 2);
+
+        // The first thread without overlap is reused
+        assert_eq!(lane.thread_for(15, 25), 1);
+        assert_eq!(lane.thread_for(20, 30), 0);
+        assert_eq!(lane.thread_for(12, 18), 2);
+        assert_eq!(lane.threads.len(), 3);
+    }
+
+    #[test]
+    fn lane_utilization() {
+        let mut lane = LaneState::default();
+        assert_eq!(lane.busy_cycles(), 0);
+
+        for (start, end) in [(0, 10), (5, 15), (8, 12), (20, 30), (30, 35)] {
+            lane.thread_for(start, end);
+        }
+        // Busy during [0, 15) and [20, 35), overlapping DOps are only counted once
+        assert_eq!(lane.busy_cycles(), 30);
+    }
+
+    #[test]
+    fn chrome_trace_summary() {
+        let ld = asm::DOp::from_str("LD R0 @0x0").unwrap();
+        let add = asm::DOp::from_str("ADD R2 R0 R1").unwrap();
+        let pbs = asm::DOp::from_str("PBS R3 R2 PbsMsgOnly").unwrap();
+        let sync: asm::DOp = asm::dop::DOpSync::new(None).into();
+
+        let mut builder = ChromeTraceBuilder::new(100);
+        builder.push_iop_name("ADD");
+        for _ in 0..4 {
+            builder.push_refill(100);
+        }
+        builder.push_issue(100, &ld);
+        builder.push_retire(120, &ld);
+        builder.push_issue(120, &add);
+        builder.push_rd_unlock(125, &add);
+        builder.push_retire(130, &add);
+        builder.push_issue(130, &pbs);
+        builder.push_retire(180, &pbs);
+        builder.push_issue(200, &sync);
+        let trace = builder.build();
+
+        let complete = trace
+            .events()
+            .iter()
+            .filter(|evt| evt.ph == "X")
+            .map(|evt| (evt.name.as_str(), evt.pid, evt.tid, evt.ts, evt.dur))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            complete,
+            [
+                ("LD R0 @0x0", Lane::Mem.pid(), 0, 0.0, Some(0.2)),
+                ("ADD R2 R0 R1", Lane::Alu.pid(), 0, 0.2, Some(0.1)),
+                ("PBS R3 R2 PbsMsgOnly", Lane::KsPbs.pid(), 0, 0.3, Some(0.5)),
+                ("ADD", Lane::IOp.pid(), 0, 0.0, Some(1.0)),
+            ]
+        );
+        let add_args = &trace
+            .events()
+            .iter()
+            .find(|evt| evt.name == "ADD R2 R0 R1")
+            .unwrap()
+            .args;
+        assert!(matches!(
+            add_args.get("rd_unlock"),
+            Some(ArgValue::Num(125))
+        ));
+
+        let summary = trace.summary();
+        for (key, value) in [
+            ("cycles", "100"),
+            ("iops", "1"),
+            ("MEM.dops", "1"),
+            ("MEM.usage", "20.00%"),
+            ("ALU.dops", "1"),
+            ("ALU.usage", "10.00%"),
+            ("KS_PBS.dops", "1"),
+            ("KS_PBS.usage", "50.00%"),
+        ] {
+            assert_eq!(summary[key], value, "Invalid summary entry {key}");
+        }
+    }
+}
@@ -20,6 +20,9 @@ pub use scheduler::Scheduler;
 
 pub(crate) mod report;
 
+/// Export execution trace in Chrome trace-event format
+pub mod chrome;
+
 use crate::asm;
 
 /// Event used for modelisation of time advance
@@ -10,10 +10,11 @@ pub mod isc_sim;
 pub mod metavar;
 pub mod program;
 pub mod rtl;
+pub mod tuning;
 
 use crate::asm;
 use enum_dispatch::enum_dispatch;
-use strum_macros::{EnumDiscriminants, EnumIter, EnumString, VariantNames};
+use strum_macros::{Display, EnumDiscriminants, EnumIter, EnumString, VariantNames};
 
 /// Parameters that reflect the targeted architecture
 /// Used to generate fw customized for the targeted architecture
@@ -71,6 +72,11 @@ impl From<FwParameters> for asm::DigitParameters {
     }
 }
 
+/// User defined IOp
+/// Expand the IOp body in the given program with the Fw DSL (i.e. `program::Program` and
+/// `metavar::MetaVarCell`)
+pub type UserIOpFn = std::sync::Arc<dyn Fn(&mut program::Program) + Send + Sync>;
+
 /// Fw trait abstraction
 /// Use to handle Fw implemantion in an abstract way
 #[enum_dispatch]
@@ -85,6 +91,7 @@ pub trait Fw {
 #[strum_discriminants(name(FwName))]
 #[strum_discriminants(derive(EnumIter))]
 #[strum_discriminants(derive(EnumString))]
+#[strum_discriminants(derive(Display))]
 pub enum AvlblFw {
     Ilp(fw_impl::ilp::Ilp),
     Llt(fw_impl::llt::Llt),
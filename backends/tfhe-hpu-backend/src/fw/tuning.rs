@@ -0,0 +1,296 @@
+//!
+//! Firmware auto-tuning
+//!
+//! Rely on the isc_sim model to estimate the execution time of the DOps stream generated by each
+//! Fw implementation. This enables to select, for a given architecture, the fastest implementation
+//! for each IOp and integer width.
+//! The selection is stored in a FwTable that is used by the backend at Fw init time.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use super::isc_sim::Scheduler;
+use super::*;
+use crate::prelude::HpuIscParameters;
+
+/// Frequency used for the simulation
+/// NB: Only the number of cycles is used, thus the value is irrelevant
+const SIM_FREQ_MHZ: usize = 300;
+/// Duration of a simulation quantum
+const SIM_QUANTUM_US: usize = 1000;
+/// Number of consecutive quantum without progress before considering that simulation is stuck
+const SIM_MAX_IDLE_QUANTUM: usize = 1000;
+/// Memory offset used to resolve destination templates
+/// NB: Source templates are resolved from 0 and heap templates downward from the top
+const SIM_DST_OFST: u16 = 0x8000;
+
+/// Fw implementation selection
+/// Depict the Fw implementation to use for each IOp and integer width
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct FwTable {
+    /// IOp name -> integer_w -> Fw name
+    table: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl FwTable {
+    /// Provide Serde mechanisms from TOML file
+    pub fn from_toml(file: &str) -> Self {
+        let file_str = match std::fs::read_to_string(file) {
+            Ok(str) => str,
+            Err(err) => {
+                panic!("Error: `{file}`:: {err}");
+            }
+        };
+
+        match toml::from_str(&file_str) {
+            Ok(table) => table,
+            Err(err) => panic!("Toml error in `{file}`: {err}"),
+        }
+    }
+
+    /// Write the table in a TOML file
+    pub fn write_toml(&self, file: &str) -> Result<(), anyhow::Error> {
+        let toml = toml::to_string(&self)?;
+        std::fs::write(file, toml)?;
+        Ok(())
+    }
+
+    pub fn insert(&mut self, iop: &asm::AsmIOpcode, integer_w: usize, fw: FwName) {
+        self.table
+            .entry(iop.to_string().trim().to_string())
+            .or_default()
+            .insert(integer_w.to_string(), fw.to_string());
+    }
+
+    /// Retrieved the Fw implementation registered for the given IOp and integer width
+    pub fn get(&self, iop: &asm::AsmIOpcode, integer_w: usize) -> Option<FwName> {
+        self.table
+            .get(iop.to_string().trim())
+            .and_then(|by_w| by_w.get(&integer_w.to_string()))
+            .map(|name| {
+                FwName::from_str(name).unwrap_or_else(|_| {
+                    panic!("Invalid Fw name {name} registered for {iop}::{integer_w}b")
+                })
+            })
+    }
+}
+
+/// Estimate the number of cycles required to execute the given DOps stream
+/// Rely on the isc_sim model to simulate the scheduling of the stream on the targeted architecture
+pub fn estimate_cycles(
+    params: &FwParameters,
+    prog: &asm::Program<asm::DOp>,
+    bpip_timeout: Option<u32>,
+) -> usize {
+    let isc_params = HpuIscParameters {
+        depth: params.isc_depth,
+        min_iop_size: params.min_iop_size,
+    };
+    let mut isc = Scheduler::new(
+        SIM_FREQ_MHZ,
+        SIM_QUANTUM_US,
+        &isc_params,
+        params.pe_cfg.clone(),
+    );
+
+    // Mimic ucore behavior: resolve templates and insert trailing Sync
+    let blk_w = params.blk_w();
+    let mut dops = prog
+        .iter()
+        .filter_map(|op| match op {
+            asm::AsmOp::Stmt(dop) => Some(resolve_template(dop, blk_w)),
+            asm::AsmOp::Comment(_) => None,
+        })
+        .collect::<Vec<_>>();
+    dops.push(asm::dop::DOpSync::new(None).into());
+    let dops_nb = dops.len();
+    isc.insert_dops(dops);
+
+    let mut exec_nb = 0;
+    let mut idle_quantum = 0;
+    while exec_nb < dops_nb {
+        let exec = isc.schedule(bpip_timeout);
+        if exec.is_empty() {
+            idle_quantum += 1;
+            assert!(
+                idle_quantum < SIM_MAX_IDLE_QUANTUM,
+                "Error: Isc simulation is stuck [{exec_nb}/{dops_nb} DOps executed]"
+            );
+        } else {
+            idle_quantum = 0;
+            exec_nb += exec.len();
+        }
+    }
+    // NB: DOps are executed at rd_unlock stage, run an extra quantum to retire the pending ones
+    isc.schedule(bpip_timeout);
+
+    isc.time_report().cycle
+}
+
+/// Replace templated memory slots with concrete one
+/// NB: Only the dependencies between DOps matter for the simulation, thus templates are mapped on
+/// disjoint memory regions without consideration for the real IOp operands
+fn resolve_template(dop: &asm::DOp, blk_w: usize) -> asm::DOp {
+    let resolve = |slot: &mut asm::MemId| {
+        *slot = match slot {
+            asm::MemId::Heap { bid } => asm::MemId::Addr(asm::CtId(u16::MAX - *bid)),
+            asm::MemId::Src { tid, bid } => {
+                asm::MemId::Addr(asm::CtId(*tid as u16 * blk_w as u16 + *bid as u16))
+            }
+            asm::MemId::Dst { tid, bid } => asm::MemId::Addr(asm::CtId(
+                SIM_DST_OFST + *tid as u16 * blk_w as u16 + *bid as u16,
+            )),
+            asm::MemId::Addr(ct_id) => asm::MemId::Addr(*ct_id),
+        };
+    };
+
+    let mut dop = dop.clone();
+    match &mut dop {
+        asm::DOp::LD(op_impl) => resolve(op_impl.slot_mut()),
+        asm::DOp::ST(op_impl) => resolve(op_impl.slot_mut()),
+        _ => {}
+    }
+    dop
+}
+
+/// Expand the given IOp with each Fw implementation and estimate their execution time
+/// Return the list of (Fw, cycles) sorted from the fastest to the slowest
+pub fn rank_fw(
+    params: &FwParameters,
+    iop: &asm::AsmIOpcode,
+    fw_kinds: &[FwName],
+    bpip_timeout: Option<u32>,
+) -> Vec<(FwName, usize)> {
+    let mut ranking = fw_kinds
+        .iter()
+        .map(|kind| {
+            let prog = AvlblFw::new(kind).expand(params, iop);
+            (*kind, estimate_cycles(params, &prog, bpip_timeout))
+        })
+        .collect::<Vec<_>>();
+    // NB: Sort is stable, on equality the first registered Fw is kept
+    ranking.sort_by_key(|(_, cycles)| *cycles);
+    ranking
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fw::isc_sim::PeConfigStore;
+    use crate::fw::rtl::config::OpCfg;
+    use crate::prelude::{HpuConfig, HpuParameters};
+
+    /// Fw parameters of the simulation configuration
+    /// Build the Fw parameters and the bpip timeout of the simulation configuration
+    fn sim_fw_params(integer_w: usize) -> (FwParameters, Option<u32>) {
+        let config = HpuConfig::from_toml(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/config_store/sim/hpu_config.toml"
+        ));
+        let params = HpuParameters::from_toml(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../mockups/tfhe-hpu-mockup/params/gaussian_64b_fast.toml"
+        ));
+
+        let fw_params = FwParameters {
+            register: params.regf_params.reg_nb,
+            isc_depth: params.isc_params.depth,
+            heap_size: config.board.heap_size,
+            min_iop_size: params.isc_params.min_iop_size,
+            min_pbs_batch_w: params
+                .ntt_params
+                .min_pbs_nb
+                .unwrap_or(params.ntt_params.batch_pbs_nb),
+            pbs_batch_w: params.ntt_params.batch_pbs_nb,
+            total_pbs_nb: params.ntt_params.total_pbs_nb,
+            msg_w: params.pbs_params.message_width,
+            carry_w: params.pbs_params.carry_width,
+            nu: 5,
+            integer_w,
+            use_ipip: !config.rtl.bpip_use,
+            // NB: Only used by the Llt adders, which are not expanded by these tests
+            kogge_cfg: String::new(),
+            op_cfg: config.firmware.op_cfg.clone(),
+            cur_op_cfg: OpCfg::default(),
+            pe_cfg: PeConfigStore::from((&params, &config)),
+            op_name: None,
+        };
+        let bpip_timeout = config.rtl.bpip_use.then_some(config.rtl.bpip_timeout);
+        (fw_params, bpip_timeout)
+    }
+
+    #[test]
+    fn fw_table_lookup() {
+        let add = &*asm::iop::IOP_ADD;
+        let sub = &*asm::iop::IOP_SUB;
+
+        let mut table = FwTable::default();
+        table.insert(add, 8, FwName::Llt);
+        table.insert(add, 16, FwName::Ilp);
+
+        assert_eq!(table.get(add, 8), Some(FwName::Llt));
+        assert_eq!(table.get(add, 16), Some(FwName::Ilp));
+        // Unlisted IOp and integer width fall back on the default Fw implementation
+        assert_eq!(table.get(add, 32), None);
+        assert_eq!(table.get(sub, 8), None);
+
+        // Latest insertion wins
+        table.insert(add, 8, FwName::Ilp);
+        assert_eq!(table.get(add, 8), Some(FwName::Ilp));
+
+        let toml = toml::to_string(&table).unwrap();
+        let table: FwTable = toml::from_str(&toml).unwrap();
+        assert_eq!(table.get(add, 8), Some(FwName::Ilp));
+        assert_eq!(table.get(add, 16), Some(FwName::Ilp));
+        assert_eq!(table.get(sub, 8), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid Fw name")]
+    fn fw_table_invalid_name() {
+        let table: FwTable = toml::from_str("[table.ADD]\n8 = \"Unknown\"\n").unwrap();
+        table.get(&asm::iop::IOP_ADD, 8);
+    }
+
+    #[test]
+    fn fw_estimate_cycles() {
+        let add = &*asm::iop::IOP_ADD;
+        let fw = AvlblFw::new(&FwName::Ilp);
+
+        let (params_8b, bpip_timeout) = sim_fw_params(8);
+        let prog_8b = fw.expand(&params_8b, add);
+        let cycles_8b = estimate_cycles(&params_8b, &prog_8b, bpip_timeout);
+        assert!(cycles_8b > 0);
+        // The simulation is deterministic
+        assert_eq!(
+            estimate_cycles(&params_8b, &prog_8b, bpip_timeout),
+            cycles_8b
+        );
+
+        // Wider integers require more DOps, and thus more cycles
+        let (params_32b, bpip_timeout) = sim_fw_params(32);
+        let prog_32b = fw.expand(&params_32b, add);
+        assert!(estimate_cycles(&params_32b, &prog_32b, bpip_timeout) > cycles_8b);
+    }
+
+    #[test]
+    fn fw_rank() {
+        let (params, bpip_timeout) = sim_fw_params(16);
+
+        for iop in [&*asm::iop::IOP_ADD, &*asm::iop::IOP_MUL] {
+            let ranking = rank_fw(&params, iop, &[FwName::Demo, FwName::Ilp], bpip_timeout);
+
+            // Each Fw is simulated and ranked from the fastest to the slowest
+            assert_eq!(ranking.len(), 2);
+            assert!(ranking.windows(2).all(|w| w[0].1 <= w[1].1));
+            for (kind, cycles) in ranking.iter() {
+                let prog = AvlblFw::new(kind).expand(&params, iop);
+                assert_eq!(*cycles, estimate_cycles(&params, &prog, bpip_timeout));
+            }
+
+            // Demo reuses the Ilp implementation, on equality the first registered Fw is kept
+            assert_eq!(ranking[0].1, ranking[1].1);
+            assert_eq!(ranking[0].0, FwName::Demo);
+        }
+    }
+}
@@ -3,12 +3,12 @@ use super::*;
 use crate::asm::PbsLut;
 use crate::entities::*;
 use crate::fw::isc_sim::PeConfigStore;
-use crate::fw::{Fw, FwParameters};
+use crate::fw::{Fw, FwParameters, UserIOpFn};
 use crate::{asm, ffi};
 use rtl::FromRtl;
 
 use itertools::Itertools;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::str::FromStr;
 use std::sync::{mpsc, Arc, Mutex};
 use strum::VariantNames;
@@ -38,6 +38,8 @@ pub struct HpuBackend {
     lut_mem: memory::HugeMemory<u64>,
     fw_mem: memory::HugeMemory<u32>,
     init_fw_width: Vec<usize>,
+    // User defined IOp, expanded at Fw init
+    user_iop: HashMap<asm::IOpcode, UserIOpFn>,
 
     // Memory management
     // Board memory is abstract as a bunch of ciphertext slot
@@ -291,6 +293,7 @@ impl HpuBackend {
                 lut_mem,
                 fw_mem,
                 init_fw_width: Vec::new(),
+                user_iop: HashMap::new(),
                 ct_mem,
                 trace_mem,
                 cmd_q: VecDeque::new(),
@@ -634,6 +637,17 @@ impl HpuBackend {
 
 /// Handle Fw Lut and translation table init
 impl HpuBackend {
+    /// Register the Fw of a user defined IOp
+    /// NB: Fw is only expanded on next fw_init
+    pub(crate) fn register_user_iop(&mut self, opcode: asm::IOpcode, expand: UserIOpFn) {
+        assert!(
+            (asm::iop::opcode::USER_RANGE_LB..=asm::iop::opcode::USER_RANGE_UB).contains(&opcode.0),
+            "Error: user IOp must have an opcode in user range, get 0x{:x}",
+            opcode.0
+        );
+        self.user_iop.insert(opcode, expand);
+    }
+
     #[tracing::instrument(skip(self, config))]
     pub(crate) fn fw_init(&mut self, config: &config::HpuConfig) {
         // Create Asm architecture properties and Fw instantiation
@@ -647,6 +661,12 @@ impl HpuBackend {
                 );
             });
         let fw = crate::fw::AvlblFw::new(&fw_name);
+        // Optional per IOp implementation selection
+        let fw_table = config
+            .firmware
+            .fw_table
+            .as_ref()
+            .map(|file| crate::fw::tuning::FwTable::from_toml(&file.expand()));
 
         // TODO Add RTL register for the nu value
         let mut fw_params = FwParameters {
@@ -702,7 +722,13 @@ impl HpuBackend {
                 .par_iter()
                 .map(|iop| {
                     let opcode = iop.opcode();
-                    let prog = fw.expand(&fw_params, iop);
+                    let prog = match fw_table
+                        .as_ref()
+                        .and_then(|table| table.get(iop, *integer_w))
+                    {
+                        Some(name) => crate::fw::AvlblFw::new(&name).expand(&fw_params, iop),
+                        None => fw.expand(&fw_params, iop),
+                    };
                     (opcode.0 as usize, prog.tr_table())
                 })
                 .collect::<Vec<_>>();
@@ -717,6 +743,15 @@ impl HpuBackend {
                 id_fw.push((opcode.0 as usize, prog.tr_table()));
             }
 
+            // Expand user defined IOp
+            for (opcode, expand) in self.user_iop.iter() {
+                let mut prog = crate::fw::program::Program::new(&fw_params);
+                prog.set_op(asm::AsmIOpcode::from_opcode(*opcode).to_string().trim());
+                expand(&mut prog);
+                let prog = asm::Program::<asm::DOp>::from(prog);
+                id_fw.push((opcode.0 as usize, prog.tr_table()));
+            }
+
             // Sanity check
             let sync_opcode = asm::dop::DOpSync::opcode();
             for (id, fw_bytes) in id_fw.iter() {
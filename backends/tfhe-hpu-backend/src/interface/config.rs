@@ -135,6 +135,11 @@ pub struct FwConfig {
     /// Defines the firmware implementation to use
     pub implementation: String,
 
+    /// Optional per IOp firmware implementation table
+    /// Generated by the `fw_tune` utility. Overrides `implementation` for the listed IOps
+    #[serde(default)]
+    pub fw_table: Option<ShellString>,
+
     /// Defines the minimum batch size for an accurate FW simulation (use this
     /// while this information is not available as a register in the hardware)
     pub min_batch_size: usize,
@@ -170,6 +170,17 @@ impl HpuDevice {
         let mut backend = self.backend.lock().unwrap();
         backend.fw_init(&self.config);
     }
+    /// Register the Fw of a user defined IOp
+    /// The given closure expands the IOp body with the Fw DSL (c.f. `crate::fw::program`)
+    /// NB: Fw is generated at device init, thus user IOp must be registered before it (or be
+    /// followed by a call to `fw_init`)
+    pub fn register_user_iop<F>(&self, iop: &crate::asm::AsmIOpcode, expand: F)
+    where
+        F: Fn(&mut crate::fw::program::Program) + Send + Sync + 'static,
+    {
+        let mut backend = self.backend.lock().unwrap();
+        backend.register_user_iop(iop.opcode(), Arc::new(expand));
+    }
     pub fn trace_init(&self) {
         let mut backend = self.backend.lock().unwrap();
         backend.trace_init();
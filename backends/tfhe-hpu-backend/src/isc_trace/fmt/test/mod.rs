@@ -54,3 +54,74 @@ fn isc_trace_v80_2() {
     let stream = IscTraceStream::from_bytes(byte_view);
     println!("stream: {:?}", stream);
 }
+
+#[test]
+fn isc_trace_v80_chrome() {
+    use crate::fw::isc_sim::chrome::Lane;
+
+    let bytes = &data::V80_TEST_DATA;
+    let byte_view = bytemuck::try_cast_slice::<_, u8>(bytes.as_slice()).unwrap();
+    let mut stream = IscTraceStream::from_bytes(byte_view);
+    stream.sort();
+    let chrome = serde_json::to_value(stream.to_chrome(300)).unwrap();
+    let events = chrome["traceEvents"].as_array().unwrap();
+
+    // 5 lanes descriptions, 26 DOps and one IOp, one Sync and the counters
+    let count_ph = |ph: &str| events.iter().filter(|evt| evt["ph"] == ph).count();
+    assert_eq!(events.len(), 139);
+    assert_eq!(count_ph("M"), 5);
+    assert_eq!(count_ph("X"), 27);
+    assert_eq!(count_ph("i"), 1);
+    assert_eq!(count_ph("C"), 106);
+
+    // Each DOp is placed on the lane of its processing element, and overlapping DOps are spread
+    // on distinct threads of the lane
+    let lane_usage = |lane: Lane| {
+        let dops = events
+            .iter()
+            .filter(|evt| evt["ph"] == "X" && evt["pid"] == lane as usize)
+            .collect::<Vec<_>>();
+        let threads = dops
+            .iter()
+            .map(|evt| evt["tid"].as_u64().unwrap())
+            .max()
+            .map_or(0, |tid| tid + 1);
+        (dops, threads)
+    };
+    for (lane, cats, dops_nb, threads_nb) in [
+        (Lane::IOp, &["IOp"][..], 1, 1),
+        (Lane::Mem, &["MemLd", "MemSt"][..], 12, 7),
+        (Lane::Alu, &["Arith"][..], 7, 4),
+        (Lane::KsPbs, &["Pbs"][..], 7, 2),
+    ] {
+        let (dops, threads) = lane_usage(lane);
+        assert_eq!(
+            dops.len(),
+            dops_nb,
+            "Invalid number of DOps on lane {lane:?}"
+        );
+        assert_eq!(
+            threads, threads_nb,
+            "Invalid number of threads on lane {lane:?}"
+        );
+        assert!(dops
+            .iter()
+            .all(|evt| cats.contains(&evt["cat"].as_str().unwrap())));
+    }
+    assert_eq!(lane_usage(Lane::Isc).0.len(), 0);
+
+    let summary = &chrome["otherData"];
+    for (key, value) in [
+        ("cycles", "1806921"),
+        ("freq_mhz", "300"),
+        ("iops", "1"),
+        ("MEM.dops", "12"),
+        ("MEM.usage", "0.27%"),
+        ("ALU.dops", "7"),
+        ("ALU.usage", "0.82%"),
+        ("KS_PBS.dops", "7"),
+        ("KS_PBS.usage", "99.45%"),
+    ] {
+        assert_eq!(summary[key], value, "Invalid summary entry {key}");
+    }
+}
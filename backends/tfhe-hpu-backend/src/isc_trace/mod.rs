@@ -2,6 +2,7 @@ pub mod fmt;
 pub mod packed_struct;
 
 use crate::ffi;
+use crate::fw::isc_sim::chrome::{ChromeTrace, ChromeTraceBuilder};
 pub use crate::isc_trace::fmt::{IscQueryCmd, IscTraceStream, TRACE_W};
 
 pub struct TraceDump {
@@ -61,3 +62,23 @@ impl From<TraceDump> for IscTraceStream {
         )
     }
 }
+
+impl IscTraceStream {
+    /// Convert the trace in Chrome trace-event format
+    /// NB: Trace timestamps are expressed in cycles, they are converted in us with the Hpu
+    /// frequency
+    pub fn to_chrome(&self, freq_mhz: usize) -> ChromeTrace {
+        let mut builder = ChromeTraceBuilder::new(freq_mhz);
+        for trace in self.0.iter() {
+            let cycle = trace.timestamp as usize;
+            match (&trace.cmd, trace.insn.as_ref()) {
+                (IscQueryCmd::REFILL, _) => builder.push_refill(cycle),
+                (IscQueryCmd::ISSUE, Some(dop)) => builder.push_issue(cycle, dop),
+                (IscQueryCmd::RDUNLOCK, Some(dop)) => builder.push_rd_unlock(cycle, dop),
+                (IscQueryCmd::RETIRE, Some(dop)) => builder.push_retire(cycle, dop),
+                _ => {}
+            }
+        }
+        builder.build()
+    }
+}
@@ -0,0 +1,196 @@
+//!
+//! Application used to tune the Fw for a given architecture
+//! Simulate every IOp with each Fw implementation and integer width and select the fastest one.
+//! The selection is written in a Fw table that could be referenced in the Hpu configuration
+//! (c.f. `firmware.fw_table`)
+
+use rayon::prelude::*;
+use tfhe_hpu_backend::asm;
+use tfhe_hpu_backend::fw::isc_sim::PeConfigStore;
+use tfhe_hpu_backend::fw::rtl::config::OpCfg;
+use tfhe_hpu_backend::fw::tuning::{rank_fw, FwTable};
+use tfhe_hpu_backend::fw::{FwName, FwParameters};
+
+/// Define CLI arguments
+use clap::Parser;
+use tfhe_hpu_backend::prelude::{HpuConfig, HpuParameters, ShellString};
+#[derive(clap::Parser, Debug, Clone)]
+#[clap(long_about = "Select the fastest Fw implementation of each IOp for a given architecture")]
+pub struct Args {
+    // Configuration -----------------------------------------------------
+    /// Toml top-level configuration file
+    /// Enable to retrieved runtime configuration register
+    #[clap(
+        long,
+        value_parser,
+        default_value = "${HPU_BACKEND_DIR}/config_store/${HPU_CONFIG}/hpu_config.toml"
+    )]
+    pub config: ShellString,
+
+    /// Hpu rtl parameters
+    /// Enable to retrieved the associated tfhe-rs parameters and other Rtl parameters
+    #[clap(
+        long,
+        value_parser,
+        default_value = "${HPU_MOCKUP_DIR}/params/gaussian_64b_fast.toml"
+    )]
+    pub params: ShellString,
+
+    /// Supported nu
+    /// Number of linear operation supported
+    #[clap(long, value_parser, default_value_t = 5)]
+    nu: usize,
+
+    /// Fw implementations to compare
+    /// NB: Kogge-stone and vardeg are not Fw implementations but building blocks of Llt. The
+    /// Kogge-stone block width is already tuned by Llt itself and cached in the `kogge_cfg` file.
+    /// Demo is excluded by default since it reuses the Ilp implementation of each IOp.
+    #[clap(long, value_parser, num_args = 1.., default_values = ["Ilp", "Llt"])]
+    fw_kind: Vec<FwName>,
+
+    /// Integer bit width to tune
+    /// NB: Use the list of integer_w from the configuration file if empty
+    #[clap(long, value_parser, num_args = 1..)]
+    integer_w: Vec<usize>,
+
+    // Override params --------------------------------------------------
+    // Quick way to override parameters through ClI instead of editing the
+    // configuration file
+    // Used to override some parameters at runtime
+    /// Override Number of Register
+    #[clap(long, value_parser)]
+    register: Option<usize>,
+
+    /// Override HPU lookahead buffer depth
+    /// Number of instruction that are considered in advance
+    #[clap(long, value_parser)]
+    isc_depth: Option<usize>,
+
+    // Input/Output configuration --------------------------------------------
+    /// Tune only the given IOpcode
+    /// NB: Use the full list of IOp if empty
+    #[clap(short, long, value_parser)]
+    expand: Vec<asm::AsmIOpcode>,
+
+    /// Output Fw table
+    #[clap(long, value_parser, default_value = "fw_table.toml")]
+    out_file: String,
+}
+
+fn main() -> Result<(), anyhow::Error> {
+    let args = Args::parse();
+    println!("User Options: {args:?}");
+
+    // Register tracing subscriber that use env-filter
+    // Select verbosity with env_var: e.g. `RUST_LOG=Alu=trace`
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .compact()
+        // Display source code file paths
+        .with_file(false)
+        // Display source code line numbers
+        .with_line_number(false)
+        .without_time()
+        // Build & register the subscriber
+        .init();
+
+    // Load config/parameters from configuration file ------------------------------------
+    let config = HpuConfig::from_toml(args.config.expand().as_str());
+    let params = {
+        let mut rtl_params = HpuParameters::from_toml(&args.params.expand());
+
+        // Override some parameters if required
+        if let Some(register) = args.register.as_ref() {
+            rtl_params.regf_params.reg_nb = *register;
+        }
+        if let Some(isc_depth) = args.isc_depth.as_ref() {
+            rtl_params.isc_params.depth = *isc_depth;
+        }
+        if rtl_params.ntt_params.min_pbs_nb.is_none() {
+            rtl_params.ntt_params.min_pbs_nb = Some(config.firmware.min_batch_size);
+        }
+        rtl_params
+    };
+    let pe_cfg = PeConfigStore::from((&params, &config));
+    let fw_params = FwParameters {
+        register: params.regf_params.reg_nb,
+        isc_depth: params.isc_params.depth,
+        heap_size: config.board.heap_size,
+        min_iop_size: params.isc_params.min_iop_size,
+        min_pbs_batch_w: params
+            .ntt_params
+            .min_pbs_nb
+            .unwrap_or(params.ntt_params.batch_pbs_nb),
+        total_pbs_nb: params.ntt_params.total_pbs_nb,
+        pbs_batch_w: params.ntt_params.batch_pbs_nb,
+        msg_w: params.pbs_params.message_width,
+        carry_w: params.pbs_params.carry_width,
+        nu: args.nu,
+        integer_w: 0,
+        use_ipip: !config.rtl.bpip_use,
+        kogge_cfg: config.firmware.kogge_cfg.expand(),
+        op_cfg: config.firmware.op_cfg.clone(),
+        cur_op_cfg: OpCfg::default(),
+        pe_cfg,
+        op_name: Default::default(),
+    };
+    println!("Fw parameters after override with CLI: {fw_params:?}");
+    let bpip_timeout = config.rtl.bpip_use.then_some(config.rtl.bpip_timeout);
+
+    let tune_list = if args.expand.is_empty() {
+        asm::iop::IOP_LIST.to_vec()
+    } else {
+        args.expand.clone()
+    };
+    let integer_w_list = if args.integer_w.is_empty() {
+        let mut list = config
+            .firmware
+            .integer_w
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+        list.sort();
+        list
+    } else {
+        args.integer_w.clone()
+    };
+
+    let mut fw_table = FwTable::default();
+    for integer_w in integer_w_list.iter() {
+        assert_eq!(
+            integer_w % fw_params.msg_w,
+            0,
+            "ERROR: requested integer_w {integer_w} isn't compliant with MSG_W {}",
+            fw_params.msg_w
+        );
+        let fw_params = FwParameters {
+            integer_w: *integer_w,
+            ..fw_params.clone()
+        };
+
+        let ranking = tune_list
+            .par_iter()
+            .map(|iop| (iop, rank_fw(&fw_params, iop, &args.fw_kind, bpip_timeout)))
+            .collect::<Vec<_>>();
+
+        for (iop, rank) in ranking.into_iter() {
+            let rank_str = rank
+                .iter()
+                .map(|(kind, cycles)| format!("{kind}: {cycles}"))
+                .collect::<Vec<_>>();
+            println!(
+                "{:<12} {integer_w:>3}b => [{}]",
+                iop.to_string().trim(),
+                rank_str.join(", ")
+            );
+            if let Some((best, _)) = rank.first() {
+                fw_table.insert(iop, *integer_w, *best);
+            }
+        }
+    }
+
+    fw_table.write_toml(&args.out_file)?;
+    println!("Fw table written in {}", args.out_file);
+
+    Ok(())
+}
@@ -78,6 +78,12 @@ pub enum Command {
     TraceDump {
         #[arg(short, long, default_value_t = String::from("trace.json"))]
         file: String,
+        /// Also export the trace in Chrome trace-event format (c.f. chrome://tracing or Perfetto)
+        #[arg(long)]
+        chrome: Option<String>,
+        /// Hpu frequency in MHz, used to convert cycles in time in the Chrome trace
+        #[arg(long, default_value_t = 300)]
+        freq_mhz: usize,
     },
 }
 
@@ -278,13 +284,27 @@ fn main() {
             mz.write(0, bfr.as_slice());
             mz.sync(ffi::SyncMode::Host2Device);
         }
-        Command::TraceDump { file: filename } => {
+        Command::TraceDump {
+            file: filename,
+            chrome,
+            freq_mhz,
+        } => {
             let trace = TraceDump::new_from(&mut hpu_hw, &regmap, config.board.trace_depth);
-            let parsed = IscTraceStream::from(trace);
+            let mut parsed = IscTraceStream::from(trace);
 
             let file = File::create(filename).expect("Failed to create or open trace dump file");
             serde_json::to_writer_pretty(file.make_writer(), &parsed)
                 .expect("Could not write trace dump");
+
+            if let Some(chrome_name) = chrome {
+                parsed.sort();
+                let chrome_trace = parsed.to_chrome(freq_mhz);
+                println!("Trace summary: {:?}", chrome_trace.summary());
+                let file =
+                    File::create(chrome_name).expect("Failed to create or open chrome trace file");
+                serde_json::to_writer(file.make_writer(), &chrome_trace)
+                    .expect("Could not write chrome trace");
+            }
         }
     }
 }
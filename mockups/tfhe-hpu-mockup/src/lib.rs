@@ -16,6 +16,7 @@ use tfhe::core_crypto::entities::{
 use tfhe::core_crypto::hpu::glwe_lookuptable::create_hpu_lookuptable;
 use tfhe::core_crypto::prelude::*;
 use tfhe::shortint::parameters::KeySwitch32PBSParameters;
+use tfhe::tfhe_hpu_backend::fw::isc_sim::chrome::ChromeTraceBuilder;
 use tfhe::tfhe_hpu_backend::fw::isc_sim::PeConfigStore;
 
 mod ipc;
@@ -248,6 +249,7 @@ impl HpuSim {
                 let (dops, dops_patched) =
                     self.ucore
                         .translate(&self.ddr, self.hbm_bank.as_slice(), &iop);
+                self.register_user_lut(&dops);
 
                 // Write required input material if needed
                 if let Some(dump_path) = self.options.dump_out.as_ref() {
@@ -402,6 +404,15 @@ impl HpuSim {
                         serde_json::to_string(&trace).expect("Could not serialize trace");
                     writeln!(trace_file, "{json_string}").unwrap();
                 }
+                if let Some(chrome_file) = self.options.report_chrome((&iop).into()) {
+                    let mut builder = ChromeTraceBuilder::new(self.params.freq_mhz);
+                    builder.push_iop_name(iop.to_string());
+                    builder.push_isc_sim(&trace);
+                    let chrome = builder.build();
+                    tracing::info!("Trace summary: {:?}", chrome.summary());
+                    serde_json::to_writer(chrome_file, &chrome)
+                        .expect("Could not serialize chrome trace");
+                }
             }
             hpu_asm::DOp::LD(op_impl) => {
                 let dst = &mut self.regfile[op_impl.0.rid.0 as usize];
@@ -636,10 +647,71 @@ impl HpuSim {
         self.pc += 1;
     }
 
+    /// User defined Pbs are registered at runtime in the application process and are thus unknown
+    /// by the mockup. Retrieve them from the lut memory and register them locally before their
+    /// use in the scheduler or for the execution.
+    fn register_user_lut(&self, dops: &[hpu_asm::DOp]) {
+        for dop in dops.iter() {
+            let (lut_nb, gid) = match dop {
+                hpu_asm::DOp::PBS(op_impl) => (1, op_impl.0.gid),
+                hpu_asm::DOp::PBS_ML2(op_impl) => (2, op_impl.0.gid),
+                hpu_asm::DOp::PBS_ML4(op_impl) => (4, op_impl.0.gid),
+                hpu_asm::DOp::PBS_ML8(op_impl) => (8, op_impl.0.gid),
+                hpu_asm::DOp::PBS_F(op_impl) => (1, op_impl.0.gid),
+                hpu_asm::DOp::PBS_ML2_F(op_impl) => (2, op_impl.0.gid),
+                hpu_asm::DOp::PBS_ML4_F(op_impl) => (4, op_impl.0.gid),
+                hpu_asm::DOp::PBS_ML8_F(op_impl) => (8, op_impl.0.gid),
+                _ => continue,
+            };
+            if hpu_asm::Pbs::from_hex(gid).is_ok() {
+                continue;
+            }
+
+            // Read Lut body from memory
+            let rtl_params = &self.params.rtl_params;
+            let pbs_p = &rtl_params.pbs_params;
+            let mut hpu_lut = HpuGlweLookuptableOwned::<u64>::new(0, rtl_params.clone());
+            let bank = match self.config.board.lut_pc {
+                MemKind::Ddr { .. } => panic!("Error: Lut memory in Ddr isn't supported"),
+                MemKind::Hbm { pc } => &self.hbm_bank[pc],
+            };
+            let ofst = {
+                let (msb, lsb) = self.regmap.addr_offset().lut;
+                let lut_addr = ((msb as usize) << 32) + lsb as usize;
+                lut_addr / std::mem::size_of::<u64>() + gid.0 as usize * pbs_p.polynomial_size
+            };
+            bank.read_across_chunk(ofst, hpu_lut.as_mut());
+            let tfhe_lut = GlweCiphertext::from(hpu_lut.as_view());
+
+            // Tabulate function from the Lut body
+            // NB: Lut is rotated by half a box, thus the first coefficient of each box holds the
+            // function output
+            let modulus_sup = 1_usize << (pbs_p.message_width + pbs_p.carry_width);
+            let box_size = pbs_p.polynomial_size / modulus_sup;
+            let fn_stride = (modulus_sup / lut_nb) * box_size;
+            let table = (0..lut_nb)
+                .map(|fn_idx| {
+                    (0..modulus_sup / lut_nb)
+                        .map(|val| {
+                            let coef =
+                                tfhe_lut.get_body().as_ref()[fn_idx * fn_stride + val * box_size];
+                            self.trivial_decode(coef) as usize
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            let name = format!("UserLut_{:x}", gid.0);
+            tracing::debug!("Register user lut {name} from memory: {table:?}");
+            hpu_asm::dop::pbs_user::register(hpu_asm::PbsUser::from_table(name, gid, table))
+                .expect("Invalid user lut");
+        }
+    }
+
     /// Compute dst_rid <- Pbs(src_rid, lut)
     /// Use a function to prevent code duplication in PBS/PBS_F implementation
-    /// NB: Current Pbs lookup function arn't reverted from Hbm memory
-    /// TODO: Read PbsLut from Hbm instead of online generation based on Pbs Id
+    /// NB: Pbs lookup function are generated online based on Pbs Id. User defined one are
+    /// retrieved from memory beforehand (c.f. register_user_lut)
     fn apply_pbs2reg(
         &mut self,
         opcode_lut_nb: u8,
@@ -89,6 +89,11 @@ pub struct Args {
     #[clap(long, value_parser)]
     report_trace: bool,
 
+    /// Activate the execution trace export in Chrome trace-event format
+    /// NB: Could be opened with chrome://tracing or https://ui.perfetto.dev
+    #[clap(long, value_parser)]
+    report_chrome: bool,
+
     // Log configuration -------------------------------------------------
     /// Write trace message in the file (instead of on stdio)
     /// NB: Use RUST_LOG env variable to set the verbosity
@@ -103,6 +108,7 @@ impl From<&Args> for MockupOptions {
             dump_reg: args.dump_reg,
             report_out: args.report_out.clone(),
             report_trace: args.report_trace,
+            report_chrome: args.report_chrome,
             trivial: args.trivial,
         }
     }
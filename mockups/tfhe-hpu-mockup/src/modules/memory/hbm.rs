@@ -100,10 +100,47 @@ impl HbmBank {
         for addr in bid_addr[bid_start..=bid_stop].iter() {
             let size_b = std::cmp::min(rmn_data, HBM_CHUNK_SIZE_B - bid_ofst);
             let chunk = self.chunk.get(addr).unwrap();
-            data_bytes[data_ofst..data_ofst + size_b].copy_from_slice(&chunk.data[0..size_b]);
+            data_bytes[data_ofst..data_ofst + size_b]
+                .copy_from_slice(&chunk.data[bid_ofst..bid_ofst + size_b]);
             data_ofst += size_b;
             rmn_data -= size_b;
             bid_ofst = 0;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_across_chunk() {
+        let mut bank = HbmBank::new(0);
+        for _ in 0..2 {
+            let paddr = bank.alloc(HBM_CHUNK_SIZE_B);
+            bank.get_mut_chunk(paddr)
+                .data_mut()
+                .iter_mut()
+                .enumerate()
+                .for_each(|(i, byte)| *byte = (paddr as usize + i) as u8);
+        }
+        let expected = |ofst_b: usize, len_b: usize| {
+            (ofst_b..ofst_b + len_b)
+                .map(|i| i as u8)
+                .collect::<Vec<_>>()
+        };
+
+        // Read inside the first chunk, at a non zero offset
+        let mut data = vec![0u32; 16];
+        bank.read_across_chunk(8, &mut data);
+        assert_eq!(bytemuck::cast_slice::<u32, u8>(&data), expected(32, 64));
+
+        // Read across the boundary of the two chunks
+        let ofst = HBM_CHUNK_SIZE_B / std::mem::size_of::<u32>() - 4;
+        bank.read_across_chunk(ofst, &mut data);
+        assert_eq!(
+            bytemuck::cast_slice::<u32, u8>(&data),
+            expected(ofst * std::mem::size_of::<u32>(), 64)
+        );
+    }
+}
@@ -16,6 +16,7 @@ pub struct MockupOptions {
     pub dump_reg: bool,
     pub report_out: Option<String>,
     pub report_trace: bool,
+    pub report_chrome: bool,
     pub trivial: bool,
 }
 
@@ -54,4 +55,13 @@ impl MockupOptions {
             None
         }
     }
+    pub fn report_chrome(&self, iop: hpu_asm::AsmIOpcode) -> Option<File> {
+        if self.report_out.is_some() && self.report_chrome {
+            let report_out = &self.report_out.as_ref().unwrap();
+            let iop_file = format!("{report_out}/{iop}.trace.json");
+            Some(Self::open_wr_file(&iop_file))
+        } else {
+            None
+        }
+    }
 }
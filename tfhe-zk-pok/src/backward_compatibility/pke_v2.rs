@@ -6,6 +6,7 @@ use std::convert::Infallible;
 use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
 
 use crate::curve_api::{CompressedG1, CompressedG2, Compressible, Curve};
+use crate::proofs::pke_v2::ceremony::{Contribution, CrsCeremony, UpdateProof};
 use crate::proofs::pke_v2::{
     CompressedComputeLoadProofFields, CompressedProof, ComputeLoadProofFields, PkeV2HashMode, Proof,
 };
@@ -296,3 +297,18 @@ pub enum PkeV2HashModeVersions {
     #[allow(dead_code)]
     V0(PkeV2HashMode),
 }
+
+#[derive(VersionsDispatch)]
+pub enum UpdateProofVersions<G: Curve> {
+    V0(UpdateProof<G>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum ContributionVersions<G: Curve> {
+    V0(Contribution<G>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CrsCeremonyVersions<G: Curve> {
+    V0(CrsCeremony<G>),
+}
@@ -1,7 +1,7 @@
 use ark_ec::pairing::PairingOutput;
 use ark_ec::short_weierstrass::Affine;
 use ark_ec::{AdditiveGroup as Group, CurveGroup, VariableBaseMSM};
-use ark_ff::{BigInt, Field, MontFp, Zero};
+use ark_ff::{BigInt, Field, MontFp, PrimeField, Zero};
 use ark_poly::univariate::DensePolynomial;
 use core::fmt;
 use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
@@ -24,8 +24,11 @@ impl<T: fmt::Display + PartialEq + Field> fmt::Debug for MontIntDisplay<'_, T> {
 
 pub mod msm;
 
+mod ark_curve;
+
 pub mod bls12_381;
 pub mod bls12_446;
+pub mod bn254;
 
 pub trait FieldOps:
     Copy
@@ -43,6 +46,8 @@ pub trait FieldOps:
 {
     const ZERO: Self;
     const ONE: Self;
+    /// Number of bits of the modulus of the field
+    const MODULUS_BITS: u32;
 
     fn from_u128(n: u128) -> Self;
     fn from_u64(n: u64) -> Self;
@@ -162,6 +167,7 @@ pub trait Curve: Clone {
 impl FieldOps for bls12_381::Zp {
     const ZERO: Self = Self::ZERO;
     const ONE: Self = Self::ONE;
+    const MODULUS_BITS: u32 = <ark_bls12_381::Fr as PrimeField>::MODULUS_BIT_SIZE;
 
     fn from_u128(n: u128) -> Self {
         Self::from_bigint([n as u64, (n >> 64) as u64, 0, 0])
@@ -302,6 +308,7 @@ impl PairingGroupOps<bls12_381::Zp, bls12_381::G1, bls12_381::G2> for bls12_381:
 impl FieldOps for bls12_446::Zp {
     const ZERO: Self = Self::ZERO;
     const ONE: Self = Self::ONE;
+    const MODULUS_BITS: u32 = <crate::curve_446::Fr as PrimeField>::MODULUS_BIT_SIZE;
 
     fn from_u128(n: u128) -> Self {
         Self::from_bigint([n as u64, (n >> 64) as u64, 0, 0, 0])
@@ -444,12 +451,160 @@ impl PairingGroupOps<bls12_446::Zp, bls12_446::G1, bls12_446::G2> for bls12_446:
     }
 }
 
+impl FieldOps for bn254::Zp {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+    const MODULUS_BITS: u32 = <ark_bn254::Fr as PrimeField>::MODULUS_BIT_SIZE;
+
+    fn from_u128(n: u128) -> Self {
+        Self::from_bigint([n as u64, (n >> 64) as u64, 0, 0])
+    }
+    fn from_u64(n: u64) -> Self {
+        Self::from_u64(n)
+    }
+    fn from_i64(n: i64) -> Self {
+        Self::from_i64(n)
+    }
+    fn to_le_bytes(self) -> impl AsRef<[u8]> {
+        self.to_le_bytes()
+    }
+    fn rand(rng: &mut dyn rand::RngCore) -> Self {
+        Self::rand(rng)
+    }
+    fn hash(values: &mut [Self], data: &[&[u8]]) {
+        Self::hash(values, data)
+    }
+    fn hash_128bit(values: &mut [Self], data: &[&[u8]]) {
+        Self::hash_128bit(values, data)
+    }
+
+    fn poly_mul(p: &[Self], q: &[Self]) -> Vec<Self> {
+        let p = p.iter().map(|x| x.inner).collect();
+        let q = q.iter().map(|x| x.inner).collect();
+        let p = DensePolynomial { coeffs: p };
+        let q = DensePolynomial { coeffs: q };
+        (&p * &q)
+            .coeffs
+            .into_iter()
+            .map(|inner| bn254::Zp { inner })
+            .collect()
+    }
+}
+
+impl CurveGroupOps<bn254::Zp> for bn254::G1 {
+    const ZERO: Self = Self::ZERO;
+    const GENERATOR: Self = Self::GENERATOR;
+    const BYTE_SIZE: usize = Self::BYTE_SIZE;
+    type Affine = bn254::G1Affine;
+
+    fn projective(affine: Self::Affine) -> Self {
+        Self {
+            inner: affine.inner.into(),
+        }
+    }
+
+    fn mul_scalar(self, scalar: bn254::Zp) -> Self {
+        if scalar.inner == MontFp!("2") {
+            self.double()
+        } else {
+            self.mul_scalar(scalar)
+        }
+    }
+
+    #[track_caller]
+    fn multi_mul_scalar(bases: &[Self::Affine], scalars: &[bn254::Zp]) -> Self {
+        // overhead seems to not be worth it outside of wasm
+        if cfg!(target_family = "wasm") {
+            msm::msm_wnaf_g1_bn254(bases, scalars)
+        } else {
+            Self::Affine::multi_mul_scalar(bases, scalars)
+        }
+    }
+
+    fn to_le_bytes(self) -> impl AsRef<[u8]> {
+        self.to_le_bytes()
+    }
+
+    fn double(self) -> Self {
+        self.double()
+    }
+
+    fn normalize(self) -> Self::Affine {
+        Self::Affine {
+            inner: self.inner.into_affine(),
+        }
+    }
+
+    fn validate_affine(affine: &Self::Affine) -> bool {
+        affine.validate()
+    }
+}
+
+impl CurveGroupOps<bn254::Zp> for bn254::G2 {
+    const ZERO: Self = Self::ZERO;
+    const GENERATOR: Self = Self::GENERATOR;
+    const BYTE_SIZE: usize = Self::BYTE_SIZE;
+    type Affine = bn254::G2Affine;
+
+    fn projective(affine: Self::Affine) -> Self {
+        Self {
+            inner: affine.inner.into(),
+        }
+    }
+
+    fn mul_scalar(self, scalar: bn254::Zp) -> Self {
+        if scalar.inner == MontFp!("2") {
+            self.double()
+        } else {
+            self.mul_scalar(scalar)
+        }
+    }
+
+    #[track_caller]
+    fn multi_mul_scalar(bases: &[Self::Affine], scalars: &[bn254::Zp]) -> Self {
+        Self::Affine::multi_mul_scalar(bases, scalars)
+    }
+
+    fn to_le_bytes(self) -> impl AsRef<[u8]> {
+        self.to_le_bytes()
+    }
+
+    fn double(self) -> Self {
+        self.double()
+    }
+
+    fn normalize(self) -> Self::Affine {
+        Self::Affine {
+            inner: self.inner.into_affine(),
+        }
+    }
+
+    fn validate_affine(affine: &Self::Affine) -> bool {
+        affine.validate()
+    }
+}
+
+impl PairingGroupOps<bn254::Zp, bn254::G1, bn254::G2> for bn254::Gt {
+    fn mul_scalar(self, scalar: bn254::Zp) -> Self {
+        self.mul_scalar(scalar)
+    }
+
+    fn pairing(x: bn254::G1, y: bn254::G2) -> Self {
+        if x == bn254::G1::ZERO || y == bn254::G2::ZERO {
+            return Self::pairing(bn254::G1::ZERO, bn254::G2::GENERATOR);
+        }
+        Self::pairing(x, y)
+    }
+}
+
 // These are just ZSTs that are not actually produced and are only used for their
 // associated types. So it's ok to derive "NotVersioned" for them.
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize, NotVersioned)]
 pub struct Bls12_381;
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize, NotVersioned)]
 pub struct Bls12_446;
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize, NotVersioned)]
+pub struct Bn254;
 
 impl Curve for Bls12_381 {
     type Zp = bls12_381::Zp;
@@ -463,3 +618,9 @@ impl Curve for Bls12_446 {
     type G2 = bls12_446::G2;
     type Gt = bls12_446::Gt;
 }
+impl Curve for Bn254 {
+    type Zp = bn254::Zp;
+    type G1 = bn254::G1;
+    type G2 = bn254::G2;
+    type Gt = bn254::Gt;
+}
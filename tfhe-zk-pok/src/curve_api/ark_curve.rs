@@ -0,0 +1,972 @@
+//! Wrappers of the pairing friendly curves implemented by arkworks crates
+
+/// Implements the `G1`, `G2`, `Gt` and `Zp` types of the curve api over an arkworks curve crate.
+///
+/// The curve is given by the name of its crate and of its pairing engine. The base field is
+/// `fq_limbs` 64 bits limbs wide, the scalar field must fit in 4 limbs.
+macro_rules! impl_ark_curve {
+    (
+        ark: $ark:ident,
+        pairing: $pairing:ident,
+        fq_limbs: $fq_limbs:literal,
+        g1_generator: ($g1_x:tt, $g1_y:tt),
+        g2_generator: (($g2_x_c0:tt, $g2_x_c1:tt), ($g2_y_c0:tt, $g2_y_c1:tt)) $(,)?
+    ) => {
+        const FQ_LIMBS: usize = $fq_limbs;
+
+        /// multiply EC point with scalar (= exponentiation in multiplicative notation)
+        fn mul_zp<T: Copy + Zero + Add<Output = T> + Group>(x: T, scalar: Zp) -> T {
+            let zero = T::zero();
+            let n: BigInt<4> = scalar.inner.into();
+
+            if n == BigInt([0; 4]) {
+                return zero;
+            }
+
+            let mut y = zero;
+            let mut x = x;
+
+            let n = n.0;
+            for word in n {
+                for idx in 0..64 {
+                    let bit = (word >> idx) & 1;
+                    if bit == 1 {
+                        y += x;
+                    }
+                    x.double_in_place();
+                }
+            }
+            y
+        }
+
+        fn bigint_to_le_bytes(x: [u64; FQ_LIMBS]) -> [u8; FQ_LIMBS * 8] {
+            let mut buf = [0u8; FQ_LIMBS * 8];
+            for (i, &xi) in x.iter().enumerate() {
+                buf[i * 8..][..8].copy_from_slice(&xi.to_le_bytes());
+            }
+            buf
+        }
+
+        mod g1 {
+            use tfhe_versionable::Versionize;
+
+            use crate::serialization::{InvalidSerializedAffineError, SerializableG1Affine};
+
+            use super::*;
+
+            #[derive(
+                Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash, Versionize,
+            )]
+            #[serde(try_from = "SerializableG1Affine", into = "SerializableG1Affine")]
+            #[versionize(try_from = "SerializableG1Affine", into = "SerializableG1Affine")]
+            #[repr(transparent)]
+            pub struct G1Affine {
+                pub(crate) inner: $ark::g1::G1Affine,
+            }
+
+            impl From<G1Affine> for SerializableAffine<SerializableFp> {
+                fn from(value: G1Affine) -> Self {
+                    SerializableAffine::uncompressed(value.inner)
+                }
+            }
+
+            impl TryFrom<SerializableAffine<SerializableFp>> for G1Affine {
+                type Error = InvalidSerializedAffineError;
+
+                fn try_from(
+                    value: SerializableAffine<SerializableFp>,
+                ) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        inner: value.try_into()?,
+                    })
+                }
+            }
+
+            impl Compressible for G1Affine {
+                type Compressed = SerializableG1Affine;
+                type UncompressError = InvalidSerializedAffineError;
+
+                fn compress(&self) -> SerializableG1Affine {
+                    SerializableAffine::compressed(self.inner)
+                }
+
+                fn uncompress(compressed: Self::Compressed) -> Result<Self, Self::UncompressError> {
+                    compressed.try_into()
+                }
+            }
+
+            impl G1Affine {
+                pub fn multi_mul_scalar(bases: &[Self], scalars: &[Zp]) -> G1 {
+                    // SAFETY: interpreting a `repr(transparent)` pointer as its contents.
+                    G1 {
+                        inner: $ark::G1Projective::msm(
+                            unsafe {
+                                &*(bases as *const [G1Affine] as *const [$ark::g1::G1Affine])
+                            },
+                            unsafe { &*(scalars as *const [Zp] as *const [$ark::Fr]) },
+                        )
+                        .unwrap(),
+                    }
+                }
+
+                pub fn validate(&self) -> bool {
+                    self.inner.is_on_curve()
+                        && self.inner.is_in_correct_subgroup_assuming_on_curve()
+                }
+            }
+
+            #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Hash, Versionize)]
+            #[serde(try_from = "SerializableG1Affine", into = "SerializableG1Affine")]
+            #[versionize(try_from = "SerializableG1Affine", into = "SerializableG1Affine")]
+            #[repr(transparent)]
+            pub struct G1 {
+                pub(crate) inner: $ark::G1Projective,
+            }
+
+            impl From<G1> for SerializableAffine<SerializableFp> {
+                fn from(value: G1) -> Self {
+                    SerializableAffine::uncompressed(value.inner.into_affine())
+                }
+            }
+
+            impl TryFrom<SerializableG1Affine> for G1 {
+                type Error = InvalidSerializedAffineError;
+
+                fn try_from(
+                    value: SerializableAffine<SerializableFp>,
+                ) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        inner: Affine::try_from(value)?.into(),
+                    })
+                }
+            }
+
+            impl Compressible for G1 {
+                type Compressed = SerializableG1Affine;
+                type UncompressError = InvalidSerializedAffineError;
+
+                fn compress(&self) -> SerializableG1Affine {
+                    SerializableAffine::compressed(self.inner.into_affine())
+                }
+
+                fn uncompress(compressed: Self::Compressed) -> Result<Self, Self::UncompressError> {
+                    compressed.try_into()
+                }
+            }
+
+            impl fmt::Debug for G1 {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_struct("G1")
+                        .field("x", &MontIntDisplay(&self.inner.x))
+                        .field("y", &MontIntDisplay(&self.inner.y))
+                        .field("z", &MontIntDisplay(&self.inner.z))
+                        .finish()
+                }
+            }
+
+            impl G1 {
+                pub const ZERO: Self = Self {
+                    inner: $ark::G1Projective {
+                        x: MontFp!("1"),
+                        y: MontFp!("1"),
+                        z: MontFp!("0"),
+                    },
+                };
+
+                pub const GENERATOR: Self = Self {
+                    inner: $ark::G1Projective {
+                        x: MontFp!($g1_x),
+                        y: MontFp!($g1_y),
+                        z: MontFp!("1"),
+                    },
+                };
+
+                // Size in number of bytes when the [to_le_bytes]
+                // function is called.
+                // This is not the size after serialization!
+                pub const BYTE_SIZE: usize = 2 * FQ_LIMBS * 8 + 1;
+
+                pub fn mul_scalar(self, scalar: Zp) -> Self {
+                    Self {
+                        inner: mul_zp(self.inner, scalar),
+                    }
+                }
+
+                pub fn multi_mul_scalar(bases: &[Self], scalars: &[Zp]) -> Self {
+                    use rayon::prelude::*;
+                    let n_threads = rayon::current_num_threads();
+                    let chunk_size = bases.len().div_ceil(n_threads);
+                    bases
+                        .par_iter()
+                        .map(|&x| x.inner.into_affine())
+                        .chunks(chunk_size)
+                        .zip(scalars.par_iter().map(|&x| x.inner).chunks(chunk_size))
+                        .map(|(bases, scalars)| Self {
+                            inner: $ark::G1Projective::msm(&bases, &scalars).unwrap(),
+                        })
+                        .sum::<Self>()
+                }
+
+                pub fn to_le_bytes(self) -> [u8; Self::BYTE_SIZE] {
+                    let g = self.inner.into_affine();
+                    let x = bigint_to_le_bytes(g.x.0 .0);
+                    let y = bigint_to_le_bytes(g.y.0 .0);
+                    let mut buf = [0u8; 2 * FQ_LIMBS * 8 + 1];
+                    buf[..FQ_LIMBS * 8].copy_from_slice(&x);
+                    buf[FQ_LIMBS * 8..][..FQ_LIMBS * 8].copy_from_slice(&y);
+                    buf[2 * FQ_LIMBS * 8] = g.infinity as u8;
+                    buf
+                }
+
+                pub fn double(self) -> Self {
+                    Self {
+                        inner: self.inner.double(),
+                    }
+                }
+            }
+
+            impl Add for G1 {
+                type Output = G1;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self::Output {
+                    G1 {
+                        inner: self.inner + rhs.inner,
+                    }
+                }
+            }
+
+            impl Sub for G1 {
+                type Output = G1;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self::Output {
+                    G1 {
+                        inner: self.inner - rhs.inner,
+                    }
+                }
+            }
+
+            impl AddAssign for G1 {
+                #[inline]
+                fn add_assign(&mut self, rhs: Self) {
+                    self.inner += rhs.inner
+                }
+            }
+
+            impl SubAssign for G1 {
+                #[inline]
+                fn sub_assign(&mut self, rhs: Self) {
+                    self.inner -= rhs.inner
+                }
+            }
+
+            impl core::iter::Sum for G1 {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(G1::ZERO, Add::add)
+                }
+            }
+
+            impl Neg for G1 {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self { inner: -self.inner }
+                }
+            }
+        }
+
+        mod g2 {
+            use tfhe_versionable::Versionize;
+
+            use crate::serialization::{InvalidSerializedAffineError, SerializableG2Affine};
+
+            use super::*;
+
+            #[derive(
+                Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash, Versionize,
+            )]
+            #[serde(try_from = "SerializableG2Affine", into = "SerializableG2Affine")]
+            #[versionize(try_from = "SerializableG2Affine", into = "SerializableG2Affine")]
+            #[repr(transparent)]
+            pub struct G2Affine {
+                pub(crate) inner: $ark::g2::G2Affine,
+            }
+
+            impl From<G2Affine> for SerializableAffine<SerializableFp2> {
+                fn from(value: G2Affine) -> Self {
+                    SerializableAffine::uncompressed(value.inner)
+                }
+            }
+
+            impl TryFrom<SerializableAffine<SerializableFp2>> for G2Affine {
+                type Error = InvalidSerializedAffineError;
+
+                fn try_from(
+                    value: SerializableAffine<SerializableFp2>,
+                ) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        inner: value.try_into()?,
+                    })
+                }
+            }
+
+            impl Compressible for G2Affine {
+                type Compressed = SerializableG2Affine;
+
+                type UncompressError = InvalidSerializedAffineError;
+
+                fn compress(&self) -> SerializableAffine<SerializableFp2> {
+                    SerializableAffine::compressed(self.inner)
+                }
+
+                fn uncompress(compressed: Self::Compressed) -> Result<Self, Self::UncompressError> {
+                    compressed.try_into()
+                }
+            }
+
+            impl G2Affine {
+                pub fn multi_mul_scalar(bases: &[Self], scalars: &[Zp]) -> G2 {
+                    // SAFETY: interpreting a `repr(transparent)` pointer as its contents.
+                    G2 {
+                        inner: $ark::G2Projective::msm(
+                            unsafe {
+                                &*(bases as *const [G2Affine] as *const [$ark::g2::G2Affine])
+                            },
+                            unsafe { &*(scalars as *const [Zp] as *const [$ark::Fr]) },
+                        )
+                        .unwrap(),
+                    }
+                }
+
+                pub fn validate(&self) -> bool {
+                    self.inner.is_on_curve()
+                        && self.inner.is_in_correct_subgroup_assuming_on_curve()
+                }
+            }
+
+            #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Hash, Versionize)]
+            #[serde(try_from = "SerializableG2Affine", into = "SerializableG2Affine")]
+            #[versionize(try_from = "SerializableG2Affine", into = "SerializableG2Affine")]
+            #[repr(transparent)]
+            pub struct G2 {
+                pub(crate) inner: $ark::G2Projective,
+            }
+
+            impl From<G2> for SerializableG2Affine {
+                fn from(value: G2) -> Self {
+                    SerializableAffine::uncompressed(value.inner.into_affine())
+                }
+            }
+
+            impl TryFrom<SerializableG2Affine> for G2 {
+                type Error = InvalidSerializedAffineError;
+
+                fn try_from(
+                    value: SerializableAffine<SerializableFp2>,
+                ) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        inner: Affine::try_from(value)?.into(),
+                    })
+                }
+            }
+
+            impl Compressible for G2 {
+                type Compressed = SerializableG2Affine;
+
+                type UncompressError = InvalidSerializedAffineError;
+
+                fn compress(&self) -> SerializableAffine<SerializableFp2> {
+                    SerializableAffine::compressed(self.inner.into_affine())
+                }
+
+                fn uncompress(compressed: Self::Compressed) -> Result<Self, Self::UncompressError> {
+                    compressed.try_into()
+                }
+            }
+
+            impl fmt::Debug for G2 {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    #[allow(dead_code)]
+                    #[derive(Debug)]
+                    struct QuadExtField<T> {
+                        c0: T,
+                        c1: T,
+                    }
+
+                    f.debug_struct("G2")
+                        .field(
+                            "x",
+                            &QuadExtField {
+                                c0: MontIntDisplay(&self.inner.x.c0),
+                                c1: MontIntDisplay(&self.inner.x.c1),
+                            },
+                        )
+                        .field(
+                            "y",
+                            &QuadExtField {
+                                c0: MontIntDisplay(&self.inner.y.c0),
+                                c1: MontIntDisplay(&self.inner.y.c1),
+                            },
+                        )
+                        .field(
+                            "z",
+                            &QuadExtField {
+                                c0: MontIntDisplay(&self.inner.z.c0),
+                                c1: MontIntDisplay(&self.inner.z.c1),
+                            },
+                        )
+                        .finish()
+                }
+            }
+
+            impl G2 {
+                pub const ZERO: Self = Self {
+                    inner: $ark::G2Projective {
+                        x: ark_ff::QuadExtField {
+                            c0: MontFp!("1"),
+                            c1: MontFp!("0"),
+                        },
+                        y: ark_ff::QuadExtField {
+                            c0: MontFp!("1"),
+                            c1: MontFp!("0"),
+                        },
+                        z: ark_ff::QuadExtField {
+                            c0: MontFp!("0"),
+                            c1: MontFp!("0"),
+                        },
+                    },
+                };
+
+                pub const GENERATOR: Self = Self {
+                    inner: $ark::G2Projective {
+                        x: ark_ff::QuadExtField {
+                            c0: MontFp!($g2_x_c0),
+                            c1: MontFp!($g2_x_c1),
+                        },
+                        y: ark_ff::QuadExtField {
+                            c0: MontFp!($g2_y_c0),
+                            c1: MontFp!($g2_y_c1),
+                        },
+                        z: ark_ff::QuadExtField {
+                            c0: MontFp!("1"),
+                            c1: MontFp!("0"),
+                        },
+                    },
+                };
+
+                // Size in number of bytes when the [to_le_bytes]
+                // function is called.
+                // This is not the size after serialization!
+                pub const BYTE_SIZE: usize = 4 * FQ_LIMBS * 8 + 1;
+
+                pub fn mul_scalar(self, scalar: Zp) -> Self {
+                    Self {
+                        inner: mul_zp(self.inner, scalar),
+                    }
+                }
+
+                pub fn multi_mul_scalar(bases: &[Self], scalars: &[Zp]) -> Self {
+                    use rayon::prelude::*;
+                    let n_threads = rayon::current_num_threads();
+                    let chunk_size = bases.len().div_ceil(n_threads);
+                    bases
+                        .par_iter()
+                        .map(|&x| x.inner.into_affine())
+                        .chunks(chunk_size)
+                        .zip(scalars.par_iter().map(|&x| x.inner).chunks(chunk_size))
+                        .map(|(bases, scalars)| Self {
+                            inner: $ark::G2Projective::msm(&bases, &scalars).unwrap(),
+                        })
+                        .sum::<Self>()
+                }
+
+                pub fn to_le_bytes(self) -> [u8; Self::BYTE_SIZE] {
+                    let g = self.inner.into_affine();
+                    let xc0 = bigint_to_le_bytes(g.x.c0.0 .0);
+                    let xc1 = bigint_to_le_bytes(g.x.c1.0 .0);
+                    let yc0 = bigint_to_le_bytes(g.y.c0.0 .0);
+                    let yc1 = bigint_to_le_bytes(g.y.c1.0 .0);
+                    let mut buf = [0u8; 4 * FQ_LIMBS * 8 + 1];
+                    buf[..FQ_LIMBS * 8].copy_from_slice(&xc0);
+                    buf[FQ_LIMBS * 8..][..FQ_LIMBS * 8].copy_from_slice(&xc1);
+                    buf[2 * FQ_LIMBS * 8..][..FQ_LIMBS * 8].copy_from_slice(&yc0);
+                    buf[3 * FQ_LIMBS * 8..][..FQ_LIMBS * 8].copy_from_slice(&yc1);
+                    buf[4 * FQ_LIMBS * 8] = g.infinity as u8;
+                    buf
+                }
+
+                pub fn double(self) -> Self {
+                    Self {
+                        inner: self.inner.double(),
+                    }
+                }
+            }
+
+            impl Add for G2 {
+                type Output = G2;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self::Output {
+                    G2 {
+                        inner: self.inner + rhs.inner,
+                    }
+                }
+            }
+
+            impl Sub for G2 {
+                type Output = G2;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self::Output {
+                    G2 {
+                        inner: self.inner - rhs.inner,
+                    }
+                }
+            }
+
+            impl AddAssign for G2 {
+                #[inline]
+                fn add_assign(&mut self, rhs: Self) {
+                    self.inner += rhs.inner
+                }
+            }
+
+            impl SubAssign for G2 {
+                #[inline]
+                fn sub_assign(&mut self, rhs: Self) {
+                    self.inner -= rhs.inner
+                }
+            }
+
+            impl core::iter::Sum for G2 {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(G2::ZERO, Add::add)
+                }
+            }
+
+            impl Neg for G2 {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self { inner: -self.inner }
+                }
+            }
+        }
+
+        mod gt {
+            use crate::serialization::InvalidArraySizeError;
+
+            use super::*;
+            use ark_ec::pairing::Pairing;
+            use tfhe_versionable::Versionize;
+
+            #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Versionize, Hash)]
+            #[serde(try_from = "SerializableFp12", into = "SerializableFp12")]
+            #[versionize(try_from = "SerializableFp12", into = "SerializableFp12")]
+            #[repr(transparent)]
+            pub struct Gt {
+                inner: ark_ec::pairing::PairingOutput<$ark::$pairing>,
+            }
+
+            impl From<Gt> for SerializableFp12 {
+                fn from(value: Gt) -> Self {
+                    value.inner.0.into()
+                }
+            }
+
+            impl TryFrom<SerializableFp12> for Gt {
+                type Error = InvalidArraySizeError;
+
+                fn try_from(value: SerializableFp12) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        inner: PairingOutput(value.try_into()?),
+                    })
+                }
+            }
+
+            impl fmt::Debug for Gt {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    #[allow(dead_code)]
+                    #[derive(Debug)]
+                    struct QuadExtField<T> {
+                        c0: T,
+                        c1: T,
+                    }
+
+                    #[allow(dead_code)]
+                    #[derive(Debug)]
+                    struct CubicExtField<T> {
+                        c0: T,
+                        c1: T,
+                        c2: T,
+                    }
+
+                    #[allow(dead_code)]
+                    #[derive(Debug)]
+                    pub struct Gt<T> {
+                        inner: T,
+                    }
+
+                    f.debug_struct("Gt")
+                        .field(
+                            "inner",
+                            &Gt {
+                                inner: QuadExtField {
+                                    c0: CubicExtField {
+                                        c0: QuadExtField {
+                                            c0: MontIntDisplay(&self.inner.0.c0.c0.c0),
+                                            c1: MontIntDisplay(&self.inner.0.c0.c0.c1),
+                                        },
+                                        c1: QuadExtField {
+                                            c0: MontIntDisplay(&self.inner.0.c0.c1.c0),
+                                            c1: MontIntDisplay(&self.inner.0.c0.c1.c1),
+                                        },
+                                        c2: QuadExtField {
+                                            c0: MontIntDisplay(&self.inner.0.c0.c2.c0),
+                                            c1: MontIntDisplay(&self.inner.0.c0.c2.c1),
+                                        },
+                                    },
+                                    c1: CubicExtField {
+                                        c0: QuadExtField {
+                                            c0: MontIntDisplay(&self.inner.0.c1.c0.c0),
+                                            c1: MontIntDisplay(&self.inner.0.c1.c0.c1),
+                                        },
+                                        c1: QuadExtField {
+                                            c0: MontIntDisplay(&self.inner.0.c1.c1.c0),
+                                            c1: MontIntDisplay(&self.inner.0.c1.c1.c1),
+                                        },
+                                        c2: QuadExtField {
+                                            c0: MontIntDisplay(&self.inner.0.c1.c2.c0),
+                                            c1: MontIntDisplay(&self.inner.0.c1.c2.c1),
+                                        },
+                                    },
+                                },
+                            },
+                        )
+                        .finish()
+                }
+            }
+
+            impl Gt {
+                pub fn pairing(g1: G1, g2: G2) -> Self {
+                    Self {
+                        inner: $ark::$pairing::pairing(g1.inner, g2.inner),
+                    }
+                }
+
+                pub fn mul_scalar(self, scalar: Zp) -> Self {
+                    Self {
+                        inner: mul_zp(self.inner, scalar),
+                    }
+                }
+            }
+
+            impl Add for Gt {
+                type Output = Gt;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self::Output {
+                    Gt {
+                        inner: self.inner + rhs.inner,
+                    }
+                }
+            }
+
+            impl Sub for Gt {
+                type Output = Gt;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self::Output {
+                    Gt {
+                        inner: self.inner - rhs.inner,
+                    }
+                }
+            }
+
+            impl AddAssign for Gt {
+                #[inline]
+                fn add_assign(&mut self, rhs: Self) {
+                    self.inner += rhs.inner
+                }
+            }
+
+            impl SubAssign for Gt {
+                #[inline]
+                fn sub_assign(&mut self, rhs: Self) {
+                    self.inner -= rhs.inner
+                }
+            }
+
+            impl Neg for Gt {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self { inner: -self.inner }
+                }
+            }
+        }
+
+        mod zp {
+            use crate::serialization::InvalidArraySizeError;
+
+            use super::*;
+            use ark_ff::Fp;
+            use tfhe_versionable::Versionize;
+            use zeroize::Zeroize;
+
+            fn redc(n: [u64; 4], nprime: u64, mut t: [u64; 6]) -> [u64; 4] {
+                for i in 0..2 {
+                    let mut c = 0u64;
+                    let m = u64::wrapping_mul(t[i], nprime);
+
+                    for j in 0..4 {
+                        let x = t[i + j] as u128 + m as u128 * n[j] as u128 + c as u128;
+                        t[i + j] = x as u64;
+                        c = (x >> 64) as u64;
+                    }
+
+                    for j in 4..6 - i {
+                        let x = t[i + j] as u128 + c as u128;
+                        t[i + j] = x as u64;
+                        c = (x >> 64) as u64;
+                    }
+                }
+
+                let mut t = [t[2], t[3], t[4], t[5]];
+
+                if t.into_iter().rev().ge(n.into_iter().rev()) {
+                    let mut o = false;
+                    for i in 0..4 {
+                        let (ti, o0) = u64::overflowing_sub(t[i], n[i]);
+                        let (ti, o1) = u64::overflowing_sub(ti, o as u64);
+                        o = o0 | o1;
+                        t[i] = ti;
+                    }
+                }
+                assert!(t.into_iter().rev().lt(n.into_iter().rev()));
+
+                t
+            }
+
+            #[derive(
+                Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Versionize, Hash, Zeroize,
+            )]
+            #[serde(try_from = "SerializableFp", into = "SerializableFp")]
+            #[versionize(try_from = "SerializableFp", into = "SerializableFp")]
+            #[repr(transparent)]
+            pub struct Zp {
+                pub(crate) inner: $ark::Fr,
+            }
+
+            impl From<Zp> for SerializableFp {
+                fn from(value: Zp) -> Self {
+                    value.inner.into()
+                }
+            }
+            impl TryFrom<SerializableFp> for Zp {
+                type Error = InvalidArraySizeError;
+
+                fn try_from(value: SerializableFp) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        inner: value.try_into()?,
+                    })
+                }
+            }
+
+            impl fmt::Debug for Zp {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple("Zp")
+                        .field(&MontIntDisplay(&self.inner))
+                        .finish()
+                }
+            }
+
+            impl Zp {
+                pub const ZERO: Self = Self {
+                    inner: MontFp!("0"),
+                };
+
+                pub const ONE: Self = Self {
+                    inner: MontFp!("1"),
+                };
+
+                pub fn from_bigint(n: [u64; 4]) -> Self {
+                    Self {
+                        inner: BigInt(n).into(),
+                    }
+                }
+
+                pub fn from_u64(n: u64) -> Self {
+                    Self {
+                        inner: BigInt([n, 0, 0, 0]).into(),
+                    }
+                }
+
+                pub fn from_i64(n: i64) -> Self {
+                    let n_abs = Self::from_u64(n.unsigned_abs());
+                    if n > 0 {
+                        n_abs
+                    } else {
+                        -n_abs
+                    }
+                }
+
+                pub fn to_le_bytes(self) -> [u8; 4 * 8] {
+                    let buf = [
+                        self.inner.0 .0[0].to_le_bytes(),
+                        self.inner.0 .0[1].to_le_bytes(),
+                        self.inner.0 .0[2].to_le_bytes(),
+                        self.inner.0 .0[3].to_le_bytes(),
+                    ];
+                    unsafe { core::mem::transmute(buf) }
+                }
+
+                fn from_raw_u64x6(n: [u64; 6]) -> Self {
+                    const MODULUS: BigInt<4> = <$ark::Fr as PrimeField>::MODULUS;
+
+                    const MODULUS_MONTGOMERY: u64 = <$ark::FrConfig as ark_ff::MontConfig<4>>::INV;
+
+                    const MODULUS_BITS: u32 = <$ark::Fr as PrimeField>::MODULUS_BIT_SIZE;
+
+                    let mut n = n;
+                    // zero the leading bits, so the result is <= MODULUS * 2^128
+                    n[5] &= (1 << (MODULUS_BITS - 1 + 128 - 5 * 64)) - 1;
+                    Zp {
+                        inner: Fp(
+                            BigInt(redc(MODULUS.0, MODULUS_MONTGOMERY, n)),
+                            core::marker::PhantomData,
+                        ),
+                    }
+                }
+
+                pub fn rand(rng: &mut dyn rand::RngCore) -> Self {
+                    use rand::Rng;
+
+                    Self::from_raw_u64x6([
+                        rng.gen::<u64>(),
+                        rng.gen::<u64>(),
+                        rng.gen::<u64>(),
+                        rng.gen::<u64>(),
+                        rng.gen::<u64>(),
+                        rng.gen::<u64>(),
+                    ])
+                }
+
+                pub fn hash(values: &mut [Zp], data: &[&[u8]]) {
+                    use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+                    let mut hasher = sha3::Shake256::default();
+                    for data in data {
+                        hasher.update(data);
+                    }
+                    let mut reader = hasher.finalize_xof();
+
+                    for value in values {
+                        let mut bytes = [0u8; 6 * 8];
+                        reader.read(&mut bytes);
+                        let bytes: [[u8; 8]; 6] = unsafe { core::mem::transmute(bytes) };
+                        *value = Zp::from_raw_u64x6(bytes.map(u64::from_le_bytes));
+                    }
+                }
+
+                pub fn hash_128bit(values: &mut [Zp], data: &[&[u8]]) {
+                    use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+                    let mut hasher = sha3::Shake256::default();
+                    for data in data {
+                        hasher.update(data);
+                    }
+                    let mut reader = hasher.finalize_xof();
+
+                    for value in values {
+                        let mut bytes = [0u8; 2 * 8];
+                        reader.read(&mut bytes);
+                        let limbs: [u64; 2] = unsafe { core::mem::transmute(bytes) };
+                        *value = Zp {
+                            inner: BigInt([limbs[0], limbs[1], 0, 0]).into(),
+                        };
+                    }
+                }
+            }
+
+            impl Add for Zp {
+                type Output = Zp;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self::Output {
+                    Zp {
+                        inner: self.inner + rhs.inner,
+                    }
+                }
+            }
+
+            impl Sub for Zp {
+                type Output = Zp;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self::Output {
+                    Zp {
+                        inner: self.inner - rhs.inner,
+                    }
+                }
+            }
+
+            impl Mul for Zp {
+                type Output = Zp;
+
+                #[inline]
+                fn mul(self, rhs: Self) -> Self::Output {
+                    Zp {
+                        inner: self.inner * rhs.inner,
+                    }
+                }
+            }
+
+            impl Div for Zp {
+                type Output = Zp;
+
+                #[inline]
+                fn div(self, rhs: Self) -> Self::Output {
+                    Zp {
+                        inner: self.inner / rhs.inner,
+                    }
+                }
+            }
+            impl AddAssign for Zp {
+                #[inline]
+                fn add_assign(&mut self, rhs: Self) {
+                    self.inner += rhs.inner
+                }
+            }
+
+            impl SubAssign for Zp {
+                #[inline]
+                fn sub_assign(&mut self, rhs: Self) {
+                    self.inner -= rhs.inner
+                }
+            }
+
+            impl Neg for Zp {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self { inner: -self.inner }
+                }
+            }
+
+            impl core::iter::Sum for Zp {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Zp::ZERO, Add::add)
+                }
+            }
+        }
+
+        pub use g1::{G1Affine, G1};
+        pub use g2::{G2Affine, G2};
+        pub use gt::Gt;
+        pub use zp::Zp;
+    };
+}
+
+pub(crate) use impl_ark_curve;
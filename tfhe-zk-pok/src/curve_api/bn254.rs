@@ -0,0 +1,160 @@
+use super::*;
+
+ark_curve::impl_ark_curve! {
+    ark: ark_bn254,
+    pairing: Bn254,
+    fq_limbs: 4,
+    // https://eips.ethereum.org/EIPS/eip-196
+    g1_generator: ("1", "2"),
+    // https://eips.ethereum.org/EIPS/eip-197
+    g2_generator: (
+        (
+            "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+            "11559732032986387107991004021392285783925812861821192530917403151452391805634"
+        ),
+        (
+            "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+            "4082367875863433681332203403145435568316851327593401208105741076214120093531"
+        )
+    ),
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_generators() {
+        assert!(G1::GENERATOR.normalize().validate());
+        assert!(G2::GENERATOR.normalize().validate());
+        assert_ne!(
+            Gt::pairing(G1::GENERATOR, G2::GENERATOR),
+            Gt::pairing(G1::ZERO, G2::GENERATOR)
+        );
+    }
+
+    #[test]
+    fn test_pairing() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let p1 = Zp::rand(rng);
+        let p2 = Zp::rand(rng);
+
+        let x1 = G1::GENERATOR.mul_scalar(p1);
+        let x2 = G2::GENERATOR.mul_scalar(p2);
+
+        assert_eq!(
+            Gt::pairing(x1, x2),
+            Gt::pairing(G1::GENERATOR, G2::GENERATOR).mul_scalar(p1 * p2),
+        );
+    }
+
+    #[test]
+    fn test_g1_msm() {
+        let n = 1024;
+        let rng = &mut StdRng::seed_from_u64(0);
+        let x = (0..n)
+            .map(|_| G1::GENERATOR.mul_scalar(Zp::rand(rng)).normalize())
+            .collect::<Vec<_>>();
+        let mut p = vec![Zp::ZERO; n];
+        Zp::hash(&mut p, &[&[0]]);
+
+        let expected = x
+            .iter()
+            .zip(p.iter())
+            .map(|(&x, &p)| G1::projective(x).mul_scalar(p))
+            .sum::<G1>();
+        assert_eq!(G1Affine::multi_mul_scalar(&x, &p), expected);
+        assert_eq!(msm::msm_wnaf_g1_bn254(&x, &p), expected);
+    }
+
+    #[test]
+    fn test_distributivity() {
+        let a = Zp {
+            inner: MontFp!(
+                "20799633726231143268782044631117354647259165363910905818134484248029981143850"
+            ),
+        };
+        let b = Zp {
+            inner: MontFp!(
+                "14333504039292951860879669847432876299949385605895551964353185488509497658948"
+            ),
+        };
+        let c = Zp {
+            inner: MontFp!(
+                "6797004509292554067788526429737434481164547177696793280652530849910670196287"
+            ),
+        };
+
+        assert_eq!((((a - b) * c) - (a * c - b * c)).inner, Zp::ZERO.inner);
+    }
+
+    #[test]
+    fn test_serialization() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let alpha = Zp::rand(rng);
+        let g_cur = G1::GENERATOR.mul_scalar(alpha);
+        let g_hat_cur = G2::GENERATOR.mul_scalar(alpha);
+
+        let alpha2: Zp = serde_json::from_str(&serde_json::to_string(&alpha).unwrap()).unwrap();
+        assert_eq!(alpha, alpha2);
+
+        let g_cur2: G1 = serde_json::from_str(&serde_json::to_string(&g_cur).unwrap()).unwrap();
+        assert_eq!(g_cur, g_cur2);
+
+        let g_hat_cur2: G2 =
+            serde_json::from_str(&serde_json::to_string(&g_hat_cur).unwrap()).unwrap();
+        assert_eq!(g_hat_cur, g_hat_cur2);
+    }
+
+    #[test]
+    fn test_compressed_serialization() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let alpha = Zp::rand(rng);
+        let g_cur = G1::GENERATOR.mul_scalar(alpha);
+        let g_hat_cur = G2::GENERATOR.mul_scalar(alpha);
+
+        let g_cur2 = G1::uncompress(
+            serde_json::from_str(&serde_json::to_string(&g_cur.compress()).unwrap()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(g_cur, g_cur2);
+
+        let g_hat_cur2 = G2::uncompress(
+            serde_json::from_str(&serde_json::to_string(&g_hat_cur.compress()).unwrap()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(g_hat_cur, g_hat_cur2);
+    }
+
+    #[test]
+    fn test_hasher_and_eq() {
+        // we need to make sure if the points are the same
+        // but the projective representations are different
+        // then they still hash into the same thing
+        let rng = &mut StdRng::seed_from_u64(0);
+        let alpha = Zp::rand(rng);
+        let a = G1::GENERATOR.mul_scalar(alpha);
+
+        // serialization should convert the point to affine representation
+        // after deserializing it we should have the same point
+        // but with a different representation
+        let a_affine: G1 = serde_json::from_str(&serde_json::to_string(&a).unwrap()).unwrap();
+
+        // the internal elements should be different
+        assert_ne!(a.inner.x, a_affine.inner.x);
+        assert_ne!(a.inner.y, a_affine.inner.y);
+        assert_ne!(a.inner.z, a_affine.inner.z);
+
+        // but equality and hasher should see the two as the same point
+        assert_eq!(a, a_affine);
+        let mut hm = HashMap::new();
+        hm.insert(a, 1);
+        assert_eq!(hm.len(), 1);
+        hm.insert(a_affine, 2);
+        assert_eq!(hm.len(), 1);
+    }
+}
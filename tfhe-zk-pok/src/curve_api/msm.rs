@@ -1,6 +1,6 @@
-use ark_ec::short_weierstrass::Affine;
+use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
 use ark_ec::AffineRepr;
-use ark_ff::{AdditiveGroup, BigInteger, Field, Fp, PrimeField};
+use ark_ff::{AdditiveGroup, BigInteger, Field, PrimeField, Zero};
 use rayon::prelude::*;
 
 fn make_digits(a: &impl BigInteger, w: usize, num_bits: usize) -> impl Iterator<Item = i64> + '_ {
@@ -47,20 +47,17 @@ fn make_digits(a: &impl BigInteger, w: usize, num_bits: usize) -> impl Iterator<
 
 // Compute msm using windowed non-adjacent form
 #[track_caller]
-pub fn msm_wnaf_g1_446(
-    bases: &[super::bls12_446::G1Affine],
-    scalars: &[super::bls12_446::Zp],
-) -> super::bls12_446::G1 {
-    use super::bls12_446::*;
-    let num_bits = 299usize;
-    type BaseField = Fp<ark_ff::MontBackend<crate::curve_446::FqConfig, 7>, 7>;
-
+fn msm_wnaf<C: SWCurveConfig>(
+    bases: &[Affine<C>],
+    scalars: &[C::ScalarField],
+    num_bits: usize,
+) -> Projective<C> {
     assert_eq!(bases.len(), scalars.len());
 
     let size = bases.len();
     let scalars = &*scalars
         .par_iter()
-        .map(|x| x.inner.into_bigint())
+        .map(|x| x.into_bigint())
         .collect::<Vec<_>>();
 
     let c = if size < 32 {
@@ -76,17 +73,15 @@ pub fn msm_wnaf_g1_446(
         .flat_map_iter(|s| make_digits(s, c, num_bits))
         .collect::<Vec<_>>();
 
-    let zero = G1Affine {
-        inner: Affine::zero(),
-    };
+    let zero = Affine::<C>::zero();
 
     let window_sums: Vec<_> = (0..digits_count)
         .into_par_iter()
         .map(|i| {
             let n = 1 << c;
             let mut indices = vec![vec![]; n];
-            let mut d = vec![BaseField::ZERO; n + 1];
-            let mut e = vec![BaseField::ZERO; n + 1];
+            let mut d = vec![C::BaseField::ZERO; n + 1];
+            let mut e = vec![C::BaseField::ZERO; n + 1];
 
             for (idx, digits) in scalar_digits.chunks(digits_count).enumerate() {
                 use core::cmp::Ordering;
@@ -102,23 +97,21 @@ pub fn msm_wnaf_g1_446(
             let mut buckets = vec![zero; 1 << c];
 
             loop {
-                d[0] = BaseField::ONE;
+                d[0] = C::BaseField::ONE;
                 for (k, (bucket, idx)) in core::iter::zip(&mut buckets, &mut indices).enumerate() {
                     if let Some(idx) = idx.last().copied() {
                         let value = if idx >> (usize::BITS - 1) == 1 {
-                            let mut val = bases[!idx];
-                            val.inner.y = -val.inner.y;
-                            val
+                            -bases[!idx]
                         } else {
                             bases[idx]
                         };
 
-                        if !bucket.inner.infinity {
-                            let a = value.inner.x - bucket.inner.x;
-                            if a != BaseField::ZERO {
+                        if !bucket.infinity {
+                            let a = value.x - bucket.x;
+                            if a != C::BaseField::ZERO {
                                 d[k + 1] = d[k] * a;
-                            } else if value.inner.y == bucket.inner.y {
-                                d[k + 1] = d[k] * value.inner.y.double();
+                            } else if value.y == bucket.y {
+                                d[k + 1] = d[k] * value.y.double();
                             } else {
                                 d[k + 1] = d[k];
                             }
@@ -135,19 +128,17 @@ pub fn msm_wnaf_g1_446(
                 {
                     if let Some(idx) = idx.last().copied() {
                         let value = if idx >> (usize::BITS - 1) == 1 {
-                            let mut val = bases[!idx];
-                            val.inner.y = -val.inner.y;
-                            val
+                            -bases[!idx]
                         } else {
                             bases[idx]
                         };
 
-                        if !bucket.inner.infinity {
-                            let a = value.inner.x - bucket.inner.x;
-                            if a != BaseField::ZERO {
+                        if !bucket.infinity {
+                            let a = value.x - bucket.x;
+                            if a != C::BaseField::ZERO {
                                 e[k] = e[k + 1] * a;
-                            } else if value.inner.y == bucket.inner.y {
-                                e[k] = e[k + 1] * value.inner.y.double();
+                            } else if value.y == bucket.y {
+                                e[k] = e[k + 1] * value.y.double();
                             } else {
                                 e[k] = e[k + 1];
                             }
@@ -168,23 +159,21 @@ pub fn msm_wnaf_g1_446(
                     empty &= idx.len() <= 1;
                     if let Some(idx) = idx.pop() {
                         let value = if idx >> (usize::BITS - 1) == 1 {
-                            let mut val = bases[!idx];
-                            val.inner.y = -val.inner.y;
-                            val
+                            -bases[!idx]
                         } else {
                             bases[idx]
                         };
 
-                        if !bucket.inner.infinity {
-                            let x1 = bucket.inner.x;
-                            let x2 = value.inner.x;
-                            let y1 = bucket.inner.y;
-                            let y2 = value.inner.y;
+                        if !bucket.infinity {
+                            let x1 = bucket.x;
+                            let x2 = value.x;
+                            let y1 = bucket.y;
+                            let y2 = value.y;
 
                             let eq_x = x1 == x2;
 
                             if eq_x && y1 != y2 {
-                                bucket.inner.infinity = true;
+                                bucket.infinity = true;
                             } else {
                                 let r = d * e;
                                 let m = if eq_x {
@@ -197,8 +186,8 @@ pub fn msm_wnaf_g1_446(
 
                                 let x3 = m.square() - x1 - x2;
                                 let y3 = m * (x1 - x3) - y1;
-                                bucket.inner.x = x3;
-                                bucket.inner.y = y3;
+                                bucket.x = x3;
+                                bucket.y = y3;
                             }
                         } else {
                             *bucket = value;
@@ -211,10 +200,10 @@ pub fn msm_wnaf_g1_446(
                 }
             }
 
-            let mut running_sum = G1::ZERO;
-            let mut res = G1::ZERO;
+            let mut running_sum = Projective::<C>::zero();
+            let mut res = Projective::<C>::zero();
             buckets.into_iter().rev().for_each(|b| {
-                running_sum.inner += b.inner;
+                running_sum += b;
                 res += running_sum;
             });
             res
@@ -229,11 +218,39 @@ pub fn msm_wnaf_g1_446(
         + window_sums[1..]
             .iter()
             .rev()
-            .fold(G1::ZERO, |mut total, &sum_i| {
+            .fold(Projective::<C>::zero(), |mut total, &sum_i| {
                 total += sum_i;
                 for _ in 0..c {
-                    total = total.double();
+                    total.double_in_place();
                 }
                 total
             })
 }
+
+#[track_caller]
+pub fn msm_wnaf_g1_446(
+    bases: &[super::bls12_446::G1Affine],
+    scalars: &[super::bls12_446::Zp],
+) -> super::bls12_446::G1 {
+    // SAFETY: interpreting a `repr(transparent)` pointer as its contents.
+    let bases = unsafe { &*(bases as *const [_] as *const [crate::curve_446::g1::G1Affine]) };
+    let scalars = unsafe { &*(scalars as *const [_] as *const [crate::curve_446::Fr]) };
+
+    super::bls12_446::G1 {
+        inner: msm_wnaf(bases, scalars, 299),
+    }
+}
+
+#[track_caller]
+pub fn msm_wnaf_g1_bn254(
+    bases: &[super::bn254::G1Affine],
+    scalars: &[super::bn254::Zp],
+) -> super::bn254::G1 {
+    // SAFETY: interpreting a `repr(transparent)` pointer as its contents.
+    let bases = unsafe { &*(bases as *const [_] as *const [ark_bn254::g1::G1Affine]) };
+    let scalars = unsafe { &*(scalars as *const [_] as *const [ark_bn254::Fr]) };
+
+    super::bn254::G1 {
+        inner: msm_wnaf(bases, scalars, 254),
+    }
+}
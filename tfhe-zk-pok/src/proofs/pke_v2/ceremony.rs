@@ -0,0 +1,634 @@
+//! Multi-party generation of the CRS.
+//!
+//! The CRS is built from the powers of a secret `alpha`, that must be erased after the generation
+//! since it would allow to forge proofs. With [`crs_gen`](super::crs_gen), the party that
+//! generates the CRS has to be trusted for this. The ceremony implemented here removes this single
+//! point of trust: the CRS is built sequentially by several participants, each one multiplying the
+//! secret by its own random value `tau`. As long as one participant erases its `tau`, nobody knows
+//! `alpha`.
+//!
+//! Each contribution comes with a proof that the CRS has been correctly updated. The transcript of
+//! the ceremony can be verified by anyone, so a participant can check that its contribution has
+//! been included in the final CRS.
+
+use core::fmt;
+use std::error::Error;
+
+use rand::RngCore;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+use super::{
+    compute_new_crs_params, inf_norm_bound_to_euclidean_squared, Bound, PKEv2DomainSeparators,
+    PublicParams,
+};
+use crate::backward_compatibility::pke_v2::{
+    ContributionVersions, CrsCeremonyVersions, UpdateProofVersions,
+};
+use crate::curve_api::{Curve, CurveGroupOps, FieldOps, PairingGroupOps};
+use crate::proofs::{GroupElements, Sid};
+
+const CEREMONY_DS: &[u8] = b"TFHE_ZK_POK_PKEV2_CRS_CEREMONY";
+
+/// Proof that a participant knows the secret `tau` it used to update the CRS.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>, G::Zp: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize, G::Zp: serde::Serialize"
+))]
+#[versionize(UpdateProofVersions)]
+pub struct UpdateProof<G: Curve> {
+    /// g^tau
+    pub(crate) h: G::G1,
+    /// g_hat^tau
+    pub(crate) h_hat: G::G2,
+    /// Commitment of the Schnorr proof of knowledge of tau
+    pub(crate) R: G::G1,
+    /// Response of the Schnorr proof of knowledge of tau
+    pub(crate) s: G::Zp,
+}
+
+/// A round of the ceremony
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>, G::Zp: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize, G::Zp: serde::Serialize"
+))]
+#[versionize(ContributionVersions)]
+pub struct Contribution<G: Curve> {
+    pub(crate) participant: String,
+    /// The first element of the CRS after this round, which is g^alpha with alpha the product of
+    /// the tau of all the participants so far
+    pub(crate) g1: G::G1,
+    pub(crate) proof: UpdateProof<G>,
+}
+
+impl<G: Curve> Contribution<G> {
+    /// Name of the participant, as given when contributing
+    pub fn participant(&self) -> &str {
+        &self.participant
+    }
+
+    fn verify(&self, crs: &PublicParams<G>, round: usize, previous_g1: G::G1) -> bool {
+        let UpdateProof { h, h_hat, R, s } = self.proof;
+
+        if !(h.validate_projective()
+            && h_hat.validate_projective()
+            && R.validate_projective()
+            && self.g1.validate_projective())
+            || h == G::G1::ZERO
+        {
+            return false;
+        }
+
+        let g = G::G1::GENERATOR;
+        let g_hat = G::G2::GENERATOR;
+
+        // h and h_hat hold the same secret
+        if G::Gt::pairing(h, g_hat) != G::Gt::pairing(g, h_hat) {
+            return false;
+        }
+
+        // The first element of the CRS has been multiplied by this secret
+        if G::Gt::pairing(self.g1, g_hat) != G::Gt::pairing(previous_g1, h_hat) {
+            return false;
+        }
+
+        let c = challenge(
+            crs,
+            round,
+            &self.participant,
+            previous_g1,
+            self.g1,
+            h,
+            h_hat,
+            R,
+        );
+        g.mul_scalar(s) == R + h.mul_scalar(c)
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        let Self {
+            participant,
+            g1,
+            proof: UpdateProof { h, h_hat, R, s },
+        } = self;
+
+        *participant == other.participant
+            && *g1 == other.g1
+            && *h == other.proof.h
+            && *h_hat == other.proof.h_hat
+            && *R == other.proof.R
+            && s.to_le_bytes().as_ref() == other.proof.s.to_le_bytes().as_ref()
+    }
+}
+
+/// Fiat-Shamir challenge of the proof of knowledge of tau, bound to the ceremony and the round
+#[allow(clippy::too_many_arguments)]
+fn challenge<G: Curve>(
+    crs: &PublicParams<G>,
+    round: usize,
+    participant: &str,
+    previous_g1: G::G1,
+    g1: G::G1,
+    h: G::G1,
+    h_hat: G::G2,
+    R: G::G1,
+) -> G::Zp {
+    let mut c = [G::Zp::ZERO];
+    G::Zp::hash(
+        &mut c,
+        &[
+            CEREMONY_DS,
+            crs.sid.to_le_bytes().as_slice(),
+            crs.domain_separators.hash(),
+            &(round as u64).to_le_bytes(),
+            previous_g1.to_le_bytes().as_ref(),
+            g1.to_le_bytes().as_ref(),
+            h.to_le_bytes().as_ref(),
+            h_hat.to_le_bytes().as_ref(),
+            R.to_le_bytes().as_ref(),
+            &(participant.len() as u64).to_le_bytes(),
+            participant.as_bytes(),
+        ],
+    );
+    c[0]
+}
+
+/// Errors returned when the verification of a ceremony fails
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CeremonyError {
+    /// The proof of a contribution is not valid
+    InvalidContribution { round: usize, participant: String },
+    /// The CRS has not been built by the contributions of the transcript
+    TranscriptMismatch,
+    /// The elements of the CRS are not the successive powers of a single secret
+    MalformedCrs,
+    /// The ceremony does not have the same parameters or the same history as the previous one
+    NotAContinuation,
+    /// Nobody has contributed to the ceremony
+    NoContribution,
+}
+
+impl fmt::Display for CeremonyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidContribution { round, participant } => write!(
+                f,
+                "invalid contribution of participant '{participant}' at round {round}"
+            ),
+            Self::TranscriptMismatch => {
+                write!(f, "the CRS does not match the transcript of the ceremony")
+            }
+            Self::MalformedCrs => write!(f, "the elements of the CRS are not well formed"),
+            Self::NotAContinuation => {
+                write!(f, "the ceremony is not a continuation of the previous one")
+            }
+            Self::NoContribution => write!(f, "no participant has contributed to the ceremony"),
+        }
+    }
+}
+
+impl Error for CeremonyError {}
+
+/// The state of a CRS generation ceremony: the current CRS and the transcript of the
+/// contributions that built it.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[serde(bound(
+    deserialize = "G: Curve, PublicParams<G>: serde::Deserialize<'de>, Contribution<G>: serde::Deserialize<'de>",
+    serialize = "G: Curve, PublicParams<G>: serde::Serialize, Contribution<G>: serde::Serialize"
+))]
+#[versionize(CrsCeremonyVersions)]
+pub struct CrsCeremony<G: Curve> {
+    pub(crate) crs: PublicParams<G>,
+    pub(crate) contributions: Vec<Contribution<G>>,
+}
+
+impl<G: Curve> CrsCeremony<G> {
+    /// Starts a new ceremony for the given parameters.
+    ///
+    /// The initial CRS is built with a secret of 1, so it does not hold any secret until the first
+    /// contribution. The parameters have the same meaning as in [`crs_gen`](super::crs_gen).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        d: usize,
+        k: usize,
+        B_inf: u64,
+        q: u64,
+        t: u64,
+        msbs_zero_padding_bit_count: u64,
+        bound_type: Bound,
+        rng: &mut dyn RngCore,
+    ) -> Self {
+        let B_squared = inf_norm_bound_to_euclidean_squared(B_inf, d + k);
+        let (n, D, B_bound_squared, _) = compute_new_crs_params::<G>(
+            d,
+            k,
+            B_squared,
+            q,
+            t,
+            msbs_zero_padding_bit_count,
+            bound_type,
+        );
+
+        Self {
+            crs: PublicParams {
+                g_lists: GroupElements::<G>::new(n, G::Zp::ONE),
+                D,
+                n,
+                d,
+                k,
+                B_bound_squared,
+                B_inf,
+                q,
+                t,
+                msbs_zero_padding_bit_count,
+                bound_type,
+                sid: Sid::new(rng),
+                domain_separators: PKEv2DomainSeparators::new(rng),
+            },
+            contributions: Vec::new(),
+        }
+    }
+
+    /// The CRS in its current state, which should not be used before the end of the ceremony
+    pub fn crs(&self) -> &PublicParams<G> {
+        &self.crs
+    }
+
+    pub fn contributions(&self) -> &[Contribution<G>] {
+        &self.contributions
+    }
+
+    fn current_g1(&self) -> G::G1 {
+        G::G1::projective(self.crs.g_lists.g_list[1])
+    }
+
+    /// Re-randomizes the CRS with a new secret drawn from `rng`, and adds the proof of this update
+    /// to the transcript.
+    ///
+    /// The secret is dropped at the end of this function, the rng should be a cryptographically
+    /// secure one that is not shared with anyone.
+    pub fn contribute(&mut self, participant: &str, rng: &mut dyn RngCore) {
+        let (tau, h) = loop {
+            let tau = G::Zp::rand(rng);
+            let h = G::G1::GENERATOR.mul_scalar(tau);
+            if h != G::G1::ZERO {
+                break (tau, h);
+            }
+        };
+        let h_hat = G::G2::GENERATOR.mul_scalar(tau);
+
+        let n = self.crs.n;
+        let previous_g1 = self.current_g1();
+
+        let mut powers = Vec::with_capacity(2 * n);
+        let mut tau_i = tau;
+        for _ in 0..2 * n {
+            powers.push(tau_i);
+            tau_i = tau_i * tau;
+        }
+
+        // g_i <- g_i^(tau^i) and g_hat_i <- g_hat_i^(tau^i), the punctured element stays at 0
+        let GroupElements {
+            g_list, g_hat_list, ..
+        } = &mut self.crs.g_lists;
+        rayon::join(
+            || {
+                g_list
+                    .0
+                    .par_iter_mut()
+                    .zip(powers.par_iter())
+                    .enumerate()
+                    .filter(|(idx, _)| *idx != n)
+                    .for_each(|(_, (g, tau_i))| {
+                        *g = G::G1::projective(*g).mul_scalar(*tau_i).normalize()
+                    })
+            },
+            || {
+                g_hat_list
+                    .0
+                    .par_iter_mut()
+                    .zip(powers.par_iter())
+                    .for_each(|(g_hat, tau_i)| {
+                        *g_hat = G::G2::projective(*g_hat).mul_scalar(*tau_i).normalize()
+                    })
+            },
+        );
+
+        let g1 = self.current_g1();
+        let round = self.contributions.len();
+
+        let r = G::Zp::rand(rng);
+        let R = G::G1::GENERATOR.mul_scalar(r);
+        let c = challenge(&self.crs, round, participant, previous_g1, g1, h, h_hat, R);
+        let s = r + c * tau;
+
+        self.contributions.push(Contribution {
+            participant: participant.to_string(),
+            g1,
+            proof: UpdateProof { h, h_hat, R, s },
+        });
+    }
+
+    /// Verifies the proofs of all the contributions, and checks that the CRS is well formed and
+    /// has been built by these contributions.
+    ///
+    /// `rng` is used to batch the checks of the CRS elements.
+    pub fn verify(&self, rng: &mut dyn RngCore) -> Result<(), CeremonyError> {
+        let mut previous_g1 = G::G1::GENERATOR;
+        for (round, contribution) in self.contributions.iter().enumerate() {
+            if !contribution.verify(&self.crs, round, previous_g1) {
+                return Err(CeremonyError::InvalidContribution {
+                    round,
+                    participant: contribution.participant.clone(),
+                });
+            }
+            previous_g1 = contribution.g1;
+        }
+
+        if !self.crs.is_usable() {
+            return Err(CeremonyError::MalformedCrs);
+        }
+
+        if self.current_g1() != previous_g1 {
+            return Err(CeremonyError::TranscriptMismatch);
+        }
+
+        if self.is_well_formed(rng) {
+            Ok(())
+        } else {
+            Err(CeremonyError::MalformedCrs)
+        }
+    }
+
+    /// Verifies that this ceremony has been obtained by adding new contributions to `previous`.
+    ///
+    /// This is used by the participants to check the output of the next one before adding their
+    /// own contribution.
+    pub fn verify_update(
+        &self,
+        previous: &Self,
+        rng: &mut dyn RngCore,
+    ) -> Result<(), CeremonyError> {
+        let same_params = {
+            let (crs, prev) = (&self.crs, &previous.crs);
+            crs.n == prev.n
+                && crs.D == prev.D
+                && crs.d == prev.d
+                && crs.k == prev.k
+                && crs.B_bound_squared == prev.B_bound_squared
+                && crs.B_inf == prev.B_inf
+                && crs.q == prev.q
+                && crs.t == prev.t
+                && crs.msbs_zero_padding_bit_count == prev.msbs_zero_padding_bit_count
+                && crs.bound_type == prev.bound_type
+                && crs.sid.0 == prev.sid.0
+                && crs.domain_separators.hash() == prev.domain_separators.hash()
+        };
+
+        let same_history = self.contributions.len() > previous.contributions.len()
+            && previous
+                .contributions
+                .iter()
+                .zip(self.contributions.iter())
+                .all(|(prev, current)| prev.is_same(current));
+
+        if !(same_params && same_history) {
+            return Err(CeremonyError::NotAContinuation);
+        }
+
+        self.verify(rng)
+    }
+
+    /// Verifies the ceremony and returns the final CRS
+    pub fn finalize(self, rng: &mut dyn RngCore) -> Result<PublicParams<G>, CeremonyError> {
+        if self.contributions.is_empty() {
+            return Err(CeremonyError::NoContribution);
+        }
+
+        self.verify(rng)?;
+        Ok(self.crs)
+    }
+
+    /// Checks that the elements of the CRS are the successive powers of the same secret. The size
+    /// of the lists and the punctured element should have been checked before.
+    ///
+    /// The pairing equations for each element are batched in a single one using a random linear
+    /// combination.
+    fn is_well_formed(&self, rng: &mut dyn RngCore) -> bool {
+        let n = self.crs.n;
+        let GroupElements {
+            g_list, g_hat_list, ..
+        } = &self.crs.g_lists;
+
+        let g = G::G1::GENERATOR;
+        let g_hat = G::G2::GENERATOR;
+
+        // e(g_i, g_hat) == e(g_(i-1), g_hat_1), except around the punctured element
+        let (g_bases, g_previous_bases): (Vec<_>, Vec<_>) = (1..=2 * n)
+            .filter(|&i| i != n + 1 && i != n + 2)
+            .map(|i| {
+                let previous = if i == 1 { g.normalize() } else { g_list[i - 1] };
+                (g_list[i], previous)
+            })
+            .unzip();
+        let rho: Vec<_> = (0..g_bases.len()).map(|_| G::Zp::rand(rng)).collect();
+        let (lhs, rhs) = rayon::join(
+            || G::G1::multi_mul_scalar(&g_bases, &rho),
+            || G::G1::multi_mul_scalar(&g_previous_bases, &rho),
+        );
+        if G::Gt::pairing(lhs, g_hat) != G::Gt::pairing(rhs, G::G2::projective(g_hat_list[1])) {
+            return false;
+        }
+
+        // The element after the punctured one is g_n times alpha^2
+        if n >= 2
+            && G::Gt::pairing(G::G1::projective(g_list[n + 2]), g_hat)
+                != G::Gt::pairing(
+                    G::G1::projective(g_list[n]),
+                    G::G2::projective(g_hat_list[2]),
+                )
+        {
+            return false;
+        }
+
+        // e(g, g_hat_i) == e(g_1, g_hat_(i-1))
+        let rho: Vec<_> = (1..n).map(|_| G::Zp::rand(rng)).collect();
+        let (lhs, rhs) = rayon::join(
+            || G::G2::multi_mul_scalar(&g_hat_list.0[1..], &rho),
+            || G::G2::multi_mul_scalar(&g_hat_list.0[..n - 1], &rho),
+        );
+        G::Gt::pairing(g, lhs) == G::Gt::pairing(G::G1::projective(g_list[1]), rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::PKEV2_TEST_PARAMS;
+    use super::super::*;
+    use super::*;
+    use crate::curve_api::Bls12_446;
+    use crate::proofs::test::*;
+    use rand::rngs::StdRng;
+    use rand::{thread_rng, Rng, SeedableRng};
+
+    type Curve = Bls12_446;
+
+    /// Small parameters used to test the ceremony itself
+    const CEREMONY_TEST_PARAMS: PkeTestParameters = PkeTestParameters {
+        d: 16,
+        k: 4,
+        B: 4,
+        q: 0,
+        t: 32,
+        msbs_zero_padding_bit_count: 1,
+    };
+
+    fn new_ceremony(params: PkeTestParameters, rng: &mut StdRng) -> CrsCeremony<Curve> {
+        let PkeTestParameters {
+            d,
+            k,
+            B,
+            q,
+            t,
+            msbs_zero_padding_bit_count,
+        } = params;
+
+        CrsCeremony::new(d, k, B, q, t, msbs_zero_padding_bit_count, Bound::CS, rng)
+    }
+
+    /// Test that a CRS built by a ceremony can be used to prove and verify
+    #[test]
+    fn test_ceremony_crs_proof() {
+        let seed = thread_rng().gen();
+        println!("ceremony seed: {seed:x}");
+        let rng = &mut StdRng::seed_from_u64(seed);
+
+        let mut ceremony = new_ceremony(PKEV2_TEST_PARAMS, rng);
+        ceremony.contribute("alice", rng);
+        ceremony.contribute("bob", rng);
+
+        let ceremony: CrsCeremony<Curve> =
+            bincode::deserialize(&bincode::serialize(&ceremony).unwrap()).unwrap();
+        let public_param = ceremony.finalize(rng).unwrap();
+
+        let testcase = PkeTestcase::gen(rng, PKEV2_TEST_PARAMS);
+        let ct = testcase.encrypt(PKEV2_TEST_PARAMS);
+
+        let (public_commit, private_commit) = commit(
+            testcase.a.clone(),
+            testcase.b.clone(),
+            ct.c1.clone(),
+            ct.c2.clone(),
+            testcase.r.clone(),
+            testcase.e1.clone(),
+            testcase.m.clone(),
+            testcase.e2.clone(),
+            &public_param,
+        );
+
+        for load in [ComputeLoad::Proof, ComputeLoad::Verify] {
+            let proof = prove(
+                (&public_param, &public_commit),
+                &private_commit,
+                &testcase.metadata,
+                load,
+                &seed.to_le_bytes(),
+            );
+
+            assert!(verify(&proof, (&public_param, &public_commit), &testcase.metadata).is_ok());
+        }
+    }
+
+    /// Test that the verification of the ceremony catches modifications of the CRS or of the
+    /// transcript
+    #[test]
+    fn test_ceremony_verify() {
+        let seed = thread_rng().gen();
+        println!("ceremony seed: {seed:x}");
+        let rng = &mut StdRng::seed_from_u64(seed);
+
+        let mut ceremony = new_ceremony(CEREMONY_TEST_PARAMS, rng);
+        assert!(ceremony.verify(rng).is_ok());
+        assert_eq!(
+            ceremony.clone().finalize(rng).unwrap_err(),
+            CeremonyError::NoContribution
+        );
+
+        let initial = ceremony.clone();
+        ceremony.contribute("alice", rng);
+        let after_alice = ceremony.clone();
+        ceremony.contribute("bob", rng);
+
+        assert!(after_alice.verify_update(&initial, rng).is_ok());
+        assert!(ceremony.verify_update(&after_alice, rng).is_ok());
+        assert!(ceremony.verify_update(&initial, rng).is_ok());
+        assert_eq!(
+            after_alice.verify_update(&ceremony, rng).unwrap_err(),
+            CeremonyError::NotAContinuation
+        );
+
+        // A ceremony started separately has a different history
+        let mut other = new_ceremony(CEREMONY_TEST_PARAMS, rng);
+        other.contribute("alice", rng);
+        other.contribute("bob", rng);
+        assert_eq!(
+            other.verify_update(&after_alice, rng).unwrap_err(),
+            CeremonyError::NotAContinuation
+        );
+
+        // The name of a participant is bound to its proof
+        let mut tampered = ceremony.clone();
+        tampered.contributions[0].participant = "eve".to_string();
+        assert_eq!(
+            tampered.verify(rng).unwrap_err(),
+            CeremonyError::InvalidContribution {
+                round: 0,
+                participant: "eve".to_string()
+            }
+        );
+
+        // The contributions can't be reordered
+        let mut tampered = ceremony.clone();
+        tampered.contributions.swap(0, 1);
+        assert!(matches!(
+            tampered.verify(rng).unwrap_err(),
+            CeremonyError::InvalidContribution { round: 0, .. }
+        ));
+
+        // The last contribution is missing
+        let mut tampered = ceremony.clone();
+        tampered.contributions.pop();
+        assert_eq!(
+            tampered.verify(rng).unwrap_err(),
+            CeremonyError::TranscriptMismatch
+        );
+
+        // An element of the CRS has been replaced by a different power
+        let n = ceremony.crs.n;
+        let mut tampered = ceremony.clone();
+        tampered.crs.g_lists.g_list[n + 3] = ceremony.crs.g_lists.g_list[n + 4];
+        assert_eq!(
+            tampered.verify(rng).unwrap_err(),
+            CeremonyError::MalformedCrs
+        );
+
+        let mut tampered = ceremony.clone();
+        tampered.crs.g_lists.g_hat_list[n] = ceremony.crs.g_lists.g_hat_list[1];
+        assert_eq!(
+            tampered.verify(rng).unwrap_err(),
+            CeremonyError::MalformedCrs
+        );
+
+        let mut tampered = ceremony.clone();
+        tampered.crs.g_lists.g_list[n + 2] = ceremony.crs.g_lists.g_list[n];
+        assert_eq!(
+            tampered.verify(rng).unwrap_err(),
+            CeremonyError::MalformedCrs
+        );
+
+        assert!(ceremony.finalize(rng).is_ok());
+    }
+}
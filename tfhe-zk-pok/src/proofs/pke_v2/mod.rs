@@ -16,6 +16,7 @@ use core::marker::PhantomData;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
+pub mod ceremony;
 mod hashes;
 
 use hashes::RHash;
@@ -301,8 +302,15 @@ impl<G: Curve> PublicParams<G> {
         hash_gamma: [u8; HASH_DS_LEN_BYTES],
     ) -> Self {
         let B_squared = inf_norm_bound_to_euclidean_squared(B_inf, d + k);
-        let (n, D, B_bound_squared, _) =
-            compute_crs_params(d, k, B_squared, t, msbs_zero_padding_bit_count, bound_type);
+        let (n, D, B_bound_squared, _) = compute_new_crs_params::<G>(
+            d,
+            k,
+            B_squared,
+            q,
+            t,
+            msbs_zero_padding_bit_count,
+            bound_type,
+        );
         Self {
             g_lists: GroupElements::<G>::from_vec(g_list, g_hat_list),
             D,
@@ -635,6 +643,10 @@ fn ceil_ilog2(value: u128) -> u64 {
     value.ilog2() as u64 + if value.is_power_of_two() { 0 } else { 1 }
 }
 
+/// Computes the parameters of the CRS: `(n, D, B_bound_squared, m_bound)`.
+///
+/// The parameters are not checked against the scalar field of a curve, see
+/// [`compute_crs_params_for_field`].
 pub fn compute_crs_params(
     d: usize,
     k: usize,
@@ -692,6 +704,91 @@ The computed m parameter is {m_bound} > 64. Please select a smaller B, d and/or
     (n, D, B_bound_squared, m_bound)
 }
 
+/// Error returned when the integer relations verified by the proof do not fit in the scalar field
+/// of the curve
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ScalarFieldOverflowError {
+    /// Number of bits of the largest value handled by the proof
+    pub required_bits: u64,
+    /// Number of bits of the modulus of the scalar field
+    pub modulus_bits: u32,
+}
+
+impl core::fmt::Display for ScalarFieldOverflowError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "the proof needs values of up to {} bits which do not fit in the {} bits scalar field \
+of the curve",
+            self.required_bits, self.modulus_bits
+        )
+    }
+}
+
+impl std::error::Error for ScalarFieldOverflowError {}
+
+/// Computes the parameters of the CRS: `(n, D, B_bound_squared, m_bound)`, and checks that they
+/// can be used with the scalar field `Zp`.
+///
+/// `q` is the ciphertext modulus, 0 meaning 2^64. It is used to check that the integer
+/// relations verified by the proof do not wrap around the modulus of the scalar field `Zp`.
+pub fn compute_crs_params_for_field<Zp: FieldOps>(
+    d: usize,
+    k: usize,
+    B_squared: u128,
+    q: u64,
+    t: u64,
+    msbs_zero_padding_bit_count: u64,
+    bound_type: Bound,
+) -> Result<(usize, usize, u128, usize), ScalarFieldOverflowError> {
+    let (n, D, B_bound_squared, m_bound) =
+        compute_crs_params(d, k, B_squared, t, msbs_zero_padding_bit_count, bound_type);
+
+    // The proof checks integer relations by computing them in Zp, so they should not wrap around
+    // the modulus of the scalar field. The largest values are the squared norm of the witness,
+    // bounded by (d + k + 4) * B_bound^2, and the products of the public matrix with the
+    // witness, bounded by D * q * B_bound. The sign takes one more bit.
+    let norm_bits = ceil_ilog2(B_bound_squared) + ceil_ilog2((d + k + 4) as u128);
+    let linear_bits = ceil_ilog2(decode_q(q)) + m_bound as u64 + ceil_ilog2(D as u128);
+    let max_bits = norm_bits.max(linear_bits) + 1;
+    if max_bits >= Zp::MODULUS_BITS as u64 {
+        return Err(ScalarFieldOverflowError {
+            required_bits: max_bits,
+            modulus_bits: Zp::MODULUS_BITS,
+        });
+    }
+
+    Ok((n, D, B_bound_squared, m_bound))
+}
+
+/// Computes the parameters of a new CRS over the curve `G`, see [`compute_crs_params_for_field`].
+///
+/// # Panics
+///
+/// Panics if the parameters cannot be used with the scalar field of the curve
+fn compute_new_crs_params<G: Curve>(
+    d: usize,
+    k: usize,
+    B_squared: u128,
+    q: u64,
+    t: u64,
+    msbs_zero_padding_bit_count: u64,
+    bound_type: Bound,
+) -> (usize, usize, u128, usize) {
+    compute_crs_params_for_field::<G::Zp>(
+        d,
+        k,
+        B_squared,
+        q,
+        t,
+        msbs_zero_padding_bit_count,
+        bound_type,
+    )
+    .unwrap_or_else(|err| {
+        panic!("Invalid parameters for zk_pok, {err}. Please select a smaller B, d and/or k")
+    })
+}
+
 /// Convert a bound on the infinite norm  of a vector into a bound on the square of the euclidean
 /// norm.
 ///
@@ -717,8 +814,15 @@ pub fn crs_gen_ghl<G: Curve>(
     let bound_type = Bound::GHL;
     let alpha = G::Zp::rand(rng);
     let B_squared = inf_norm_bound_to_euclidean_squared(B_inf, d + k);
-    let (n, D, B_bound_squared, _) =
-        compute_crs_params(d, k, B_squared, t, msbs_zero_padding_bit_count, bound_type);
+    let (n, D, B_bound_squared, _) = compute_new_crs_params::<G>(
+        d,
+        k,
+        B_squared,
+        q,
+        t,
+        msbs_zero_padding_bit_count,
+        bound_type,
+    );
     PublicParams {
         g_lists: GroupElements::<G>::new(n, alpha),
         D,
@@ -750,8 +854,15 @@ pub fn crs_gen_cs<G: Curve>(
     let bound_type = Bound::CS;
     let alpha = G::Zp::rand(rng);
     let B_squared = inf_norm_bound_to_euclidean_squared(B_inf, d + k);
-    let (n, D, B_bound_squared, _) =
-        compute_crs_params(d, k, B_squared, t, msbs_zero_padding_bit_count, bound_type);
+    let (n, D, B_bound_squared, _) = compute_new_crs_params::<G>(
+        d,
+        k,
+        B_squared,
+        q,
+        t,
+        msbs_zero_padding_bit_count,
+        bound_type,
+    );
     PublicParams {
         g_lists: GroupElements::<G>::new(n, alpha),
         D,
@@ -876,6 +987,8 @@ fn prove_impl<G: Curve>(
     let decoded_q = decode_q(q);
 
     // Recompute some params for our case if k is smaller than the k max
+    // NB: The values handled by the proof only grow with k, so they fit in the scalar field since
+    // the crs has been checked for k_max
     let B_squared = inf_norm_bound_to_euclidean_squared(B_inf, d + k);
     let (_, D, _, m_bound) = compute_crs_params(
         d,
@@ -1772,14 +1885,17 @@ pub fn verify_impl<G: Curve>(
 
     let effective_cleartext_t = t_input >> msbs_zero_padding_bit_count;
     let B_squared = inf_norm_bound_to_euclidean_squared(B_inf, d + k);
-    let (_, D, _, m_bound) = compute_crs_params(
+    let Ok((_, D, _, m_bound)) = compute_crs_params_for_field::<G::Zp>(
         d,
         k,
         B_squared,
+        q,
         t_input,
         msbs_zero_padding_bit_count,
         bound_type,
-    );
+    ) else {
+        return Err(());
+    };
 
     let m = m_bound;
 
@@ -2691,7 +2807,7 @@ mod tests {
             d,
             k,
             B,
-            q: _,
+            q,
             t,
             msbs_zero_padding_bit_count,
         } = PKEV2_TEST_PARAMS;
@@ -2699,19 +2815,40 @@ mod tests {
         let B_squared = inf_norm_bound_to_euclidean_squared(B, d + k);
         assert_eq!(B_squared, 40681930227712);
 
-        let (n, D, B_bound_squared, m_bound) =
-            compute_crs_params(d, k, B_squared, t, msbs_zero_padding_bit_count, Bound::GHL);
+        let (n, D, B_bound_squared, m_bound) = compute_crs_params_for_field::<bls12_446::Zp>(
+            d,
+            k,
+            B_squared,
+            q,
+            t,
+            msbs_zero_padding_bit_count,
+            Bound::GHL,
+        )
+        .unwrap();
         assert_eq!(n, 6784);
         assert_eq!(D, 3328);
         assert_eq!(B_bound_squared, 3867562496364372);
         assert_eq!(m_bound, 27);
 
-        let (n, D, B_bound_squared, m_bound) =
-            compute_crs_params(d, k, B_squared, t, msbs_zero_padding_bit_count, Bound::CS);
+        let (n, D, B_bound_squared, m_bound) = compute_crs_params_for_field::<bls12_446::Zp>(
+            d,
+            k,
+            B_squared,
+            q,
+            t,
+            msbs_zero_padding_bit_count,
+            Bound::CS,
+        )
+        .unwrap();
         assert_eq!(n, 7168);
         assert_eq!(D, 3328);
         assert_eq!(B_bound_squared, 192844141830554880);
         assert_eq!(m_bound, 30);
+
+        assert_eq!(
+            compute_crs_params(d, k, B_squared, t, msbs_zero_padding_bit_count, Bound::CS),
+            (n, D, B_bound_squared, m_bound)
+        );
     }
 
     /// Test that the proof is rejected if we don't have the padding bit set to 0
@@ -3045,6 +3182,60 @@ mod tests {
         }
     }
 
+    /// Test that proofs built on the bn254 curve are accepted, and rejected with bad metadata
+    #[test]
+    fn test_pke_bn254() {
+        let PkeTestParameters {
+            d,
+            k,
+            B,
+            q,
+            t,
+            msbs_zero_padding_bit_count,
+        } = PKEV2_TEST_PARAMS;
+
+        let seed = thread_rng().gen();
+        println!("pkev2_bn254 seed: {seed:x}");
+        let rng = &mut StdRng::seed_from_u64(seed);
+
+        let testcase = PkeTestcase::gen(rng, PKEV2_TEST_PARAMS);
+        let ct = testcase.encrypt(PKEV2_TEST_PARAMS);
+
+        let public_param =
+            crs_gen::<curve_api::Bn254>(d, k, B, q, t, msbs_zero_padding_bit_count, rng);
+
+        let (public_commit, private_commit) = commit(
+            testcase.a.clone(),
+            testcase.b.clone(),
+            ct.c1.clone(),
+            ct.c2.clone(),
+            testcase.r.clone(),
+            testcase.e1.clone(),
+            testcase.m.clone(),
+            testcase.e2.clone(),
+            &public_param,
+        );
+
+        for load in [ComputeLoad::Proof, ComputeLoad::Verify] {
+            let proof = prove(
+                (&public_param, &public_commit),
+                &private_commit,
+                &testcase.metadata,
+                load,
+                &seed.to_le_bytes(),
+            );
+
+            verify(&proof, (&public_param, &public_commit), &testcase.metadata).unwrap();
+            assert!(verify(&proof, (&public_param, &public_commit), &[]).is_err());
+
+            let compressed_proof = bincode::serialize(&proof.compress()).unwrap();
+            let proof: Proof<curve_api::Bn254> =
+                Proof::uncompress(bincode::deserialize(&compressed_proof).unwrap()).unwrap();
+
+            verify(&proof, (&public_param, &public_commit), &testcase.metadata).unwrap();
+        }
+    }
+
     /// Test the `is_usable` method, that checks the correctness of the the crs
     #[test]
     fn test_crs_usable() {
@@ -0,0 +1,64 @@
+#include "tfhe.h"
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+int main(void) {
+  int ok = 0;
+
+  ConfigBuilder *builder;
+  Config *config;
+
+  ok = config_builder_default(&builder);
+  assert(ok == 0);
+  ok = config_builder_build(builder, &config);
+  assert(ok == 0);
+
+  ClientKey *client_key = NULL;
+  ServerKey *server_key = NULL;
+  ok = generate_keys(config, &client_key, &server_key);
+  assert(ok == 0);
+
+  const char *passphrase = "correct horse battery staple";
+  DynamicBufferView passphrase_view = {.pointer = (const uint8_t *)passphrase,
+                                       .length = strlen(passphrase)};
+
+  DynamicBuffer exported = {.pointer = NULL, .length = 0, .destructor = NULL};
+  ok = client_key_export_encrypted(client_key, passphrase_view, &exported);
+  assert(ok == 0);
+
+  DynamicBufferView exported_view = {.pointer = exported.pointer, .length = exported.length};
+
+  ClientKey *imported_client_key = NULL;
+  ok = client_key_import_encrypted(exported_view, passphrase_view, &imported_client_key);
+  assert(ok == 0);
+
+  // The imported key decrypts values encrypted with the original one
+  FheUint8 *value = NULL;
+  ok = fhe_uint8_try_encrypt_with_client_key_u8(42, client_key, &value);
+  assert(ok == 0);
+
+  uint8_t clear = 0;
+  ok = fhe_uint8_decrypt(value, imported_client_key, &clear);
+  assert(ok == 0);
+  assert(clear == 42);
+
+  // A wrong passphrase is an error
+  tfhe_error_disable_automatic_prints();
+  const char *wrong_passphrase = "wrong passphrase";
+  DynamicBufferView wrong_passphrase_view = {.pointer = (const uint8_t *)wrong_passphrase,
+                                             .length = strlen(wrong_passphrase)};
+  ClientKey *wrong_client_key = NULL;
+  ok = client_key_import_encrypted(exported_view, wrong_passphrase_view, &wrong_client_key);
+  assert(ok != 0);
+  assert(wrong_client_key == NULL);
+  tfhe_error_enable_automatic_prints();
+
+  destroy_dynamic_buffer(&exported);
+  fhe_uint8_destroy(value);
+  client_key_destroy(imported_client_key);
+  client_key_destroy(client_key);
+  server_key_destroy(server_key);
+
+  return EXIT_SUCCESS;
+}
@@ -0,0 +1,154 @@
+//! Runs the rounds of a multi-party ceremony that generates the CRS of the zk proofs.
+//!
+//! The state of the ceremony is passed from one participant to the next one as a file:
+//! - the coordinator starts the ceremony with `init`
+//! - each participant checks the state received from the previous one with `verify` and adds its
+//!   own randomness with `contribute`
+//! - once everyone has contributed, anyone can check the whole transcript with `verify` and write
+//!   the final CRS with `finalize`
+
+use clap::{Arg, ArgMatches, Command};
+use rand::rngs::OsRng;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use tfhe::safe_serialization::{safe_deserialize, safe_serialize};
+use tfhe::shortint::parameters::{
+    PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+};
+use tfhe::zk::CompactPkeCrsCeremony;
+use tfhe::ConfigBuilder;
+
+const SIZE_LIMIT: u64 = 1 << 30;
+
+fn load_ceremony(path: &str) -> Result<CompactPkeCrsCeremony, Box<dyn Error>> {
+    let file = BufReader::new(File::open(path)?);
+    Ok(safe_deserialize(file, SIZE_LIMIT)?)
+}
+
+fn save<T>(object: &T, path: &str) -> Result<(), Box<dyn Error>>
+where
+    T: serde::Serialize + tfhe_versionable::Versionize + tfhe::named::Named,
+{
+    if Path::new(path).exists() {
+        return Err(format!("{path} already exists").into());
+    }
+    let file = BufWriter::new(File::create(path)?);
+    safe_serialize(object, file, SIZE_LIMIT)?;
+    Ok(())
+}
+
+fn input_arg() -> Arg {
+    Arg::new("input")
+        .long("input")
+        .help("File holding the current state of the ceremony")
+        .required(true)
+}
+
+fn output_arg() -> Arg {
+    Arg::new("output")
+        .long("output")
+        .help("File where the result is written")
+        .required(true)
+}
+
+fn arg<'a>(matches: &'a ArgMatches, name: &str) -> &'a str {
+    // Ok to unwrap since the arguments are required
+    matches.get_one::<String>(name).unwrap()
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let matches = Command::new("zk crs ceremony")
+        .about("Multi-party generation of the CRS of the zk proofs")
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("init")
+                .about("Starts a new ceremony for the default compact public key parameters")
+                .arg(output_arg())
+                .arg(
+                    Arg::new("max_bit_size")
+                        .long("max-bit-size")
+                        .help("Maximum number of bits that can be proven at once")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("64"),
+                ),
+        )
+        .subcommand(
+            Command::new("contribute")
+                .about("Verifies the ceremony then adds a contribution to it")
+                .arg(input_arg())
+                .arg(output_arg())
+                .arg(
+                    Arg::new("name")
+                        .long("name")
+                        .help("Name of the participant, recorded in the transcript")
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            Command::new("verify")
+                .about("Verifies the transcript of the ceremony")
+                .arg(input_arg())
+                .arg(
+                    Arg::new("previous")
+                        .long("previous")
+                        .help("Also checks that the ceremony is a continuation of this one"),
+                ),
+        )
+        .subcommand(
+            Command::new("finalize")
+                .about("Verifies the ceremony and writes the final CRS")
+                .arg(input_arg())
+                .arg(output_arg()),
+        )
+        .get_matches();
+
+    let mut rng = OsRng;
+
+    match matches.subcommand() {
+        Some(("init", matches)) => {
+            let max_bit_size = *matches.get_one::<usize>("max_bit_size").unwrap();
+            let config = ConfigBuilder::with_custom_parameters(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            )
+            .use_dedicated_compact_public_key_parameters((
+                PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+                PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            ))
+            .build();
+            let ceremony = CompactPkeCrsCeremony::from_config(config, max_bit_size)?;
+            save(&ceremony, arg(matches, "output"))?;
+            println!("Ceremony started");
+        }
+        Some(("contribute", matches)) => {
+            let mut ceremony = load_ceremony(arg(matches, "input"))?;
+            ceremony.verify(&mut rng)?;
+            ceremony.contribute(arg(matches, "name"), &mut rng);
+            save(&ceremony, arg(matches, "output"))?;
+            println!("Contribution added");
+        }
+        Some(("verify", matches)) => {
+            let ceremony = load_ceremony(arg(matches, "input"))?;
+            match matches.get_one::<String>("previous") {
+                Some(previous) => ceremony.verify_update(&load_ceremony(previous)?, &mut rng)?,
+                None => ceremony.verify(&mut rng)?,
+            }
+            println!("The ceremony is valid, participants:");
+            for (round, participant) in ceremony.participants().enumerate() {
+                println!("{round}: {participant}");
+            }
+        }
+        Some(("finalize", matches)) => {
+            let ceremony = load_ceremony(arg(matches, "input"))?;
+            let crs = ceremony.finalize(&mut rng)?;
+            save(&crs, arg(matches, "output"))?;
+            println!("CRS written");
+        }
+        _ => unreachable!("a subcommand is required"),
+    }
+
+    Ok(())
+}
@@ -78,6 +78,23 @@ test("hlapi_key_gen_big", (t) => {
   let serializedCompressedServerKey = compressedServerKey.serialize();
 });
 
+test("hlapi_client_key_export_encrypted", (t) => {
+  let config = TfheConfigBuilder.default().build();
+
+  let clientKey = TfheClientKey.generate(config);
+
+  let exported = clientKey.export_encrypted("correct horse battery staple");
+  let imported = TfheClientKey.import_encrypted(
+    exported,
+    "correct horse battery staple",
+  );
+  assert.deepStrictEqual(imported.serialize(), clientKey.serialize());
+
+  assert.throws(() =>
+    TfheClientKey.import_encrypted(exported, "wrong passphrase"),
+  );
+});
+
 test("hlapi_key_gen_small", (t) => {
   let params_name_small =
     ShortintParametersName.V1_3_PARAM_MESSAGE_2_CARRY_2_PBS_KS_GAUSSIAN_2M128;
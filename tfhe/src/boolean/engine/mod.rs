@@ -517,6 +517,46 @@ impl BooleanEngine {
             }
         }
     }
+
+    pub fn majority(
+        &mut self,
+        ct_a: &Ciphertext,
+        ct_b: &Ciphertext,
+        ct_c: &Ciphertext,
+        server_key: &ServerKey,
+    ) -> Ciphertext {
+        match (ct_a, ct_b, ct_c) {
+            // if one of the inputs is trivially encrypted, the majority is an OR or an AND of the
+            // two others
+            (Ciphertext::Trivial(message), ct_left, ct_right)
+            | (ct_left, Ciphertext::Trivial(message), ct_right)
+            | (ct_left, ct_right, Ciphertext::Trivial(message)) => {
+                if *message {
+                    self.or(ct_left, ct_right, server_key)
+                } else {
+                    self.and(ct_left, ct_right, server_key)
+                }
+            }
+            (
+                Ciphertext::Encrypted(ct_a_ct),
+                Ciphertext::Encrypted(ct_b_ct),
+                Ciphertext::Encrypted(ct_c_ct),
+            ) => {
+                let mut buffer_lwe_before_pbs =
+                    LweCiphertext::new(0u32, ct_a_ct.lwe_size(), ct_a_ct.ciphertext_modulus());
+
+                let bootstrapper = &mut self.bootstrapper;
+
+                // compute the linear combination for MAJORITY: ct_a + ct_b + ct_c
+                // its sign is the one of the majority of the inputs
+                lwe_ciphertext_add(&mut buffer_lwe_before_pbs, ct_a_ct, ct_b_ct);
+                lwe_ciphertext_add_assign(&mut buffer_lwe_before_pbs, ct_c_ct);
+
+                // compute the bootstrap and the key switch
+                bootstrapper.apply_bootstrapping_pattern(buffer_lwe_before_pbs, server_key)
+            }
+        }
+    }
 }
 
 /// convert into an actual LWE ciphertext even when trivial
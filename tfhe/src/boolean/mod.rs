@@ -63,6 +63,8 @@ pub mod engine;
 pub mod key_switching_key;
 #[cfg(any(test, doctest, feature = "internal-keycache"))]
 pub mod keycache;
+#[cfg(feature = "netlist")]
+pub mod netlist;
 pub mod parameters;
 pub mod prelude;
 pub mod public_key;
@@ -0,0 +1,351 @@
+//! Parser of the BLIF format
+
+use super::{yosys, Driver, Gate, NetlistBuilder};
+use std::collections::HashMap;
+
+fn error_at(line_number: usize, message: &str) -> crate::Error {
+    crate::Error::new(format!("BLIF line {line_number}: {message}"))
+}
+
+/// Returns the logical lines of the source with their line number, comments removed and
+/// continued lines joined
+fn logical_lines(source: &str) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+
+    for (index, line) in source.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default();
+        let (line, continued) = match line.trim_end().strip_suffix('\\') {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+
+        let (_, text) = current.get_or_insert_with(|| (index + 1, String::new()));
+        text.push(' ');
+        text.push_str(line);
+
+        if !continued {
+            let (line_number, text) = current.take().unwrap();
+            if !text.trim().is_empty() {
+                lines.push((line_number, text));
+            }
+        }
+    }
+
+    if let Some((line_number, text)) = current {
+        if !text.trim().is_empty() {
+            lines.push((line_number, text));
+        }
+    }
+
+    lines
+}
+
+/// A `.names` construct: a function described by the list of its true (or false) rows
+struct Cover {
+    inputs: Vec<String>,
+    output: String,
+    // Each cube holds one of '0', '1' or '-' for each input
+    cubes: Vec<Vec<u8>>,
+    // Output value of the rows matched by the cubes, the other rows have the opposite value
+    value: bool,
+}
+
+impl Cover {
+    fn evaluate(&self, row: usize) -> bool {
+        let matched = self.cubes.iter().any(|cube| {
+            cube.iter().enumerate().all(|(i, literal)| match literal {
+                b'0' => (row >> i) & 1 == 0,
+                b'1' => (row >> i) & 1 == 1,
+                _ => true,
+            })
+        });
+        if matched {
+            self.value
+        } else {
+            !self.value
+        }
+    }
+
+    /// Truth table of the cover, bit `row` holds the value of the function for the inputs
+    /// `(row >> i) & 1`. Only used for covers with at most 3 inputs.
+    fn truth_table(&self) -> u8 {
+        (0..1usize << self.inputs.len())
+            .filter(|&row| self.evaluate(row))
+            .fold(0, |table, row| table | (1 << row))
+    }
+
+    /// Adds the drivers of the function to the builder
+    fn lower(self, builder: &mut NetlistBuilder) -> crate::Result<()> {
+        if self.inputs.len() <= 3 {
+            if let Some(driver) = self.match_driver(builder) {
+                return builder.add_driver(&self.output, driver);
+            }
+        }
+
+        self.lower_sum_of_products(builder)
+    }
+
+    /// Tries to map a function of at most 3 inputs to a single driver
+    fn match_driver(&self, builder: &mut NetlistBuilder) -> Option<Driver> {
+        let num_inputs = self.inputs.len();
+        let table = self.truth_table();
+        let num_rows = 1usize << num_inputs;
+        let value = |row: usize| (table >> row) & 1 == 1;
+
+        if (0..num_rows).all(|row| value(row) == value(0)) {
+            return Some(Driver::Constant(value(0)));
+        }
+
+        // Functions of a single input
+        for i in 0..num_inputs {
+            if (0..num_rows).all(|row| value(row) == ((row >> i) & 1 == 1)) {
+                return Some(Driver::Buffer(self.inputs[i].clone()));
+            }
+            if (0..num_rows).all(|row| value(row) == ((row >> i) & 1 == 0)) {
+                return Some(Driver::Not(self.inputs[i].clone()));
+            }
+        }
+
+        // Functions of two inputs, the negation of an input does not need a bootstrap
+        if num_inputs == 2 {
+            for (negate_lhs, negate_rhs) in [(false, false), (true, false), (false, true)] {
+                for gate in Gate::ALL {
+                    let matches = (0..num_rows).all(|row| {
+                        let lhs = (row & 1 == 1) ^ negate_lhs;
+                        let rhs = ((row >> 1) & 1 == 1) ^ negate_rhs;
+                        value(row) == gate.apply(lhs, rhs)
+                    });
+                    if matches {
+                        let mut operand = |i: usize, negate: bool| {
+                            if negate {
+                                builder.add_internal_driver(Driver::Not(self.inputs[i].clone()))
+                            } else {
+                                self.inputs[i].clone()
+                            }
+                        };
+                        let lhs = operand(0, negate_lhs);
+                        let rhs = operand(1, negate_rhs);
+                        return Some(Driver::Gate(gate, lhs, rhs));
+                    }
+                }
+            }
+        }
+
+        // Multiplexers
+        if num_inputs == 3 {
+            for condition in 0..3 {
+                for then in 0..3 {
+                    if then == condition {
+                        continue;
+                    }
+                    // The indexes of the three inputs sum to 3
+                    let otherwise = 3 - condition - then;
+                    let matches = (0..num_rows).all(|row| {
+                        let selected = if (row >> condition) & 1 == 1 {
+                            then
+                        } else {
+                            otherwise
+                        };
+                        value(row) == ((row >> selected) & 1 == 1)
+                    });
+                    if matches {
+                        return Some(Driver::Mux {
+                            condition: self.inputs[condition].clone(),
+                            then: self.inputs[then].clone(),
+                            otherwise: self.inputs[otherwise].clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Lowers the cover as an OR of ANDs, built as balanced trees of gates
+    fn lower_sum_of_products(self, builder: &mut NetlistBuilder) -> crate::Result<()> {
+        let mut negated_inputs: Vec<Option<String>> = vec![None; self.inputs.len()];
+
+        let mut products = Vec::with_capacity(self.cubes.len());
+        for cube in &self.cubes {
+            let mut literals = Vec::new();
+            for (i, literal) in cube.iter().enumerate() {
+                match literal {
+                    b'1' => literals.push(self.inputs[i].clone()),
+                    b'0' => {
+                        let negated = negated_inputs[i].get_or_insert_with(|| {
+                            builder.add_internal_driver(Driver::Not(self.inputs[i].clone()))
+                        });
+                        literals.push(negated.clone());
+                    }
+                    _ => {}
+                }
+            }
+
+            match reduce(builder, Gate::And, literals) {
+                Some(product) => products.push(product),
+                // A cube without literals matches every row
+                None => return builder.add_driver(&self.output, Driver::Constant(self.value)),
+            }
+        }
+
+        let driver = match reduce(builder, Gate::Or, products) {
+            Some(sum) if self.value => Driver::Buffer(sum),
+            Some(sum) => Driver::Not(sum),
+            // An empty cover never matches
+            None => Driver::Constant(!self.value),
+        };
+        builder.add_driver(&self.output, driver)
+    }
+}
+
+/// Combines the nets with a balanced tree of the gate, returns `None` if there are no nets
+fn reduce(builder: &mut NetlistBuilder, gate: Gate, mut nets: Vec<String>) -> Option<String> {
+    while nets.len() > 1 {
+        let mut next = Vec::with_capacity(nets.len().div_ceil(2));
+        let mut nets_iter = nets.into_iter();
+        while let Some(lhs) = nets_iter.next() {
+            match nets_iter.next() {
+                Some(rhs) => next.push(builder.add_internal_driver(Driver::Gate(gate, lhs, rhs))),
+                None => next.push(lhs),
+            }
+        }
+        nets = next;
+    }
+    nets.pop()
+}
+
+pub(super) fn parse(source: &str) -> crate::Result<NetlistBuilder> {
+    let mut builder = NetlistBuilder::default();
+    let mut seen_model = false;
+    let mut current_cover: Option<Cover> = None;
+
+    for (line_number, line) in logical_lines(source) {
+        let mut tokens = line.split_whitespace();
+        let Some(first) = tokens.next() else {
+            continue;
+        };
+
+        if !first.starts_with('.') {
+            let Some(cover) = current_cover.as_mut() else {
+                return Err(error_at(line_number, "unexpected line outside of a .names"));
+            };
+
+            let (cube, value) = if cover.inputs.is_empty() {
+                ("", first)
+            } else {
+                let value = tokens
+                    .next()
+                    .ok_or_else(|| error_at(line_number, "missing output value"))?;
+                (first, value)
+            };
+
+            if tokens.next().is_some() {
+                return Err(error_at(line_number, "too many values"));
+            }
+
+            if cube.len() != cover.inputs.len()
+                || !cube.bytes().all(|c| matches!(c, b'0' | b'1' | b'-'))
+            {
+                return Err(error_at(line_number, "invalid input plane"));
+            }
+
+            let value = match value {
+                "0" => false,
+                "1" => true,
+                _ => return Err(error_at(line_number, "invalid output value")),
+            };
+
+            if !cover.cubes.is_empty() && value != cover.value {
+                return Err(error_at(
+                    line_number,
+                    "a .names cannot mix rows for both output values",
+                ));
+            }
+
+            cover.value = value;
+            cover.cubes.push(cube.as_bytes().to_vec());
+            continue;
+        }
+
+        if let Some(cover) = current_cover.take() {
+            cover.lower(&mut builder)?;
+        }
+
+        match first {
+            ".model" => {
+                if seen_model {
+                    // Only the first model is evaluated
+                    break;
+                }
+                seen_model = true;
+            }
+            ".inputs" => {
+                for name in tokens {
+                    builder.add_input(name)?;
+                }
+            }
+            ".outputs" => {
+                for name in tokens {
+                    builder.add_output(name, name)?;
+                }
+            }
+            ".names" => {
+                let mut nets: Vec<String> = tokens.map(str::to_string).collect();
+                let output = nets
+                    .pop()
+                    .ok_or_else(|| error_at(line_number, ".names without output"))?;
+                current_cover = Some(Cover {
+                    inputs: nets,
+                    output,
+                    cubes: Vec::new(),
+                    value: true,
+                });
+            }
+            // Cells of the yosys internal gate library, written by `write_blif` for the designs
+            // that have been mapped to it
+            ".subckt" | ".gate" => {
+                let cell_type = tokens
+                    .next()
+                    .ok_or_else(|| error_at(line_number, "missing cell type"))?;
+                let mut connections = HashMap::new();
+                for connection in tokens {
+                    let (port, net) = connection
+                        .split_once('=')
+                        .ok_or_else(|| error_at(line_number, "invalid connection"))?;
+                    connections.insert(port.to_string(), net.to_string());
+                }
+                yosys::lower_cell(&mut builder, cell_type, &connections)
+                    .map_err(|err| error_at(line_number, &err.to_string()))?;
+            }
+            ".conn" => {
+                let (Some(source), Some(destination), None) =
+                    (tokens.next(), tokens.next(), tokens.next())
+                else {
+                    return Err(error_at(line_number, "invalid .conn"));
+                };
+                builder.add_driver(destination, Driver::Buffer(source.to_string()))?;
+            }
+            ".end" => break,
+            ".latch" | ".mlatch" => {
+                return Err(error_at(
+                    line_number,
+                    "sequential elements are not supported, the netlist must be combinational",
+                ));
+            }
+            directive => {
+                return Err(error_at(
+                    line_number,
+                    &format!("unsupported directive {directive}"),
+                ));
+            }
+        }
+    }
+
+    if let Some(cover) = current_cover.take() {
+        cover.lower(&mut builder)?;
+    }
+
+    Ok(builder)
+}
@@ -0,0 +1,501 @@
+//! Evaluation of combinational netlists over encrypted bits.
+//!
+//! This module reads a gate-level description of a combinational circuit, as produced by
+//! hardware synthesis tools, and evaluates it homomorphically with the boolean gates of the
+//! [`ServerKey`].
+//!
+//! Two input formats are supported:
+//! - [BLIF](https://course.ece.cmu.edu/~ee760/760docs/blif.pdf), as written by `yosys`
+//!   (`write_blif`) or `abc`. The `.model`, `.inputs`, `.outputs`, `.names` and `.conn` constructs
+//!   are supported, as well as `.subckt` of the `yosys` internal gate library. The first model of
+//!   the file is the one that is evaluated.
+//! - The JSON netlist written by `yosys` (`write_json`), once the design has been mapped to the
+//!   internal gate library (e.g. with `synth -flatten; abc -g AND,NAND,OR,NOR,XOR,XNOR,MUX`).
+//!
+//! The netlist is levelized when it is parsed: all the gates of a level only depend on the
+//! outputs of the previous levels, so they are evaluated in parallel.
+//!
+//! Multi-bit ports of a JSON netlist are split into one input or output per bit, named
+//! `port[i]`, `i` starting at 0 for the least significant bit, which is the naming `yosys`
+//! uses when writing BLIF files.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use tfhe::boolean::gen_keys;
+//! use tfhe::boolean::netlist::Netlist;
+//!
+//! let full_adder = "
+//! .model full_adder
+//! .inputs a b cin
+//! .outputs sum cout
+//! .names a b p
+//! 01 1
+//! 10 1
+//! .names p cin sum
+//! 01 1
+//! 10 1
+//! .names a b cin cout
+//! 11- 1
+//! 1-1 1
+//! -11 1
+//! .end
+//! ";
+//!
+//! let netlist = Netlist::from_blif(full_adder).unwrap();
+//!
+//! let (client_key, server_key) = gen_keys();
+//!
+//! let inputs = HashMap::from([
+//!     ("a".to_string(), client_key.encrypt(true)),
+//!     ("b".to_string(), client_key.encrypt(false)),
+//!     ("cin".to_string(), client_key.encrypt(true)),
+//! ]);
+//!
+//! let outputs = netlist.evaluate(&server_key, inputs).unwrap();
+//!
+//! assert!(!client_key.decrypt(&outputs["sum"]));
+//! assert!(client_key.decrypt(&outputs["cout"]));
+//! ```
+
+mod blif;
+#[cfg(test)]
+mod tests;
+mod yosys;
+
+use crate::boolean::ciphertext::Ciphertext;
+use crate::boolean::server_key::{BinaryBooleanGates, ServerKey};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// The two-input gates a netlist can be made of
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Gate {
+    And,
+    Nand,
+    Or,
+    Nor,
+    Xor,
+    Xnor,
+}
+
+impl Gate {
+    const ALL: [Self; 6] = [
+        Self::And,
+        Self::Nand,
+        Self::Or,
+        Self::Nor,
+        Self::Xor,
+        Self::Xnor,
+    ];
+
+    fn apply(self, lhs: bool, rhs: bool) -> bool {
+        match self {
+            Self::And => lhs & rhs,
+            Self::Nand => !(lhs & rhs),
+            Self::Or => lhs | rhs,
+            Self::Nor => !(lhs | rhs),
+            Self::Xor => lhs ^ rhs,
+            Self::Xnor => !(lhs ^ rhs),
+        }
+    }
+}
+
+/// A node of a levelized netlist, inputs of a node are indexes of previous nodes
+#[derive(Clone, Copy, Debug)]
+enum Node {
+    Input,
+    Constant(bool),
+    Not(usize),
+    Gate(Gate, usize, usize),
+    Mux {
+        condition: usize,
+        then: usize,
+        otherwise: usize,
+    },
+}
+
+impl Node {
+    fn fanin(&self) -> Vec<usize> {
+        match *self {
+            Self::Input | Self::Constant(_) => vec![],
+            Self::Not(input) => vec![input],
+            Self::Gate(_, lhs, rhs) => vec![lhs, rhs],
+            Self::Mux {
+                condition,
+                then,
+                otherwise,
+            } => vec![condition, then, otherwise],
+        }
+    }
+}
+
+/// How a net of the netlist is computed, as described in the source file
+#[derive(Clone, Debug)]
+enum Driver {
+    Input,
+    Constant(bool),
+    Buffer(String),
+    Not(String),
+    Gate(Gate, String, String),
+    Mux {
+        condition: String,
+        then: String,
+        otherwise: String,
+    },
+}
+
+impl Driver {
+    fn fanin(&self) -> Vec<&str> {
+        match self {
+            Self::Input | Self::Constant(_) => vec![],
+            Self::Buffer(input) | Self::Not(input) => vec![input],
+            Self::Gate(_, lhs, rhs) => vec![lhs, rhs],
+            Self::Mux {
+                condition,
+                then,
+                otherwise,
+            } => vec![condition, then, otherwise],
+        }
+    }
+}
+
+/// Collects the nets of a netlist while it is parsed, and turns them into a levelized [`Netlist`]
+#[derive(Default)]
+struct NetlistBuilder {
+    inputs: Vec<String>,
+    // (port name, net name)
+    outputs: Vec<(String, String)>,
+    drivers: HashMap<String, Driver>,
+    num_internal_nets: usize,
+}
+
+impl NetlistBuilder {
+    fn add_input(&mut self, name: &str) -> crate::Result<()> {
+        self.add_driver(name, Driver::Input)?;
+        self.inputs.push(name.to_string());
+        Ok(())
+    }
+
+    fn add_output(&mut self, port: &str, net: &str) -> crate::Result<()> {
+        if self.outputs.iter().any(|(name, _)| name == port) {
+            return Err(crate::Error::new(format!(
+                "Output '{port}' is declared several times"
+            )));
+        }
+        self.outputs.push((port.to_string(), net.to_string()));
+        Ok(())
+    }
+
+    fn add_driver(&mut self, net: &str, driver: Driver) -> crate::Result<()> {
+        if self.drivers.insert(net.to_string(), driver).is_some() {
+            return Err(crate::Error::new(format!(
+                "Net '{net}' is driven several times"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the name of a new net which cannot collide with the names of the source file
+    fn new_internal_net(&mut self) -> String {
+        // Net names of the supported formats cannot contain whitespaces
+        let name = format!(" internal {}", self.num_internal_nets);
+        self.num_internal_nets += 1;
+        name
+    }
+
+    /// Adds a new internal net with the given driver and returns its name
+    fn add_internal_driver(&mut self, driver: Driver) -> String {
+        let name = self.new_internal_net();
+        self.drivers.insert(name.clone(), driver);
+        name
+    }
+
+    fn build(self) -> crate::Result<Netlist> {
+        enum State {
+            InProgress,
+            Done(usize),
+        }
+
+        let Self {
+            inputs,
+            outputs,
+            drivers,
+            num_internal_nets: _,
+        } = self;
+
+        let mut nodes = Vec::with_capacity(drivers.len());
+        let mut node_levels = Vec::with_capacity(drivers.len());
+        let mut states: HashMap<&str, State> = HashMap::with_capacity(drivers.len());
+
+        // All inputs are kept, even if they are not used. They are the first nodes, in the order
+        // they are declared.
+        for name in &inputs {
+            states.insert(name, State::Done(nodes.len()));
+            nodes.push(Node::Input);
+            node_levels.push(0);
+        }
+
+        // Iterative depth first traversal from the outputs, so that deep netlists do not overflow
+        // the stack. Only the logic that is needed to compute the outputs is kept.
+        let mut stack: Vec<(&str, bool)> = outputs
+            .iter()
+            .rev()
+            .map(|(_, net)| (net.as_str(), false))
+            .collect();
+
+        while let Some((net, fanin_done)) = stack.pop() {
+            let driver = drivers
+                .get(net)
+                .ok_or_else(|| crate::Error::new(format!("Net '{net}' is not driven")))?;
+
+            if !fanin_done {
+                match states.get(net) {
+                    Some(State::Done(_)) => continue,
+                    Some(State::InProgress) => {
+                        return Err(crate::Error::new(format!(
+                            "Net '{net}' is part of a combinational loop"
+                        )));
+                    }
+                    None => {}
+                }
+                states.insert(net, State::InProgress);
+                stack.push((net, true));
+                stack.extend(driver.fanin().into_iter().rev().map(|input| (input, false)));
+                continue;
+            }
+
+            let node_of = |name: &str| match states.get(name) {
+                Some(State::Done(index)) => *index,
+                _ => unreachable!("the fanin of a net is processed before the net"),
+            };
+
+            let node = match driver {
+                Driver::Input => unreachable!("inputs are processed before the traversal"),
+                Driver::Buffer(input) => {
+                    let index = node_of(input);
+                    states.insert(net, State::Done(index));
+                    continue;
+                }
+                Driver::Constant(value) => Node::Constant(*value),
+                Driver::Not(input) => Node::Not(node_of(input)),
+                Driver::Gate(gate, lhs, rhs) => Node::Gate(*gate, node_of(lhs), node_of(rhs)),
+                Driver::Mux {
+                    condition,
+                    then,
+                    otherwise,
+                } => Node::Mux {
+                    condition: node_of(condition),
+                    then: node_of(then),
+                    otherwise: node_of(otherwise),
+                },
+            };
+
+            let level = node
+                .fanin()
+                .iter()
+                .map(|&index| node_levels[index] + 1)
+                .max()
+                .unwrap_or(0);
+
+            states.insert(net, State::Done(nodes.len()));
+            nodes.push(node);
+            node_levels.push(level);
+        }
+
+        let outputs = outputs
+            .iter()
+            .map(|(port, net)| match states.get(net.as_str()) {
+                Some(State::Done(index)) => (port.clone(), *index),
+                _ => unreachable!("all the outputs have been processed"),
+            })
+            .collect();
+
+        let depth = node_levels.iter().copied().max().unwrap_or(0);
+        let mut levels = vec![Vec::new(); depth + 1];
+        for (index, level) in node_levels.into_iter().enumerate() {
+            levels[level].push(index);
+        }
+
+        Ok(Netlist {
+            inputs,
+            outputs,
+            nodes,
+            levels,
+        })
+    }
+}
+
+/// The operations needed to evaluate a netlist on a given representation of bits
+pub(crate) trait NetlistBackend: Sync {
+    type Bit: Clone + Send + Sync;
+
+    fn trivial(&self, value: bool) -> Self::Bit;
+
+    fn not(&self, bit: &Self::Bit) -> Self::Bit;
+
+    fn gate(&self, gate: Gate, lhs: &Self::Bit, rhs: &Self::Bit) -> Self::Bit;
+
+    fn mux(&self, condition: &Self::Bit, then: &Self::Bit, otherwise: &Self::Bit) -> Self::Bit;
+}
+
+impl NetlistBackend for ServerKey {
+    type Bit = Ciphertext;
+
+    fn trivial(&self, value: bool) -> Ciphertext {
+        self.trivial_encrypt(value)
+    }
+
+    fn not(&self, bit: &Ciphertext) -> Ciphertext {
+        Self::not(self, bit)
+    }
+
+    fn gate(&self, gate: Gate, lhs: &Ciphertext, rhs: &Ciphertext) -> Ciphertext {
+        match gate {
+            Gate::And => self.and(lhs, rhs),
+            Gate::Nand => self.nand(lhs, rhs),
+            Gate::Or => self.or(lhs, rhs),
+            Gate::Nor => self.nor(lhs, rhs),
+            Gate::Xor => self.xor(lhs, rhs),
+            Gate::Xnor => self.xnor(lhs, rhs),
+        }
+    }
+
+    fn mux(&self, condition: &Ciphertext, then: &Ciphertext, otherwise: &Ciphertext) -> Ciphertext {
+        Self::mux(self, condition, then, otherwise)
+    }
+}
+
+/// A levelized combinational netlist
+///
+/// See the [module level documentation](self) for the supported formats.
+#[derive(Clone, Debug)]
+pub struct Netlist {
+    inputs: Vec<String>,
+    // (port name, node index)
+    outputs: Vec<(String, usize)>,
+    // Nodes are in topological order
+    nodes: Vec<Node>,
+    // Indexes of the nodes of each level, the first level holds the inputs and constants
+    levels: Vec<Vec<usize>>,
+}
+
+impl Netlist {
+    /// Parses a netlist in the BLIF format
+    pub fn from_blif(source: &str) -> crate::Result<Self> {
+        blif::parse(source)?.build()
+    }
+
+    /// Parses a JSON netlist written by `yosys`
+    ///
+    /// If the file holds several modules, the one marked as `top` is used.
+    pub fn from_yosys_json(source: &str) -> crate::Result<Self> {
+        yosys::parse(source)?.build()
+    }
+
+    /// Names of the inputs of the netlist
+    ///
+    /// For BLIF netlists they are in the order they are declared, for JSON netlists the ports
+    /// are sorted by name.
+    pub fn input_names(&self) -> impl Iterator<Item = &str> {
+        self.inputs.iter().map(String::as_str)
+    }
+
+    /// Names of the outputs of the netlist, ordered like the [inputs](Self::input_names)
+    pub fn output_names(&self) -> impl Iterator<Item = &str> {
+        self.outputs.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Number of gates that are evaluated one after the other on the longest path of the
+    /// netlist
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// Number of gates of the netlist, not counting the negations which do not need a bootstrap
+    pub fn num_gates(&self) -> usize {
+        self.nodes
+            .iter()
+            .filter(|node| matches!(node, Node::Gate(..) | Node::Mux { .. }))
+            .count()
+    }
+
+    /// Evaluates the netlist on encrypted inputs
+    ///
+    /// `inputs` must hold exactly one ciphertext for each input of the netlist, the outputs are
+    /// returned by port name.
+    pub fn evaluate(
+        &self,
+        server_key: &ServerKey,
+        inputs: HashMap<String, Ciphertext>,
+    ) -> crate::Result<HashMap<String, Ciphertext>> {
+        self.evaluate_with(server_key, inputs)
+    }
+
+    pub(crate) fn evaluate_with<B: NetlistBackend>(
+        &self,
+        backend: &B,
+        mut inputs: HashMap<String, B::Bit>,
+    ) -> crate::Result<HashMap<String, B::Bit>> {
+        let mut values: Vec<Option<B::Bit>> = vec![None; self.nodes.len()];
+
+        // The inputs are the first nodes of the netlist
+        for (value, name) in values.iter_mut().zip(&self.inputs) {
+            let bit = inputs
+                .remove(name)
+                .ok_or_else(|| crate::Error::new(format!("Missing value for input '{name}'")))?;
+            *value = Some(bit);
+        }
+
+        if let Some(name) = inputs.keys().next() {
+            return Err(crate::Error::new(format!(
+                "'{name}' is not an input of the netlist"
+            )));
+        }
+
+        for level in &self.levels {
+            let level: Vec<usize> = level
+                .iter()
+                .copied()
+                .filter(|&index| !matches!(self.nodes[index], Node::Input))
+                .collect();
+
+            let results: Vec<B::Bit> = level
+                .par_iter()
+                .map(|&index| {
+                    let value = |index: usize| {
+                        values[index]
+                            .as_ref()
+                            .expect("nodes of previous levels are evaluated")
+                    };
+
+                    match self.nodes[index] {
+                        Node::Input => unreachable!("inputs are already set"),
+                        Node::Constant(constant) => backend.trivial(constant),
+                        Node::Not(input) => backend.not(value(input)),
+                        Node::Gate(gate, lhs, rhs) => backend.gate(gate, value(lhs), value(rhs)),
+                        Node::Mux {
+                            condition,
+                            then,
+                            otherwise,
+                        } => backend.mux(value(condition), value(then), value(otherwise)),
+                    }
+                })
+                .collect();
+
+            for (&index, result) in level.iter().zip(results) {
+                values[index] = Some(result);
+            }
+        }
+
+        Ok(self
+            .outputs
+            .iter()
+            .map(|(name, index)| {
+                let value = values[*index].clone().expect("all the nodes are evaluated");
+                (name.clone(), value)
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,332 @@
+use super::*;
+use crate::boolean::keycache::KEY_CACHE;
+use crate::boolean::parameters::DEFAULT_PARAMETERS;
+use crate::boolean::random_boolean;
+
+/// Evaluates netlists on clear bits, to check the parsers without the cost of the bootstraps
+struct ClearBackend;
+
+impl NetlistBackend for ClearBackend {
+    type Bit = bool;
+
+    fn trivial(&self, value: bool) -> bool {
+        value
+    }
+
+    fn not(&self, bit: &bool) -> bool {
+        !bit
+    }
+
+    fn gate(&self, gate: Gate, lhs: &bool, rhs: &bool) -> bool {
+        gate.apply(*lhs, *rhs)
+    }
+
+    fn mux(&self, condition: &bool, then: &bool, otherwise: &bool) -> bool {
+        if *condition {
+            *then
+        } else {
+            *otherwise
+        }
+    }
+}
+
+fn clear_inputs(names: &[&str], values: u64) -> HashMap<String, bool> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.to_string(), (values >> i) & 1 == 1))
+        .collect()
+}
+
+/// 4 bits ripple carry adder, the carry is computed with a 3 inputs cover
+const ADDER_BLIF: &str = r"
+# 4 bits adder
+.model adder
+.inputs a[0] a[1] a[2] a[3] \
+        b[0] b[1] b[2] b[3]
+.outputs s[0] s[1] s[2] s[3] s[4]
+.names $false
+.names a[0] b[0] $false s[0]
+100 1
+010 1
+001 1
+111 1
+.names a[0] b[0] $false c[1]
+11- 1
+1-1 1
+-11 1
+.names a[1] b[1] p[1]
+01 1
+10 1
+.names p[1] c[1] s[1]
+01 1
+10 1
+.names a[1] b[1] c[1] c[2]
+11- 1
+1-1 1
+-11 1
+.names a[2] b[2] p[2]
+01 1
+10 1
+.names p[2] c[2] s[2]
+01 1
+10 1
+.names a[2] b[2] c[2] c[3]
+11- 1
+1-1 1
+-11 1
+.names a[3] b[3] c[3] s[3]
+100 1
+010 1
+001 1
+111 1
+.names a[3] b[3] c[3] c[4]
+00- 0
+0-0 0
+-00 0
+.names c[4] s[4]
+1 1
+.end
+";
+
+const ADDER_INPUTS: [&str; 8] = [
+    "a[0]", "a[1]", "a[2]", "a[3]", "b[0]", "b[1]", "b[2]", "b[3]",
+];
+
+fn adder_result<B>(outputs: &HashMap<String, B>, decode: impl Fn(&B) -> bool) -> u64 {
+    (0..5).fold(0, |result, i| {
+        result | (u64::from(decode(&outputs[&format!("s[{i}]")])) << i)
+    })
+}
+
+/// `y = a ? b : c`, `z = (a & !b) ^ const 1`, `w = !((a | b) & (c | d))` on 2 bits ports
+const MUX_YOSYS_JSON: &str = r#"
+{
+  "creator": "Yosys",
+  "modules": {
+    "helper": {
+      "attributes": {},
+      "ports": {},
+      "cells": {}
+    },
+    "top": {
+      "attributes": { "top": "00000000000000000000000000000001" },
+      "ports": {
+        "a": { "direction": "input", "bits": [ 2 ] },
+        "b": { "direction": "input", "bits": [ 3 ] },
+        "cd": { "direction": "input", "bits": [ 4, 5 ] },
+        "y": { "direction": "output", "bits": [ 6 ] },
+        "zw": { "direction": "output", "bits": [ 8, 10 ] },
+        "k": { "direction": "output", "bits": [ "0", 2 ] }
+      },
+      "cells": {
+        "$mux": {
+          "type": "$_MUX_",
+          "connections": { "A": [ 4 ], "B": [ 3 ], "S": [ 2 ], "Y": [ 6 ] }
+        },
+        "$andnot": {
+          "type": "$_ANDNOT_",
+          "connections": { "A": [ 2 ], "B": [ 3 ], "Y": [ 7 ] }
+        },
+        "$xor": {
+          "type": "$_XOR_",
+          "connections": { "A": [ 7 ], "B": [ "1" ], "Y": [ 8 ] }
+        },
+        "$oai": {
+          "type": "$_OAI4_",
+          "connections": { "A": [ 2 ], "B": [ 3 ], "C": [ 4 ], "D": [ 5 ], "Y": [ 10 ] }
+        }
+      }
+    }
+  }
+}
+"#;
+
+fn mux_expected(values: u64) -> HashMap<String, bool> {
+    let bit = |i: usize| (values >> i) & 1 == 1;
+    let (a, b, c, d) = (bit(0), bit(1), bit(2), bit(3));
+    HashMap::from([
+        ("y".to_string(), if a { b } else { c }),
+        ("zw[0]".to_string(), !(a & !b)),
+        ("zw[1]".to_string(), !((a | b) & (c | d))),
+        ("k[0]".to_string(), false),
+        ("k[1]".to_string(), a),
+    ])
+}
+
+#[test]
+fn test_blif_adder_clear() {
+    let netlist = Netlist::from_blif(ADDER_BLIF).unwrap();
+
+    assert_eq!(netlist.input_names().collect::<Vec<_>>(), ADDER_INPUTS);
+    assert_eq!(
+        netlist.output_names().collect::<Vec<_>>(),
+        ["s[0]", "s[1]", "s[2]", "s[3]", "s[4]"]
+    );
+
+    for values in 0..256u64 {
+        let inputs = clear_inputs(&ADDER_INPUTS, values);
+        let outputs = netlist.evaluate_with(&ClearBackend, inputs).unwrap();
+        assert_eq!(
+            adder_result(&outputs, |bit| *bit),
+            (values & 0xF) + (values >> 4)
+        );
+    }
+}
+
+#[test]
+fn test_blif_all_three_inputs_functions() {
+    for table in 0..=u8::MAX {
+        let mut source = String::from(".model f\n.inputs a b c\n.outputs y\n.names a b c y\n");
+        for row in (0..8).filter(|row| (table >> row) & 1 == 1) {
+            let cube: String = (0..3)
+                .map(|i| if (row >> i) & 1 == 1 { '1' } else { '0' })
+                .collect();
+            source.push_str(&format!("{cube} 1\n"));
+        }
+
+        let netlist = Netlist::from_blif(&source).unwrap();
+
+        for row in 0..8 {
+            let inputs = clear_inputs(&["a", "b", "c"], row);
+            let outputs = netlist.evaluate_with(&ClearBackend, inputs).unwrap();
+            assert_eq!(outputs["y"], (table >> row) & 1 == 1, "table {table:08b}");
+        }
+    }
+}
+
+#[test]
+fn test_blif_gate_matching() {
+    let netlist =
+        Netlist::from_blif(".model m\n.inputs s a b\n.outputs y\n.names s a b y\n11- 1\n0-1 1\n")
+            .unwrap();
+    assert_eq!(netlist.num_gates(), 1);
+    assert_eq!(netlist.depth(), 1);
+
+    let netlist =
+        Netlist::from_blif(".model m\n.inputs a b\n.outputs y\n.names a b y\n00 1\n11 1\n")
+            .unwrap();
+    assert_eq!(netlist.num_gates(), 1);
+    assert_eq!(netlist.depth(), 1);
+}
+
+#[test]
+fn test_blif_subckt() {
+    let source = "
+.model m
+.inputs a b c
+.outputs y z
+.subckt $_NAND_ A=a B=b Y=n
+.subckt $_AOI3_ A=n B=c C=a Y=y
+.conn n z
+.end
+";
+    let netlist = Netlist::from_blif(source).unwrap();
+
+    for values in 0..8 {
+        let inputs = clear_inputs(&["a", "b", "c"], values);
+        let bit = |i: usize| (values >> i) & 1 == 1;
+        let n = !(bit(0) & bit(1));
+        let outputs = netlist.evaluate_with(&ClearBackend, inputs).unwrap();
+        assert_eq!(outputs["y"], !((n & bit(2)) | bit(0)));
+        assert_eq!(outputs["z"], n);
+    }
+}
+
+#[test]
+fn test_yosys_json_clear() {
+    let netlist = Netlist::from_yosys_json(MUX_YOSYS_JSON).unwrap();
+
+    assert_eq!(
+        netlist.input_names().collect::<Vec<_>>(),
+        ["a", "b", "cd[0]", "cd[1]"]
+    );
+
+    for values in 0..16 {
+        let inputs = clear_inputs(&["a", "b", "cd[0]", "cd[1]"], values);
+        let outputs = netlist.evaluate_with(&ClearBackend, inputs).unwrap();
+        assert_eq!(outputs, mux_expected(values));
+    }
+}
+
+#[test]
+fn test_netlist_errors() {
+    let parse = |source: &str| Netlist::from_blif(source).map(|_| ()).unwrap_err();
+
+    // Combinational loop
+    parse(".model m\n.inputs a\n.outputs y\n.names a z y\n11 1\n.names y z\n1 1\n");
+    // Undriven net
+    parse(".model m\n.inputs a\n.outputs y\n.names a z y\n11 1\n");
+    // Net with several drivers
+    parse(".model m\n.inputs a\n.outputs y\n.names a y\n1 1\n.names a y\n0 1\n");
+    // Sequential logic
+    parse(".model m\n.inputs a\n.outputs y\n.latch a y re clk 0\n");
+    // Invalid covers
+    parse(".model m\n.inputs a\n.outputs y\n.names a y\n12 1\n");
+    parse(".model m\n.inputs a\n.outputs y\n.names a y\n1 1\n0 0\n");
+    // Unknown cell
+    parse(".model m\n.inputs a\n.outputs y\n.subckt $_DFF_P_ C=a D=a Q=y\n");
+
+    assert!(Netlist::from_yosys_json("{}").is_err());
+    assert!(Netlist::from_yosys_json(&MUX_YOSYS_JSON.replace("$_MUX_", "$_DFF_P_")).is_err());
+
+    let netlist = Netlist::from_blif(ADDER_BLIF).unwrap();
+    let mut inputs = clear_inputs(&ADDER_INPUTS, 0);
+    inputs.remove("b[2]");
+    assert!(netlist.evaluate_with(&ClearBackend, inputs).is_err());
+
+    let mut inputs = clear_inputs(&ADDER_INPUTS, 0);
+    inputs.insert("c".to_string(), true);
+    assert!(netlist.evaluate_with(&ClearBackend, inputs).is_err());
+}
+
+#[test]
+fn test_blif_adder_encrypted() {
+    let keys = KEY_CACHE.get_from_param(DEFAULT_PARAMETERS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let netlist = Netlist::from_blif(ADDER_BLIF).unwrap();
+
+    for _ in 0..4 {
+        let values = (0..8).fold(0u64, |values, i| {
+            values | (u64::from(random_boolean()) << i)
+        });
+        let inputs = ADDER_INPUTS
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.to_string(), cks.encrypt((values >> i) & 1 == 1)))
+            .collect();
+
+        let outputs = netlist.evaluate(sks, inputs).unwrap();
+
+        assert_eq!(
+            adder_result(&outputs, |bit| cks.decrypt(bit)),
+            (values & 0xF) + (values >> 4)
+        );
+    }
+}
+
+#[test]
+fn test_yosys_json_encrypted() {
+    let keys = KEY_CACHE.get_from_param(DEFAULT_PARAMETERS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let netlist = Netlist::from_yosys_json(MUX_YOSYS_JSON).unwrap();
+
+    for values in 0..16 {
+        let inputs = ["a", "b", "cd[0]", "cd[1]"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.to_string(), cks.encrypt((values >> i) & 1 == 1)))
+            .collect();
+
+        let outputs: HashMap<String, bool> = netlist
+            .evaluate(sks, inputs)
+            .unwrap()
+            .iter()
+            .map(|(name, bit)| (name.clone(), cks.decrypt(bit)))
+            .collect();
+
+        assert_eq!(outputs, mux_expected(values));
+    }
+}
@@ -0,0 +1,231 @@
+//! Parser of the JSON netlists written by `yosys`, and lowering of the cells of its internal gate
+//! library
+
+use super::{Driver, Gate, NetlistBuilder};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Adds the drivers of a cell of the `yosys` internal gate library (`$_AND_`, `$_MUX_`, ...) to
+/// the builder. `connections` maps the ports of the cell to the names of the nets.
+pub(super) fn lower_cell(
+    builder: &mut NetlistBuilder,
+    cell_type: &str,
+    connections: &HashMap<String, String>,
+) -> crate::Result<()> {
+    let port = |name: &str| {
+        connections.get(name).cloned().ok_or_else(|| {
+            crate::Error::new(format!(
+                "Port {name} of a {cell_type} cell is not connected"
+            ))
+        })
+    };
+
+    let output = port("Y")?;
+
+    let gate = |gate| -> crate::Result<Driver> { Ok(Driver::Gate(gate, port("A")?, port("B")?)) };
+
+    let driver = match cell_type {
+        "$_BUF_" => Driver::Buffer(port("A")?),
+        "$_NOT_" => Driver::Not(port("A")?),
+        "$_AND_" => gate(Gate::And)?,
+        "$_NAND_" => gate(Gate::Nand)?,
+        "$_OR_" => gate(Gate::Or)?,
+        "$_NOR_" => gate(Gate::Nor)?,
+        "$_XOR_" => gate(Gate::Xor)?,
+        "$_XNOR_" => gate(Gate::Xnor)?,
+        // A & !B
+        "$_ANDNOT_" => {
+            let not_b = builder.add_internal_driver(Driver::Not(port("B")?));
+            Driver::Gate(Gate::And, port("A")?, not_b)
+        }
+        // A | !B
+        "$_ORNOT_" => {
+            let not_b = builder.add_internal_driver(Driver::Not(port("B")?));
+            Driver::Gate(Gate::Or, port("A")?, not_b)
+        }
+        // S ? B : A
+        "$_MUX_" => Driver::Mux {
+            condition: port("S")?,
+            then: port("B")?,
+            otherwise: port("A")?,
+        },
+        // !(S ? B : A)
+        "$_NMUX_" => {
+            let mux = builder.add_internal_driver(Driver::Mux {
+                condition: port("S")?,
+                then: port("B")?,
+                otherwise: port("A")?,
+            });
+            Driver::Not(mux)
+        }
+        // !((A & B) | C)
+        "$_AOI3_" => {
+            let and = builder.add_internal_driver(gate(Gate::And)?);
+            Driver::Gate(Gate::Nor, and, port("C")?)
+        }
+        // !((A | B) & C)
+        "$_OAI3_" => {
+            let or = builder.add_internal_driver(gate(Gate::Or)?);
+            Driver::Gate(Gate::Nand, or, port("C")?)
+        }
+        // !((A & B) | (C & D))
+        "$_AOI4_" => {
+            let and_ab = builder.add_internal_driver(gate(Gate::And)?);
+            let and_cd =
+                builder.add_internal_driver(Driver::Gate(Gate::And, port("C")?, port("D")?));
+            Driver::Gate(Gate::Nor, and_ab, and_cd)
+        }
+        // !((A | B) & (C | D))
+        "$_OAI4_" => {
+            let or_ab = builder.add_internal_driver(gate(Gate::Or)?);
+            let or_cd = builder.add_internal_driver(Driver::Gate(Gate::Or, port("C")?, port("D")?));
+            Driver::Gate(Gate::Nand, or_ab, or_cd)
+        }
+        _ => {
+            return Err(crate::Error::new(format!(
+                "Unsupported cell type {cell_type}, the design must be mapped to the internal \
+                gate library of yosys (e.g. with `synth -flatten; abc -g cmos2`) and be \
+                combinational"
+            )));
+        }
+    };
+
+    builder.add_driver(&output, driver)
+}
+
+fn field<'a>(
+    object: &'a Map<String, Value>,
+    name: &str,
+    context: &str,
+) -> crate::Result<&'a Value> {
+    object
+        .get(name)
+        .ok_or_else(|| crate::Error::new(format!("Missing field '{name}' in {context}")))
+}
+
+fn as_object<'a>(value: &'a Value, context: &str) -> crate::Result<&'a Map<String, Value>> {
+    value
+        .as_object()
+        .ok_or_else(|| crate::Error::new(format!("{context} is not a JSON object")))
+}
+
+/// Name of the net of a bit: an integer id, or a constant
+fn bit_net(bit: &Value) -> crate::Result<String> {
+    match bit {
+        Value::Number(id) => Ok(format!("#{id}")),
+        Value::String(constant) if constant == "0" || constant == "1" => Ok(constant.clone()),
+        _ => Err(crate::Error::new(format!(
+            "Unsupported bit {bit}, only defined constants and nets are supported"
+        ))),
+    }
+}
+
+fn bits(port: &Map<String, Value>, context: &str) -> crate::Result<Vec<String>> {
+    field(port, "bits", context)?
+        .as_array()
+        .ok_or_else(|| crate::Error::new(format!("The bits of {context} are not an array")))?
+        .iter()
+        .map(bit_net)
+        .collect()
+}
+
+/// Returns the module marked as top, or the only module of the file
+fn top_module(modules: &Map<String, Value>) -> crate::Result<&Map<String, Value>> {
+    let is_top = |module: &Value| {
+        module
+            .get("attributes")
+            .and_then(|attributes| attributes.get("top"))
+            .is_some_and(|top| match top {
+                // yosys writes the attribute as a binary string
+                Value::String(value) => value.contains('1'),
+                Value::Number(value) => value.as_u64() != Some(0),
+                _ => false,
+            })
+    };
+
+    let mut tops = modules.iter().filter(|(_, module)| is_top(module));
+    let (name, module) = match (tops.next(), tops.next()) {
+        (Some(top), None) => top,
+        (Some(_), Some(_)) => return Err("Several modules are marked as top".into()),
+        (None, _) if modules.len() == 1 => modules.iter().next().unwrap(),
+        (None, _) => return Err("No module is marked as top".into()),
+    };
+
+    as_object(module, &format!("module {name}"))
+}
+
+pub(super) fn parse(source: &str) -> crate::Result<NetlistBuilder> {
+    let root: Value = serde_json::from_str(source)
+        .map_err(|err| crate::Error::new(format!("Invalid JSON netlist: {err}")))?;
+
+    let root = as_object(&root, "the netlist")?;
+    let modules = as_object(field(root, "modules", "the netlist")?, "modules")?;
+    let module = top_module(modules)?;
+
+    let mut builder = NetlistBuilder::default();
+    builder.add_driver("0", Driver::Constant(false))?;
+    builder.add_driver("1", Driver::Constant(true))?;
+
+    if let Some(ports) = module.get("ports") {
+        for (name, port) in as_object(ports, "ports")? {
+            let context = format!("port {name}");
+            let port = as_object(port, &context)?;
+            let bits = bits(port, &context)?;
+            let bit_name = |index: usize| {
+                if bits.len() == 1 {
+                    name.clone()
+                } else {
+                    format!("{name}[{index}]")
+                }
+            };
+
+            match field(port, "direction", &context)?.as_str() {
+                Some("input") => {
+                    for (index, net) in bits.iter().enumerate() {
+                        let input = bit_name(index);
+                        builder.add_input(&input)?;
+                        builder.add_driver(net, Driver::Buffer(input))?;
+                    }
+                }
+                Some("output") => {
+                    for (index, net) in bits.iter().enumerate() {
+                        builder.add_output(&bit_name(index), net)?;
+                    }
+                }
+                _ => {
+                    return Err(crate::Error::new(format!(
+                        "Unsupported direction for {context}, only inputs and outputs are \
+                        supported"
+                    )));
+                }
+            }
+        }
+    }
+
+    if let Some(cells) = module.get("cells") {
+        for (name, cell) in as_object(cells, "cells")? {
+            let context = format!("cell {name}");
+            let cell = as_object(cell, &context)?;
+            let cell_type = field(cell, "type", &context)?
+                .as_str()
+                .ok_or_else(|| crate::Error::new(format!("Invalid type for {context}")))?;
+
+            let mut connections = HashMap::new();
+            for (port, bits) in as_object(field(cell, "connections", &context)?, &context)? {
+                let bits = bits.as_array().ok_or_else(|| {
+                    crate::Error::new(format!("Invalid connection for {context}"))
+                })?;
+                let [bit] = bits.as_slice() else {
+                    return Err(crate::Error::new(format!(
+                        "Port {port} of {context} is not a single bit"
+                    )));
+                };
+                connections.insert(port.clone(), bit_net(bit)?);
+            }
+
+            lower_cell(&mut builder, cell_type, &connections)?;
+        }
+    }
+
+    Ok(builder)
+}
@@ -3,7 +3,7 @@
 //! This module implements the generation of the server's public key, together with all the
 //! available homomorphic Boolean gates ($\mathrm{AND}$, $\mathrm{MUX}$, $\mathrm{NAND}$,
 //! $\mathrm{NOR}$,
-//! $\mathrm{NOT}$, $\mathrm{OR}$, $\mathrm{XNOR}$, $\mathrm{XOR}$).
+//! $\mathrm{NOT}$, $\mathrm{OR}$, $\mathrm{XNOR}$, $\mathrm{XOR}$, $\mathrm{MAJORITY}$).
 
 #[cfg(test)]
 mod tests;
@@ -156,6 +156,66 @@ impl ServerKey {
     }
 }
 
+impl ServerKey {
+    /// Computes the majority of three ciphertexts, i.e. whether at least two of them encrypt
+    /// `true`, with a single bootstrap.
+    ///
+    /// The noise of the three inputs is summed before the bootstrap, so the error probability is
+    /// slightly higher than the one of the binary gates. The boolean encoding leaves no room for
+    /// other three-input gates in a single bootstrap, see the `FheBool` type of the high level
+    /// API for those.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let ct_a = cks.encrypt(true);
+    /// let ct_b = cks.encrypt(false);
+    /// let ct_c = cks.encrypt(true);
+    ///
+    /// let ct_res = sks.majority(&ct_a, &ct_b, &ct_c);
+    /// assert!(cks.decrypt(&ct_res));
+    /// ```
+    pub fn majority(&self, ct_a: &Ciphertext, ct_b: &Ciphertext, ct_c: &Ciphertext) -> Ciphertext {
+        BooleanEngine::with_thread_local_mut(|engine| engine.majority(ct_a, ct_b, ct_c, self))
+    }
+
+    /// Adds three ciphertexts, returns the sum and the carry.
+    ///
+    /// The carry is the majority of the inputs and costs a single bootstrap, it is computed in
+    /// parallel with the two XOR gates of the sum.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::gen_keys;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let ct_a = cks.encrypt(true);
+    /// let ct_b = cks.encrypt(false);
+    /// let ct_carry = cks.encrypt(true);
+    ///
+    /// let (ct_sum, ct_carry) = sks.full_adder(&ct_a, &ct_b, &ct_carry);
+    /// assert!(!cks.decrypt(&ct_sum));
+    /// assert!(cks.decrypt(&ct_carry));
+    /// ```
+    pub fn full_adder(
+        &self,
+        ct_a: &Ciphertext,
+        ct_b: &Ciphertext,
+        ct_carry: &Ciphertext,
+    ) -> (Ciphertext, Ciphertext) {
+        rayon::join(
+            || self.xor(&self.xor(ct_a, ct_b), ct_carry),
+            || self.majority(ct_a, ct_b, ct_carry),
+        )
+    }
+}
+
 impl CompressedServerKey {
     pub fn new(cks: &ClientKey) -> Self {
         BooleanEngine::with_thread_local_mut(|engine| engine.create_compressed_server_key(cks))
@@ -64,6 +64,14 @@ mod default_parameters_tests {
         test_mux_gate(DEFAULT_PARAMETERS);
     }
     #[test]
+    fn test_majority_gate_default_parameters() {
+        test_majority_gate(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_full_adder_default_parameters() {
+        test_full_adder(DEFAULT_PARAMETERS);
+    }
+    #[test]
     fn test_deep_circuit_default_parameters() {
         test_deep_circuit(DEFAULT_PARAMETERS);
     }
@@ -111,6 +119,14 @@ mod low_prob_parameters_tests {
         test_mux_gate(PARAMETERS_ERROR_PROB_2_POW_MINUS_165);
     }
     #[test]
+    fn test_majority_gate_low_prob() {
+        test_majority_gate(PARAMETERS_ERROR_PROB_2_POW_MINUS_165);
+    }
+    #[test]
+    fn test_full_adder_low_prob() {
+        test_full_adder(PARAMETERS_ERROR_PROB_2_POW_MINUS_165);
+    }
+    #[test]
     fn test_deep_circuit_low_prob() {
         test_deep_circuit(PARAMETERS_ERROR_PROB_2_POW_MINUS_165);
     }
@@ -157,6 +173,14 @@ mod default_parameters_ks_pbs_tests {
         test_mux_gate(DEFAULT_PARAMETERS_KS_PBS);
     }
     #[test]
+    fn test_majority_gate_default_parameters_ks_pbs() {
+        test_majority_gate(DEFAULT_PARAMETERS_KS_PBS);
+    }
+    #[test]
+    fn test_full_adder_default_parameters_ks_pbs() {
+        test_full_adder(DEFAULT_PARAMETERS_KS_PBS);
+    }
+    #[test]
     fn test_deep_circuit_default_parameters_ks_pbs() {
         test_deep_circuit(DEFAULT_PARAMETERS_KS_PBS);
     }
@@ -204,6 +228,14 @@ mod low_prob_parameters_ks_pbs_tests {
         test_mux_gate(PARAMETERS_ERROR_PROB_2_POW_MINUS_165_KS_PBS);
     }
     #[test]
+    fn test_majority_gate_low_probability_ks_pbs() {
+        test_majority_gate(PARAMETERS_ERROR_PROB_2_POW_MINUS_165_KS_PBS);
+    }
+    #[test]
+    fn test_full_adder_low_probability_ks_pbs() {
+        test_full_adder(PARAMETERS_ERROR_PROB_2_POW_MINUS_165_KS_PBS);
+    }
+    #[test]
     fn test_deep_circuit_low_probability_ks_pbs() {
         test_deep_circuit(PARAMETERS_ERROR_PROB_2_POW_MINUS_165_KS_PBS);
     }
@@ -251,6 +283,14 @@ mod tfhe_lib_parameters_tests {
         test_mux_gate(TFHE_LIB_PARAMETERS);
     }
     #[test]
+    fn test_majority_gate_tfhe_lib_parameters() {
+        test_majority_gate(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_full_adder_tfhe_lib_parameters() {
+        test_full_adder(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
     fn test_deep_circuit_tfhe_lib_parameters() {
         test_deep_circuit(TFHE_LIB_PARAMETERS);
     }
@@ -386,6 +426,66 @@ fn test_mux_gate(parameters: BooleanParameters) {
     }
 }
 
+fn test_majority_gate(parameters: BooleanParameters) {
+    let keys = KEY_CACHE.get_from_param(parameters);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    for _ in 0..NB_TESTS {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+        let expected_result = (b1 && b2) || (b1 && b3) || (b2 && b3);
+
+        let ct1 = random_enum_encryption(cks, sks, b1);
+
+        let ct2 = random_enum_encryption(cks, sks, b2);
+
+        let ct3 = random_enum_encryption(cks, sks, b3);
+
+        // MAJORITY gate
+        let ct_res = sks.majority(&ct1, &ct2, &ct3);
+
+        let dec_majority = cks.decrypt(&ct_res);
+
+        assert_eq!(
+            expected_result, dec_majority,
+            "a: {ct1:?}, b: {ct2:?}, c: {ct3:?}"
+        );
+    }
+}
+
+fn test_full_adder(parameters: BooleanParameters) {
+    let keys = KEY_CACHE.get_from_param(parameters);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    for _ in 0..NB_TESTS {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+        let expected_sum = b1 ^ b2 ^ b3;
+        let expected_carry = (b1 && b2) || (b1 && b3) || (b2 && b3);
+
+        let ct1 = random_enum_encryption(cks, sks, b1);
+
+        let ct2 = random_enum_encryption(cks, sks, b2);
+
+        let ct3 = random_enum_encryption(cks, sks, b3);
+
+        let (ct_sum, ct_carry) = sks.full_adder(&ct1, &ct2, &ct3);
+
+        let dec_sum = cks.decrypt(&ct_sum);
+        let dec_carry = cks.decrypt(&ct_carry);
+
+        assert_eq!(
+            (expected_sum, expected_carry),
+            (dec_sum, dec_carry),
+            "a: {ct1:?}, b: {ct2:?}, carry: {ct3:?}"
+        );
+    }
+}
+
 fn test_nand_gate(parameters: BooleanParameters) {
     let keys = KEY_CACHE.get_from_param(parameters);
     let (cks, sks) = (keys.client_key(), keys.server_key());
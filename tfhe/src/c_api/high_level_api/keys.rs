@@ -47,6 +47,11 @@ impl_safe_deserialize_on_type!(CompactPublicKey);
 impl_safe_deserialize_on_type!(CompressedCompactPublicKey);
 impl_safe_deserialize_on_type!(CompressedServerKey);
 
+#[cfg(feature = "key-wrapping")]
+impl_key_wrapping_on_type!(ClientKey);
+#[cfg(feature = "key-wrapping")]
+impl_key_wrapping_on_type!(CompressedServerKey);
+
 #[no_mangle]
 pub unsafe extern "C" fn generate_keys(
     config: *mut super::config::Config,
@@ -401,6 +401,74 @@ macro_rules! impl_safe_deserialize_on_type {
 
 pub(crate) use impl_safe_deserialize_on_type;
 
+#[cfg(feature = "key-wrapping")]
+macro_rules! impl_key_wrapping_on_type {
+    ($wrapper_type:ty) => {
+        ::paste::paste! {
+            #[no_mangle]
+            /// Exports the key encrypted with a key derived from a passphrase.
+            ///
+            /// The encryption key is derived from the passphrase using Argon2id, and the key is
+            /// encrypted with XChaCha20-Poly1305.
+            ///
+            /// - `passphrase`: bytes of the passphrase
+            pub unsafe extern "C" fn [<$wrapper_type:snake _export_encrypted>](
+                sself: *const $wrapper_type,
+                passphrase: crate::c_api::buffer::DynamicBufferView,
+                result: *mut crate::c_api::buffer::DynamicBuffer,
+            ) -> ::std::os::raw::c_int {
+                crate::c_api::utils::catch_panic(|| {
+                    crate::c_api::utils::check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    let sself = crate::c_api::utils::get_ref_checked(sself).unwrap();
+
+                    let buffer = $crate::key_wrapping::WrappableKey::export_encrypted(
+                        &sself.0,
+                        passphrase.as_slice(),
+                    )
+                    .unwrap();
+
+                    *result = buffer.into();
+                })
+            }
+
+            #[no_mangle]
+            /// Imports a key exported by the `export_encrypted` function.
+            ///
+            /// - `passphrase`: bytes of the passphrase used for the export
+            /// - `result`: pointer where resulting imported key needs to be stored.
+            ///    * cannot be NULL
+            ///    * (*result) will point the imported key on success, else NULL
+            pub unsafe extern "C" fn [<$wrapper_type:snake _import_encrypted>](
+                buffer_view: crate::c_api::buffer::DynamicBufferView,
+                passphrase: crate::c_api::buffer::DynamicBufferView,
+                result: *mut *mut $wrapper_type,
+            ) -> ::std::os::raw::c_int {
+                crate::c_api::utils::catch_panic(|| {
+                    crate::c_api::utils::check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    // First fill the result with a null ptr so that if we fail and the return code is not
+                    // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+                    *result = std::ptr::null_mut();
+
+                    let inner = <_ as $crate::key_wrapping::WrappableKey>::import_encrypted(
+                        buffer_view.as_slice(),
+                        passphrase.as_slice(),
+                    )
+                    .unwrap();
+
+                    let heap_allocated_object = Box::new($wrapper_type(inner));
+
+                    *result = Box::into_raw(heap_allocated_object);
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "key-wrapping")]
+pub(crate) use impl_key_wrapping_on_type;
+
 macro_rules! impl_binary_fn_on_type {
     // More general binary fn case,
     // where the type of the left-hand side can be different
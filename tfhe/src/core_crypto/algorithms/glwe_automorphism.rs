@@ -0,0 +1,202 @@
+//! Module containing primitives pertaining to the application of ring automorphisms on
+//! [`GLWE ciphertexts`](`GlweCiphertext`), see [`GlweAutomorphismKey`] for the formal definition.
+
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Apply the ring automorphism $X \mapsto X^{k}$ to every polynomial of a
+/// [`GLWE ciphertext`](`GlweCiphertext`).
+///
+/// If the input is encrypted under the [`GLWE secret key`](`GlweSecretKey`) $\vec{S}$, the output
+/// encrypts the transformed plaintext under $\tau\_k(\vec{S})$, use
+/// [`apply_automorphism_glwe_ciphertext`] to get an output encrypted under $\vec{S}$.
+pub fn glwe_ciphertext_automorphism<Scalar, InputCont, OutputCont>(
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    automorphism_exponent: AutomorphismExponent,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input_glwe_ciphertext.glwe_size(),
+        output_glwe_ciphertext.glwe_size(),
+        "Mismatched GlweSize between input ({:?}) and output ({:?}).",
+        input_glwe_ciphertext.glwe_size(),
+        output_glwe_ciphertext.glwe_size(),
+    );
+    assert_eq!(
+        input_glwe_ciphertext.polynomial_size(),
+        output_glwe_ciphertext.polynomial_size(),
+        "Mismatched PolynomialSize between input ({:?}) and output ({:?}).",
+        input_glwe_ciphertext.polynomial_size(),
+        output_glwe_ciphertext.polynomial_size(),
+    );
+    assert_eq!(
+        input_glwe_ciphertext.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between input ({:?}) and output ({:?}).",
+        input_glwe_ciphertext.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus(),
+    );
+    assert!(
+        input_glwe_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+
+    for (mut output_polynomial, input_polynomial) in output_glwe_ciphertext
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(input_glwe_ciphertext.as_polynomial_list().iter())
+    {
+        polynomial_wrapping_automorphism(
+            &mut output_polynomial,
+            &input_polynomial,
+            automorphism_exponent,
+        );
+    }
+}
+
+/// Homomorphically apply the ring automorphism $X \mapsto X^{k}$ to the plaintext of a
+/// [`GLWE ciphertext`](`GlweCiphertext`) using a [`GlweAutomorphismKey`] for the exponent $k$.
+///
+/// # Formal Definition
+///
+/// See [`GLWE automorphism key`](`GlweAutomorphismKey#homomorphic-automorphism`).
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweAutomorphismKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(21);
+/// let decomp_level_count = DecompositionLevelCount(2);
+/// let automorphism_exponent = AutomorphismExponent(5);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let delta = 1 << 59;
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let automorphism_key = allocate_and_generate_new_glwe_automorphism_key(
+///     &glwe_secret_key,
+///     automorphism_exponent,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Encrypt the polynomial 3 + X
+/// let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// *plaintext_list.get_mut(0).0 = 3 * delta;
+/// *plaintext_list.get_mut(1).0 = delta;
+///
+/// let mut input_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut input_glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// apply_automorphism_glwe_ciphertext(&automorphism_key, &input_glwe, &mut output_glwe);
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &output_glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+/// let cleartext_list: Vec<_> = output_plaintext_list
+///     .iter()
+///     .map(|elt| decomposer.decode_plaintext(Plaintext(*elt.0)).0)
+///     .collect();
+///
+/// // We get 3 + X^5
+/// for (degree, cleartext) in cleartext_list.iter().enumerate() {
+///     let expected = match degree {
+///         0 => 3,
+///         5 => 1,
+///         _ => 0,
+///     };
+///     assert_eq!(*cleartext, expected);
+/// }
+/// ```
+pub fn apply_automorphism_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
+    automorphism_key: &GlweAutomorphismKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        automorphism_key.ciphertext_modulus(),
+        input_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between GlweAutomorphismKey ({:?}) \
+        and input GlweCiphertext ({:?}).",
+        automorphism_key.ciphertext_modulus(),
+        input_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    let mut permuted_glwe_ciphertext = GlweCiphertext::new(
+        Scalar::ZERO,
+        input_glwe_ciphertext.glwe_size(),
+        input_glwe_ciphertext.polynomial_size(),
+        input_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    glwe_ciphertext_automorphism(
+        input_glwe_ciphertext,
+        &mut permuted_glwe_ciphertext,
+        automorphism_key.automorphism_exponent(),
+    );
+
+    keyswitch_glwe_ciphertext(
+        &automorphism_key.as_glwe_keyswitch_key(),
+        &permuted_glwe_ciphertext,
+        output_glwe_ciphertext,
+    );
+}
@@ -0,0 +1,304 @@
+//! Module containing primitives pertaining to [`GLWE automorphism key
+//! generation`](`GlweAutomorphismKey`).
+
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_scalar_div_assign;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTermSlice,
+};
+use crate::core_crypto::commons::math::random::{DefaultRandomGenerator, Distribution, Uniform};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Return the [`GLWE secret key`](`GlweSecretKey`) $\tau\_k(\vec{S})$ obtained by applying the ring
+/// automorphism $X \mapsto X^{k}$ to every polynomial of the input key.
+fn allocate_and_apply_automorphism_glwe_secret_key<Scalar, KeyCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    automorphism_exponent: AutomorphismExponent,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+{
+    let mut permuted_polynomials = PolynomialList::new(
+        Scalar::ZERO,
+        glwe_secret_key.polynomial_size(),
+        PolynomialCount(glwe_secret_key.glwe_dimension().0),
+    );
+
+    for (mut output_polynomial, input_polynomial) in permuted_polynomials
+        .iter_mut()
+        .zip(glwe_secret_key.as_polynomial_list().iter())
+    {
+        polynomial_wrapping_automorphism(
+            &mut output_polynomial,
+            &input_polynomial,
+            automorphism_exponent,
+        );
+    }
+
+    GlweSecretKeyOwned::from_container(
+        permuted_polynomials.into_container(),
+        glwe_secret_key.polynomial_size(),
+    )
+}
+
+/// Fill a [`GLWE automorphism key`](`GlweAutomorphismKey`) with an actual automorphism key for the
+/// input [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`apply_automorphism_glwe_ciphertext`] for usage.
+pub fn generate_glwe_automorphism_key<Scalar, NoiseDistribution, KeyCont, AKCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    automorphism_key: &mut GlweAutomorphismKey<AKCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    AKCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let permuted_key = allocate_and_apply_automorphism_glwe_secret_key(
+        glwe_secret_key,
+        automorphism_key.automorphism_exponent(),
+    );
+
+    generate_glwe_keyswitch_key(
+        &permuted_key,
+        glwe_secret_key,
+        &mut automorphism_key.as_mut_glwe_keyswitch_key(),
+        noise_distribution,
+        generator,
+    );
+}
+
+/// Allocate a new [`GLWE automorphism key`](`GlweAutomorphismKey`) and fill it with an actual
+/// automorphism key for the input [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`apply_automorphism_glwe_ciphertext`] for usage.
+pub fn allocate_and_generate_new_glwe_automorphism_key<Scalar, NoiseDistribution, KeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    automorphism_exponent: AutomorphismExponent,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlweAutomorphismKeyOwned<Scalar>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_automorphism_key = GlweAutomorphismKeyOwned::new(
+        Scalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_secret_key.glwe_dimension(),
+        glwe_secret_key.polynomial_size(),
+        automorphism_exponent,
+        ciphertext_modulus,
+    );
+
+    generate_glwe_automorphism_key(
+        glwe_secret_key,
+        &mut new_automorphism_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_automorphism_key
+}
+
+/// Fill a [`seeded GLWE automorphism key`](`SeededGlweAutomorphismKey`) with an actual seeded
+/// automorphism key for the input [`GLWE secret key`](`GlweSecretKey`).
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for SeededGlweAutomorphismKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(21);
+/// let decomp_level_count = DecompositionLevelCount(2);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut seeded_automorphism_key = SeededGlweAutomorphismKey::new(
+///     0u64,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_dimension,
+///     polynomial_size,
+///     AutomorphismExponent(polynomial_size.0 + 1),
+///     seeder.seed().into(),
+///     ciphertext_modulus,
+/// );
+///
+/// generate_seeded_glwe_automorphism_key(
+///     &glwe_secret_key,
+///     &mut seeded_automorphism_key,
+///     glwe_noise_distribution,
+///     seeder,
+/// );
+///
+/// assert!(!seeded_automorphism_key.as_ref().iter().all(|&x| x == 0));
+///
+/// let automorphism_key = seeded_automorphism_key.decompress_into_glwe_automorphism_key();
+///
+/// assert!(!automorphism_key.as_ref().iter().all(|&x| x == 0));
+/// ```
+pub fn generate_seeded_glwe_automorphism_key<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    AKCont,
+    NoiseSeeder,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    automorphism_key: &mut SeededGlweAutomorphismKey<AKCont>,
+    noise_distribution: NoiseDistribution,
+    noise_seeder: &mut NoiseSeeder,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    AKCont: ContainerMut<Element = Scalar>,
+    // Maybe Sized allows to pass Box<dyn Seeder>.
+    NoiseSeeder: Seeder + ?Sized,
+{
+    assert!(
+        automorphism_key.glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "The destination SeededGlweAutomorphismKey GlweDimension is not equal \
+    to the GlweSecretKey GlweDimension. Destination: {:?}, key: {:?}",
+        automorphism_key.glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    assert!(
+        automorphism_key.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "The destination SeededGlweAutomorphismKey PolynomialSize is not equal \
+    to the GlweSecretKey PolynomialSize. Destination: {:?}, key: {:?}",
+        automorphism_key.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let decomp_base_log = automorphism_key.decomposition_base_log();
+    let decomp_level_count = automorphism_key.decomposition_level_count();
+    let polynomial_size = automorphism_key.polynomial_size();
+    let ciphertext_modulus = automorphism_key.ciphertext_modulus();
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    let permuted_key = allocate_and_apply_automorphism_glwe_secret_key(
+        glwe_secret_key,
+        automorphism_key.automorphism_exponent(),
+    );
+
+    // The plaintexts used to encrypt a key element will be stored in this buffer
+    let mut decomposition_plaintexts_buffer = PlaintextListOwned::new(
+        Scalar::ZERO,
+        PlaintextCount(decomp_level_count.0 * polynomial_size.0),
+    );
+
+    let mut generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(
+        automorphism_key.compression_seed().seed,
+        noise_seeder,
+    );
+
+    // Iterate over the permuted key polynomials and the destination automorphism_key memory
+    for (input_key_polynomial, mut automorphism_key_block) in permuted_key
+        .as_polynomial_list()
+        .iter()
+        .zip(automorphism_key.iter_mut())
+    {
+        // We fill the buffer with the powers of the key elements
+        for (level, mut messages) in (1..=decomp_level_count.0)
+            .map(DecompositionLevel)
+            .rev()
+            .zip(decomposition_plaintexts_buffer.chunks_exact_mut(polynomial_size.0))
+        {
+            // Here  we take the decomposition term from the native torus, bring it to the torus we
+            // are working with by dividing by the scaling factor and the encryption will take care
+            // of mapping that back to the native torus
+            DecompositionTermSlice::new(level, decomp_base_log, input_key_polynomial.as_ref())
+                .fill_slice_with_recomposition_summand(messages.as_mut());
+
+            slice_wrapping_scalar_div_assign(
+                messages.as_mut(),
+                ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
+            );
+        }
+
+        encrypt_seeded_glwe_ciphertext_list_with_pre_seeded_generator(
+            glwe_secret_key,
+            &mut automorphism_key_block,
+            &decomposition_plaintexts_buffer,
+            noise_distribution,
+            &mut generator,
+        );
+    }
+}
+
+/// Allocate a new [`seeded GLWE automorphism key`](`SeededGlweAutomorphismKey`) and fill it with
+/// an actual seeded automorphism key for the input [`GLWE secret key`](`GlweSecretKey`).
+#[allow(clippy::too_many_arguments)]
+pub fn allocate_and_generate_new_seeded_glwe_automorphism_key<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    NoiseSeeder,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    automorphism_exponent: AutomorphismExponent,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    noise_seeder: &mut NoiseSeeder,
+) -> SeededGlweAutomorphismKeyOwned<Scalar>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    // Maybe Sized allows to pass Box<dyn Seeder>.
+    NoiseSeeder: Seeder + ?Sized,
+{
+    let mut new_automorphism_key = SeededGlweAutomorphismKeyOwned::new(
+        Scalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_secret_key.glwe_dimension(),
+        glwe_secret_key.polynomial_size(),
+        automorphism_exponent,
+        noise_seeder.seed().into(),
+        ciphertext_modulus,
+    );
+
+    generate_seeded_glwe_automorphism_key(
+        glwe_secret_key,
+        &mut new_automorphism_key,
+        noise_distribution,
+        noise_seeder,
+    );
+
+    new_automorphism_key
+}
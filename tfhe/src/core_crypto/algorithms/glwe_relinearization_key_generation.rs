@@ -0,0 +1,129 @@
+//! Module containing primitives pertaining to [`GLWE relinearization key
+//! generation`](`GlweRelinearizationKey`).
+
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{Distribution, Uniform};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Return the [`GLWE secret key`](`GlweSecretKey`) made of the polynomials $-S\_i \cdot S\_j$ for
+/// $i \leq j$, in lexicographic order, which is the quadratic part of the key a tensor product
+/// output is encrypted under.
+fn allocate_and_compute_glwe_tensor_product_quadratic_secret_key<Scalar, KeyCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+{
+    let glwe_dimension = glwe_secret_key.glwe_dimension();
+    let polynomial_size = glwe_secret_key.polynomial_size();
+
+    let mut quadratic_polynomials = PolynomialList::new(
+        Scalar::ZERO,
+        polynomial_size,
+        PolynomialCount(glwe_relinearization_key_input_key_polynomial_count(
+            glwe_dimension,
+        )),
+    );
+
+    let secret_key_polynomials = glwe_secret_key.as_polynomial_list();
+    let mut quadratic_polynomials_iter = quadratic_polynomials.iter_mut();
+
+    for i in 0..glwe_dimension.0 {
+        for j in i..glwe_dimension.0 {
+            let mut output_polynomial = quadratic_polynomials_iter.next().unwrap();
+            polynomial_wrapping_sub_mul_assign(
+                &mut output_polynomial,
+                &secret_key_polynomials.get(i),
+                &secret_key_polynomials.get(j),
+            );
+        }
+    }
+
+    GlweSecretKeyOwned::from_container(quadratic_polynomials.into_container(), polynomial_size)
+}
+
+/// Fill a [`GLWE relinearization key`](`GlweRelinearizationKey`) with an actual relinearization
+/// key for the input [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`relinearize_glwe_tensor_product`] for usage.
+pub fn generate_glwe_relinearization_key<Scalar, NoiseDistribution, KeyCont, RLKCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    relinearization_key: &mut GlweRelinearizationKey<RLKCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    RLKCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        relinearization_key.glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "The destination GlweRelinearizationKey GlweDimension is not equal \
+    to the GlweSecretKey GlweDimension. Destination: {:?}, key: {:?}",
+        relinearization_key.glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    // The products of key polynomials are computed with wrapping arithmetic
+    assert!(
+        relinearization_key
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "GlweRelinearizationKey generation only supports power of 2 moduli, got {:?}",
+        relinearization_key.ciphertext_modulus()
+    );
+
+    let quadratic_key =
+        allocate_and_compute_glwe_tensor_product_quadratic_secret_key(glwe_secret_key);
+
+    generate_glwe_keyswitch_key(
+        &quadratic_key,
+        glwe_secret_key,
+        &mut relinearization_key.as_mut_glwe_keyswitch_key(),
+        noise_distribution,
+        generator,
+    );
+}
+
+/// Allocate a new [`GLWE relinearization key`](`GlweRelinearizationKey`) and fill it with an
+/// actual relinearization key for the input [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`relinearize_glwe_tensor_product`] for usage.
+pub fn allocate_and_generate_new_glwe_relinearization_key<Scalar, NoiseDistribution, KeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlweRelinearizationKeyOwned<Scalar>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_relinearization_key = GlweRelinearizationKeyOwned::new(
+        Scalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_secret_key.glwe_dimension(),
+        glwe_secret_key.polynomial_size(),
+        ciphertext_modulus,
+    );
+
+    generate_glwe_relinearization_key(
+        glwe_secret_key,
+        &mut new_relinearization_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_relinearization_key
+}
@@ -0,0 +1,589 @@
+//! Module containing primitives pertaining to the multiplication of
+//! [`GLWE ciphertexts`](`GlweCiphertext`) via a tensor product followed by a relinearization, see
+//! [`GlweRelinearizationKey`] for the formal definition.
+//!
+//! Messages are expected to be encoded as $\Delta \cdot m$ in the MSBs of the plaintexts without a
+//! padding bit. Given a scaling $\Delta$ which is a power of 2, the tensor product of two
+//! ciphertexts encrypting $\Delta \cdot m$ and $\Delta \cdot m'$ is rescaled by $\Delta$ to encrypt
+//! $\Delta \cdot m \cdot m'$, the product being computed in the plaintext space
+//! $\mathbb{Z}\_{q / \Delta}\[X\]/(X^N+1)$.
+
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft128::math::fft::Fft128;
+use aligned_vec::avec;
+use dyn_stack::{GlobalPodBuffer, PodStack};
+use tfhe_fft::fft128::f128;
+
+/// Return the [`GlweDimension`] of the output of the tensor product of two
+/// [`GLWE ciphertexts`](`GlweCiphertext`) with the given [`GlweDimension`].
+///
+/// The output has $k$ linear mask polynomials followed by $\frac{k(k+1)}{2}$ quadratic ones.
+pub fn glwe_tensor_product_output_glwe_dimension(glwe_dimension: GlweDimension) -> GlweDimension {
+    GlweDimension(
+        glwe_dimension.0 + glwe_relinearization_key_input_key_polynomial_count(glwe_dimension),
+    )
+}
+
+/// Return, for each polynomial of the output of a tensor product in order, the pairs of indices
+/// of the input polynomials whose products sum to it. Index $k$ is the body of a ciphertext.
+fn glwe_tensor_product_terms(glwe_dimension: GlweDimension) -> Vec<Vec<(usize, usize)>> {
+    let k = glwe_dimension.0;
+
+    // Linear terms T_i = A_i * B' + B * A'_i
+    let linear_terms = (0..k).map(|i| vec![(i, k), (k, i)]);
+    // Quadratic terms T_ij = A_i * A'_j + A_j * A'_i for i < j and T_ii = A_i * A'_i
+    let quadratic_terms = (0..k).flat_map(|i| {
+        (i..k).map(move |j| {
+            if i == j {
+                vec![(i, i)]
+            } else {
+                vec![(i, j), (j, i)]
+            }
+        })
+    });
+    // Constant term T_0 = B * B'
+    let constant_term = std::iter::once(vec![(k, k)]);
+
+    linear_terms
+        .chain(quadratic_terms)
+        .chain(constant_term)
+        .collect()
+}
+
+/// Return the value of the input interpreted as a signed integer, sign extended to 128 bits.
+fn sign_extend_to_u128<Scalar: UnsignedInteger>(value: Scalar) -> u128 {
+    let extended: u128 = value.cast_into();
+    if (value >> (Scalar::BITS - 1)) == Scalar::ONE {
+        extended | (u128::MAX << Scalar::BITS)
+    } else {
+        extended
+    }
+}
+
+/// Lift the polynomials of a [`GLWE ciphertext`](`GlweCiphertext`) to their centered
+/// representatives stored on 128 bits.
+fn lift_glwe_ciphertext_to_u128<Scalar, InputCont>(
+    glwe_ciphertext: &GlweCiphertext<InputCont>,
+) -> PolynomialListOwned<u128>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    PolynomialListOwned::from_container(
+        glwe_ciphertext
+            .as_ref()
+            .iter()
+            .copied()
+            .map(sign_extend_to_u128)
+            .collect(),
+        glwe_ciphertext.polynomial_size(),
+    )
+}
+
+/// Divide the 128 bits integer coefficients of the input by $2^{\log\_2(\Delta)}$ with rounding
+/// and write them reduced modulo $2^{\mathsf{BITS}}$ in the output.
+fn rescale_polynomial_from_u128<Scalar, OutputCont, InputCont>(
+    output: &mut Polynomial<OutputCont>,
+    input: &Polynomial<InputCont>,
+    scaling_log: DeltaLog,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = u128>,
+{
+    let rounding = if scaling_log.0 == 0 {
+        0
+    } else {
+        1u128 << (scaling_log.0 - 1)
+    };
+
+    for (output_coeff, &input_coeff) in output.iter_mut().zip(input.iter()) {
+        *output_coeff = Scalar::cast_from(input_coeff.wrapping_add(rounding) >> scaling_log.0);
+    }
+}
+
+fn assert_glwe_tensor_product_compatible<Scalar, LhsCont, RhsCont, OutputCont>(
+    lhs: &GlweCiphertext<LhsCont>,
+    rhs: &GlweCiphertext<RhsCont>,
+    output: &GlweCiphertext<OutputCont>,
+    scaling_log: DeltaLog,
+) where
+    Scalar: UnsignedInteger,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: Container<Element = Scalar>,
+{
+    assert!(
+        Scalar::BITS <= 64,
+        "The tensor product is computed on 128 bits integers and requires Scalar::BITS <= 64, \
+        got {}.",
+        Scalar::BITS
+    );
+    assert!(
+        scaling_log.0 <= Scalar::BITS,
+        "The scaling {scaling_log:?} cannot exceed the Scalar::BITS ({}).",
+        Scalar::BITS
+    );
+    assert_eq!(
+        lhs.glwe_size(),
+        rhs.glwe_size(),
+        "Mismatched GlweSize between lhs ({:?}) and rhs ({:?}).",
+        lhs.glwe_size(),
+        rhs.glwe_size(),
+    );
+    assert_eq!(
+        lhs.polynomial_size(),
+        rhs.polynomial_size(),
+        "Mismatched PolynomialSize between lhs ({:?}) and rhs ({:?}).",
+        lhs.polynomial_size(),
+        rhs.polynomial_size(),
+    );
+    assert_eq!(
+        lhs.polynomial_size(),
+        output.polynomial_size(),
+        "Mismatched PolynomialSize between inputs ({:?}) and output ({:?}).",
+        lhs.polynomial_size(),
+        output.polynomial_size(),
+    );
+    assert_eq!(
+        output.glwe_size().to_glwe_dimension(),
+        glwe_tensor_product_output_glwe_dimension(lhs.glwe_size().to_glwe_dimension()),
+        "The output GlweDimension ({:?}) must be equal to the tensor product GlweDimension \
+        ({:?}) for an input GlweDimension of {:?}.",
+        output.glwe_size().to_glwe_dimension(),
+        glwe_tensor_product_output_glwe_dimension(lhs.glwe_size().to_glwe_dimension()),
+        lhs.glwe_size().to_glwe_dimension(),
+    );
+    assert_eq!(
+        lhs.ciphertext_modulus(),
+        rhs.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between lhs ({:?}) and rhs ({:?}).",
+        lhs.ciphertext_modulus(),
+        rhs.ciphertext_modulus(),
+    );
+    assert_eq!(
+        lhs.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between inputs ({:?}) and output ({:?}).",
+        lhs.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+    );
+    assert!(
+        lhs.ciphertext_modulus().is_native_modulus(),
+        "This operation currently only supports the native modulus"
+    );
+}
+
+/// Compute the tensor product of two [`GLWE ciphertexts`](`GlweCiphertext`) and rescale it by
+/// $\Delta = 2^{\log\_2(\Delta)}$.
+///
+/// Polynomial products are computed exactly on the centered representatives of the inputs modulo
+/// $2^{128}$ using [`polynomial_karatsuba_wrapping_mul`]. The output is encrypted under the
+/// tensored key described in [`GlweRelinearizationKey`], its [`GlweDimension`] is given by
+/// [`glwe_tensor_product_output_glwe_dimension`]. Use [`relinearize_glwe_tensor_product`] to get
+/// back a ciphertext under the original key.
+///
+/// See [`relinearize_glwe_tensor_product`] for usage.
+pub fn glwe_tensor_product_and_rescale<Scalar, LhsCont, RhsCont, OutputCont>(
+    lhs: &GlweCiphertext<LhsCont>,
+    rhs: &GlweCiphertext<RhsCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    scaling_log: DeltaLog,
+) where
+    Scalar: UnsignedInteger,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_glwe_tensor_product_compatible(lhs, rhs, output, scaling_log);
+
+    let polynomial_size = lhs.polynomial_size();
+    let lifted_lhs = lift_glwe_ciphertext_to_u128(lhs);
+    let lifted_rhs = lift_glwe_ciphertext_to_u128(rhs);
+
+    let mut product = Polynomial::new(0u128, polynomial_size);
+    let mut accumulator = Polynomial::new(0u128, polynomial_size);
+
+    for (mut output_polynomial, terms) in
+        output
+            .as_mut_polynomial_list()
+            .iter_mut()
+            .zip(glwe_tensor_product_terms(
+                lhs.glwe_size().to_glwe_dimension(),
+            ))
+    {
+        accumulator.as_mut().fill(0);
+
+        for (lhs_index, rhs_index) in terms {
+            polynomial_karatsuba_wrapping_mul(
+                &mut product,
+                &lifted_lhs.get(lhs_index),
+                &lifted_rhs.get(rhs_index),
+            );
+            polynomial_wrapping_add_assign(&mut accumulator, &product);
+        }
+
+        rescale_polynomial_from_u128(&mut output_polynomial, &accumulator, scaling_log);
+    }
+}
+
+/// Compute the tensor product of two [`GLWE ciphertexts`](`GlweCiphertext`) and rescale it by
+/// $\Delta = 2^{\log\_2(\Delta)}$, using the 128 bits FFT for polynomial products.
+///
+/// This is the FFT counterpart of [`glwe_tensor_product_and_rescale`]. Products are approximated
+/// with an error of a few dozen bits out of 128, which is negligible once rescaled by $\Delta$
+/// for practical scalings.
+///
+/// See [`relinearize_glwe_tensor_product`] for usage.
+pub fn glwe_tensor_product_and_rescale_f128<Scalar, LhsCont, RhsCont, OutputCont>(
+    lhs: &GlweCiphertext<LhsCont>,
+    rhs: &GlweCiphertext<RhsCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    scaling_log: DeltaLog,
+) where
+    Scalar: UnsignedTorus,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_glwe_tensor_product_compatible(lhs, rhs, output, scaling_log);
+
+    let polynomial_size = lhs.polynomial_size();
+    let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
+    let input_fourier_size = lhs.glwe_size().0 * fourier_polynomial_size;
+
+    let fft = Fft128::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = GlobalPodBuffer::new(fft.backward_scratch().unwrap());
+    let stack = PodStack::new(&mut buffers);
+
+    // The lhs is seen as an integer and the rhs as a torus element, so that the backward FFT
+    // directly yields the product modulo 2^128
+    let lifted_lhs = lift_glwe_ciphertext_to_u128(lhs);
+    let lifted_rhs = lift_glwe_ciphertext_to_u128(rhs);
+
+    let mut lhs_re0 = avec![0.0f64; input_fourier_size].into_boxed_slice();
+    let mut lhs_re1 = avec![0.0f64; input_fourier_size].into_boxed_slice();
+    let mut lhs_im0 = avec![0.0f64; input_fourier_size].into_boxed_slice();
+    let mut lhs_im1 = avec![0.0f64; input_fourier_size].into_boxed_slice();
+    let mut rhs_re0 = avec![0.0f64; input_fourier_size].into_boxed_slice();
+    let mut rhs_re1 = avec![0.0f64; input_fourier_size].into_boxed_slice();
+    let mut rhs_im0 = avec![0.0f64; input_fourier_size].into_boxed_slice();
+    let mut rhs_im1 = avec![0.0f64; input_fourier_size].into_boxed_slice();
+
+    for (
+        lhs_polynomial,
+        rhs_polynomial,
+        lhs_re0,
+        lhs_re1,
+        lhs_im0,
+        lhs_im1,
+        rhs_re0,
+        rhs_re1,
+        rhs_im0,
+        rhs_im1,
+    ) in izip!(
+        lifted_lhs.iter(),
+        lifted_rhs.iter(),
+        lhs_re0.chunks_exact_mut(fourier_polynomial_size),
+        lhs_re1.chunks_exact_mut(fourier_polynomial_size),
+        lhs_im0.chunks_exact_mut(fourier_polynomial_size),
+        lhs_im1.chunks_exact_mut(fourier_polynomial_size),
+        rhs_re0.chunks_exact_mut(fourier_polynomial_size),
+        rhs_re1.chunks_exact_mut(fourier_polynomial_size),
+        rhs_im0.chunks_exact_mut(fourier_polynomial_size),
+        rhs_im1.chunks_exact_mut(fourier_polynomial_size),
+    ) {
+        fft.forward_as_integer(lhs_re0, lhs_re1, lhs_im0, lhs_im1, lhs_polynomial.as_ref());
+        fft.forward_as_torus(rhs_re0, rhs_re1, rhs_im0, rhs_im1, rhs_polynomial.as_ref());
+    }
+
+    let mut acc_re0 = avec![0.0f64; fourier_polynomial_size].into_boxed_slice();
+    let mut acc_re1 = avec![0.0f64; fourier_polynomial_size].into_boxed_slice();
+    let mut acc_im0 = avec![0.0f64; fourier_polynomial_size].into_boxed_slice();
+    let mut acc_im1 = avec![0.0f64; fourier_polynomial_size].into_boxed_slice();
+
+    let mut accumulator = Polynomial::new(0u128, polynomial_size);
+
+    for (mut output_polynomial, terms) in
+        output
+            .as_mut_polynomial_list()
+            .iter_mut()
+            .zip(glwe_tensor_product_terms(
+                lhs.glwe_size().to_glwe_dimension(),
+            ))
+    {
+        acc_re0.fill(0.0);
+        acc_re1.fill(0.0);
+        acc_im0.fill(0.0);
+        acc_im1.fill(0.0);
+
+        for (lhs_index, rhs_index) in terms {
+            let lhs_range =
+                lhs_index * fourier_polynomial_size..(lhs_index + 1) * fourier_polynomial_size;
+            let rhs_range =
+                rhs_index * fourier_polynomial_size..(rhs_index + 1) * fourier_polynomial_size;
+
+            for (
+                acc_re0,
+                acc_re1,
+                acc_im0,
+                acc_im1,
+                lhs_re0,
+                lhs_re1,
+                lhs_im0,
+                lhs_im1,
+                rhs_re0,
+                rhs_re1,
+                rhs_im0,
+                rhs_im1,
+            ) in izip!(
+                acc_re0.iter_mut(),
+                acc_re1.iter_mut(),
+                acc_im0.iter_mut(),
+                acc_im1.iter_mut(),
+                &lhs_re0[lhs_range.clone()],
+                &lhs_re1[lhs_range.clone()],
+                &lhs_im0[lhs_range.clone()],
+                &lhs_im1[lhs_range],
+                &rhs_re0[rhs_range.clone()],
+                &rhs_re1[rhs_range.clone()],
+                &rhs_im0[rhs_range.clone()],
+                &rhs_im1[rhs_range],
+            ) {
+                let lhs_re = f128(*lhs_re0, *lhs_re1);
+                let lhs_im = f128(*lhs_im0, *lhs_im1);
+                let rhs_re = f128(*rhs_re0, *rhs_re1);
+                let rhs_im = f128(*rhs_im0, *rhs_im1);
+
+                let mut acc_re = f128(*acc_re0, *acc_re1);
+                let mut acc_im = f128(*acc_im0, *acc_im1);
+
+                acc_re += lhs_re * rhs_re - lhs_im * rhs_im;
+                acc_im += lhs_im * rhs_re + lhs_re * rhs_im;
+
+                *acc_re0 = acc_re.0;
+                *acc_re1 = acc_re.1;
+                *acc_im0 = acc_im.0;
+                *acc_im1 = acc_im.1;
+            }
+        }
+
+        fft.backward_as_torus(
+            accumulator.as_mut(),
+            &acc_re0,
+            &acc_re1,
+            &acc_im0,
+            &acc_im1,
+            stack,
+        );
+
+        rescale_polynomial_from_u128(&mut output_polynomial, &accumulator, scaling_log);
+    }
+}
+
+/// Relinearize the output of [`glwe_tensor_product_and_rescale`] (or
+/// [`glwe_tensor_product_and_rescale_f128`]) using a [`GlweRelinearizationKey`], yielding a
+/// [`GLWE ciphertext`](`GlweCiphertext`) encrypted under the original
+/// [`GLWE secret key`](`GlweSecretKey`).
+///
+/// # Formal Definition
+///
+/// See [`GLWE relinearization key`](`GlweRelinearizationKey#relinearization`).
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweRelinearizationKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(15);
+/// let decomp_level_count = DecompositionLevelCount(3);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// // 4 bits messages without padding
+/// let delta_log = DeltaLog(60);
+/// let delta = 1u64 << delta_log.0;
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let relinearization_key = allocate_and_generate_new_glwe_relinearization_key(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Encrypt the polynomials 3 + X and 2 + 5X
+/// let mut lhs_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// *lhs_plaintext_list.get_mut(0).0 = 3 * delta;
+/// *lhs_plaintext_list.get_mut(1).0 = delta;
+/// let mut rhs_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// *rhs_plaintext_list.get_mut(0).0 = 2 * delta;
+/// *rhs_plaintext_list.get_mut(1).0 = 5 * delta;
+///
+/// let mut lhs = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+/// let mut rhs = lhs.clone();
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut lhs,
+///     &lhs_plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut rhs,
+///     &rhs_plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut tensor_product = GlweCiphertext::new(
+///     0u64,
+///     glwe_tensor_product_output_glwe_dimension(glwe_dimension).to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// glwe_tensor_product_and_rescale(&lhs, &rhs, &mut tensor_product, delta_log);
+///
+/// let mut product = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// relinearize_glwe_tensor_product(&relinearization_key, &tensor_product, &mut product);
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &product, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+/// let cleartext_list: Vec<_> = output_plaintext_list
+///     .iter()
+///     .map(|elt| decomposer.decode_plaintext(Plaintext(*elt.0)).0 % 16)
+///     .collect();
+///
+/// // We get (3 + X) * (2 + 5X) = 6 + 17X + 5X^2 = 6 + X + 5X^2 mod 16
+/// for (degree, cleartext) in cleartext_list.iter().enumerate() {
+///     let expected = match degree {
+///         0 => 6,
+///         1 => 1,
+///         2 => 5,
+///         _ => 0,
+///     };
+///     assert_eq!(*cleartext, expected);
+/// }
+/// ```
+pub fn relinearize_glwe_tensor_product<Scalar, KeyCont, InputCont, OutputCont>(
+    relinearization_key: &GlweRelinearizationKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let glwe_dimension = relinearization_key.glwe_dimension();
+    let polynomial_size = relinearization_key.polynomial_size();
+
+    assert_eq!(
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        glwe_tensor_product_output_glwe_dimension(glwe_dimension),
+        "The input GlweDimension ({:?}) must be equal to the tensor product GlweDimension ({:?}) \
+        for the GlweRelinearizationKey GlweDimension {:?}.",
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        glwe_tensor_product_output_glwe_dimension(glwe_dimension),
+        glwe_dimension,
+    );
+    assert_eq!(
+        output_glwe_ciphertext.glwe_size(),
+        relinearization_key.glwe_size(),
+        "Mismatched output GlweSize. \
+        GlweRelinearizationKey GlweSize: {:?}, output GlweCiphertext GlweSize {:?}.",
+        relinearization_key.glwe_size(),
+        output_glwe_ciphertext.glwe_size(),
+    );
+    assert!(
+        relinearization_key
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+    assert_eq!(
+        input_glwe_ciphertext.ciphertext_modulus(),
+        relinearization_key.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        GlweRelinearizationKey CiphertextModulus: {:?}, input GlweCiphertext CiphertextModulus \
+        {:?}.",
+        relinearization_key.ciphertext_modulus(),
+        input_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    // The quadratic mask polynomials followed by the body form a GLWE ciphertext under the
+    // quadratic part of the tensored key, which the relinearization key switches to the original
+    // key
+    let (linear_part, quadratic_part) = input_glwe_ciphertext
+        .as_ref()
+        .split_at(glwe_dimension.0 * polynomial_size.0);
+
+    let quadratic_glwe_ciphertext = GlweCiphertext::from_container(
+        quadratic_part,
+        polynomial_size,
+        input_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    keyswitch_glwe_ciphertext(
+        &relinearization_key.as_glwe_keyswitch_key(),
+        &quadratic_glwe_ciphertext,
+        output_glwe_ciphertext,
+    );
+
+    let linear_part = PolynomialList::from_container(linear_part, polynomial_size);
+
+    for (mut output_mask_polynomial, linear_polynomial) in output_glwe_ciphertext
+        .get_mut_mask()
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(linear_part.iter())
+    {
+        polynomial_wrapping_add_assign(&mut output_mask_polynomial, &linear_polynomial);
+    }
+}
@@ -3,8 +3,10 @@
 //! like the Fourier domain.
 
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::ntt::ntt128::Ntt128;
 use crate::core_crypto::commons::math::ntt::ntt64::Ntt64;
 use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::utils::izip;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft128::math::fft::Fft128;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::fill_with_forward_fourier_scratch;
@@ -447,3 +449,114 @@ pub fn par_convert_standard_lwe_bootstrap_key_to_ntt64<InputCont, OutputCont>(
             }
         });
 }
+
+/// Convert an [`LWE bootstrap key`](`LweBootstrapKey`) with standard coefficients to the NTT
+/// domain using the exact 128 bits NTT.
+///
+/// The obtained coefficients embed the NTT normalization.
+///
+/// See [`programmable_bootstrap_ntt128_lwe_ciphertext`](`crate::core_crypto::algorithms::programmable_bootstrap_ntt128_lwe_ciphertext`) for usage.
+pub fn convert_standard_lwe_bootstrap_key_to_ntt128<InputCont, OutputCont>(
+    input_bsk: &LweBootstrapKey<InputCont>,
+    output_bsk: &mut Ntt128LweBootstrapKey<OutputCont>,
+) where
+    InputCont: Container<Element = u128>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    assert_ntt128_conversion_compatible(input_bsk, output_bsk);
+
+    let ntt = Ntt128::new(input_bsk.polynomial_size());
+    let ntt = ntt.as_view();
+
+    for (input_poly, output_poly) in izip!(
+        input_bsk.as_polynomial_list().iter(),
+        output_bsk
+            .as_mut_view()
+            .into_container()
+            .chunks_exact_mut(ntt.ntt_polynomial_len())
+    ) {
+        ntt.forward_normalized(output_poly, input_poly.as_ref());
+    }
+}
+
+/// Parallel variant of [`convert_standard_lwe_bootstrap_key_to_ntt128`].
+pub fn par_convert_standard_lwe_bootstrap_key_to_ntt128<InputCont, OutputCont>(
+    input_bsk: &LweBootstrapKey<InputCont>,
+    output_bsk: &mut Ntt128LweBootstrapKey<OutputCont>,
+) where
+    InputCont: Container<Element = u128> + Sync,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    assert_ntt128_conversion_compatible(input_bsk, output_bsk);
+
+    let ntt = Ntt128::new(input_bsk.polynomial_size());
+    let ntt = ntt.as_view();
+
+    input_bsk
+        .as_polynomial_list()
+        .par_iter()
+        .zip(
+            output_bsk
+                .as_mut_view()
+                .into_container()
+                .par_chunks_exact_mut(ntt.ntt_polynomial_len()),
+        )
+        .for_each(|(input_poly, output_poly)| {
+            ntt.forward_normalized(output_poly, input_poly.as_ref());
+        });
+}
+
+fn assert_ntt128_conversion_compatible<InputCont, OutputCont>(
+    input_bsk: &LweBootstrapKey<InputCont>,
+    output_bsk: &Ntt128LweBootstrapKey<OutputCont>,
+) where
+    InputCont: Container<Element = u128>,
+    OutputCont: Container<Element = u64>,
+{
+    assert_eq!(
+        input_bsk.polynomial_size(),
+        output_bsk.polynomial_size(),
+        "Mismatched PolynomialSize between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.polynomial_size(),
+        output_bsk.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_bsk.glwe_size(),
+        output_bsk.glwe_size(),
+        "Mismatched GlweSize between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.glwe_size(),
+        output_bsk.glwe_size(),
+    );
+
+    assert_eq!(
+        input_bsk.decomposition_base_log(),
+        output_bsk.decomposition_base_log(),
+        "Mismatched DecompositionBaseLog between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.decomposition_base_log(),
+        output_bsk.decomposition_base_log(),
+    );
+
+    assert_eq!(
+        input_bsk.decomposition_level_count(),
+        output_bsk.decomposition_level_count(),
+        "Mismatched DecompositionLevelCount between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.decomposition_level_count(),
+        output_bsk.decomposition_level_count(),
+    );
+
+    assert_eq!(
+        input_bsk.input_lwe_dimension(),
+        output_bsk.input_lwe_dimension(),
+        "Mismatched input LweDimension between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.input_lwe_dimension(),
+        output_bsk.input_lwe_dimension(),
+    );
+
+    assert!(
+        input_bsk
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "The 128 bits NTT only supports power of 2 ciphertext moduli"
+    );
+}
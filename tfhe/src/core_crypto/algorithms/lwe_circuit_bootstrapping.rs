@@ -0,0 +1,378 @@
+//! Module containing primitives pertaining to the circuit bootstrapping of an
+//! [`LWE ciphertext`](`LweCiphertext`) into a [`GGSW ciphertext`](`GgswCiphertext`) in the Fourier
+//! domain.
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use tfhe_fft::c64;
+
+/// Circuit bootstrap an input [`LWE ciphertext`](`LweCiphertext`) encrypting a single bit into an
+/// output [`GGSW ciphertext`](`GgswCiphertext`) in the Fourier domain, usable as a selector for
+/// [`cmux_assign`] or [`add_external_product_assign`].
+///
+/// The input is expected to use the classic PBS encoding: the bit is encoded at `delta_log` and
+/// there is a bit of padding above the message, it can therefore be the output of a PBS computed
+/// with the same [`fourier LWE bootstrap key`](`FourierLweBootstrapKey`). The decomposition
+/// parameters of the output GGSW ciphertext are the ones of `output_ggsw`.
+///
+/// Contrary to the circuit bootstrapping used by the WoP-PBS, a single blind rotation is computed:
+/// the look-up table encodes one function per level of the output GGSW ciphertext and all of them
+/// are evaluated at once (the so called "many-LUT" technique). This requires the half torus to
+/// hold at least twice as many values as the output level count, i.e.
+/// $2 \cdot \ell \leq 2^{\log\_2(q) - 1 - \mathsf{delta\\_log}} \leq N$.
+///
+/// The `pfpksk_list` is expected to be generated with
+/// [`allocate_and_generate_new_circuit_bootstrap_lwe_pfpksk_list`] (or its parallel variant) from
+/// the output LWE secret key of the bootstrap key to the GLWE secret key of the output GGSW
+/// ciphertext.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.000007069849454709433), 0.0);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// // The GGSW ciphertext built by the circuit bootstrapping is as noisy as the output of the PBS,
+/// // we use a more precise decomposition than for a regular PBS to keep the CMux noise low
+/// let pbs_base_log = DecompositionBaseLog(15);
+/// let pbs_level = DecompositionLevelCount(2);
+/// let pfpksk_base_log = DecompositionBaseLog(15);
+/// let pfpksk_level = DecompositionLevelCount(2);
+/// let cbs_base_log = DecompositionBaseLog(6);
+/// let cbs_level = DecompositionLevelCount(4);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+/// let big_lwe_sk = glwe_sk.as_lwe_secret_key();
+///
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+/// convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+/// drop(std_bootstrapping_key);
+///
+/// let pfpksk_list = par_allocate_and_generate_new_circuit_bootstrap_lwe_pfpksk_list(
+///     &big_lwe_sk,
+///     &glwe_sk,
+///     pfpksk_base_log,
+///     pfpksk_level,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // 4 bits of message and a bit of padding, the selector bit is the LSB of the message space
+/// let message_modulus = 1u64 << 4;
+/// let delta_log = DeltaLog(59);
+/// let delta = 1u64 << delta_log.0;
+///
+/// // Two GLWE ciphertexts we want to select from with our encrypted bit
+/// let mut glwe_0 = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+/// let mut glwe_1 = glwe_0.clone();
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_sk,
+///     &mut glwe_0,
+///     &PlaintextList::new(3 * delta, PlaintextCount(polynomial_size.0)),
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+/// encrypt_glwe_ciphertext(
+///     &glwe_sk,
+///     &mut glwe_1,
+///     &PlaintextList::new(12 * delta, PlaintextCount(polynomial_size.0)),
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let selector_bit = 1u64;
+///
+/// let lwe_in = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     Plaintext(selector_bit * delta),
+///     lwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut ggsw = FourierGgswCiphertext::new(
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     cbs_base_log,
+///     cbs_level,
+/// );
+///
+/// circuit_bootstrap_lwe_ciphertext_to_ggsw(
+///     &lwe_in,
+///     &mut ggsw,
+///     delta_log,
+///     &fourier_bsk,
+///     &pfpksk_list,
+/// );
+///
+/// // glwe_0 receives the selected ciphertext
+/// cmux_assign(&mut glwe_0, &mut glwe_1, &ggsw);
+///
+/// let mut decrypted = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// decrypt_glwe_ciphertext(&glwe_sk, &glwe_0, &mut decrypted);
+///
+/// // Round to the 5 MSB, 1 bit of padding plus our 4 bits of message
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+/// for plaintext in decrypted.iter() {
+///     let decoded = decomposer.closest_representable(*plaintext.0) / delta % message_modulus;
+///     assert_eq!(decoded, 12);
+/// }
+/// ```
+pub fn circuit_bootstrap_lwe_ciphertext_to_ggsw<
+    Scalar,
+    InputCont,
+    OutputCont,
+    BskCont,
+    PfpkskCont,
+>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_ggsw_ciphertext: &mut FourierGgswCiphertext<OutputCont>,
+    delta_log: DeltaLog,
+    fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+    pfpksk_list: &LwePrivateFunctionalPackingKeyswitchKeyList<PfpkskCont>,
+) where
+    Scalar: UnsignedTorus + CastInto<usize> + CastFrom<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+    BskCont: Container<Element = c64>,
+    PfpkskCont: Container<Element = Scalar>,
+{
+    let ciphertext_modulus = input_lwe_ciphertext.ciphertext_modulus();
+
+    assert!(
+        ciphertext_modulus.is_native_modulus(),
+        "This operation currently only supports native moduli"
+    );
+    assert_eq!(ciphertext_modulus, pfpksk_list.ciphertext_modulus());
+
+    let msed_input = lwe_ciphertext_modulus_switch(
+        input_lwe_ciphertext.as_view(),
+        fourier_bsk
+            .polynomial_size()
+            .to_blind_rotation_input_modulus_log(),
+    );
+
+    circuit_bootstrap_modulus_switched_lwe_ciphertext_to_ggsw(
+        &msed_input,
+        output_ggsw_ciphertext,
+        delta_log,
+        fourier_bsk,
+        pfpksk_list,
+    );
+}
+
+/// Circuit bootstrap an input LWE ciphertext that has already been modulus switched to the
+/// blind rotation input modulus into an output [`GGSW ciphertext`](`GgswCiphertext`) in the
+/// Fourier domain.
+///
+/// This allows to use a modulus switch reducing the noise of the input (e.g. the drift
+/// technique) before the blind rotation, see [`circuit_bootstrap_lwe_ciphertext_to_ggsw`] for the
+/// requirements on the parameters and an example.
+pub fn circuit_bootstrap_modulus_switched_lwe_ciphertext_to_ggsw<
+    Scalar,
+    OutputCont,
+    BskCont,
+    PfpkskCont,
+>(
+    msed_input: &impl ModulusSwitchedLweCiphertext<usize>,
+    output_ggsw_ciphertext: &mut FourierGgswCiphertext<OutputCont>,
+    delta_log: DeltaLog,
+    fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+    pfpksk_list: &LwePrivateFunctionalPackingKeyswitchKeyList<PfpkskCont>,
+) where
+    Scalar: UnsignedTorus + CastInto<usize> + CastFrom<usize>,
+    OutputCont: ContainerMut<Element = c64>,
+    BskCont: Container<Element = c64>,
+    PfpkskCont: Container<Element = Scalar>,
+{
+    let ciphertext_modulus = pfpksk_list.ciphertext_modulus();
+
+    assert!(
+        ciphertext_modulus.is_native_modulus(),
+        "This operation currently only supports native moduli"
+    );
+
+    assert_eq!(
+        msed_input.lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+        "Mismatched LweDimension between the input LweCiphertext and the bootstrap key input.",
+    );
+    assert_eq!(
+        msed_input.log_modulus(),
+        fourier_bsk
+            .polynomial_size()
+            .to_blind_rotation_input_modulus_log(),
+        "The input LweCiphertext must be modulus switched to the blind rotation input modulus.",
+    );
+    assert_eq!(
+        fourier_bsk.output_lwe_dimension(),
+        pfpksk_list.input_key_lwe_dimension(),
+        "Mismatched LweDimension between the bootstrap key output and the \
+        LwePrivateFunctionalPackingKeyswitchKeyList input.",
+    );
+    assert_eq!(
+        output_ggsw_ciphertext.glwe_size(),
+        pfpksk_list.output_key_glwe_dimension().to_glwe_size(),
+        "Mismatched GlweSize between the output FourierGgswCiphertext and the \
+        LwePrivateFunctionalPackingKeyswitchKeyList output.",
+    );
+    assert_eq!(
+        output_ggsw_ciphertext.polynomial_size(),
+        pfpksk_list.output_polynomial_size(),
+        "Mismatched PolynomialSize between the output FourierGgswCiphertext and the \
+        LwePrivateFunctionalPackingKeyswitchKeyList output.",
+    );
+    assert_eq!(
+        output_ggsw_ciphertext.glwe_size().0,
+        pfpksk_list.lwe_pfpksk_count().0,
+        "The LwePrivateFunctionalPackingKeyswitchKeyList must contain one key per row of a \
+        GGSW level matrix.",
+    );
+
+    let cbs_base_log = output_ggsw_ciphertext.decomposition_base_log();
+    let cbs_level_count = output_ggsw_ciphertext.decomposition_level_count();
+    let bsk_polynomial_size = fourier_bsk.polynomial_size();
+
+    assert!(
+        cbs_base_log.0 * cbs_level_count.0 <= Scalar::BITS,
+        "The output GGSW decomposition cannot represent more than {} bits, got base_log: {:?}, \
+        level_count: {:?}",
+        Scalar::BITS,
+        cbs_base_log,
+        cbs_level_count,
+    );
+    assert!(
+        delta_log.0 < Scalar::BITS,
+        "DeltaLog must be smaller than the bit width of the Scalar type, got {delta_log:?}",
+    );
+
+    // Number of values that can be encoded in the half torus with the input encoding, each one of
+    // them gets a box of the look-up table
+    let half_torus_value_count = 1usize << (Scalar::BITS - 1 - delta_log.0);
+
+    assert!(
+        2 * cbs_level_count.0 <= half_torus_value_count,
+        "The input encoding can only hold {half_torus_value_count} values in the half torus, \
+        which is not enough to evaluate the {} functions required by the circuit bootstrapping",
+        cbs_level_count.0
+    );
+    assert!(
+        half_torus_value_count <= bsk_polynomial_size.0,
+        "The input encoding holds {half_torus_value_count} values in the half torus, \
+        which does not fit in a look-up table with {bsk_polynomial_size:?}",
+    );
+
+    let box_size = bsk_polynomial_size.0 / half_torus_value_count;
+
+    // The value x = 2 * i + b of the look-up table holds b * q / B^(i + 1), the encryption of the
+    // input bit b for the level i + 1 of the output GGSW ciphertext is therefore sampled at
+    // 2 * i * box_size after the blind rotation
+    let mut accumulator = generate_programmable_bootstrap_glwe_lut(
+        bsk_polynomial_size,
+        fourier_bsk.glwe_size(),
+        half_torus_value_count,
+        ciphertext_modulus,
+        Scalar::ONE,
+        |x: Scalar| {
+            let x: usize = x.cast_into();
+            let level = x / 2 + 1;
+            if x % 2 == 1 && level <= cbs_level_count.0 {
+                Scalar::ONE << (Scalar::BITS - cbs_base_log.0 * level)
+            } else {
+                Scalar::ZERO
+            }
+        },
+    );
+
+    blind_rotate_assign(msed_input, &mut accumulator, fourier_bsk);
+
+    let mut lwe_level_buffer = LweCiphertext::new(
+        Scalar::ZERO,
+        fourier_bsk.output_lwe_dimension().to_lwe_size(),
+        ciphertext_modulus,
+    );
+
+    let mut standard_ggsw = GgswCiphertext::new(
+        Scalar::ZERO,
+        output_ggsw_ciphertext.glwe_size(),
+        output_ggsw_ciphertext.polynomial_size(),
+        cbs_base_log,
+        cbs_level_count,
+        ciphertext_modulus,
+    );
+
+    // Level matrices are stored from the highest level to the lowest one
+    for (matrix_index, mut level_matrix) in standard_ggsw.iter_mut().enumerate() {
+        let level = cbs_level_count.0 - matrix_index;
+
+        extract_lwe_sample_from_glwe_ciphertext(
+            &accumulator,
+            &mut lwe_level_buffer,
+            MonomialDegree(2 * (level - 1) * box_size),
+        );
+
+        for (pfpksk, mut glwe_row) in pfpksk_list
+            .iter()
+            .zip(level_matrix.as_mut_glwe_list().iter_mut())
+        {
+            private_functional_keyswitch_lwe_ciphertext_into_glwe_ciphertext(
+                &pfpksk,
+                &mut glwe_row,
+                &lwe_level_buffer,
+            );
+        }
+    }
+
+    convert_standard_ggsw_ciphertext_to_fourier(&standard_ggsw, output_ggsw_ciphertext);
+}
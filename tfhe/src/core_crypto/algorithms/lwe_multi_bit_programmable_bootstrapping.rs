@@ -1,8 +1,14 @@
 use crate::core_crypto::algorithms::extract_lwe_sample_from_glwe_ciphertext;
+use crate::core_crypto::algorithms::lwe_programmable_bootstrapping::ntt128_pbs::{
+    add_external_product_ntt128_assign, ntt128_add_external_product_assign_scratch,
+};
 use crate::core_crypto::algorithms::polynomial_algorithms::*;
 use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::math::ntt::ntt128::{
+    assert_ntt128_exact_external_product, Ntt128, Ntt128View,
+};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -2713,3 +2719,528 @@ pub fn multi_bit_programmable_bootstrap_f128_lwe_ciphertext<
 
     extract_lwe_sample_from_glwe_ciphertext(&local_accumulator, output, MonomialDegree(0));
 }
+
+pub fn prepare_multi_bit_ntt128_ggsw_mem_optimized<
+    GgswBufferCont,
+    GgswGroupCont,
+    UnitCont,
+    PolyCont,
+>(
+    ntt_ggsw_buffer: &mut Ntt128GgswCiphertext<GgswBufferCont>,
+    ggsw_group: &[Ntt128GgswCiphertext<GgswGroupCont>],
+    switched_degrees: impl Iterator<Item = usize>,
+    unit_polynomial: &Polynomial<UnitCont>,
+    std_a_monomial: &mut Polynomial<PolyCont>,
+    ntt_a_monomial: &mut [u64],
+    ntt: Ntt128View<'_>,
+) where
+    GgswBufferCont: ContainerMut<Element = u64>,
+    GgswGroupCont: Container<Element = u64>,
+    UnitCont: Container<Element = u128>,
+    PolyCont: ContainerMut<Element = u128>,
+{
+    let ntt_poly_len = ntt.ntt_polynomial_len();
+    let mut ggsw_group_iter = ggsw_group.iter();
+
+    // Keygen guarantees the first term is a constant term of the polynomial, no
+    // polynomial multiplication required
+    let ggsw_a_none = ggsw_group_iter.next().unwrap();
+
+    ntt_ggsw_buffer
+        .as_mut()
+        .copy_from_slice(ggsw_a_none.as_ref());
+
+    for (ntt_ggsw, switched_degree) in ggsw_group_iter.zip_eq(switched_degrees) {
+        polynomial_wrapping_monic_monomial_mul(
+            std_a_monomial,
+            unit_polynomial,
+            MonomialDegree(switched_degree),
+        );
+
+        // The key is stored normalized, the monomial must therefore not be normalized
+        ntt.forward(ntt_a_monomial, std_a_monomial.as_ref());
+
+        for (dst_poly, src_poly) in ntt_ggsw_buffer
+            .as_mut()
+            .chunks_exact_mut(ntt_poly_len)
+            .zip(ntt_ggsw.as_ref().chunks_exact(ntt_poly_len))
+        {
+            ntt.mul_accumulate(dst_poly, src_poly, ntt_a_monomial);
+        }
+    }
+}
+
+pub fn multi_bit_ntt128_deterministic_blind_rotate_assign<OutputCont, KeyCont>(
+    switched_modulus_input: &impl MultiBitModulusSwitchedLweCiphertext,
+    accumulator: &mut GlweCiphertext<OutputCont>,
+    multi_bit_bsk: &Ntt128LweMultiBitBootstrapKey<KeyCont>,
+    thread_count: ThreadCount,
+) where
+    OutputCont: ContainerMut<Element = u128>,
+    KeyCont: Container<Element = u64> + Sync + Split,
+{
+    assert_eq!(
+        switched_modulus_input.lwe_dimension(),
+        multi_bit_bsk.input_lwe_dimension(),
+        "Mismatched input LweDimension. LweCiphertext input LweDimension {:?}. \
+        Ntt128LweMultiBitBootstrapKey input LweDimension {:?}.",
+        switched_modulus_input.lwe_dimension(),
+        multi_bit_bsk.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        accumulator.glwe_size(),
+        multi_bit_bsk.glwe_size(),
+        "Mismatched GlweSize. Accumulator GlweSize {:?}. \
+        Ntt128LweMultiBitBootstrapKey GlweSize {:?}.",
+        accumulator.glwe_size(),
+        multi_bit_bsk.glwe_size(),
+    );
+
+    assert_eq!(
+        accumulator.polynomial_size(),
+        multi_bit_bsk.polynomial_size(),
+        "Mismatched PolynomialSize. Accumulator PolynomialSize {:?}. \
+        Ntt128LweMultiBitBootstrapKey PolynomialSize {:?}.",
+        accumulator.polynomial_size(),
+        multi_bit_bsk.polynomial_size(),
+    );
+
+    assert!(
+        thread_count.0 != 0,
+        "Got thread_count == 0, this is not supported"
+    );
+
+    assert!(
+        accumulator
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Multi bit PBS does not support non power of two ciphertext modulus"
+    );
+
+    let grouping_factor = multi_bit_bsk.grouping_factor();
+    let ggsw_per_multi_bit_element = grouping_factor.ggsw_per_multi_bit_element();
+
+    // Each prepared GGSW is the sum of ggsw_per_multi_bit_element rotated key GGSWs
+    assert_ntt128_exact_external_product(
+        multi_bit_bsk.glwe_size(),
+        multi_bit_bsk.polynomial_size(),
+        multi_bit_bsk.decomposition_base_log(),
+        multi_bit_bsk.decomposition_level_count(),
+        ggsw_per_multi_bit_element.0,
+    );
+
+    // No way to chunk the result of ggsw_iter at the moment
+    let ggsw_vec: Vec<_> = multi_bit_bsk.as_view().into_ggsw_iter().collect();
+
+    let input_lwe_dimension = multi_bit_bsk.input_lwe_dimension();
+
+    assert_eq!(input_lwe_dimension.0 % grouping_factor.0, 0);
+    let max_work_index = input_lwe_dimension.0 / grouping_factor.0;
+
+    accumulator
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .for_each(|mut poly| {
+            polynomial_wrapping_monic_monomial_div_assign(
+                &mut poly,
+                MonomialDegree(switched_modulus_input.switched_modulus_input_lwe_body()),
+            );
+        });
+
+    let ntt_multi_bit_ggsw_buffers: Vec<_> = (0..thread_count.0)
+        .map(|_| {
+            (
+                Mutex::new(false),
+                Condvar::new(),
+                Mutex::new(Ntt128GgswCiphertext::new(
+                    multi_bit_bsk.glwe_size(),
+                    multi_bit_bsk.polynomial_size(),
+                    multi_bit_bsk.decomposition_base_log(),
+                    multi_bit_bsk.decomposition_level_count(),
+                )),
+            )
+        })
+        .collect();
+    let ntt = Ntt128::new(multi_bit_bsk.polynomial_size());
+    let ntt = ntt.as_view();
+
+    let produce_multi_bit_ntt_ggsw = |thread_id| {
+        let mut unit_polynomial = Polynomial::new(0u128, multi_bit_bsk.polynomial_size());
+        unit_polynomial.as_mut()[0] = 1;
+        let mut std_a_monomial = unit_polynomial.clone();
+        let mut ntt_a_monomial = vec![0u64; ntt.ntt_polynomial_len()];
+
+        let dest_idx = thread_id;
+
+        #[allow(clippy::type_complexity)]
+        let (ready_for_consumer_lock, condvar, ntt_ggsw_buffer): &(
+            Mutex<bool>,
+            Condvar,
+            Mutex<Ntt128GgswCiphertext<ABox<[u64]>>>,
+        ) = &ntt_multi_bit_ggsw_buffers[dest_idx];
+
+        for work_index in (0..max_work_index).skip(thread_id).step_by(thread_count.0) {
+            let switched_degrees =
+                switched_modulus_input.switched_modulus_input_mask_per_group(work_index);
+
+            let ggsw_group = &ggsw_vec[work_index * ggsw_per_multi_bit_element.0
+                ..(work_index + 1) * ggsw_per_multi_bit_element.0];
+
+            let mut ready_for_consumer = ready_for_consumer_lock.lock().unwrap();
+
+            // Wait while the buffer is not ready for processing and wait on the condvar
+            // to get notified when we can start processing again
+            while *ready_for_consumer {
+                ready_for_consumer = condvar.wait(ready_for_consumer).unwrap();
+            }
+
+            let mut ntt_ggsw_buffer = ntt_ggsw_buffer.lock().unwrap();
+
+            prepare_multi_bit_ntt128_ggsw_mem_optimized(
+                &mut ntt_ggsw_buffer,
+                ggsw_group,
+                switched_degrees,
+                &unit_polynomial,
+                &mut std_a_monomial,
+                &mut ntt_a_monomial,
+                ntt,
+            );
+
+            // Drop the lock before we wake other threads
+            drop(ntt_ggsw_buffer);
+
+            *ready_for_consumer = true;
+
+            // Wake threads waiting on the condvar
+            condvar.notify_all();
+        }
+    };
+
+    thread::scope(|s| {
+        // false positive as the mapping function has side effects (thread spawning)
+        #[allow(clippy::needless_collect)]
+        let threads: Vec<_> = (0..thread_count.0)
+            .map(|idx| s.spawn(move || produce_multi_bit_ntt_ggsw(idx)))
+            .collect();
+
+        // We initialize ct0 for the successive external products
+        let ct0 = accumulator;
+        let mut ct1 = GlweCiphertext::new(
+            0u128,
+            ct0.glwe_size(),
+            ct0.polynomial_size(),
+            ct0.ciphertext_modulus(),
+        );
+        let ct1 = &mut ct1;
+
+        let mut buffers = ComputationBuffers::new();
+
+        buffers.resize(
+            ntt128_add_external_product_assign_scratch(
+                multi_bit_bsk.glwe_size(),
+                multi_bit_bsk.polynomial_size(),
+                ntt,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+
+        let mut src_idx = 1usize;
+
+        for (ready_lock, condvar, multi_bit_ntt_ggsw) in ntt_multi_bit_ggsw_buffers
+            .iter()
+            .cycle()
+            .take(multi_bit_bsk.multi_bit_input_lwe_dimension().0)
+        {
+            src_idx ^= 1;
+
+            let (src_ct, mut dst_ct) = if src_idx == 0 {
+                (ct0.as_view(), ct1.as_mut_view())
+            } else {
+                (ct1.as_view(), ct0.as_mut_view())
+            };
+
+            dst_ct.as_mut().fill(0);
+
+            let mut ready = ready_lock.lock().unwrap();
+
+            while !*ready {
+                ready = condvar.wait(ready).unwrap();
+            }
+
+            let multi_bit_ntt_ggsw = multi_bit_ntt_ggsw.lock().unwrap();
+
+            add_external_product_ntt128_assign(
+                dst_ct,
+                multi_bit_ntt_ggsw.as_view(),
+                &src_ct,
+                ntt,
+                buffers.stack(),
+            );
+
+            *ready = false;
+
+            // Wake a single producer thread sleeping on the condvar (only one will get to work
+            // anyways)
+            condvar.notify_one();
+        }
+
+        if src_idx == 0 {
+            ct0.as_mut().copy_from_slice(ct1.as_ref());
+        }
+
+        // Products are exact in the NTT domain, the LSBs of power of 2 moduli < native modulus
+        // stay empty and no rounding is required here
+
+        threads.into_iter().for_each(|t| t.join().unwrap());
+    });
+}
+
+pub fn multi_bit_ntt128_blind_rotate_assign<InputScalar, InputCont, OutputCont, KeyCont>(
+    input: &LweCiphertext<InputCont>,
+    accumulator: &mut GlweCiphertext<OutputCont>,
+    multi_bit_bsk: &Ntt128LweMultiBitBootstrapKey<KeyCont>,
+    thread_count: ThreadCount,
+    deterministic_execution: bool,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    InputScalar: UnsignedTorus + CastInto<usize> + CastFrom<usize>,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = u128>,
+    KeyCont: Container<Element = u64> + Sync + Split,
+{
+    assert_eq!(
+        input.lwe_size().to_lwe_dimension(),
+        multi_bit_bsk.input_lwe_dimension(),
+        "Mismatched input LweDimension. LweCiphertext input LweDimension {:?}. \
+        Ntt128LweMultiBitBootstrapKey input LweDimension {:?}.",
+        input.lwe_size().to_lwe_dimension(),
+        multi_bit_bsk.input_lwe_dimension(),
+    );
+
+    let grouping_factor = multi_bit_bsk.grouping_factor();
+
+    let lut_poly_size = accumulator.polynomial_size();
+
+    let multi_bitmodulus_switched_ct = StandardMultiBitModulusSwitchedCt {
+        input: input.as_view(),
+        grouping_factor,
+        log_modulus: lut_poly_size.to_blind_rotation_input_modulus_log(),
+    };
+
+    // The NTT external products are exact, the deterministic implementation is always used
+    let _ = deterministic_execution;
+
+    multi_bit_ntt128_deterministic_blind_rotate_assign(
+        &multi_bitmodulus_switched_ct,
+        accumulator,
+        multi_bit_bsk,
+        thread_count,
+    )
+}
+
+/// Perform a programmable bootstrap with given an input [`LWE ciphertext`](`LweCiphertext`), a
+/// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) with 128 bits coefficients and
+/// an [`LWE multi-bit bootstrap key`](`LweMultiBitBootstrapKey`) in the 128 bits NTT domain, see
+/// [`Ntt128LweMultiBitBootstrapKey`]. The result is written in the provided output
+/// [`LWE ciphertext`](`LweCiphertext`).
+///
+/// The polynomial products are computed exactly, see
+/// [`programmable_bootstrap_ntt128_lwe_ciphertext`] for the classic PBS counterpart.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define the parameters for a 4 bits message able to hold the doubled 2 bits message
+/// let small_lwe_dimension = LweDimension(744);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_noise_distribution = Gaussian::from_dispersion_parameter(
+///     StandardDev(0.000007069849454709433 * 0.000007069849454709433),
+///     0.0,
+/// );
+/// let glwe_noise_distribution = Gaussian::from_dispersion_parameter(
+///     StandardDev(0.00000000000000029403601535432533 * 0.00000000000000029403601535432533),
+///     0.0,
+/// );
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let grouping_factor = LweBskGroupingFactor(2);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// // Generate an LweSecretKey with binary coefficients
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+///
+/// // Generate a GlweSecretKey with binary coefficients
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+///
+/// // Create a copy of the GlweSecretKey re-interpreted as an LweSecretKey
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let mut bsk = LweMultiBitBootstrapKey::new(
+///     0u128,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     pbs_base_log,
+///     pbs_level,
+///     small_lwe_dimension,
+///     grouping_factor,
+///     ciphertext_modulus,
+/// );
+///
+/// par_generate_lwe_multi_bit_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     &mut bsk,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut multi_bit_bsk = Ntt128LweMultiBitBootstrapKey::new(
+///     bsk.input_lwe_dimension(),
+///     bsk.glwe_size(),
+///     bsk.polynomial_size(),
+///     bsk.decomposition_base_log(),
+///     bsk.decomposition_level_count(),
+///     bsk.grouping_factor(),
+/// );
+///
+/// par_convert_standard_lwe_multi_bit_bootstrap_key_to_ntt128(&bsk, &mut multi_bit_bsk);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u128 << 4;
+///
+/// // Our input message
+/// let input_message = 3u128;
+///
+/// // Delta used to encode 4 bits of message + a bit of padding on u128
+/// let delta = (1_u128 << 127) / message_modulus;
+///
+/// // Apply our encoding
+/// let plaintext = Plaintext(input_message * delta);
+///
+/// // Allocate a new LweCiphertext and encrypt our plaintext
+/// let lwe_ciphertext_in: LweCiphertextOwned<u128> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     plaintext,
+///     lwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Generate the accumulator for our multiplication by 2 using a simple closure
+/// let accumulator: GlweCiphertextOwned<u128> = generate_programmable_bootstrap_glwe_lut(
+///     polynomial_size,
+///     glwe_dimension.to_glwe_size(),
+///     message_modulus as usize,
+///     ciphertext_modulus,
+///     delta,
+///     |x: u128| 2 * x,
+/// );
+///
+/// // Allocate the LweCiphertext to store the result of the PBS
+/// let mut pbs_multiplication_ct = LweCiphertext::new(
+///     0u128,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+/// multi_bit_programmable_bootstrap_ntt128_lwe_ciphertext(
+///     &lwe_ciphertext_in,
+///     &mut pbs_multiplication_ct,
+///     &accumulator,
+///     &multi_bit_bsk,
+///     ThreadCount(2),
+///     true,
+/// );
+///
+/// // Decrypt the PBS multiplication result
+/// let pbs_multiplication_plaintext: Plaintext<u128> =
+///     decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
+///
+/// // Round the 5 MSB, 1 bit of padding plus our 4 bits of message, and remove our encoding
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+/// let pbs_multiplication_result: u128 =
+///     signed_decomposer.closest_representable(pbs_multiplication_plaintext.0) / delta;
+///
+/// assert_eq!(6, pbs_multiplication_result);
+/// ```
+pub fn multi_bit_programmable_bootstrap_ntt128_lwe_ciphertext<
+    InputScalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    multi_bit_bsk: &Ntt128LweMultiBitBootstrapKey<KeyCont>,
+    thread_count: ThreadCount,
+    deterministic_execution: bool,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    InputScalar: UnsignedTorus + CastInto<usize> + CastFrom<usize>,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = u128>,
+    AccCont: Container<Element = u128>,
+    KeyCont: Container<Element = u64> + Sync,
+{
+    assert_eq!(
+        output.lwe_size().to_lwe_dimension(),
+        multi_bit_bsk.output_lwe_dimension(),
+        "Mismatched output LweDimension. LweCiphertext output LweDimension {:?}. \
+        Ntt128LweMultiBitBootstrapKey output LweDimension {:?}.",
+        output.lwe_size().to_lwe_dimension(),
+        multi_bit_bsk.output_lwe_dimension(),
+    );
+
+    assert_eq!(
+        output.ciphertext_modulus(),
+        accumulator.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between output ({:?}) and accumulator ({:?})",
+        output.ciphertext_modulus(),
+        accumulator.ciphertext_modulus(),
+    );
+
+    let mut local_accumulator = GlweCiphertext::new(
+        0u128,
+        accumulator.glwe_size(),
+        accumulator.polynomial_size(),
+        accumulator.ciphertext_modulus(),
+    );
+    local_accumulator
+        .as_mut()
+        .copy_from_slice(accumulator.as_ref());
+
+    multi_bit_ntt128_blind_rotate_assign(
+        input,
+        &mut local_accumulator,
+        &multi_bit_bsk.as_view(),
+        thread_count,
+        deterministic_execution,
+    );
+
+    extract_lwe_sample_from_glwe_ciphertext(&local_accumulator, output, MonomialDegree(0));
+}
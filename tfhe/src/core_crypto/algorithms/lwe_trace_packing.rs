@@ -0,0 +1,418 @@
+//! Module containing primitives pertaining to the packing of [`LWE ciphertexts`](`LweCiphertext`)
+//! into a [`GLWE ciphertext`](`GlweCiphertext`) using homomorphic traces.
+//!
+//! Contrary to the [`LWE packing keyswitch`](`LwePackingKeyswitchKey`), the input ciphertexts must
+//! already be encrypted under the [`LWE secret key`](`LweSecretKey`) obtained by flattening the
+//! output [`GLWE secret key`](`GlweSecretKey`). The packing only uses
+//! [`GLWE automorphism keys`](`GlweAutomorphismKey`) and its output noise does not depend on the
+//! [`LweDimension`] of the inputs.
+//!
+//! The algorithm is the ring packing from Chen, Dai, Kim and Song "Efficient Homomorphic
+//! Conversion Between (Ring) LWE Ciphertexts", see <https://eprint.iacr.org/2020/015>.
+
+use crate::core_crypto::algorithms::misc::divide_round;
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{Distribution, Uniform};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Return the [`AutomorphismExponent`]s of the [`GLWE automorphism keys`](`GlweAutomorphismKey`)
+/// required by [`trace_pack_lwe_ciphertext_list_into_glwe_ciphertext`] and
+/// [`glwe_ciphertext_trace_assign`] for a given [`PolynomialSize`].
+///
+/// These are the exponents $2^j + 1$ for $1 \le j \le \log\_2(N)$.
+pub fn trace_packing_automorphism_exponents(
+    polynomial_size: PolynomialSize,
+) -> Vec<AutomorphismExponent> {
+    assert!(
+        polynomial_size.0.is_power_of_two(),
+        "Trace packing requires a power of 2 PolynomialSize, got {polynomial_size:?}"
+    );
+
+    (1..=polynomial_size.log2().0)
+        .map(|j| AutomorphismExponent((1 << j) + 1))
+        .collect()
+}
+
+/// Allocate and generate the [`GLWE automorphism keys`](`GlweAutomorphismKey`) required by
+/// [`trace_pack_lwe_ciphertext_list_into_glwe_ciphertext`] and [`glwe_ciphertext_trace_assign`]
+/// for the input [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`trace_pack_lwe_ciphertext_list_into_glwe_ciphertext`] for usage.
+pub fn allocate_and_generate_new_trace_packing_automorphism_keys<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    Gen,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> Vec<GlweAutomorphismKeyOwned<Scalar>>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    trace_packing_automorphism_exponents(glwe_secret_key.polynomial_size())
+        .into_iter()
+        .map(|automorphism_exponent| {
+            allocate_and_generate_new_glwe_automorphism_key(
+                glwe_secret_key,
+                automorphism_exponent,
+                decomp_base_log,
+                decomp_level_count,
+                noise_distribution,
+                ciphertext_modulus,
+                generator,
+            )
+        })
+        .collect()
+}
+
+/// Return the distance between the coefficients holding two consecutive input messages in the
+/// output of [`trace_pack_lwe_ciphertext_list_into_glwe_ciphertext`].
+///
+/// The message of the i-th input ciphertext is found in the coefficient of degree
+/// `i * stride`, with `stride = N / lwe_count.next_power_of_two()`.
+pub fn lwe_trace_packing_coefficient_stride(
+    polynomial_size: PolynomialSize,
+    lwe_count: LweCiphertextCount,
+) -> usize {
+    assert!(
+        lwe_count.0 > 0 && lwe_count.0 <= polynomial_size.0,
+        "The number of packed ciphertexts must be in [1, {}], got {}",
+        polynomial_size.0,
+        lwe_count.0
+    );
+
+    polynomial_size.0 / lwe_count.0.next_power_of_two()
+}
+
+fn find_automorphism_key<Scalar, KeyCont>(
+    automorphism_keys: &[GlweAutomorphismKey<KeyCont>],
+    automorphism_exponent: AutomorphismExponent,
+) -> &GlweAutomorphismKey<KeyCont>
+where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+{
+    automorphism_keys
+        .iter()
+        .find(|key| key.automorphism_exponent() == automorphism_exponent)
+        .unwrap_or_else(|| {
+            panic!("Missing GlweAutomorphismKey for exponent {automorphism_exponent:?}")
+        })
+}
+
+/// Compute $\mathsf{CT} + \tau\_{2^j+1}(\mathsf{CT})$ for all $j$ in
+/// $\left]\mathsf{log\\_subring\\_dimension}, \log\_2(N)\right]$, this is the trace from
+/// $\mathbb{Z}\[X\]/(X^N+1)$ to its subring generated by
+/// $X^{N/2^{\mathsf{log\\_subring\\_dimension}}}$.
+fn glwe_ciphertext_partial_trace_assign<Scalar, KeyCont, OutputCont>(
+    automorphism_keys: &[GlweAutomorphismKey<KeyCont>],
+    glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    log_subring_dimension: usize,
+) where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let mut buffer = GlweCiphertext::new(
+        Scalar::ZERO,
+        glwe_ciphertext.glwe_size(),
+        glwe_ciphertext.polynomial_size(),
+        glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    for automorphism_exponent in
+        trace_packing_automorphism_exponents(glwe_ciphertext.polynomial_size())
+            .into_iter()
+            .skip(log_subring_dimension)
+    {
+        let automorphism_key = find_automorphism_key(automorphism_keys, automorphism_exponent);
+        apply_automorphism_glwe_ciphertext(automorphism_key, glwe_ciphertext, &mut buffer);
+        glwe_ciphertext_add_assign(glwe_ciphertext, &buffer);
+    }
+}
+
+/// Homomorphically compute the trace of the plaintext of a [`GLWE ciphertext`](`GlweCiphertext`)
+/// in place.
+///
+/// The trace maps a plaintext $M(X) = \sum\_{i=0}^{N-1} m\_i X^i$ to the constant polynomial
+/// $N \cdot m\_0$, i.e. it cancels all non constant coefficients. The required
+/// [`GLWE automorphism keys`](`GlweAutomorphismKey`) are those with the exponents returned by
+/// [`trace_packing_automorphism_exponents`].
+///
+/// # Panics
+///
+/// This function panics if one of the required automorphism keys is missing.
+pub fn glwe_ciphertext_trace_assign<Scalar, KeyCont, OutputCont>(
+    automorphism_keys: &[GlweAutomorphismKey<KeyCont>],
+    glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    glwe_ciphertext_partial_trace_assign(automorphism_keys, glwe_ciphertext, 0);
+}
+
+/// Write the input [`LWE ciphertext`](`LweCiphertext`), with all its coefficients divided by $N$,
+/// as a [`GLWE ciphertext`](`GlweCiphertext`) whose constant coefficient holds the input message.
+///
+/// The rounding error of the division is cancelled by the trace which multiplies by $N$ again.
+fn lwe_ciphertext_into_scaled_glwe_ciphertext<Scalar, InputCont, OutputCont>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = output_glwe_ciphertext.polynomial_size();
+    let ciphertext_modulus = output_glwe_ciphertext.ciphertext_modulus();
+    let scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+    let divisor = scaling << polynomial_size.log2().0;
+    let scale = |value: Scalar| divide_round(value, divisor).wrapping_mul(scaling);
+
+    output_glwe_ciphertext.as_mut().fill(Scalar::ZERO);
+
+    let (input_mask, input_body) = input_lwe_ciphertext.get_mask_and_body();
+    let (mut output_mask, mut output_body) = output_glwe_ciphertext.get_mut_mask_and_body();
+
+    output_body.as_mut()[0] = scale(*input_body.data);
+
+    // A GLWE sample extraction on the constant coefficient reads a_0 = A_0 and a_j = -A_{N - j},
+    // we do the inverse mapping here
+    for (input_mask_chunk, mut output_mask_polynomial) in input_mask
+        .as_ref()
+        .chunks_exact(polynomial_size.0)
+        .zip(output_mask.as_mut_polynomial_list().iter_mut())
+    {
+        output_mask_polynomial[0] = scale(input_mask_chunk[0]);
+        for (j, &mask_element) in input_mask_chunk.iter().enumerate().skip(1) {
+            output_mask_polynomial[polynomial_size.0 - j] = scale(mask_element).wrapping_neg();
+        }
+    }
+}
+
+/// Pack the messages of a list of [`LWE ciphertexts`](`LweCiphertext`) in a single
+/// [`GLWE ciphertext`](`GlweCiphertext`) using homomorphic traces.
+///
+/// The input ciphertexts must be encrypted under the [`LWE secret key`](`LweSecretKey`) obtained
+/// by flattening the [`GLWE secret key`](`GlweSecretKey`) of the automorphism keys (see
+/// [`GlweSecretKey::as_lwe_secret_key`]), for example the output of a sample extraction. The
+/// message of the i-th input is put in the coefficient of degree `i * stride`, with `stride`
+/// given by [`lwe_trace_packing_coefficient_stride`], all other coefficients are zero.
+///
+/// The required [`GLWE automorphism keys`](`GlweAutomorphismKey`) are those with the exponents
+/// returned by [`trace_packing_automorphism_exponents`], they can be generated with
+/// [`allocate_and_generate_new_trace_packing_automorphism_keys`].
+///
+/// # Formal Definition
+///
+/// With $n = 2^\ell$ inputs, each input is first turned into a GLWE ciphertext $\mathsf{CT}\_i$
+/// of $\frac{1}{N} \cdot m\_i + \cdots$ then the ciphertexts are merged pairwise, for each level
+/// $1 \le l \le \ell$:
+///
+/// $$\mathsf{CT} = \left(\mathsf{CT}\_{even} + X^{N/2^l} \cdot \mathsf{CT}\_{odd}\right) +
+/// \tau\_{2^l+1}\left(\mathsf{CT}\_{even} - X^{N/2^l} \cdot \mathsf{CT}\_{odd}\right)$$
+///
+/// finally the trace from $\mathbb{Z}\[X\]/(X^N+1)$ to its subring generated by $X^{N/n}$ clears
+/// the remaining coefficients and removes the $\frac{1}{N}$ factor.
+///
+/// # Panics
+///
+/// This function panics if the ciphertext modulus is not a power of 2, if there are more input
+/// ciphertexts than the [`PolynomialSize`] or if one of the required automorphism keys is missing.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(21);
+/// let decomp_level_count = DecompositionLevelCount(2);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let delta = 1 << 59;
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+///
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let automorphism_keys = allocate_and_generate_new_trace_packing_automorphism_keys(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Encrypt some messages under the flattened GLWE secret key
+/// let messages = [1u64, 2, 3, 4, 5];
+/// let plaintext_list =
+///     PlaintextList::from_container(messages.iter().map(|m| m * delta).collect::<Vec<_>>());
+///
+/// let mut lwe_list = LweCiphertextList::new(
+///     0u64,
+///     glwe_dimension
+///         .to_equivalent_lwe_dimension(polynomial_size)
+///         .to_lwe_size(),
+///     LweCiphertextCount(messages.len()),
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_lwe_ciphertext_list(
+///     &glwe_secret_key.as_lwe_secret_key(),
+///     &mut lwe_list,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// trace_pack_lwe_ciphertext_list_into_glwe_ciphertext(
+///     &automorphism_keys,
+///     &lwe_list,
+///     &mut output_glwe,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &output_glwe, &mut output_plaintext_list);
+///
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+/// let stride =
+///     lwe_trace_packing_coefficient_stride(polynomial_size, lwe_list.lwe_ciphertext_count());
+///
+/// for (degree, plaintext) in output_plaintext_list.iter().enumerate() {
+///     let decoded = decomposer.decode_plaintext(Plaintext(*plaintext.0)).0;
+///     let expected = if degree % stride == 0 {
+///         messages.get(degree / stride).copied().unwrap_or(0)
+///     } else {
+///         0
+///     };
+///     assert_eq!(decoded, expected);
+/// }
+/// ```
+pub fn trace_pack_lwe_ciphertext_list_into_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
+    automorphism_keys: &[GlweAutomorphismKey<KeyCont>],
+    input_lwe_ciphertext_list: &LweCiphertextList<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let glwe_size = output_glwe_ciphertext.glwe_size();
+    let polynomial_size = output_glwe_ciphertext.polynomial_size();
+    let ciphertext_modulus = output_glwe_ciphertext.ciphertext_modulus();
+
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Trace packing currently only supports power of 2 moduli"
+    );
+    assert_eq!(
+        input_lwe_ciphertext_list.ciphertext_modulus(),
+        ciphertext_modulus,
+        "Mismatched CiphertextModulus between input ({:?}) and output ({:?}).",
+        input_lwe_ciphertext_list.ciphertext_modulus(),
+        ciphertext_modulus,
+    );
+    assert_eq!(
+        input_lwe_ciphertext_list.lwe_size().to_lwe_dimension(),
+        glwe_size
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(polynomial_size),
+        "The input LweDimension ({:?}) must be the equivalent LweDimension of the output \
+        GlweDimension ({:?}) and PolynomialSize ({polynomial_size:?}).",
+        input_lwe_ciphertext_list.lwe_size().to_lwe_dimension(),
+        glwe_size.to_glwe_dimension(),
+    );
+
+    let lwe_count = input_lwe_ciphertext_list.lwe_ciphertext_count();
+    let stride = lwe_trace_packing_coefficient_stride(polynomial_size, lwe_count);
+    let padded_count = polynomial_size.0 / stride;
+
+    // Missing inputs are trivial encryptions of 0
+    let mut glwe_ciphertexts: Vec<_> = (0..padded_count)
+        .map(|_| GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus))
+        .collect();
+
+    for (input_lwe, output_glwe) in input_lwe_ciphertext_list
+        .iter()
+        .zip(glwe_ciphertexts.iter_mut())
+    {
+        lwe_ciphertext_into_scaled_glwe_ciphertext(&input_lwe, output_glwe);
+    }
+
+    let mut buffer =
+        GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
+
+    // The ciphertexts with indices i and i + half are the "even" and "odd" parts at this level
+    let mut current_count = padded_count;
+    for level in 1..=padded_count.ilog2() as usize {
+        let half = current_count / 2;
+        let (evens, odds) = glwe_ciphertexts[..current_count].split_at_mut(half);
+        let monomial_degree = MonomialDegree(polynomial_size.0 >> level);
+        let automorphism_key =
+            find_automorphism_key(automorphism_keys, AutomorphismExponent((1 << level) + 1));
+
+        for (even, odd) in evens.iter_mut().zip(odds.iter_mut()) {
+            // odd <- X^{N / 2^level} * odd
+            for mut polynomial in odd.as_mut_polynomial_list().iter_mut() {
+                polynomial_wrapping_monic_monomial_mul_assign(&mut polynomial, monomial_degree);
+            }
+
+            // buffer <- even - odd, even <- even + odd
+            glwe_ciphertext_sub(&mut buffer, even, odd);
+            glwe_ciphertext_add_assign(even, odd);
+
+            // even <- even + tau(buffer), odd is not needed anymore and is used as output buffer
+            apply_automorphism_glwe_ciphertext(automorphism_key, &buffer, odd);
+            glwe_ciphertext_add_assign(even, odd);
+        }
+
+        current_count = half;
+    }
+
+    output_glwe_ciphertext
+        .as_mut()
+        .copy_from_slice(glwe_ciphertexts[0].as_ref());
+
+    glwe_ciphertext_partial_trace_assign(
+        automorphism_keys,
+        output_glwe_ciphertext,
+        padded_count.ilog2() as usize,
+    );
+}
@@ -4,14 +4,19 @@
 
 pub mod ggsw_conversion;
 pub mod ggsw_encryption;
+pub mod glwe_automorphism;
+pub mod glwe_automorphism_key_generation;
 pub mod glwe_encryption;
 pub mod glwe_keyswitch;
 pub mod glwe_keyswitch_key_generation;
 pub mod glwe_linear_algebra;
+pub mod glwe_relinearization_key_generation;
 pub mod glwe_sample_extraction;
 pub mod glwe_secret_key_generation;
+pub mod glwe_tensor_product;
 pub mod lwe_bootstrap_key_conversion;
 pub mod lwe_bootstrap_key_generation;
+pub mod lwe_circuit_bootstrapping;
 pub mod lwe_compact_ciphertext_list_expansion;
 pub mod lwe_compact_public_key_generation;
 pub mod lwe_encryption;
@@ -28,6 +33,7 @@ pub mod lwe_private_functional_packing_keyswitch_key_generation;
 pub mod lwe_programmable_bootstrapping;
 pub mod lwe_public_key_generation;
 pub mod lwe_secret_key_generation;
+pub mod lwe_trace_packing;
 pub mod lwe_wopbs;
 #[cfg(feature = "zk-pok")]
 pub mod lwe_zero_knowledge_verification;
@@ -37,6 +43,7 @@ pub mod modulus_switch_noise_reduction;
 pub mod polynomial_algorithms;
 pub mod seeded_ggsw_ciphertext_decompression;
 pub mod seeded_ggsw_ciphertext_list_decompression;
+pub mod seeded_glwe_automorphism_key_decompression;
 pub mod seeded_glwe_ciphertext_decompression;
 pub mod seeded_glwe_ciphertext_list_decompression;
 pub mod seeded_lwe_bootstrap_key_decompression;
@@ -56,14 +63,19 @@ pub(crate) mod test;
 // They can still be used via `use crate::core_crypto::algorithms::slice_algorithms::*;`
 pub use ggsw_conversion::*;
 pub use ggsw_encryption::*;
+pub use glwe_automorphism::*;
+pub use glwe_automorphism_key_generation::*;
 pub use glwe_encryption::*;
 pub use glwe_keyswitch::*;
 pub use glwe_keyswitch_key_generation::*;
 pub use glwe_linear_algebra::*;
+pub use glwe_relinearization_key_generation::*;
 pub use glwe_sample_extraction::*;
 pub use glwe_secret_key_generation::*;
+pub use glwe_tensor_product::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_generation::*;
+pub use lwe_circuit_bootstrapping::*;
 pub use lwe_compact_ciphertext_list_expansion::*;
 pub use lwe_compact_public_key_generation::*;
 pub use lwe_encryption::*;
@@ -80,12 +92,14 @@ pub use lwe_private_functional_packing_keyswitch_key_generation::*;
 pub use lwe_programmable_bootstrapping::*;
 pub use lwe_public_key_generation::*;
 pub use lwe_secret_key_generation::*;
+pub use lwe_trace_packing::*;
 pub use lwe_wopbs::*;
 #[cfg(feature = "zk-pok")]
 pub use lwe_zero_knowledge_verification::*;
 pub use modulus_switch::*;
 pub use seeded_ggsw_ciphertext_decompression::*;
 pub use seeded_ggsw_ciphertext_list_decompression::*;
+pub use seeded_glwe_automorphism_key_decompression::*;
 pub use seeded_glwe_ciphertext_decompression::*;
 pub use seeded_glwe_ciphertext_list_decompression::*;
 pub use seeded_lwe_bootstrap_key_decompression::*;
@@ -3,7 +3,7 @@
 use itertools::Itertools;
 
 use crate::core_crypto::algorithms::slice_algorithms::*;
-use crate::core_crypto::commons::parameters::MonomialDegree;
+use crate::core_crypto::commons::parameters::{AutomorphismExponent, MonomialDegree};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 
@@ -646,6 +646,67 @@ pub fn polynomial_wrapping_monic_monomial_mul<Scalar, OutputCont, InputCont>(
     }
 }
 
+/// Apply the ring automorphism $X \mapsto X^{k}$ (mod $(X^{N}+1)$) to the input polynomial and
+/// write the result to the output polynomial.
+///
+/// # Note
+///
+/// Computations wrap around (similar to computing modulo $2^{n\_{bits}}$) when exceeding the
+/// unsigned integer capacity.
+///
+/// # Panics
+///
+/// This function will panic if `output` and `input` do not have the same polynomial size or if the
+/// exponent is not odd and smaller than $2N$.
+///
+/// # Examples
+///
+/// ```rust
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let input = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+/// let mut output = Polynomial::from_container(vec![0, 0, 0, 0]);
+/// polynomial_wrapping_automorphism(&mut output, &input, AutomorphismExponent(3));
+/// // 1 + 2X + 3X^2 + 4X^3 -> 1 + 2X^3 + 3X^6 + 4X^9 = 1 + 4X - 3X^2 + 2X^3
+/// assert_eq!(output.as_ref(), &[1, 4, 253, 2]);
+/// ```
+pub fn polynomial_wrapping_automorphism<Scalar, OutputCont, InputCont>(
+    output: &mut Polynomial<OutputCont>,
+    input: &Polynomial<InputCont>,
+    automorphism_exponent: AutomorphismExponent,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == input.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input polynomial size {:?}.",
+        output.polynomial_size(),
+        input.polynomial_size(),
+    );
+
+    let polynomial_size = input.polynomial_size().0;
+    let exponent = automorphism_exponent.0;
+    assert!(
+        exponent % 2 == 1 && exponent < 2 * polynomial_size,
+        "The automorphism exponent must be odd and smaller than 2 * polynomial_size, \
+        got exponent: {exponent} and polynomial_size: {polynomial_size}."
+    );
+
+    // As the exponent is odd, X -> X^k is a permutation of the monomials up to a sign
+    let mut output_degree = 0;
+    for &coefficient in input.as_ref().iter() {
+        if output_degree < polynomial_size {
+            output[output_degree] = coefficient;
+        } else {
+            output[output_degree - polynomial_size] = coefficient.wrapping_neg();
+        }
+        output_degree = (output_degree + exponent) % (2 * polynomial_size);
+    }
+}
+
 /// Multiply (mod $(X^{N}+1)$), the input polynomial with a monic monomial of a given degree i.e.
 /// $X^{degree}$, then subtract the input from the result and assign to the output.
 ///
@@ -0,0 +1,59 @@
+//! Module with primitives pertaining to [`SeededGlweAutomorphismKey`] decompression.
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::MaskRandomGenerator;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Convenience function to share the core logic of the decompression algorithm for
+/// [`SeededGlweAutomorphismKey`] between all functions needing it.
+pub fn decompress_seeded_glwe_automorphism_key_with_pre_seeded_generator<
+    Scalar,
+    InputCont,
+    OutputCont,
+    Gen,
+>(
+    output_key: &mut GlweAutomorphismKey<OutputCont>,
+    input_key: &SeededGlweAutomorphismKey<InputCont>,
+    generator: &mut MaskRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        output_key.automorphism_exponent(),
+        input_key.automorphism_exponent(),
+        "Mismatched AutomorphismExponent between output ({:?}) and input ({:?}).",
+        output_key.automorphism_exponent(),
+        input_key.automorphism_exponent(),
+    );
+
+    decompress_seeded_glwe_ciphertext_list_with_pre_seeded_generator(
+        &mut output_key
+            .as_mut_glwe_keyswitch_key()
+            .as_mut_glwe_ciphertext_list(),
+        &input_key.as_seeded_glwe_ciphertext_list(),
+        generator,
+    );
+}
+
+/// Decompress a [`SeededGlweAutomorphismKey`], without consuming it, into a standard
+/// [`GlweAutomorphismKey`].
+pub fn decompress_seeded_glwe_automorphism_key<Scalar, InputCont, OutputCont, Gen>(
+    output_key: &mut GlweAutomorphismKey<OutputCont>,
+    input_key: &SeededGlweAutomorphismKey<InputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut generator = MaskRandomGenerator::<Gen>::new(input_key.compression_seed().seed);
+    decompress_seeded_glwe_automorphism_key_with_pre_seeded_generator::<_, _, _, Gen>(
+        output_key,
+        input_key,
+        &mut generator,
+    );
+}
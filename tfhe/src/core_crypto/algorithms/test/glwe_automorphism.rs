@@ -0,0 +1,300 @@
+use super::*;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism;
+
+#[cfg(not(tarpaulin))]
+const NB_TESTS: usize = 4;
+#[cfg(tarpaulin)]
+const NB_TESTS: usize = 1;
+
+fn decrypt_and_decode<Scalar: UnsignedTorus>(
+    glwe_sk: &GlweSecretKeyOwned<Scalar>,
+    glwe: &GlweCiphertextOwned<Scalar>,
+    delta: Scalar,
+    msg_modulus: Scalar,
+) -> Vec<Scalar> {
+    let mut decrypted_plaintext_list =
+        PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
+
+    decrypt_glwe_ciphertext(glwe_sk, glwe, &mut decrypted_plaintext_list);
+
+    decrypted_plaintext_list
+        .iter()
+        .map(|plaintext| round_decode(*plaintext.0, delta) % msg_modulus)
+        .collect()
+}
+
+fn glwe_encrypt_automorphism_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let automorphism_exponents = [
+        AutomorphismExponent(3),
+        AutomorphismExponent(polynomial_size.0 + 1),
+        AutomorphismExponent(2 * polynomial_size.0 - 1),
+    ];
+
+    for _ in 0..NB_TESTS {
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        for (index, &automorphism_exponent) in automorphism_exponents.iter().enumerate() {
+            // Alternate between the standard and the seeded key generation
+            let automorphism_key = if index % 2 == 0 {
+                allocate_and_generate_new_glwe_automorphism_key(
+                    &glwe_sk,
+                    automorphism_exponent,
+                    params.pbs_base_log,
+                    params.pbs_level,
+                    glwe_noise_distribution,
+                    ciphertext_modulus,
+                    &mut rsc.encryption_random_generator,
+                )
+            } else {
+                allocate_and_generate_new_seeded_glwe_automorphism_key(
+                    &glwe_sk,
+                    automorphism_exponent,
+                    params.pbs_base_log,
+                    params.pbs_level,
+                    glwe_noise_distribution,
+                    ciphertext_modulus,
+                    rsc.seeder.as_mut(),
+                )
+                .decompress_into_glwe_automorphism_key()
+            };
+
+            assert!(check_encrypted_content_respects_mod(
+                &automorphism_key,
+                ciphertext_modulus
+            ));
+
+            let msg: Vec<Scalar> = (0..polynomial_size.0)
+                .map(|i| Scalar::cast_from(i as u128) % msg_modulus)
+                .collect();
+
+            let plaintext_list =
+                PlaintextList::from_container(msg.iter().map(|&m| m * delta).collect::<Vec<_>>());
+
+            let mut glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                &mut glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            apply_automorphism_glwe_ciphertext(&automorphism_key, &glwe, &mut output_glwe);
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let decoded = decrypt_and_decode(&glwe_sk, &output_glwe, delta, msg_modulus);
+
+            // Compute the expected result in the clear, in the message space
+            let mut expected = Polynomial::new(Scalar::ZERO, polynomial_size);
+            polynomial_wrapping_automorphism(
+                &mut expected,
+                &Polynomial::from_container(msg.as_slice()),
+                automorphism_exponent,
+            );
+            let expected: Vec<Scalar> = expected
+                .iter()
+                .map(|&coefficient| coefficient.wrapping_rem(msg_modulus))
+                .collect();
+
+            assert_eq!(decoded, expected);
+        }
+    }
+}
+
+create_parameterized_test!(glwe_encrypt_automorphism_decrypt_custom_mod);
+
+fn glwe_encrypt_trace_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestParams<Scalar>) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    // The trace multiplies the constant coefficient by N
+    let scaled_delta = delta >> polynomial_size.log2().0;
+
+    for _ in 0..NB_TESTS {
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        let automorphism_keys = allocate_and_generate_new_trace_packing_automorphism_keys(
+            &glwe_sk,
+            params.pbs_base_log,
+            params.pbs_level,
+            glwe_noise_distribution,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let msg: Vec<Scalar> = (0..polynomial_size.0)
+            .map(|i| Scalar::cast_from((i * 7 + 1) as u128) % msg_modulus)
+            .collect();
+
+        let plaintext_list = PlaintextList::from_container(
+            msg.iter().map(|&m| m * scaled_delta).collect::<Vec<_>>(),
+        );
+
+        let mut glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        encrypt_glwe_ciphertext(
+            &glwe_sk,
+            &mut glwe,
+            &plaintext_list,
+            glwe_noise_distribution,
+            &mut rsc.encryption_random_generator,
+        );
+
+        glwe_ciphertext_trace_assign(&automorphism_keys, &mut glwe);
+
+        assert!(check_encrypted_content_respects_mod(
+            &glwe,
+            ciphertext_modulus
+        ));
+
+        let decoded = decrypt_and_decode(&glwe_sk, &glwe, delta, msg_modulus);
+
+        assert_eq!(decoded[0], msg[0]);
+        assert!(decoded[1..].iter().all(|&x| x == Scalar::ZERO));
+    }
+}
+
+create_parameterized_test!(glwe_encrypt_trace_decrypt_custom_mod);
+
+fn lwe_list_encrypt_trace_pack_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let automorphism_keys = allocate_and_generate_new_trace_packing_automorphism_keys(
+        &glwe_sk,
+        params.pbs_base_log,
+        params.pbs_level,
+        glwe_noise_distribution,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    for lwe_count in [1, 2, 5, 64, polynomial_size.0] {
+        let msg: Vec<Scalar> = (0..lwe_count)
+            .map(|i| Scalar::cast_from((i * 3 + 1) as u128) % msg_modulus)
+            .collect();
+
+        let plaintext_list =
+            PlaintextList::from_container(msg.iter().map(|&m| m * delta).collect::<Vec<_>>());
+
+        let mut lwe_list = LweCiphertextList::new(
+            Scalar::ZERO,
+            glwe_dimension
+                .to_equivalent_lwe_dimension(polynomial_size)
+                .to_lwe_size(),
+            LweCiphertextCount(lwe_count),
+            ciphertext_modulus,
+        );
+
+        encrypt_lwe_ciphertext_list(
+            &glwe_sk.as_lwe_secret_key(),
+            &mut lwe_list,
+            &plaintext_list,
+            glwe_noise_distribution,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut output_glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        trace_pack_lwe_ciphertext_list_into_glwe_ciphertext(
+            &automorphism_keys,
+            &lwe_list,
+            &mut output_glwe,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &output_glwe,
+            ciphertext_modulus
+        ));
+
+        let decoded = decrypt_and_decode(&glwe_sk, &output_glwe, delta, msg_modulus);
+
+        let stride =
+            lwe_trace_packing_coefficient_stride(polynomial_size, LweCiphertextCount(lwe_count));
+
+        for (degree, &decoded_value) in decoded.iter().enumerate() {
+            let expected = if degree % stride == 0 {
+                msg.get(degree / stride).copied().unwrap_or(Scalar::ZERO)
+            } else {
+                Scalar::ZERO
+            };
+            assert_eq!(decoded_value, expected, "Mismatch at degree {degree}");
+        }
+    }
+}
+
+create_parameterized_test!(lwe_list_encrypt_trace_pack_decrypt_custom_mod);
@@ -0,0 +1,172 @@
+use super::*;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_add_mul_assign;
+
+#[cfg(not(tarpaulin))]
+const NB_TESTS: usize = 4;
+#[cfg(tarpaulin)]
+const NB_TESTS: usize = 1;
+
+// The default parameter sets have a GlweDimension of 1, this one also exercises the cross terms
+// between different polynomials of the secret key
+const TEST_PARAMS_4_BITS_NATIVE_U64_GLWE_DIM_2: ClassicTestParams<u64> = ClassicTestParams {
+    glwe_dimension: GlweDimension(2),
+    polynomial_size: PolynomialSize(1024),
+    ..TEST_PARAMS_4_BITS_NATIVE_U64
+};
+
+#[derive(Clone, Copy, Debug)]
+enum TensorProductKind {
+    Karatsuba,
+    Fft128,
+}
+
+fn glwe_encrypt_tensor_product_relinearize_decrypt<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    // Messages are encoded without padding bit
+    let delta_log = DeltaLog(Scalar::BITS - message_modulus_log.0);
+    let delta = Scalar::ONE << delta_log.0;
+
+    let tensor_product_glwe_size =
+        glwe_tensor_product_output_glwe_dimension(glwe_dimension).to_glwe_size();
+
+    for _ in 0..NB_TESTS {
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        let relinearization_key = allocate_and_generate_new_glwe_relinearization_key(
+            &glwe_sk,
+            params.pbs_base_log,
+            params.pbs_level,
+            glwe_noise_distribution,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &relinearization_key,
+            ciphertext_modulus
+        ));
+
+        let mut msgs = [
+            vec![Scalar::ZERO; polynomial_size.0],
+            vec![Scalar::ZERO; polynomial_size.0],
+        ];
+        let mut glwes = [
+            GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            ),
+            GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            ),
+        ];
+
+        for (msg, glwe) in msgs.iter_mut().zip(glwes.iter_mut()) {
+            rsc.encryption_random_generator
+                .fill_slice_with_random_uniform_mask(msg);
+            for coefficient in msg.iter_mut() {
+                *coefficient = coefficient.wrapping_rem(msg_modulus);
+            }
+
+            let plaintext_list =
+                PlaintextList::from_container(msg.iter().map(|&m| m * delta).collect::<Vec<_>>());
+
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
+        }
+
+        // Compute the expected result in the clear, in the message space
+        let mut expected = Polynomial::new(Scalar::ZERO, polynomial_size);
+        polynomial_wrapping_add_mul_assign(
+            &mut expected,
+            &Polynomial::from_container(msgs[0].as_slice()),
+            &Polynomial::from_container(msgs[1].as_slice()),
+        );
+        let expected: Vec<Scalar> = expected
+            .iter()
+            .map(|&coefficient| coefficient.wrapping_rem(msg_modulus))
+            .collect();
+
+        for kind in [TensorProductKind::Karatsuba, TensorProductKind::Fft128] {
+            let mut tensor_product = GlweCiphertext::new(
+                Scalar::ZERO,
+                tensor_product_glwe_size,
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            match kind {
+                TensorProductKind::Karatsuba => glwe_tensor_product_and_rescale(
+                    &glwes[0],
+                    &glwes[1],
+                    &mut tensor_product,
+                    delta_log,
+                ),
+                TensorProductKind::Fft128 => glwe_tensor_product_and_rescale_f128(
+                    &glwes[0],
+                    &glwes[1],
+                    &mut tensor_product,
+                    delta_log,
+                ),
+            }
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            relinearize_glwe_tensor_product(
+                &relinearization_key,
+                &tensor_product,
+                &mut output_glwe,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let mut decrypted_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+            decrypt_glwe_ciphertext(&glwe_sk, &output_glwe, &mut decrypted_plaintext_list);
+
+            let decoded: Vec<Scalar> = decrypted_plaintext_list
+                .iter()
+                .map(|plaintext| round_decode(*plaintext.0, delta).wrapping_rem(msg_modulus))
+                .collect();
+
+            assert_eq!(decoded, expected, "Failed for {kind:?}");
+        }
+    }
+}
+
+create_parameterized_test!(glwe_encrypt_tensor_product_relinearize_decrypt {
+    TEST_PARAMS_4_BITS_NATIVE_U64,
+    TEST_PARAMS_4_BITS_NATIVE_U64_GLWE_DIM_2
+});
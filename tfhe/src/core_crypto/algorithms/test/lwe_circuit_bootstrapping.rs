@@ -0,0 +1,175 @@
+use super::*;
+
+#[cfg(not(tarpaulin))]
+const NB_TESTS: usize = 4;
+#[cfg(tarpaulin)]
+const NB_TESTS: usize = 1;
+
+// The GGSW ciphertexts built by the circuit bootstrapping are as noisy as a PBS output, a more
+// precise PBS decomposition than for the default parameters keeps the CMux noise low
+const TEST_PARAMS_CIRCUIT_BOOTSTRAP_4_BITS_NATIVE_U64: ClassicTestParams<u64> = ClassicTestParams {
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    pfks_base_log: DecompositionBaseLog(15),
+    pfks_level: DecompositionLevelCount(2),
+    cbs_base_log: DecompositionBaseLog(6),
+    cbs_level: DecompositionLevelCount(4),
+    ..TEST_PARAMS_4_BITS_NATIVE_U64
+};
+
+fn lwe_encrypt_circuit_bootstrap_cmux_decrypt<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let lwe_noise_distribution = params.lwe_noise_distribution;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    // Messages are encoded with a bit of padding
+    let delta_log = DeltaLog(Scalar::BITS - 1 - message_modulus_log.0);
+    let delta = Scalar::ONE << delta_log.0;
+
+    let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &lwe_sk,
+        &glwe_sk,
+        params.pbs_base_log,
+        params.pbs_level,
+        glwe_noise_distribution,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fourier_bsk = FourierLweBootstrapKey::new(
+        bsk.input_lwe_dimension(),
+        bsk.glwe_size(),
+        bsk.polynomial_size(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+    );
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fourier_bsk);
+    drop(bsk);
+
+    let pfpksk_list = par_allocate_and_generate_new_circuit_bootstrap_lwe_pfpksk_list(
+        &glwe_sk.as_lwe_secret_key(),
+        &glwe_sk,
+        params.pfks_base_log,
+        params.pfks_level,
+        params.pfks_noise_distribution,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut ggsw = FourierGgswCiphertext::new(
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        params.cbs_base_log,
+        params.cbs_level,
+    );
+
+    for _ in 0..NB_TESTS {
+        for bit in [Scalar::ZERO, Scalar::ONE] {
+            let mut msgs = [
+                vec![Scalar::ZERO; polynomial_size.0],
+                vec![Scalar::ZERO; polynomial_size.0],
+            ];
+            let mut glwes = [
+                GlweCiphertext::new(
+                    Scalar::ZERO,
+                    glwe_dimension.to_glwe_size(),
+                    polynomial_size,
+                    ciphertext_modulus,
+                ),
+                GlweCiphertext::new(
+                    Scalar::ZERO,
+                    glwe_dimension.to_glwe_size(),
+                    polynomial_size,
+                    ciphertext_modulus,
+                ),
+            ];
+
+            for (msg, glwe) in msgs.iter_mut().zip(glwes.iter_mut()) {
+                rsc.encryption_random_generator
+                    .fill_slice_with_random_uniform_mask(msg);
+                for coefficient in msg.iter_mut() {
+                    *coefficient = coefficient.wrapping_rem(msg_modulus);
+                }
+
+                let plaintext_list = PlaintextList::from_container(
+                    msg.iter().map(|&m| m * delta).collect::<Vec<_>>(),
+                );
+
+                encrypt_glwe_ciphertext(
+                    &glwe_sk,
+                    glwe,
+                    &plaintext_list,
+                    glwe_noise_distribution,
+                    &mut rsc.encryption_random_generator,
+                );
+            }
+
+            let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+                &lwe_sk,
+                Plaintext(bit * delta),
+                lwe_noise_distribution,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            circuit_bootstrap_lwe_ciphertext_to_ggsw(
+                &lwe,
+                &mut ggsw,
+                delta_log,
+                &fourier_bsk,
+                &pfpksk_list,
+            );
+
+            let [mut glwe_0, mut glwe_1] = glwes;
+            cmux_assign(&mut glwe_0, &mut glwe_1, &ggsw);
+
+            assert!(check_encrypted_content_respects_mod(
+                &glwe_0,
+                ciphertext_modulus
+            ));
+
+            let mut decrypted_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+            decrypt_glwe_ciphertext(&glwe_sk, &glwe_0, &mut decrypted_plaintext_list);
+
+            let decoded: Vec<Scalar> = decrypted_plaintext_list
+                .iter()
+                .map(|plaintext| round_decode(*plaintext.0, delta).wrapping_rem(msg_modulus))
+                .collect();
+
+            let expected = if bit == Scalar::ZERO {
+                &msgs[0]
+            } else {
+                &msgs[1]
+            };
+
+            assert_eq!(&decoded, expected, "Failed for bit {bit:?}");
+        }
+    }
+}
+
+create_parameterized_test!(lwe_encrypt_circuit_bootstrap_cmux_decrypt {
+    TEST_PARAMS_CIRCUIT_BOOTSTRAP_4_BITS_NATIVE_U64
+});
@@ -707,6 +707,117 @@ fn lwe_encrypt_pbs_f128_decrypt_custom_mod_test_params_3_bits_127_u128() {
     lwe_encrypt_pbs_f128_decrypt_custom_mod(TEST_PARAMS_3_BITS_127_U128);
 }
 
+fn lwe_encrypt_pbs_ntt128_decrypt_custom_mod(params: ClassicTestParams<u128>) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_noise_distribution = params.lwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = 1u128 << message_modulus_log.0;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let decomp_base_log = params.pbs_base_log;
+    let decomp_level_count = params.pbs_level;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: u128| x;
+
+    let delta = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    assert!(check_encrypted_content_respects_mod(
+        &accumulator,
+        ciphertext_modulus
+    ));
+
+    while msg != 0 {
+        msg = msg.wrapping_sub(1);
+
+        let mut keys_gen = |params| generate_keys(params, &mut rsc);
+
+        let keys = gen_keys_or_get_from_cache_if_enabled(params, &mut keys_gen);
+        let (input_lwe_secret_key, output_lwe_secret_key, bsk) =
+            (keys.small_lwe_sk, keys.big_lwe_sk, keys.bsk);
+
+        let mut nbsk = Ntt128LweBootstrapKey::new(
+            input_lwe_dimension,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+        );
+
+        convert_standard_lwe_bootstrap_key_to_ntt128(&bsk, &mut nbsk);
+
+        drop(bsk);
+
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_noise_distribution,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &lwe_ciphertext_in,
+                ciphertext_modulus
+            ));
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                0u128,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            programmable_bootstrap_ntt128_lwe_ciphertext(
+                &lwe_ciphertext_in,
+                &mut out_pbs_ct,
+                &accumulator,
+                &nbsk,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &out_pbs_ct,
+                ciphertext_modulus
+            ));
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+#[test]
+fn lwe_encrypt_pbs_ntt128_decrypt_custom_mod_test_params_4_bits_native_u128() {
+    lwe_encrypt_pbs_ntt128_decrypt_custom_mod(TEST_PARAMS_4_BITS_NATIVE_U128);
+}
+#[test]
+fn lwe_encrypt_pbs_ntt128_decrypt_custom_mod_test_params_3_bits_127_u128() {
+    lwe_encrypt_pbs_ntt128_decrypt_custom_mod(TEST_PARAMS_3_BITS_127_U128);
+}
+
 fn lwe_encrypt_pbs_ntt64_decrypt_custom_mod(params: ClassicTestParams<u64>) {
     let input_lwe_dimension = params.lwe_dimension;
     let lwe_noise_distribution = params.lwe_noise_distribution;
@@ -7,10 +7,13 @@ pub(crate) use params::*;
 use std::fmt::Debug;
 
 mod ggsw_encryption;
+mod glwe_automorphism;
 mod glwe_encryption;
 mod glwe_linear_algebra;
 mod glwe_sample_extraction;
+mod glwe_tensor_product;
 mod lwe_bootstrap_key_generation;
+mod lwe_circuit_bootstrapping;
 mod lwe_compact_public_key_generation;
 mod lwe_encryption;
 mod lwe_keyswitch;
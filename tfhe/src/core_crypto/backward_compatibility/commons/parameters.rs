@@ -103,6 +103,11 @@ pub enum MonomialDegreeVersions {
     V0(MonomialDegree),
 }
 
+#[derive(VersionsDispatch)]
+pub enum AutomorphismExponentVersions {
+    V0(AutomorphismExponent),
+}
+
 #[derive(VersionsDispatch)]
 pub enum DecompositionBaseLogVersions {
     V0(DecompositionBaseLog),
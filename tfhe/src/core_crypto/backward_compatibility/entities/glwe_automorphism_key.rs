@@ -0,0 +1,10 @@
+use crate::core_crypto::prelude::{Container, GlweAutomorphismKey, UnsignedInteger};
+use tfhe_versionable::VersionsDispatch;
+
+#[derive(VersionsDispatch)]
+pub enum GlweAutomorphismKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(GlweAutomorphismKey<C>),
+}
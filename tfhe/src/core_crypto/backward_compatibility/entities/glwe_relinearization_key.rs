@@ -0,0 +1,10 @@
+use crate::core_crypto::prelude::{Container, GlweRelinearizationKey, UnsignedInteger};
+use tfhe_versionable::VersionsDispatch;
+
+#[derive(VersionsDispatch)]
+pub enum GlweRelinearizationKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(GlweRelinearizationKey<C>),
+}
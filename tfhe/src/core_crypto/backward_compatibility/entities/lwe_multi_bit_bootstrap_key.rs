@@ -2,7 +2,9 @@ use tfhe_fft::c64;
 use tfhe_versionable::deprecation::{Deprecable, Deprecated};
 use tfhe_versionable::VersionsDispatch;
 
-use crate::core_crypto::entities::lwe_multi_bit_bootstrap_key::Fourier128LweMultiBitBootstrapKey;
+use crate::core_crypto::entities::lwe_multi_bit_bootstrap_key::{
+    Fourier128LweMultiBitBootstrapKey, Ntt128LweMultiBitBootstrapKey,
+};
 use crate::core_crypto::prelude::{
     Container, FourierLweMultiBitBootstrapKey, LweMultiBitBootstrapKey, UnsignedInteger,
 };
@@ -39,3 +41,8 @@ pub enum FourierLweMultiBitBootstrapKeyVersions<C: Container<Element = c64>> {
 pub enum Fourier128MultiBitLweBootstrapKeyVersions<C: Container<Element = f64>> {
     V0(Fourier128LweMultiBitBootstrapKey<C>),
 }
+
+#[derive(VersionsDispatch)]
+pub enum Ntt128LweMultiBitBootstrapKeyVersions<C: Container<Element = u64>> {
+    V0(Ntt128LweMultiBitBootstrapKey<C>),
+}
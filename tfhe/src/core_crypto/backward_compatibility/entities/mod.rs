@@ -4,9 +4,11 @@ pub mod compressed_modulus_switched_lwe_ciphertext;
 pub mod compressed_modulus_switched_multi_bit_lwe_ciphertext;
 pub mod ggsw_ciphertext;
 pub mod ggsw_ciphertext_list;
+pub mod glwe_automorphism_key;
 pub mod glwe_ciphertext;
 pub mod glwe_ciphertext_list;
 pub mod glwe_keyswitch_key;
+pub mod glwe_relinearization_key;
 pub mod glwe_secret_key;
 pub mod gsw_ciphertext;
 pub mod lwe_bootstrap_key;
@@ -23,6 +25,8 @@ pub mod lwe_private_functional_packing_keyswitch_key;
 pub mod lwe_private_functional_packing_keyswitch_key_list;
 pub mod lwe_public_key;
 pub mod lwe_secret_key;
+pub mod ntt128_ggsw_ciphertext;
+pub mod ntt128_lwe_bootstrap_key;
 pub mod ntt_ggsw_ciphertext;
 pub mod ntt_ggsw_ciphertext_list;
 pub mod ntt_lwe_bootstrap_key;
@@ -33,6 +37,7 @@ pub mod polynomial;
 pub mod polynomial_list;
 pub mod seeded_ggsw_ciphertext;
 pub mod seeded_ggsw_ciphertext_list;
+pub mod seeded_glwe_automorphism_key;
 pub mod seeded_glwe_ciphertext;
 pub mod seeded_glwe_ciphertext_list;
 pub mod seeded_lwe_bootstrap_key;
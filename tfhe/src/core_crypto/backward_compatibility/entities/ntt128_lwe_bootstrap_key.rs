@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::{Container, Ntt128LweBootstrapKey};
+
+#[derive(VersionsDispatch)]
+pub enum Ntt128LweBootstrapKeyVersions<C: Container<Element = u64>> {
+    V0(Ntt128LweBootstrapKey<C>),
+}
@@ -0,0 +1,10 @@
+use crate::core_crypto::prelude::{Container, SeededGlweAutomorphismKey, UnsignedInteger};
+use tfhe_versionable::VersionsDispatch;
+
+#[derive(VersionsDispatch)]
+pub enum SeededGlweAutomorphismKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(SeededGlweAutomorphismKey<C>),
+}
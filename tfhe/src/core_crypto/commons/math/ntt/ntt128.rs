@@ -0,0 +1,300 @@
+//! Exact negacyclic polynomial multiplication for 128 bits integers.
+//!
+//! Polynomials are represented by their residues modulo several 64 bits NTT friendly primes, each
+//! residue polynomial being transformed with the 64 bits NTT of the corresponding prime. Products
+//! are accumulated independently for each prime and the exact integer result is recovered with the
+//! Chinese remainder theorem before being reduced modulo $2^{128}$.
+//!
+//! As long as the absolute value of the integer result stays below half the product of the
+//! primes, no approximation is made, unlike with the 128 bits FFT.
+use super::ntt64::{Ntt64, Ntt64View};
+use crate::core_crypto::commons::parameters::{
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+use crate::core_crypto::commons::utils::izip;
+
+/// Primes used for the CRT representation of 128 bits polynomials.
+///
+/// They are all below $2^{62}$ and congruent to 1 modulo $2^{17}$, so that negacyclic NTTs exist
+/// for polynomial sizes up to $2^{16}$. Their product is slightly below $2^{186}$.
+pub const NTT128_PRIMES: [u64; 3] = [
+    0x3fff_ffff_ffe8_0001,
+    0x3fff_ffff_ffbe_0001,
+    0x3fff_ffff_ffb8_0001,
+];
+
+/// Number of primes, i.e. the number of 64 bits NTT polynomials needed to represent a single 128
+/// bits polynomial in the NTT domain.
+pub const NTT128_PRIME_COUNT: usize = NTT128_PRIMES.len();
+
+const P0: u64 = NTT128_PRIMES[0];
+const P1: u64 = NTT128_PRIMES[1];
+const P2: u64 = NTT128_PRIMES[2];
+
+const fn mul_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    ((lhs as u128 * rhs as u128) % modulus as u128) as u64
+}
+
+const fn inv_mod(value: u64, prime: u64) -> u64 {
+    // Fermat's little theorem
+    let mut base = value % prime;
+    let mut exp = prime - 2;
+    let mut result = 1;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, prime);
+        }
+        base = mul_mod(base, base, prime);
+        exp >>= 1;
+    }
+    result
+}
+
+// Constants for Garner's algorithm
+const P0_INV_MOD_P1: u64 = inv_mod(P0, P1);
+const P0_INV_MOD_P2: u64 = inv_mod(P0, P2);
+const P1_INV_MOD_P2: u64 = inv_mod(P1, P2);
+const P0_P1: u128 = P0 as u128 * P1 as u128;
+const P0_P1_P2_WRAPPING: u128 = P0_P1.wrapping_mul(P2 as u128);
+
+/// Upper bound on the log2 of the absolute value an accumulated integer product may reach while
+/// still being exactly recovered from its residues.
+const EXACT_PRODUCT_MAX_LOG2: u32 = 184;
+
+/// Check that an external product between a GLWE ciphertext decomposed with the given parameters
+/// and a GGSW ciphertext, whose polynomials are sums of `key_term_count` polynomials with
+/// coefficients on 128 bits, can be computed exactly.
+///
+/// Panics otherwise.
+pub(crate) fn assert_ntt128_exact_external_product(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    key_term_count: usize,
+) {
+    // Each output coefficient is a sum of glwe_size * level_count * polynomial_size products
+    // between a decomposition term bounded by base / 2 and a key coefficient bounded by
+    // key_term_count * 2^127
+    let term_count = glwe_size.0 * decomposition_level_count.0 * polynomial_size.0 * key_term_count;
+    let bound_log2 = term_count.next_power_of_two().ilog2() + decomposition_base_log.0 as u32 - 1
+        + u128::BITS
+        - 1;
+    assert!(
+        bound_log2 <= EXACT_PRODUCT_MAX_LOG2,
+        "The 128 bits NTT cannot compute exact products for the given parameters: \
+        GlweSize {glwe_size:?}, PolynomialSize {polynomial_size:?}, \
+        DecompositionBaseLog {decomposition_base_log:?}, \
+        DecompositionLevelCount {decomposition_level_count:?}"
+    );
+}
+
+#[derive(Clone, Debug)]
+pub struct Ntt128 {
+    ntts: [Ntt64; NTT128_PRIME_COUNT],
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Ntt128View<'a> {
+    pub(crate) ntts: [Ntt64View<'a>; NTT128_PRIME_COUNT],
+}
+
+impl Ntt128 {
+    /// Negacyclic polynomials of size `size`.
+    ///
+    /// The underlying 64 bits NTT plans are shared with [`Ntt64`] and cached in the same way.
+    pub fn new(size: PolynomialSize) -> Self {
+        Self {
+            ntts: NTT128_PRIMES
+                .map(|prime| Ntt64::new(CiphertextModulus::try_new(prime as u128).unwrap(), size)),
+        }
+    }
+
+    #[inline]
+    pub fn as_view(&self) -> Ntt128View<'_> {
+        Ntt128View {
+            ntts: [
+                self.ntts[0].as_view(),
+                self.ntts[1].as_view(),
+                self.ntts[2].as_view(),
+            ],
+        }
+    }
+}
+
+#[inline(always)]
+fn reduce_signed_u128(value: u128, prime: u64) -> u64 {
+    if (value as i128) < 0 {
+        let rem = (value.wrapping_neg() % prime as u128) as u64;
+        if rem == 0 {
+            0
+        } else {
+            prime - rem
+        }
+    } else {
+        (value % prime as u128) as u64
+    }
+}
+
+#[inline(always)]
+fn sub_mod(lhs: u64, rhs: u64, prime: u64) -> u64 {
+    if lhs >= rhs {
+        lhs - rhs
+    } else {
+        lhs.wrapping_sub(rhs).wrapping_add(prime)
+    }
+}
+
+/// Recover the integer of minimal absolute value having the given residues and return it reduced
+/// modulo $2^{128}$.
+#[inline(always)]
+fn crt_reconstruct_wrapping(r0: u64, r1: u64, r2: u64) -> u128 {
+    // Mixed radix representation x = v0 + v1 * p0 + v2 * p0 * p1
+    let v0 = r0;
+    let v1 = mul_mod(sub_mod(r1, v0 % P1, P1), P0_INV_MOD_P1, P1);
+    let v2 = mul_mod(
+        sub_mod(
+            mul_mod(sub_mod(r2, v0 % P2, P2), P0_INV_MOD_P2, P2),
+            v1 % P2,
+            P2,
+        ),
+        P1_INV_MOD_P2,
+        P2,
+    );
+
+    let x = (v0 as u128)
+        .wrapping_add((v1 as u128) * (P0 as u128))
+        .wrapping_add((v2 as u128).wrapping_mul(P0_P1));
+
+    // The integer is far smaller than the product of the primes in absolute value, so the most
+    // significant digit tells whether it is the representative of a negative value
+    if v2 > P2 / 2 {
+        x.wrapping_sub(P0_P1_P2_WRAPPING)
+    } else {
+        x
+    }
+}
+
+impl Ntt128View<'_> {
+    pub fn polynomial_size(self) -> PolynomialSize {
+        self.ntts[0].polynomial_size()
+    }
+
+    /// Number of `u64` required to store a polynomial in the NTT domain.
+    pub fn ntt_polynomial_len(self) -> usize {
+        NTT128_PRIME_COUNT * self.polynomial_size().0
+    }
+
+    /// Applies a forward negacyclic NTT to a polynomial with 128 bits coefficients.
+    ///
+    /// Coefficients are interpreted as signed values, which allows to use the same function for
+    /// the small signed terms output by a decomposer and for torus values. The output is laid out
+    /// as the consecutive NTT polynomials for each prime.
+    pub fn forward(self, ntt: &mut [u64], standard: &[u128]) {
+        let polynomial_size = self.polynomial_size().0;
+        debug_assert_eq!(ntt.len(), self.ntt_polynomial_len());
+        debug_assert_eq!(standard.len(), polynomial_size);
+
+        for (ntt_prime, prime_ntt) in izip!(ntt.chunks_exact_mut(polynomial_size), self.ntts) {
+            let prime = prime_ntt.custom_modulus();
+            for (out, &inp) in izip!(ntt_prime.iter_mut(), standard) {
+                *out = reduce_signed_u128(inp, prime);
+            }
+            prime_ntt.plan.fwd(ntt_prime);
+        }
+    }
+
+    /// Same as [`Ntt128View::forward`] but also multiplies the output by the inverse of the
+    /// polynomial size, so that the backward transform of a product does not need to be
+    /// normalized.
+    pub fn forward_normalized(self, ntt: &mut [u64], standard: &[u128]) {
+        self.forward(ntt, standard);
+        for (ntt_prime, prime_ntt) in
+            izip!(ntt.chunks_exact_mut(self.polynomial_size().0), self.ntts)
+        {
+            prime_ntt.plan.normalize(ntt_prime);
+        }
+    }
+
+    /// Accumulates the product of `lhs` and `rhs` in `acc`, all being in the NTT domain.
+    pub fn mul_accumulate(self, acc: &mut [u64], lhs: &[u64], rhs: &[u64]) {
+        let polynomial_size = self.polynomial_size().0;
+        for (acc, lhs, rhs, prime_ntt) in izip!(
+            acc.chunks_exact_mut(polynomial_size),
+            lhs.chunks_exact(polynomial_size),
+            rhs.chunks_exact(polynomial_size),
+            self.ntts
+        ) {
+            prime_ntt.plan.mul_accumulate(acc, lhs, rhs);
+        }
+    }
+
+    /// Applies a backward negacyclic NTT to `ntt`, recovers the exact integer coefficients and
+    /// adds them modulo $2^{128}$ to `standard`.
+    ///
+    /// The backward transform is not normalized, see [`Ntt128View::forward_normalized`].
+    /// `ntt` is used as a temporary buffer and its content is meaningless after the call.
+    pub fn add_backward(self, standard: &mut [u128], ntt: &mut [u64]) {
+        let polynomial_size = self.polynomial_size().0;
+        debug_assert_eq!(ntt.len(), self.ntt_polynomial_len());
+        debug_assert_eq!(standard.len(), polynomial_size);
+
+        for (ntt_prime, prime_ntt) in izip!(ntt.chunks_exact_mut(polynomial_size), self.ntts) {
+            prime_ntt.plan.inv(ntt_prime);
+        }
+
+        let (r0, rest) = ntt.split_at(polynomial_size);
+        let (r1, r2) = rest.split_at(polynomial_size);
+
+        for (out, &r0, &r1, &r2) in izip!(standard.iter_mut(), r0, r1, r2) {
+            *out = out.wrapping_add(crt_reconstruct_wrapping(r0, r1, r2));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_add_mul_assign;
+    use crate::core_crypto::entities::Polynomial;
+    use rand::Rng;
+
+    #[test]
+    fn test_ntt128_exact_product() {
+        let mut rng = rand::thread_rng();
+
+        for log_size in 4..=11 {
+            let polynomial_size = PolynomialSize(1 << log_size);
+            let ntt = Ntt128::new(polynomial_size);
+            let ntt = ntt.as_view();
+
+            // Small signed lhs as output by a decomposer and a full 128 bits rhs
+            let base_log = 24;
+            let lhs: Vec<u128> = (0..polynomial_size.0)
+                .map(|_| {
+                    (rng.gen_range(-(1i128 << (base_log - 1))..=(1i128 << (base_log - 1)))) as u128
+                })
+                .collect();
+            let rhs: Vec<u128> = (0..polynomial_size.0).map(|_| rng.gen()).collect();
+
+            let mut expected = Polynomial::new(0u128, polynomial_size);
+            polynomial_wrapping_add_mul_assign(
+                &mut expected,
+                &Polynomial::from_container(lhs.as_slice()),
+                &Polynomial::from_container(rhs.as_slice()),
+            );
+
+            let mut ntt_lhs = vec![0u64; ntt.ntt_polynomial_len()];
+            let mut ntt_rhs = vec![0u64; ntt.ntt_polynomial_len()];
+            let mut ntt_acc = vec![0u64; ntt.ntt_polynomial_len()];
+            ntt.forward(&mut ntt_lhs, &lhs);
+            ntt.forward_normalized(&mut ntt_rhs, &rhs);
+            ntt.mul_accumulate(&mut ntt_acc, &ntt_lhs, &ntt_rhs);
+
+            let mut result = vec![0u128; polynomial_size.0];
+            ntt.add_backward(&mut result, &mut ntt_acc);
+
+            assert_eq!(result.as_slice(), expected.as_ref());
+        }
+    }
+}
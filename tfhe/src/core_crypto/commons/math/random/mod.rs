@@ -256,6 +256,14 @@ impl<T: UnsignedInteger> DynamicDistribution<T> {
         }
     }
 
+    /// Returns the variance of the distribution normalized to the torus, for a given modulus
+    pub fn variance(&self, modulus: f64) -> Variance {
+        match self {
+            Self::Gaussian(gaussian) => StandardDev::from_standard_dev(gaussian.std).get_variance(),
+            Self::TUniform(tuniform) => tuniform.variance(modulus),
+        }
+    }
+
     #[track_caller]
     pub fn gaussian_variance(&self) -> Variance {
         match self {
@@ -0,0 +1,91 @@
+// This file was autogenerated, do not modify by hand.
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::*;
+
+/// This formula is only valid for uniform binary secret keys, messages uniformly distributed in
+/// the plaintext space and input variances expressed on the torus.
+pub fn glwe_tensor_product_and_rescale_variance_binary_key(
+    lhs_input_variance: Variance,
+    rhs_input_variance: Variance,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    plaintext_modulus: f64,
+    modulus: f64,
+) -> Variance {
+    Variance(glwe_tensor_product_and_rescale_variance_binary_key_impl(
+        lhs_input_variance.0,
+        rhs_input_variance.0,
+        glwe_dimension.0 as f64,
+        polynomial_size.0 as f64,
+        plaintext_modulus,
+        modulus,
+    ))
+}
+
+/// This formula is only valid for uniform binary secret keys, messages uniformly distributed in
+/// the plaintext space and input variances expressed on the torus.
+pub fn glwe_tensor_product_and_rescale_variance_binary_key_impl(
+    lhs_input_variance: f64,
+    rhs_input_variance: f64,
+    glwe_dimension: f64,
+    polynomial_size: f64,
+    plaintext_modulus: f64,
+    modulus: f64,
+) -> f64 {
+    polynomial_size
+        * plaintext_modulus.powf(2.0)
+        * (lhs_input_variance + rhs_input_variance)
+        * ((1_f64 / 3.0) + (1_f64 / 24.0) * glwe_dimension * polynomial_size + (1_f64 / 12.0))
+        + polynomial_size * plaintext_modulus.powf(2.0) * lhs_input_variance * rhs_input_variance
+        + (1_f64 / 12.0)
+            * modulus.powf(-2.0)
+            * (1.0
+                + 0.5 * glwe_dimension * polynomial_size
+                + 0.5
+                    * glwe_dimension
+                    * (glwe_dimension + 1.0)
+                    * polynomial_size
+                    * ((1_f64 / 48.0) * polynomial_size.powf(2.0)
+                        + (3_f64 / 16.0) * polynomial_size))
+}
+
+/// This formula is only valid for uniform binary secret keys and a relinearization key variance
+/// expressed on the torus.
+pub fn glwe_relinearization_additive_variance_binary_key(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    relinearization_key_variance: Variance,
+    modulus: f64,
+) -> Variance {
+    Variance(glwe_relinearization_additive_variance_binary_key_impl(
+        glwe_dimension.0 as f64,
+        polynomial_size.0 as f64,
+        2.0f64.powi(decomposition_base_log.0 as i32),
+        decomposition_level_count.0 as f64,
+        relinearization_key_variance.0,
+        modulus,
+    ))
+}
+
+/// This formula is only valid for uniform binary secret keys and a relinearization key variance
+/// expressed on the torus.
+pub fn glwe_relinearization_additive_variance_binary_key_impl(
+    glwe_dimension: f64,
+    polynomial_size: f64,
+    decomposition_base: f64,
+    decomposition_level_count: f64,
+    relinearization_key_variance: f64,
+    modulus: f64,
+) -> f64 {
+    0.5 * glwe_dimension
+        * (glwe_dimension + 1.0)
+        * polynomial_size
+        * (decomposition_level_count
+            * relinearization_key_variance
+            * ((1_f64 / 12.0) * decomposition_base.powf(2.0) + 0.166666666666667)
+            + ((1_f64 / 48.0) * polynomial_size.powf(2.0) + (3_f64 / 16.0) * polynomial_size)
+                * ((1_f64 / 6.0) * modulus.powf(-2.0)
+                    + (1_f64 / 12.0) * decomposition_base.powf(-2.0 * decomposition_level_count)))
+}
@@ -1,5 +1,6 @@
 // This file was autogenerated, do not modify by hand.
 pub mod generalized_modulus_switch;
+pub mod glwe_tensor_product;
 pub mod lwe_keyswitch;
 pub mod lwe_multi_bit_programmable_bootstrap;
 pub mod lwe_packing_keyswitch;
@@ -207,6 +207,13 @@ pub struct PolynomialCount(pub usize);
 #[versionize(MonomialDegreeVersions)]
 pub struct MonomialDegree(pub usize);
 
+/// The exponent $k$ of a ring automorphism $X \mapsto X^k$ of $\mathbb{Z}\[X\]/(X^N+1)$.
+///
+/// The exponent must be odd and smaller than $2N$ for the map to be an automorphism.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(AutomorphismExponentVersions)]
+pub struct AutomorphismExponent(pub usize);
+
 /// The logarithm of the base used in a decomposition.
 ///
 /// When decomposing an integer over powers of the $2^B$ basis, this type represents the $B$ value.
@@ -0,0 +1,385 @@
+//! Module containing the definition of the [`GlweAutomorphismKey`].
+
+use crate::core_crypto::backward_compatibility::entities::glwe_automorphism_key::GlweAutomorphismKeyVersions;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use tfhe_versionable::Versionize;
+
+/// A [`GLWE automorphism key`](`GlweAutomorphismKey`).
+///
+/// # Formal Definition
+///
+/// ## Automorphisms
+///
+/// For an odd integer $k$ in $[1, 2N)$ the map $\tau\_k: X \mapsto X^k$ is an automorphism of the
+/// ring $R\_q = \mathbb{Z}\_q\[X\]/(X^N+1)$. Applying $\tau\_k$ to every polynomial of a
+/// [`GLWE ciphertext`](`crate::core_crypto::entities::GlweCiphertext`)
+/// $\mathsf{CT} = \left( \vec{A} , B\right) \in \mathsf{GLWE}\_{\vec{S}}( \mathsf{PT} )$ yields a
+/// GLWE ciphertext of $\tau\_k(\mathsf{PT})$ under the key $\tau\_k(\vec{S})$.
+///
+/// ## Automorphism Key
+///
+/// An automorphism key for the exponent $k$ is a
+/// [`GLWE keyswitch key`](`crate::core_crypto::entities::GlweKeyswitchKey`) from
+/// $\tau\_k(\vec{S})$ to $\vec{S}$:
+///
+/// $$\mathsf{AK}\_{k} = \mathsf{KSK}\_{\tau\_k(\vec{S})\rightarrow \vec{S}}$$
+///
+/// ## Homomorphic Automorphism
+///
+/// ###### inputs:
+/// - $\mathsf{CT}\_{\mathsf{in}} = \left( \vec{A}\_{\mathsf{in}} , B\_{\mathsf{in}}\right) \in
+///   \mathsf{GLWE}\_{\vec{S}}( \mathsf{PT} )$: a [`GLWE ciphertext`](`GlweCiphertext`)
+/// - $\mathsf{AK}\_{k}$: an automorphism key
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}} \in \mathsf{GLWE}\_{\vec{S}} \left( \tau\_k(\mathsf{PT})
+///   \right)$: a [`GLWE ciphertext`](`crate::core_crypto::entities::GlweCiphertext`)
+///
+/// ###### algorithm:
+/// 1. compute $\mathsf{CT} = \left( \tau\_k(\vec{A}\_{\mathsf{in}}) , \tau\_k(B\_{\mathsf{in}})
+///    \right) \in \mathsf{GLWE}\_{\tau\_k(\vec{S})}( \tau\_k(\mathsf{PT}) )$
+/// 2. output $\mathsf{CT}\_{\mathsf{out}} = \mathsf{keyswitch}\left(\mathsf{CT} , \mathsf{AK}\_{k}
+///    \right)$
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(GlweAutomorphismKeyVersions)]
+pub struct GlweAutomorphismKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    automorphism_exponent: AutomorphismExponent,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweAutomorphismKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for GlweAutomorphismKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+/// Return the number of elements in a [`GlweAutomorphismKey`] given a [`DecompositionLevelCount`],
+/// [`GlweSize`] and [`PolynomialSize`].
+pub fn glwe_automorphism_key_size(
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> usize {
+    // One GLev ciphertext per polynomial of the secret key
+    glwe_size.to_glwe_dimension().0
+        * glwe_keyswitch_key_input_key_element_encrypted_size(
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+        )
+}
+
+/// Panic if the given [`AutomorphismExponent`] does not define an automorphism of the ring
+/// $\mathbb{Z}\[X\]/(X^N+1)$ for the given [`PolynomialSize`].
+pub(crate) fn assert_valid_automorphism_exponent(
+    automorphism_exponent: AutomorphismExponent,
+    polynomial_size: PolynomialSize,
+) {
+    assert!(
+        automorphism_exponent.0 % 2 == 1 && automorphism_exponent.0 < 2 * polynomial_size.0,
+        "The automorphism exponent must be odd and smaller than 2 * polynomial_size, \
+        got exponent: {automorphism_exponent:?} and polynomial_size: {polynomial_size:?}."
+    );
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweAutomorphismKey<C> {
+    /// Create a [`GlweAutomorphismKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`GlweAutomorphismKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_automorphism_key`] using this key as
+    /// output.
+    ///
+    /// This docstring exhibits [`GlweAutomorphismKey`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlweAutomorphismKey creation
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let decomp_base_log = DecompositionBaseLog(4);
+    /// let decomp_level_count = DecompositionLevelCount(5);
+    /// let automorphism_exponent = AutomorphismExponent(3);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new GlweAutomorphismKey
+    /// let automorphism_key = GlweAutomorphismKey::new(
+    ///     0u64,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     automorphism_exponent,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(automorphism_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_level_count(),
+    ///     decomp_level_count
+    /// );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.glwe_size(), glwe_dimension.to_glwe_size());
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     automorphism_key.automorphism_exponent(),
+    ///     automorphism_exponent
+    /// );
+    /// assert_eq!(automorphism_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = automorphism_key.into_container();
+    ///
+    /// // Recreate a key using from_container
+    /// let automorphism_key = GlweAutomorphismKey::from_container(
+    ///     underlying_container,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     automorphism_exponent,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(automorphism_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_level_count(),
+    ///     decomp_level_count
+    /// );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.glwe_size(), glwe_dimension.to_glwe_size());
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     automorphism_key.automorphism_exponent(),
+    ///     automorphism_exponent
+    /// );
+    /// assert_eq!(automorphism_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // The key can be used wherever a GlweKeyswitchKey is expected
+    /// let ksk = automorphism_key.as_glwe_keyswitch_key();
+    /// assert_eq!(ksk.input_key_glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ksk.output_key_glwe_dimension(), glwe_dimension);
+    /// ```
+    pub fn from_container(
+        container: C,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        automorphism_exponent: AutomorphismExponent,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert_valid_automorphism_exponent(automorphism_exponent, polynomial_size);
+        assert!(
+            container.container_len()
+                == glwe_automorphism_key_size(decomp_level_count, glwe_size, polynomial_size),
+            "The provided container length is not valid. \
+        It needs to be equal to glwe_dimension * decomp_level_count * glwe_size * poly_size: {}. \
+        Got container length: {} and decomp_level_count: {decomp_level_count:?}, \
+        glwe_size: {glwe_size:?}, polynomial_size: {polynomial_size:?}.",
+            glwe_automorphism_key_size(decomp_level_count, glwe_size, polynomial_size),
+            container.container_len()
+        );
+
+        Self {
+            data: container,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            automorphism_exponent,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Return the [`GlweDimension`] of the [`GlweSecretKey`] the [`GlweAutomorphismKey`] works
+    /// with.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Return the [`GlweSize`] of the ciphertexts the [`GlweAutomorphismKey`] works with.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Return the [`PolynomialSize`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Return the [`AutomorphismExponent`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn automorphism_exponent(&self) -> AutomorphismExponent {
+        self.automorphism_exponent
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+
+    /// Return a view of the [`GlweAutomorphismKey`]. This is useful if an algorithm takes a view
+    /// by value.
+    pub fn as_view(&self) -> GlweAutomorphismKey<&'_ [Scalar]> {
+        GlweAutomorphismKey::from_container(
+            self.as_ref(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.glwe_size,
+            self.polynomial_size,
+            self.automorphism_exponent,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Return a view of the [`GlweAutomorphismKey`] as a [`GlweKeyswitchKey`] from
+    /// $\tau\_k(\vec{S})$ to $\vec{S}$.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn as_glwe_keyswitch_key(&self) -> GlweKeyswitchKeyView<'_, Scalar> {
+        GlweKeyswitchKeyView::from_container(
+            self.as_ref(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.glwe_size,
+            self.polynomial_size,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.data
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlweAutomorphismKey<C> {
+    /// Mutable variant of [`GlweAutomorphismKey::as_view`].
+    pub fn as_mut_view(&mut self) -> GlweAutomorphismKey<&'_ mut [Scalar]> {
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let glwe_size = self.glwe_size;
+        let polynomial_size = self.polynomial_size;
+        let automorphism_exponent = self.automorphism_exponent;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        GlweAutomorphismKey::from_container(
+            self.as_mut(),
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            automorphism_exponent,
+            ciphertext_modulus,
+        )
+    }
+
+    /// Mutable variant of [`GlweAutomorphismKey::as_glwe_keyswitch_key`].
+    pub fn as_mut_glwe_keyswitch_key(&mut self) -> GlweKeyswitchKeyMutView<'_, Scalar> {
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let glwe_size = self.glwe_size;
+        let polynomial_size = self.polynomial_size;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        GlweKeyswitchKeyMutView::from_container(
+            self.as_mut(),
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+}
+
+/// A [`GlweAutomorphismKey`] owning the memory for its own storage.
+pub type GlweAutomorphismKeyOwned<Scalar> = GlweAutomorphismKey<Vec<Scalar>>;
+/// A [`GlweAutomorphismKey`] immutably borrowing memory for its own storage.
+pub type GlweAutomorphismKeyView<'data, Scalar> = GlweAutomorphismKey<&'data [Scalar]>;
+/// A [`GlweAutomorphismKey`] mutably borrowing memory for its own storage.
+pub type GlweAutomorphismKeyMutView<'data, Scalar> = GlweAutomorphismKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> GlweAutomorphismKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`GlweAutomorphismKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`GlweAutomorphismKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_automorphism_key`] using this key as
+    /// output.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        automorphism_exponent: AutomorphismExponent,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                glwe_automorphism_key_size(
+                    decomp_level_count,
+                    glwe_dimension.to_glwe_size(),
+                    polynomial_size
+                )
+            ],
+            decomp_base_log,
+            decomp_level_count,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            automorphism_exponent,
+            ciphertext_modulus,
+        )
+    }
+}
@@ -0,0 +1,367 @@
+//! Module containing the definition of the [`GlweRelinearizationKey`].
+
+use crate::core_crypto::backward_compatibility::entities::glwe_relinearization_key::GlweRelinearizationKeyVersions;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use tfhe_versionable::Versionize;
+
+/// A [`GLWE relinearization key`](`GlweRelinearizationKey`).
+///
+/// # Formal Definition
+///
+/// ## Tensor Product
+///
+/// Given two [`GLWE ciphertexts`](`crate::core_crypto::entities::GlweCiphertext`)
+/// $\mathsf{CT} = \left( \vec{A} , B\right) \in \mathsf{GLWE}\_{\vec{S}}( \mathsf{PT} )$ and
+/// $\mathsf{CT}' = \left( \vec{A'} , B'\right) \in \mathsf{GLWE}\_{\vec{S}}( \mathsf{PT}' )$, their
+/// tensor product is a GLWE ciphertext of $\mathsf{PT} \cdot \mathsf{PT}'$ (once rescaled) under
+/// the tensored key made of the $k$ polynomials $S\_i$ followed by the $\frac{k(k+1)}{2}$
+/// polynomials $-S\_i \cdot S\_j$ for $i \leq j$.
+///
+/// ## Relinearization Key
+///
+/// A relinearization key is a
+/// [`GLWE keyswitch key`](`crate::core_crypto::entities::GlweKeyswitchKey`) from the quadratic
+/// part of the tensored key back to $\vec{S}$, i.e. for each $i \leq j$ a GLev ciphertext of
+/// $-S\_i \cdot S\_j$ under $\vec{S}$:
+///
+/// $$\mathsf{RLK} = \mathsf{KSK}\_{\left(-S\_i \cdot S\_j\right)\_{i \leq j}\rightarrow \vec{S}}$$
+///
+/// ## Relinearization
+///
+/// ###### inputs:
+/// - $\mathsf{CT}\_{\otimes} = \left( T\_1, \ldots, T\_k, T\_{1,1}, T\_{1,2}, \ldots, T\_{k,k},
+///   T\_0\right)$: the output of a tensor product
+/// - $\mathsf{RLK}$: a relinearization key
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}} \in \mathsf{GLWE}\_{\vec{S}} \left( \mathsf{PT} \cdot
+///   \mathsf{PT}' \right)$: a [`GLWE ciphertext`](`crate::core_crypto::entities::GlweCiphertext`)
+///
+/// ###### algorithm:
+/// 1. keyswitch the quadratic part $\left( T\_{1,1}, \ldots, T\_{k,k}, T\_0\right)$ using
+///    $\mathsf{RLK}$ to get $\left( \vec{A}\_{\mathsf{ks}} , B\_{\mathsf{ks}}\right)$
+/// 2. output $\mathsf{CT}\_{\mathsf{out}} = \left( \vec{A}\_{\mathsf{ks}} + \left( T\_1, \ldots,
+///    T\_k\right), B\_{\mathsf{ks}}\right)$
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(GlweRelinearizationKeyVersions)]
+pub struct GlweRelinearizationKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweRelinearizationKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for GlweRelinearizationKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+/// Return the number of products $S\_i \cdot S\_j, i \leq j$ of polynomials of a
+/// [`GlweSecretKey`] with the given [`GlweDimension`].
+pub fn glwe_relinearization_key_input_key_polynomial_count(glwe_dimension: GlweDimension) -> usize {
+    glwe_dimension.0 * (glwe_dimension.0 + 1) / 2
+}
+
+/// Return the number of elements in a [`GlweRelinearizationKey`] given a
+/// [`DecompositionLevelCount`], [`GlweSize`] and [`PolynomialSize`].
+pub fn glwe_relinearization_key_size(
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> usize {
+    // One GLev ciphertext per product of polynomials of the secret key
+    glwe_relinearization_key_input_key_polynomial_count(glwe_size.to_glwe_dimension())
+        * glwe_keyswitch_key_input_key_element_encrypted_size(
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+        )
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweRelinearizationKey<C> {
+    /// Create a [`GlweRelinearizationKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`GlweRelinearizationKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_relinearization_key`] using this key as
+    /// output.
+    ///
+    /// This docstring exhibits [`GlweRelinearizationKey`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlweRelinearizationKey creation
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let decomp_base_log = DecompositionBaseLog(4);
+    /// let decomp_level_count = DecompositionLevelCount(5);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new GlweRelinearizationKey
+    /// let relinearization_key = GlweRelinearizationKey::new(
+    ///     0u64,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     relinearization_key.decomposition_base_log(),
+    ///     decomp_base_log
+    /// );
+    /// assert_eq!(
+    ///     relinearization_key.decomposition_level_count(),
+    ///     decomp_level_count
+    /// );
+    /// assert_eq!(relinearization_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(
+    ///     relinearization_key.glwe_size(),
+    ///     glwe_dimension.to_glwe_size()
+    /// );
+    /// assert_eq!(relinearization_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(relinearization_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = relinearization_key.into_container();
+    ///
+    /// // Recreate a key using from_container
+    /// let relinearization_key = GlweRelinearizationKey::from_container(
+    ///     underlying_container,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     relinearization_key.decomposition_base_log(),
+    ///     decomp_base_log
+    /// );
+    /// assert_eq!(
+    ///     relinearization_key.decomposition_level_count(),
+    ///     decomp_level_count
+    /// );
+    /// assert_eq!(relinearization_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(
+    ///     relinearization_key.glwe_size(),
+    ///     glwe_dimension.to_glwe_size()
+    /// );
+    /// assert_eq!(relinearization_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(relinearization_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // The key can be used wherever a GlweKeyswitchKey is expected, its input key is made of
+    /// // the k(k+1)/2 products of the secret key polynomials
+    /// let ksk = relinearization_key.as_glwe_keyswitch_key();
+    /// assert_eq!(ksk.input_key_glwe_dimension(), GlweDimension(3));
+    /// assert_eq!(ksk.output_key_glwe_dimension(), glwe_dimension);
+    /// ```
+    pub fn from_container(
+        container: C,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            container.container_len()
+                == glwe_relinearization_key_size(decomp_level_count, glwe_size, polynomial_size),
+            "The provided container length is not valid. \
+        It needs to be equal to glwe_dimension * (glwe_dimension + 1) / 2 * decomp_level_count \
+        * glwe_size * poly_size: {}. \
+        Got container length: {} and decomp_level_count: {decomp_level_count:?}, \
+        glwe_size: {glwe_size:?}, polynomial_size: {polynomial_size:?}.",
+            glwe_relinearization_key_size(decomp_level_count, glwe_size, polynomial_size),
+            container.container_len()
+        );
+
+        Self {
+            data: container,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the [`GlweRelinearizationKey`].
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the [`GlweRelinearizationKey`].
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Return the [`GlweDimension`] of the [`GlweSecretKey`] the [`GlweRelinearizationKey`]
+    /// works with.
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Return the [`GlweSize`] of the ciphertexts output by the relinearization.
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Return the [`PolynomialSize`] of the [`GlweRelinearizationKey`].
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`GlweRelinearizationKey`].
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+
+    /// Return a view of the [`GlweRelinearizationKey`]. This is useful if an algorithm takes a
+    /// view by value.
+    pub fn as_view(&self) -> GlweRelinearizationKey<&'_ [Scalar]> {
+        GlweRelinearizationKey::from_container(
+            self.as_ref(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.glwe_size,
+            self.polynomial_size,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Return a view of the [`GlweRelinearizationKey`] as a [`GlweKeyswitchKey`] from the
+    /// polynomials $\left(-S\_i \cdot S\_j\right)\_{i \leq j}$ to $\vec{S}$.
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn as_glwe_keyswitch_key(&self) -> GlweKeyswitchKeyView<'_, Scalar> {
+        GlweKeyswitchKeyView::from_container(
+            self.as_ref(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.glwe_size,
+            self.polynomial_size,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.data
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlweRelinearizationKey<C> {
+    /// Mutable variant of [`GlweRelinearizationKey::as_view`].
+    pub fn as_mut_view(&mut self) -> GlweRelinearizationKey<&'_ mut [Scalar]> {
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let glwe_size = self.glwe_size;
+        let polynomial_size = self.polynomial_size;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        GlweRelinearizationKey::from_container(
+            self.as_mut(),
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+
+    /// Mutable variant of [`GlweRelinearizationKey::as_glwe_keyswitch_key`].
+    pub fn as_mut_glwe_keyswitch_key(&mut self) -> GlweKeyswitchKeyMutView<'_, Scalar> {
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let glwe_size = self.glwe_size;
+        let polynomial_size = self.polynomial_size;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        GlweKeyswitchKeyMutView::from_container(
+            self.as_mut(),
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+}
+
+/// A [`GlweRelinearizationKey`] owning the memory for its own storage.
+pub type GlweRelinearizationKeyOwned<Scalar> = GlweRelinearizationKey<Vec<Scalar>>;
+/// A [`GlweRelinearizationKey`] immutably borrowing memory for its own storage.
+pub type GlweRelinearizationKeyView<'data, Scalar> = GlweRelinearizationKey<&'data [Scalar]>;
+/// A [`GlweRelinearizationKey`] mutably borrowing memory for its own storage.
+pub type GlweRelinearizationKeyMutView<'data, Scalar> = GlweRelinearizationKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> GlweRelinearizationKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`GlweRelinearizationKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`GlweRelinearizationKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_relinearization_key`] using this key as
+    /// output.
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                glwe_relinearization_key_size(
+                    decomp_level_count,
+                    glwe_dimension.to_glwe_size(),
+                    polynomial_size
+                )
+            ],
+            decomp_base_log,
+            decomp_level_count,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+}
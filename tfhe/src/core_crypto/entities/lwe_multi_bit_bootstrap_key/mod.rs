@@ -2,6 +2,7 @@
 
 pub mod fft128_lwe_multi_bit_bootstrap_key;
 pub mod fft64_lwe_multi_bit_bootstrap_key;
+pub mod ntt128_lwe_multi_bit_bootstrap_key;
 pub mod standard_lwe_multi_bit_bootstrap_key;
 
 pub use fft128_lwe_multi_bit_bootstrap_key::Fourier128LweMultiBitBootstrapKey;
@@ -9,6 +10,11 @@ pub use fft64_lwe_multi_bit_bootstrap_key::{
     FourierLweMultiBitBootstrapKey, FourierLweMultiBitBootstrapKeyMutView,
     FourierLweMultiBitBootstrapKeyOwned, FourierLweMultiBitBootstrapKeyView,
 };
+pub use ntt128_lwe_multi_bit_bootstrap_key::{
+    ntt128_lwe_multi_bit_bootstrap_key_size, Ntt128LweMultiBitBootstrapKey,
+    Ntt128LweMultiBitBootstrapKeyMutView, Ntt128LweMultiBitBootstrapKeyOwned,
+    Ntt128LweMultiBitBootstrapKeyView,
+};
 pub use standard_lwe_multi_bit_bootstrap_key::{
     lwe_multi_bit_bootstrap_key_fork_config, LweMultiBitBootstrapKey, LweMultiBitBootstrapKeyOwned,
     MultiBitBootstrapKeyConformanceParams,
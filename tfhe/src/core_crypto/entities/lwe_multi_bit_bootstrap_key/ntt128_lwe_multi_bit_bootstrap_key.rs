@@ -0,0 +1,192 @@
+use super::equivalent_multi_bit_lwe_dimension;
+use crate::core_crypto::backward_compatibility::entities::lwe_multi_bit_bootstrap_key::Ntt128LweMultiBitBootstrapKeyVersions;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweBskGroupingFactor, LweDimension,
+    PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{Container, ContainerMut, Split};
+use crate::core_crypto::entities::ntt128_ggsw_ciphertext::{
+    ntt128_ggsw_ciphertext_size, Ntt128GgswCiphertext,
+};
+
+use aligned_vec::{avec, ABox};
+use tfhe_versionable::Versionize;
+
+/// An [`LWE multi bit bootstrap key`](`crate::core_crypto::entities::LweMultiBitBootstrapKey`)
+/// with 128 bits coefficients in the NTT domain, see [`Ntt128GgswCiphertext`] for the layout of
+/// the GGSW ciphertexts it contains.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(Ntt128LweMultiBitBootstrapKeyVersions)]
+pub struct Ntt128LweMultiBitBootstrapKey<C: Container<Element = u64>> {
+    data: C,
+    polynomial_size: PolynomialSize,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+}
+
+pub fn ntt128_lwe_multi_bit_bootstrap_key_size(
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+) -> Result<usize, &'static str> {
+    let equivalent_multi_bit_lwe_dimension =
+        equivalent_multi_bit_lwe_dimension(input_lwe_dimension, grouping_factor)?;
+    let ggsw_count =
+        equivalent_multi_bit_lwe_dimension.0 * grouping_factor.ggsw_per_multi_bit_element().0;
+
+    Ok(ggsw_count * ntt128_ggsw_ciphertext_size(glwe_size, polynomial_size, decomp_level_count))
+}
+
+impl<C: Container<Element = u64>> Ntt128LweMultiBitBootstrapKey<C> {
+    pub fn from_container(
+        data: C,
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+    ) -> Self {
+        let container_len = ntt128_lwe_multi_bit_bootstrap_key_size(
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_level_count,
+            grouping_factor,
+        )
+        .unwrap();
+        assert_eq!(data.container_len(), container_len);
+
+        Self {
+            data,
+            polynomial_size,
+            input_lwe_dimension,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            grouping_factor,
+        }
+    }
+
+    /// Return an iterator over the GGSW ciphertexts composing the key.
+    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = Ntt128GgswCiphertext<C>>
+    where
+        C: Split,
+    {
+        let ggsw_count = self.multi_bit_input_lwe_dimension().0
+            * self.grouping_factor.ggsw_per_multi_bit_element().0;
+
+        self.data.split_into(ggsw_count).map(move |slice| {
+            Ntt128GgswCiphertext::from_container(
+                slice,
+                self.glwe_size,
+                self.polynomial_size,
+                self.decomposition_base_log,
+                self.decomposition_level_count,
+            )
+        })
+    }
+
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        self.glwe_size
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(self.polynomial_size)
+    }
+
+    pub fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.grouping_factor
+    }
+
+    pub fn multi_bit_input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.input_lwe_dimension.0 / self.grouping_factor.0)
+    }
+
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> Ntt128LweMultiBitBootstrapKeyView<'_> {
+        Ntt128LweMultiBitBootstrapKey {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            input_lwe_dimension: self.input_lwe_dimension,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            grouping_factor: self.grouping_factor,
+        }
+    }
+}
+
+impl<C: ContainerMut<Element = u64>> Ntt128LweMultiBitBootstrapKey<C> {
+    pub fn as_mut_view(&mut self) -> Ntt128LweMultiBitBootstrapKeyMutView<'_> {
+        Ntt128LweMultiBitBootstrapKey {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+            input_lwe_dimension: self.input_lwe_dimension,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            grouping_factor: self.grouping_factor,
+        }
+    }
+}
+
+pub type Ntt128LweMultiBitBootstrapKeyOwned = Ntt128LweMultiBitBootstrapKey<ABox<[u64]>>;
+pub type Ntt128LweMultiBitBootstrapKeyView<'a> = Ntt128LweMultiBitBootstrapKey<&'a [u64]>;
+pub type Ntt128LweMultiBitBootstrapKeyMutView<'a> = Ntt128LweMultiBitBootstrapKey<&'a mut [u64]>;
+
+impl Ntt128LweMultiBitBootstrapKey<ABox<[u64]>> {
+    pub fn new(
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+    ) -> Self {
+        let container_len = ntt128_lwe_multi_bit_bootstrap_key_size(
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_level_count,
+            grouping_factor,
+        )
+        .unwrap();
+
+        Self::from_container(
+            avec![0u64; container_len].into_boxed_slice(),
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            grouping_factor,
+        )
+    }
+}
@@ -9,9 +9,11 @@ pub mod compressed_modulus_switched_lwe_ciphertext;
 pub mod compressed_modulus_switched_multi_bit_lwe_ciphertext;
 pub mod ggsw_ciphertext;
 pub mod ggsw_ciphertext_list;
+pub mod glwe_automorphism_key;
 pub mod glwe_ciphertext;
 pub mod glwe_ciphertext_list;
 pub mod glwe_keyswitch_key;
+pub mod glwe_relinearization_key;
 pub mod glwe_secret_key;
 pub mod gsw_ciphertext;
 pub mod lwe_bootstrap_key;
@@ -29,6 +31,8 @@ pub mod lwe_private_functional_packing_keyswitch_key_list;
 pub mod lwe_public_key;
 pub mod lwe_secret_key;
 pub mod modulus_switched_lwe_ciphertext;
+pub mod ntt128_ggsw_ciphertext;
+pub mod ntt128_lwe_bootstrap_key;
 pub mod ntt_ggsw_ciphertext;
 pub mod ntt_ggsw_ciphertext_list;
 pub mod ntt_lwe_bootstrap_key;
@@ -39,6 +43,7 @@ pub mod polynomial;
 pub mod polynomial_list;
 pub mod seeded_ggsw_ciphertext;
 pub mod seeded_ggsw_ciphertext_list;
+pub mod seeded_glwe_automorphism_key;
 pub mod seeded_glwe_ciphertext;
 pub mod seeded_glwe_ciphertext_list;
 pub mod seeded_lwe_bootstrap_key;
@@ -72,9 +77,11 @@ pub use compressed_modulus_switched_lwe_ciphertext::*;
 pub use compressed_modulus_switched_multi_bit_lwe_ciphertext::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_list::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_list::*;
 pub use glwe_keyswitch_key::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
 pub use gsw_ciphertext::*;
 pub use lwe_bootstrap_key::*;
@@ -92,6 +99,8 @@ pub use lwe_private_functional_packing_keyswitch_key_list::*;
 pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
 pub use modulus_switched_lwe_ciphertext::*;
+pub use ntt128_ggsw_ciphertext::*;
+pub use ntt128_lwe_bootstrap_key::*;
 pub use ntt_ggsw_ciphertext::*;
 pub use ntt_ggsw_ciphertext_list::*;
 pub use ntt_lwe_bootstrap_key::*;
@@ -101,6 +110,7 @@ pub use polynomial::*;
 pub use polynomial_list::*;
 pub use seeded_ggsw_ciphertext::*;
 pub use seeded_ggsw_ciphertext_list::*;
+pub use seeded_glwe_automorphism_key::*;
 pub use seeded_glwe_ciphertext::*;
 pub use seeded_glwe_ciphertext_list::*;
 pub use seeded_lwe_bootstrap_key::*;
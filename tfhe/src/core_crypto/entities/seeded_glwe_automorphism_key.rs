@@ -0,0 +1,462 @@
+//! Module containing the definition of the [`SeededGlweAutomorphismKey`].
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::backward_compatibility::entities::seeded_glwe_automorphism_key::SeededGlweAutomorphismKeyVersions;
+use crate::core_crypto::commons::math::random::{CompressionSeed, DefaultRandomGenerator};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use tfhe_versionable::Versionize;
+
+/// A [`seeded GLWE automorphism key`](`SeededGlweAutomorphismKey`).
+///
+/// See [`GlweAutomorphismKey`] for the formal definition.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(SeededGlweAutomorphismKeyVersions)]
+pub struct SeededGlweAutomorphismKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    automorphism_exponent: AutomorphismExponent,
+    compression_seed: CompressionSeed,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for SeededGlweAutomorphismKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for SeededGlweAutomorphismKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+/// Return the number of elements in an encryption of an input [`GlweSecretKey`] polynomial for a
+/// [`SeededGlweAutomorphismKey`] given a [`DecompositionLevelCount`] and [`PolynomialSize`].
+pub fn seeded_glwe_automorphism_key_input_key_element_encrypted_size(
+    decomp_level_count: DecompositionLevelCount,
+    polynomial_size: PolynomialSize,
+) -> usize {
+    // One seeded ciphertext per level
+    decomp_level_count.0 * polynomial_size.0
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> SeededGlweAutomorphismKey<C> {
+    /// Create a [`SeededGlweAutomorphismKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`SeededGlweAutomorphismKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_seeded_glwe_automorphism_key`] using this key as
+    /// output.
+    ///
+    /// This docstring exhibits [`SeededGlweAutomorphismKey`] primitives usage.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for SeededGlweAutomorphismKey creation
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let decomp_base_log = DecompositionBaseLog(4);
+    /// let decomp_level_count = DecompositionLevelCount(5);
+    /// let automorphism_exponent = AutomorphismExponent(5);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Get a seeder
+    /// let mut seeder = new_seeder();
+    /// let seeder = seeder.as_mut();
+    ///
+    /// // Create a new SeededGlweAutomorphismKey
+    /// let automorphism_key = SeededGlweAutomorphismKey::new(
+    ///     0u64,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     automorphism_exponent,
+    ///     seeder.seed().into(),
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(automorphism_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_level_count(),
+    ///     decomp_level_count
+    /// );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.glwe_size(), glwe_dimension.to_glwe_size());
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     automorphism_key.automorphism_exponent(),
+    ///     automorphism_exponent
+    /// );
+    /// assert_eq!(automorphism_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// let compression_seed = automorphism_key.compression_seed();
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = automorphism_key.into_container();
+    ///
+    /// // Recreate a key using from_container
+    /// let automorphism_key = SeededGlweAutomorphismKey::from_container(
+    ///     underlying_container,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     automorphism_exponent,
+    ///     compression_seed,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(automorphism_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_level_count(),
+    ///     decomp_level_count
+    /// );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     automorphism_key.automorphism_exponent(),
+    ///     automorphism_exponent
+    /// );
+    /// assert_eq!(automorphism_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// let automorphism_key = automorphism_key.decompress_into_glwe_automorphism_key();
+    ///
+    /// assert_eq!(automorphism_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_level_count(),
+    ///     decomp_level_count
+    /// );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     automorphism_key.automorphism_exponent(),
+    ///     automorphism_exponent
+    /// );
+    /// assert_eq!(automorphism_key.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_container(
+        container: C,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        automorphism_exponent: AutomorphismExponent,
+        compression_seed: CompressionSeed,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            ciphertext_modulus.is_compatible_with_native_modulus(),
+            "Seeded entities are not yet compatible with non power of 2 moduli."
+        );
+        assert_valid_automorphism_exponent(automorphism_exponent, polynomial_size);
+        let expected_len = glwe_size.to_glwe_dimension().0
+            * seeded_glwe_automorphism_key_input_key_element_encrypted_size(
+                decomp_level_count,
+                polynomial_size,
+            );
+        assert!(
+            container.container_len() == expected_len,
+            "The provided container length is not valid. \
+            It needs to be equal to glwe_dimension * decomp_level_count * polynomial_size: \
+            {expected_len}. Got container length: {} decomp_level_count: \
+            {decomp_level_count:?}, glwe_size: {glwe_size:?} \
+            and polynomial_size {polynomial_size:?}.",
+            container.container_len()
+        );
+
+        Self {
+            data: container,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            automorphism_exponent,
+            compression_seed,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the [`SeededGlweAutomorphismKey`].
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the [`SeededGlweAutomorphismKey`].
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Return the [`GlweDimension`] of the [`GlweSecretKey`] the [`SeededGlweAutomorphismKey`]
+    /// works with.
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Return the [`GlweSize`] of the ciphertexts the [`SeededGlweAutomorphismKey`] works with.
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Return the [`PolynomialSize`] of the [`SeededGlweAutomorphismKey`].
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Return the [`AutomorphismExponent`] of the [`SeededGlweAutomorphismKey`].
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    pub fn automorphism_exponent(&self) -> AutomorphismExponent {
+        self.automorphism_exponent
+    }
+
+    /// Return the [`CompressionSeed`] of the [`SeededGlweAutomorphismKey`].
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`SeededGlweAutomorphismKey`].
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+
+    /// Return the number of elements in an encryption of an input [`GlweSecretKey`] polynomial
+    /// of the current [`SeededGlweAutomorphismKey`].
+    pub fn seeded_input_key_element_encrypted_size(&self) -> usize {
+        seeded_glwe_automorphism_key_input_key_element_encrypted_size(
+            self.decomp_level_count,
+            self.polynomial_size,
+        )
+    }
+
+    /// Return a view of the [`SeededGlweAutomorphismKey`]. This is useful if an algorithm takes a
+    /// view by value.
+    pub fn as_view(&self) -> SeededGlweAutomorphismKey<&'_ [Scalar]> {
+        SeededGlweAutomorphismKey::from_container(
+            self.as_ref(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.glwe_size,
+            self.polynomial_size,
+            self.automorphism_exponent,
+            self.compression_seed,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn as_seeded_glwe_ciphertext_list(&self) -> SeededGlweCiphertextListView<'_, Scalar> {
+        SeededGlweCiphertextListView::from_container(
+            self.as_ref(),
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.compression_seed(),
+            self.ciphertext_modulus(),
+        )
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    /// Consume the [`SeededGlweAutomorphismKey`] and decompress it into a standard
+    /// [`GlweAutomorphismKey`].
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    pub fn decompress_into_glwe_automorphism_key(self) -> GlweAutomorphismKeyOwned<Scalar>
+    where
+        Scalar: UnsignedTorus,
+    {
+        let mut decompressed_key = GlweAutomorphismKeyOwned::new(
+            Scalar::ZERO,
+            self.decomposition_base_log(),
+            self.decomposition_level_count(),
+            self.glwe_dimension(),
+            self.polynomial_size(),
+            self.automorphism_exponent(),
+            self.ciphertext_modulus(),
+        );
+        decompress_seeded_glwe_automorphism_key::<_, _, _, DefaultRandomGenerator>(
+            &mut decompressed_key,
+            &self,
+        );
+        decompressed_key
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> SeededGlweAutomorphismKey<C> {
+    /// Mutable variant of [`SeededGlweAutomorphismKey::as_view`].
+    pub fn as_mut_view(&mut self) -> SeededGlweAutomorphismKey<&'_ mut [Scalar]> {
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let glwe_size = self.glwe_size;
+        let polynomial_size = self.polynomial_size;
+        let automorphism_exponent = self.automorphism_exponent;
+        let compression_seed = self.compression_seed;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        SeededGlweAutomorphismKey::from_container(
+            self.as_mut(),
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            automorphism_exponent,
+            compression_seed,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn as_mut_seeded_glwe_ciphertext_list(
+        &mut self,
+    ) -> SeededGlweCiphertextListMutView<'_, Scalar> {
+        let glwe_size = self.glwe_size();
+        let polynomial_size = self.polynomial_size();
+        let compression_seed = self.compression_seed();
+        let ciphertext_modulus = self.ciphertext_modulus();
+        SeededGlweCiphertextListMutView::from_container(
+            self.as_mut(),
+            glwe_size,
+            polynomial_size,
+            compression_seed,
+            ciphertext_modulus,
+        )
+    }
+}
+
+/// A [`SeededGlweAutomorphismKey`] owning the memory for its own storage.
+pub type SeededGlweAutomorphismKeyOwned<Scalar> = SeededGlweAutomorphismKey<Vec<Scalar>>;
+
+impl<Scalar: UnsignedInteger> SeededGlweAutomorphismKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`SeededGlweAutomorphismKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`SeededGlweAutomorphismKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_seeded_glwe_automorphism_key`] using this key as
+    /// output.
+    ///
+    /// See [`SeededGlweAutomorphismKey::from_container`] for usage.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        fill_with: Scalar,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        automorphism_exponent: AutomorphismExponent,
+        compression_seed: CompressionSeed,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                glwe_dimension.0
+                    * seeded_glwe_automorphism_key_input_key_element_encrypted_size(
+                        decomp_level_count,
+                        polynomial_size,
+                    )
+            ],
+            decomp_base_log,
+            decomp_level_count,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            automorphism_exponent,
+            compression_seed,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> ContiguousEntityContainer
+    for SeededGlweAutomorphismKey<C>
+{
+    type Element = C::Element;
+
+    type EntityViewMetadata = SeededGlweCiphertextListCreationMetadata<Self::Element>;
+
+    type EntityView<'this>
+        = SeededGlweCiphertextListView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    type SelfViewMetadata = ();
+
+    // At the moment it does not make sense to return "sub" automorphism keys. So we use a dummy
+    // placeholder type here.
+    type SelfView<'this>
+        = DummyCreateFrom
+    where
+        Self: 'this;
+
+    fn get_entity_view_creation_metadata(
+        &self,
+    ) -> SeededGlweCiphertextListCreationMetadata<Self::Element> {
+        SeededGlweCiphertextListCreationMetadata {
+            glwe_size: self.glwe_size(),
+            polynomial_size: self.polynomial_size(),
+            compression_seed: self.compression_seed(),
+            ciphertext_modulus: self.ciphertext_modulus(),
+        }
+    }
+
+    fn get_entity_view_pod_size(&self) -> usize {
+        self.seeded_input_key_element_encrypted_size()
+    }
+
+    /// Unimplemented for [`SeededGlweAutomorphismKey`]. At the moment it does not make sense to
+    /// return "sub" automorphism keys.
+    fn get_self_view_creation_metadata(&self) -> Self::SelfViewMetadata {
+        unimplemented!(
+            "This function is not supported for SeededGlweAutomorphismKey. \
+        At the moment it does not make sense to return 'sub' automorphism keys."
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntityContainerMut
+    for SeededGlweAutomorphismKey<C>
+{
+    type EntityMutView<'this>
+        = SeededGlweCiphertextListMutView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    // At the moment it does not make sense to return "sub" automorphism keys. So we use a dummy
+    // placeholder type here.
+    type SelfMutView<'this>
+        = DummyCreateFrom
+    where
+        Self: 'this;
+}
@@ -11,3 +11,5 @@ pub mod keys;
 #[cfg(feature = "strings")]
 pub mod strings;
 pub mod tag;
+#[cfg(feature = "transciphering")]
+pub mod transciphering;
@@ -1,7 +1,12 @@
-use crate::FheAsciiString;
+use crate::{FheAsciiString, FheUtf8String};
 use tfhe_versionable::VersionsDispatch;
 
 #[derive(VersionsDispatch)]
 pub enum FheAsciiStringVersions {
     V0(FheAsciiString),
 }
+
+#[derive(VersionsDispatch)]
+pub enum FheUtf8StringVersions {
+    V0(FheUtf8String),
+}
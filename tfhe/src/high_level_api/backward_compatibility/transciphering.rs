@@ -0,0 +1,45 @@
+use crate::high_level_api::transciphering::{
+    ByteRegister, FheCipherKey, FheKreyvium, FheTrivium, Kreyvium, KreyviumRegisters, Trivium,
+    TriviumRegisters,
+};
+use tfhe_versionable::VersionsDispatch;
+
+#[derive(VersionsDispatch)]
+pub enum FheCipherKeyVersions {
+    V0(FheCipherKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum ByteRegisterVersions<T> {
+    V0(ByteRegister<T>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum TriviumRegistersVersions<T> {
+    V0(TriviumRegisters<T>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum TriviumVersions {
+    V0(Trivium),
+}
+
+#[derive(VersionsDispatch)]
+pub enum FheTriviumVersions {
+    V0(FheTrivium),
+}
+
+#[derive(VersionsDispatch)]
+pub enum KreyviumRegistersVersions<T> {
+    V0(KreyviumRegisters<T>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum KreyviumVersions {
+    V0(Kreyvium),
+}
+
+#[derive(VersionsDispatch)]
+pub enum FheKreyviumVersions {
+    V0(FheKreyvium),
+}
@@ -2,10 +2,10 @@ use super::inner::InnerBoolean;
 use crate::backward_compatibility::booleans::FheBoolVersions;
 use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
-use crate::high_level_api::global_state;
 use crate::high_level_api::integers::{FheInt, FheIntId, FheUint, FheUintId};
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::{FheEq, IfThenElse, ScalarIfThenElse, Tagged};
+use crate::high_level_api::{global_state, profiling};
 use crate::integer::block_decomposition::DecomposableInto;
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
@@ -199,6 +199,240 @@ impl FheBool {
     pub fn is_trivial(&self) -> bool {
         self.ciphertext.on_cpu().is_trivial()
     }
+
+    /// Returns the estimated variance of the noise of the ciphertext, i.e. the largest one of its
+    /// blocks, or `None` if it is unknown
+    ///
+    /// See [`noise_tracking`](crate::shortint::noise_tracking) for the details of the estimation.
+    #[cfg(feature = "noise-tracking")]
+    pub fn estimated_noise(&self) -> Option<crate::core_crypto::commons::dispersion::Variance> {
+        crate::shortint::noise_tracking::max_estimated_variance([&self.ciphertext.on_cpu().0])
+    }
+
+    /// Returns the estimated probability that a PBS applied on this FheBool gives a wrong result,
+    /// or `None` if the noise of the ciphertext is unknown
+    ///
+    /// Requires the server key to be set.
+    #[cfg(feature = "noise-tracking")]
+    pub fn failure_probability(&self) -> Option<f64> {
+        crate::high_level_api::utils::failure_probability_of_blocks([&self.ciphertext.on_cpu().0])
+    }
+}
+
+impl FheBool {
+    /// Applies a gate of several inputs implemented by the integer server key
+    fn n_ary_gate<F>(booleans: &[&Self], gate: F) -> Self
+    where
+        F: FnOnce(&crate::integer::ServerKey, &[&BooleanBlock]) -> BooleanBlock,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => {
+                let blocks = booleans
+                    .iter()
+                    .map(|boolean| boolean.ciphertext.on_cpu())
+                    .collect::<Vec<_>>();
+                let refs = blocks.iter().map(|block| &**block).collect::<Vec<_>>();
+                let inner = gate(key.pbs_key(), &refs);
+                Self::new(inner, key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda does not support boolean gates with more than 2 inputs")
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support boolean gates with more than 2 inputs")
+            }
+        })
+    }
+
+    /// Returns whether strictly more than half of the inputs are `true`.
+    ///
+    /// The result is computed with a single bootstrap, the number of inputs is limited by the
+    /// parameters: up to 5 inputs with the default parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `booleans` is empty or has too many elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheBool::encrypt(true, &client_key);
+    /// let b = FheBool::encrypt(false, &client_key);
+    /// let c = FheBool::encrypt(true, &client_key);
+    ///
+    /// let result = FheBool::majority(&[&a, &b, &c]);
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn majority(booleans: &[&Self]) -> Self {
+        let _scope = profiling::scope("majority");
+        Self::n_ary_gate(booleans, |key, blocks| key.boolean_majority(blocks))
+    }
+
+    /// Returns whether at least `threshold` of the inputs are `true`.
+    ///
+    /// The result is computed with a single bootstrap, the number of inputs is limited by the
+    /// parameters: up to 5 inputs with the default parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `booleans` is empty or has too many elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let votes = [true, false, true, true].map(|vote| FheBool::encrypt(vote, &client_key));
+    /// let votes = votes.iter().collect::<Vec<_>>();
+    ///
+    /// let result = FheBool::threshold(&votes, 3);
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn threshold(booleans: &[&Self], threshold: usize) -> Self {
+        let _scope = profiling::scope("threshold");
+        Self::n_ary_gate(booleans, |key, blocks| {
+            key.boolean_threshold(blocks, threshold)
+        })
+    }
+
+    /// Computes `a & b & c` with a single bootstrap
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheBool::encrypt(true, &client_key);
+    /// let b = FheBool::encrypt(true, &client_key);
+    /// let c = FheBool::encrypt(false, &client_key);
+    ///
+    /// let result = FheBool::and3(&a, &b, &c);
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(!decrypted);
+    /// ```
+    pub fn and3(a: &Self, b: &Self, c: &Self) -> Self {
+        let _scope = profiling::scope("and3");
+        Self::n_ary_gate(&[a, b, c], |key, blocks| {
+            key.boolean_bitand3(blocks[0], blocks[1], blocks[2])
+        })
+    }
+
+    /// Computes `a | b | c` with a single bootstrap
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheBool::encrypt(false, &client_key);
+    /// let b = FheBool::encrypt(false, &client_key);
+    /// let c = FheBool::encrypt(true, &client_key);
+    ///
+    /// let result = FheBool::or3(&a, &b, &c);
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn or3(a: &Self, b: &Self, c: &Self) -> Self {
+        let _scope = profiling::scope("or3");
+        Self::n_ary_gate(&[a, b, c], |key, blocks| {
+            key.boolean_bitor3(blocks[0], blocks[1], blocks[2])
+        })
+    }
+
+    /// Computes `a ^ b ^ c` with a single bootstrap
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheBool::encrypt(true, &client_key);
+    /// let b = FheBool::encrypt(true, &client_key);
+    /// let c = FheBool::encrypt(true, &client_key);
+    ///
+    /// let result = FheBool::xor3(&a, &b, &c);
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn xor3(a: &Self, b: &Self, c: &Self) -> Self {
+        let _scope = profiling::scope("xor3");
+        Self::n_ary_gate(&[a, b, c], |key, blocks| {
+            key.boolean_bitxor3(blocks[0], blocks[1], blocks[2])
+        })
+    }
+
+    /// Adds three booleans, returns the sum and the carry
+    ///
+    /// Both outputs are computed with a single bootstrap each, or a single bootstrap in total when
+    /// the parameters allow it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheBool::encrypt(true, &client_key);
+    /// let b = FheBool::encrypt(false, &client_key);
+    /// let carry = FheBool::encrypt(true, &client_key);
+    ///
+    /// let (sum, carry) = FheBool::full_adder(&a, &b, &carry);
+    /// assert!(!sum.decrypt(&client_key));
+    /// assert!(carry.decrypt(&client_key));
+    /// ```
+    pub fn full_adder(a: &Self, b: &Self, carry: &Self) -> (Self, Self) {
+        let _scope = profiling::scope("full_adder");
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => {
+                let (sum, carry) = key.pbs_key().boolean_full_adder(
+                    &a.ciphertext.on_cpu(),
+                    &b.ciphertext.on_cpu(),
+                    &carry.ciphertext.on_cpu(),
+                );
+                (
+                    Self::new(sum, key.tag.clone()),
+                    Self::new(carry, key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda does not support full_adder")
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support full_adder")
+            }
+        })
+    }
 }
 
 impl<Id, Scalar> ScalarIfThenElse<&FheUint<Id>, Scalar> for FheBool
@@ -606,6 +840,7 @@ where
     /// assert!(!decrypted);
     /// ```
     fn eq(&self, other: B) -> Self {
+        let _scope = profiling::scope("eq");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner = key.pbs_key().key.equal(
@@ -653,6 +888,7 @@ where
     /// assert_eq!(decrypted, true != false);
     /// ```
     fn ne(&self, other: B) -> Self {
+        let _scope = profiling::scope("ne");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 let inner = key.pbs_key().key.not_equal(
@@ -1651,6 +1887,7 @@ where
     /// assert!(result);
     /// ```
     fn bitand_assign(&mut self, rhs: B) {
+        let _scope = profiling::scope("bitand_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
@@ -1699,6 +1936,7 @@ where
     /// assert!(result);
     /// ```
     fn bitor_assign(&mut self, rhs: B) {
+        let _scope = profiling::scope("bitor_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
@@ -1747,6 +1985,7 @@ where
     /// assert!(!result);
     /// ```
     fn bitxor_assign(&mut self, rhs: B) {
+        let _scope = profiling::scope("bitxor_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
@@ -1791,6 +2030,7 @@ impl BitAndAssign<bool> for FheBool {
     /// assert_eq!(result, true & false);
     /// ```
     fn bitand_assign(&mut self, rhs: bool) {
+        let _scope = profiling::scope("bitand_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 key.pbs_key()
@@ -1833,6 +2073,7 @@ impl BitOrAssign<bool> for FheBool {
     /// assert_eq!(result, true | false);
     /// ```
     fn bitor_assign(&mut self, rhs: bool) {
+        let _scope = profiling::scope("bitor_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 key.pbs_key()
@@ -1875,6 +2116,7 @@ impl BitXorAssign<bool> for FheBool {
     /// assert_eq!(result, true ^ false);
     /// ```
     fn bitxor_assign(&mut self, rhs: bool) {
+        let _scope = profiling::scope("bitxor_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(key) => {
                 key.pbs_key()
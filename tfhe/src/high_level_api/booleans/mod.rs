@@ -12,6 +12,8 @@ mod base;
 mod compressed;
 mod encrypt;
 mod inner;
+#[cfg(feature = "netlist")]
+mod netlist;
 mod oprf;
 mod squashed_noise;
 #[cfg(test)]
@@ -0,0 +1,103 @@
+use super::inner::InnerBoolean;
+use crate::boolean::netlist::{Gate, Netlist, NetlistBackend};
+use crate::high_level_api::global_state;
+use crate::integer::prelude::*;
+use crate::integer::{BooleanBlock, ServerKey as IntegerServerKey};
+use crate::FheBool;
+use std::collections::HashMap;
+
+impl NetlistBackend for IntegerServerKey {
+    type Bit = BooleanBlock;
+
+    fn trivial(&self, value: bool) -> BooleanBlock {
+        self.create_trivial_boolean_block(value)
+    }
+
+    fn not(&self, bit: &BooleanBlock) -> BooleanBlock {
+        self.boolean_bitnot(bit)
+    }
+
+    fn gate(&self, gate: Gate, lhs: &BooleanBlock, rhs: &BooleanBlock) -> BooleanBlock {
+        match gate {
+            Gate::And => self.boolean_bitand(lhs, rhs),
+            Gate::Nand => self.boolean_bitnot(&self.boolean_bitand(lhs, rhs)),
+            Gate::Or => self.boolean_bitor(lhs, rhs),
+            Gate::Nor => self.boolean_bitnot(&self.boolean_bitor(lhs, rhs)),
+            Gate::Xor => self.boolean_bitxor(lhs, rhs),
+            Gate::Xnor => self.boolean_bitnot(&self.boolean_bitxor(lhs, rhs)),
+        }
+    }
+
+    fn mux(
+        &self,
+        condition: &BooleanBlock,
+        then: &BooleanBlock,
+        otherwise: &BooleanBlock,
+    ) -> BooleanBlock {
+        self.if_then_else_parallelized(condition, then, otherwise)
+    }
+}
+
+impl Netlist {
+    /// Evaluates the netlist on [`FheBool`] inputs, using the server key of the current thread
+    ///
+    /// `inputs` must hold exactly one value for each input of the netlist, the outputs are
+    /// returned by port name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key of the current thread is not a CPU key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use tfhe::boolean::netlist::Netlist;
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let netlist = Netlist::from_blif(
+    ///     "
+    /// .model nand_or
+    /// .inputs a b c
+    /// .outputs y
+    /// .names a b c y
+    /// 0-- 1
+    /// -01 1
+    /// .end
+    /// ",
+    /// )
+    /// .unwrap();
+    ///
+    /// let inputs = HashMap::from([
+    ///     ("a".to_string(), FheBool::encrypt(true, &client_key)),
+    ///     ("b".to_string(), FheBool::encrypt(false, &client_key)),
+    ///     ("c".to_string(), FheBool::encrypt(true, &client_key)),
+    /// ]);
+    ///
+    /// let outputs = netlist.evaluate_fhe_bool(inputs).unwrap();
+    /// let y: bool = outputs["y"].decrypt(&client_key);
+    /// assert!(y);
+    /// ```
+    pub fn evaluate_fhe_bool(
+        &self,
+        inputs: HashMap<String, FheBool>,
+    ) -> crate::Result<HashMap<String, FheBool>> {
+        global_state::with_cpu_internal_keys(|key| {
+            let inputs = inputs
+                .into_iter()
+                .map(|(name, bit)| (name, bit.ciphertext.on_cpu().into_owned()))
+                .collect();
+
+            let outputs = self.evaluate_with(key.pbs_key(), inputs)?;
+
+            Ok(outputs
+                .into_iter()
+                .map(|(name, bit)| (name, FheBool::new(InnerBoolean::Cpu(bit), key.tag.clone())))
+                .collect())
+        })
+    }
+}
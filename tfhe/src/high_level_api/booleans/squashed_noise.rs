@@ -7,7 +7,7 @@ use crate::high_level_api::errors::UninitializedNoiseSquashing;
 use crate::high_level_api::global_state::{self, with_internal_keys};
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::{FheDecrypt, SquashNoise, Tagged};
-use crate::high_level_api::SquashedNoiseCiphertextState;
+use crate::high_level_api::{profiling, SquashedNoiseCiphertextState};
 use crate::integer::ciphertext::SquashedNoiseBooleanBlock;
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
@@ -176,6 +176,7 @@ impl SquashNoise for FheBool {
     type Output = SquashedNoiseFheBool;
 
     fn squash_noise(&self) -> crate::Result<Self::Output> {
+        let _scope = profiling::scope("squash_noise");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(server_key) => {
                 let noise_squashing_key = server_key
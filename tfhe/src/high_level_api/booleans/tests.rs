@@ -624,6 +624,53 @@ mod cpu {
         scalar_ne_truth_table(&ttrue, &ffalse, &keys);
     }
 
+    #[test]
+    fn test_n_ary_gates_default() {
+        let keys = setup_default();
+
+        for values in 0..8u8 {
+            let clear = [0, 1, 2].map(|i| (values >> i) & 1 == 1);
+            let [a, b, c] = clear.map(|value| FheBool::encrypt(value, &keys));
+            let count = clear.iter().filter(|value| **value).count();
+
+            let result = FheBool::majority(&[&a, &b, &c]);
+            assert_degree_is_ok(&result);
+            assert_eq!(result.decrypt(&keys), count >= 2);
+
+            for threshold in 0..=3 {
+                let result = FheBool::threshold(&[&a, &b, &c], threshold);
+                assert_degree_is_ok(&result);
+                assert_eq!(result.decrypt(&keys), count >= threshold);
+            }
+
+            let result = FheBool::and3(&a, &b, &c);
+            assert_degree_is_ok(&result);
+            assert_eq!(result.decrypt(&keys), clear[0] & clear[1] & clear[2]);
+
+            let result = FheBool::or3(&a, &b, &c);
+            assert_degree_is_ok(&result);
+            assert_eq!(result.decrypt(&keys), clear[0] | clear[1] | clear[2]);
+
+            let result = FheBool::xor3(&a, &b, &c);
+            assert_degree_is_ok(&result);
+            assert_eq!(result.decrypt(&keys), clear[0] ^ clear[1] ^ clear[2]);
+
+            let (sum, carry) = FheBool::full_adder(&a, &b, &c);
+            assert_degree_is_ok(&sum);
+            assert_degree_is_ok(&carry);
+            assert_eq!(sum.decrypt(&keys), count % 2 == 1);
+            assert_eq!(carry.decrypt(&keys), count >= 2);
+        }
+
+        // Inputs that are outputs of other operations
+        let votes = [true, false, true, true, false].map(|vote| FheBool::encrypt(vote, &keys));
+        let votes = votes.iter().map(|vote| vote & vote).collect::<Vec<_>>();
+        let votes = votes.iter().collect::<Vec<_>>();
+        assert!(FheBool::majority(&votes).decrypt(&keys));
+        assert!(FheBool::threshold(&votes, 3).decrypt(&keys));
+        assert!(!FheBool::threshold(&votes, 4).decrypt(&keys));
+    }
+
     #[test]
     fn test_compressed_bool() {
         compressed_bool_test_case(|| (setup_default(), Device::Cpu));
@@ -0,0 +1,547 @@
+//! Asynchronous execution of high level operations.
+//!
+//! Operations of the high level API block the calling thread until they are done, which does not
+//! play well with async runtimes: a thread of the runtime blocked on a multiplication cannot
+//! serve network I/O.
+//!
+//! An [`FheExecutor`] owns a set of dedicated threads that run the operations, and returns an
+//! [`FheTask`] for each of them: a [`Future`] that resolves to the result of the operation.
+//! The futures do not depend on a specific runtime.
+//!
+//! - Each operation runs with the server key that was set on the thread that submitted it, as
+//!   [`with_server_key_as_context`](crate::with_server_key_as_context) would do.
+//! - Dropping an [`FheTask`] cancels the operation if it has not started yet, otherwise its result
+//!   is discarded.
+//! - The number of operations waiting for a thread is bounded: once the queue is full, new tasks
+//!   are only queued when polled, after some room has been made.
+//!
+//! The [`FheAsyncOps`] trait adds `async_` versions of the common operations on the FHE types,
+//! they run on the [global executor](FheExecutor::global).
+
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::traits::{FheEq, FheMax, FheMin, FheOrd};
+use crate::{FheBool, FheInt, FheUint, ServerKey};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Rem, Sub};
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, JoinHandle};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+struct QueueState {
+    jobs: VecDeque<Job>,
+    // Wakers of the tasks that are waiting for room in the queue
+    waiting_tasks: HashMap<u64, Waker>,
+    shutdown: bool,
+}
+
+struct Queue {
+    state: Mutex<QueueState>,
+    job_available: Condvar,
+    max_queued_jobs: usize,
+}
+
+impl Queue {
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        // Jobs run outside of the lock and catch panics, it cannot be poisoned
+        self.state.lock().unwrap()
+    }
+
+    /// Queues the job if there is room, otherwise gives it back and registers the waker
+    fn try_push(&self, task_id: u64, job: Job, waker: Option<&Waker>) -> Result<(), Job> {
+        let mut state = self.lock();
+        if state.jobs.len() >= self.max_queued_jobs {
+            if let Some(waker) = waker {
+                state.waiting_tasks.insert(task_id, waker.clone());
+            }
+            return Err(job);
+        }
+
+        state.waiting_tasks.remove(&task_id);
+        state.jobs.push_back(job);
+        drop(state);
+        self.job_available.notify_one();
+        Ok(())
+    }
+
+    fn forget_waiting_task(&self, task_id: u64) {
+        self.lock().waiting_tasks.remove(&task_id);
+    }
+
+    /// Returns the next job to run, or `None` once the executor is dropped and all the jobs are
+    /// done
+    fn pop(&self) -> Option<Job> {
+        let mut state = self.lock();
+        loop {
+            if let Some(job) = state.jobs.pop_front() {
+                // Wake all the waiting tasks, the ones that do not get the room will register
+                // again
+                let waiting_tasks = std::mem::take(&mut state.waiting_tasks);
+                drop(state);
+                waiting_tasks.into_values().for_each(Waker::wake);
+                return Some(job);
+            }
+            if state.shutdown {
+                return None;
+            }
+            state = self.job_available.wait(state).unwrap();
+        }
+    }
+}
+
+struct ExecutorInner {
+    queue: Arc<Queue>,
+    workers: Vec<JoinHandle<()>>,
+    next_task_id: AtomicU64,
+}
+
+impl Drop for ExecutorInner {
+    fn drop(&mut self) {
+        self.queue.lock().shutdown = true;
+        self.queue.job_available.notify_all();
+        let current_thread = thread::current().id();
+        for worker in self.workers.drain(..) {
+            // The last handle may be dropped by an operation, that worker stops on its own
+            if worker.thread().id() != current_thread {
+                // Panics of the operations are caught, the workers cannot panic
+                let _ = worker.join();
+            }
+        }
+    }
+}
+
+/// A set of dedicated threads running high level operations asynchronously.
+///
+/// Cloning an executor gives a new handle to the same threads, the threads stop once all the
+/// handles and all the tasks are dropped.
+///
+/// The threads run one operation at a time each, every operation still uses the global rayon
+/// thread pool for its own parallelism, so a few threads are enough to overlap the operations of
+/// a service with its I/O.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheExecutor, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let executor = FheExecutor::new(2, 16).unwrap();
+///
+/// let a = FheUint8::encrypt(7u8, &client_key);
+/// let b = FheUint8::encrypt(6u8, &client_key);
+///
+/// // In an async function, the task would be awaited
+/// let task = executor.spawn(move || &a * &b);
+/// let result = task.join();
+///
+/// let decrypted: u8 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 42);
+/// ```
+#[derive(Clone)]
+pub struct FheExecutor {
+    inner: Arc<ExecutorInner>,
+}
+
+impl FheExecutor {
+    /// Creates an executor with `num_threads` dedicated threads, at most `max_queued_operations`
+    /// operations can wait for a thread before the tasks start applying backpressure.
+    pub fn new(num_threads: usize, max_queued_operations: usize) -> crate::Result<Self> {
+        if num_threads == 0 {
+            return Err("An FheExecutor needs at least one thread".into());
+        }
+        if max_queued_operations == 0 {
+            return Err("An FheExecutor must be able to queue at least one operation".into());
+        }
+
+        let queue = Arc::new(Queue {
+            state: Mutex::new(QueueState {
+                jobs: VecDeque::new(),
+                waiting_tasks: HashMap::new(),
+                shutdown: false,
+            }),
+            job_available: Condvar::new(),
+            max_queued_jobs: max_queued_operations,
+        });
+
+        let mut inner = ExecutorInner {
+            queue,
+            workers: Vec::with_capacity(num_threads),
+            next_task_id: AtomicU64::new(0),
+        };
+
+        for index in 0..num_threads {
+            let queue = Arc::clone(&inner.queue);
+            let worker = thread::Builder::new()
+                .name(format!("tfhe-executor-{index}"))
+                .spawn(move || {
+                    while let Some(job) = queue.pop() {
+                        job();
+                    }
+                })
+                // Dropping `inner` stops the threads already spawned
+                .map_err(|err| {
+                    crate::Error::new(format!("Failed to spawn an executor thread: {err}"))
+                })?;
+            inner.workers.push(worker);
+        }
+
+        Ok(Self {
+            inner: Arc::new(inner),
+        })
+    }
+
+    /// Returns the executor used by the methods of [`FheAsyncOps`].
+    ///
+    /// Unless [`Self::set_global`] was called before, it is created on first use with one thread
+    /// per available core and a queue of 4 operations per thread.
+    pub fn global() -> &'static Self {
+        GLOBAL_EXECUTOR.get_or_init(|| {
+            let num_threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+            Self::new(num_threads, 4 * num_threads).expect("Failed to create the global executor")
+        })
+    }
+
+    /// Sets the executor used by the methods of [`FheAsyncOps`].
+    ///
+    /// Returns an error if the global executor was already set or used.
+    pub fn set_global(executor: Self) -> crate::Result<()> {
+        GLOBAL_EXECUTOR
+            .set(executor)
+            .map_err(|_| "The global FheExecutor is already initialized".into())
+    }
+
+    /// Runs `operation` on one of the threads of the executor, with the server key of the calling
+    /// thread.
+    ///
+    /// The operation is queued right away if there is room in the queue, otherwise when the
+    /// returned task is polled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key of the calling thread is not set, or is not a CPU key.
+    pub fn spawn<F, T>(&self, operation: F) -> FheTask<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let server_key = global_state::with_cpu_internal_keys(ServerKey::clone);
+
+        let shared = Arc::new(TaskShared {
+            result: Mutex::new(None),
+            waker: Mutex::new(None),
+            cancelled: AtomicBool::new(false),
+        });
+
+        let job_shared = Arc::clone(&shared);
+        let job: Job = Box::new(move || {
+            if job_shared.cancelled.load(Ordering::Acquire) {
+                return;
+            }
+            // The result of a panicking operation is only given back as the panic payload, and the
+            // guard unsets the server key of the worker thread even if the operation panics
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                global_state::set_server_key(server_key);
+                let _server_key_guard = ServerKeyGuard;
+                operation()
+            }));
+            *job_shared.result.lock().unwrap() = Some(result);
+            if let Some(waker) = job_shared.waker.lock().unwrap().take() {
+                waker.wake();
+            }
+        });
+
+        let id = self.inner.next_task_id.fetch_add(1, Ordering::Relaxed);
+        let state = match self.inner.queue.try_push(id, job, None) {
+            Ok(()) => TaskState::Queued,
+            Err(job) => TaskState::Pending(job),
+        };
+
+        FheTask {
+            id,
+            executor: Arc::clone(&self.inner),
+            shared,
+            state,
+        }
+    }
+}
+
+static GLOBAL_EXECUTOR: OnceLock<FheExecutor> = OnceLock::new();
+
+/// Unsets the server key of the current thread when dropped
+struct ServerKeyGuard;
+
+impl Drop for ServerKeyGuard {
+    fn drop(&mut self) {
+        global_state::unset_server_key();
+    }
+}
+
+struct TaskShared<T> {
+    result: Mutex<Option<thread::Result<T>>>,
+    waker: Mutex<Option<Waker>>,
+    cancelled: AtomicBool,
+}
+
+enum TaskState {
+    // Waiting for room in the queue
+    Pending(Job),
+    Queued,
+    Done,
+}
+
+/// An operation running on an [`FheExecutor`].
+///
+/// The task is a [`Future`] resolving to the result of the operation, it can also be waited for
+/// synchronously with [`Self::join`].
+///
+/// Dropping the task cancels the operation if it has not started yet.
+///
+/// If the operation panics, the panic is resumed when the task is polled.
+#[must_use = "dropping the task cancels the operation"]
+pub struct FheTask<T> {
+    id: u64,
+    executor: Arc<ExecutorInner>,
+    shared: Arc<TaskShared<T>>,
+    state: TaskState,
+}
+
+impl<T> FheTask<T> {
+    /// Returns whether the operation is done, i.e. whether polling the task would return its
+    /// result
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, TaskState::Done) || self.shared.result.lock().unwrap().is_some()
+    }
+
+    /// Blocks the current thread until the operation is done and returns its result
+    pub fn join(mut self) -> T {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            match Pin::new(&mut self).poll(&mut context) {
+                Poll::Ready(result) => return result,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+}
+
+impl<T> Future for FheTask<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<T> {
+        let this = &mut *self;
+
+        match std::mem::replace(&mut this.state, TaskState::Done) {
+            TaskState::Pending(job) => {
+                if let Err(job) = this
+                    .executor
+                    .queue
+                    .try_push(this.id, job, Some(context.waker()))
+                {
+                    this.state = TaskState::Pending(job);
+                    return Poll::Pending;
+                }
+                this.state = TaskState::Queued;
+            }
+            TaskState::Queued => this.state = TaskState::Queued,
+            TaskState::Done => panic!("FheTask polled after completion"),
+        }
+
+        // The waker is registered before checking the result so that a result stored in between
+        // is not missed
+        *this.shared.waker.lock().unwrap() = Some(context.waker().clone());
+        let Some(result) = this.shared.result.lock().unwrap().take() else {
+            return Poll::Pending;
+        };
+
+        this.state = TaskState::Done;
+        match result {
+            Ok(result) => Poll::Ready(result),
+            Err(panic) => resume_unwind(panic),
+        }
+    }
+}
+
+impl<T> Drop for FheTask<T> {
+    fn drop(&mut self) {
+        match self.state {
+            TaskState::Pending(_) => self.executor.queue.forget_waiting_task(self.id),
+            TaskState::Queued => self.shared.cancelled.store(true, Ordering::Release),
+            TaskState::Done => {}
+        }
+    }
+}
+
+struct ThreadWaker(thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Asynchronous versions of the operations of the FHE types.
+///
+/// The operands are cloned and the operation runs on the [global executor](FheExecutor::global)
+/// with the server key of the calling thread, see [`FheExecutor::spawn`].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(7u8, &client_key);
+/// let b = FheUint8::encrypt(6u8, &client_key);
+///
+/// // Both operations run concurrently, in an async function they would be awaited
+/// let product = a.async_mul(&b);
+/// let is_greater = a.async_gt(&b);
+///
+/// let product: u8 = product.join().decrypt(&client_key);
+/// let is_greater = is_greater.join().decrypt(&client_key);
+/// assert_eq!(product, 42);
+/// assert!(is_greater);
+/// ```
+pub trait FheAsyncOps: Clone + Send + 'static {
+    fn async_add(&self, rhs: &Self) -> FheTask<Self>
+    where
+        for<'a> &'a Self: Add<&'a Self, Output = Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs + rhs)
+    }
+
+    fn async_sub(&self, rhs: &Self) -> FheTask<Self>
+    where
+        for<'a> &'a Self: Sub<&'a Self, Output = Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs - rhs)
+    }
+
+    fn async_mul(&self, rhs: &Self) -> FheTask<Self>
+    where
+        for<'a> &'a Self: Mul<&'a Self, Output = Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs * rhs)
+    }
+
+    fn async_div(&self, rhs: &Self) -> FheTask<Self>
+    where
+        for<'a> &'a Self: Div<&'a Self, Output = Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs / rhs)
+    }
+
+    fn async_rem(&self, rhs: &Self) -> FheTask<Self>
+    where
+        for<'a> &'a Self: Rem<&'a Self, Output = Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs % rhs)
+    }
+
+    fn async_bitand(&self, rhs: &Self) -> FheTask<Self>
+    where
+        for<'a> &'a Self: BitAnd<&'a Self, Output = Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs & rhs)
+    }
+
+    fn async_bitor(&self, rhs: &Self) -> FheTask<Self>
+    where
+        for<'a> &'a Self: BitOr<&'a Self, Output = Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs | rhs)
+    }
+
+    fn async_bitxor(&self, rhs: &Self) -> FheTask<Self>
+    where
+        for<'a> &'a Self: BitXor<&'a Self, Output = Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs ^ rhs)
+    }
+
+    fn async_min(&self, rhs: &Self) -> FheTask<Self>
+    where
+        Self: for<'a> FheMin<&'a Self, Output = Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs.min(rhs))
+    }
+
+    fn async_max(&self, rhs: &Self) -> FheTask<Self>
+    where
+        Self: for<'a> FheMax<&'a Self, Output = Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs.max(rhs))
+    }
+
+    fn async_eq(&self, rhs: &Self) -> FheTask<FheBool>
+    where
+        Self: for<'a> FheEq<&'a Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs.eq(rhs))
+    }
+
+    fn async_ne(&self, rhs: &Self) -> FheTask<FheBool>
+    where
+        Self: for<'a> FheEq<&'a Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs.ne(rhs))
+    }
+
+    fn async_lt(&self, rhs: &Self) -> FheTask<FheBool>
+    where
+        Self: for<'a> FheOrd<&'a Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs.lt(rhs))
+    }
+
+    fn async_le(&self, rhs: &Self) -> FheTask<FheBool>
+    where
+        Self: for<'a> FheOrd<&'a Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs.le(rhs))
+    }
+
+    fn async_gt(&self, rhs: &Self) -> FheTask<FheBool>
+    where
+        Self: for<'a> FheOrd<&'a Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs.gt(rhs))
+    }
+
+    fn async_ge(&self, rhs: &Self) -> FheTask<FheBool>
+    where
+        Self: for<'a> FheOrd<&'a Self>,
+    {
+        spawn_binary(self, rhs, |lhs, rhs| lhs.ge(rhs))
+    }
+}
+
+fn spawn_binary<T, R, F>(lhs: &T, rhs: &T, operation: F) -> FheTask<R>
+where
+    T: Clone + Send + 'static,
+    R: Send + 'static,
+    F: FnOnce(&T, &T) -> R + Send + 'static,
+{
+    let (lhs, rhs) = (lhs.clone(), rhs.clone());
+    FheExecutor::global().spawn(move || operation(&lhs, &rhs))
+}
+
+impl<Id: FheUintId + Send> FheAsyncOps for FheUint<Id> {}
+
+impl<Id: FheIntId + Send> FheAsyncOps for FheInt<Id> {}
+
+impl FheAsyncOps for FheBool {}
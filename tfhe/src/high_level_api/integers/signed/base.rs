@@ -4,11 +4,13 @@ use super::inner::SignedRadixCiphertext;
 use crate::backward_compatibility::integers::FheIntVersions;
 use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::prelude::SignedNumeric;
-use crate::high_level_api::global_state;
 use crate::high_level_api::integers::{FheUint, FheUintId, IntegerId};
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::Tagged;
+use crate::high_level_api::{global_state, profiling};
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableSignedInteger};
+#[cfg(feature = "hpu")]
+use crate::integer::hpu::ciphertext::HpuRadixCiphertext;
 use crate::integer::parameters::RadixCiphertextConformanceParams;
 use crate::named::Named;
 use crate::prelude::CastFrom;
@@ -16,6 +18,8 @@ use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::PBSParameters;
 use crate::{Device, FheBool, ServerKey, Tag};
 use std::marker::PhantomData;
+#[cfg(feature = "hpu")]
+use tfhe_hpu_backend::prelude::*;
 
 pub trait FheIntId: IntegerId {}
 
@@ -161,6 +165,26 @@ where
         self.ciphertext.current_device()
     }
 
+    /// Returns the estimated variance of the noise of the ciphertext, i.e. the largest one of its
+    /// blocks, or `None` if it is unknown
+    ///
+    /// See [`noise_tracking`](crate::shortint::noise_tracking) for the details of the estimation.
+    #[cfg(feature = "noise-tracking")]
+    pub fn estimated_noise(&self) -> Option<crate::core_crypto::commons::dispersion::Variance> {
+        crate::shortint::noise_tracking::max_estimated_variance(&self.ciphertext.on_cpu().blocks)
+    }
+
+    /// Returns the estimated probability that a PBS applied on this FheInt gives a wrong result,
+    /// or `None` if the noise of the ciphertext is unknown
+    ///
+    /// Requires the server key to be set.
+    #[cfg(feature = "noise-tracking")]
+    pub fn failure_probability(&self) -> Option<f64> {
+        crate::high_level_api::utils::failure_probability_of_blocks(
+            &self.ciphertext.on_cpu().blocks,
+        )
+    }
+
     /// Returns the absolute value
     ///
     /// # Example
@@ -187,6 +211,7 @@ where
     /// assert_eq!(result, i16::MIN.wrapping_abs());
     /// ```
     pub fn abs(&self) -> Self {
+        let _scope = profiling::scope("abs");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = cpu_key
@@ -204,6 +229,67 @@ where
                 Self::new(result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(device) => {
+                let hpu_self = self.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_ABS;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_self], &[])
+                    .pop()
+                    .unwrap();
+                Self::new(hpu_result.to_signed_radix_ciphertext(), device.tag.clone())
+            }
+        })
+    }
+
+    /// Returns the gcd `g` of `self` and `other`, as well as Bézout coefficients `x` and `y`
+    /// such that `self * x + other * y = g`
+    ///
+    /// The result is `(g, x, y)`, with `g` non-negative, except when it is
+    /// `2^(Self::num_bits() - 1)` which is not representable and wraps around to the minimum value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt8::encrypt(30i8, &client_key);
+    /// let b = FheInt8::encrypt(-12i8, &client_key);
+    ///
+    /// let (g, x, y) = a.extended_gcd(&b);
+    /// let g: i8 = g.decrypt(&client_key);
+    /// let x: i8 = x.decrypt(&client_key);
+    /// let y: i8 = y.decrypt(&client_key);
+    /// assert_eq!(g, 6);
+    /// assert_eq!(30 * x - 12 * y, g);
+    /// ```
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let _scope = profiling::scope("extended_gcd");
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (g, x, y) = cpu_key.pbs_key().extended_gcd_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*other.ciphertext.on_cpu(),
+                );
+                (
+                    Self::new(g, cpu_key.tag.clone()),
+                    Self::new(x, cpu_key.tag.clone()),
+                    Self::new(y, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support extended_gcd yet");
+            }
+            #[cfg(feature = "hpu")]
             InternalServerKey::Hpu(_device) => {
                 panic!("Hpu does not support this operation yet.")
             }
@@ -228,6 +314,7 @@ where
     /// assert!(decrypted);
     /// ```
     pub fn is_even(&self) -> FheBool {
+        let _scope = profiling::scope("is_even");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -269,6 +356,7 @@ where
     /// assert!(decrypted);
     /// ```
     pub fn is_odd(&self) -> FheBool {
+        let _scope = profiling::scope("is_odd");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -310,6 +398,7 @@ where
     /// assert_eq!(decrypted, 0);
     /// ```
     pub fn leading_zeros(&self) -> crate::FheUint32 {
+        let _scope = profiling::scope("leading_zeros");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -360,6 +449,7 @@ where
     /// assert_eq!(decrypted, 16);
     /// ```
     pub fn leading_ones(&self) -> crate::FheUint32 {
+        let _scope = profiling::scope("leading_ones");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -410,6 +500,7 @@ where
     /// assert_eq!(decrypted, 2);
     /// ```
     pub fn trailing_zeros(&self) -> crate::FheUint32 {
+        let _scope = profiling::scope("trailing_zeros");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -460,6 +551,7 @@ where
     /// assert_eq!(decrypted, 2);
     /// ```
     pub fn trailing_ones(&self) -> crate::FheUint32 {
+        let _scope = profiling::scope("trailing_ones");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -511,6 +603,7 @@ where
     /// assert_eq!(decrypted, clear_a.count_ones());
     /// ```
     pub fn count_ones(&self) -> crate::FheUint32 {
+        let _scope = profiling::scope("count_ones");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -552,6 +645,7 @@ where
     /// assert_eq!(decrypted, clear_a.count_zeros());
     /// ```
     pub fn count_zeros(&self) -> crate::FheUint32 {
+        let _scope = profiling::scope("count_zeros");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -594,6 +688,7 @@ where
     /// assert_eq!(decrypted, 1);
     /// ```
     pub fn ilog2(&self) -> crate::FheUint32 {
+        let _scope = profiling::scope("ilog2");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -650,6 +745,7 @@ where
     /// assert_eq!(decrypted, 15); // result is meaningless
     /// ```
     pub fn checked_ilog2(&self) -> (crate::FheUint32, FheBool) {
+        let _scope = profiling::scope("checked_ilog2");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, is_ok) = cpu_key
@@ -747,6 +843,7 @@ where
     /// assert_eq!(decrypted, msg.reverse_bits());
     /// ```
     pub fn reverse_bits(&self) -> Self {
+        let _scope = profiling::scope("reverse_bits");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let sk = &cpu_key.pbs_key();
@@ -792,6 +889,7 @@ where
     where
         Clear: SignedNumeric + DecomposableInto<u64>,
     {
+        let _scope = profiling::scope("if_then_else");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let sk = cpu_key.pbs_key();
@@ -857,6 +955,7 @@ where
     /// assert_eq!(decrypted, i32::MAX as i16);
     /// ```
     fn cast_from(input: FheInt<FromId>) -> Self {
+        let _scope = profiling::scope("cast_from");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let target_num_blocks = IntoId::num_blocks(cpu_key.message_modulus());
@@ -907,6 +1006,7 @@ where
     /// assert_eq!(decrypted, u32::MAX as i16);
     /// ```
     fn cast_from(input: FheUint<FromId>) -> Self {
+        let _scope = profiling::scope("cast_from");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let new_ciphertext = cpu_key.pbs_key().cast_to_signed(
@@ -955,6 +1055,7 @@ where
     /// assert_eq!(decrypted, i16::from(true));
     /// ```
     fn cast_from(input: FheBool) -> Self {
+        let _scope = profiling::scope("cast_from");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = input
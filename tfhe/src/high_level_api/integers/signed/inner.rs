@@ -7,10 +7,14 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_cuda_internal_keys;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::global_state::with_thread_local_cuda_streams_for_gpu_indexes;
+#[cfg(feature = "hpu")]
+use crate::high_level_api::keys::HpuTaggedDevice;
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaSignedRadixCiphertext;
+#[cfg(feature = "hpu")]
+use crate::integer::hpu::ciphertext::HpuRadixCiphertext;
 use crate::Device;
 use serde::{Deserializer, Serializer};
 use tfhe_versionable::{Unversionize, UnversionizeError, Versionize, VersionizeOwned};
@@ -158,6 +162,14 @@ impl SignedRadixCiphertext {
         }
     }
 
+    /// Returns a copy of the inner ciphertext on the Hpu
+    ///
+    /// NB: Signed ciphertexts are only stored on the CPU, the Hpu copy is always built from it
+    #[cfg(feature = "hpu")]
+    pub(crate) fn on_hpu(&self, device: &HpuTaggedDevice) -> HpuRadixCiphertext {
+        HpuRadixCiphertext::from_signed_radix_ciphertext(&self.on_cpu(), &device.device)
+    }
+
     pub(crate) fn as_cpu_mut(&mut self) -> &mut crate::integer::SignedRadixCiphertext {
         match self {
             Self::Cpu(radix_ct) => radix_ct,
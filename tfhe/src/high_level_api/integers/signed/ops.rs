@@ -1,6 +1,5 @@
 #[cfg(feature = "gpu")]
 use crate::high_level_api::details::MaybeCloned;
-use crate::high_level_api::global_state;
 use crate::high_level_api::integers::{FheIntId, FheUintId};
 use crate::high_level_api::keys::InternalServerKey;
 #[cfg(feature = "gpu")]
@@ -14,14 +13,19 @@ use crate::high_level_api::traits::{
     DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
 };
+use crate::high_level_api::{global_state, profiling};
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+#[cfg(feature = "hpu")]
+use crate::integer::hpu::ciphertext::HpuRadixCiphertext;
 use crate::{FheBool, FheInt, FheUint};
 use std::borrow::Borrow;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
     Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
+#[cfg(feature = "hpu")]
+use tfhe_hpu_backend::prelude::*;
 
 impl<'a, Id> std::iter::Sum<&'a Self> for FheInt<Id>
 where
@@ -55,6 +59,7 @@ where
     /// assert_eq!(decrypted, clears.into_iter().sum::<i16>());
     /// ```
     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        let _scope = profiling::scope("sum");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertexts = iter
@@ -143,6 +148,7 @@ where
     /// assert_eq!(decrypted_max, 2i16);
     /// ```
     fn max(&self, rhs: &Self) -> Self::Output {
+        let _scope = profiling::scope("max");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -161,8 +167,20 @@ where
                 Self::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                panic!("Hpu does not support this operation yet.")
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_SMAX;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[])
+                    .pop()
+                    .unwrap();
+                Self::new(hpu_result.to_signed_radix_ciphertext(), device.tag.clone())
             }
         })
     }
@@ -204,6 +222,7 @@ where
     /// assert_eq!(decrypted_min, -1i16);
     /// ```
     fn min(&self, rhs: &Self) -> Self::Output {
+        let _scope = profiling::scope("min");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -222,8 +241,20 @@ where
                 Self::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                panic!("Hpu does not support this operation yet.")
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_SMIN;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[])
+                    .pop()
+                    .unwrap();
+                Self::new(hpu_result.to_signed_radix_ciphertext(), device.tag.clone())
             }
         })
     }
@@ -276,6 +307,7 @@ where
     /// assert_eq!(decrypted, -1i16 == 2i16);
     /// ```
     fn eq(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("eq");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -294,8 +326,20 @@ where
                 FheBool::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                panic!("Hpu does not support this operation yet.")
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_CMP_EQ;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[])
+                    .pop()
+                    .unwrap();
+                FheBool::new(hpu_result, device.tag.clone())
             }
         })
     }
@@ -320,6 +364,7 @@ where
     /// assert_eq!(decrypted, -1i16 != 2i16);
     /// ```
     fn ne(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("ne");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -338,8 +383,20 @@ where
                 FheBool::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                panic!("Hpu does not support this operation yet.")
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_CMP_NEQ;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[])
+                    .pop()
+                    .unwrap();
+                FheBool::new(hpu_result, device.tag.clone())
             }
         })
     }
@@ -390,6 +447,7 @@ where
     /// assert_eq!(decrypted, -1i16 < 2i16);
     /// ```
     fn lt(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("lt");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -408,8 +466,20 @@ where
                 FheBool::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                panic!("Hpu does not support this operation yet.")
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_CMP_SLT;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[])
+                    .pop()
+                    .unwrap();
+                FheBool::new(hpu_result, device.tag.clone())
             }
         })
     }
@@ -434,6 +504,7 @@ where
     /// assert_eq!(decrypted, -1i16 <= 2i16);
     /// ```
     fn le(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("le");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -452,8 +523,20 @@ where
                 FheBool::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                panic!("Hpu does not support this operation yet.")
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_CMP_SLTE;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[])
+                    .pop()
+                    .unwrap();
+                FheBool::new(hpu_result, device.tag.clone())
             }
         })
     }
@@ -478,6 +561,7 @@ where
     /// assert_eq!(decrypted, -1i16 > 2i16);
     /// ```
     fn gt(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("gt");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -496,8 +580,20 @@ where
                 FheBool::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                panic!("Hpu does not support this operation yet.")
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_CMP_SGT;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[])
+                    .pop()
+                    .unwrap();
+                FheBool::new(hpu_result, device.tag.clone())
             }
         })
     }
@@ -522,6 +618,7 @@ where
     /// assert_eq!(decrypted, -1i16 >= 2i16);
     /// ```
     fn ge(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("ge");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -540,8 +637,20 @@ where
                 FheBool::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                panic!("Hpu does not support this operation yet.")
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_CMP_SGTE;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[])
+                    .pop()
+                    .unwrap();
+                FheBool::new(hpu_result, device.tag.clone())
             }
         })
     }
@@ -606,6 +715,7 @@ where
     /// assert_eq!(remainder, -23i16 % 3i16);
     /// ```
     fn div_rem(self, rhs: Self) -> Self::Output {
+        let _scope = profiling::scope("div_rem");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let (q, r) = cpu_key
@@ -630,8 +740,24 @@ where
                 )
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                panic!("Hpu does not support this operation yet.")
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_SDIV;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let mut hpu_result =
+                    HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[]);
+                let remainder = hpu_result.pop().expect("IOP_SDIV must return 2 value");
+                let quotient = hpu_result.pop().expect("IOP_SDIV must return 2 value");
+                (
+                    FheInt::<Id>::new(quotient.to_signed_radix_ciphertext(), device.tag.clone()),
+                    FheInt::<Id>::new(remainder.to_signed_radix_ciphertext(), device.tag.clone()),
+                )
             }
         })
     }
@@ -667,6 +793,7 @@ macro_rules! generic_integer_impl_operation (
 
             $(#[$outer])*
             fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let _scope = profiling::scope(stringify!($rust_trait_method));
                 $closure(self, rhs.borrow())
             }
         }
@@ -996,8 +1123,20 @@ generic_integer_impl_operation!(
                     FheInt::new(inner_result, cuda_key.tag.clone())
                 },
                 #[cfg(feature = "hpu")]
-                InternalServerKey::Hpu(_device) => {
-                    panic!("Hpu does not support this operation yet.")
+                InternalServerKey::Hpu(device) => {
+                    let hpu_lhs = lhs.ciphertext.on_hpu(device);
+                    let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                    let (opcode, proto) = {
+                        let asm_iop = &hpu_asm::iop::IOP_SDIV;
+                        (
+                            asm_iop.opcode(),
+                            &asm_iop.format().expect("Unspecified IOP format").proto,
+                        )
+                    };
+                    let mut hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[]);
+                    let remainder = hpu_result.pop().expect("IOP_SDIV must return 2 value");
+                    let quotient = hpu_result.pop().expect("IOP_SDIV must return 2 value");
+                    FheInt::new(quotient.to_signed_radix_ciphertext(), device.tag.clone())
                 }
             })
         }
@@ -1051,8 +1190,20 @@ generic_integer_impl_operation!(
                     FheInt::new(inner_result, cuda_key.tag.clone())
                 },
                 #[cfg(feature = "hpu")]
-                InternalServerKey::Hpu(_device) => {
-                    panic!("Hpu does not support this operation yet.")
+                InternalServerKey::Hpu(device) => {
+                    let hpu_lhs = lhs.ciphertext.on_hpu(device);
+                    let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                    let (opcode, proto) = {
+                        let asm_iop = &hpu_asm::iop::IOP_SDIV;
+                        (
+                            asm_iop.opcode(),
+                            &asm_iop.format().expect("Unspecified IOP format").proto,
+                        )
+                    };
+                    let mut hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_lhs, hpu_rhs], &[]);
+                    let remainder = hpu_result.pop().expect("IOP_SDIV must return 2 value");
+                    let quotient = hpu_result.pop().expect("IOP_SDIV must return 2 value");
+                    FheInt::new(remainder.to_signed_radix_ciphertext(), device.tag.clone())
                 }
             })
         }
@@ -1123,6 +1274,7 @@ macro_rules! generic_integer_impl_shift_rotate (
 
             $(#[$outer])*
             fn $rust_trait_method(self, rhs: &FheUint<Id2>) -> Self::Output {
+                let _scope = profiling::scope(stringify!($rust_trait_method));
                 $closure(self, rhs.borrow())
             }
         }
@@ -1349,6 +1501,7 @@ where
     /// assert_eq!(result, 3i16.wrapping_add(7849i16));
     /// ```
     fn add_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("add_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1399,6 +1552,7 @@ where
     /// assert_eq!(result, 3i16.wrapping_sub(7849i16));
     /// ```
     fn sub_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("sub_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1449,6 +1603,7 @@ where
     /// assert_eq!(result, 3i16.wrapping_mul(7849i16));
     /// ```
     fn mul_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("mul_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1497,6 +1652,7 @@ where
     /// assert_eq!(result, 3i16 & 7849i16);
     /// ```
     fn bitand_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("bitand_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1545,6 +1701,7 @@ where
     /// assert_eq!(result, 3i16 | 7849i16);
     /// ```
     fn bitor_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("bitor_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1593,6 +1750,7 @@ where
     /// assert_eq!(result, 3i16 ^ 7849i16);
     /// ```
     fn bitxor_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("bitxor_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1646,6 +1804,7 @@ where
     /// assert_eq!(result, 7849i16 / 3i16);
     /// ```
     fn div_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("div_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1703,6 +1862,7 @@ where
     /// assert_eq!(result, 7849i16 % 3i16);
     /// ```
     fn rem_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("rem_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1763,6 +1923,7 @@ where
     /// assert_eq!(result, 7849i16 << 3u16);
     /// ```
     fn shl_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _scope = profiling::scope("shl_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().left_shift_assign_parallelized(
@@ -1820,6 +1981,7 @@ where
     /// assert_eq!(result, 7849i16 >> 3u16);
     /// ```
     fn shr_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _scope = profiling::scope("shr_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().right_shift_assign_parallelized(
@@ -1878,6 +2040,7 @@ where
     /// assert_eq!(result, 7849i16.rotate_left(3));
     /// ```
     fn rotate_left_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _scope = profiling::scope("rotate_left_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().rotate_left_assign_parallelized(
@@ -1936,6 +2099,7 @@ where
     /// assert_eq!(result, 7849i16.rotate_right(3));
     /// ```
     fn rotate_right_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _scope = profiling::scope("rotate_right_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().rotate_right_assign_parallelized(
@@ -2012,6 +2176,7 @@ where
     /// assert_eq!(result, 3i16);
     /// ```
     fn neg(self) -> Self::Output {
+        let _scope = profiling::scope("neg");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = cpu_key
@@ -2029,8 +2194,19 @@ where
                 FheInt::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                panic!("Hpu does not support this operation yet.")
+            InternalServerKey::Hpu(device) => {
+                let hpu_self = self.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_NEG;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                let hpu_result = HpuRadixCiphertext::exec(proto, opcode, &[hpu_self], &[])
+                    .pop()
+                    .unwrap();
+                FheInt::new(hpu_result.to_signed_radix_ciphertext(), device.tag.clone())
             }
         })
     }
@@ -2088,6 +2264,7 @@ where
     /// assert_eq!(result, !-3i16);
     /// ```
     fn not(self) -> Self::Output {
+        let _scope = profiling::scope("not");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = cpu_key.pbs_key().bitnot(&*self.ciphertext.on_cpu());
@@ -1,7 +1,7 @@
 use crate::core_crypto::prelude::SignedNumeric;
-use crate::high_level_api::global_state;
 use crate::high_level_api::integers::FheIntId;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::{global_state, profiling};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::prelude::{OverflowingAdd, OverflowingMul, OverflowingNeg, OverflowingSub};
 use crate::{FheBool, FheInt};
@@ -39,6 +39,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_add(self, other: Self) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_add");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().signed_overflowing_add_parallelized(
@@ -141,6 +142,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_add(self, other: Clear) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_add");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key
@@ -279,6 +281,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_sub(self, other: Self) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_sub");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().signed_overflowing_sub_parallelized(
@@ -380,6 +383,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_sub(self, other: Clear) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_sub");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key
@@ -480,6 +484,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_mul(self, other: Self) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_mul");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().signed_overflowing_mul_parallelized(
@@ -568,6 +573,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_neg(self) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_neg");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key
@@ -1,7 +1,6 @@
 #[cfg(feature = "gpu")]
 use crate::core_crypto::commons::numeric::CastFrom;
 use crate::high_level_api::errors::UnwrapResultExt;
-use crate::high_level_api::global_state;
 use crate::high_level_api::integers::signed::inner::SignedRadixCiphertext;
 use crate::high_level_api::integers::FheIntId;
 use crate::high_level_api::keys::InternalServerKey;
@@ -15,6 +14,7 @@ use crate::high_level_api::traits::{
     DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
 };
+use crate::high_level_api::{global_state, profiling};
 use crate::integer::bigint::{I1024, I2048, U1024, U2048};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::{I256, I512, U256, U512};
@@ -50,6 +50,7 @@ where
     /// assert_eq!(decrypted_max, 2i16);
     /// ```
     fn max(&self, rhs: Clear) -> Self::Output {
+        let _scope = profiling::scope("scalar_max");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -101,6 +102,7 @@ where
     /// assert_eq!(decrypted_min, -1i16);
     /// ```
     fn min(&self, rhs: Clear) -> Self::Output {
+        let _scope = profiling::scope("scalar_min");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -151,6 +153,7 @@ where
     /// assert_eq!(decrypted, -1i16 == 2i16);
     /// ```
     fn eq(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_eq");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -195,6 +198,7 @@ where
     /// assert_eq!(decrypted, -1i16 != 2i16);
     /// ```
     fn ne(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_ne");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -244,6 +248,7 @@ where
     /// assert_eq!(decrypted, -1i16 < 2i16);
     /// ```
     fn lt(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_lt");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -287,6 +292,7 @@ where
     /// assert_eq!(decrypted, -1i16 <= 2i16);
     /// ```
     fn le(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_le");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -330,6 +336,7 @@ where
     /// assert_eq!(decrypted, -1i16 > 2i16);
     /// ```
     fn gt(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_gt");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -373,6 +380,7 @@ where
     /// assert_eq!(decrypted, -1i16 >= 2i16);
     /// ```
     fn ge(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_ge");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -558,6 +566,7 @@ macro_rules! generic_integer_impl_scalar_div_rem {
                     type Output = ($concrete_type, $concrete_type);
 
                     fn div_rem(self, rhs: $scalar_type) -> Self::Output {
+                        let _scope = profiling::scope("scalar_div_rem");
                         global_state::with_internal_keys(|keys| match keys {
                             InternalServerKey::Cpu(cpu_key) => {
                                 let (q, r) = cpu_key
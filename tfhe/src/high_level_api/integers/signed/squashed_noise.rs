@@ -7,7 +7,7 @@ use crate::high_level_api::errors::UninitializedNoiseSquashing;
 use crate::high_level_api::global_state::{self, with_internal_keys};
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::{FheDecrypt, SquashNoise};
-use crate::high_level_api::SquashedNoiseCiphertextState;
+use crate::high_level_api::{profiling, SquashedNoiseCiphertextState};
 use crate::integer::block_decomposition::{RecomposableFrom, SignExtendable};
 use crate::named::Named;
 use crate::prelude::Tagged;
@@ -191,6 +191,7 @@ impl<Id: FheIntId> SquashNoise for FheInt<Id> {
     type Output = SquashedNoiseFheInt;
 
     fn squash_noise(&self) -> crate::Result<Self::Output> {
+        let _scope = profiling::scope("squash_noise");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(server_key) => {
                 let noise_squashing_key = server_key
@@ -241,3 +241,9 @@ fn test_safe_deserialize_conformant_compressed_fhe_int32() {
     let decrypted: i32 = deserialized_a.decompress().decrypt(&client_key);
     assert_eq!(decrypted, clear_a);
 }
+
+#[test]
+fn test_extended_gcd() {
+    let client_key = setup_default_cpu();
+    super::test_case_extended_gcd(&client_key);
+}
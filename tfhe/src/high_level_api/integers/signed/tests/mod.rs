@@ -478,3 +478,30 @@ fn test_case_min_max(cks: &ClientKey) {
     assert_eq!(decrypted_min, a_val.min(b_val));
     assert_eq!(decrypted_max, a_val.max(b_val));
 }
+
+fn test_case_extended_gcd(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    let a_val = rng.gen_range(i8::MIN + 1..=i8::MAX);
+    let b_val = rng.gen_range(i8::MIN + 1..=i8::MAX);
+
+    let a = FheInt8::encrypt(a_val, cks);
+    let b = FheInt8::encrypt(b_val, cks);
+
+    let mut expected_gcd = a_val.unsigned_abs();
+    let mut tmp = b_val.unsigned_abs();
+    while tmp != 0 {
+        (expected_gcd, tmp) = (tmp, expected_gcd % tmp);
+    }
+
+    let (g, x, y) = a.extended_gcd(&b);
+    let g: i8 = g.decrypt(cks);
+    let x: i8 = x.decrypt(cks);
+    let y: i8 = y.decrypt(cks);
+
+    assert_eq!(g, expected_gcd as i8, "Invalid gcd({a_val}, {b_val})");
+    assert_eq!(
+        i16::from(a_val) * i16::from(x) + i16::from(b_val) * i16::from(y),
+        i16::from(g),
+        "Invalid Bézout coefficients for ({a_val}, {b_val}): ({x}, {y})"
+    );
+}
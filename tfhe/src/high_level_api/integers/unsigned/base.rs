@@ -8,7 +8,7 @@ use crate::high_level_api::integers::signed::{FheInt, FheIntId};
 use crate::high_level_api::integers::IntegerId;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::{FheWait, Tagged};
-use crate::high_level_api::{global_state, Device};
+use crate::high_level_api::{global_state, profiling, Device};
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
@@ -296,6 +296,7 @@ where
     /// assert!(decrypted);
     /// ```
     pub fn is_even(&self) -> FheBool {
+        let _scope = profiling::scope("is_even");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -337,6 +338,7 @@ where
     /// assert!(decrypted);
     /// ```
     pub fn is_odd(&self) -> FheBool {
+        let _scope = profiling::scope("is_odd");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -419,6 +421,26 @@ where
         self.ciphertext.on_cpu().is_trivial()
     }
 
+    /// Returns the estimated variance of the noise of the ciphertext, i.e. the largest one of its
+    /// blocks, or `None` if it is unknown
+    ///
+    /// See [`noise_tracking`](crate::shortint::noise_tracking) for the details of the estimation.
+    #[cfg(feature = "noise-tracking")]
+    pub fn estimated_noise(&self) -> Option<crate::core_crypto::commons::dispersion::Variance> {
+        crate::shortint::noise_tracking::max_estimated_variance(&self.ciphertext.on_cpu().blocks)
+    }
+
+    /// Returns the estimated probability that a PBS applied on this FheUint gives a wrong result,
+    /// or `None` if the noise of the ciphertext is unknown
+    ///
+    /// Requires the server key to be set.
+    #[cfg(feature = "noise-tracking")]
+    pub fn failure_probability(&self) -> Option<f64> {
+        crate::high_level_api::utils::failure_probability_of_blocks(
+            &self.ciphertext.on_cpu().blocks,
+        )
+    }
+
     /// Sums multiple ciphertexts together.
     ///
     /// This is much more efficient than manually calling the `+` operator, thus
@@ -471,6 +493,7 @@ where
     /// assert_eq!(decrypted, 2);
     /// ```
     pub fn leading_zeros(&self) -> super::FheUint32 {
+        let _scope = profiling::scope("leading_zeros");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -534,6 +557,7 @@ where
     /// assert_eq!(decrypted, 2);
     /// ```
     pub fn leading_ones(&self) -> super::FheUint32 {
+        let _scope = profiling::scope("leading_ones");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -597,6 +621,7 @@ where
     /// assert_eq!(decrypted, 3);
     /// ```
     pub fn trailing_zeros(&self) -> super::FheUint32 {
+        let _scope = profiling::scope("trailing_zeros");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -660,6 +685,7 @@ where
     /// assert_eq!(decrypted, 3);
     /// ```
     pub fn trailing_ones(&self) -> super::FheUint32 {
+        let _scope = profiling::scope("trailing_ones");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -724,6 +750,7 @@ where
     /// assert_eq!(decrypted, clear_a.count_ones());
     /// ```
     pub fn count_ones(&self) -> super::FheUint32 {
+        let _scope = profiling::scope("count_ones");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -778,6 +805,7 @@ where
     /// assert_eq!(decrypted, clear_a.count_zeros());
     /// ```
     pub fn count_zeros(&self) -> super::FheUint32 {
+        let _scope = profiling::scope("count_zeros");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -833,6 +861,7 @@ where
     /// assert_eq!(decrypted, 1);
     /// ```
     pub fn ilog2(&self) -> super::FheUint32 {
+        let _scope = profiling::scope("ilog2");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -902,6 +931,7 @@ where
     /// assert_eq!(decrypted, 63); // result is meaningless
     /// ```
     pub fn checked_ilog2(&self) -> (super::FheUint32, FheBool) {
+        let _scope = profiling::scope("checked_ilog2");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, is_ok) = cpu_key
@@ -985,6 +1015,7 @@ where
         Clear: UnsignedInteger + DecomposableInto<u64> + CastInto<usize>,
         OutId: FheUintId,
     {
+        let _scope = profiling::scope("match_value");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, matched) = cpu_key
@@ -1069,6 +1100,7 @@ where
         Clear: UnsignedInteger + DecomposableInto<u64> + CastInto<usize>,
         OutId: FheUintId,
     {
+        let _scope = profiling::scope("match_value_or");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key.pbs_key().match_value_or_parallelized(
@@ -1130,6 +1162,7 @@ where
     /// assert_eq!(decrypted, msg.reverse_bits());
     /// ```
     pub fn reverse_bits(&self) -> Self {
+        let _scope = profiling::scope("reverse_bits");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let sk = &cpu_key.pbs_key();
@@ -1149,6 +1182,86 @@ where
         })
     }
 
+    /// Returns the greatest common divisor of `self` and `other`
+    ///
+    /// gcd(a, 0) = a, and gcd(0, 0) = 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(84u8, &client_key);
+    /// let b = FheUint8::encrypt(36u8, &client_key);
+    ///
+    /// let result = a.gcd(&b);
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 12);
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let _scope = profiling::scope("gcd");
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .gcd_parallelized(&*self.ciphertext.on_cpu(), &*other.ciphertext.on_cpu());
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support gcd yet");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_device) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+
+    /// Returns the least common multiple of `self` and `other`
+    ///
+    /// lcm(a, 0) = 0, the result wraps around if the lcm is not representable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(12u8, &client_key);
+    /// let b = FheUint8::encrypt(18u8, &client_key);
+    ///
+    /// let result = a.lcm(&b);
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 36);
+    /// ```
+    pub fn lcm(&self, other: &Self) -> Self {
+        let _scope = profiling::scope("lcm");
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .lcm_parallelized(&*self.ciphertext.on_cpu(), &*other.ciphertext.on_cpu());
+                Self::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support lcm yet");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_device) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+
     /// Creates a FheUint that encrypts either of two values depending
     /// on an encrypted condition
     ///
@@ -1175,6 +1288,7 @@ where
     where
         Clear: UnsignedNumeric + DecomposableInto<u64>,
     {
+        let _scope = profiling::scope("if_then_else");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let sk = cpu_key.pbs_key();
@@ -1308,6 +1422,7 @@ where
     /// assert_eq!(decrypted, i32::MIN as u16);
     /// ```
     fn cast_from(input: FheInt<FromId>) -> Self {
+        let _scope = profiling::scope("cast_from");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let casted = cpu_key.pbs_key().cast_to_unsigned(
@@ -1357,6 +1472,7 @@ where
     /// assert_eq!(decrypted, u32::MAX as u16);
     /// ```
     fn cast_from(input: FheUint<FromId>) -> Self {
+        let _scope = profiling::scope("cast_from");
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let casted = cpu_key.pbs_key().cast_to_unsigned(
@@ -1405,6 +1521,7 @@ where
     /// assert_eq!(decrypted, u16::from(true));
     /// ```
     fn cast_from(input: FheBool) -> Self {
+        let _scope = profiling::scope("cast_from");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext: crate::integer::RadixCiphertext = input
@@ -5,7 +5,6 @@
 use super::inner::RadixCiphertext;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::details::MaybeCloned;
-use crate::high_level_api::global_state;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
 #[cfg(feature = "gpu")]
@@ -19,6 +18,7 @@ use crate::high_level_api::traits::{
     DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
 };
+use crate::high_level_api::{global_state, profiling};
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
 #[cfg(feature = "hpu")]
@@ -64,6 +64,7 @@ where
     /// assert_eq!(decrypted, clears.into_iter().sum::<u16>());
     /// ```
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let _scope = profiling::scope("sum");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertexts = iter.map(|elem| elem.ciphertext.into_cpu()).collect();
@@ -147,6 +148,7 @@ where
     /// assert_eq!(decrypted, clears.into_iter().sum::<u16>());
     /// ```
     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        let _scope = profiling::scope("sum");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertexts = iter
@@ -250,6 +252,7 @@ where
     /// assert_eq!(decrypted_max, 2u16);
     /// ```
     fn max(&self, rhs: &Self) -> Self::Output {
+        let _scope = profiling::scope("max");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -268,10 +271,26 @@ where
                 Self::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                use crate::prelude::IfThenElse;
-                let max_cmp = self.ge(rhs);
-                max_cmp.if_then_else(self, rhs)
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_MAX;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                // These clones are cheap are they are just Arc
+                let hpu_result = HpuRadixCiphertext::exec(
+                    proto,
+                    opcode,
+                    &[hpu_lhs.clone(), hpu_rhs.clone()],
+                    &[],
+                )
+                .pop()
+                .unwrap();
+                Self::new(hpu_result, device.tag.clone())
             }
         })
     }
@@ -313,6 +332,7 @@ where
     /// assert_eq!(decrypted_min, 1u16);
     /// ```
     fn min(&self, rhs: &Self) -> Self::Output {
+        let _scope = profiling::scope("min");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -331,10 +351,26 @@ where
                 Self::new(inner_result, cuda_key.tag.clone())
             }
             #[cfg(feature = "hpu")]
-            InternalServerKey::Hpu(_device) => {
-                use crate::prelude::IfThenElse;
-                let min_cmp = self.le(rhs);
-                min_cmp.if_then_else(self, rhs)
+            InternalServerKey::Hpu(device) => {
+                let hpu_lhs = self.ciphertext.on_hpu(device);
+                let hpu_rhs = rhs.ciphertext.on_hpu(device);
+                let (opcode, proto) = {
+                    let asm_iop = &hpu_asm::iop::IOP_MIN;
+                    (
+                        asm_iop.opcode(),
+                        &asm_iop.format().expect("Unspecified IOP format").proto,
+                    )
+                };
+                // These clones are cheap are they are just Arc
+                let hpu_result = HpuRadixCiphertext::exec(
+                    proto,
+                    opcode,
+                    &[hpu_lhs.clone(), hpu_rhs.clone()],
+                    &[],
+                )
+                .pop()
+                .unwrap();
+                Self::new(hpu_result, device.tag.clone())
             }
         })
     }
@@ -387,6 +423,7 @@ where
     /// assert_eq!(decrypted, 1u16 == 2u16);
     /// ```
     fn eq(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("eq");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -449,6 +486,7 @@ where
     /// assert_eq!(decrypted, 1u16 != 2u16);
     /// ```
     fn ne(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("ne");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -537,6 +575,7 @@ where
     /// assert_eq!(decrypted, 1u16 < 2u16);
     /// ```
     fn lt(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("lt");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -599,6 +638,7 @@ where
     /// assert_eq!(decrypted, 1u16 <= 2u16);
     /// ```
     fn le(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("le");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -661,6 +701,7 @@ where
     /// assert_eq!(decrypted, 1u16 > 2u16);
     /// ```
     fn gt(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("gt");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -723,6 +764,7 @@ where
     /// assert_eq!(decrypted, 1u16 > 2u16);
     /// ```
     fn ge(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("ge");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -826,6 +868,7 @@ where
     /// assert_eq!(remainder, 23u16 % 3u16);
     /// ```
     fn div_rem(self, rhs: Self) -> Self::Output {
+        let _scope = profiling::scope("div_rem");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (q, r) = cpu_key
@@ -912,6 +955,7 @@ macro_rules! generic_integer_impl_operation (
 
             $(#[$outer])*
             fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let _scope = profiling::scope(stringify!($rust_trait_method));
                 $closure(self, rhs.borrow())
             }
         }
@@ -1413,6 +1457,7 @@ macro_rules! generic_integer_impl_shift_rotate (
 
             $(#[$outer])*
             fn $rust_trait_method(self, rhs: &FheUint<Id2>) -> Self::Output {
+                let _scope = profiling::scope(stringify!($rust_trait_method));
                 $closure(self, rhs.borrow())
             }
         }
@@ -1703,6 +1748,7 @@ where
     /// assert_eq!(result, 3u16.wrapping_add(37849u16));
     /// ```
     fn add_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("add_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1755,6 +1801,7 @@ where
     /// assert_eq!(result, 3u16.wrapping_sub(37849u16));
     /// ```
     fn sub_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("sub_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1807,6 +1854,7 @@ where
     /// assert_eq!(result, 3u16.wrapping_mul(37849u16));
     /// ```
     fn mul_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("mul_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1857,6 +1905,7 @@ where
     /// assert_eq!(result, 3u16 & 37849u16);
     /// ```
     fn bitand_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("bitand_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1907,6 +1956,7 @@ where
     /// assert_eq!(result, 3u16 | 37849u16);
     /// ```
     fn bitor_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("bitor_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -1957,6 +2007,7 @@ where
     /// assert_eq!(result, 3u16 ^ 37849u16);
     /// ```
     fn bitxor_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("bitxor_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -2012,6 +2063,7 @@ where
     /// assert_eq!(result, 37849u16 / 3u16);
     /// ```
     fn div_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("div_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -2085,6 +2137,7 @@ where
     /// assert_eq!(result, 37849u16 % 3u16);
     /// ```
     fn rem_assign(&mut self, rhs: I) {
+        let _scope = profiling::scope("rem_assign");
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
@@ -2160,6 +2213,7 @@ where
     /// assert_eq!(result, 37849u16 << 3u16);
     /// ```
     fn shl_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _scope = profiling::scope("shl_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().left_shift_assign_parallelized(
@@ -2235,6 +2289,7 @@ where
     /// assert_eq!(result, 37849u16 >> 3u16);
     /// ```
     fn shr_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _scope = profiling::scope("shr_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().right_shift_assign_parallelized(
@@ -2311,6 +2366,7 @@ where
     /// assert_eq!(result, 37849u16.rotate_left(3));
     /// ```
     fn rotate_left_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _scope = profiling::scope("rotate_left_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().rotate_left_assign_parallelized(
@@ -2387,6 +2443,7 @@ where
     /// assert_eq!(result, 37849u16.rotate_right(3));
     /// ```
     fn rotate_right_assign(&mut self, rhs: &FheUint<Id2>) {
+        let _scope = profiling::scope("rotate_right_assign");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 cpu_key.pbs_key().rotate_right_assign_parallelized(
@@ -2487,6 +2544,7 @@ where
     /// assert_eq!(result, 3u16.wrapping_neg());
     /// ```
     fn neg(self) -> Self::Output {
+        let _scope = profiling::scope("neg");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = cpu_key
@@ -2577,6 +2635,7 @@ where
     /// assert_eq!(result, !3u16);
     /// ```
     fn not(self) -> Self::Output {
+        let _scope = profiling::scope("not");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertext = cpu_key.pbs_key().bitnot(&*self.ciphertext.on_cpu());
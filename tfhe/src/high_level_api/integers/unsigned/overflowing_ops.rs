@@ -1,7 +1,7 @@
 use crate::core_crypto::prelude::UnsignedNumeric;
-use crate::high_level_api::global_state;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::{global_state, profiling};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::prelude::{CastInto, OverflowingAdd, OverflowingMul, OverflowingNeg, OverflowingSub};
 use crate::{FheBool, FheUint};
@@ -44,6 +44,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_add(self, other: Self) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_add");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().unsigned_overflowing_add_parallelized(
@@ -168,6 +169,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_add(self, other: Clear) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_add");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key
@@ -329,6 +331,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_sub(self, other: Self) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_sub");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().unsigned_overflowing_sub_parallelized(
@@ -453,6 +456,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_sub(self, other: Clear) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_sub");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key
@@ -564,6 +568,7 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_mul(self, other: Self) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_mul");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key.pbs_key().unsigned_overflowing_mul_parallelized(
@@ -651,6 +656,7 @@ where
     type Output = FheUint<Id>;
 
     fn overflowing_neg(self) -> (Self::Output, FheBool) {
+        let _scope = profiling::scope("overflowing_neg");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let (result, overflow) = cpu_key
@@ -6,7 +6,6 @@ use super::base::FheUint;
 use super::inner::RadixCiphertext;
 use crate::error::InvalidRangeError;
 use crate::high_level_api::errors::UnwrapResultExt;
-use crate::high_level_api::global_state;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
 #[cfg(feature = "gpu")]
@@ -19,6 +18,7 @@ use crate::high_level_api::traits::{
     BitSlice, DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
 };
+use crate::high_level_api::{global_state, profiling};
 use crate::integer::bigint::{U1024, U2048, U512};
 use crate::integer::block_decomposition::DecomposableInto;
 #[cfg(feature = "gpu")]
@@ -60,6 +60,7 @@ where
     /// assert_eq!(decrypted, 1u16 == 2u16);
     /// ```
     fn eq(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_eq");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -104,6 +105,7 @@ where
     /// assert_eq!(decrypted, 1u16 != 2u16);
     /// ```
     fn ne(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_ne");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -154,6 +156,7 @@ where
     /// assert_eq!(decrypted, 1u16 < 2u16);
     /// ```
     fn lt(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_lt");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -198,6 +201,7 @@ where
     /// assert_eq!(decrypted, 1u16 <= 2u16);
     /// ```
     fn le(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_le");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -242,6 +246,7 @@ where
     /// assert_eq!(decrypted, 1u16 > 2u16);
     /// ```
     fn gt(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_gt");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -286,6 +291,7 @@ where
     /// assert_eq!(decrypted, 1u16 >= 2u16);
     /// ```
     fn ge(&self, rhs: Clear) -> FheBool {
+        let _scope = profiling::scope("scalar_ge");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -473,6 +479,7 @@ where
     /// assert_eq!(decrypted_max, 2u16);
     /// ```
     fn max(&self, rhs: Clear) -> Self::Output {
+        let _scope = profiling::scope("scalar_max");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -525,6 +532,7 @@ where
     /// assert_eq!(decrypted_min, 1u16);
     /// ```
     fn min(&self, rhs: Clear) -> Self::Output {
+        let _scope = profiling::scope("scalar_min");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner_result = cpu_key
@@ -585,6 +593,7 @@ where
     where
         R: RangeBounds<Clear>,
     {
+        let _scope = profiling::scope("bitslice");
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let result = cpu_key
@@ -669,6 +678,7 @@ macro_rules! generic_integer_impl_scalar_div_rem {
                     type Output = ($concrete_type, $concrete_type);
 
                     fn div_rem(self, rhs: $scalar_type) -> Self::Output {
+                        let _scope = profiling::scope("scalar_div_rem");
                         global_state::with_internal_keys(|key| {
                             match key {
                                 InternalServerKey::Cpu(cpu_key) => {
@@ -756,6 +766,7 @@ macro_rules! generic_integer_impl_scalar_operation {
                     type Output = $concrete_type;
 
                     fn $rust_trait_method(self, rhs: $scalar_type) -> Self::Output {
+                        let _scope = profiling::scope(concat!("scalar_", stringify!($rust_trait_method)));
                         let inner_result = $closure(self, rhs);
                         let tag = global_state::tag_of_internal_server_key().unwrap_display();
                         <$concrete_type>::new(inner_result, tag)
@@ -854,6 +865,7 @@ macro_rules! generic_integer_impl_scalar_left_operation {
 
                     $(#[$doc])*
                     fn $rust_trait_method(self, rhs: &$concrete_type) -> Self::Output {
+                        let _scope = profiling::scope(concat!("scalar_", stringify!($rust_trait_method)));
                         let inner_result = $closure(*self, rhs);
                         let tag = global_state::tag_of_internal_server_key().unwrap_display();
                         <$concrete_type>::new(inner_result, tag)
@@ -923,6 +935,7 @@ macro_rules! generic_integer_impl_scalar_operation_assign {
                 {
                     $(#[$doc])*
                     fn $rust_trait_method(&mut self, rhs: $scalar_type) {
+                        let _scope = profiling::scope(concat!("scalar_", stringify!($rust_trait_method)));
                         $closure(self, rhs);
                     }
                 }
@@ -1580,8 +1593,11 @@ macro_rules! define_scalar_ops {
                             RadixCiphertext::Cuda(inner_result)
                         }
                         #[cfg(feature = "hpu")]
-                        InternalServerKey::Hpu(_device) => {
-                            panic!("Hpu does not support this operation yet.")
+                        InternalServerKey::Hpu(device) => {
+                            let lhs = lhs.ciphertext.on_hpu(device);
+                            let rhs = u128::try_from(rhs).unwrap();
+
+                            RadixCiphertext::Hpu(&*lhs & rhs)
                         }
                     })
                 }
@@ -1637,8 +1653,11 @@ macro_rules! define_scalar_ops {
                             RadixCiphertext::Cuda(inner_result)
                         }
                         #[cfg(feature = "hpu")]
-                        InternalServerKey::Hpu(_device) => {
-                            panic!("Hpu does not support this operation yet.")
+                        InternalServerKey::Hpu(device) => {
+                            let lhs = lhs.ciphertext.on_hpu(device);
+                            let rhs = u128::try_from(rhs).unwrap();
+
+                            RadixCiphertext::Hpu(&*lhs | rhs)
                         }
                     })
                 }
@@ -1695,8 +1714,11 @@ macro_rules! define_scalar_ops {
                             RadixCiphertext::Cuda(inner_result)
                         }
                         #[cfg(feature = "hpu")]
-                        InternalServerKey::Hpu(_device) => {
-                            panic!("Hpu does not support this operation yet.")
+                        InternalServerKey::Hpu(device) => {
+                            let lhs = lhs.ciphertext.on_hpu(device);
+                            let rhs = u128::try_from(rhs).unwrap();
+
+                            RadixCiphertext::Hpu(&*lhs ^ rhs)
                         }
                     })
                 }
@@ -2252,8 +2274,11 @@ macro_rules! define_scalar_ops {
                                     .scalar_bitand_assign(lhs.ciphertext.as_gpu_mut(streams), rhs, streams);
                         }
                         #[cfg(feature = "hpu")]
-                        InternalServerKey::Hpu(_device) => {
-                            panic!("Hpu does not support this operation yet.")
+                        InternalServerKey::Hpu(device) => {
+                            let lhs = lhs.ciphertext.as_hpu_mut(device);
+                            let rhs = u128::try_from(rhs).unwrap();
+
+                            *lhs &= rhs;
                         }
                     })
                 }
@@ -2282,8 +2307,11 @@ macro_rules! define_scalar_ops {
                                     .scalar_bitor_assign(lhs.ciphertext.as_gpu_mut(streams), rhs, streams);
                         }
                         #[cfg(feature = "hpu")]
-                        InternalServerKey::Hpu(_device) => {
-                            panic!("Hpu does not support this operation yet.")
+                        InternalServerKey::Hpu(device) => {
+                            let lhs = lhs.ciphertext.as_hpu_mut(device);
+                            let rhs = u128::try_from(rhs).unwrap();
+
+                            *lhs |= rhs;
                         }
                     })
                 }
@@ -2311,8 +2339,11 @@ macro_rules! define_scalar_ops {
                                     .scalar_bitxor_assign(lhs.ciphertext.as_gpu_mut(streams), rhs, streams);
                         }
                         #[cfg(feature = "hpu")]
-                        InternalServerKey::Hpu(_device) => {
-                            panic!("Hpu does not support this operation yet.")
+                        InternalServerKey::Hpu(device) => {
+                            let lhs = lhs.ciphertext.as_hpu_mut(device);
+                            let rhs = u128::try_from(rhs).unwrap();
+
+                            *lhs ^= rhs;
                         }
                     })
                 }
@@ -8,7 +8,7 @@ use crate::high_level_api::errors::UninitializedNoiseSquashing;
 use crate::high_level_api::global_state::{self, with_internal_keys};
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::{FheDecrypt, SquashNoise, Tagged};
-use crate::high_level_api::SquashedNoiseCiphertextState;
+use crate::high_level_api::{profiling, SquashedNoiseCiphertextState};
 use crate::integer::block_decomposition::RecomposableFrom;
 use crate::named::Named;
 use crate::{ClientKey, Device, Tag};
@@ -184,6 +184,7 @@ impl<Id: FheUintId> SquashNoise for FheUint<Id> {
     type Output = SquashedNoiseFheUint;
 
     fn squash_noise(&self) -> crate::Result<Self::Output> {
+        let _scope = profiling::scope("squash_noise");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(server_key) => {
                 let noise_squashing_key = server_key
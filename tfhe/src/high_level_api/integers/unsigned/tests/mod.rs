@@ -740,3 +740,25 @@ fn test_case_min_max(cks: &ClientKey) {
     assert_eq!(decrypted_min, a_val.min(b_val));
     assert_eq!(decrypted_max, a_val.max(b_val));
 }
+
+fn test_case_gcd_lcm(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    let a_val: u8 = rng.gen_range(1..=u8::MAX);
+    let b_val: u8 = rng.gen_range(1..=u8::MAX);
+
+    let a = FheUint8::encrypt(a_val, cks);
+    let b = FheUint8::encrypt(b_val, cks);
+
+    let mut expected_gcd = a_val;
+    let mut tmp = b_val;
+    while tmp != 0 {
+        (expected_gcd, tmp) = (tmp, expected_gcd % tmp);
+    }
+
+    let decrypted_gcd: u8 = a.gcd(&b).decrypt(cks);
+    assert_eq!(decrypted_gcd, expected_gcd, "Invalid gcd({a_val}, {b_val})");
+
+    let decrypted_lcm: u8 = a.lcm(&b).decrypt(cks);
+    let expected_lcm = (a_val / expected_gcd).wrapping_mul(b_val);
+    assert_eq!(decrypted_lcm, expected_lcm, "Invalid lcm({a_val}, {b_val})");
+}
@@ -55,6 +55,25 @@ impl ServerKey {
         }
     }
 
+    /// Generates an insecure server key that evaluates lookup tables in the clear, to quickly
+    /// debug and test programs.
+    ///
+    /// Operations give the same results, degrees, noise levels and number of PBS as with a key
+    /// generated by [`ServerKey::new`], but no keyswitch or bootstrap is computed. Only the
+    /// computation key is generated: compression, noise squashing and the casting of compact
+    /// public key ciphertexts are not available. The key cannot be serialized.
+    ///
+    /// # Warning
+    ///
+    /// The returned key contains the secret key of `keys`, it must only be used for tests and
+    /// debugging.
+    #[cfg(feature = "cleartext-simulation")]
+    pub fn new_cleartext_simulation(keys: &ClientKey) -> Self {
+        let key = crate::integer::ServerKey::new_radix_cleartext_simulation(&keys.key.key);
+
+        Self::from_raw_parts(key, None, None, None, None, None, keys.tag.clone())
+    }
+
     #[allow(clippy::type_complexity)]
     pub fn into_raw_parts(
         self,
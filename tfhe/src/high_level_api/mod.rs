@@ -51,6 +51,7 @@ pub use crate::integer::server_key::MatchValues;
 use crate::{error, Error, Versionize};
 use backward_compatibility::compressed_ciphertext_list::SquashedNoiseCiphertextStateVersions;
 pub use config::{Config, ConfigBuilder};
+pub use executor::{FheExecutor, FheTask};
 #[cfg(feature = "gpu")]
 pub use global_state::CudaGpuChoice;
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
@@ -130,7 +131,12 @@ pub use compressed_noise_squashed_ciphertext_list::{
     HlSquashedNoiseCompressible, HlSquashedNoiseExpandable,
 };
 #[cfg(feature = "strings")]
-pub use strings::ascii::{EncryptableString, FheAsciiString, FheStringIsEmpty, FheStringLen};
+pub use strings::ascii::{
+    EncryptableString, FheAsciiString, FheAsciiStringConformanceParams, FheStringIsEmpty,
+    FheStringLen,
+};
+#[cfg(feature = "strings")]
+pub use strings::utf8::FheUtf8String;
 pub use tag::Tag;
 pub use traits::FheId;
 
@@ -138,6 +144,7 @@ mod booleans;
 mod compressed_ciphertext_list;
 mod config;
 mod errors;
+mod executor;
 mod global_state;
 mod integers;
 mod keys;
@@ -153,6 +160,8 @@ pub mod array;
 pub mod backward_compatibility;
 mod compact_list;
 mod tag;
+#[cfg(feature = "transciphering")]
+pub mod transciphering;
 
 #[cfg(feature = "gpu")]
 pub use crate::core_crypto::gpu::vec::GpuIndex;
@@ -161,6 +170,10 @@ mod compressed_noise_squashed_ciphertext_list;
 pub(in crate::high_level_api) mod details;
 /// The tfhe prelude.
 pub mod prelude;
+#[cfg(feature = "profiling")]
+pub mod profiling;
+#[cfg(not(feature = "profiling"))]
+pub(crate) mod profiling;
 pub mod upgrade;
 #[cfg(feature = "zk-pok")]
 mod zk;
@@ -19,6 +19,7 @@ pub use crate::conformance::ParameterSetConformant;
 pub use crate::core_crypto::prelude::{CastFrom, CastInto};
 
 pub use crate::high_level_api::array::traits::FheSliceDotProduct;
+pub use crate::high_level_api::executor::FheAsyncOps;
 
 #[cfg(feature = "gpu")]
 pub use crate::high_level_api::gpu_utils::*;
@@ -31,3 +32,5 @@ pub use crate::high_level_api::traits::{
     FheOrdSizeOnGpu, IfThenElseSizeOnGpu, MulSizeOnGpu, NegSizeOnGpu, RemSizeOnGpu,
     RotateLeftSizeOnGpu, RotateRightSizeOnGpu, ShlSizeOnGpu, ShrSizeOnGpu, SizeOnGpu, SubSizeOnGpu,
 };
+#[cfg(feature = "transciphering")]
+pub use crate::high_level_api::transciphering::StreamCipher;
@@ -0,0 +1,343 @@
+//! Profiling of the operations of the high level API, enabled by the `profiling` feature.
+//!
+//! A [`ProfilingRecorder`] attributes the blind rotations and keyswitches computed by the server
+//! key to the high level operation (`add`, `mul`, `div_rem`, ...) that computed them. Users can
+//! label their own code with [`scope`], operations called inside a scope are attributed to the
+//! stack of scopes they were called from.
+//!
+//! ```rust
+//! use tfhe::prelude::*;
+//! use tfhe::profiling::{scope, FoldedStacksMetric, ProfilingRecorder};
+//! use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+//!
+//! let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+//! set_server_key(server_key);
+//!
+//! let a = FheUint8::encrypt(23u8, &client_key);
+//! let b = FheUint8::encrypt(3u8, &client_key);
+//!
+//! let recorder = ProfilingRecorder::start().unwrap();
+//! {
+//!     let _scope = scope("my_circuit");
+//!     let c = &a * &b;
+//!     let _ = &c + &a;
+//! }
+//! let report = recorder.finish();
+//!
+//! let mul = report.get("my_circuit;mul").unwrap();
+//! assert_eq!(mul.calls, 1);
+//! assert!(mul.inclusive.pbs_count > 0);
+//!
+//! println!("{}", report.to_folded_stacks(FoldedStacksMetric::PbsCount));
+//! ```
+//!
+//! The counters of the server key are global: operations run concurrently from several threads
+//! are attributed to each of the scopes open at that time. Only the computations done on CPU are
+//! recorded.
+
+#[cfg(feature = "profiling")]
+pub use recording::*;
+
+/// Guard of a profiling scope, the scope is closed when it is dropped
+#[cfg(not(feature = "profiling"))]
+#[must_use]
+pub(crate) struct ProfileScope;
+
+/// Opens a profiling scope, which does nothing when the `profiling` feature is disabled
+#[cfg(not(feature = "profiling"))]
+#[inline(always)]
+pub(crate) fn scope(_name: &'static str) -> ProfileScope {
+    ProfileScope
+}
+
+#[cfg(feature = "profiling")]
+mod recording {
+    use crate::shortint::server_key::profiling::Counters;
+    use serde::Serialize;
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+    use std::fmt::Write;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    static RECORDING: AtomicBool = AtomicBool::new(false);
+    static PROFILES: Mutex<BTreeMap<String, OperationProfile>> = Mutex::new(BTreeMap::new());
+
+    /// Resources used by an operation
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize)]
+    pub struct ResourceUsage {
+        /// Number of PBS, including the ones evaluating several lookup tables
+        pub pbs_count: u64,
+        pub keyswitch_count: u64,
+        /// Number of PBS evaluating several lookup tables at once
+        pub many_lut_count: u64,
+        pub wall_time: Duration,
+        /// Time spent by all the threads computing keyswitches and PBS
+        pub busy_time: Duration,
+    }
+
+    impl ResourceUsage {
+        fn new(counters: Counters, wall_time: Duration) -> Self {
+            Self {
+                pbs_count: counters.pbs_count,
+                keyswitch_count: counters.keyswitch_count,
+                many_lut_count: counters.many_lut_count,
+                wall_time,
+                busy_time: counters.busy_time,
+            }
+        }
+
+        /// Returns the fraction of the available threads that were computing keyswitches and
+        /// PBS during the operation
+        pub fn thread_utilization(&self, thread_count: usize) -> f64 {
+            if self.wall_time.is_zero() || thread_count == 0 {
+                return 0.0;
+            }
+
+            self.busy_time.as_secs_f64() / (self.wall_time.as_secs_f64() * thread_count as f64)
+        }
+    }
+
+    impl std::ops::AddAssign for ResourceUsage {
+        fn add_assign(&mut self, rhs: Self) {
+            self.pbs_count += rhs.pbs_count;
+            self.keyswitch_count += rhs.keyswitch_count;
+            self.many_lut_count += rhs.many_lut_count;
+            self.wall_time += rhs.wall_time;
+            self.busy_time += rhs.busy_time;
+        }
+    }
+
+    impl std::ops::Sub for ResourceUsage {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            Self {
+                pbs_count: self.pbs_count.saturating_sub(rhs.pbs_count),
+                keyswitch_count: self.keyswitch_count.saturating_sub(rhs.keyswitch_count),
+                many_lut_count: self.many_lut_count.saturating_sub(rhs.many_lut_count),
+                wall_time: self.wall_time.saturating_sub(rhs.wall_time),
+                busy_time: self.busy_time.saturating_sub(rhs.busy_time),
+            }
+        }
+    }
+
+    /// Profile of an operation called from a given stack of scopes
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize)]
+    pub struct OperationProfile {
+        pub calls: u64,
+        /// Resources used by the operation, including the scopes it opened
+        pub inclusive: ResourceUsage,
+        /// Resources used by the operation itself
+        pub exclusive: ResourceUsage,
+    }
+
+    struct Frame {
+        path: String,
+        start: Instant,
+        start_counters: Counters,
+        children: ResourceUsage,
+    }
+
+    thread_local! {
+        static STACK: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Guard of a profiling scope, the scope is closed when it is dropped
+    #[must_use]
+    pub struct ProfileScope {
+        active: bool,
+    }
+
+    /// Opens a profiling scope, the operations done until the returned guard is dropped are
+    /// attributed to it
+    ///
+    /// Scopes can be nested, and do nothing if no [`ProfilingRecorder`] is active.
+    pub fn scope(name: &'static str) -> ProfileScope {
+        if !RECORDING.load(Ordering::Relaxed) {
+            return ProfileScope { active: false };
+        }
+
+        STACK.with_borrow_mut(|stack| {
+            let path = stack.last().map_or_else(
+                || name.to_owned(),
+                |parent| format!("{};{name}", parent.path),
+            );
+            stack.push(Frame {
+                path,
+                start: Instant::now(),
+                start_counters: Counters::snapshot(),
+                children: ResourceUsage::default(),
+            });
+        });
+
+        ProfileScope { active: true }
+    }
+
+    impl Drop for ProfileScope {
+        fn drop(&mut self) {
+            if !self.active {
+                return;
+            }
+
+            let Some(frame) = STACK.with_borrow_mut(Vec::pop) else {
+                return;
+            };
+
+            let inclusive = ResourceUsage::new(
+                Counters::snapshot() - frame.start_counters,
+                frame.start.elapsed(),
+            );
+
+            STACK.with_borrow_mut(|stack| {
+                if let Some(parent) = stack.last_mut() {
+                    parent.children += inclusive;
+                }
+            });
+
+            // The recording may have been stopped while the scope was open
+            if !RECORDING.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let mut profiles = PROFILES.lock().unwrap();
+            let profile = profiles.entry(frame.path).or_default();
+            profile.calls += 1;
+            profile.inclusive += inclusive;
+            profile.exclusive += inclusive - frame.children;
+        }
+    }
+
+    /// Records the profile of the operations done between its creation and [`Self::finish`]
+    ///
+    /// Only one recorder can be active at a time.
+    pub struct ProfilingRecorder {
+        start: Instant,
+    }
+
+    impl ProfilingRecorder {
+        pub fn start() -> crate::Result<Self> {
+            if RECORDING.swap(true, Ordering::AcqRel) {
+                return Err(crate::error!("A profiling recorder is already active"));
+            }
+
+            PROFILES.lock().unwrap().clear();
+
+            Ok(Self {
+                start: Instant::now(),
+            })
+        }
+
+        /// Stops the recording and returns the report
+        pub fn finish(self) -> ProfilingReport {
+            let wall_time = self.start.elapsed();
+            // Drop stops the recording
+            drop(self);
+
+            ProfilingReport {
+                wall_time,
+                thread_count: rayon::current_num_threads(),
+                operations: std::mem::take(&mut *PROFILES.lock().unwrap()),
+            }
+        }
+    }
+
+    impl Drop for ProfilingRecorder {
+        fn drop(&mut self) {
+            RECORDING.store(false, Ordering::Release);
+        }
+    }
+
+    /// Metric used as the value of the folded stacks
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum FoldedStacksMetric {
+        PbsCount,
+        KeyswitchCount,
+        /// Wall time in microseconds
+        WallTime,
+        /// Busy time of all the threads in microseconds
+        BusyTime,
+    }
+
+    /// Profile of the operations recorded by a [`ProfilingRecorder`]
+    #[derive(Clone, Debug, Serialize)]
+    pub struct ProfilingReport {
+        /// Duration of the recording
+        pub wall_time: Duration,
+        /// Number of threads available to the computations
+        pub thread_count: usize,
+        /// Profiles indexed by the stack of scopes of the operations, separated by `;`
+        pub operations: BTreeMap<String, OperationProfile>,
+    }
+
+    impl ProfilingReport {
+        /// Returns the profile of the operation called from the given stack, e.g.
+        /// `"my_circuit;add"`
+        pub fn get(&self, stack: &str) -> Option<&OperationProfile> {
+            self.operations.get(stack)
+        }
+
+        /// Returns the profiles summed by operation name, regardless of the scopes they were
+        /// called from
+        pub fn by_operation(&self) -> BTreeMap<&str, OperationProfile> {
+            let mut result = BTreeMap::<&str, OperationProfile>::new();
+            for (stack, profile) in &self.operations {
+                let name = stack.rsplit(';').next().unwrap_or(stack);
+                let entry = result.entry(name).or_default();
+                entry.calls += profile.calls;
+                entry.inclusive += profile.inclusive;
+                entry.exclusive += profile.exclusive;
+            }
+            result
+        }
+
+        pub fn to_json(&self) -> String {
+            serde_json::to_string_pretty(self).unwrap()
+        }
+
+        /// Exports the report in the folded stacks format, which can be turned into a
+        /// flamegraph (e.g. with inferno or `flamegraph.pl`)
+        pub fn to_folded_stacks(&self, metric: FoldedStacksMetric) -> String {
+            let mut result = String::new();
+            for (stack, profile) in &self.operations {
+                let usage = &profile.exclusive;
+                let value = match metric {
+                    FoldedStacksMetric::PbsCount => usage.pbs_count,
+                    FoldedStacksMetric::KeyswitchCount => usage.keyswitch_count,
+                    FoldedStacksMetric::WallTime => usage.wall_time.as_micros() as u64,
+                    FoldedStacksMetric::BusyTime => usage.busy_time.as_micros() as u64,
+                };
+                if value != 0 {
+                    writeln!(result, "{stack} {value}").unwrap();
+                }
+            }
+            result
+        }
+    }
+
+    impl std::fmt::Display for ProfilingReport {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(
+                f,
+                "{:<40} {:>8} {:>10} {:>10} {:>10} {:>12} {:>8}",
+                "operation", "calls", "PBS", "KS", "many LUT", "wall time", "threads"
+            )?;
+            for (stack, profile) in &self.operations {
+                let usage = &profile.inclusive;
+                writeln!(
+                    f,
+                    "{:<40} {:>8} {:>10} {:>10} {:>10} {:>12.3?} {:>7.1}%",
+                    stack,
+                    profile.calls,
+                    usage.pbs_count,
+                    usage.keyswitch_count,
+                    usage.many_lut_count,
+                    usage.wall_time,
+                    100.0 * usage.thread_utilization(self.thread_count),
+                )?;
+            }
+            write!(f, "total wall time: {:.3?}", self.wall_time)
+        }
+    }
+}
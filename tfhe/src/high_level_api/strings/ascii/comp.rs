@@ -1,5 +1,6 @@
 use crate::high_level_api::global_state::with_internal_keys;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::profiling;
 use crate::high_level_api::strings::ascii::FheAsciiString;
 use crate::prelude::{FheEq, FheEqIgnoreCase, FheOrd};
 use crate::strings::ciphertext::ClearString;
@@ -7,6 +8,7 @@ use crate::FheBool;
 
 impl FheEq<&Self> for FheAsciiString {
     fn eq(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("eq");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -26,6 +28,7 @@ impl FheEq<&Self> for FheAsciiString {
     }
 
     fn ne(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("ne");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -47,6 +50,7 @@ impl FheEq<&Self> for FheAsciiString {
 
 impl FheEq<&ClearString> for FheAsciiString {
     fn eq(&self, other: &ClearString) -> FheBool {
+        let _scope = profiling::scope("eq");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().eq(&self.inner.on_cpu(), other.into());
@@ -64,6 +68,7 @@ impl FheEq<&ClearString> for FheAsciiString {
     }
 
     fn ne(&self, other: &ClearString) -> FheBool {
+        let _scope = profiling::scope("ne");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().ne(&self.inner.on_cpu(), other.into());
@@ -83,6 +88,7 @@ impl FheEq<&ClearString> for FheAsciiString {
 
 impl FheOrd<&Self> for FheAsciiString {
     fn lt(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("lt");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -102,6 +108,7 @@ impl FheOrd<&Self> for FheAsciiString {
     }
 
     fn le(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("le");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -121,6 +128,7 @@ impl FheOrd<&Self> for FheAsciiString {
     }
 
     fn gt(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("gt");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -140,6 +148,7 @@ impl FheOrd<&Self> for FheAsciiString {
     }
 
     fn ge(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("ge");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -161,6 +170,7 @@ impl FheOrd<&Self> for FheAsciiString {
 
 impl FheOrd<&ClearString> for FheAsciiString {
     fn lt(&self, other: &ClearString) -> FheBool {
+        let _scope = profiling::scope("lt");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().lt(&self.inner.on_cpu(), other.into());
@@ -178,6 +188,7 @@ impl FheOrd<&ClearString> for FheAsciiString {
     }
 
     fn le(&self, other: &ClearString) -> FheBool {
+        let _scope = profiling::scope("le");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().le(&self.inner.on_cpu(), other.into());
@@ -195,6 +206,7 @@ impl FheOrd<&ClearString> for FheAsciiString {
     }
 
     fn gt(&self, other: &ClearString) -> FheBool {
+        let _scope = profiling::scope("gt");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().gt(&self.inner.on_cpu(), other.into());
@@ -212,6 +224,7 @@ impl FheOrd<&ClearString> for FheAsciiString {
     }
 
     fn ge(&self, other: &ClearString) -> FheBool {
+        let _scope = profiling::scope("ge");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().ge(&self.inner.on_cpu(), other.into());
@@ -250,6 +263,7 @@ impl FheEqIgnoreCase for FheAsciiString {
     /// assert!(is_eq.decrypt(&client_key));
     /// ```
     fn eq_ignore_case(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("eq_ignore_case");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -290,6 +304,7 @@ impl FheEqIgnoreCase<ClearString> for FheAsciiString {
     /// assert!(is_eq.decrypt(&client_key));
     /// ```
     fn eq_ignore_case(&self, rhs: &ClearString) -> FheBool {
+        let _scope = profiling::scope("eq_ignore_case");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -1,5 +1,6 @@
 use crate::high_level_api::global_state::with_internal_keys;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::profiling;
 use crate::high_level_api::strings::ascii::FheAsciiString;
 use crate::high_level_api::strings::traits::FheStringMatching;
 use crate::strings::ciphertext::ClearString;
@@ -26,6 +27,7 @@ impl FheStringMatching<&Self> for FheAsciiString {
     /// assert!(found.decrypt(&client_key));
     /// ```
     fn contains(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("contains");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -64,6 +66,7 @@ impl FheStringMatching<&Self> for FheAsciiString {
     /// assert!(!found.decrypt(&client_key));
     /// ```
     fn starts_with(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("starts_with");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -102,6 +105,7 @@ impl FheStringMatching<&Self> for FheAsciiString {
     /// assert!(found.decrypt(&client_key));
     /// ```
     fn ends_with(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("ends_with");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -123,6 +127,7 @@ impl FheStringMatching<&Self> for FheAsciiString {
 
 impl FheStringMatching<&ClearString> for FheAsciiString {
     fn contains(&self, other: &ClearString) -> FheBool {
+        let _scope = profiling::scope("contains");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -142,6 +147,7 @@ impl FheStringMatching<&ClearString> for FheAsciiString {
     }
 
     fn starts_with(&self, other: &ClearString) -> FheBool {
+        let _scope = profiling::scope("starts_with");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -161,6 +167,7 @@ impl FheStringMatching<&ClearString> for FheAsciiString {
     }
 
     fn ends_with(&self, other: &ClearString) -> FheBool {
+        let _scope = profiling::scope("ends_with");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -1,5 +1,6 @@
 use crate::high_level_api::global_state::with_internal_keys;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::profiling;
 use crate::high_level_api::strings::ascii::FheAsciiString;
 use crate::high_level_api::strings::traits::FheStringFind;
 use crate::strings::ciphertext::ClearString;
@@ -29,6 +30,7 @@ impl FheStringFind<&Self> for FheAsciiString {
     /// assert_eq!(pos, 1);
     /// ```
     fn find(&self, pat: &Self) -> (FheUint32, FheBool) {
+        let _scope = profiling::scope("find");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let (inner, block) = cpu_key
@@ -73,6 +75,7 @@ impl FheStringFind<&Self> for FheAsciiString {
     /// assert_eq!(pos, 11);
     /// ```
     fn rfind(&self, pat: &Self) -> (FheUint32, FheBool) {
+        let _scope = profiling::scope("rfind");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let (inner, block) = cpu_key
@@ -119,6 +122,7 @@ impl FheStringFind<&ClearString> for FheAsciiString {
     /// assert_eq!(pos, 1);
     /// ```
     fn find(&self, pat: &ClearString) -> (FheUint32, FheBool) {
+        let _scope = profiling::scope("find");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let (inner, block) = cpu_key.string_key().find(&self.inner.on_cpu(), pat.into());
@@ -161,6 +165,7 @@ impl FheStringFind<&ClearString> for FheAsciiString {
     /// assert_eq!(pos, 11);
     /// ```
     fn rfind(&self, pat: &ClearString) -> (FheUint32, FheBool) {
+        let _scope = profiling::scope("rfind");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let (inner, block) = cpu_key.string_key().rfind(&self.inner.on_cpu(), pat.into());
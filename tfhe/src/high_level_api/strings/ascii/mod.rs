@@ -6,17 +6,20 @@ mod replace;
 mod strip;
 mod trim;
 
+use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
 pub use crate::high_level_api::backward_compatibility::strings::FheAsciiStringVersions;
 use crate::high_level_api::details::MaybeCloned;
 use crate::high_level_api::errors::UninitializedServerKey;
 use crate::high_level_api::global_state;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::integer::ciphertext::{Compressible, DataKind, Expandable};
+use crate::integer::parameters::RadixCiphertextConformanceParams;
 use crate::named::Named;
 use crate::prelude::{FheDecrypt, FheTryEncrypt, FheTryTrivialEncrypt, Tagged};
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
-use crate::strings::ciphertext::FheString;
-use crate::{ClientKey, HlExpandable, Tag};
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::{num_ascii_blocks, FheString};
+use crate::{ClientKey, HlExpandable, ServerKey, Tag};
 pub use no_pattern::{FheStringIsEmpty, FheStringLen};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use tfhe_versionable::{Unversionize, UnversionizeError, Versionize, VersionizeOwned};
@@ -27,7 +30,7 @@ pub enum EncryptableString<'a> {
 }
 
 impl EncryptableString<'_> {
-    fn str_and_padding(&self) -> (&str, Option<u32>) {
+    pub(in crate::high_level_api::strings) fn str_and_padding(&self) -> (&str, Option<u32>) {
         match self {
             EncryptableString::NoPadding(str) => (str, None),
             EncryptableString::WithPadding { str, padding } => (str, Some(*padding)),
@@ -134,6 +137,68 @@ impl Named for FheAsciiString {
     const NAME: &'static str = "high_level_api::FheAsciiString";
 }
 
+/// Parameters used to check the conformance of a [`FheAsciiString`]
+///
+/// By default strings of any length are accepted, the number of encrypted characters (including
+/// padding) can be restricted with [`Self::with_len_constraint`].
+#[derive(Copy, Clone)]
+pub struct FheAsciiStringConformanceParams {
+    pub(crate) char_params: RadixCiphertextConformanceParams,
+    pub(crate) len_constraint: Option<ListSizeConstraint>,
+}
+
+impl FheAsciiStringConformanceParams {
+    pub fn with_len_constraint(self, len_constraint: ListSizeConstraint) -> Self {
+        Self {
+            len_constraint: Some(len_constraint),
+            ..self
+        }
+    }
+}
+
+impl<P: Into<PBSParameters>> From<P> for FheAsciiStringConformanceParams {
+    fn from(params: P) -> Self {
+        let params = params.into();
+        Self {
+            char_params: RadixCiphertextConformanceParams {
+                shortint_params: params.to_shortint_conformance_param(),
+                num_blocks_per_integer: num_ascii_blocks(params.message_modulus()),
+            },
+            len_constraint: None,
+        }
+    }
+}
+
+impl From<&ServerKey> for FheAsciiStringConformanceParams {
+    fn from(sks: &ServerKey) -> Self {
+        Self {
+            char_params: RadixCiphertextConformanceParams {
+                shortint_params: sks.key.pbs_key().key.conformance_params(),
+                num_blocks_per_integer: num_ascii_blocks(sks.key.pbs_key().message_modulus()),
+            },
+            len_constraint: None,
+        }
+    }
+}
+
+impl ParameterSetConformant for FheAsciiString {
+    type ParameterSet = FheAsciiStringConformanceParams;
+
+    fn is_conformant(&self, params: &FheAsciiStringConformanceParams) -> bool {
+        let Self { inner, tag: _ } = self;
+
+        let string = inner.on_cpu();
+
+        params
+            .len_constraint
+            .is_none_or(|len_constraint| len_constraint.is_valid(string.enc_string.len()))
+            && string
+                .enc_string
+                .iter()
+                .all(|char| char.enc_char.is_conformant(&params.char_params))
+    }
+}
+
 impl Tagged for FheAsciiString {
     fn tag(&self) -> &Tag {
         &self.tag
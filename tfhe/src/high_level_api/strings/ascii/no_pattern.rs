@@ -1,6 +1,7 @@
 use crate::high_level_api::global_state::with_internal_keys;
 use crate::high_level_api::integers::FheUint16;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::profiling;
 use crate::high_level_api::strings::ascii::FheAsciiString;
 use crate::high_level_api::traits::FheTrivialEncrypt;
 use crate::prelude::FheStringRepeat;
@@ -93,6 +94,7 @@ impl FheAsciiString {
     /// }
     /// ```
     pub fn len(&self) -> FheStringLen {
+        let _scope = profiling::scope("len");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let mut len = cpu_key.string_key().len(&self.inner.on_cpu()).into();
@@ -142,6 +144,7 @@ impl FheAsciiString {
     /// }
     /// ```
     pub fn is_empty(&self) -> FheStringIsEmpty {
+        let _scope = profiling::scope("is_empty");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let mut result = cpu_key.string_key().is_empty(&self.inner.on_cpu()).into();
@@ -179,6 +182,7 @@ impl FheAsciiString {
     /// assert_eq!(&dec, "tfhe-rs");
     /// ```
     pub fn to_lowercase(&self) -> Self {
+        let _scope = profiling::scope("to_lowercase");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().to_lowercase(&self.inner.on_cpu());
@@ -213,6 +217,7 @@ impl FheAsciiString {
     /// assert_eq!(&dec, "TFHE-RS");
     /// ```
     pub fn to_uppercase(&self) -> Self {
+        let _scope = profiling::scope("to_uppercase");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().to_uppercase(&self.inner.on_cpu());
@@ -248,6 +253,7 @@ impl FheAsciiString {
     /// assert_eq!(&dec, "tfhe-rs");
     /// ```
     pub fn concat(&self, other: &Self) -> Self {
+        let _scope = profiling::scope("concat");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -307,6 +313,7 @@ impl FheStringRepeat<u16> for FheAsciiString {
     /// assert_eq!(&dec, "tfhe tfhe tfhe ");
     /// ```
     fn repeat(&self, count: u16) -> Self {
+        let _scope = profiling::scope("repeat");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key
@@ -354,6 +361,7 @@ impl FheStringRepeat<(FheUint16, u16)> for FheAsciiString {
     /// assert_eq!(&dec, &expected);
     /// ```
     fn repeat(&self, (count, bound): (FheUint16, u16)) -> Self {
+        let _scope = profiling::scope("repeat");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().repeat(
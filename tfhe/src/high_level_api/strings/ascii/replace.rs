@@ -1,5 +1,6 @@
 use crate::high_level_api::global_state::with_internal_keys;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::profiling;
 use crate::high_level_api::strings::ascii::FheAsciiString;
 use crate::high_level_api::strings::traits::FheStringReplace;
 use crate::prelude::FheStringReplaceN;
@@ -29,6 +30,7 @@ impl FheStringReplace<&Self> for FheAsciiString {
     /// assert_eq!(&dec, "tcookie is an cookie scheme");
     /// ```
     fn replace(&self, from: &Self, to: &Self) -> Self {
+        let _scope = profiling::scope("replace");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().replace(
@@ -72,6 +74,7 @@ impl FheStringReplace<&ClearString> for FheAsciiString {
     /// assert_eq!(&dec, "tcookie is an cookie scheme");
     /// ```
     fn replace(&self, from: &ClearString, to: &Self) -> Self {
+        let _scope = profiling::scope("replace");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().replace(
@@ -113,6 +116,7 @@ impl FheStringReplaceN<&Self, u32> for FheAsciiString {
 
 impl FheStringReplaceN<&Self, u16> for FheAsciiString {
     fn replacen(&self, from: &Self, to: &Self, count: u16) -> Self {
+        let _scope = profiling::scope("replacen");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().replacen(
@@ -137,6 +141,7 @@ impl FheStringReplaceN<&Self, u16> for FheAsciiString {
 
 impl FheStringReplaceN<&Self, (FheUint16, u16)> for FheAsciiString {
     fn replacen(&self, from: &Self, to: &Self, (count, max): (FheUint16, u16)) -> Self {
+        let _scope = profiling::scope("replacen");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().replacen(
@@ -179,6 +184,7 @@ impl FheStringReplaceN<&ClearString, u32> for FheAsciiString {
 
 impl FheStringReplaceN<&ClearString, u16> for FheAsciiString {
     fn replacen(&self, from: &ClearString, to: &Self, count: u16) -> Self {
+        let _scope = profiling::scope("replacen");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().replacen(
@@ -203,6 +209,7 @@ impl FheStringReplaceN<&ClearString, u16> for FheAsciiString {
 
 impl FheStringReplaceN<&ClearString, (FheUint16, u16)> for FheAsciiString {
     fn replacen(&self, from: &ClearString, to: &Self, (count, max): (FheUint16, u16)) -> Self {
+        let _scope = profiling::scope("replacen");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().replacen(
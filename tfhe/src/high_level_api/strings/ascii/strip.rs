@@ -2,7 +2,7 @@ use crate::high_level_api::global_state::with_internal_keys;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::strings::ascii::FheAsciiString;
 use crate::high_level_api::strings::traits::FheStringStrip;
-use crate::high_level_api::FheBool;
+use crate::high_level_api::{profiling, FheBool};
 use crate::strings::ciphertext::ClearString;
 
 impl FheStringStrip<&Self> for FheAsciiString {
@@ -32,6 +32,7 @@ impl FheStringStrip<&Self> for FheAsciiString {
     /// assert_eq!(&dec, "-rs");
     /// ```
     fn strip_prefix<'a>(&self, pat: &Self) -> (Self, FheBool) {
+        let _scope = profiling::scope("strip_prefix");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let (inner, block) = cpu_key
@@ -79,6 +80,7 @@ impl FheStringStrip<&Self> for FheAsciiString {
     /// assert_eq!(&dec, "tfhe-rs");
     /// ```
     fn strip_suffix<'a>(&self, pat: &Self) -> (Self, FheBool) {
+        let _scope = profiling::scope("strip_suffix");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let (inner, block) = cpu_key
@@ -128,6 +130,7 @@ impl FheStringStrip<&ClearString> for FheAsciiString {
     /// assert_eq!(&dec, "-rs");
     /// ```
     fn strip_prefix<'a>(&self, pat: &ClearString) -> (Self, FheBool) {
+        let _scope = profiling::scope("strip_prefix");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let (inner, block) = cpu_key
@@ -175,6 +178,7 @@ impl FheStringStrip<&ClearString> for FheAsciiString {
     /// assert_eq!(&dec, "tfhe-rs");
     /// ```
     fn strip_suffix<'a>(&self, pat: &ClearString) -> (Self, FheBool) {
+        let _scope = profiling::scope("strip_suffix");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let (inner, block) = cpu_key
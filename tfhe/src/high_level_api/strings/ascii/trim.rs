@@ -1,5 +1,6 @@
 use crate::high_level_api::global_state::with_internal_keys;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::profiling;
 use crate::high_level_api::strings::ascii::FheAsciiString;
 
 impl FheAsciiString {
@@ -20,6 +21,7 @@ impl FheAsciiString {
     /// assert_eq!(&dec, "tfhe-rs   ");
     /// ```
     pub fn trim_start(&self) -> Self {
+        let _scope = profiling::scope("trim_start");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().trim_start(&self.inner.on_cpu());
@@ -53,6 +55,7 @@ impl FheAsciiString {
     /// assert_eq!(&dec, "   tfhe-rs");
     /// ```
     pub fn trim_end(&self) -> Self {
+        let _scope = profiling::scope("trim_end");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().trim_end(&self.inner.on_cpu());
@@ -86,6 +89,7 @@ impl FheAsciiString {
     /// assert_eq!(&dec, "tfhe-rs");
     /// ```
     pub fn trim(&self) -> Self {
+        let _scope = profiling::scope("trim");
         with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
                 let inner = cpu_key.string_key().trim(&self.inner.on_cpu());
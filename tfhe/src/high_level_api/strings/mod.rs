@@ -2,3 +2,4 @@ pub(crate) mod ascii;
 #[cfg(test)]
 mod tests;
 pub(in crate::high_level_api) mod traits;
+pub(crate) mod utf8;
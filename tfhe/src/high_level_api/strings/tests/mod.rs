@@ -1,5 +1,7 @@
 use crate::prelude::*;
-use crate::{ClearString, ClientKey, FheAsciiString, FheStringIsEmpty, FheStringLen};
+use crate::{
+    ClearString, ClientKey, FheAsciiString, FheStringIsEmpty, FheStringLen, FheUtf8String,
+};
 
 mod cpu;
 
@@ -181,3 +183,36 @@ fn test_string_strip(client_key: &ClientKey) {
     let dec = stripped.decrypt(client_key);
     assert_eq!(dec, "The lazy cat");
 }
+
+fn test_utf8_string(client_key: &ClientKey) {
+    let clear_string = "Straße 7, 50667 Köln";
+    let string = FheUtf8String::try_encrypt_with_padding(clear_string, 2, client_key).unwrap();
+    assert_eq!(string.decrypt(client_key), clear_string);
+
+    match string.len() {
+        FheStringLen::NoPadding(_) => panic!("Unexpected result"),
+        FheStringLen::Padding(enc_len) => {
+            let len: u16 = enc_len.decrypt(client_key);
+            assert_eq!(len, clear_string.len() as u16)
+        }
+    }
+    let count: u16 = string.char_count().decrypt(client_key);
+    assert_eq!(count, clear_string.chars().count() as u16);
+    assert!(string.is_valid_utf8().decrypt(client_key));
+
+    let (index, found) = string.find("Köln");
+    assert!(found.decrypt(client_key));
+    let index: u32 = index.decrypt(client_key);
+    assert_eq!(index as usize, clear_string.find("Köln").unwrap());
+
+    let other = FheUtf8String::try_encrypt("STRASSE", client_key).unwrap();
+    assert!(other.lt(&string).decrypt(client_key));
+
+    let upper = string.to_uppercase();
+    assert_eq!(upper.decrypt(client_key), "STRAßE 7, 50667 KÖLN");
+    assert!(upper.eq_ignore_case(&string).decrypt(client_key));
+    assert!(upper
+        .to_lowercase()
+        .eq("straße 7, 50667 köln")
+        .decrypt(client_key));
+}
@@ -0,0 +1,240 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::profiling;
+use crate::high_level_api::strings::utf8::{trivial_pattern, FheUtf8String};
+use crate::prelude::{FheEq, FheEqIgnoreCase, FheOrd};
+use crate::FheBool;
+
+impl FheEq<&Self> for FheUtf8String {
+    fn eq(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("eq");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .eq(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings eq");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+
+    fn ne(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("ne");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .ne(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings ne");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+}
+
+impl FheEq<&str> for FheUtf8String {
+    fn eq(&self, other: &str) -> FheBool {
+        let _scope = profiling::scope("eq");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let string_key = cpu_key.string_key();
+                let other = trivial_pattern(&string_key, other);
+                let inner = string_key.eq(&self.inner.on_cpu(), (&other).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings eq");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+
+    fn ne(&self, other: &str) -> FheBool {
+        let _scope = profiling::scope("ne");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let string_key = cpu_key.string_key();
+                let other = trivial_pattern(&string_key, other);
+                let inner = string_key.ne(&self.inner.on_cpu(), (&other).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings ne");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+}
+
+// As the UTF-8 encoding preserves the code point order, the byte-wise comparisons of the
+// strings module give the same result as comparing `str`s
+impl FheOrd<&Self> for FheUtf8String {
+    fn lt(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("lt");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .lt(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings lt");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+
+    fn le(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("le");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .le(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings le");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+
+    fn gt(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("gt");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .gt(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings gt");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+
+    fn ge(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("ge");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .ge(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings ge");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+}
+
+impl FheEqIgnoreCase for FheUtf8String {
+    /// checks if the strings are equal, ignoring the case of ASCII and Latin-1 letters
+    ///
+    /// Returns a [FheBool] that encrypts `true` if the strings are equal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string1 = FheUtf8String::try_encrypt("Müller", &client_key).unwrap();
+    /// let string2 = FheUtf8String::try_encrypt("MÜLLER", &client_key).unwrap();
+    /// let is_eq = string1.eq_ignore_case(&string2);
+    ///
+    /// assert!(is_eq.decrypt(&client_key));
+    /// ```
+    fn eq_ignore_case(&self, rhs: &Self) -> FheBool {
+        let _scope = profiling::scope("eq_ignore_case");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .utf8_eq_ignore_case(&self.inner.on_cpu(), &rhs.inner.on_cpu());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings eq_ignore_case");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+}
+
+impl FheEqIgnoreCase<String> for FheUtf8String {
+    /// checks if the strings are equal, ignoring the case of ASCII and Latin-1 letters
+    ///
+    /// Returns a [FheBool] that encrypts `true` if the strings are equal.
+    fn eq_ignore_case(&self, rhs: &String) -> FheBool {
+        let _scope = profiling::scope("eq_ignore_case");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let string_key = cpu_key.string_key();
+                let rhs = trivial_pattern(&string_key, rhs);
+                let inner = string_key.utf8_eq_ignore_case(&self.inner.on_cpu(), &rhs);
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings eq_ignore_case");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("Hpu does not support this operation yet.")
+            }
+        })
+    }
+}
@@ -0,0 +1,260 @@
+mod comp;
+mod no_pattern;
+mod pattern;
+
+pub use crate::high_level_api::backward_compatibility::strings::FheUtf8StringVersions;
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::strings::ascii::{AsciiDevice, EncryptableString, FheAsciiString};
+use crate::named::Named;
+use crate::prelude::{FheDecrypt, FheTryEncrypt, FheTryTrivialEncrypt, Tagged};
+use crate::shortint::ciphertext::NotTrivialCiphertextError;
+use crate::strings::ciphertext::FheString;
+use crate::{ClientKey, Tag};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// An encrypted UTF-8 string
+///
+/// Each byte of the UTF-8 encoding is encrypted separately, so the encrypted string has the same
+/// byte length as its clear counterpart (plus padding). Operations inherited from
+/// [FheAsciiString] (comparisons, pattern matching, replacement, ...) work byte-wise, which is
+/// correct for UTF-8 as lexicographic byte order matches code point order and a valid pattern can
+/// only match on a char boundary. Indices returned by e.g. `find` are byte offsets, as for
+/// [str::find].
+#[derive(Serialize, Deserialize, Versionize, Clone)]
+#[versionize(FheUtf8StringVersions)]
+pub struct FheUtf8String {
+    pub(crate) inner: AsciiDevice,
+    pub(crate) tag: Tag,
+}
+
+impl Named for FheUtf8String {
+    const NAME: &'static str = "high_level_api::FheUtf8String";
+}
+
+impl Tagged for FheUtf8String {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+// Returns the longest prefix of `str` that is at most `size` bytes long, without splitting a char
+fn truncate_to_char_boundary(str: &str, size: usize) -> &str {
+    if str.len() <= size {
+        return str;
+    }
+
+    let mut end = size;
+    while !str.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    &str[..end]
+}
+
+impl FheUtf8String {
+    pub(crate) fn new(inner: impl Into<AsciiDevice>, tag: Tag) -> Self {
+        Self {
+            inner: inner.into(),
+            tag,
+        }
+    }
+
+    /// Encrypts the string `str` and adds `padding` blocks of padding (encryption of zero)
+    pub fn try_encrypt_with_padding(
+        str: impl AsRef<str>,
+        padding: u32,
+        client_key: &ClientKey,
+    ) -> crate::Result<Self> {
+        Self::try_encrypt(
+            EncryptableString::WithPadding {
+                str: str.as_ref(),
+                padding,
+            },
+            client_key,
+        )
+    }
+
+    /// Encrypts the string `str` with a fixed byte size `size`
+    ///
+    /// * If the input str is shorter than size, it will be padded with encryptions of 0
+    /// * If the input str is longer than size, it will be truncated to the last char boundary
+    ///   before `size`, and padded to `size` bytes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::prelude::*;
+    /// use tfhe::safe_serialization::safe_serialize;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// // 'ü' is 2 bytes long and would be split by a 5 bytes truncation
+    /// let string1 = FheUtf8String::try_encrypt_with_fixed_sized("Zürich", 5, &client_key).unwrap();
+    /// assert_eq!(string1.decrypt(&client_key), "Zür".to_string());
+    ///
+    /// let string2 = FheUtf8String::try_encrypt_with_fixed_sized("Bern", 5, &client_key).unwrap();
+    /// assert_eq!(string2.decrypt(&client_key), "Bern".to_string());
+    ///
+    /// let mut buffer1 = vec![];
+    /// safe_serialize(&string1, &mut buffer1, 1 << 30).unwrap();
+    /// let mut buffer2 = vec![];
+    /// safe_serialize(&string2, &mut buffer2, 1 << 30).unwrap();
+    /// // But they have the same 'size'
+    /// assert_eq!(buffer1.len(), buffer2.len())
+    /// ```
+    pub fn try_encrypt_with_fixed_sized(
+        str: impl AsRef<str>,
+        size: usize,
+        client_key: &ClientKey,
+    ) -> crate::Result<Self> {
+        let sliced = truncate_to_char_boundary(str.as_ref(), size);
+
+        Self::try_encrypt(
+            EncryptableString::WithPadding {
+                str: sliced,
+                padding: (size - sliced.len()) as u32,
+            },
+            client_key,
+        )
+    }
+
+    /// Trivially encrypts the string `str` and adds `padding` blocks of padding (encryption of
+    /// zero)
+    pub fn try_encrypt_trivial_with_padding(
+        str: impl AsRef<str>,
+        padding: u32,
+    ) -> crate::Result<Self> {
+        Self::try_encrypt_trivial(EncryptableString::WithPadding {
+            str: str.as_ref(),
+            padding,
+        })
+    }
+
+    pub fn try_decrypt_trivial(&self) -> Result<String, NotTrivialCiphertextError> {
+        self.inner.on_cpu().decrypt_trivial()
+    }
+
+    pub fn is_trivial(&self) -> bool {
+        self.inner.on_cpu().is_trivial()
+    }
+}
+
+impl From<FheAsciiString> for FheUtf8String {
+    /// An ASCII string is always a valid UTF-8 string, so no computation is needed
+    fn from(value: FheAsciiString) -> Self {
+        Self {
+            inner: value.inner,
+            tag: value.tag,
+        }
+    }
+}
+
+impl<'a> FheTryEncrypt<EncryptableString<'a>, ClientKey> for FheUtf8String {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: EncryptableString<'a>, key: &ClientKey) -> Result<Self, Self::Error> {
+        let (str, padding) = value.str_and_padding();
+        if str.contains('\0') {
+            return Err(crate::Error::new(
+                "Input string contains a null character".to_string(),
+            ));
+        }
+
+        let inner = crate::strings::ClientKey::new(&key.key.key).encrypt_utf8(str, padding);
+        Ok(Self {
+            inner: inner.into(),
+            tag: key.tag.clone(),
+        })
+    }
+}
+
+impl FheTryEncrypt<&str, ClientKey> for FheUtf8String {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: &str, key: &ClientKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt(EncryptableString::NoPadding(value), key)
+    }
+}
+
+impl FheTryEncrypt<&String, ClientKey> for FheUtf8String {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: &String, key: &ClientKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt(EncryptableString::NoPadding(value), key)
+    }
+}
+
+impl<'a> FheTryTrivialEncrypt<EncryptableString<'a>> for FheUtf8String {
+    type Error = crate::Error;
+
+    fn try_encrypt_trivial(value: EncryptableString<'a>) -> Result<Self, Self::Error> {
+        let (str, padding) = value.str_and_padding();
+
+        if str.contains('\0') {
+            return Err(crate::Error::new(
+                "Input string contains a null character".to_string(),
+            ));
+        }
+
+        global_state::try_with_internal_keys(|keys| match keys {
+            Some(InternalServerKey::Cpu(cpu_key)) => {
+                let inner = cpu_key.string_key().trivial_encrypt_utf8(str, padding);
+                Ok(Self::new(inner, cpu_key.tag.clone()))
+            }
+            #[cfg(feature = "gpu")]
+            Some(InternalServerKey::Cuda(_)) => Err(crate::error!("CUDA does not support string")),
+            #[cfg(feature = "hpu")]
+            Some(InternalServerKey::Hpu(_)) => Err(crate::error!("Hpu does not support string")),
+            None => Err(crate::high_level_api::errors::UninitializedServerKey.into()),
+        })
+    }
+}
+
+impl FheTryTrivialEncrypt<&str> for FheUtf8String {
+    type Error = crate::Error;
+
+    fn try_encrypt_trivial(value: &str) -> Result<Self, Self::Error> {
+        Self::try_encrypt_trivial(EncryptableString::NoPadding(value))
+    }
+}
+
+impl FheTryTrivialEncrypt<&String> for FheUtf8String {
+    type Error = crate::Error;
+
+    fn try_encrypt_trivial(value: &String) -> Result<Self, Self::Error> {
+        Self::try_encrypt_trivial(EncryptableString::NoPadding(value.as_str()))
+    }
+}
+
+impl FheDecrypt<String> for FheUtf8String {
+    fn decrypt(&self, key: &ClientKey) -> String {
+        crate::strings::ClientKey::new(&key.key.key).decrypt_utf8(&self.inner.on_cpu())
+    }
+}
+
+// Clear patterns are trivially encrypted, as the clear pattern code paths of the strings
+// module slice the pattern assuming one char per byte
+fn trivial_pattern(server_key: &crate::strings::ServerKeyRef<'_>, pattern: &str) -> FheString {
+    server_key.trivial_encrypt_utf8(pattern, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::truncate_to_char_boundary;
+
+    #[test]
+    fn test_truncate_to_char_boundary() {
+        assert_eq!(truncate_to_char_boundary("Zürich", 10), "Zürich");
+        assert_eq!(truncate_to_char_boundary("Zürich", 3), "Zü");
+        assert_eq!(truncate_to_char_boundary("Zürich", 2), "Z");
+        assert_eq!(truncate_to_char_boundary("€", 2), "");
+    }
+}
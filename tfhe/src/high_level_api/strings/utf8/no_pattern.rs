@@ -0,0 +1,203 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::integers::FheUint16;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::profiling;
+use crate::high_level_api::strings::ascii::FheStringLen;
+use crate::high_level_api::strings::utf8::FheUtf8String;
+use crate::FheBool;
+
+impl FheUtf8String {
+    /// Returns the length in bytes of an encrypted string as an `FheStringLen` enum.
+    ///
+    /// * If the encrypted string has no padding, the length is the clear length of the byte vector.
+    /// * If there is padding, the length is calculated homomorphically and returned encrypted.
+    ///
+    /// See [Self::char_count] to get the number of chars.
+    pub fn len(&self) -> FheStringLen {
+        let _scope = profiling::scope("len");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let mut len = cpu_key.string_key().len(&self.inner.on_cpu()).into();
+                if let FheStringLen::Padding(len) = &mut len {
+                    len.tag = cpu_key.tag.clone();
+                }
+                len
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings len");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings len");
+            }
+        })
+    }
+
+    /// Returns the number of chars (Unicode scalar values) of the encrypted string.
+    ///
+    /// As the number of bytes per char is unknown, the result is always computed
+    /// homomorphically.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt_with_padding("Łódź", 3, &client_key).unwrap();
+    /// let count: u16 = string.char_count().decrypt(&client_key);
+    /// assert_eq!(count, 4);
+    /// ```
+    pub fn char_count(&self) -> FheUint16 {
+        let _scope = profiling::scope("char_count");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().utf8_char_count(&self.inner.on_cpu());
+                FheUint16::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings char_count");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings char_count");
+            }
+        })
+    }
+
+    /// Returns a [FheBool] that encrypts `true` if the encrypted bytes are valid UTF-8.
+    ///
+    /// Strings encrypted from a `&str` are always valid, this is meant to check strings whose
+    /// content comes from an untrusted source.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("Ærøskøbing", &client_key).unwrap();
+    /// assert!(string.is_valid_utf8().decrypt(&client_key));
+    /// ```
+    pub fn is_valid_utf8(&self) -> FheBool {
+        let _scope = profiling::scope("is_valid_utf8");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().is_valid_utf8(&self.inner.on_cpu());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings is_valid_utf8");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings is_valid_utf8");
+            }
+        })
+    }
+
+    /// Returns a new encrypted string with ASCII and Latin-1 Supplement letters converted to
+    /// lowercase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("ÉLODIE Müller", &client_key).unwrap();
+    /// let lower = string.to_lowercase();
+    ///
+    /// let dec = lower.decrypt(&client_key);
+    /// assert_eq!(&dec, "élodie müller");
+    /// ```
+    pub fn to_lowercase(&self) -> Self {
+        let _scope = profiling::scope("to_lowercase");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().utf8_to_lowercase(&self.inner.on_cpu());
+                Self::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings to_lowercase");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings to_lowercase");
+            }
+        })
+    }
+
+    /// Returns a new encrypted string with ASCII and Latin-1 Supplement letters converted to
+    /// uppercase.
+    ///
+    /// Letters whose uppercase form is outside of the Latin-1 Supplement block ('ß', 'ÿ', 'µ')
+    /// are left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("São Paulo", &client_key).unwrap();
+    /// let upper = string.to_uppercase();
+    ///
+    /// let dec = upper.decrypt(&client_key);
+    /// assert_eq!(&dec, "SÃO PAULO");
+    /// ```
+    pub fn to_uppercase(&self) -> Self {
+        let _scope = profiling::scope("to_uppercase");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().utf8_to_uppercase(&self.inner.on_cpu());
+                Self::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings to_uppercase");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings to_uppercase");
+            }
+        })
+    }
+
+    /// Concatenates two encrypted strings and returns the result as a new encrypted string.
+    pub fn concat(&self, other: &Self) -> Self {
+        let _scope = profiling::scope("concat");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .concat(&self.inner.on_cpu(), &other.inner.on_cpu());
+                Self::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings concatenating");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings concatenating");
+            }
+        })
+    }
+}
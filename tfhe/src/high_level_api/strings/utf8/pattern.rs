@@ -0,0 +1,337 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::profiling;
+use crate::high_level_api::strings::traits::{FheStringFind, FheStringMatching, FheStringReplace};
+use crate::high_level_api::strings::utf8::{trivial_pattern, FheUtf8String};
+use crate::{FheBool, FheUint32};
+
+// UTF-8 is self-synchronizing: a valid pattern can only be found at a char boundary of a valid
+// string, so the byte-wise pattern matching of the strings module can be used as is.
+
+impl FheStringMatching<&Self> for FheUtf8String {
+    /// checks if the string contains the given substring
+    ///
+    /// Returns a [FheBool] that encrypts `true` if the substring was found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("Straße 12, Köln", &client_key).unwrap();
+    /// let pattern = FheUtf8String::try_encrypt("Köln", &client_key).unwrap();
+    /// let found = string.contains(&pattern);
+    ///
+    /// assert!(found.decrypt(&client_key));
+    /// ```
+    fn contains(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("contains");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .contains(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings contains");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support contains");
+            }
+        })
+    }
+
+    fn starts_with(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("starts_with");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .starts_with(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings starts_with");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support starts_with");
+            }
+        })
+    }
+
+    fn ends_with(&self, other: &Self) -> FheBool {
+        let _scope = profiling::scope("ends_with");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .ends_with(&self.inner.on_cpu(), (&*other.inner.on_cpu()).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings ends_with");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support ends_with");
+            }
+        })
+    }
+}
+
+impl FheStringMatching<&str> for FheUtf8String {
+    fn contains(&self, other: &str) -> FheBool {
+        let _scope = profiling::scope("contains");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let string_key = cpu_key.string_key();
+                let other = trivial_pattern(&string_key, other);
+                let inner = string_key.contains(&self.inner.on_cpu(), (&other).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings contains");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support contains");
+            }
+        })
+    }
+
+    fn starts_with(&self, other: &str) -> FheBool {
+        let _scope = profiling::scope("starts_with");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let string_key = cpu_key.string_key();
+                let other = trivial_pattern(&string_key, other);
+                let inner = string_key.starts_with(&self.inner.on_cpu(), (&other).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings starts_with");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support starts_with");
+            }
+        })
+    }
+
+    fn ends_with(&self, other: &str) -> FheBool {
+        let _scope = profiling::scope("ends_with");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let string_key = cpu_key.string_key();
+                let other = trivial_pattern(&string_key, other);
+                let inner = string_key.ends_with(&self.inner.on_cpu(), (&other).into());
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings ends_with");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support ends_with");
+            }
+        })
+    }
+}
+
+impl FheStringFind<&Self> for FheUtf8String {
+    /// find a substring inside a string
+    ///
+    /// Returns the byte index of the first match of the pattern in this string,
+    /// as well as a [FheBool] that encrypts `true` if the pattern was found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("Café Zoé", &client_key).unwrap();
+    /// let pattern = FheUtf8String::try_encrypt("Zoé", &client_key).unwrap();
+    /// let (position, found) = string.find(&pattern);
+    ///
+    /// assert!(found.decrypt(&client_key));
+    /// let pos: u32 = position.decrypt(&client_key);
+    /// assert_eq!(pos, 6);
+    /// ```
+    fn find(&self, pat: &Self) -> (FheUint32, FheBool) {
+        let _scope = profiling::scope("find");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (inner, block) = cpu_key
+                    .string_key()
+                    .find(&self.inner.on_cpu(), (&*pat.inner.on_cpu()).into());
+                (
+                    FheUint32::new(inner, cpu_key.tag.clone()),
+                    FheBool::new(block, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings find");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings find");
+            }
+        })
+    }
+
+    fn rfind(&self, pat: &Self) -> (FheUint32, FheBool) {
+        let _scope = profiling::scope("rfind");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (inner, block) = cpu_key
+                    .string_key()
+                    .rfind(&self.inner.on_cpu(), (&*pat.inner.on_cpu()).into());
+                (
+                    FheUint32::new(inner, cpu_key.tag.clone()),
+                    FheBool::new(block, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings rfind");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings rfind");
+            }
+        })
+    }
+}
+
+impl FheStringFind<&str> for FheUtf8String {
+    fn find(&self, pat: &str) -> (FheUint32, FheBool) {
+        let _scope = profiling::scope("find");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let string_key = cpu_key.string_key();
+                let pat = trivial_pattern(&string_key, pat);
+                let (inner, block) = string_key.find(&self.inner.on_cpu(), (&pat).into());
+                (
+                    FheUint32::new(inner, cpu_key.tag.clone()),
+                    FheBool::new(block, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings find");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings find");
+            }
+        })
+    }
+
+    fn rfind(&self, pat: &str) -> (FheUint32, FheBool) {
+        let _scope = profiling::scope("rfind");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let string_key = cpu_key.string_key();
+                let pat = trivial_pattern(&string_key, pat);
+                let (inner, block) = string_key.rfind(&self.inner.on_cpu(), (&pat).into());
+                (
+                    FheUint32::new(inner, cpu_key.tag.clone()),
+                    FheBool::new(block, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings rfind");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings rfind");
+            }
+        })
+    }
+}
+
+impl FheStringReplace<&Self> for FheUtf8String {
+    /// Returns a new encrypted string with all non-overlapping occurrences of a pattern
+    /// replaced by another specified encrypted pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUtf8String};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheUtf8String::try_encrypt("Grüße aus Köln", &client_key).unwrap();
+    /// let pattern = FheUtf8String::try_encrypt("Köln", &client_key).unwrap();
+    /// let new_val = FheUtf8String::try_encrypt("Málaga", &client_key).unwrap();
+    /// let replaced = string.replace(&pattern, &new_val);
+    ///
+    /// let dec = replaced.decrypt(&client_key);
+    /// assert_eq!(&dec, "Grüße aus Málaga");
+    /// ```
+    fn replace(&self, from: &Self, to: &Self) -> Self {
+        let _scope = profiling::scope("replace");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().replace(
+                    &self.inner.on_cpu(),
+                    (&*from.inner.on_cpu()).into(),
+                    &to.inner.on_cpu(),
+                );
+                Self::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings replace");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings replace");
+            }
+        })
+    }
+}
+
+impl FheStringReplace<&str> for FheUtf8String {
+    fn replace(&self, from: &str, to: &Self) -> Self {
+        let _scope = profiling::scope("replace");
+        with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let string_key = cpu_key.string_key();
+                let from = trivial_pattern(&string_key, from);
+                let inner =
+                    string_key.replace(&self.inner.on_cpu(), (&from).into(), &to.inner.on_cpu());
+                Self::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("gpu does not support strings replace");
+            }
+            #[cfg(feature = "hpu")]
+            InternalServerKey::Hpu(_) => {
+                panic!("hpu does not support strings replace");
+            }
+        })
+    }
+}
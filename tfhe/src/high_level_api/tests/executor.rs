@@ -0,0 +1,94 @@
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, ConfigBuilder, FheBool, FheExecutor, FheUint8};
+use crate::set_server_key;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::time::Duration;
+
+#[test]
+fn test_async_ops() {
+    let (cks, sks) = generate_keys(ConfigBuilder::default().build());
+    set_server_key(sks);
+
+    let a = FheUint8::encrypt(7u8, &cks);
+    let b = FheUint8::encrypt(6u8, &cks);
+
+    let sum = a.async_add(&b);
+    let product = a.async_mul(&b);
+    let min = a.async_min(&b);
+    let is_greater = a.async_gt(&b);
+    let is_equal = a.async_eq(&b);
+
+    let sum: u8 = sum.join().decrypt(&cks);
+    let product: u8 = product.join().decrypt(&cks);
+    let min: u8 = min.join().decrypt(&cks);
+    assert_eq!(sum, 13);
+    assert_eq!(product, 42);
+    assert_eq!(min, 6);
+    assert!(is_greater.join().decrypt(&cks));
+    assert!(!is_equal.join().decrypt(&cks));
+
+    let x = FheBool::encrypt(true, &cks);
+    let y = FheBool::encrypt(false, &cks);
+    assert!(x.async_bitxor(&y).join().decrypt(&cks));
+    assert!(!x.async_bitand(&y).join().decrypt(&cks));
+}
+
+#[test]
+fn test_executor_cancellation_and_backpressure() {
+    let (cks, sks) = generate_keys(ConfigBuilder::default().build());
+    set_server_key(sks);
+
+    // A single thread and a single queued operation
+    let executor = FheExecutor::new(1, 1).unwrap();
+
+    let (release_sender, release_receiver) = mpsc::channel::<()>();
+    let (started_sender, started_receiver) = mpsc::channel::<()>();
+    let blocking = executor.spawn(move || {
+        started_sender.send(()).unwrap();
+        release_receiver.recv().unwrap();
+    });
+    started_receiver.recv().unwrap();
+
+    let (ran_sender, ran_receiver) = mpsc::channel::<u8>();
+    let queued = {
+        let ran_sender = ran_sender.clone();
+        executor.spawn(move || ran_sender.send(1).unwrap())
+    };
+    // The queue is full, this one is only queued when polled
+    let a = FheUint8::encrypt(3u8, &cks);
+    let pending = executor.spawn(move || {
+        ran_sender.send(2).unwrap();
+        &a + &a
+    });
+
+    // Cancels the queued operation before it starts
+    drop(queued);
+    release_sender.send(()).unwrap();
+    blocking.join();
+
+    let result: u8 = pending.join().decrypt(&cks);
+    assert_eq!(result, 6);
+    assert_eq!(
+        ran_receiver.recv_timeout(Duration::from_secs(10)).unwrap(),
+        2
+    );
+    assert!(ran_receiver.try_recv().is_err());
+}
+
+#[test]
+fn test_executor_panic() {
+    let (_, sks) = generate_keys(ConfigBuilder::default().build());
+    set_server_key(sks);
+
+    let executor = FheExecutor::new(1, 4).unwrap();
+
+    let task = executor.spawn(|| panic!("operation failed"));
+    assert!(catch_unwind(AssertUnwindSafe(|| task.join())).is_err());
+
+    // The thread of the executor is still usable
+    assert_eq!(executor.spawn(|| 42).join(), 42);
+
+    assert!(FheExecutor::new(0, 1).is_err());
+    assert!(FheExecutor::new(1, 0).is_err());
+}
@@ -1,7 +1,10 @@
+mod executor;
 #[cfg(feature = "gpu")]
 mod gpu_selection;
 mod noise_distribution;
 mod noise_squashing;
+#[cfg(feature = "cleartext-simulation")]
+mod simulation;
 mod tags_on_entities;
 
 use crate::high_level_api::prelude::*;
@@ -213,3 +216,53 @@ fn test_try_from_single_lwe_encryption_key() {
     let clear_res: u32 = encrypted_res_mul.decrypt(&client_key);
     assert_eq!(clear_res, clear_a + clear_b);
 }
+
+#[cfg(feature = "profiling")]
+#[test]
+fn test_profiling_report() {
+    use crate::profiling::{scope, FoldedStacksMetric, ProfilingRecorder};
+
+    let client_key = setup_default_cpu();
+
+    let a = FheUint8::encrypt(27u8, &client_key);
+    let b = FheUint8::encrypt(5u8, &client_key);
+
+    let recorder = ProfilingRecorder::start().unwrap();
+    assert!(ProfilingRecorder::start().is_err());
+    let (q, r) = {
+        let _scope = scope("circuit");
+        let c = &a * &b;
+        let _ = &c + &a;
+        c.div_rem(&b)
+    };
+    let report = recorder.finish();
+
+    let q: u8 = q.decrypt(&client_key);
+    let r: u8 = r.decrypt(&client_key);
+    assert_eq!((q, r), (27, 0));
+
+    let circuit = report.get("circuit").unwrap();
+    assert_eq!(circuit.calls, 1);
+    assert!(circuit.exclusive.pbs_count <= circuit.inclusive.pbs_count);
+    // With the default parameters, each blind rotation is preceded by a single keyswitch
+    assert_eq!(
+        circuit.inclusive.keyswitch_count,
+        circuit.inclusive.pbs_count
+    );
+
+    for operation in ["mul", "add", "div_rem"] {
+        let profile = report.get(&format!("circuit;{operation}")).unwrap();
+        assert_eq!(profile.calls, 1);
+        assert!(profile.inclusive.pbs_count > 0);
+        assert!(profile.inclusive.pbs_count <= circuit.inclusive.pbs_count);
+    }
+    assert_eq!(report.by_operation()["mul"].calls, 1);
+
+    let folded = report.to_folded_stacks(FoldedStacksMetric::PbsCount);
+    assert!(folded
+        .lines()
+        .any(|line| line.starts_with("circuit;div_rem ")));
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+    assert!(json["operations"]["circuit;mul"]["calls"] == 1);
+}
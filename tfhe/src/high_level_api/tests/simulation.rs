@@ -0,0 +1,120 @@
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{
+    ClientKey, ConfigBuilder, FheBool, FheInt8, FheUint32, FheUint8, ServerKey,
+};
+use crate::set_server_key;
+use crate::shortint::parameters::{Degree, NoiseLevel};
+use rand::prelude::*;
+
+fn bookkeeping(ct: &FheUint8) -> Vec<(Degree, NoiseLevel)> {
+    ct.ciphertext
+        .on_cpu()
+        .blocks
+        .iter()
+        .map(|block| (block.degree, block.noise_level()))
+        .collect()
+}
+
+fn program(cks: &ClientKey, a: u8, b: u8) -> Vec<FheUint8> {
+    let a = FheUint8::encrypt(a, cks);
+    let b = FheUint8::encrypt(b, cks);
+
+    let sum = &a + &b;
+    let product = &a * &b;
+    let quotient = &a / (&b | 1u8);
+    let selected = a.gt(&b).select(&sum, &product);
+    let rotated = (&a).rotate_left(&b);
+
+    vec![sum, product, quotient, selected, rotated]
+}
+
+#[test]
+fn test_simulation_integers() {
+    let cks = ClientKey::generate(ConfigBuilder::default().build());
+    set_server_key(ServerKey::new_cleartext_simulation(&cks));
+
+    let mut rng = thread_rng();
+
+    for _ in 0..5 {
+        let clear_a: u32 = rng.gen();
+        let clear_b: u32 = rng.gen();
+
+        let a = FheUint32::encrypt(clear_a, &cks);
+        let b = FheUint32::encrypt(clear_b, &cks);
+
+        let result: u32 = (&a + &b).decrypt(&cks);
+        assert_eq!(result, clear_a.wrapping_add(clear_b));
+
+        let result: u32 = (&a * &b).decrypt(&cks);
+        assert_eq!(result, clear_a.wrapping_mul(clear_b));
+
+        let result: u32 = (&a - &b).decrypt(&cks);
+        assert_eq!(result, clear_a.wrapping_sub(clear_b));
+
+        let result: u32 = (&a >> &b).decrypt(&cks);
+        assert_eq!(result, clear_a >> (clear_b % 32));
+
+        let (result, overflowed) = (&a).overflowing_add(&b);
+        let result: u32 = result.decrypt(&cks);
+        let overflowed: bool = overflowed.decrypt(&cks);
+        assert_eq!((result, overflowed), clear_a.overflowing_add(clear_b));
+
+        let result: bool = a.lt(&b).decrypt(&cks);
+        assert_eq!(result, clear_a < clear_b);
+    }
+
+    let a = FheInt8::encrypt(i8::MAX, &cks);
+    let (result, overflowed) = (&a).overflowing_add(&FheInt8::encrypt(1i8, &cks));
+    let result: i8 = result.decrypt(&cks);
+    let overflowed: bool = overflowed.decrypt(&cks);
+    assert_eq!((result, overflowed), i8::MAX.overflowing_add(1));
+
+    let result: i8 = (-&a).decrypt(&cks);
+    assert_eq!(result, -i8::MAX);
+
+    let t = FheBool::encrypt(true, &cks);
+    let f = FheBool::encrypt(false, &cks);
+    let result: bool = (&t & &f).decrypt(&cks);
+    assert!(!result);
+    let result: bool = (&t ^ &f).decrypt(&cks);
+    assert!(result);
+}
+
+#[test]
+fn test_simulation_matches_real_server_key() {
+    let (a, b) = (201u8, 37u8);
+
+    let cks = ClientKey::generate(ConfigBuilder::default().build());
+
+    set_server_key(ServerKey::new_cleartext_simulation(&cks));
+    let simulated = program(&cks, a, b);
+
+    set_server_key(ServerKey::new(&cks));
+    let expected = program(&cks, a, b);
+
+    for (simulated, expected) in simulated.iter().zip(expected.iter()) {
+        let simulated_result: u8 = simulated.decrypt(&cks);
+        let expected_result: u8 = expected.decrypt(&cks);
+        assert_eq!(simulated_result, expected_result);
+
+        assert_eq!(bookkeeping(simulated), bookkeeping(expected));
+    }
+}
+
+#[cfg(feature = "strings")]
+#[test]
+fn test_simulation_strings() {
+    use crate::FheAsciiString;
+
+    let cks = ClientKey::generate(ConfigBuilder::default().build());
+    set_server_key(ServerKey::new_cleartext_simulation(&cks));
+
+    let string = FheAsciiString::try_encrypt("Simulated", &cks).unwrap();
+    let pattern = FheAsciiString::try_encrypt("ate", &cks).unwrap();
+
+    let upper: String = string.to_uppercase().decrypt(&cks);
+    assert_eq!(upper, "SIMULATED");
+
+    let contains: bool = string.contains(&pattern).decrypt(&cks);
+    assert!(contains);
+}
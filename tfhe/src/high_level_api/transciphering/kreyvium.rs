@@ -0,0 +1,235 @@
+//! Kreyvium stream cipher, evaluated byte per byte.
+//!
+//! Kreyvium is a variant of Trivium with 128 bits key and iv. On top of the Trivium registers,
+//! the key and the iv are stored in two additional registers that are rotated at each step.
+
+use super::register::ByteRegister;
+use super::{drain_keystream, ByteOps, ClearOps, FheCipherKey, StreamCipher};
+use crate::high_level_api::backward_compatibility::transciphering::{
+    FheKreyviumVersions, KreyviumRegistersVersions, KreyviumVersions,
+};
+use crate::high_level_api::global_state;
+use crate::integer::RadixCiphertext;
+use crate::named::Named;
+use crate::FheUint8;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+pub const KREYVIUM_KEY_LEN: usize = 16;
+pub const KREYVIUM_IV_LEN: usize = 16;
+
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(KreyviumRegistersVersions)]
+pub struct KreyviumRegisters<T> {
+    a: ByteRegister<T>,
+    b: ByteRegister<T>,
+    c: ByteRegister<T>,
+    k: ByteRegister<T>,
+    /// The iv is public, thus its register is always kept in clear
+    iv: ByteRegister<u8>,
+}
+
+impl<T: Clone + Send + Sync> KreyviumRegisters<T> {
+    /// Load the key and the iv in the registers, and run the initialization rounds
+    fn new<O: ByteOps<Byte = T>>(ops: &O, key: &[T], iv: &[u8; KREYVIUM_IV_LEN]) -> Self {
+        assert_eq!(key.len(), KREYVIUM_KEY_LEN);
+
+        // a has the end of the secret key, b the end of the input vector
+        let a = key[4..].to_vec();
+        let b = iv[5..].iter().map(|byte| ops.trivial(*byte)).collect();
+
+        // c is filled with a lot of ones, followed by the beginning of the input vector
+        let mut c_clear = [0u8; 14];
+        c_clear[0] = 252;
+        c_clear[1..8].fill(255);
+        c_clear[8] = (iv[0] << 4) | 31;
+        for i in 9..14 {
+            c_clear[i] = (iv[i - 9] >> 4) | (iv[i - 8] << 4);
+        }
+        let c = c_clear.iter().map(|byte| ops.trivial(*byte)).collect();
+
+        // Key and iv are stored in reverse in their shift registers
+        let k = key
+            .iter()
+            .rev()
+            .map(|byte| ops.reverse_bits(byte))
+            .collect();
+        let iv = iv.iter().rev().map(|byte| byte.reverse_bits()).collect();
+
+        let mut registers = Self {
+            a: ByteRegister::new(a),
+            b: ByteRegister::new(b),
+            c: ByteRegister::new(c),
+            k: ByteRegister::new(k),
+            iv: ByteRegister::new(iv),
+        };
+        // As for Trivium, 1152 (= 18*64) unused steps are run to mix up the registers, before
+        // starting the proper stream
+        for _ in 0..18 {
+            registers.next_64(ops);
+        }
+        registers
+    }
+
+    /// Computes 8 steps of Kreyvium, `b*8` steps in the future. This does not update the
+    /// registers, but rather returns the output and the three values that will be used to
+    /// update the registers.
+    fn output_and_values<O: ByteOps<Byte = T>>(&self, ops: &O, b: usize) -> [T; 4] {
+        let n = b * 8 + 7;
+        assert!(n < 65);
+
+        let iv = self.iv.byte(&ClearOps, 127 - n);
+        let ((k, [a1, a2, a3, a4, a5]), ([b1, b2, b3, b4, b5], [c1, c2, c3, c4, c5])) = rayon::join(
+            || {
+                rayon::join(
+                    || self.k.byte(ops, 127 - n),
+                    || self.a.bytes(ops, [91 - n, 90 - n, 68 - n, 65 - n, 92 - n]),
+                )
+            },
+            || {
+                rayon::join(
+                    || self.b.bytes(ops, [82 - n, 81 - n, 77 - n, 68 - n, 83 - n]),
+                    || {
+                        self.c
+                            .bytes(ops, [109 - n, 108 - n, 86 - n, 65 - n, 110 - n])
+                    },
+                )
+            },
+        );
+
+        let (((temp_a, temp_b), (temp_c, a_and)), (b_and, c_and)) = rayon::join(
+            || {
+                rayon::join(
+                    || rayon::join(|| ops.xor(&a4, &a5), || ops.xor(&b4, &b5)),
+                    || {
+                        rayon::join(
+                            || ops.xor(&ops.xor(&c4, &c5), &k),
+                            || ops.scalar_xor(&ops.and(&a1, &a2), iv),
+                        )
+                    },
+                )
+            },
+            || rayon::join(|| ops.and(&b1, &b2), || ops.and(&c1, &c2)),
+        );
+
+        let ((o, a), (b, c)) = rayon::join(
+            || {
+                rayon::join(
+                    || ops.xor(&ops.xor(&temp_a, &temp_b), &temp_c),
+                    || ops.xor(&temp_c, &ops.xor(&c_and, &a3)),
+                )
+            },
+            || {
+                rayon::join(
+                    || ops.xor(&temp_a, &ops.xor(&a_and, &b3)),
+                    || ops.xor(&temp_b, &ops.xor(&b_and, &c3)),
+                )
+            },
+        );
+
+        [o, a, b, c]
+    }
+
+    /// Computes 64 steps, outputting 8 bytes of keystream (first value is the oldest)
+    fn next_64<O: ByteOps<Byte = T>>(&mut self, ops: &O) -> Vec<T> {
+        let values = (0..8)
+            .into_par_iter()
+            .map(|b| self.output_and_values(ops, b))
+            .collect::<Vec<_>>();
+
+        let bytes = values
+            .into_iter()
+            .map(|[o, a, b, c]| {
+                self.a.push(a);
+                self.b.push(b);
+                self.c.push(c);
+                o
+            })
+            .collect();
+        self.k.shift(8);
+        self.iv.shift(8);
+        bytes
+    }
+}
+
+/// Clear Kreyvium, used to encrypt data that will be transciphered with [FheKreyvium]
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(KreyviumVersions)]
+pub struct Kreyvium {
+    registers: KreyviumRegisters<u8>,
+    /// Keystream bytes generated but not consumed yet
+    keystream: Vec<u8>,
+}
+
+impl Named for Kreyvium {
+    const NAME: &'static str = "high_level_api::Kreyvium";
+}
+
+impl Kreyvium {
+    pub fn new(key: &[u8; KREYVIUM_KEY_LEN], iv: &[u8; KREYVIUM_IV_LEN]) -> Self {
+        Self {
+            registers: KreyviumRegisters::new(&ClearOps, key, iv),
+            keystream: Vec::new(),
+        }
+    }
+
+    /// Returns the next `count` bytes of the keystream
+    pub fn next_keystream(&mut self, count: usize) -> Vec<u8> {
+        while self.keystream.len() < count {
+            let bytes = self.registers.next_64(&ClearOps);
+            self.keystream.extend(bytes);
+        }
+        self.keystream.drain(..count).collect()
+    }
+
+    /// Encrypt (or decrypt) data with the next bytes of the keystream
+    pub fn encrypt(&mut self, data: &[u8]) -> Vec<u8> {
+        self.next_keystream(data.len())
+            .iter()
+            .zip(data.iter())
+            .map(|(key, byte)| key ^ byte)
+            .collect()
+    }
+}
+
+/// Homomorphic Kreyvium
+///
+/// Requires a Cpu server key to be set, to run the initialization rounds and generate the
+/// keystream.
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(FheKreyviumVersions)]
+pub struct FheKreyvium {
+    registers: KreyviumRegisters<RadixCiphertext>,
+    /// Keystream bytes generated but not consumed yet
+    keystream: Vec<RadixCiphertext>,
+}
+
+impl Named for FheKreyvium {
+    const NAME: &'static str = "high_level_api::FheKreyvium";
+}
+
+impl FheKreyvium {
+    pub fn new(key: &FheCipherKey, iv: &[u8; KREYVIUM_IV_LEN]) -> crate::Result<Self> {
+        let key = key.cpu_bytes("Kreyvium", KREYVIUM_KEY_LEN)?;
+        let registers = global_state::with_cpu_internal_keys(|cpu_key| {
+            KreyviumRegisters::new(cpu_key.pbs_key(), &key, iv)
+        });
+        Ok(Self {
+            registers,
+            keystream: Vec::new(),
+        })
+    }
+}
+
+impl StreamCipher for FheKreyvium {
+    fn next_keystream(&mut self, count: usize) -> Vec<FheUint8> {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            while self.keystream.len() < count {
+                let bytes = self.registers.next_64(cpu_key.pbs_key());
+                self.keystream.extend(bytes);
+            }
+        });
+        drain_keystream(&mut self.keystream, count)
+    }
+}
@@ -0,0 +1,221 @@
+//! Homomorphic evaluation of stream ciphers (transciphering).
+//!
+//! Encrypting data directly under FHE generates large ciphertexts. Instead, a client can encrypt
+//! its data with a symmetric stream cipher, and only send the FHE encryption of the symmetric key
+//! once. The server then evaluates the cipher homomorphically to obtain an encrypted keystream,
+//! and removes it from the symmetric ciphertexts, which turns them into FHE ciphertexts.
+//!
+//! Each cipher comes in two flavors:
+//! * a clear one (e.g. [Trivium]) used by the client to encrypt its data,
+//! * a homomorphic one (e.g. [FheTrivium]) used by the server, implementing [StreamCipher].
+//!
+//! Ciphers states could be serialized at any point of the stream, enabling to resume
+//! the keystream generation later on.
+
+mod kreyvium;
+mod register;
+#[cfg(test)]
+mod tests;
+mod trivium;
+
+pub use kreyvium::{FheKreyvium, Kreyvium, KREYVIUM_IV_LEN, KREYVIUM_KEY_LEN};
+pub use trivium::{FheTrivium, Trivium, TRIVIUM_IV_LEN, TRIVIUM_KEY_LEN};
+
+pub(crate) use kreyvium::KreyviumRegisters;
+pub(crate) use register::ByteRegister;
+pub(crate) use trivium::TriviumRegisters;
+
+use crate::high_level_api::backward_compatibility::transciphering::FheCipherKeyVersions;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheUint64Id, FheUint8Id, IntegerId};
+use crate::integer::RadixCiphertext;
+use crate::named::Named;
+use crate::prelude::FheEncrypt;
+use crate::{ClientKey, FheUint64, FheUint8, FheUint8Array};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// Stream cipher evaluated homomorphically
+///
+/// The keystream is encrypted under the FHE key, and is removed from data encrypted with the
+/// same cipher, key and iv on the client side. The keystream consumed by the server must thus
+/// stay in sync with the one used by the client.
+pub trait StreamCipher {
+    /// Returns the next `count` bytes of the keystream
+    fn next_keystream(&mut self, count: usize) -> Vec<FheUint8>;
+
+    /// Returns the next byte of the keystream
+    fn next_u8(&mut self) -> FheUint8 {
+        self.next_keystream(1)
+            .pop()
+            .expect("Keystream must contain one byte")
+    }
+
+    /// Returns the next 8 bytes of the keystream packed in a FheUint64
+    ///
+    /// The first byte of the keystream is the most significant one.
+    fn next_u64(&mut self) -> FheUint64 {
+        let keystream = self.next_keystream(8);
+        let tag = keystream[0].tag.clone();
+        let blocks = keystream
+            .into_iter()
+            .rev()
+            .flat_map(|byte| byte.into_raw_parts().0.blocks)
+            .collect::<Vec<_>>();
+        FheUint64::from_raw_parts(RadixCiphertext::from(blocks), FheUint64Id, tag)
+    }
+
+    /// Decrypt data encrypted with the clear version of the cipher
+    ///
+    /// The output is encrypted under the FHE key.
+    fn decrypt_symmetric(&mut self, ciphertext: &[u8]) -> FheUint8Array {
+        let keystream = self.next_keystream(ciphertext.len());
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let data = keystream
+                .par_iter()
+                .zip(ciphertext.par_iter())
+                .map(|(key, byte)| sks.scalar_bitxor_parallelized(&*key.ciphertext.on_cpu(), *byte))
+                .collect::<Vec<_>>();
+            FheUint8Array::new(data, vec![ciphertext.len()])
+        })
+    }
+}
+
+/// Symmetric key of a stream cipher, encrypted under the FHE key
+///
+/// It's meant to be generated by the client and sent once to the server.
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(FheCipherKeyVersions)]
+pub struct FheCipherKey {
+    bytes: Vec<FheUint8>,
+}
+
+impl Named for FheCipherKey {
+    const NAME: &'static str = "high_level_api::FheCipherKey";
+}
+
+impl FheCipherKey {
+    /// Encrypt a symmetric key byte per byte
+    pub fn encrypt(key: &[u8], client_key: &ClientKey) -> Self {
+        let bytes = key
+            .iter()
+            .map(|byte| FheUint8::encrypt(*byte, client_key))
+            .collect();
+        Self { bytes }
+    }
+
+    /// Number of bytes of the key
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Retrieve the key bytes as integer ciphertexts
+    /// Error if the key doesn't have the length expected by the cipher
+    fn cpu_bytes(&self, cipher: &str, expected_len: usize) -> crate::Result<Vec<RadixCiphertext>> {
+        if self.len() != expected_len {
+            return Err(crate::Error::new(format!(
+                "{cipher} requires a key of {expected_len} bytes, got {}",
+                self.len()
+            )));
+        }
+        Ok(self
+            .bytes
+            .iter()
+            .map(|byte| byte.ciphertext.on_cpu().into_owned())
+            .collect())
+    }
+}
+
+/// Byte level operations required to evaluate the ciphers
+///
+/// This enables to share the ciphers description between the clear and the homomorphic versions.
+pub(crate) trait ByteOps: Sync {
+    type Byte: Clone + Send + Sync;
+
+    fn trivial(&self, value: u8) -> Self::Byte;
+    fn xor(&self, lhs: &Self::Byte, rhs: &Self::Byte) -> Self::Byte;
+    fn scalar_xor(&self, lhs: &Self::Byte, rhs: u8) -> Self::Byte;
+    fn and(&self, lhs: &Self::Byte, rhs: &Self::Byte) -> Self::Byte;
+    fn or(&self, lhs: &Self::Byte, rhs: &Self::Byte) -> Self::Byte;
+    fn shl(&self, lhs: &Self::Byte, shift: u8) -> Self::Byte;
+    fn shr(&self, lhs: &Self::Byte, shift: u8) -> Self::Byte;
+    fn reverse_bits(&self, byte: &Self::Byte) -> Self::Byte;
+}
+
+/// Clear evaluation of the ciphers
+pub(crate) struct ClearOps;
+
+impl ByteOps for ClearOps {
+    type Byte = u8;
+
+    fn trivial(&self, value: u8) -> u8 {
+        value
+    }
+    fn xor(&self, lhs: &u8, rhs: &u8) -> u8 {
+        lhs ^ rhs
+    }
+    fn scalar_xor(&self, lhs: &u8, rhs: u8) -> u8 {
+        lhs ^ rhs
+    }
+    fn and(&self, lhs: &u8, rhs: &u8) -> u8 {
+        lhs & rhs
+    }
+    fn or(&self, lhs: &u8, rhs: &u8) -> u8 {
+        lhs | rhs
+    }
+    fn shl(&self, lhs: &u8, shift: u8) -> u8 {
+        lhs << shift
+    }
+    fn shr(&self, lhs: &u8, shift: u8) -> u8 {
+        lhs >> shift
+    }
+    fn reverse_bits(&self, byte: &u8) -> u8 {
+        byte.reverse_bits()
+    }
+}
+
+/// Homomorphic evaluation of the ciphers, bytes are encrypted as radix ciphertexts
+impl ByteOps for crate::integer::ServerKey {
+    type Byte = RadixCiphertext;
+
+    fn trivial(&self, value: u8) -> RadixCiphertext {
+        self.create_trivial_radix(value, FheUint8Id::num_blocks(self.message_modulus()))
+    }
+    fn xor(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        self.bitxor_parallelized(lhs, rhs)
+    }
+    fn scalar_xor(&self, lhs: &RadixCiphertext, rhs: u8) -> RadixCiphertext {
+        self.scalar_bitxor_parallelized(lhs, rhs)
+    }
+    fn and(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        self.bitand_parallelized(lhs, rhs)
+    }
+    fn or(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        self.bitor_parallelized(lhs, rhs)
+    }
+    fn shl(&self, lhs: &RadixCiphertext, shift: u8) -> RadixCiphertext {
+        self.scalar_left_shift_parallelized(lhs, shift)
+    }
+    fn shr(&self, lhs: &RadixCiphertext, shift: u8) -> RadixCiphertext {
+        self.scalar_right_shift_parallelized(lhs, shift)
+    }
+    fn reverse_bits(&self, byte: &RadixCiphertext) -> RadixCiphertext {
+        self.reverse_bits_parallelized(byte)
+    }
+}
+
+/// Wrap the keystream bytes produced by a homomorphic cipher in FheUint8
+/// `count` bytes are taken from the front of `keystream`, which must be long enough.
+fn drain_keystream(keystream: &mut Vec<RadixCiphertext>, count: usize) -> Vec<FheUint8> {
+    let tag = global_state::tag_of_internal_server_key().unwrap();
+    keystream
+        .drain(..count)
+        .map(|byte| FheUint8::new(byte, tag.clone()))
+        .collect()
+}
@@ -0,0 +1,75 @@
+//! Shift register of bytes, pretending to store bits.
+//!
+//! Registers are filled byte per byte, but are indexed by bit: bit 0 is the youngest one.
+//! Accessing a byte at an index that is not a multiple of 8 recombines the two stored bytes that
+//! cover it.
+
+use super::ByteOps;
+use crate::high_level_api::backward_compatibility::transciphering::ByteRegisterVersions;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(ByteRegisterVersions)]
+pub struct ByteRegister<T> {
+    bytes: Vec<T>,
+    /// Index of the oldest byte, i.e. the next one to be overwritten
+    cursor: usize,
+}
+
+impl<T> ByteRegister<T> {
+    /// The first byte is the oldest, the last is the youngest
+    pub(super) fn new(bytes: Vec<T>) -> Self {
+        assert!(!bytes.is_empty(), "Byte register cannot be empty");
+        Self { bytes, cursor: 0 }
+    }
+
+    /// Push a new byte, overwriting the oldest one
+    pub(super) fn push(&mut self, byte: T) {
+        self.bytes[self.cursor] = byte;
+        self.shift(1);
+    }
+
+    /// Rotate the register by `n` bytes, i.e. push back the `n` oldest bytes
+    pub(super) fn shift(&mut self, n: usize) {
+        self.cursor = (self.cursor + n) % self.bytes.len();
+    }
+
+    /// Stored byte at position `i`, 0 is the youngest
+    fn stored(&self, i: usize) -> &T {
+        let len = self.bytes.len();
+        assert!(i < len, "Index {i} too high for register of {len} bytes");
+        &self.bytes[(len + self.cursor - i - 1) % len]
+    }
+}
+
+impl<T: Clone + Send + Sync> ByteRegister<T> {
+    /// Byte covering the bits `i..i+8`, reconstructed from the stored ones if required
+    pub(super) fn byte<O: ByteOps<Byte = T>>(&self, ops: &O, i: usize) -> T {
+        let byte = self.stored(i / 8);
+        let bit_idx = (i % 8) as u8;
+
+        if bit_idx == 0 {
+            return byte.clone();
+        }
+
+        let byte_next = self.stored(i / 8 + 1);
+        let (hi, lo) = rayon::join(
+            || ops.shl(byte, bit_idx),
+            || ops.shr(byte_next, 8 - bit_idx),
+        );
+        ops.or(&hi, &lo)
+    }
+
+    /// Reconstruct a bunch of 5 bytes in parallel
+    pub(super) fn bytes<O: ByteOps<Byte = T>>(&self, ops: &O, offsets: [usize; 5]) -> [T; 5] {
+        let bytes = offsets
+            .par_iter()
+            .map(|&i| self.byte(ops, i))
+            .collect::<Vec<_>>();
+        bytes
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("Exactly 5 offsets are reconstructed"))
+    }
+}
@@ -0,0 +1,156 @@
+use super::*;
+use crate::high_level_api::tests::setup_default_cpu;
+use crate::prelude::FheDecrypt;
+use crate::safe_serialization::{safe_deserialize, safe_serialize};
+use std::fmt::Write;
+
+// Test vectors from the reference implementations
+const TRIVIUM_KEY: &str = "0053A6F94C9FF24598EB";
+const TRIVIUM_IV: &str = "0D74DB42A91077DE45AC";
+const TRIVIUM_OUTPUT_0_63: &str = "F4CD954A717F26A7D6930830C4E7CF0819F80E03F25F342C64ADC66ABA7F8A8E6EAA49F23632AE3CD41A7BD290A0132F81C6D4043B6E397D7388F3A03B5FE358";
+const TRIVIUM_OUTPUT_65472_65535: &str = "C04C24A6938C8AF8A491D5E481271E0E601338F01067A86A795CA493AA4FF265619B8D448B706B7C88EE8395FC79E5B51AB40245BBF7773AE67DF86FCFB71F30";
+
+const KREYVIUM_KEY: &str = "0053A6F94C9FF24598EB000000000000";
+const KREYVIUM_IV: &str = "0D74DB42A91077DE45AC000000000000";
+const KREYVIUM_OUTPUT_0_7: &str = "D1F0303482061111";
+
+const SERIALIZATION_LIMIT: u64 = 1 << 30;
+
+fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    bytes
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        write!(hex, "{byte:02X}").unwrap();
+        hex
+    })
+}
+
+#[test]
+fn test_trivium_clear() {
+    let mut trivium = Trivium::new(&from_hex(TRIVIUM_KEY), &from_hex(TRIVIUM_IV));
+
+    // Uneven requests must not alter the keystream
+    let mut keystream = trivium.next_keystream(5);
+    keystream.extend(trivium.next_keystream(65536 - 5));
+
+    assert_eq!(to_hex(&keystream[..64]), TRIVIUM_OUTPUT_0_63);
+    assert_eq!(to_hex(&keystream[65472..]), TRIVIUM_OUTPUT_65472_65535);
+}
+
+#[test]
+fn test_kreyvium_clear() {
+    let mut kreyvium = Kreyvium::new(&from_hex(KREYVIUM_KEY), &from_hex(KREYVIUM_IV));
+
+    assert_eq!(to_hex(&kreyvium.next_keystream(8)), KREYVIUM_OUTPUT_0_7);
+}
+
+#[test]
+fn test_fhe_byte_ops() {
+    let (cks, sks) = crate::integer::gen_keys_radix(
+        crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        4,
+    );
+
+    let (lhs, rhs) = (0b1011_0110u8, 0b0110_1101u8);
+    let (fhe_lhs, fhe_rhs) = (cks.encrypt(lhs), cks.encrypt(rhs));
+    let decrypt = |ct: &RadixCiphertext| -> u8 { cks.decrypt(ct) };
+
+    assert_eq!(decrypt(&sks.trivial(lhs)), ClearOps.trivial(lhs));
+    assert_eq!(
+        decrypt(&sks.xor(&fhe_lhs, &fhe_rhs)),
+        ClearOps.xor(&lhs, &rhs)
+    );
+    assert_eq!(
+        decrypt(&sks.scalar_xor(&fhe_lhs, rhs)),
+        ClearOps.scalar_xor(&lhs, rhs)
+    );
+    assert_eq!(
+        decrypt(&sks.and(&fhe_lhs, &fhe_rhs)),
+        ClearOps.and(&lhs, &rhs)
+    );
+    assert_eq!(
+        decrypt(&sks.or(&fhe_lhs, &fhe_rhs)),
+        ClearOps.or(&lhs, &rhs)
+    );
+    assert_eq!(decrypt(&sks.shl(&fhe_lhs, 3)), ClearOps.shl(&lhs, 3));
+    assert_eq!(decrypt(&sks.shr(&fhe_lhs, 3)), ClearOps.shr(&lhs, 3));
+    assert_eq!(
+        decrypt(&sks.reverse_bits(&fhe_lhs)),
+        ClearOps.reverse_bits(&lhs)
+    );
+}
+
+/// Check the keystream against the reference output, then check that the transciphered data
+/// matches the one encrypted with the clear cipher, before and after a serialization round trip
+fn check_stream_cipher<C>(
+    cks: &ClientKey,
+    fhe_cipher: &mut C,
+    clear_encrypt: &mut dyn FnMut(&[u8]) -> Vec<u8>,
+    expected: [u8; 8],
+) where
+    C: StreamCipher + Serialize + serde::de::DeserializeOwned + Versionize + Named,
+    C: tfhe_versionable::Unversionize,
+{
+    // Keystream is consumed by the clear cipher as well, to stay in sync
+    let _ = clear_encrypt(&[0u8; 8]);
+    let keystream = fhe_cipher.next_u64();
+    let keystream: u64 = keystream.decrypt(cks);
+    assert_eq!(keystream.to_be_bytes(), expected);
+
+    let message = b"Hello, transciphering!";
+    let (head, tail) = message.split_at(7);
+
+    let encrypted = clear_encrypt(head);
+    let decrypted: Vec<u8> = fhe_cipher.decrypt_symmetric(&encrypted).decrypt(cks);
+    assert_eq!(decrypted, head);
+
+    let mut buffer = vec![];
+    safe_serialize(&*fhe_cipher, &mut buffer, SERIALIZATION_LIMIT).unwrap();
+    let mut fhe_cipher: C = safe_deserialize(buffer.as_slice(), SERIALIZATION_LIMIT).unwrap();
+
+    let encrypted = clear_encrypt(tail);
+    let decrypted: Vec<u8> = fhe_cipher.decrypt_symmetric(&encrypted).decrypt(cks);
+    assert_eq!(decrypted, tail);
+}
+
+#[test]
+fn test_fhe_trivium() {
+    let cks = setup_default_cpu();
+    let key = from_hex::<TRIVIUM_KEY_LEN>(TRIVIUM_KEY);
+    let iv = from_hex::<TRIVIUM_IV_LEN>(TRIVIUM_IV);
+
+    let fhe_key = FheCipherKey::encrypt(&key, &cks);
+    assert!(FheTrivium::new(&FheCipherKey::encrypt(&key[1..], &cks), &iv).is_err());
+
+    let mut fhe_trivium = FheTrivium::new(&fhe_key, &iv).unwrap();
+    let mut trivium = Trivium::new(&key, &iv);
+    check_stream_cipher(
+        &cks,
+        &mut fhe_trivium,
+        &mut |data| trivium.encrypt(data),
+        from_hex(TRIVIUM_OUTPUT_0_63),
+    );
+}
+
+#[test]
+fn test_fhe_kreyvium() {
+    let cks = setup_default_cpu();
+    let key = from_hex::<KREYVIUM_KEY_LEN>(KREYVIUM_KEY);
+    let iv = from_hex::<KREYVIUM_IV_LEN>(KREYVIUM_IV);
+
+    let fhe_key = FheCipherKey::encrypt(&key, &cks);
+    let mut fhe_kreyvium = FheKreyvium::new(&fhe_key, &iv).unwrap();
+    let mut kreyvium = Kreyvium::new(&key, &iv);
+    check_stream_cipher(
+        &cks,
+        &mut fhe_kreyvium,
+        &mut |data| kreyvium.encrypt(data),
+        from_hex(KREYVIUM_OUTPUT_0_7),
+    );
+}
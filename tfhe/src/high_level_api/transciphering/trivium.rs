@@ -0,0 +1,204 @@
+//! Trivium stream cipher, evaluated byte per byte.
+//!
+//! Trivium registers' sizes are not a multiple of 8, thus the byte registers are sized to the
+//! closest multiple of 8 above the original ones.
+
+use super::register::ByteRegister;
+use super::{drain_keystream, ByteOps, ClearOps, FheCipherKey, StreamCipher};
+use crate::high_level_api::backward_compatibility::transciphering::{
+    FheTriviumVersions, TriviumRegistersVersions, TriviumVersions,
+};
+use crate::high_level_api::global_state;
+use crate::integer::RadixCiphertext;
+use crate::named::Named;
+use crate::FheUint8;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+pub const TRIVIUM_KEY_LEN: usize = 10;
+pub const TRIVIUM_IV_LEN: usize = 10;
+
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(TriviumRegistersVersions)]
+pub struct TriviumRegisters<T> {
+    a: ByteRegister<T>,
+    b: ByteRegister<T>,
+    c: ByteRegister<T>,
+}
+
+impl<T: Clone + Send + Sync> TriviumRegisters<T> {
+    /// Load the key and the iv in the registers, and run the initialization rounds
+    fn new<O: ByteOps<Byte = T>>(ops: &O, key: Vec<T>, iv: &[u8; TRIVIUM_IV_LEN]) -> Self {
+        assert_eq!(key.len(), TRIVIUM_KEY_LEN);
+
+        // a has the secret key, b the input vector and c a few ones
+        let mut a = (0..2).map(|_| ops.trivial(0)).collect::<Vec<_>>();
+        a.extend(key);
+        let mut b = vec![ops.trivial(0)];
+        b.extend(iv.iter().map(|byte| ops.trivial(*byte)));
+        // Magic number 14, aka 00001110: this represents the 3 ones at the beginning of the c
+        // register, with additional zeros to make the register's size a multiple of 8.
+        let mut c = vec![ops.trivial(14)];
+        c.extend((1..14).map(|_| ops.trivial(0)));
+
+        let mut registers = Self {
+            a: ByteRegister::new(a),
+            b: ByteRegister::new(b),
+            c: ByteRegister::new(c),
+        };
+        // The specification of Trivium includes running 1152 (= 18*64) unused steps to mix up
+        // the registers, before starting the proper stream
+        for _ in 0..18 {
+            registers.next_64(ops);
+        }
+        registers
+    }
+
+    /// Computes 8 steps of Trivium, `b*8` steps in the future. This does not update the
+    /// registers, but rather returns the output and the three values that will be used to
+    /// update the registers.
+    fn output_and_values<O: ByteOps<Byte = T>>(&self, ops: &O, b: usize) -> [T; 4] {
+        let n = b * 8 + 7;
+        assert!(n < 65);
+
+        let ([a1, a2, a3, a4, a5], ([b1, b2, b3, b4, b5], [c1, c2, c3, c4, c5])) = rayon::join(
+            || self.a.bytes(ops, [91 - n, 90 - n, 68 - n, 65 - n, 92 - n]),
+            || {
+                rayon::join(
+                    || self.b.bytes(ops, [82 - n, 81 - n, 77 - n, 68 - n, 83 - n]),
+                    || {
+                        self.c
+                            .bytes(ops, [109 - n, 108 - n, 86 - n, 65 - n, 110 - n])
+                    },
+                )
+            },
+        );
+
+        let (((temp_a, temp_b), (temp_c, a_and)), (b_and, c_and)) = rayon::join(
+            || {
+                rayon::join(
+                    || rayon::join(|| ops.xor(&a4, &a5), || ops.xor(&b4, &b5)),
+                    || rayon::join(|| ops.xor(&c4, &c5), || ops.and(&a1, &a2)),
+                )
+            },
+            || rayon::join(|| ops.and(&b1, &b2), || ops.and(&c1, &c2)),
+        );
+
+        let ((o, a), (b, c)) = rayon::join(
+            || {
+                rayon::join(
+                    || ops.xor(&ops.xor(&temp_a, &temp_b), &temp_c),
+                    || ops.xor(&temp_c, &ops.xor(&c_and, &a3)),
+                )
+            },
+            || {
+                rayon::join(
+                    || ops.xor(&temp_a, &ops.xor(&a_and, &b3)),
+                    || ops.xor(&temp_b, &ops.xor(&b_and, &c3)),
+                )
+            },
+        );
+
+        [o, a, b, c]
+    }
+
+    /// Computes 64 steps, outputting 8 bytes of keystream (first value is the oldest)
+    fn next_64<O: ByteOps<Byte = T>>(&mut self, ops: &O) -> Vec<T> {
+        let values = (0..8)
+            .into_par_iter()
+            .map(|b| self.output_and_values(ops, b))
+            .collect::<Vec<_>>();
+
+        values
+            .into_iter()
+            .map(|[o, a, b, c]| {
+                self.a.push(a);
+                self.b.push(b);
+                self.c.push(c);
+                o
+            })
+            .collect()
+    }
+}
+
+/// Clear Trivium, used to encrypt data that will be transciphered with [FheTrivium]
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(TriviumVersions)]
+pub struct Trivium {
+    registers: TriviumRegisters<u8>,
+    /// Keystream bytes generated but not consumed yet
+    keystream: Vec<u8>,
+}
+
+impl Named for Trivium {
+    const NAME: &'static str = "high_level_api::Trivium";
+}
+
+impl Trivium {
+    pub fn new(key: &[u8; TRIVIUM_KEY_LEN], iv: &[u8; TRIVIUM_IV_LEN]) -> Self {
+        Self {
+            registers: TriviumRegisters::new(&ClearOps, key.to_vec(), iv),
+            keystream: Vec::new(),
+        }
+    }
+
+    /// Returns the next `count` bytes of the keystream
+    pub fn next_keystream(&mut self, count: usize) -> Vec<u8> {
+        while self.keystream.len() < count {
+            let bytes = self.registers.next_64(&ClearOps);
+            self.keystream.extend(bytes);
+        }
+        self.keystream.drain(..count).collect()
+    }
+
+    /// Encrypt (or decrypt) data with the next bytes of the keystream
+    pub fn encrypt(&mut self, data: &[u8]) -> Vec<u8> {
+        self.next_keystream(data.len())
+            .iter()
+            .zip(data.iter())
+            .map(|(key, byte)| key ^ byte)
+            .collect()
+    }
+}
+
+/// Homomorphic Trivium
+///
+/// Requires a Cpu server key to be set, to run the initialization rounds and generate the
+/// keystream.
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(FheTriviumVersions)]
+pub struct FheTrivium {
+    registers: TriviumRegisters<RadixCiphertext>,
+    /// Keystream bytes generated but not consumed yet
+    keystream: Vec<RadixCiphertext>,
+}
+
+impl Named for FheTrivium {
+    const NAME: &'static str = "high_level_api::FheTrivium";
+}
+
+impl FheTrivium {
+    pub fn new(key: &FheCipherKey, iv: &[u8; TRIVIUM_IV_LEN]) -> crate::Result<Self> {
+        let key = key.cpu_bytes("Trivium", TRIVIUM_KEY_LEN)?;
+        let registers = global_state::with_cpu_internal_keys(|cpu_key| {
+            TriviumRegisters::new(cpu_key.pbs_key(), key, iv)
+        });
+        Ok(Self {
+            registers,
+            keystream: Vec::new(),
+        })
+    }
+}
+
+impl StreamCipher for FheTrivium {
+    fn next_keystream(&mut self, count: usize) -> Vec<FheUint8> {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            while self.keystream.len() < count {
+                let bytes = self.registers.next_64(cpu_key.pbs_key());
+                self.keystream.extend(bytes);
+            }
+        });
+        drain_keystream(&mut self.keystream, count)
+    }
+}
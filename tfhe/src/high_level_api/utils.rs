@@ -119,3 +119,24 @@ impl Expandable for FheBool {
         }
     }
 }
+
+/// Estimates the failure probability of a PBS applied on any of the blocks using the current
+/// server key, the noise of ciphertexts is only tracked by the CPU backend
+#[cfg(feature = "noise-tracking")]
+pub(in crate::high_level_api) fn failure_probability_of_blocks<'a>(
+    blocks: impl IntoIterator<Item = &'a Ciphertext>,
+) -> Option<f64> {
+    use crate::high_level_api::global_state;
+    use crate::high_level_api::keys::InternalServerKey;
+    use crate::shortint::noise_tracking::log2_failure_probability_of_blocks;
+
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            log2_failure_probability_of_blocks(&cpu_key.pbs_key().key, blocks).map(f64::exp2)
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => None,
+        #[cfg(feature = "hpu")]
+        InternalServerKey::Hpu(_) => None,
+    })
+}
@@ -1,7 +1,37 @@
 use crate::core_crypto::prelude::LweCiphertextCount;
-use crate::zk::CompactPkeCrs;
+use crate::shortint::parameters::CompactPublicKeyEncryptionParameters;
+use crate::zk::{CompactPkeCrs, CompactPkeCrsCeremony, CompactPkeZkCurve};
 use crate::{Config, Error};
 
+/// Returns the encryption parameters of the config and the number of messages needed to prove
+/// `max_bit_size` bits, assuming that packing will be applied during ZK proof.
+fn packed_crs_parameters(
+    config: Config,
+    max_bit_size: usize,
+) -> crate::Result<(CompactPublicKeyEncryptionParameters, LweCiphertextCount)> {
+    let compact_encryption_parameters = config.public_key_encryption_parameters()?;
+
+    if compact_encryption_parameters.carry_modulus.0
+        < compact_encryption_parameters.message_modulus.0
+    {
+        return Err(Error::new(
+            "In order to build a ZK-CRS for packed compact ciphertext list encryption, \
+            parameters must have CarryModulus >= MessageModulus"
+                .to_string(),
+        ));
+    }
+
+    let carry_and_message_bit_capacity = (compact_encryption_parameters.carry_modulus.0
+        * compact_encryption_parameters.message_modulus.0)
+        .ilog2() as usize;
+    let max_num_message = max_bit_size.div_ceil(carry_and_message_bit_capacity);
+
+    Ok((
+        compact_encryption_parameters,
+        LweCiphertextCount(max_num_message),
+    ))
+}
+
 impl CompactPkeCrs {
     /// Create a new `CompactPkeCrs` from a `Config` object.
     /// max_bit_size is the maximum number of bits that can be proven, e.g. 64 for a single
@@ -9,26 +39,37 @@ impl CompactPkeCrs {
     ///
     /// This function assumes that packing will be applied during ZK proof.
     pub fn from_config(config: Config, max_bit_size: usize) -> crate::Result<Self> {
-        let compact_encryption_parameters = config.public_key_encryption_parameters()?;
-
-        if compact_encryption_parameters.carry_modulus.0
-            < compact_encryption_parameters.message_modulus.0
-        {
-            return Err(Error::new(
-                "In order to build a ZK-CRS for packed compact ciphertext list encryption, \
-                parameters must have CarryModulus >= MessageModulus"
-                    .to_string(),
-            ));
-        }
+        Self::from_config_with_curve(config, max_bit_size, CompactPkeZkCurve::default())
+    }
 
-        let carry_and_message_bit_capacity = (compact_encryption_parameters.carry_modulus.0
-            * compact_encryption_parameters.message_modulus.0)
-            .ilog2() as usize;
-        let max_num_message = max_bit_size.div_ceil(carry_and_message_bit_capacity);
-        let crs = Self::from_shortint_params(
+    /// Create a new `CompactPkeCrs` from a `Config` object, for proofs built on the given curve.
+    ///
+    /// See [`Self::from_config`] for the meaning of max_bit_size.
+    pub fn from_config_with_curve(
+        config: Config,
+        max_bit_size: usize,
+        curve: CompactPkeZkCurve,
+    ) -> crate::Result<Self> {
+        let (compact_encryption_parameters, max_num_message) =
+            packed_crs_parameters(config, max_bit_size)?;
+        let crs = Self::from_shortint_params_with_curve(
             compact_encryption_parameters,
-            LweCiphertextCount(max_num_message),
+            max_num_message,
+            curve,
         )?;
         Ok(crs)
     }
 }
+
+impl CompactPkeCrsCeremony {
+    /// Starts a ceremony for a `CompactPkeCrs` from a `Config` object.
+    /// max_bit_size is the maximum number of bits that can be proven, e.g. 64 for a single
+    /// FheUint64 or 8 x FheUint8 values.
+    ///
+    /// The CRS produced by the ceremony is the same as the one of [`CompactPkeCrs::from_config`].
+    pub fn from_config(config: Config, max_bit_size: usize) -> crate::Result<Self> {
+        let (compact_encryption_parameters, max_num_message) =
+            packed_crs_parameters(config, max_bit_size)?;
+        Self::from_shortint_params(compact_encryption_parameters, max_num_message)
+    }
+}
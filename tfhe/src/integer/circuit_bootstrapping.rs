@@ -0,0 +1,53 @@
+//! Keys to circuit bootstrap the blocks of integer ciphertexts into GGSW ciphertexts
+//!
+//! Given to [`ServerKey::table_lookup_with_circuit_bootstrapping_parallelized`], they allow to
+//! evaluate large tables with a vertical packing.
+//!
+//! [`ServerKey::table_lookup_with_circuit_bootstrapping_parallelized`]: crate::integer::ServerKey::table_lookup_with_circuit_bootstrapping_parallelized
+
+use crate::integer::ClientKey;
+use crate::shortint::parameters::CircuitBootstrappingParameters;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(dylint_lib = "tfhe_lints", allow(serialize_without_versionize))]
+pub struct CircuitBootstrappingKey {
+    pub(crate) key: crate::shortint::circuit_bootstrapping::CircuitBootstrappingKey,
+}
+
+impl ClientKey {
+    /// Generates a [`CircuitBootstrappingKey`], the `parameters` must have been designed for the
+    /// parameters of the client key, e.g.
+    /// [`CIRCUIT_BOOTSTRAPPING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128`](crate::shortint::parameters::CIRCUIT_BOOTSTRAPPING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+    /// for
+    /// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128).
+    pub fn new_circuit_bootstrapping_key(
+        &self,
+        parameters: CircuitBootstrappingParameters,
+    ) -> CircuitBootstrappingKey {
+        CircuitBootstrappingKey {
+            key: self.key.new_circuit_bootstrapping_key(parameters),
+        }
+    }
+}
+
+impl CircuitBootstrappingKey {
+    pub fn new(client_key: &ClientKey, parameters: CircuitBootstrappingParameters) -> Self {
+        client_key.new_circuit_bootstrapping_key(parameters)
+    }
+
+    pub fn parameters(&self) -> CircuitBootstrappingParameters {
+        self.key.parameters()
+    }
+
+    pub fn into_raw_parts(self) -> crate::shortint::circuit_bootstrapping::CircuitBootstrappingKey {
+        let Self { key } = self;
+        key
+    }
+
+    pub fn from_raw_parts(
+        key: crate::shortint::circuit_bootstrapping::CircuitBootstrappingKey,
+    ) -> Self {
+        Self { key }
+    }
+}
@@ -2,7 +2,7 @@ use hpu_asm::iop::*;
 use tfhe_hpu_backend::prelude::*;
 
 use crate::core_crypto::prelude::{CreateFrom, LweCiphertextOwned};
-use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::integer::{BooleanBlock, RadixCiphertext, SignedRadixCiphertext};
 use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::parameters::KeySwitch32PBSParameters;
 use crate::shortint::{AtomicPatternKind, Ciphertext};
@@ -21,10 +21,26 @@ impl HpuRadixCiphertext {
     ///
     /// No transfer with FPGA will occur until an operation on the HpuRadixCiphertext is requested
     pub fn from_radix_ciphertext(cpu_ct: &RadixCiphertext, device: &HpuDevice) -> Self {
+        Self::from_blocks(&cpu_ct.blocks, device)
+    }
+
+    /// Create a Hpu Radix ciphertext based on a Cpu signed one.
+    ///
+    /// Hpu stores the two's complement representation as is, the signed semantic is carried by
+    /// the IOp used on it (e.g. CMP_SLT, SDIV, ...).
+    ///
+    /// No transfer with FPGA will occur until an operation on the HpuRadixCiphertext is requested
+    pub fn from_signed_radix_ciphertext(
+        cpu_ct: &SignedRadixCiphertext,
+        device: &HpuDevice,
+    ) -> Self {
+        Self::from_blocks(&cpu_ct.blocks, device)
+    }
+
+    fn from_blocks(blocks: &[Ciphertext], device: &HpuDevice) -> Self {
         let params = device.params().clone();
 
-        let hpu_ct = cpu_ct
-            .blocks
+        let hpu_ct = blocks
             .iter()
             .map(|blk| HpuLweCiphertextOwned::create_from(blk.ct.as_view(), params.clone()))
             .collect::<Vec<_>>();
@@ -34,10 +50,21 @@ impl HpuRadixCiphertext {
 
     /// Create a Cpu radix ciphertext copy from a Hpu one.
     pub fn to_radix_ciphertext(&self) -> RadixCiphertext {
+        RadixCiphertext {
+            blocks: self.to_blocks(),
+        }
+    }
+
+    /// Create a Cpu signed radix ciphertext copy from a Hpu one.
+    pub fn to_signed_radix_ciphertext(&self) -> SignedRadixCiphertext {
+        SignedRadixCiphertext::from(self.to_blocks())
+    }
+
+    fn to_blocks(&self) -> Vec<Ciphertext> {
         // NB: We clone the inner part of HpuRadixCiphertext but it is not costly since
         // it's wrapped inside an Arc
         let hpu_ct = self.0.clone().into_ct();
-        let cpu_ct = hpu_ct
+        hpu_ct
             .into_iter()
             .map(|ct| {
                 let pbs_p = KeySwitch32PBSParameters::from(ct.params());
@@ -52,8 +79,7 @@ impl HpuRadixCiphertext {
                     AtomicPatternKind::KeySwitch32,
                 )
             })
-            .collect::<Vec<_>>();
-        RadixCiphertext { blocks: cpu_ct }
+            .collect::<Vec<_>>()
     }
 
     /// Create a Hpu boolean ciphertext based on a Cpu one.
@@ -237,6 +263,10 @@ map_ct_ct!(IOP_BW_AND -> "BitAnd");
 map_ct_ct!(IOP_BW_OR  -> "BitOr");
 map_ct_ct!(IOP_BW_XOR -> "BitXor");
 
+map_ct_scalar!(IOP_BWS_AND -> "BitAnd");
+map_ct_scalar!(IOP_BWS_OR  -> "BitOr");
+map_ct_scalar!(IOP_BWS_XOR -> "BitXor");
+
 map_ct_scalar!(IOP_ADDS -> "Add");
 map_scalar_ct!(IOP_ADDS -> "Add");
 map_ct_scalar!(IOP_SUBS -> "Sub");
@@ -56,6 +56,7 @@ mod tests;
 pub mod backward_compatibility;
 pub mod bigint;
 pub mod ciphertext;
+pub mod circuit_bootstrapping;
 pub mod client_key;
 pub mod compression_keys;
 pub mod key_switching_key;
@@ -181,12 +181,14 @@ impl<'a> Comparator<'a> {
         // so (-1) % (4 * 4) = 15 = 1|1111
         // We then add one and get 0 = 0|0000
 
+        let estimated_noise = lhs.estimated_noise() + rhs.estimated_noise();
         // Here we need the true lwe sub, not the one that comes from shortint.
         crate::core_crypto::algorithms::lwe_ciphertext_sub_assign(&mut lhs.ct, &rhs.ct);
         lhs.set_noise_level(
             lhs.noise_level() + rhs.noise_level(),
             self.server_key.key.max_noise_level,
         );
+        lhs.set_estimated_noise(estimated_noise);
         self.server_key
             .key
             .apply_lookup_table_assign(lhs, &self.sign_lut);
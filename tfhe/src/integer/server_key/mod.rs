@@ -109,6 +109,31 @@ impl ServerKey {
         Self { key: sks }
     }
 
+    /// Generates an insecure server key destined to be used with
+    /// [`RadixCiphertext`](`crate::integer::RadixCiphertext`), that evaluates lookup tables in
+    /// the clear.
+    ///
+    /// See [`crate::shortint::ServerKey::new_cleartext_simulation`], the returned key contains
+    /// the secret key of `cks` and must only be used for tests and debugging.
+    #[cfg(feature = "cleartext-simulation")]
+    pub fn new_radix_cleartext_simulation<C>(cks: C) -> Self
+    where
+        C: AsRef<ClientKey>,
+    {
+        let client_key = cks.as_ref();
+        let max_degree = MaxDegree::integer_radix_server_key(
+            client_key.key.parameters().message_modulus(),
+            client_key.key.parameters().carry_modulus(),
+        );
+
+        let sks = crate::shortint::server_key::ServerKey::new_cleartext_simulation_with_max_degree(
+            &client_key.key,
+            max_degree,
+        );
+
+        Self { key: sks }
+    }
+
     pub fn new_crt_server_key<C>(cks: C) -> Self
     where
         C: AsRef<ClientKey>,
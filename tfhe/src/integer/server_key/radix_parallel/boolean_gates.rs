@@ -0,0 +1,254 @@
+use super::ServerKey;
+use crate::integer::BooleanBlock;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+
+impl ServerKey {
+    /// Returns the number of boolean blocks that can be summed before a PBS
+    pub(crate) fn max_boolean_sum_size(&self) -> usize {
+        self.max_sum_size(Degree::new(1))
+    }
+
+    /// Sums the boolean blocks, so that a single PBS can then compute any function of the number
+    /// of blocks that encrypt `true`
+    fn boolean_sum(&self, booleans: &[&BooleanBlock]) -> crate::shortint::Ciphertext {
+        let max_sum_size = self.max_boolean_sum_size();
+        assert!(
+            !booleans.is_empty() && booleans.len() <= max_sum_size,
+            "Between 1 and {max_sum_size} booleans can be combined with the current parameters, \
+            got {}",
+            booleans.len()
+        );
+
+        let mut sum = self.key.create_trivial(0);
+        for boolean in booleans {
+            if boolean.0.noise_level() > NoiseLevel::NOMINAL {
+                let cleaned = self.key.message_extract(&boolean.0);
+                self.key.unchecked_add_assign(&mut sum, &cleaned);
+            } else {
+                self.key.unchecked_add_assign(&mut sum, &boolean.0);
+            }
+        }
+        sum
+    }
+
+    /// Computes `f` of the number of booleans that encrypt `true` with a single PBS
+    fn boolean_count_function<F>(&self, booleans: &[&BooleanBlock], f: F) -> BooleanBlock
+    where
+        F: Fn(usize) -> bool,
+    {
+        let sum = self.boolean_sum(booleans);
+        let lut = self
+            .key
+            .generate_lookup_table(|count| u64::from(f(count as usize)));
+        BooleanBlock::new_unchecked(self.key.apply_lookup_table(&sum, &lut))
+    }
+
+    /// Computes homomorphically whether at least `threshold` of the booleans encrypt `true`,
+    /// with a single PBS.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `booleans` is empty or holds too many values to be summed in a single block,
+    /// e.g. more than 5 with 2 bits of message and 2 bits of carry.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, num_blocks);
+    ///
+    /// let booleans = [true, false, true, true].map(|b| cks.encrypt_bool(b));
+    /// let refs = booleans.iter().collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.boolean_threshold(&refs, 3);
+    /// assert!(cks.decrypt_bool(&ct_res));
+    ///
+    /// let ct_res = sks.boolean_threshold(&refs, 4);
+    /// assert!(!cks.decrypt_bool(&ct_res));
+    /// ```
+    pub fn boolean_threshold(&self, booleans: &[&BooleanBlock], threshold: usize) -> BooleanBlock {
+        self.boolean_count_function(booleans, |count| count >= threshold)
+    }
+
+    /// Computes homomorphically whether strictly more than half of the booleans encrypt `true`,
+    /// with a single PBS.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `booleans` is empty or holds too many values to be summed in a single block,
+    /// e.g. more than 5 with 2 bits of message and 2 bits of carry.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, num_blocks);
+    ///
+    /// let a = cks.encrypt_bool(true);
+    /// let b = cks.encrypt_bool(false);
+    /// let c = cks.encrypt_bool(true);
+    ///
+    /// let ct_res = sks.boolean_majority(&[&a, &b, &c]);
+    /// assert!(cks.decrypt_bool(&ct_res));
+    /// ```
+    pub fn boolean_majority(&self, booleans: &[&BooleanBlock]) -> BooleanBlock {
+        let num_booleans = booleans.len();
+        self.boolean_count_function(booleans, |count| 2 * count > num_booleans)
+    }
+
+    /// Computes homomorphically a bitand between three boolean ciphertexts, with a single PBS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, num_blocks);
+    ///
+    /// let a = cks.encrypt_bool(true);
+    /// let b = cks.encrypt_bool(true);
+    /// let c = cks.encrypt_bool(false);
+    ///
+    /// let ct_res = sks.boolean_bitand3(&a, &b, &c);
+    /// assert!(!cks.decrypt_bool(&ct_res));
+    /// ```
+    pub fn boolean_bitand3(
+        &self,
+        a: &BooleanBlock,
+        b: &BooleanBlock,
+        c: &BooleanBlock,
+    ) -> BooleanBlock {
+        self.boolean_count_function(&[a, b, c], |count| count == 3)
+    }
+
+    /// Computes homomorphically a bitor between three boolean ciphertexts, with a single PBS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, num_blocks);
+    ///
+    /// let a = cks.encrypt_bool(false);
+    /// let b = cks.encrypt_bool(false);
+    /// let c = cks.encrypt_bool(true);
+    ///
+    /// let ct_res = sks.boolean_bitor3(&a, &b, &c);
+    /// assert!(cks.decrypt_bool(&ct_res));
+    /// ```
+    pub fn boolean_bitor3(
+        &self,
+        a: &BooleanBlock,
+        b: &BooleanBlock,
+        c: &BooleanBlock,
+    ) -> BooleanBlock {
+        self.boolean_count_function(&[a, b, c], |count| count != 0)
+    }
+
+    /// Computes homomorphically a bitxor between three boolean ciphertexts, with a single PBS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, num_blocks);
+    ///
+    /// let a = cks.encrypt_bool(true);
+    /// let b = cks.encrypt_bool(true);
+    /// let c = cks.encrypt_bool(true);
+    ///
+    /// let ct_res = sks.boolean_bitxor3(&a, &b, &c);
+    /// assert!(cks.decrypt_bool(&ct_res));
+    /// ```
+    pub fn boolean_bitxor3(
+        &self,
+        a: &BooleanBlock,
+        b: &BooleanBlock,
+        c: &BooleanBlock,
+    ) -> BooleanBlock {
+        self.boolean_count_function(&[a, b, c], |count| count % 2 == 1)
+    }
+
+    /// Adds three boolean ciphertexts, returns the sum and the carry.
+    ///
+    /// Both outputs are computed from the same linear combination of the inputs, with a single
+    /// PBS when the parameters allow to evaluate two lookup tables at once, or two PBS computed
+    /// in parallel otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, num_blocks);
+    ///
+    /// let a = cks.encrypt_bool(true);
+    /// let b = cks.encrypt_bool(false);
+    /// let carry = cks.encrypt_bool(true);
+    ///
+    /// let (sum, carry) = sks.boolean_full_adder(&a, &b, &carry);
+    /// assert!(!cks.decrypt_bool(&sum));
+    /// assert!(cks.decrypt_bool(&carry));
+    /// ```
+    pub fn boolean_full_adder(
+        &self,
+        a: &BooleanBlock,
+        b: &BooleanBlock,
+        carry: &BooleanBlock,
+    ) -> (BooleanBlock, BooleanBlock) {
+        let sum = self.boolean_sum(&[a, b, carry]);
+
+        let sum_bit = |count: u64| count % 2;
+        let carry_bit = |count: u64| u64::from(count >= 2);
+
+        let luts = self.key.generate_many_lookup_table(&[&sum_bit, &carry_bit]);
+        if sum.degree.get() <= luts.input_max_degree.get() {
+            let mut results = self.key.apply_many_lookup_table(&sum, &luts).into_iter();
+            let (Some(sum), Some(carry)) = (results.next(), results.next()) else {
+                unreachable!("two lookup tables were applied")
+            };
+            return (
+                BooleanBlock::new_unchecked(sum),
+                BooleanBlock::new_unchecked(carry),
+            );
+        }
+
+        let (sum, carry) = rayon::join(
+            || {
+                let lut = self.key.generate_lookup_table(sum_bit);
+                self.key.apply_lookup_table(&sum, &lut)
+            },
+            || {
+                let lut = self.key.generate_lookup_table(carry_bit);
+                self.key.apply_lookup_table(&sum, &lut)
+            },
+        );
+
+        (
+            BooleanBlock::new_unchecked(sum),
+            BooleanBlock::new_unchecked(carry),
+        )
+    }
+}
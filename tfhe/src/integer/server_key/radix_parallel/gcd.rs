@@ -0,0 +1,439 @@
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+
+impl ServerKey {
+    /// Returns the magnitude of `ct` as an unsigned ciphertext
+    ///
+    /// `ct` must not have carries
+    fn unchecked_unsigned_abs<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if T::IS_SIGNED {
+            RadixCiphertext::from_blocks(self.unchecked_abs_parallelized(ct).into_blocks())
+        } else {
+            RadixCiphertext::from_blocks(ct.blocks().to_vec())
+        }
+    }
+
+    /// Binary GCD (Stein's algorithm) with a number of iterations that only depends on the
+    /// number of bits of the inputs
+    fn unsigned_unchecked_gcd_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "lhs and rhs must have same length"
+        );
+        let num_bits = self.key.message_modulus.0.ilog2() as usize * lhs.blocks.len();
+        if num_bits == 0 {
+            return lhs.clone();
+        }
+
+        // gcd(a, b) = 2^k * gcd(a >> k, b >> k) where k is the number of trailing zeros of a | b,
+        // after this at least one of the two values is odd (unless both are 0)
+        let common_twos = self
+            .unchecked_trailing_zeros_parallelized(&self.unchecked_bitor_parallelized(lhs, rhs));
+        let (mut a, mut b) = rayon::join(
+            || self.unchecked_right_shift_parallelized(lhs, &common_twos),
+            || self.unchecked_right_shift_parallelized(rhs, &common_twos),
+        );
+
+        let is_even_lut = self.key.generate_lookup_table(|x| u64::from(x % 2 == 0));
+        let is_even = |ct: &RadixCiphertext| {
+            BooleanBlock::new_unchecked(self.key.apply_lookup_table(&ct.blocks[0], &is_even_lut))
+        };
+
+        // Each iteration where a and b are both non-zero decreases the sum of their bit lengths
+        // by at least one, once one of them is 0 the other one is the (odd part of the) gcd and
+        // no longer changes.
+        for _ in 0..2 * num_bits {
+            let ((a_is_even, b_is_even), ((a_minus_b, a_is_lower), b_minus_a)) = rayon::join(
+                || rayon::join(|| is_even(&a), || is_even(&b)),
+                || {
+                    rayon::join(
+                        || self.unchecked_unsigned_overflowing_sub_parallelized(&a, &b),
+                        || self.sub_parallelized(&b, &a),
+                    )
+                },
+            );
+
+            // - if a is even, a = a / 2
+            // - if b is even, b = b / 2
+            // - if both are odd, the greater one is replaced by half their difference
+            let (both_are_odd, (shift_a, shift_b)) = rayon::join(
+                || {
+                    let a_is_odd = self.boolean_bitnot(&a_is_even);
+                    let b_is_odd = self.boolean_bitnot(&b_is_even);
+                    self.boolean_bitand(&a_is_odd, &b_is_odd)
+                },
+                || {
+                    rayon::join(
+                        || {
+                            let b_is_odd = self.boolean_bitnot(&b_is_even);
+                            let a_is_greater_or_equal = self.boolean_bitnot(&a_is_lower);
+                            let tmp = self.boolean_bitand(&b_is_odd, &a_is_greater_or_equal);
+                            self.boolean_bitor(&a_is_even, &tmp)
+                        },
+                        || {
+                            let a_is_odd = self.boolean_bitnot(&a_is_even);
+                            let tmp = self.boolean_bitand(&a_is_odd, &a_is_lower);
+                            self.boolean_bitor(&b_is_even, &tmp)
+                        },
+                    )
+                },
+            );
+
+            let update = |value: &RadixCiphertext, difference: &RadixCiphertext, do_shift| {
+                let to_shift =
+                    self.unchecked_if_then_else_parallelized(&both_are_odd, difference, value);
+                let shifted = self.unchecked_scalar_right_shift_parallelized(&to_shift, 1u32);
+                self.unchecked_if_then_else_parallelized(do_shift, &shifted, value)
+            };
+            (a, b) = rayon::join(
+                || update(&a, &a_minus_b, &shift_a),
+                || update(&b, &b_minus_a, &shift_b),
+            );
+        }
+
+        // One of a or b is 0
+        let odd_gcd = self.unchecked_bitor_parallelized(&a, &b);
+        self.unchecked_left_shift_parallelized(&odd_gcd, &common_twos)
+    }
+
+    /// Computes homomorphically the greatest common divisor of two ciphertexts
+    ///
+    /// For signed ciphertexts, the gcd of the absolute values is returned.
+    ///
+    /// This function, like all "unchecked" operations, expects the input ciphertexts to have
+    /// empty carries.
+    pub fn unchecked_gcd_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (a, b) = rayon::join(
+            || self.unchecked_unsigned_abs(lhs),
+            || self.unchecked_unsigned_abs(rhs),
+        );
+        let gcd = self.unsigned_unchecked_gcd_parallelized(&a, &b);
+        T::from_blocks(gcd.into_blocks())
+    }
+
+    /// Computes homomorphically the greatest common divisor of two ciphertexts
+    ///
+    /// The number of operations done only depends on the number of blocks of the inputs.
+    ///
+    /// # Notes
+    ///
+    /// - gcd(a, 0) = a, and gcd(0, 0) = 0
+    /// - For signed ciphertexts, the gcd of the absolute values is returned, which wraps around to
+    ///   the minimum value when it is `2^(num_bits - 1)` (i.e. when the inputs are the minimum
+    ///   value and either 0 or the minimum value)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg1 = 84u64;
+    /// let msg2 = 36u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.gcd_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 12);
+    /// ```
+    pub fn gcd_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_gcd_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the least common multiple of two ciphertexts
+    ///
+    /// For signed ciphertexts, the lcm of the absolute values is returned.
+    ///
+    /// This function, like all "unchecked" operations, expects the input ciphertexts to have
+    /// empty carries.
+    pub fn unchecked_lcm_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (a, b) = rayon::join(
+            || self.unchecked_unsigned_abs(lhs),
+            || self.unchecked_unsigned_abs(rhs),
+        );
+        let gcd = self.unsigned_unchecked_gcd_parallelized(&a, &b);
+        // When both inputs are 0, the division by 0 returns the max value, and the product is 0
+        let quotient = self.unchecked_div_parallelized(&a, &gcd);
+        let lcm = self.mul_parallelized(&quotient, &b);
+        T::from_blocks(lcm.into_blocks())
+    }
+
+    /// Computes homomorphically the least common multiple of two ciphertexts
+    ///
+    /// The number of operations done only depends on the number of blocks of the inputs.
+    ///
+    /// # Notes
+    ///
+    /// - lcm(a, 0) = 0
+    /// - The result wraps around when the lcm is not representable on the number of bits of the
+    ///   inputs
+    /// - For signed ciphertexts, the lcm of the absolute values is returned
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg1 = 12u64;
+    /// let msg2 = 18u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.lcm_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 36);
+    /// ```
+    pub fn lcm_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_lcm_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the gcd `g` of two ciphertexts `a` and `b`, as well as Bézout
+    /// coefficients `x` and `y` such that `a * x + b * y = g`
+    ///
+    /// Returns `(g, x, y)`
+    ///
+    /// This function, like all "unchecked" operations, expects the input ciphertexts to have
+    /// empty carries.
+    pub fn unchecked_extended_gcd_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> (
+        SignedRadixCiphertext,
+        SignedRadixCiphertext,
+        SignedRadixCiphertext,
+    ) {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "lhs and rhs must have same length"
+        );
+        let num_blocks = lhs.blocks.len();
+        let num_bits = self.key.message_modulus.0.ilog2() as usize * num_blocks;
+
+        // Extended Euclidean algorithm on the absolute values, the Bézout coefficients are
+        // computed modulo 2^num_bits, as the final ones are smaller than max(|a|, |b|) / 2,
+        // they are correct once interpreted as signed values.
+        let (mut r0, mut r1) = rayon::join(
+            || self.unchecked_unsigned_abs(lhs),
+            || self.unchecked_unsigned_abs(rhs),
+        );
+        let mut s0: RadixCiphertext = self.create_trivial_radix(1u64, num_blocks);
+        let mut s1: RadixCiphertext = self.create_trivial_zero_radix(num_blocks);
+        let mut t0: RadixCiphertext = self.create_trivial_zero_radix(num_blocks);
+        let mut t1: RadixCiphertext = self.create_trivial_radix(1u64, num_blocks);
+
+        // By Lamé's theorem, the number of division steps is at most log_phi(2^num_bits) + 1,
+        // plus one step to swap the inputs if r0 < r1
+        let golden_ratio = (1.0 + 5f64.sqrt()) / 2.0;
+        let max_steps = (num_bits as f64 / golden_ratio.log2()).ceil() as usize + 2;
+        for _ in 0..max_steps {
+            // Once r1 is 0, r0 is the gcd and nothing must change anymore
+            let (is_active, (quotient, remainder)) = rayon::join(
+                || self.unchecked_scalar_ne_parallelized(&r1, 0u64),
+                || self.unchecked_div_rem_parallelized(&r0, &r1),
+            );
+
+            let (new_s1, new_t1) = rayon::join(
+                || self.sub_parallelized(&s0, &self.mul_parallelized(&quotient, &s1)),
+                || self.sub_parallelized(&t0, &self.mul_parallelized(&quotient, &t1)),
+            );
+
+            let select = |new: &RadixCiphertext, old: &RadixCiphertext| {
+                self.unchecked_if_then_else_parallelized(&is_active, new, old)
+            };
+            let ((new_r0, new_r1), ((new_s0, new_s1), (new_t0, new_t1))) = rayon::join(
+                || rayon::join(|| select(&r1, &r0), || select(&remainder, &r1)),
+                || {
+                    rayon::join(
+                        || rayon::join(|| select(&s1, &s0), || select(&new_s1, &s1)),
+                        || rayon::join(|| select(&t1, &t0), || select(&new_t1, &t1)),
+                    )
+                },
+            );
+            (r0, r1, s0, s1, t0, t1) = (new_r0, new_r1, new_s0, new_s1, new_t0, new_t1);
+        }
+
+        // |a| * s0 + |b| * t0 = gcd, so the signs of the inputs are moved to the coefficients
+        let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+        let apply_sign = |coefficient: RadixCiphertext, input: &SignedRadixCiphertext| {
+            let coefficient = SignedRadixCiphertext::from_blocks(coefficient.into_blocks());
+            let negated = self.neg_parallelized(&coefficient);
+            self.unchecked_programmable_if_then_else_parallelized(
+                input.blocks().last().unwrap(),
+                &negated,
+                &coefficient,
+                |sign_block| (sign_block >> sign_bit_pos) == 1,
+                true,
+            )
+        };
+        let (x, y) = rayon::join(|| apply_sign(s0, lhs), || apply_sign(t0, rhs));
+
+        (SignedRadixCiphertext::from_blocks(r0.into_blocks()), x, y)
+    }
+
+    /// Computes homomorphically the gcd `g` of two ciphertexts `a` and `b`, as well as Bézout
+    /// coefficients `x` and `y` such that `a * x + b * y = g`
+    ///
+    /// Returns `(g, x, y)`, `g` is non-negative and the coefficients are the ones computed by
+    /// the extended Euclidean algorithm.
+    ///
+    /// The number of operations done only depends on the number of blocks of the inputs.
+    ///
+    /// # Notes
+    ///
+    /// - extended_gcd(0, 0) = (0, 1, 0)
+    /// - `g` wraps around to the minimum value when it is `2^(num_bits - 1)`, the equality still
+    ///   holds modulo `2^num_bits`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg1 = 30i8;
+    /// let msg2 = -12i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let (g, x, y) = sks.extended_gcd_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let g: i8 = cks.decrypt_signed(&g);
+    /// let x: i8 = cks.decrypt_signed(&x);
+    /// let y: i8 = cks.decrypt_signed(&y);
+    /// assert_eq!(g, 6);
+    /// assert_eq!(msg1 * x + msg2 * y, g);
+    /// ```
+    pub fn extended_gcd_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> (
+        SignedRadixCiphertext,
+        SignedRadixCiphertext,
+        SignedRadixCiphertext,
+    ) {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_extended_gcd_parallelized(lhs, rhs)
+    }
+}
@@ -3,9 +3,11 @@ mod add;
 mod bit_extractor;
 mod bitwise_op;
 mod block_shift;
+mod boolean_gates;
 pub(crate) mod cmux;
 mod comparison;
 mod div_mod;
+mod gcd;
 mod modulus_switch_compression;
 mod mul;
 mod neg;
@@ -21,6 +23,7 @@ mod scalar_sub;
 mod shift;
 pub(crate) mod sub;
 mod sum;
+mod table_lookup;
 
 mod count_zeros_ones;
 pub(crate) mod ilog2;
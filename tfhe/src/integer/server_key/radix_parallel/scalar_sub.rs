@@ -266,11 +266,13 @@ impl ServerKey {
             );
             // And here, it's because shortint sub_assign adds a correcting term,
             // which we do not want here
+            let estimated_noise = lhs_b.estimated_noise() + borrow.estimated_noise();
             crate::core_crypto::algorithms::lwe_ciphertext_sub_assign(&mut lhs_b.ct, &borrow.ct);
             lhs_b.set_noise_level(
                 lhs_b.noise_level() + borrow.noise_level(),
                 self.key.max_noise_level,
             );
+            lhs_b.set_estimated_noise(estimated_noise);
 
             borrow.clone_from(lhs_b);
 
@@ -393,6 +395,7 @@ impl ServerKey {
                     // we would remove one from the block, which would be absorbed by the 1 we just
                     // added
                     for block in chunk_of_two.iter_mut() {
+                        let estimated_noise = block.estimated_noise() + simulator.estimated_noise();
                         crate::core_crypto::algorithms::lwe_ciphertext_sub_assign(
                             &mut block.ct,
                             &simulator.ct,
@@ -401,6 +404,7 @@ impl ServerKey {
                             block.noise_level() + simulator.noise_level(),
                             self.key.max_noise_level,
                         );
+                        block.set_estimated_noise(estimated_noise);
                         self.key.unchecked_scalar_add_assign(block, 1);
                     }
                 });
@@ -426,6 +430,7 @@ impl ServerKey {
                     .for_each(|(i, block)| {
                         let grouping_index = i / (grouping_size * 2);
                         let borrow = &resolved_borrows[grouping_index];
+                        let estimated_noise = block.estimated_noise() + borrow.estimated_noise();
                         crate::core_crypto::algorithms::lwe_ciphertext_sub_assign(
                             &mut block.ct,
                             &borrow.ct,
@@ -434,6 +439,7 @@ impl ServerKey {
                             block.noise_level() + borrow.noise_level(),
                             self.key.max_noise_level,
                         );
+                        block.set_estimated_noise(estimated_noise);
 
                         let lut = if i % 2 == 0 {
                             &extract_message_low_block_mut
@@ -419,6 +419,7 @@ impl ServerKey {
                     // '(-0 + 1) ==  +1' we add one, meaning that if the block receives a borrow,
                     // we would remove one from the block, which would be absorbed by the 1 we just
                     // added
+                    let estimated_noise = block.estimated_noise() + simulator.estimated_noise();
                     crate::core_crypto::algorithms::lwe_ciphertext_sub_assign(
                         &mut block.ct,
                         &simulator.ct,
@@ -427,6 +428,7 @@ impl ServerKey {
                         block.noise_level() + simulator.noise_level(),
                         self.key.max_noise_level,
                     );
+                    block.set_estimated_noise(estimated_noise);
                     self.key.unchecked_scalar_add_assign(block, 1);
                 });
 
@@ -451,6 +453,7 @@ impl ServerKey {
                 .for_each(|(i, block)| {
                     let grouping_index = i / grouping_size;
                     let borrow = &resolved_borrows[grouping_index];
+                    let estimated_noise = block.estimated_noise() + borrow.estimated_noise();
                     crate::core_crypto::algorithms::lwe_ciphertext_sub_assign(
                         &mut block.ct,
                         &borrow.ct,
@@ -459,6 +462,7 @@ impl ServerKey {
                         block.noise_level() + borrow.noise_level(),
                         self.key.max_noise_level,
                     );
+                    block.set_estimated_noise(estimated_noise);
 
                     self.key
                         .apply_lookup_table_assign(block, &message_extract_lut)
@@ -640,6 +644,7 @@ impl ServerKey {
             // the correcting term to be used
             // -> This is ok as the value returned by unchecked_sub is in range
             // 1..(message_mod * 2)
+            let estimated_noise = lhs_block.estimated_noise() + borrow.estimated_noise();
             crate::core_crypto::algorithms::lwe_ciphertext_sub_assign(
                 &mut lhs_block.ct,
                 &borrow.ct,
@@ -648,6 +653,7 @@ impl ServerKey {
                 lhs_block.noise_level() + borrow.noise_level(),
                 self.key.max_noise_level,
             );
+            lhs_block.set_estimated_noise(estimated_noise);
             let (msg, new_borrow) = rayon::join(
                 || self.key.message_extract(lhs_block),
                 || self.key.apply_lookup_table(lhs_block, &compute_borrow_lut),
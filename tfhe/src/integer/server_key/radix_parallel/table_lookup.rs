@@ -0,0 +1,712 @@
+use crate::core_crypto::fft_impl::fft64::crypto::wop_pbs::{
+    vertical_packing, vertical_packing_scratch,
+};
+use crate::core_crypto::prelude::{
+    keyswitch_lwe_ciphertext, ComputationBuffers, Fft, FourierGgswCiphertext,
+    FourierGgswCiphertextList, LweCiphertext, PolynomialCount, PolynomialList,
+};
+use crate::integer::ciphertext::IntegerCiphertext;
+use crate::integer::circuit_bootstrapping::CircuitBootstrappingKey;
+use crate::integer::{RadixCiphertext, ServerKey};
+use crate::shortint::atomic_pattern::AtomicPattern;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::encoding::{compute_delta, PaddingBit};
+use crate::shortint::server_key::StandardServerKeyView;
+use crate::shortint::{Ciphertext, PBSOrder};
+use aligned_vec::avec;
+use rayon::prelude::*;
+use tfhe_fft::c64;
+
+/// Maximum number of entries of a table evaluated by
+/// [`ServerKey::unchecked_table_lookup_parallelized`]
+const MAX_TABLE_LOOKUP_ENTRY_COUNT: usize = 1 << 16;
+
+impl ServerKey {
+    /// Estimated number of PBS needed by [`Self::many_lut_tree_table_lookup`]
+    fn many_lut_tree_table_lookup_pbs_count(
+        &self,
+        num_input_blocks: usize,
+        num_lookup_blocks: usize,
+        num_output_blocks: usize,
+    ) -> usize {
+        let message_modulus = self.message_modulus().0 as usize;
+        let carry_modulus = self.carry_modulus().0 as usize;
+        let sum_size = self.max_sum_size(Degree::new(self.message_modulus().0 - 1));
+
+        // First level: all the functions are evaluated on the least significant block
+        let mut count = (message_modulus.pow(num_lookup_blocks as u32 - 1) * num_output_blocks)
+            .div_ceil(carry_modulus);
+
+        // Next levels: one bivariate PBS per candidate block, followed by the cleaning of the sums
+        for level in 1..num_lookup_blocks {
+            let num_groups = message_modulus.pow((num_lookup_blocks - level - 1) as u32);
+            count += num_groups
+                * num_output_blocks
+                * (message_modulus + (message_modulus - 1).div_ceil((sum_size - 1).max(1)));
+        }
+
+        // Zeroing the result if the blocks not taking part in the lookup are not all zeros
+        if num_input_blocks > num_lookup_blocks {
+            count += num_input_blocks - num_lookup_blocks + num_output_blocks;
+        }
+
+        count
+    }
+
+    /// Estimated number of PBS needed by [`Self::one_hot_table_lookup`]
+    fn one_hot_table_lookup_pbs_count(
+        &self,
+        num_input_blocks: usize,
+        num_non_zero_entries: usize,
+        num_output_blocks: usize,
+    ) -> usize {
+        let max_num_many_luts = (self.message_modulus().0 * self.carry_modulus().0) as usize / 2;
+        let boolean_sum_size = self.max_sum_size(Degree::new(1));
+        let chunk_size = self.key.max_noise_level.get() as usize;
+        let num_packed_output_blocks = num_output_blocks.div_ceil(2);
+
+        let per_entry_count = (num_input_blocks - 1).div_ceil((boolean_sum_size - 1).max(1))
+            + num_packed_output_blocks.div_ceil(max_num_many_luts);
+
+        num_input_blocks
+            + num_non_zero_entries * per_entry_count
+            + (num_non_zero_entries * num_packed_output_blocks).div_ceil(chunk_size)
+            + 2 * num_packed_output_blocks
+    }
+
+    /// Estimated number of PBS needed by [`Self::vertical_packing_table_lookup`]
+    fn vertical_packing_table_lookup_pbs_count(
+        &self,
+        cbs_key: &CircuitBootstrappingKey,
+        std_sks: StandardServerKeyView,
+        num_input_blocks: usize,
+        num_lookup_blocks: usize,
+        num_output_blocks: usize,
+    ) -> usize {
+        let num_bits_in_message = self.message_modulus().0.ilog2() as usize;
+        let max_num_many_luts = self.carry_modulus().0 as usize;
+        let cbs_parameters = cbs_key.parameters();
+        let server_pbs_level = std_sks
+            .atomic_pattern
+            .bootstrapping_key
+            .decomposition_level_count();
+
+        // The blind rotation of a circuit bootstrapping costs as many PBS as its bootstrapping key
+        // has more levels than the one of the server key, and the packing keyswitches building
+        // the rows of each level of the output GGSW ciphertext cost about one more PBS
+        let per_bit_count =
+            cbs_parameters.pbs_level.0.div_ceil(server_pbs_level.0) + cbs_parameters.cbs_level.0;
+
+        // Bit extraction, circuit bootstrapping of each bit and cleaning of the outputs of the
+        // vertical packings
+        let mut count = num_lookup_blocks * num_bits_in_message.div_ceil(max_num_many_luts)
+            + num_lookup_blocks * num_bits_in_message * per_bit_count
+            + num_output_blocks;
+
+        // Zeroing the result if the blocks not taking part in the lookup are not all zeros
+        if num_input_blocks > num_lookup_blocks {
+            count += num_input_blocks - num_lookup_blocks + num_output_blocks;
+        }
+
+        count
+    }
+
+    /// Sums blocks of which at most one is non-zero, and cleans the result
+    fn sum_one_hot_blocks(&self, mut blocks: Vec<Ciphertext>) -> Ciphertext {
+        let sum_size = self.max_sum_size(Degree::new(self.message_modulus().0 - 1));
+
+        while blocks.len() > 1 {
+            blocks = blocks
+                .par_chunks(sum_size)
+                .map(|chunk| {
+                    let mut sum = chunk[0].clone();
+                    if chunk.len() > 1 {
+                        for block in &chunk[1..] {
+                            self.key.unchecked_add_assign(&mut sum, block);
+                        }
+                        self.key.message_extract_assign(&mut sum);
+                    }
+                    sum
+                })
+                .collect();
+        }
+
+        blocks.pop().expect("at least one block was expected")
+    }
+
+    /// Looks up `table` using the `lookup_blocks` as index, `table` must have one entry per
+    /// value that the `lookup_blocks` can take.
+    ///
+    /// The first level evaluates all the possible outputs for the least significant block with
+    /// many-LUT PBS, each next level then selects one out of `message_modulus` candidates for
+    /// each output block using the next block of the index.
+    fn many_lut_tree_table_lookup(
+        &self,
+        lookup_blocks: &[Ciphertext],
+        table: &[u64],
+        num_output_blocks: usize,
+    ) -> Vec<RadixCiphertext> {
+        let message_modulus = self.message_modulus().0;
+        let num_bits_in_message = message_modulus.ilog2();
+        let num_groups = table.len() / message_modulus as usize;
+
+        let functions = (0..num_groups)
+            .flat_map(|group_index| {
+                (0..num_output_blocks).map(move |block_index| {
+                    move |x: u64| {
+                        let entry = table[group_index * message_modulus as usize + x as usize];
+                        entry
+                            .checked_shr(block_index as u32 * num_bits_in_message)
+                            .unwrap_or(0)
+                            % message_modulus
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // As the input block has empty carries, one function can be evaluated per carry value
+        let max_num_many_luts = self.carry_modulus().0 as usize;
+
+        let first_level_blocks = functions
+            .par_chunks(max_num_many_luts)
+            .flat_map(|chunk| {
+                let fns = chunk
+                    .iter()
+                    .map(|func| func as &dyn Fn(u64) -> u64)
+                    .collect::<Vec<_>>();
+                let luts = self.key.generate_many_lookup_table(&fns);
+                self.key.apply_many_lookup_table(&lookup_blocks[0], &luts)
+            })
+            .collect::<Vec<_>>();
+
+        let mut candidates = first_level_blocks
+            .chunks_exact(num_output_blocks)
+            .map(|blocks| RadixCiphertext::from_blocks(blocks.to_vec()))
+            .collect::<Vec<_>>();
+
+        let select_luts = (0..message_modulus)
+            .map(|selected_value| {
+                self.key.generate_lookup_table_bivariate(|block, selector| {
+                    if selector == selected_value {
+                        block
+                    } else {
+                        0
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for selector in &lookup_blocks[1..] {
+            candidates = candidates
+                .par_chunks_exact(message_modulus as usize)
+                .map(|group| {
+                    let blocks = (0..num_output_blocks)
+                        .into_par_iter()
+                        .map(|block_index| {
+                            let selected_blocks = group
+                                .par_iter()
+                                .zip(select_luts.par_iter())
+                                .map(|(candidate, lut)| {
+                                    self.key.unchecked_apply_lookup_table_bivariate(
+                                        &candidate.blocks[block_index],
+                                        selector,
+                                        lut,
+                                    )
+                                })
+                                .collect::<Vec<_>>();
+                            self.sum_one_hot_blocks(selected_blocks)
+                        })
+                        .collect::<Vec<_>>();
+                    RadixCiphertext::from_blocks(blocks)
+                })
+                .collect();
+        }
+
+        candidates
+    }
+
+    /// Looks up `table` using the `lookup_blocks` as index, `table` must have one entry per
+    /// value that the `lookup_blocks` can take.
+    ///
+    /// Each bit of the index is extracted with a many-LUT PBS and circuit bootstrapped into a GGSW
+    /// ciphertext, a vertical packing then selects the entry of the table for each output block.
+    fn vertical_packing_table_lookup(
+        &self,
+        cbs_key: &CircuitBootstrappingKey,
+        std_sks: StandardServerKeyView,
+        lookup_blocks: &[Ciphertext],
+        table: &[u64],
+        num_output_blocks: usize,
+    ) -> RadixCiphertext {
+        let message_modulus = self.message_modulus().0;
+        let num_bits_in_message = message_modulus.ilog2();
+
+        let bit_functions = (0..num_bits_in_message)
+            .map(|bit_index| move |x: u64| (x >> bit_index) & 1)
+            .collect::<Vec<_>>();
+
+        // As the input blocks have empty carries, one function can be evaluated per carry value
+        let max_num_many_luts = self.carry_modulus().0 as usize;
+
+        let bit_luts = bit_functions
+            .chunks(max_num_many_luts)
+            .map(|chunk| {
+                let fns = chunk
+                    .iter()
+                    .map(|func| func as &dyn Fn(u64) -> u64)
+                    .collect::<Vec<_>>();
+                self.key.generate_many_lookup_table(&fns)
+            })
+            .collect::<Vec<_>>();
+
+        // Bits of the index, from the least significant one
+        let bits = lookup_blocks
+            .par_iter()
+            .flat_map(|block| {
+                bit_luts
+                    .par_iter()
+                    .flat_map(|luts| self.key.apply_many_lookup_table(block, luts))
+            })
+            .collect::<Vec<_>>();
+
+        let cbs_parameters = cbs_key.parameters();
+        let glwe_size = cbs_key.key.glwe_size();
+        let polynomial_size = cbs_key.key.polynomial_size();
+        let ggsw_size = polynomial_size.to_fourier_polynomial_size().0
+            * glwe_size.0
+            * glwe_size.0
+            * cbs_parameters.cbs_level.0;
+
+        let mut ggsw_list_data = avec![c64::default(); bits.len() * ggsw_size].into_boxed_slice();
+
+        // The vertical packing expects the GGSW ciphertexts of the most significant bits first
+        ggsw_list_data
+            .par_chunks_exact_mut(ggsw_size)
+            .zip(bits.par_iter().rev())
+            .for_each(|(ggsw_data, bit)| {
+                let mut ggsw = FourierGgswCiphertext::from_container(
+                    ggsw_data,
+                    glwe_size,
+                    polynomial_size,
+                    cbs_parameters.cbs_base_log,
+                    cbs_parameters.cbs_level,
+                );
+                cbs_key
+                    .key
+                    .unchecked_circuit_bootstrap_into(bit, std_sks, &mut ggsw);
+            });
+
+        let ggsw_list = FourierGgswCiphertextList::new(
+            &*ggsw_list_data,
+            bits.len(),
+            glwe_size,
+            polynomial_size,
+            cbs_parameters.cbs_base_log,
+            cbs_parameters.cbs_level,
+        );
+
+        let ciphertext_modulus = std_sks.ciphertext_modulus;
+        let delta = compute_delta(
+            ciphertext_modulus,
+            self.message_modulus(),
+            self.carry_modulus(),
+            PaddingBit::Yes,
+        );
+
+        // Entry i of the table is the coefficient i % N of the polynomial i / N
+        let lut_polynomial_count = table.len().div_ceil(polynomial_size.0);
+
+        let blocks = (0..num_output_blocks)
+            .into_par_iter()
+            .map(|block_index| {
+                let mut lut = vec![0u64; lut_polynomial_count * polynomial_size.0];
+                for (lut_value, entry) in lut.iter_mut().zip(table.iter()) {
+                    let block_value = entry
+                        .checked_shr(block_index as u32 * num_bits_in_message)
+                        .unwrap_or(0)
+                        % message_modulus;
+                    *lut_value = block_value * delta;
+                }
+
+                // The output of the vertical packing is under the GLWE secret key seen as an LWE
+                // secret key, i.e. the key of the inputs of the keyswitch
+                let mut vertical_packing_output = LweCiphertext::new(
+                    0u64,
+                    glwe_size
+                        .to_glwe_dimension()
+                        .to_equivalent_lwe_dimension(polynomial_size)
+                        .to_lwe_size(),
+                    ciphertext_modulus,
+                );
+
+                let fft = Fft::new(polynomial_size);
+                let fft = fft.as_view();
+
+                let mut buffers = ComputationBuffers::new();
+                buffers.resize(
+                    vertical_packing_scratch::<u64>(
+                        glwe_size,
+                        polynomial_size,
+                        PolynomialCount(lut_polynomial_count),
+                        ggsw_list.count(),
+                        fft,
+                    )
+                    .unwrap()
+                    .unaligned_bytes_required(),
+                );
+
+                vertical_packing(
+                    PolynomialList::from_container(lut.as_slice(), polynomial_size),
+                    vertical_packing_output.as_mut_view(),
+                    ggsw_list.as_view(),
+                    fft,
+                    buffers.stack(),
+                );
+
+                let lwe = match std_sks.atomic_pattern.pbs_order {
+                    PBSOrder::KeyswitchBootstrap => vertical_packing_output,
+                    PBSOrder::BootstrapKeyswitch => {
+                        let mut after_ks_ct = LweCiphertext::new(
+                            0u64,
+                            std_sks.atomic_pattern.key_switching_key.output_lwe_size(),
+                            ciphertext_modulus,
+                        );
+                        keyswitch_lwe_ciphertext(
+                            &std_sks.atomic_pattern.key_switching_key,
+                            &vertical_packing_output,
+                            &mut after_ks_ct,
+                        );
+                        after_ks_ct
+                    }
+                };
+
+                // The circuit bootstrapping parameters are designed so that the output of a
+                // vertical packing has at most the noise that the server key accepts as input of a
+                // PBS, a PBS brings it back to the nominal noise level
+                let mut block = Ciphertext::new(
+                    lwe,
+                    Degree::new(message_modulus - 1),
+                    NoiseLevel::NOMINAL * self.key.max_noise_level.get(),
+                    self.message_modulus(),
+                    self.carry_modulus(),
+                    self.key.atomic_pattern.kind(),
+                );
+                self.key.message_extract_assign(&mut block);
+                block
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from_blocks(blocks)
+    }
+
+    /// Looks up `table` by computing an encrypted equality selector for each non-zero entry
+    fn one_hot_table_lookup(
+        &self,
+        ct: &RadixCiphertext,
+        table: &[u64],
+        num_output_blocks: usize,
+    ) -> RadixCiphertext {
+        let non_zero_entries = table
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, output)| *output != 0)
+            .map(|(input, output)| (input as u64, output))
+            .collect::<Vec<_>>();
+
+        let selectors = self
+            .compute_equality_selectors(ct, non_zero_entries.par_iter().map(|(input, _)| *input));
+
+        let possible_results = self.create_possible_results::<RadixCiphertext, _, _>(
+            num_output_blocks,
+            selectors
+                .into_par_iter()
+                .zip(non_zero_entries.par_iter().map(|(_, output)| *output)),
+        );
+
+        let result = self.aggregate_one_hot_vector(possible_results);
+        self.cast_to_unsigned(result, num_output_blocks)
+    }
+
+    /// Evaluates the clear look-up table `table` on the encrypted index `ct`
+    ///
+    /// The result encrypts `table[ct]`, or 0 if `ct` is not a valid index of the table. It has
+    /// as many blocks as required to represent the biggest value of the table.
+    ///
+    /// The table can have at most `2^16` entries. Depending on the size of the table, on its
+    /// number of non-zero entries and on the parameters, the lookup is either done with a tree of
+    /// many-LUT PBS (one level per block of the index) or by computing an encrypted equality
+    /// selector for each non-zero entry, the method requiring the fewest PBS is used.
+    ///
+    /// A circuit bootstrapping followed by a vertical packing needs a dedicated key, see
+    /// [`Self::unchecked_table_lookup_with_circuit_bootstrapping_parallelized`].
+    ///
+    /// This function, like all "unchecked" operations, expects the input ciphertext to have
+    /// empty carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table is empty or has more than `2^16` entries, or if the parameters do not
+    /// have at least as much carry space as message space.
+    pub fn unchecked_table_lookup_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        table: &[u64],
+    ) -> RadixCiphertext {
+        self.unchecked_table_lookup_impl(ct, table, None)
+    }
+
+    /// Evaluates the clear look-up table `table` on the encrypted index `ct`
+    ///
+    /// Same as [`Self::unchecked_table_lookup_parallelized`], with a circuit bootstrapping of the
+    /// bits of the index followed by a vertical packing as an additional candidate method, which
+    /// is used when it requires fewer PBS, typically for large tables.
+    ///
+    /// The `cbs_key` must have been generated with [`CircuitBootstrappingParameters`] designed
+    /// for the parameters of the server key, see
+    /// [`CircuitBootstrappingKey::new`](crate::integer::circuit_bootstrapping::CircuitBootstrappingKey::new).
+    ///
+    /// [`CircuitBootstrappingParameters`]: crate::shortint::parameters::CircuitBootstrappingParameters
+    ///
+    /// This function, like all "unchecked" operations, expects the input ciphertext to have
+    /// empty carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`Self::unchecked_table_lookup_parallelized`], if the server
+    /// key does not use the standard atomic pattern or if the message and carry moduli of the
+    /// `cbs_key` are not the ones of the server key.
+    pub fn unchecked_table_lookup_with_circuit_bootstrapping_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        table: &[u64],
+        cbs_key: &CircuitBootstrappingKey,
+    ) -> RadixCiphertext {
+        let std_sks: StandardServerKeyView = self
+            .key
+            .as_view()
+            .try_into()
+            .expect("The circuit bootstrapping requires the standard atomic pattern");
+
+        let cbs_parameters = cbs_key.parameters();
+        assert_eq!(
+            cbs_parameters.message_modulus,
+            self.message_modulus(),
+            "Mismatched MessageModulus between CircuitBootstrappingKey {:?} and ServerKey {:?}",
+            cbs_parameters.message_modulus,
+            self.message_modulus(),
+        );
+        assert_eq!(
+            cbs_parameters.carry_modulus,
+            self.carry_modulus(),
+            "Mismatched CarryModulus between CircuitBootstrappingKey {:?} and ServerKey {:?}",
+            cbs_parameters.carry_modulus,
+            self.carry_modulus(),
+        );
+
+        self.unchecked_table_lookup_impl(ct, table, Some((cbs_key, std_sks)))
+    }
+
+    fn unchecked_table_lookup_impl(
+        &self,
+        ct: &RadixCiphertext,
+        table: &[u64],
+        vertical_packing_keys: Option<(&CircuitBootstrappingKey, StandardServerKeyView)>,
+    ) -> RadixCiphertext {
+        assert!(
+            !table.is_empty() && table.len() <= MAX_TABLE_LOOKUP_ENTRY_COUNT,
+            "The table must have between 1 and {MAX_TABLE_LOOKUP_ENTRY_COUNT} entries, got {}",
+            table.len()
+        );
+        assert!(
+            self.carry_modulus().0 >= self.message_modulus().0,
+            "Table lookups require to have at least as much carry space as message space \
+            ({:?} vs {:?})",
+            self.carry_modulus(),
+            self.message_modulus()
+        );
+
+        let message_modulus = self.message_modulus().0 as usize;
+        let num_input_blocks = ct.blocks.len();
+
+        if num_input_blocks == 0 {
+            // The only possible index is 0
+            return self.create_trivial_radix(
+                table[0],
+                self.num_blocks_to_represent_unsigned_value(table[0]),
+            );
+        }
+
+        // Number of blocks of the index needed to address every entry of the table, entries that
+        // cannot be addressed by the input are ignored
+        let mut num_lookup_blocks = 1;
+        let mut num_addressable_entries = message_modulus;
+        while num_addressable_entries < table.len() && num_lookup_blocks < num_input_blocks {
+            num_lookup_blocks += 1;
+            num_addressable_entries *= message_modulus;
+        }
+        let table = &table[..table.len().min(num_addressable_entries)];
+
+        let max_output_value = table.iter().copied().max().unwrap_or(0);
+        let num_output_blocks = self.num_blocks_to_represent_unsigned_value(max_output_value);
+
+        if max_output_value == 0 {
+            return self.create_trivial_zero_radix(num_output_blocks);
+        }
+
+        let num_non_zero_entries = table.iter().filter(|entry| **entry != 0).count();
+
+        let tree_pbs_count = self.many_lut_tree_table_lookup_pbs_count(
+            num_input_blocks,
+            num_lookup_blocks,
+            num_output_blocks,
+        );
+        let one_hot_pbs_count = self.one_hot_table_lookup_pbs_count(
+            num_input_blocks,
+            num_non_zero_entries,
+            num_output_blocks,
+        );
+
+        let vertical_packing_pbs_count =
+            vertical_packing_keys.map_or(usize::MAX, |(cbs_key, std_sks)| {
+                self.vertical_packing_table_lookup_pbs_count(
+                    cbs_key,
+                    std_sks,
+                    num_input_blocks,
+                    num_lookup_blocks,
+                    num_output_blocks,
+                )
+            });
+
+        if one_hot_pbs_count < tree_pbs_count.min(vertical_packing_pbs_count) {
+            return self.one_hot_table_lookup(ct, table, num_output_blocks);
+        }
+
+        // Entries that are not in the table are 0
+        let mut padded_table = table.to_vec();
+        padded_table.resize(num_addressable_entries, 0);
+
+        let (lookup_blocks, other_blocks) = ct.blocks.split_at(num_lookup_blocks);
+
+        let (mut result, other_blocks_are_zero) = rayon::join(
+            || match vertical_packing_keys {
+                Some((cbs_key, std_sks)) if vertical_packing_pbs_count < tree_pbs_count => self
+                    .vertical_packing_table_lookup(
+                        cbs_key,
+                        std_sks,
+                        lookup_blocks,
+                        &padded_table,
+                        num_output_blocks,
+                    ),
+                _ => self
+                    .many_lut_tree_table_lookup(lookup_blocks, &padded_table, num_output_blocks)
+                    .pop()
+                    .expect("the tree should return a single result"),
+            },
+            || (!other_blocks.is_empty()).then(|| self.are_all_blocks_zero(other_blocks)),
+        );
+
+        if let Some(other_blocks_are_zero) = other_blocks_are_zero {
+            self.zero_out_if_condition_is_false(&mut result, &other_blocks_are_zero);
+        }
+
+        result
+    }
+
+    /// Evaluates the clear look-up table `table` on the encrypted index `ct`
+    ///
+    /// The result encrypts `table[ct]`, or 0 if `ct` is not a valid index of the table. It has
+    /// as many blocks as required to represent the biggest value of the table.
+    ///
+    /// See [`Self::unchecked_table_lookup_parallelized`] for the details of the evaluation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 3 * 2 = 6 bits of message
+    /// let num_blocks = 3;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// // A table of squares, the output needs more bits than the input
+    /// let table = (0..64u64).map(|x| x * x).collect::<Vec<_>>();
+    ///
+    /// let msg = 45u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.table_lookup_parallelized(&ct, &table);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg * msg);
+    /// ```
+    pub fn table_lookup_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        table: &[u64],
+    ) -> RadixCiphertext {
+        if ct.block_carries_are_empty() {
+            self.unchecked_table_lookup_parallelized(ct, table)
+        } else {
+            let mut clone = ct.clone();
+            self.full_propagate_parallelized(&mut clone);
+            self.unchecked_table_lookup_parallelized(&clone, table)
+        }
+    }
+
+    /// Evaluates the clear look-up table `table` on the encrypted index `ct`, a circuit
+    /// bootstrapping followed by a vertical packing is used when it requires fewer PBS
+    ///
+    /// See [`Self::unchecked_table_lookup_with_circuit_bootstrapping_parallelized`] for the
+    /// details of the evaluation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::circuit_bootstrapping::CircuitBootstrappingKey;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::{
+    ///     CIRCUIT_BOOTSTRAPPING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    /// };
+    ///
+    /// // We have 8 * 2 = 16 bits of message
+    /// let num_blocks = 8;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, num_blocks);
+    /// let cbs_key = CircuitBootstrappingKey::new(
+    ///     cks.as_ref(),
+    ///     CIRCUIT_BOOTSTRAPPING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    /// );
+    ///
+    /// let table = (0..1u64 << 16)
+    ///     .map(|x| x.reverse_bits() >> 48)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let msg = 12345u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.table_lookup_with_circuit_bootstrapping_parallelized(&ct, &table, &cbs_key);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, table[msg as usize]);
+    /// ```
+    pub fn table_lookup_with_circuit_bootstrapping_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        table: &[u64],
+        cbs_key: &CircuitBootstrappingKey,
+    ) -> RadixCiphertext {
+        if ct.block_carries_are_empty() {
+            self.unchecked_table_lookup_with_circuit_bootstrapping_parallelized(ct, table, cbs_key)
+        } else {
+            let mut clone = ct.clone();
+            self.full_propagate_parallelized(&mut clone);
+            self.unchecked_table_lookup_with_circuit_bootstrapping_parallelized(
+                &clone, table, cbs_key,
+            )
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_signed::NB_CTXT;
+use crate::integer::server_key::radix_parallel::tests_unsigned::test_gcd::gcd_u64;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::test_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(
+    integer_signed_default_extended_gcd {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        },
+        no_coverage => {
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        }
+    }
+);
+
+fn integer_signed_default_extended_gcd<P>(param: P)
+where
+    P: Into<TestParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::extended_gcd_parallelized);
+    signed_default_extended_gcd_test(param, executor);
+}
+
+pub(crate) fn signed_default_extended_gcd_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<TestParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a SignedRadixCiphertext, &'a SignedRadixCiphertext),
+        (
+            SignedRadixCiphertext,
+            SignedRadixCiphertext,
+            SignedRadixCiphertext,
+        ),
+    >,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    let value = rng.gen_range(1..modulus);
+    let edge_cases = [
+        (0, 0),
+        (0, -value),
+        (value, 0),
+        (-value, -value),
+        (-modulus, value),
+        (-modulus, -modulus + 1),
+    ];
+    let random_cases = (0..nb_tests_smaller).map(|_| {
+        (
+            rng.gen_range(-modulus..modulus),
+            rng.gen_range(-modulus..modulus),
+        )
+    });
+
+    for (clear_0, clear_1) in edge_cases
+        .into_iter()
+        .chain(random_cases.collect::<Vec<_>>())
+    {
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let (g, x, y) = executor.execute((&ctxt_0, &ctxt_1));
+        let g: i64 = cks.decrypt_signed(&g);
+        let x: i64 = cks.decrypt_signed(&x);
+        let y: i64 = cks.decrypt_signed(&y);
+
+        let expected = gcd_u64(clear_0.unsigned_abs(), clear_1.unsigned_abs()) as i64;
+        assert_eq!(
+            g, expected,
+            "Invalid gcd result for extended_gcd({clear_0}, {clear_1}), \
+            expected {expected}, got {g}"
+        );
+        assert_eq!(
+            clear_0 * x + clear_1 * y,
+            g,
+            "Invalid Bézout coefficients for extended_gcd({clear_0}, {clear_1}), got ({x}, {y})"
+        );
+    }
+}
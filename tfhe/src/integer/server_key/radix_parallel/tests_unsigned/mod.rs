@@ -7,6 +7,7 @@ pub(crate) mod test_cmux;
 pub(crate) mod test_comparison;
 mod test_count_zeros_ones;
 pub(crate) mod test_div_mod;
+pub(crate) mod test_gcd;
 pub(crate) mod test_ilog2;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
@@ -24,6 +25,7 @@ pub(crate) mod test_shift;
 pub(crate) mod test_slice;
 pub(crate) mod test_sub;
 pub(crate) mod test_sum;
+pub(crate) mod test_table_lookup;
 pub(crate) mod test_vector_comparisons;
 pub(crate) mod test_vector_find;
 
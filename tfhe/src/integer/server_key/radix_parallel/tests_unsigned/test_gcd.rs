@@ -0,0 +1,165 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::test_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(
+    integer_default_gcd {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        },
+        no_coverage => {
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        }
+    }
+);
+create_parameterized_test!(
+    integer_default_lcm {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        },
+        no_coverage => {
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        }
+    }
+);
+
+fn integer_default_gcd<P>(param: P)
+where
+    P: Into<TestParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::gcd_parallelized);
+    default_gcd_test(param, executor);
+}
+
+fn integer_default_lcm<P>(param: P)
+where
+    P: Into<TestParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::lcm_parallelized);
+    default_lcm_test(param, executor);
+}
+
+pub(crate) fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub(crate) fn default_gcd_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<TestParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    let value = rng.gen_range(1..modulus);
+    let edge_cases = [
+        (0, 0),
+        (0, value),
+        (value, 0),
+        (value, value),
+        (modulus / 2, 4),
+    ];
+    let random_cases = (0..nb_tests_smaller).map(|_| {
+        // Multiply by a common factor so that the gcd is not always 1
+        let factor = rng.gen_range(1..16);
+        (
+            (rng.gen::<u64>() % modulus) * factor % modulus,
+            (rng.gen::<u64>() % modulus) * factor % modulus,
+        )
+    });
+
+    for (clear_0, clear_1) in edge_cases
+        .into_iter()
+        .chain(random_cases.collect::<Vec<_>>())
+    {
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        let dec_res: u64 = cks.decrypt(&ct_res);
+
+        assert!(ct_res.block_carries_are_empty());
+        let expected = gcd_u64(clear_0, clear_1);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid gcd result for gcd({clear_0}, {clear_1}), expected {expected}, got {dec_res}"
+        );
+    }
+}
+
+pub(crate) fn default_lcm_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<TestParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    let value = rng.gen_range(1..modulus);
+    let edge_cases = [(0, 0), (0, value), (value, value)];
+    let random_cases = (0..nb_tests_smaller).map(|_| {
+        // Keep the values small enough for the lcm to be representable most of the time
+        let bound = 1 << (modulus.ilog2() / 2 + 1);
+        (rng.gen_range(1..bound), rng.gen_range(1..bound))
+    });
+
+    for (clear_0, clear_1) in edge_cases
+        .into_iter()
+        .chain(random_cases.collect::<Vec<_>>())
+    {
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        let dec_res: u64 = cks.decrypt(&ct_res);
+
+        assert!(ct_res.block_carries_are_empty());
+        let expected = match gcd_u64(clear_0, clear_1) {
+            0 => 0,
+            gcd => (clear_0 / gcd).wrapping_mul(clear_1) % modulus,
+        };
+        assert_eq!(
+            dec_res, expected,
+            "Invalid lcm result for lcm({clear_0}, {clear_1}), expected {expected}, got {dec_res}"
+        );
+    }
+}
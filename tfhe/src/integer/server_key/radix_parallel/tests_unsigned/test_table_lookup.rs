@@ -0,0 +1,140 @@
+use crate::integer::circuit_bootstrapping::CircuitBootstrappingKey;
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, panic_if_any_block_is_not_clean_or_trivial, unsigned_modulus,
+    CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::test_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(
+    integer_default_table_lookup {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        },
+        no_coverage => {
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        }
+    }
+);
+
+fn integer_default_table_lookup<P>(param: P)
+where
+    P: Into<TestParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::table_lookup_parallelized);
+    default_table_lookup_test(param, executor);
+}
+
+/// Forwards the table lookups to
+/// [`ServerKey::table_lookup_with_circuit_bootstrapping_parallelized`] with a
+/// [`CircuitBootstrappingKey`] generated at setup
+struct CircuitBootstrappingTableLookupExecutor {
+    cbs_parameters: CircuitBootstrappingParameters,
+    keys: Option<(Arc<ServerKey>, CircuitBootstrappingKey)>,
+}
+
+impl<'a> FunctionExecutor<(&'a RadixCiphertext, &'a [u64]), RadixCiphertext>
+    for CircuitBootstrappingTableLookupExecutor
+{
+    fn setup(&mut self, cks: &RadixClientKey, sks: Arc<ServerKey>) {
+        let cbs_key = CircuitBootstrappingKey::new(cks.as_ref(), self.cbs_parameters);
+        self.keys = Some((sks, cbs_key));
+    }
+
+    fn execute(&mut self, (ct, table): (&'a RadixCiphertext, &'a [u64])) -> RadixCiphertext {
+        let (sks, cbs_key) = self.keys.as_ref().expect("setup was not properly called");
+        sks.table_lookup_with_circuit_bootstrapping_parallelized(ct, table, cbs_key)
+    }
+}
+
+#[test]
+fn integer_table_lookup_with_circuit_bootstrapping() {
+    let executor = CircuitBootstrappingTableLookupExecutor {
+        cbs_parameters: CIRCUIT_BOOTSTRAPPING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        keys: None,
+    };
+    default_table_lookup_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, executor);
+}
+
+pub(crate) fn default_table_lookup_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<TestParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a [u64]), RadixCiphertext>,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let block_modulus = cks.parameters().message_modulus().0;
+
+    executor.setup(&cks, sks.clone());
+
+    // A dense table with outputs fitting in a single block
+    let narrow_table = (0..modulus)
+        .map(|_| rng.gen_range(0..block_modulus))
+        .collect::<Vec<_>>();
+    // A dense table with outputs wider than the input
+    let wide_table = (0..modulus)
+        .map(|_| rng.gen::<u64>() % (1 << 20))
+        .collect::<Vec<_>>();
+    // A table with a few non-zero entries
+    let mut sparse_table = vec![0u64; modulus as usize];
+    for _ in 0..3 {
+        sparse_table[rng.gen_range(0..modulus) as usize] = rng.gen::<u64>() % (1 << 20);
+    }
+    // A table smaller than the input range, bigger inputs look up 0
+    let short_table = (0..2 * block_modulus + 1)
+        .map(|_| rng.gen_range(1..block_modulus))
+        .collect::<Vec<_>>();
+    let zero_table = vec![0u64; 3];
+
+    for (table_name, table) in [
+        ("narrow", narrow_table),
+        ("wide", wide_table),
+        ("sparse", sparse_table),
+        ("short", short_table),
+        ("zero", zero_table),
+    ] {
+        let max_value = table.iter().copied().max().unwrap();
+        let expected_num_blocks = sks.num_blocks_to_represent_unsigned_value(max_value);
+
+        let edge_cases = [0, table.len() as u64 - 1, modulus - 1];
+        let random_cases = (0..nb_tests_smaller).map(|_| rng.gen_range(0..modulus));
+
+        for clear in edge_cases
+            .into_iter()
+            .chain(random_cases.collect::<Vec<_>>())
+        {
+            let ctxt = cks.encrypt(clear);
+
+            let ct_res = executor.execute((&ctxt, table.as_slice()));
+            let dec_res: u64 = cks.decrypt(&ct_res);
+
+            panic_if_any_block_is_not_clean_or_trivial(&ct_res, &cks);
+            assert_eq!(ct_res.blocks.len(), expected_num_blocks);
+
+            let expected = table.get(clear as usize).copied().unwrap_or(0);
+            assert_eq!(
+                dec_res, expected,
+                "Invalid {table_name} table lookup result for {clear}, \
+                expected {expected}, got {dec_res}"
+            );
+        }
+    }
+}
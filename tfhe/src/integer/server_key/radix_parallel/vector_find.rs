@@ -1071,7 +1071,7 @@ impl ServerKey {
     /// otherwise it will be 0.
     ///
     /// Requires ct to have empty carries
-    fn compute_equality_selectors<T, Iter, Clear>(
+    pub(crate) fn compute_equality_selectors<T, Iter, Clear>(
         &self,
         ct: &T,
         possible_input_values: Iter,
@@ -1143,7 +1143,7 @@ impl ServerKey {
     ///
     /// - Resulting radix ciphertexts have their block packed, thus they will have ceil (numb_blocks
     ///   / 2) elements
-    fn create_possible_results<T, Iter, Clear>(
+    pub(crate) fn create_possible_results<T, Iter, Clear>(
         &self,
         num_blocks: usize,
         possible_outputs: Iter,
@@ -1214,7 +1214,7 @@ impl ServerKey {
     /// The elements in the one hot vector have their block packed.
     ///
     /// The returned result has non packed blocks
-    fn aggregate_one_hot_vector<T>(&self, mut one_hot_vector: Vec<T>) -> T
+    pub(crate) fn aggregate_one_hot_vector<T>(&self, mut one_hot_vector: Vec<T>) -> T
     where
         T: IntegerRadixCiphertext,
     {
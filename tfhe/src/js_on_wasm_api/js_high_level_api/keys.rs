@@ -71,6 +71,25 @@ impl TfheClientKey {
                 .map_err(into_js_error)
         })
     }
+
+    #[cfg(feature = "key-wrapping")]
+    #[wasm_bindgen]
+    pub fn export_encrypted(&self, passphrase: &str) -> Result<Vec<u8>, JsError> {
+        catch_panic_result(|| {
+            crate::key_wrapping::WrappableKey::export_encrypted(&self.0, passphrase.as_bytes())
+                .map_err(into_js_error)
+        })
+    }
+
+    #[cfg(feature = "key-wrapping")]
+    #[wasm_bindgen]
+    pub fn import_encrypted(buffer: &[u8], passphrase: &str) -> Result<TfheClientKey, JsError> {
+        catch_panic_result(|| {
+            crate::key_wrapping::WrappableKey::import_encrypted(buffer, passphrase.as_bytes())
+                .map(Self)
+                .map_err(into_js_error)
+        })
+    }
 }
 
 // Wasm cannot generate a normal server key, only a compressed one
@@ -123,6 +142,28 @@ impl TfheCompressedServerKey {
                 .map_err(into_js_error)
         })
     }
+
+    #[cfg(feature = "key-wrapping")]
+    #[wasm_bindgen]
+    pub fn export_encrypted(&self, passphrase: &str) -> Result<Vec<u8>, JsError> {
+        catch_panic_result(|| {
+            crate::key_wrapping::WrappableKey::export_encrypted(&self.0, passphrase.as_bytes())
+                .map_err(into_js_error)
+        })
+    }
+
+    #[cfg(feature = "key-wrapping")]
+    #[wasm_bindgen]
+    pub fn import_encrypted(
+        buffer: &[u8],
+        passphrase: &str,
+    ) -> Result<TfheCompressedServerKey, JsError> {
+        catch_panic_result(|| {
+            crate::key_wrapping::WrappableKey::import_encrypted(buffer, passphrase.as_bytes())
+                .map(Self)
+                .map_err(into_js_error)
+        })
+    }
 }
 
 #[wasm_bindgen]
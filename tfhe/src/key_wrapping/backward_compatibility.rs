@@ -0,0 +1,18 @@
+use tfhe_versionable::VersionsDispatch;
+
+use super::{AeadParams, KdfParams, WrappedKey};
+
+#[derive(VersionsDispatch)]
+pub enum KdfParamsVersions {
+    V0(KdfParams),
+}
+
+#[derive(VersionsDispatch)]
+pub enum AeadParamsVersions {
+    V0(AeadParams),
+}
+
+#[derive(VersionsDispatch)]
+pub enum WrappedKeyVersions {
+    V0(WrappedKey),
+}
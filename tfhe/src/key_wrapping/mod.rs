@@ -0,0 +1,314 @@
+//! Passphrase-protected export of secret keys.
+//!
+//! Keys serialized with [`safe_serialize`](crate::safe_serialization::safe_serialize) are not
+//! protected at rest. This module defines a key-wrapping format, where the safe-serialized key is
+//! encrypted with a key derived from a passphrase:
+//! - the encryption key is derived from the passphrase and a random salt using Argon2id,
+//! - the serialized key is encrypted with XChaCha20-Poly1305, using a random nonce. The KDF
+//!   parameters and the name of the wrapped type are authenticated as associated data.
+//!
+//! The resulting [`WrappedKey`] is itself written with the safe serialization, so the format is
+//! versioned and the KDF and AEAD algorithms can be updated without breaking existing exports.
+//!
+//! Keys that can be exported this way implement the [`WrappableKey`] trait.
+
+mod backward_compatibility;
+#[cfg(test)]
+mod tests;
+
+use crate::error;
+use crate::named::Named;
+use crate::safe_serialization::{DeserializationConfig, SerializationConfig};
+use argon2::{Algorithm, Argon2, Params, Version};
+use backward_compatibility::{AeadParamsVersions, KdfParamsVersions, WrappedKeyVersions};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use rand_core::{OsRng, RngCore};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::{Unversionize, Versionize};
+use zeroize::Zeroizing;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const WRAPPING_KEY_LEN: usize = 32;
+
+/// Largest Argon2id memory cost, in KiB, accepted when wrapping or unwrapping a key (1 GiB)
+///
+/// The KDF parameters are read from the wrapped key before it can be authenticated, without
+/// those limits a crafted file could require an arbitrary amount of memory and time to import.
+pub const MAX_ARGON2ID_MEMORY_KIB: u32 = 1024 * 1024;
+/// Largest Argon2id number of iterations accepted when wrapping or unwrapping a key
+pub const MAX_ARGON2ID_ITERATIONS: u32 = 64;
+/// Largest Argon2id degree of parallelism accepted when wrapping or unwrapping a key
+pub const MAX_ARGON2ID_PARALLELISM: u32 = 64;
+
+/// Parameters of the key derivation function
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(KdfParamsVersions)]
+pub enum KdfParams {
+    Argon2id {
+        /// Memory cost, in KiB
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+        salt: [u8; SALT_LEN],
+    },
+}
+
+/// Parameters of the authenticated encryption
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(AeadParamsVersions)]
+pub enum AeadParams {
+    XChaCha20Poly1305 { nonce: [u8; NONCE_LEN] },
+}
+
+/// A key encrypted with a passphrase, see the [module documentation](self).
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(WrappedKeyVersions)]
+pub struct WrappedKey {
+    kdf: KdfParams,
+    aead: AeadParams,
+    /// Name of the wrapped type, as given by [`Named::NAME`]
+    key_name: String,
+    ciphertext: Vec<u8>,
+}
+
+impl Named for WrappedKey {
+    const NAME: &'static str = "key_wrapping::WrappedKey";
+}
+
+impl WrappedKey {
+    pub fn kdf_params(&self) -> &KdfParams {
+        &self.kdf
+    }
+
+    pub fn aead_params(&self) -> &AeadParams {
+        &self.aead
+    }
+
+    pub fn key_name(&self) -> &str {
+        &self.key_name
+    }
+
+    /// The KDF and AEAD parameters and the key name are authenticated but not encrypted
+    fn associated_data(
+        kdf: &KdfParams,
+        aead: &AeadParams,
+        key_name: &str,
+    ) -> crate::Result<Vec<u8>> {
+        bincode::serialize(&(kdf, aead, key_name))
+            .map_err(|err| error!("Failed to serialize the wrapped key parameters: {err}"))
+    }
+
+    fn derive_wrapping_key(
+        kdf: &KdfParams,
+        passphrase: &[u8],
+    ) -> crate::Result<Zeroizing<[u8; WRAPPING_KEY_LEN]>> {
+        let KdfParams::Argon2id {
+            memory_kib,
+            iterations,
+            parallelism,
+            salt,
+        } = kdf;
+
+        if *memory_kib > MAX_ARGON2ID_MEMORY_KIB
+            || *iterations > MAX_ARGON2ID_ITERATIONS
+            || *parallelism > MAX_ARGON2ID_PARALLELISM
+        {
+            return Err(error!(
+                "Argon2id parameters (memory: {memory_kib} KiB, iterations: {iterations}, \
+                parallelism: {parallelism}) are above the accepted maximums \
+                (memory: {MAX_ARGON2ID_MEMORY_KIB} KiB, iterations: {MAX_ARGON2ID_ITERATIONS}, \
+                parallelism: {MAX_ARGON2ID_PARALLELISM})"
+            ));
+        }
+
+        let params = Params::new(
+            *memory_kib,
+            *iterations,
+            *parallelism,
+            Some(WRAPPING_KEY_LEN),
+        )
+        .map_err(|err| error!("Invalid Argon2id parameters: {err}"))?;
+
+        let mut wrapping_key = Zeroizing::new([0u8; WRAPPING_KEY_LEN]);
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase, salt, wrapping_key.as_mut())
+            .map_err(|err| error!("Failed to derive the wrapping key: {err}"))?;
+
+        Ok(wrapping_key)
+    }
+
+    /// Encrypts `key` with a key derived from `passphrase`
+    pub fn new<T: WrappableKey>(
+        key: &T,
+        passphrase: &[u8],
+        config: &KeyWrappingConfig,
+    ) -> crate::Result<Self> {
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng
+            .try_fill_bytes(&mut salt)
+            .and_then(|()| OsRng.try_fill_bytes(&mut nonce))
+            .map_err(|err| error!("Failed to generate the salt and nonce: {err}"))?;
+
+        let kdf = KdfParams::Argon2id {
+            memory_kib: config.memory_kib,
+            iterations: config.iterations,
+            parallelism: config.parallelism,
+            salt,
+        };
+        let aead = AeadParams::XChaCha20Poly1305 { nonce };
+        let key_name = T::NAME.to_owned();
+
+        // The buffer is allocated with its final size, so that no copy of the serialized key is
+        // left in memory by a reallocation
+        let serialization_config = SerializationConfig::new_with_unlimited_size();
+        let plaintext_size = serialization_config
+            .serialized_size(key)
+            .ok()
+            .and_then(|size| usize::try_from(size).ok())
+            .ok_or_else(|| error!("Failed to compute the serialized size of the key"))?;
+
+        let mut plaintext = Zeroizing::new(vec![0u8; plaintext_size]);
+        serialization_config
+            .serialize_into(key, plaintext.as_mut_slice())
+            .map_err(|err| error!("Failed to serialize the key: {err}"))?;
+
+        let wrapping_key = Self::derive_wrapping_key(&kdf, passphrase)?;
+        let aad = Self::associated_data(&kdf, &aead, &key_name)?;
+
+        let ciphertext = XChaCha20Poly1305::new(wrapping_key.as_ref().into())
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: &plaintext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| error!("Failed to encrypt the key"))?;
+
+        Ok(Self {
+            kdf,
+            aead,
+            key_name,
+            ciphertext,
+        })
+    }
+
+    /// Decrypts the wrapped key using `passphrase`.
+    ///
+    /// An error is returned if the wrapped key is not of type `T`, if the passphrase is wrong or
+    /// if the wrapped key has been modified.
+    pub fn decrypt<T: WrappableKey>(&self, passphrase: &[u8]) -> crate::Result<T> {
+        let Self {
+            kdf,
+            aead,
+            key_name,
+            ciphertext,
+        } = self;
+
+        if key_name != T::NAME && !T::BACKWARD_COMPATIBILITY_ALIASES.contains(&key_name.as_str()) {
+            return Err(error!(
+                "On key import, expected a key of type {}, got {key_name}",
+                T::NAME
+            ));
+        }
+
+        let wrapping_key = Self::derive_wrapping_key(kdf, passphrase)?;
+        let aad = Self::associated_data(kdf, aead, key_name)?;
+
+        let AeadParams::XChaCha20Poly1305 { nonce } = aead;
+        let plaintext = Zeroizing::new(
+            XChaCha20Poly1305::new(wrapping_key.as_ref().into())
+                .decrypt(
+                    XNonce::from_slice(nonce),
+                    Payload {
+                        msg: ciphertext,
+                        aad: &aad,
+                    },
+                )
+                .map_err(|_| {
+                    error!("Failed to decrypt the key, wrong passphrase or corrupted data")
+                })?,
+        );
+
+        DeserializationConfig::new(plaintext.len() as u64)
+            .disable_conformance()
+            .deserialize_from(plaintext.as_slice())
+            .map_err(|err| error!("Failed to deserialize the key: {err}"))
+    }
+}
+
+/// Parameters used to wrap a key.
+///
+/// The default Argon2id parameters (19 MiB of memory, 2 iterations, 1 lane) follow the OWASP
+/// recommendations. Increasing them makes brute-forcing the passphrase more expensive, at the
+/// cost of a slower export and import.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KeyWrappingConfig {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+impl Default for KeyWrappingConfig {
+    fn default() -> Self {
+        Self {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+impl KeyWrappingConfig {
+    /// Sets the Argon2id parameters. The validity of the parameters is checked when a key is
+    /// wrapped, they must not exceed [`MAX_ARGON2ID_MEMORY_KIB`], [`MAX_ARGON2ID_ITERATIONS`] and
+    /// [`MAX_ARGON2ID_PARALLELISM`].
+    pub fn with_argon2id_params(self, memory_kib: u32, iterations: u32, parallelism: u32) -> Self {
+        Self {
+            memory_kib,
+            iterations,
+            parallelism,
+        }
+    }
+
+    /// Wraps `key` and serializes the result
+    pub fn export<T: WrappableKey>(&self, key: &T, passphrase: &[u8]) -> crate::Result<Vec<u8>> {
+        let wrapped = WrappedKey::new(key, passphrase, self)?;
+
+        let mut buffer = Vec::new();
+        SerializationConfig::new_with_unlimited_size()
+            .serialize_into(&wrapped, &mut buffer)
+            .map_err(|err| error!("Failed to serialize the wrapped key: {err}"))?;
+
+        Ok(buffer)
+    }
+}
+
+/// Secret keys that can be exported protected by a passphrase.
+pub trait WrappableKey:
+    Serialize + DeserializeOwned + Versionize + Unversionize + Named + Sized
+{
+    /// Exports the key, encrypted with a key derived from `passphrase`, using the default
+    /// [`KeyWrappingConfig`].
+    fn export_encrypted(&self, passphrase: &[u8]) -> crate::Result<Vec<u8>> {
+        KeyWrappingConfig::default().export(self, passphrase)
+    }
+
+    /// Imports a key exported with [`Self::export_encrypted`] or [`KeyWrappingConfig::export`]
+    fn import_encrypted(data: &[u8], passphrase: &[u8]) -> crate::Result<Self> {
+        let wrapped: WrappedKey = DeserializationConfig::new(data.len() as u64)
+            .disable_conformance()
+            .deserialize_from(data)
+            .map_err(|err| error!("Failed to deserialize the wrapped key: {err}"))?;
+
+        wrapped.decrypt(passphrase)
+    }
+}
+
+impl WrappableKey for crate::ClientKey {}
+impl WrappableKey for crate::CompressedServerKey {}
+impl WrappableKey for crate::integer::noise_squashing::NoiseSquashingPrivateKey {}
@@ -0,0 +1,125 @@
+use super::*;
+use crate::integer::noise_squashing::NoiseSquashingPrivateKey;
+use crate::shortint::parameters::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+use crate::{ClientKey, CompressedServerKey, ConfigBuilder};
+
+const PASSPHRASE: &[u8] = b"correct horse battery staple";
+
+/// Small Argon2id parameters to keep the tests fast
+fn config() -> KeyWrappingConfig {
+    KeyWrappingConfig::default().with_argon2id_params(64, 1, 1)
+}
+
+fn serialized<T: Serialize>(object: &T) -> Vec<u8> {
+    bincode::serialize(object).unwrap()
+}
+
+#[test]
+fn test_wrap_client_key() {
+    let cks = ClientKey::generate(ConfigBuilder::default().build());
+
+    let exported = config().export(&cks, PASSPHRASE).unwrap();
+    let imported = ClientKey::import_encrypted(&exported, PASSPHRASE).unwrap();
+    assert_eq!(serialized(&imported), serialized(&cks));
+
+    // Each export uses a new salt and nonce
+    assert_ne!(config().export(&cks, PASSPHRASE).unwrap(), exported);
+
+    assert!(ClientKey::import_encrypted(&exported, b"wrong passphrase").is_err());
+
+    // The type of the wrapped key is checked
+    assert!(CompressedServerKey::import_encrypted(&exported, PASSPHRASE).is_err());
+
+    // Any modification of the ciphertext is detected
+    let mut tampered = exported.clone();
+    *tampered.last_mut().unwrap() ^= 1;
+    assert!(ClientKey::import_encrypted(&tampered, PASSPHRASE).is_err());
+
+    assert!(ClientKey::import_encrypted(&exported[..exported.len() - 1], PASSPHRASE).is_err());
+}
+
+#[test]
+fn test_wrap_authenticates_params() {
+    let cks = ClientKey::generate(ConfigBuilder::default().build());
+
+    let wrapped = WrappedKey::new(&cks, PASSPHRASE, &config()).unwrap();
+    wrapped.decrypt::<ClientKey>(PASSPHRASE).unwrap();
+
+    let KdfParams::Argon2id {
+        memory_kib,
+        iterations,
+        parallelism,
+        salt,
+    } = wrapped.kdf_params().clone();
+
+    let downgraded = WrappedKey {
+        kdf: KdfParams::Argon2id {
+            memory_kib,
+            iterations: iterations + 1,
+            parallelism,
+            salt,
+        },
+        ..wrapped.clone()
+    };
+    assert!(downgraded.decrypt::<ClientKey>(PASSPHRASE).is_err());
+
+    let renamed = WrappedKey {
+        key_name: CompressedServerKey::NAME.to_owned(),
+        ..wrapped
+    };
+    assert!(renamed.decrypt::<CompressedServerKey>(PASSPHRASE).is_err());
+}
+
+#[test]
+fn test_wrap_noise_squashing_private_key() {
+    let key = NoiseSquashingPrivateKey::new(
+        NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    );
+
+    let exported = config().export(&key, PASSPHRASE).unwrap();
+    let imported = NoiseSquashingPrivateKey::import_encrypted(&exported, PASSPHRASE).unwrap();
+    assert_eq!(serialized(&imported), serialized(&key));
+}
+
+#[test]
+fn test_invalid_kdf_params() {
+    let cks = ClientKey::generate(ConfigBuilder::default().build());
+
+    let config = KeyWrappingConfig::default().with_argon2id_params(64, 0, 1);
+    assert!(config.export(&cks, PASSPHRASE).is_err());
+}
+
+#[test]
+fn test_kdf_params_above_maximums() {
+    let cks = ClientKey::generate(ConfigBuilder::default().build());
+
+    let config =
+        KeyWrappingConfig::default().with_argon2id_params(MAX_ARGON2ID_MEMORY_KIB + 1, 1, 1);
+    assert!(config.export(&cks, PASSPHRASE).is_err());
+
+    // A crafted file is rejected before running the KDF
+    let wrapped = WrappedKey::new(&cks, PASSPHRASE, &config()).unwrap();
+    let KdfParams::Argon2id { salt, .. } = wrapped.kdf_params().clone();
+
+    for (memory_kib, iterations, parallelism) in [
+        (u32::MAX, 1, 1),
+        (64, u32::MAX, 1),
+        (64, 1, MAX_ARGON2ID_PARALLELISM + 1),
+    ] {
+        let crafted = WrappedKey {
+            kdf: KdfParams::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+                salt,
+            },
+            ..wrapped.clone()
+        };
+
+        let mut exported = Vec::new();
+        SerializationConfig::new_with_unlimited_size()
+            .serialize_into(&crafted, &mut exported)
+            .unwrap();
+        assert!(ClientKey::import_encrypted(&exported, PASSPHRASE).is_err());
+    }
+}
@@ -113,6 +113,9 @@ pub mod shortint;
 #[cfg(feature = "pbs-stats")]
 pub use shortint::server_key::pbs_stats::*;
 
+#[cfg(feature = "noise-tracking")]
+pub use shortint::noise_tracking::{get_noise_report, reset_noise_report, NoiseReport};
+
 #[cfg(feature = "__wasm_api")]
 /// cbindgen:ignore
 mod js_on_wasm_api;
@@ -136,6 +139,14 @@ pub mod keycache;
 
 pub mod safe_serialization;
 
+#[cfg(feature = "mapped-keys")]
+/// cbindgen:ignore
+pub mod mapped_serialization;
+
+#[cfg(feature = "key-wrapping")]
+/// cbindgen:ignore
+pub mod key_wrapping;
+
 pub mod conformance;
 
 pub mod named;
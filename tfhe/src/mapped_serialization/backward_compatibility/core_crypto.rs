@@ -0,0 +1,59 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::UnsignedInteger;
+use crate::mapped_serialization::core_crypto::{
+    Fourier128LweBootstrapKeyLayout, FourierLweBootstrapKeyLayout,
+    FourierLweMultiBitBootstrapKeyLayout, LweCiphertextListLayout, LweKeyswitchKeyLayout,
+    LwePackingKeyswitchKeyLayout, SeededLweBootstrapKeyLayout, SeededLweKeyswitchKeyLayout,
+    SeededLweMultiBitBootstrapKeyLayout, SeededLwePackingKeyswitchKeyLayout,
+};
+
+#[derive(VersionsDispatch)]
+pub enum LweKeyswitchKeyLayoutVersions<Scalar: UnsignedInteger> {
+    V0(LweKeyswitchKeyLayout<Scalar>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum LweCiphertextListLayoutVersions<Scalar: UnsignedInteger> {
+    V0(LweCiphertextListLayout<Scalar>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum LwePackingKeyswitchKeyLayoutVersions<Scalar: UnsignedInteger> {
+    V0(LwePackingKeyswitchKeyLayout<Scalar>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum FourierLweBootstrapKeyLayoutVersions {
+    V0(FourierLweBootstrapKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum FourierLweMultiBitBootstrapKeyLayoutVersions {
+    V0(FourierLweMultiBitBootstrapKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum Fourier128LweBootstrapKeyLayoutVersions {
+    V0(Fourier128LweBootstrapKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SeededLweKeyswitchKeyLayoutVersions<Scalar: UnsignedInteger> {
+    V0(SeededLweKeyswitchKeyLayout<Scalar>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SeededLweBootstrapKeyLayoutVersions<Scalar: UnsignedInteger> {
+    V0(SeededLweBootstrapKeyLayout<Scalar>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SeededLweMultiBitBootstrapKeyLayoutVersions<Scalar: UnsignedInteger> {
+    V0(SeededLweMultiBitBootstrapKeyLayout<Scalar>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SeededLwePackingKeyswitchKeyLayoutVersions<Scalar: UnsignedInteger> {
+    V0(SeededLwePackingKeyswitchKeyLayout<Scalar>),
+}
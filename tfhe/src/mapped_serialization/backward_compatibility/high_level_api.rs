@@ -0,0 +1,13 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::mapped_serialization::high_level_api::{CompressedServerKeyLayout, ServerKeyLayout};
+
+#[derive(VersionsDispatch)]
+pub enum ServerKeyLayoutVersions {
+    V0(ServerKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressedServerKeyLayoutVersions {
+    V0(CompressedServerKeyLayout),
+}
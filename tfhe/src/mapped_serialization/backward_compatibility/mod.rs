@@ -0,0 +1,20 @@
+#![allow(clippy::large_enum_variant)]
+
+pub mod core_crypto;
+#[cfg(feature = "integer")]
+pub mod high_level_api;
+pub mod shortint;
+
+use tfhe_versionable::VersionsDispatch;
+
+use super::{ScalarKind, Section};
+
+#[derive(VersionsDispatch)]
+pub enum ScalarKindVersions {
+    V0(ScalarKind),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SectionVersions {
+    V0(Section),
+}
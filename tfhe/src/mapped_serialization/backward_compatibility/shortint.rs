@@ -0,0 +1,96 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::UnsignedInteger;
+use crate::mapped_serialization::shortint::{
+    AtomicPatternServerKeyLayout, CompressedAtomicPatternServerKeyLayout,
+    CompressedCompressionKeyLayout, CompressedDecompressionKeyLayout,
+    CompressedNoiseSquashingKeyLayout, CompressedServerKeyLayout,
+    CompressedShortint128BootstrappingKeyLayout, CompressionKeyLayout, DecompressionKeyLayout,
+    ModulusSwitchConfigurationLayout, NoiseSquashingKeyLayout, ServerKeyLayout,
+    Shortint128BootstrappingKeyLayout, ShortintBootstrappingKeyLayout,
+    ShortintCompressedBootstrappingKeyLayout,
+};
+
+#[derive(VersionsDispatch)]
+pub enum ModulusSwitchConfigurationLayoutVersions<InputScalar>
+where
+    InputScalar: UnsignedInteger,
+{
+    V0(ModulusSwitchConfigurationLayout<InputScalar>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum ShortintBootstrappingKeyLayoutVersions<InputScalar>
+where
+    InputScalar: UnsignedInteger,
+{
+    V0(ShortintBootstrappingKeyLayout<InputScalar>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum AtomicPatternServerKeyLayoutVersions {
+    V0(AtomicPatternServerKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum ServerKeyLayoutVersions {
+    V0(ServerKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum ShortintCompressedBootstrappingKeyLayoutVersions<InputScalar>
+where
+    InputScalar: UnsignedInteger,
+{
+    V0(ShortintCompressedBootstrappingKeyLayout<InputScalar>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressedAtomicPatternServerKeyLayoutVersions {
+    V0(CompressedAtomicPatternServerKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressedServerKeyLayoutVersions {
+    V0(CompressedServerKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressionKeyLayoutVersions {
+    V0(CompressionKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum DecompressionKeyLayoutVersions {
+    V0(DecompressionKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressedCompressionKeyLayoutVersions {
+    V0(CompressedCompressionKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressedDecompressionKeyLayoutVersions {
+    V0(CompressedDecompressionKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum Shortint128BootstrappingKeyLayoutVersions {
+    V0(Shortint128BootstrappingKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingKeyLayoutVersions {
+    V0(NoiseSquashingKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressedShortint128BootstrappingKeyLayoutVersions {
+    V0(CompressedShortint128BootstrappingKeyLayout),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressedNoiseSquashingKeyLayoutVersions {
+    V0(CompressedNoiseSquashingKeyLayout),
+}
@@ -0,0 +1,758 @@
+//! Layouts of the core_crypto entities that are stored as sections in a mapped file.
+//!
+//! Each layout holds the parameters of an entity and the location of its container(s). It can
+//! be used to view the entity in place in a mapped file, or to copy it into an owned entity.
+
+use super::backward_compatibility::core_crypto::*;
+use super::{get_section_checked, MappedScalar, Section, Sections, SectionsWriter};
+use crate::core_crypto::commons::math::random::CompressionSeed;
+use crate::core_crypto::fft_impl::fft128::crypto::bootstrap::Fourier128LweBootstrapKey;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyView;
+use crate::core_crypto::prelude::*;
+use crate::error;
+use aligned_vec::{ABox, AVec, CACHELINE_ALIGN};
+use serde::{Deserialize, Serialize};
+use tfhe_fft::c64;
+use tfhe_versionable::Versionize;
+
+fn to_aligned_box<Scalar: Copy>(data: &[Scalar]) -> ABox<[Scalar]> {
+    AVec::from_slice(CACHELINE_ALIGN, data).into_boxed_slice()
+}
+
+fn check_native_modulus<Scalar: UnsignedInteger>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> crate::Result<()> {
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        Ok(())
+    } else {
+        Err(error!(
+            "Seeded entities are not compatible with the non power of 2 modulus \
+            {ciphertext_modulus:?}"
+        ))
+    }
+}
+
+fn fourier_polynomial_size(polynomial_size: PolynomialSize) -> crate::Result<usize> {
+    if polynomial_size.0 % 2 == 0 {
+        Ok(polynomial_size.to_fourier_polynomial_size().0)
+    } else {
+        Err(error!(
+            "Invalid polynomial size for a Fourier entity: {polynomial_size:?}"
+        ))
+    }
+}
+
+/// Returns the number of GGSW in a multi bit bootstrapping key
+fn multi_bit_ggsw_count(
+    input_lwe_dimension: LweDimension,
+    grouping_factor: LweBskGroupingFactor,
+) -> crate::Result<usize> {
+    if grouping_factor.0 == 0 || input_lwe_dimension.0 % grouping_factor.0 != 0 {
+        return Err(error!(
+            "Invalid grouping factor {grouping_factor:?} for input dimension \
+            {input_lwe_dimension:?}"
+        ));
+    }
+
+    u32::try_from(grouping_factor.0)
+        .ok()
+        .and_then(|factor| 1usize.checked_shl(factor))
+        .and_then(|ggsw_per_element| {
+            (input_lwe_dimension.0 / grouping_factor.0).checked_mul(ggsw_per_element)
+        })
+        .ok_or_else(|| error!("Invalid grouping factor {grouping_factor:?}"))
+}
+
+/// Layout of an [`LweKeyswitchKey`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(LweKeyswitchKeyLayoutVersions)]
+pub struct LweKeyswitchKeyLayout<Scalar: UnsignedInteger> {
+    data: Section,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    input_key_lwe_dimension: LweDimension,
+    output_lwe_size: LweSize,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger + MappedScalar> LweKeyswitchKeyLayout<Scalar> {
+    pub fn new<'a, C: Container<Element = Scalar>>(
+        key: &'a LweKeyswitchKey<C>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        Self {
+            data: sections.push(key.as_ref()),
+            decomp_base_log: key.decomposition_base_log(),
+            decomp_level_count: key.decomposition_level_count(),
+            input_key_lwe_dimension: key.input_key_lwe_dimension(),
+            output_lwe_size: key.output_lwe_size(),
+            ciphertext_modulus: key.ciphertext_modulus(),
+        }
+    }
+
+    pub fn view<'a>(&self, sections: Sections<'a>) -> crate::Result<LweKeyswitchKey<&'a [Scalar]>> {
+        let data = get_section_checked(
+            sections,
+            &self.data,
+            &[
+                self.input_key_lwe_dimension.0,
+                self.decomp_level_count.0,
+                self.output_lwe_size.0,
+            ],
+        )?;
+
+        Ok(LweKeyswitchKey::from_container(
+            data,
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.output_lwe_size,
+            self.ciphertext_modulus,
+        ))
+    }
+
+    pub fn copy_out(&self, sections: Sections<'_>) -> crate::Result<LweKeyswitchKeyOwned<Scalar>> {
+        let view = self.view(sections)?;
+        Ok(LweKeyswitchKey::from_container(
+            view.into_container().to_vec(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.output_lwe_size,
+            self.ciphertext_modulus,
+        ))
+    }
+}
+
+/// Layout of an [`LweCiphertextList`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(LweCiphertextListLayoutVersions)]
+pub struct LweCiphertextListLayout<Scalar: UnsignedInteger> {
+    data: Section,
+    lwe_size: LweSize,
+    lwe_ciphertext_count: LweCiphertextCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger + MappedScalar> LweCiphertextListLayout<Scalar> {
+    pub fn new<'a, C: Container<Element = Scalar>>(
+        list: &'a LweCiphertextList<C>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        Self {
+            data: sections.push(list.as_ref()),
+            lwe_size: list.lwe_size(),
+            lwe_ciphertext_count: list.lwe_ciphertext_count(),
+            ciphertext_modulus: list.ciphertext_modulus(),
+        }
+    }
+
+    pub fn view<'a>(
+        &self,
+        sections: Sections<'a>,
+    ) -> crate::Result<LweCiphertextList<&'a [Scalar]>> {
+        let data = get_section_checked(
+            sections,
+            &self.data,
+            &[self.lwe_ciphertext_count.0, self.lwe_size.0],
+        )?;
+
+        Ok(LweCiphertextList::from_container(
+            data,
+            self.lwe_size,
+            self.ciphertext_modulus,
+        ))
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<LweCiphertextListOwned<Scalar>> {
+        let view = self.view(sections)?;
+        Ok(LweCiphertextList::from_container(
+            view.into_container().to_vec(),
+            self.lwe_size,
+            self.ciphertext_modulus,
+        ))
+    }
+}
+
+/// Layout of an [`LwePackingKeyswitchKey`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(LwePackingKeyswitchKeyLayoutVersions)]
+pub struct LwePackingKeyswitchKeyLayout<Scalar: UnsignedInteger> {
+    data: Section,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    input_key_lwe_dimension: LweDimension,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger + MappedScalar> LwePackingKeyswitchKeyLayout<Scalar> {
+    pub fn new<'a, C: Container<Element = Scalar>>(
+        key: &'a LwePackingKeyswitchKey<C>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        Self {
+            data: sections.push(key.as_ref()),
+            decomp_base_log: key.decomposition_base_log(),
+            decomp_level_count: key.decomposition_level_count(),
+            input_key_lwe_dimension: key.input_key_lwe_dimension(),
+            output_glwe_size: key.output_glwe_size(),
+            output_polynomial_size: key.output_polynomial_size(),
+            ciphertext_modulus: key.ciphertext_modulus(),
+        }
+    }
+
+    pub fn view<'a>(
+        &self,
+        sections: Sections<'a>,
+    ) -> crate::Result<LwePackingKeyswitchKey<&'a [Scalar]>> {
+        let data = get_section_checked(
+            sections,
+            &self.data,
+            &[
+                self.input_key_lwe_dimension.0,
+                self.decomp_level_count.0,
+                self.output_glwe_size.0,
+                self.output_polynomial_size.0,
+            ],
+        )?;
+
+        Ok(LwePackingKeyswitchKey::from_container(
+            data,
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.output_glwe_size,
+            self.output_polynomial_size,
+            self.ciphertext_modulus,
+        ))
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<LwePackingKeyswitchKeyOwned<Scalar>> {
+        let view = self.view(sections)?;
+        Ok(LwePackingKeyswitchKey::from_container(
+            view.into_container().to_vec(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.output_glwe_size,
+            self.output_polynomial_size,
+            self.ciphertext_modulus,
+        ))
+    }
+}
+
+/// Layout of a [`FourierLweBootstrapKey`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(FourierLweBootstrapKeyLayoutVersions)]
+pub struct FourierLweBootstrapKeyLayout {
+    data: Section,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+impl FourierLweBootstrapKeyLayout {
+    pub fn new<'a, C: Container<Element = c64>>(
+        key: &'a FourierLweBootstrapKey<C>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        Self {
+            data: sections.push(key.as_view().data()),
+            input_lwe_dimension: key.input_lwe_dimension(),
+            glwe_size: key.glwe_size(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_base_log: key.decomposition_base_log(),
+            decomposition_level_count: key.decomposition_level_count(),
+        }
+    }
+
+    pub fn view<'a>(
+        &self,
+        sections: Sections<'a>,
+    ) -> crate::Result<FourierLweBootstrapKeyView<'a>> {
+        let data = get_section_checked(
+            sections,
+            &self.data,
+            &[
+                self.input_lwe_dimension.0,
+                self.decomposition_level_count.0,
+                self.glwe_size.0,
+                self.glwe_size.0,
+                fourier_polynomial_size(self.polynomial_size)?,
+            ],
+        )?;
+
+        Ok(FourierLweBootstrapKey::from_container(
+            data,
+            self.input_lwe_dimension,
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomposition_base_log,
+            self.decomposition_level_count,
+        ))
+    }
+
+    pub fn copy_out(&self, sections: Sections<'_>) -> crate::Result<FourierLweBootstrapKeyOwned> {
+        let view = self.view(sections)?;
+        Ok(FourierLweBootstrapKey::from_container(
+            to_aligned_box(view.data()),
+            self.input_lwe_dimension,
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomposition_base_log,
+            self.decomposition_level_count,
+        ))
+    }
+}
+
+/// Layout of a [`FourierLweMultiBitBootstrapKey`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(FourierLweMultiBitBootstrapKeyLayoutVersions)]
+pub struct FourierLweMultiBitBootstrapKeyLayout {
+    data: Section,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+}
+
+impl FourierLweMultiBitBootstrapKeyLayout {
+    pub fn new<'a, C: Container<Element = c64>>(
+        key: &'a FourierLweMultiBitBootstrapKey<C>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        Self {
+            data: sections.push(key.as_view().data()),
+            input_lwe_dimension: key.input_lwe_dimension(),
+            glwe_size: key.glwe_size(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_base_log: key.decomposition_base_log(),
+            decomposition_level_count: key.decomposition_level_count(),
+            grouping_factor: key.grouping_factor(),
+        }
+    }
+
+    pub fn view<'a>(
+        &self,
+        sections: Sections<'a>,
+    ) -> crate::Result<FourierLweMultiBitBootstrapKeyView<'a>> {
+        let data = get_section_checked(
+            sections,
+            &self.data,
+            &[
+                multi_bit_ggsw_count(self.input_lwe_dimension, self.grouping_factor)?,
+                self.decomposition_level_count.0,
+                self.glwe_size.0,
+                self.glwe_size.0,
+                fourier_polynomial_size(self.polynomial_size)?,
+            ],
+        )?;
+
+        Ok(FourierLweMultiBitBootstrapKey::from_container(
+            data,
+            self.input_lwe_dimension,
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomposition_base_log,
+            self.decomposition_level_count,
+            self.grouping_factor,
+        ))
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<FourierLweMultiBitBootstrapKeyOwned> {
+        let view = self.view(sections)?;
+        Ok(FourierLweMultiBitBootstrapKey::from_container(
+            to_aligned_box(view.data()),
+            self.input_lwe_dimension,
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomposition_base_log,
+            self.decomposition_level_count,
+            self.grouping_factor,
+        ))
+    }
+}
+
+/// Layout of a [`Fourier128LweBootstrapKey`], each of its four containers is stored in its own
+/// section
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(Fourier128LweBootstrapKeyLayoutVersions)]
+pub struct Fourier128LweBootstrapKeyLayout {
+    data_re0: Section,
+    data_re1: Section,
+    data_im0: Section,
+    data_im1: Section,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+impl Fourier128LweBootstrapKeyLayout {
+    pub fn new<'a, C: Container<Element = f64>>(
+        key: &'a Fourier128LweBootstrapKey<C>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        let (data_re0, data_re1, data_im0, data_im1) = key.as_view().data();
+        Self {
+            data_re0: sections.push(data_re0),
+            data_re1: sections.push(data_re1),
+            data_im0: sections.push(data_im0),
+            data_im1: sections.push(data_im1),
+            input_lwe_dimension: key.input_lwe_dimension(),
+            glwe_size: key.glwe_size(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_base_log: key.decomposition_base_log(),
+            decomposition_level_count: key.decomposition_level_count(),
+        }
+    }
+
+    pub fn view<'a>(
+        &self,
+        sections: Sections<'a>,
+    ) -> crate::Result<Fourier128LweBootstrapKey<&'a [f64]>> {
+        let dimensions = [
+            self.input_lwe_dimension.0,
+            self.decomposition_level_count.0,
+            self.glwe_size.0,
+            self.glwe_size.0,
+            fourier_polynomial_size(self.polynomial_size)?,
+        ];
+
+        Ok(Fourier128LweBootstrapKey::from_container(
+            get_section_checked(sections, &self.data_re0, &dimensions)?,
+            get_section_checked(sections, &self.data_re1, &dimensions)?,
+            get_section_checked(sections, &self.data_im0, &dimensions)?,
+            get_section_checked(sections, &self.data_im1, &dimensions)?,
+            self.polynomial_size,
+            self.input_lwe_dimension,
+            self.glwe_size,
+            self.decomposition_base_log,
+            self.decomposition_level_count,
+        ))
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<Fourier128LweBootstrapKey<ABox<[f64]>>> {
+        let (data_re0, data_re1, data_im0, data_im1) = self.view(sections)?.data();
+        Ok(Fourier128LweBootstrapKey::from_container(
+            to_aligned_box(data_re0),
+            to_aligned_box(data_re1),
+            to_aligned_box(data_im0),
+            to_aligned_box(data_im1),
+            self.polynomial_size,
+            self.input_lwe_dimension,
+            self.glwe_size,
+            self.decomposition_base_log,
+            self.decomposition_level_count,
+        ))
+    }
+}
+
+/// Layout of a [`SeededLweKeyswitchKey`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(SeededLweKeyswitchKeyLayoutVersions)]
+pub struct SeededLweKeyswitchKeyLayout<Scalar: UnsignedInteger> {
+    data: Section,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    input_key_lwe_dimension: LweDimension,
+    output_lwe_size: LweSize,
+    compression_seed: CompressionSeed,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger + MappedScalar> SeededLweKeyswitchKeyLayout<Scalar> {
+    pub fn new<'a, C: Container<Element = Scalar>>(
+        key: &'a SeededLweKeyswitchKey<C>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        Self {
+            data: sections.push(key.as_ref()),
+            decomp_base_log: key.decomposition_base_log(),
+            decomp_level_count: key.decomposition_level_count(),
+            input_key_lwe_dimension: key.input_key_lwe_dimension(),
+            output_lwe_size: key.output_lwe_size(),
+            compression_seed: key.compression_seed(),
+            ciphertext_modulus: key.ciphertext_modulus(),
+        }
+    }
+
+    pub fn view<'a>(
+        &self,
+        sections: Sections<'a>,
+    ) -> crate::Result<SeededLweKeyswitchKey<&'a [Scalar]>> {
+        check_native_modulus(self.ciphertext_modulus)?;
+        let data = get_section_checked(
+            sections,
+            &self.data,
+            &[self.input_key_lwe_dimension.0, self.decomp_level_count.0],
+        )?;
+
+        Ok(SeededLweKeyswitchKey::from_container(
+            data,
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.output_lwe_size,
+            self.compression_seed,
+            self.ciphertext_modulus,
+        ))
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<SeededLweKeyswitchKeyOwned<Scalar>> {
+        let view = self.view(sections)?;
+        Ok(SeededLweKeyswitchKey::from_container(
+            view.into_container().to_vec(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.output_lwe_size,
+            self.compression_seed,
+            self.ciphertext_modulus,
+        ))
+    }
+}
+
+/// Layout of a [`SeededLweBootstrapKey`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(SeededLweBootstrapKeyLayoutVersions)]
+pub struct SeededLweBootstrapKeyLayout<Scalar: UnsignedInteger> {
+    data: Section,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    compression_seed: CompressionSeed,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger + MappedScalar> SeededLweBootstrapKeyLayout<Scalar> {
+    pub fn new<'a, C: Container<Element = Scalar>>(
+        key: &'a SeededLweBootstrapKey<C>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        Self {
+            data: sections.push(key.as_ref()),
+            input_lwe_dimension: key.input_lwe_dimension(),
+            glwe_size: key.glwe_size(),
+            polynomial_size: key.polynomial_size(),
+            decomp_base_log: key.decomposition_base_log(),
+            decomp_level_count: key.decomposition_level_count(),
+            compression_seed: key.compression_seed(),
+            ciphertext_modulus: key.ciphertext_modulus(),
+        }
+    }
+
+    pub fn view<'a>(
+        &self,
+        sections: Sections<'a>,
+    ) -> crate::Result<SeededLweBootstrapKey<&'a [Scalar]>> {
+        check_native_modulus(self.ciphertext_modulus)?;
+        let data = get_section_checked(
+            sections,
+            &self.data,
+            &[
+                self.input_lwe_dimension.0,
+                self.decomp_level_count.0,
+                self.glwe_size.0,
+                self.polynomial_size.0,
+            ],
+        )?;
+
+        Ok(SeededLweBootstrapKey::from_container(
+            data,
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.compression_seed,
+            self.ciphertext_modulus,
+        ))
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<SeededLweBootstrapKeyOwned<Scalar>> {
+        let view = self.view(sections)?;
+        Ok(SeededLweBootstrapKey::from_container(
+            view.into_container().to_vec(),
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.compression_seed,
+            self.ciphertext_modulus,
+        ))
+    }
+}
+
+/// Layout of a [`SeededLweMultiBitBootstrapKey`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(SeededLweMultiBitBootstrapKeyLayoutVersions)]
+pub struct SeededLweMultiBitBootstrapKeyLayout<Scalar: UnsignedInteger> {
+    data: Section,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    compression_seed: CompressionSeed,
+    grouping_factor: LweBskGroupingFactor,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger + MappedScalar> SeededLweMultiBitBootstrapKeyLayout<Scalar> {
+    pub fn new<'a, C: Container<Element = Scalar>>(
+        key: &'a SeededLweMultiBitBootstrapKey<C>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        Self {
+            data: sections.push(key.as_ref()),
+            input_lwe_dimension: key.input_lwe_dimension(),
+            glwe_size: key.glwe_size(),
+            polynomial_size: key.polynomial_size(),
+            decomp_base_log: key.decomposition_base_log(),
+            decomp_level_count: key.decomposition_level_count(),
+            compression_seed: key.compression_seed(),
+            grouping_factor: key.grouping_factor(),
+            ciphertext_modulus: key.ciphertext_modulus(),
+        }
+    }
+
+    pub fn view<'a>(
+        &self,
+        sections: Sections<'a>,
+    ) -> crate::Result<SeededLweMultiBitBootstrapKey<&'a [Scalar]>> {
+        check_native_modulus(self.ciphertext_modulus)?;
+        let data = get_section_checked(
+            sections,
+            &self.data,
+            &[
+                multi_bit_ggsw_count(self.input_lwe_dimension, self.grouping_factor)?,
+                self.decomp_level_count.0,
+                self.glwe_size.0,
+                self.polynomial_size.0,
+            ],
+        )?;
+
+        Ok(SeededLweMultiBitBootstrapKey::from_container(
+            data,
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.compression_seed,
+            self.grouping_factor,
+            self.ciphertext_modulus,
+        ))
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<SeededLweMultiBitBootstrapKeyOwned<Scalar>> {
+        let view = self.view(sections)?;
+        Ok(SeededLweMultiBitBootstrapKey::from_container(
+            view.into_container().to_vec(),
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.compression_seed,
+            self.grouping_factor,
+            self.ciphertext_modulus,
+        ))
+    }
+}
+
+/// Layout of a [`SeededLwePackingKeyswitchKey`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(SeededLwePackingKeyswitchKeyLayoutVersions)]
+pub struct SeededLwePackingKeyswitchKeyLayout<Scalar: UnsignedInteger> {
+    data: Section,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    input_key_lwe_dimension: LweDimension,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger + MappedScalar> SeededLwePackingKeyswitchKeyLayout<Scalar> {
+    pub fn new<'a, C: Container<Element = Scalar>>(
+        key: &'a SeededLwePackingKeyswitchKey<C>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        Self {
+            data: sections.push(key.as_ref()),
+            decomp_base_log: key.decomposition_base_log(),
+            decomp_level_count: key.decomposition_level_count(),
+            input_key_lwe_dimension: key.input_key_lwe_dimension(),
+            output_glwe_size: key.output_glwe_size(),
+            output_polynomial_size: key.output_polynomial_size(),
+            compression_seed: key.compression_seed(),
+            ciphertext_modulus: key.ciphertext_modulus(),
+        }
+    }
+
+    pub fn view<'a>(
+        &self,
+        sections: Sections<'a>,
+    ) -> crate::Result<SeededLwePackingKeyswitchKey<&'a [Scalar]>> {
+        check_native_modulus(self.ciphertext_modulus)?;
+        let data = get_section_checked(
+            sections,
+            &self.data,
+            &[
+                self.input_key_lwe_dimension.0,
+                self.decomp_level_count.0,
+                self.output_polynomial_size.0,
+            ],
+        )?;
+
+        Ok(SeededLwePackingKeyswitchKey::from_container(
+            data,
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.output_glwe_size,
+            self.output_polynomial_size,
+            self.compression_seed,
+            self.ciphertext_modulus,
+        ))
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<SeededLwePackingKeyswitchKeyOwned<Scalar>> {
+        let view = self.view(sections)?;
+        Ok(SeededLwePackingKeyswitchKey::from_container(
+            view.into_container().to_vec(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.output_glwe_size,
+            self.output_polynomial_size,
+            self.compression_seed,
+            self.ciphertext_modulus,
+        ))
+    }
+}
@@ -0,0 +1,255 @@
+//! Layouts of the high level api keys in a mapped file
+//!
+//! The bootstrapping and keyswitching keys of the [`ServerKey`] and [`CompressedServerKey`] are
+//! stored in sections. The smaller components (the key switching material used to cast compact
+//! lists, the noise squashing compression key, ...) are stored in the metadata.
+
+use super::backward_compatibility::high_level_api::*;
+use super::shortint::{
+    CompressedCompressionKeyLayout, CompressedDecompressionKeyLayout,
+    CompressedNoiseSquashingKeyLayout,
+    CompressedServerKeyLayout as ShortintCompressedServerKeyLayout, CompressionKeyLayout,
+    DecompressionKeyLayout, NoiseSquashingKeyLayout, ServerKeyLayout as ShortintServerKeyLayout,
+};
+use super::{Mappable, Sections, SectionsWriter};
+use crate::integer::ciphertext::{
+    CompressedNoiseSquashingCompressionKey, NoiseSquashingCompressionKey,
+};
+use crate::integer::compression_keys::{
+    CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, DecompressionKey,
+};
+use crate::integer::key_switching_key::{
+    CompressedKeySwitchingKeyMaterial, KeySwitchingKeyMaterial,
+};
+use crate::integer::noise_squashing::{CompressedNoiseSquashingKey, NoiseSquashingKey};
+use crate::named::Named;
+use crate::{CompressedServerKey, ServerKey, Tag};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// Layout of a [`ServerKey`]
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(ServerKeyLayoutVersions)]
+pub struct ServerKeyLayout {
+    key: ShortintServerKeyLayout,
+    cpk_key_switching_key_material: Option<KeySwitchingKeyMaterial>,
+    compression_key: Option<CompressionKeyLayout>,
+    decompression_key: Option<DecompressionKeyLayout>,
+    noise_squashing_key: Option<NoiseSquashingKeyLayout>,
+    noise_squashing_compression_key: Option<NoiseSquashingCompressionKey>,
+    tag: Tag,
+}
+
+impl Named for ServerKeyLayout {
+    const NAME: &'static str = "high_level_api::ServerKeyLayout";
+}
+
+impl ServerKeyLayout {
+    /// Layout of the shortint key used for the computations
+    pub fn pbs_key(&self) -> &ShortintServerKeyLayout {
+        &self.key
+    }
+
+    pub fn compression_key(&self) -> Option<&CompressionKeyLayout> {
+        self.compression_key.as_ref()
+    }
+
+    pub fn decompression_key(&self) -> Option<&DecompressionKeyLayout> {
+        self.decompression_key.as_ref()
+    }
+
+    pub fn noise_squashing_key(&self) -> Option<&NoiseSquashingKeyLayout> {
+        self.noise_squashing_key.as_ref()
+    }
+
+    pub fn tag(&self) -> &Tag {
+        &self.tag
+    }
+}
+
+impl Mappable for ServerKey {
+    type Layout = ServerKeyLayout;
+
+    fn layout<'a>(&'a self, sections: &mut SectionsWriter<'a>) -> crate::Result<ServerKeyLayout> {
+        let key = &*self.key;
+
+        Ok(ServerKeyLayout {
+            key: key.key.key.layout(sections)?,
+            cpk_key_switching_key_material: key.cpk_key_switching_key_material.clone(),
+            compression_key: key
+                .compression_key
+                .as_ref()
+                .map(|compression_key| CompressionKeyLayout::new(&compression_key.key, sections)),
+            decompression_key: key.decompression_key.as_ref().map(|decompression_key| {
+                DecompressionKeyLayout::new(&decompression_key.key, sections)
+            }),
+            noise_squashing_key: key.noise_squashing_key.as_ref().map(|noise_squashing_key| {
+                NoiseSquashingKeyLayout::new(&noise_squashing_key.key, sections)
+            }),
+            noise_squashing_compression_key: key.noise_squashing_compression_key.clone(),
+            tag: self.tag.clone(),
+        })
+    }
+
+    fn from_layout(layout: &ServerKeyLayout, sections: Sections<'_>) -> crate::Result<Self> {
+        let key = crate::integer::ServerKey::from_raw_parts(
+            crate::shortint::ServerKey::from_layout(&layout.key, sections)?,
+        );
+
+        let compression_key = layout
+            .compression_key
+            .as_ref()
+            .map(|compression_key| {
+                compression_key
+                    .copy_out(sections)
+                    .map(|key| CompressionKey { key })
+            })
+            .transpose()?;
+
+        let decompression_key = layout
+            .decompression_key
+            .as_ref()
+            .map(|decompression_key| {
+                decompression_key
+                    .copy_out(sections)
+                    .map(|key| DecompressionKey { key })
+            })
+            .transpose()?;
+
+        let noise_squashing_key = layout
+            .noise_squashing_key
+            .as_ref()
+            .map(|noise_squashing_key| {
+                noise_squashing_key
+                    .copy_out(sections)
+                    .map(|key| NoiseSquashingKey { key })
+            })
+            .transpose()?;
+
+        Ok(Self::from_raw_parts(
+            key,
+            layout.cpk_key_switching_key_material.clone(),
+            compression_key,
+            decompression_key,
+            noise_squashing_key,
+            layout.noise_squashing_compression_key.clone(),
+            layout.tag.clone(),
+        ))
+    }
+}
+
+/// Layout of a [`CompressedServerKey`]
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedServerKeyLayoutVersions)]
+pub struct CompressedServerKeyLayout {
+    key: ShortintCompressedServerKeyLayout,
+    cpk_key_switching_key_material: Option<CompressedKeySwitchingKeyMaterial>,
+    compression_key: Option<CompressedCompressionKeyLayout>,
+    decompression_key: Option<CompressedDecompressionKeyLayout>,
+    noise_squashing_key: Option<CompressedNoiseSquashingKeyLayout>,
+    noise_squashing_compression_key: Option<CompressedNoiseSquashingCompressionKey>,
+    tag: Tag,
+}
+
+impl Named for CompressedServerKeyLayout {
+    const NAME: &'static str = "high_level_api::CompressedServerKeyLayout";
+}
+
+impl CompressedServerKeyLayout {
+    /// Layout of the compressed shortint key used for the computations
+    pub fn pbs_key(&self) -> &ShortintCompressedServerKeyLayout {
+        &self.key
+    }
+
+    pub fn compression_key(&self) -> Option<&CompressedCompressionKeyLayout> {
+        self.compression_key.as_ref()
+    }
+
+    pub fn decompression_key(&self) -> Option<&CompressedDecompressionKeyLayout> {
+        self.decompression_key.as_ref()
+    }
+
+    pub fn noise_squashing_key(&self) -> Option<&CompressedNoiseSquashingKeyLayout> {
+        self.noise_squashing_key.as_ref()
+    }
+
+    pub fn tag(&self) -> &Tag {
+        &self.tag
+    }
+}
+
+impl Mappable for CompressedServerKey {
+    type Layout = CompressedServerKeyLayout;
+
+    fn layout<'a>(
+        &'a self,
+        sections: &mut SectionsWriter<'a>,
+    ) -> crate::Result<CompressedServerKeyLayout> {
+        let key = &self.integer_key;
+
+        Ok(CompressedServerKeyLayout {
+            key: key.key.key.layout(sections)?,
+            cpk_key_switching_key_material: key.cpk_key_switching_key_material.clone(),
+            compression_key: key.compression_key.as_ref().map(|compression_key| {
+                CompressedCompressionKeyLayout::new(&compression_key.key, sections)
+            }),
+            decompression_key: key.decompression_key.as_ref().map(|decompression_key| {
+                CompressedDecompressionKeyLayout::new(&decompression_key.key, sections)
+            }),
+            noise_squashing_key: key.noise_squashing_key.as_ref().map(|noise_squashing_key| {
+                CompressedNoiseSquashingKeyLayout::new(&noise_squashing_key.key, sections)
+            }),
+            noise_squashing_compression_key: key.noise_squashing_compression_key.clone(),
+            tag: self.tag.clone(),
+        })
+    }
+
+    fn from_layout(
+        layout: &CompressedServerKeyLayout,
+        sections: Sections<'_>,
+    ) -> crate::Result<Self> {
+        let key = crate::integer::CompressedServerKey::from_raw_parts(
+            crate::shortint::CompressedServerKey::from_layout(&layout.key, sections)?,
+        );
+
+        let compression_key = layout
+            .compression_key
+            .as_ref()
+            .map(|compression_key| {
+                compression_key
+                    .copy_out(sections)
+                    .map(|key| CompressedCompressionKey { key })
+            })
+            .transpose()?;
+
+        let decompression_key = layout
+            .decompression_key
+            .as_ref()
+            .map(|decompression_key| {
+                decompression_key
+                    .copy_out(sections)
+                    .map(|key| CompressedDecompressionKey { key })
+            })
+            .transpose()?;
+
+        let noise_squashing_key = layout
+            .noise_squashing_key
+            .as_ref()
+            .map(|noise_squashing_key| {
+                noise_squashing_key
+                    .copy_out(sections)
+                    .map(|key| CompressedNoiseSquashingKey { key })
+            })
+            .transpose()?;
+
+        Ok(Self::from_raw_parts(
+            key,
+            layout.cpk_key_switching_key_material.clone(),
+            compression_key,
+            decompression_key,
+            noise_squashing_key,
+            layout.noise_squashing_compression_key.clone(),
+            layout.tag.clone(),
+        ))
+    }
+}
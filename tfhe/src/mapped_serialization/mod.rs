@@ -0,0 +1,440 @@
+//! Aligned serialization of large keys, that can be memory-mapped.
+//!
+//! [`safe_serialize`](crate::safe_serialization::safe_serialize) writes an object as a single
+//! bincode stream, so loading a key requires to read the whole stream in memory and to decode
+//! it into every one of the containers of the key.
+//!
+//! This module defines an alternative on-disk layout, where the large containers of a key
+//! (bootstrapping keys, keyswitching keys, ...) are written as raw aligned sections. A file
+//! written with [`safe_serialize_mapped`] can be opened with [`Mapped::open`] which maps it in
+//! memory. Each component can then be viewed in place as the usual container-generic entity
+//! (for example a `FourierLweBootstrapKey<&[c64]>`) using the [`Mapped::layout`].
+//!
+//! The shortint, integer and high level server keys only exist with owned containers, so
+//! [`Mapped::copy_out`] copies every section once into an owned key, which does not borrow the
+//! mapping. Copying out a whole server key is therefore not zero-copy, it only avoids the
+//! intermediate buffer and the decoding of the bincode stream.
+//!
+//! A mapped file is made of:
+//! - a fixed size preamble: magic bytes, format version, endianness marker and metadata size,
+//! - the metadata, which describes the key and where each of its components is stored. The metadata
+//!   is written using the safe serialization, which means that it is versioned and that its type is
+//!   checked when the file is opened,
+//! - the sections, each starting at an offset that is a multiple of [`SECTION_ALIGNMENT`].
+//!
+//! Sections are stored with the native endianness, a file written on a little endian platform
+//! cannot be opened on a big endian one.
+
+pub mod core_crypto;
+#[cfg(feature = "integer")]
+pub mod high_level_api;
+pub mod shortint;
+
+mod backward_compatibility;
+#[cfg(test)]
+mod tests;
+
+use crate::conformance::ParameterSetConformant;
+use crate::error;
+use crate::named::Named;
+use crate::safe_serialization::{DeserializationConfig, SerializationConfig};
+use aligned_vec::{AVec, ConstAlign};
+use backward_compatibility::{ScalarKindVersions, SectionVersions};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::Path;
+use tfhe_fft::c64;
+use tfhe_versionable::{Unversionize, Versionize};
+
+/// Alignment, in bytes, of the beginning of each section in a mapped file.
+///
+/// This is the page size of most platforms, so that sections are aligned in memory once the file
+/// is mapped.
+pub const SECTION_ALIGNMENT: usize = 4096;
+
+const MAGIC: [u8; 8] = *b"TFHE-MAP";
+
+/// Version of the preamble and of the sections layout. This should be updated when the way
+/// sections are stored is updated. Changes in the metadata are handled by the versioning.
+const MAPPED_FORMAT_VERSION: u32 = 1;
+
+/// Written in native endianness, used to detect files coming from a platform with a different
+/// endianness.
+const ENDIANNESS_MARKER: u32 = 0x0102_0304;
+
+/// Magic bytes, format version, endianness marker and metadata size
+const PREAMBLE_SIZE: usize = 24;
+
+/// Type of the elements stored in a [`Section`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(ScalarKindVersions)]
+pub enum ScalarKind {
+    U32,
+    U64,
+    U128,
+    F64,
+    C64,
+}
+
+/// Scalar types that can be stored in a [`Section`]
+pub trait MappedScalar: bytemuck::Pod {
+    const KIND: ScalarKind;
+}
+
+impl MappedScalar for u32 {
+    const KIND: ScalarKind = ScalarKind::U32;
+}
+
+impl MappedScalar for u64 {
+    const KIND: ScalarKind = ScalarKind::U64;
+}
+
+impl MappedScalar for u128 {
+    const KIND: ScalarKind = ScalarKind::U128;
+}
+
+impl MappedScalar for f64 {
+    const KIND: ScalarKind = ScalarKind::F64;
+}
+
+impl MappedScalar for c64 {
+    const KIND: ScalarKind = ScalarKind::C64;
+}
+
+/// Location of a raw container in the data area of a mapped file
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(SectionVersions)]
+pub struct Section {
+    kind: ScalarKind,
+    /// Offset in bytes from the start of the data area
+    offset: u64,
+    /// Number of elements
+    len: u64,
+}
+
+impl Section {
+    pub fn kind(&self) -> ScalarKind {
+        self.kind
+    }
+
+    /// Number of elements stored in the section
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Collects the containers of an object that will be written as sections
+pub struct SectionsWriter<'a> {
+    sections: Vec<(u64, &'a [u8])>,
+    size: u64,
+}
+
+impl<'a> SectionsWriter<'a> {
+    fn new() -> Self {
+        Self {
+            sections: Vec::new(),
+            size: 0,
+        }
+    }
+
+    /// Adds a container to the sections, and returns its location in the data area
+    pub fn push<Scalar: MappedScalar>(&mut self, data: &'a [Scalar]) -> Section {
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+        let offset = self.size.next_multiple_of(SECTION_ALIGNMENT as u64);
+
+        self.sections.push((offset, bytes));
+        self.size = offset + bytes.len() as u64;
+
+        Section {
+            kind: Scalar::KIND,
+            offset,
+            len: data.len() as u64,
+        }
+    }
+
+    fn write_into(self, mut writer: impl Write) -> std::io::Result<()> {
+        let mut position = 0;
+        for (offset, bytes) in self.sections {
+            write_padding(&mut writer, offset - position)?;
+            writer.write_all(bytes)?;
+            position = offset + bytes.len() as u64;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_padding(mut writer: impl Write, len: u64) -> std::io::Result<()> {
+    std::io::copy(&mut std::io::repeat(0).take(len), &mut writer).map(|_| ())
+}
+
+/// The data area of a mapped file, used to view the sections described by the metadata
+#[derive(Copy, Clone)]
+pub struct Sections<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Sections<'a> {
+    /// Returns a view of the content of a section, after checking its type, bounds and alignment
+    pub fn get<Scalar: MappedScalar>(&self, section: &Section) -> crate::Result<&'a [Scalar]> {
+        if section.kind != Scalar::KIND {
+            return Err(error!(
+                "Invalid section type, expected {:?}, got {:?}",
+                Scalar::KIND,
+                section.kind
+            ));
+        }
+
+        let bytes = usize::try_from(section.offset)
+            .ok()
+            .zip(usize::try_from(section.len).ok())
+            .and_then(|(start, len)| {
+                let end = len
+                    .checked_mul(std::mem::size_of::<Scalar>())?
+                    .checked_add(start)?;
+                self.data.get(start..end)
+            })
+            .ok_or_else(|| {
+                error!(
+                    "Section of {} elements at offset {} is out of bounds of the data area \
+                    ({} bytes)",
+                    section.len,
+                    section.offset,
+                    self.data.len()
+                )
+            })?;
+
+        bytemuck::try_cast_slice(bytes).map_err(|err| error!("Invalid section: {err}"))
+    }
+}
+
+/// Objects that can be written as a mapped file.
+///
+/// The large containers of the object are written as [`Section`]s, and everything else is stored
+/// in the versioned [`Layout`](Mappable::Layout), along with the location of each section.
+pub trait Mappable: Sized {
+    type Layout: Serialize + DeserializeOwned + Versionize + Unversionize + Named;
+
+    /// Creates the layout of the object, pushing its large containers to `sections`
+    fn layout<'a>(&'a self, sections: &mut SectionsWriter<'a>) -> crate::Result<Self::Layout>;
+
+    /// Rebuilds an owned object from its layout and the sections of a mapped file
+    fn from_layout(layout: &Self::Layout, sections: Sections<'_>) -> crate::Result<Self>;
+}
+
+/// Serializes an object in the mapped file format, into a [writer](std::io::Write).
+///
+/// The written bytes can be opened with [`Mapped::open`] or [`Mapped::from_bytes`].
+pub fn safe_serialize_mapped<T: Mappable>(object: &T, mut writer: impl Write) -> crate::Result<()> {
+    let mut sections = SectionsWriter::new();
+    let layout = object.layout(&mut sections)?;
+
+    let mut metadata = Vec::new();
+    SerializationConfig::new_with_unlimited_size()
+        .serialize_into(&layout, &mut metadata)
+        .map_err(|err| error!("Failed to serialize the mapped metadata: {err}"))?;
+
+    let io_error = |err: std::io::Error| error!("Failed to write the mapped file: {err}");
+
+    writer.write_all(&MAGIC).map_err(io_error)?;
+    writer
+        .write_all(&MAPPED_FORMAT_VERSION.to_le_bytes())
+        .map_err(io_error)?;
+    writer
+        .write_all(&ENDIANNESS_MARKER.to_ne_bytes())
+        .map_err(io_error)?;
+    writer
+        .write_all(&(metadata.len() as u64).to_le_bytes())
+        .map_err(io_error)?;
+    writer.write_all(&metadata).map_err(io_error)?;
+
+    let header_size = PREAMBLE_SIZE + metadata.len();
+    let data_start = header_size.next_multiple_of(SECTION_ALIGNMENT);
+    write_padding(&mut writer, (data_start - header_size) as u64).map_err(io_error)?;
+
+    sections.write_into(&mut writer).map_err(io_error)
+}
+
+enum MappedBytes {
+    Mmap(memmap2::Mmap),
+    Owned(AVec<u8, ConstAlign<SECTION_ALIGNMENT>>),
+}
+
+impl MappedBytes {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Self::Mmap(mmap) => mmap,
+            Self::Owned(bytes) => bytes,
+        }
+    }
+}
+
+/// An object stored in the mapped file format.
+///
+/// The metadata of the object is checked and deserialized when the file is opened, its
+/// sections are only checked when they are viewed or copied out.
+pub struct Mapped<T: Mappable> {
+    bytes: MappedBytes,
+    data_start: usize,
+    layout: T::Layout,
+}
+
+impl<T: Mappable> Mapped<T> {
+    /// Maps a file written with [`safe_serialize_mapped`] in memory, and deserializes its
+    /// metadata.
+    ///
+    /// `metadata_size_limit` is the size limit (in number of bytes) of the safe-serialized
+    /// metadata. The size of the sections is not limited, since they are not read when the file
+    /// is opened.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the returned object, or any view
+    /// created from it, is alive. See [`memmap2::Mmap::map`].
+    pub unsafe fn open(path: impl AsRef<Path>, metadata_size_limit: u64) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)
+            .map_err(|err| error!("Failed to open {}: {err}", path.display()))?;
+        let mmap = memmap2::Mmap::map(&file)
+            .map_err(|err| error!("Failed to map {}: {err}", path.display()))?;
+
+        Self::new(MappedBytes::Mmap(mmap), metadata_size_limit)
+    }
+
+    /// Reads an object written with [`safe_serialize_mapped`] from a buffer.
+    ///
+    /// The buffer is copied once in an aligned allocation, this is mostly useful when the mapped
+    /// file does not come from the filesystem.
+    pub fn from_bytes(bytes: &[u8], metadata_size_limit: u64) -> crate::Result<Self> {
+        Self::new(
+            MappedBytes::Owned(AVec::from_slice(SECTION_ALIGNMENT, bytes)),
+            metadata_size_limit,
+        )
+    }
+
+    fn new(bytes: MappedBytes, metadata_size_limit: u64) -> crate::Result<Self> {
+        let slice = bytes.as_slice();
+
+        let Some((preamble, remaining)) = slice.split_first_chunk::<PREAMBLE_SIZE>() else {
+            return Err(error!(
+                "Mapped file is too small to contain a valid preamble"
+            ));
+        };
+
+        let (magic, preamble) = preamble.split_at(MAGIC.len());
+        if magic != MAGIC {
+            return Err(error!("Invalid magic bytes, this is not a mapped file"));
+        }
+
+        let read_u32 = |bytes: &[u8]| u32::from_le_bytes(bytes.try_into().unwrap());
+        let version = read_u32(&preamble[..4]);
+        if version != MAPPED_FORMAT_VERSION {
+            return Err(error!(
+                "On deserialization, expected mapped format version {MAPPED_FORMAT_VERSION}, \
+                got version {version}"
+            ));
+        }
+
+        if preamble[4..8] != ENDIANNESS_MARKER.to_ne_bytes() {
+            return Err(error!(
+                "This mapped file has been written on a platform with a different endianness"
+            ));
+        }
+
+        let metadata_size = u64::from_le_bytes(preamble[8..16].try_into().unwrap());
+        if metadata_size > metadata_size_limit {
+            return Err(error!(
+                "Mapped metadata size ({metadata_size} bytes) is above the limit \
+                ({metadata_size_limit} bytes)"
+            ));
+        }
+
+        let metadata = usize::try_from(metadata_size)
+            .ok()
+            .and_then(|size| remaining.get(..size))
+            .ok_or_else(|| error!("Mapped file is too small to contain its metadata"))?;
+
+        let layout = DeserializationConfig::new(metadata_size)
+            .disable_conformance()
+            .deserialize_from(metadata)
+            .map_err(|err| error!("Failed to deserialize the mapped metadata: {err}"))?;
+
+        let data_start = (PREAMBLE_SIZE + metadata.len()).next_multiple_of(SECTION_ALIGNMENT);
+
+        Ok(Self {
+            bytes,
+            data_start,
+            layout,
+        })
+    }
+
+    /// Returns the metadata of the object, which can be used to view its components in place
+    pub fn layout(&self) -> &T::Layout {
+        &self.layout
+    }
+
+    /// Returns the data area of the file, where the sections are stored
+    pub fn sections(&self) -> Sections<'_> {
+        let data = self.bytes.as_slice();
+        Sections {
+            data: data.get(self.data_start..).unwrap_or_default(),
+        }
+    }
+
+    /// Copies the object out of the mapped file
+    ///
+    /// Every section is copied into an owned container, the returned object does not borrow the
+    /// mapping.
+    pub fn copy_out(&self) -> crate::Result<T> {
+        T::from_layout(&self.layout, self.sections())
+    }
+
+    /// Copies the object out of the mapped file, and checks that it is conformant with the given
+    /// parameter set
+    pub fn copy_out_conformant(&self, parameter_set: &T::ParameterSet) -> crate::Result<T>
+    where
+        T: ParameterSetConformant,
+    {
+        let object = self.copy_out()?;
+
+        if !object.is_conformant(parameter_set) {
+            return Err(error!("Mapped {} is not conformant", T::Layout::NAME));
+        }
+
+        Ok(object)
+    }
+}
+
+/// Returns the product of the given dimensions, used to check the size of a section before
+/// viewing it as an entity
+pub(crate) fn checked_container_len(dimensions: &[usize]) -> crate::Result<usize> {
+    dimensions
+        .iter()
+        .try_fold(1usize, |acc, dim| acc.checked_mul(*dim))
+        .filter(|len| *len > 0)
+        .ok_or_else(|| error!("Invalid dimensions in mapped metadata: {dimensions:?}"))
+}
+
+/// Returns the data of a section after checking that its size is the expected one
+pub(crate) fn get_section_checked<'a, Scalar: MappedScalar>(
+    sections: Sections<'a>,
+    section: &Section,
+    dimensions: &[usize],
+) -> crate::Result<&'a [Scalar]> {
+    let expected_len = checked_container_len(dimensions)?;
+    let data = sections.get(section)?;
+
+    if data.len() != expected_len {
+        return Err(error!(
+            "Invalid section size, expected {expected_len} elements, got {}",
+            data.len()
+        ));
+    }
+
+    Ok(data)
+}
@@ -0,0 +1,843 @@
+//! Layouts of the shortint keys in a mapped file
+
+use super::backward_compatibility::shortint::*;
+use super::core_crypto::{
+    Fourier128LweBootstrapKeyLayout, FourierLweBootstrapKeyLayout,
+    FourierLweMultiBitBootstrapKeyLayout, LweCiphertextListLayout, LweKeyswitchKeyLayout,
+    LwePackingKeyswitchKeyLayout, SeededLweBootstrapKeyLayout, SeededLweKeyswitchKeyLayout,
+    SeededLweMultiBitBootstrapKeyLayout, SeededLwePackingKeyswitchKeyLayout,
+};
+use super::{Mappable, MappedScalar, Sections, SectionsWriter};
+use crate::core_crypto::prelude::{
+    CiphertextModulus as CoreCiphertextModulus, CiphertextModulusLog, LweCiphertextCount,
+    LweDimension, NoiseEstimationMeasureBound, RSigmaFactor, ThreadCount, UnsignedInteger,
+    Variance,
+};
+use crate::error;
+use crate::named::Named;
+use crate::shortint::atomic_pattern::compressed::{
+    CompressedAtomicPatternServerKey, CompressedKS32AtomicPatternServerKey,
+    CompressedStandardAtomicPatternServerKey,
+};
+use crate::shortint::atomic_pattern::{
+    AtomicPatternServerKey, KS32AtomicPatternServerKey, StandardAtomicPatternServerKey,
+};
+use crate::shortint::ciphertext::MaxDegree;
+use crate::shortint::list_compression::{
+    CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, DecompressionKey,
+};
+use crate::shortint::noise_squashing::{
+    CompressedNoiseSquashingKey, CompressedShortint128BootstrappingKey, NoiseSquashingKey,
+    Shortint128BootstrappingKey,
+};
+use crate::shortint::server_key::{
+    CompressedModulusSwitchConfiguration, ModulusSwitchConfiguration,
+    ModulusSwitchNoiseReductionKey, SerializableShortintBootstrappingKey, ShortintBootstrappingKey,
+    ShortintCompressedBootstrappingKey,
+};
+use crate::shortint::{
+    CarryModulus, CiphertextModulus, CompressedServerKey, MaxNoiseLevel, MessageModulus, PBSOrder,
+    ServerKey,
+};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// Checks that the keyswitch and bootstrapping keys of an atomic pattern fit together, before
+/// building it
+fn check_atomic_pattern_dimensions(
+    ksk_input: LweDimension,
+    ksk_output: LweDimension,
+    bsk_input: LweDimension,
+    bsk_output: LweDimension,
+) -> crate::Result<()> {
+    if ksk_input != bsk_output || ksk_output != bsk_input {
+        return Err(error!(
+            "Mismatch between the LweKeyswitchKey dimensions ({ksk_input:?} -> {ksk_output:?}) \
+            and the bootstrapping key dimensions ({bsk_input:?} -> {bsk_output:?})"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Layout of a [`ModulusSwitchConfiguration`]
+///
+/// The encryptions of zero used by the drift technique are stored in a section.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(ModulusSwitchConfigurationLayoutVersions)]
+pub enum ModulusSwitchConfigurationLayout<InputScalar>
+where
+    InputScalar: UnsignedInteger,
+{
+    Standard,
+    DriftTechniqueNoiseReduction {
+        modulus_switch_zeros: LweCiphertextListLayout<InputScalar>,
+        ms_bound: NoiseEstimationMeasureBound,
+        ms_r_sigma_factor: RSigmaFactor,
+        ms_input_variance: Variance,
+    },
+    CenteredMeanNoiseReduction,
+}
+
+impl<InputScalar: UnsignedInteger + MappedScalar> ModulusSwitchConfigurationLayout<InputScalar> {
+    pub fn new<'a>(
+        configuration: &'a ModulusSwitchConfiguration<InputScalar>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        match configuration {
+            ModulusSwitchConfiguration::Standard => Self::Standard,
+            ModulusSwitchConfiguration::DriftTechniqueNoiseReduction(key) => {
+                Self::DriftTechniqueNoiseReduction {
+                    modulus_switch_zeros: LweCiphertextListLayout::new(
+                        &key.modulus_switch_zeros,
+                        sections,
+                    ),
+                    ms_bound: key.ms_bound,
+                    ms_r_sigma_factor: key.ms_r_sigma_factor,
+                    ms_input_variance: key.ms_input_variance,
+                }
+            }
+            ModulusSwitchConfiguration::CenteredMeanNoiseReduction => {
+                Self::CenteredMeanNoiseReduction
+            }
+        }
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<ModulusSwitchConfiguration<InputScalar>> {
+        Ok(match self {
+            Self::Standard => ModulusSwitchConfiguration::Standard,
+            Self::DriftTechniqueNoiseReduction {
+                modulus_switch_zeros,
+                ms_bound,
+                ms_r_sigma_factor,
+                ms_input_variance,
+            } => ModulusSwitchConfiguration::DriftTechniqueNoiseReduction(
+                ModulusSwitchNoiseReductionKey {
+                    modulus_switch_zeros: modulus_switch_zeros.copy_out(sections)?,
+                    ms_bound: *ms_bound,
+                    ms_r_sigma_factor: *ms_r_sigma_factor,
+                    ms_input_variance: *ms_input_variance,
+                },
+            ),
+            Self::CenteredMeanNoiseReduction => {
+                ModulusSwitchConfiguration::CenteredMeanNoiseReduction
+            }
+        })
+    }
+}
+
+/// Layout of a [`ShortintBootstrappingKey`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(ShortintBootstrappingKeyLayoutVersions)]
+pub enum ShortintBootstrappingKeyLayout<InputScalar>
+where
+    InputScalar: UnsignedInteger,
+{
+    Classic {
+        bsk: FourierLweBootstrapKeyLayout,
+        modulus_switch_noise_reduction_key: ModulusSwitchConfigurationLayout<InputScalar>,
+    },
+    MultiBit {
+        fourier_bsk: FourierLweMultiBitBootstrapKeyLayout,
+        deterministic_execution: bool,
+    },
+}
+
+impl<InputScalar: UnsignedInteger + MappedScalar> ShortintBootstrappingKeyLayout<InputScalar> {
+    pub fn new<'a>(
+        key: &'a ShortintBootstrappingKey<InputScalar>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        match key {
+            ShortintBootstrappingKey::Classic {
+                bsk,
+                modulus_switch_noise_reduction_key,
+            } => Self::Classic {
+                bsk: FourierLweBootstrapKeyLayout::new(bsk, sections),
+                modulus_switch_noise_reduction_key: ModulusSwitchConfigurationLayout::new(
+                    modulus_switch_noise_reduction_key,
+                    sections,
+                ),
+            },
+            ShortintBootstrappingKey::MultiBit {
+                fourier_bsk,
+                deterministic_execution,
+                ..
+            } => Self::MultiBit {
+                fourier_bsk: FourierLweMultiBitBootstrapKeyLayout::new(fourier_bsk, sections),
+                deterministic_execution: *deterministic_execution,
+            },
+        }
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<ShortintBootstrappingKey<InputScalar>> {
+        // Goes through the serializable key to compute the thread count of multi bit keys the
+        // same way as regular deserialization
+        let key = match self {
+            Self::Classic {
+                bsk,
+                modulus_switch_noise_reduction_key,
+            } => SerializableShortintBootstrappingKey::Classic {
+                bsk: bsk.copy_out(sections)?,
+                modulus_switch_noise_reduction_key: modulus_switch_noise_reduction_key
+                    .copy_out(sections)?,
+            },
+            Self::MultiBit {
+                fourier_bsk,
+                deterministic_execution,
+            } => SerializableShortintBootstrappingKey::MultiBit {
+                fourier_bsk: fourier_bsk.copy_out(sections)?,
+                deterministic_execution: *deterministic_execution,
+            },
+        };
+
+        Ok(key.into())
+    }
+}
+
+/// Layout of an [`AtomicPatternServerKey`]
+///
+/// Dynamic atomic patterns cannot be serialized, and thus have no layout.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(AtomicPatternServerKeyLayoutVersions)]
+pub enum AtomicPatternServerKeyLayout {
+    Standard {
+        key_switching_key: LweKeyswitchKeyLayout<u64>,
+        bootstrapping_key: ShortintBootstrappingKeyLayout<u64>,
+        pbs_order: PBSOrder,
+    },
+    KeySwitch32 {
+        key_switching_key: LweKeyswitchKeyLayout<u32>,
+        bootstrapping_key: ShortintBootstrappingKeyLayout<u32>,
+        ciphertext_modulus: CiphertextModulus,
+    },
+}
+
+impl AtomicPatternServerKeyLayout {
+    pub fn new<'a>(
+        key: &'a AtomicPatternServerKey,
+        sections: &mut SectionsWriter<'a>,
+    ) -> crate::Result<Self> {
+        match key {
+            AtomicPatternServerKey::Standard(ap) => Ok(Self::Standard {
+                key_switching_key: LweKeyswitchKeyLayout::new(&ap.key_switching_key, sections),
+                bootstrapping_key: ShortintBootstrappingKeyLayout::new(
+                    &ap.bootstrapping_key,
+                    sections,
+                ),
+                pbs_order: ap.pbs_order,
+            }),
+            AtomicPatternServerKey::KeySwitch32(ap) => Ok(Self::KeySwitch32 {
+                key_switching_key: LweKeyswitchKeyLayout::new(&ap.key_switching_key, sections),
+                bootstrapping_key: ShortintBootstrappingKeyLayout::new(
+                    &ap.bootstrapping_key,
+                    sections,
+                ),
+                ciphertext_modulus: ap.ciphertext_modulus,
+            }),
+            AtomicPatternServerKey::Dynamic(_) => Err(error!(
+                "Dynamic atomic patterns cannot be written in a mapped file"
+            )),
+        }
+    }
+
+    pub fn copy_out(&self, sections: Sections<'_>) -> crate::Result<AtomicPatternServerKey> {
+        match self {
+            Self::Standard {
+                key_switching_key,
+                bootstrapping_key,
+                pbs_order,
+            } => {
+                let key_switching_key = key_switching_key.copy_out(sections)?;
+                let bootstrapping_key = bootstrapping_key.copy_out(sections)?;
+                check_atomic_pattern_dimensions(
+                    key_switching_key.input_key_lwe_dimension(),
+                    key_switching_key.output_key_lwe_dimension(),
+                    bootstrapping_key.input_lwe_dimension(),
+                    bootstrapping_key.output_lwe_dimension(),
+                )?;
+
+                Ok(AtomicPatternServerKey::Standard(
+                    StandardAtomicPatternServerKey::from_raw_parts(
+                        key_switching_key,
+                        bootstrapping_key,
+                        *pbs_order,
+                    ),
+                ))
+            }
+            Self::KeySwitch32 {
+                key_switching_key,
+                bootstrapping_key,
+                ciphertext_modulus,
+            } => {
+                let key_switching_key = key_switching_key.copy_out(sections)?;
+                let bootstrapping_key = bootstrapping_key.copy_out(sections)?;
+                check_atomic_pattern_dimensions(
+                    key_switching_key.input_key_lwe_dimension(),
+                    key_switching_key.output_key_lwe_dimension(),
+                    bootstrapping_key.input_lwe_dimension(),
+                    bootstrapping_key.output_lwe_dimension(),
+                )?;
+
+                Ok(AtomicPatternServerKey::KeySwitch32(
+                    KS32AtomicPatternServerKey::from_raw_parts(
+                        key_switching_key,
+                        bootstrapping_key,
+                        *ciphertext_modulus,
+                    ),
+                ))
+            }
+        }
+    }
+}
+
+/// Layout of a shortint [`ServerKey`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(ServerKeyLayoutVersions)]
+pub struct ServerKeyLayout {
+    pub atomic_pattern: AtomicPatternServerKeyLayout,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub max_degree: MaxDegree,
+    pub max_noise_level: MaxNoiseLevel,
+    pub ciphertext_modulus: CiphertextModulus,
+}
+
+impl Named for ServerKeyLayout {
+    const NAME: &'static str = "shortint::ServerKeyLayout";
+}
+
+impl Mappable for ServerKey {
+    type Layout = ServerKeyLayout;
+
+    fn layout<'a>(&'a self, sections: &mut SectionsWriter<'a>) -> crate::Result<ServerKeyLayout> {
+        Ok(ServerKeyLayout {
+            atomic_pattern: AtomicPatternServerKeyLayout::new(&self.atomic_pattern, sections)?,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            max_degree: self.max_degree,
+            max_noise_level: self.max_noise_level,
+            ciphertext_modulus: self.ciphertext_modulus,
+        })
+    }
+
+    fn from_layout(layout: &ServerKeyLayout, sections: Sections<'_>) -> crate::Result<Self> {
+        Ok(Self {
+            atomic_pattern: layout.atomic_pattern.copy_out(sections)?,
+            message_modulus: layout.message_modulus,
+            carry_modulus: layout.carry_modulus,
+            max_degree: layout.max_degree,
+            max_noise_level: layout.max_noise_level,
+            ciphertext_modulus: layout.ciphertext_modulus,
+        })
+    }
+}
+
+/// Layout of a [`ShortintCompressedBootstrappingKey`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(ShortintCompressedBootstrappingKeyLayoutVersions)]
+pub enum ShortintCompressedBootstrappingKeyLayout<InputScalar>
+where
+    InputScalar: UnsignedInteger,
+{
+    Classic {
+        bsk: SeededLweBootstrapKeyLayout<u64>,
+        modulus_switch_noise_reduction_key: CompressedModulusSwitchConfiguration<InputScalar>,
+    },
+    MultiBit {
+        seeded_bsk: SeededLweMultiBitBootstrapKeyLayout<u64>,
+        deterministic_execution: bool,
+    },
+}
+
+impl<InputScalar: UnsignedInteger> ShortintCompressedBootstrappingKeyLayout<InputScalar> {
+    pub fn new<'a>(
+        key: &'a ShortintCompressedBootstrappingKey<InputScalar>,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        match key {
+            ShortintCompressedBootstrappingKey::Classic {
+                bsk,
+                modulus_switch_noise_reduction_key,
+            } => Self::Classic {
+                bsk: SeededLweBootstrapKeyLayout::new(bsk, sections),
+                modulus_switch_noise_reduction_key: modulus_switch_noise_reduction_key.clone(),
+            },
+            ShortintCompressedBootstrappingKey::MultiBit {
+                seeded_bsk,
+                deterministic_execution,
+            } => Self::MultiBit {
+                seeded_bsk: SeededLweMultiBitBootstrapKeyLayout::new(seeded_bsk, sections),
+                deterministic_execution: *deterministic_execution,
+            },
+        }
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<ShortintCompressedBootstrappingKey<InputScalar>> {
+        Ok(match self {
+            Self::Classic {
+                bsk,
+                modulus_switch_noise_reduction_key,
+            } => ShortintCompressedBootstrappingKey::Classic {
+                bsk: bsk.copy_out(sections)?,
+                modulus_switch_noise_reduction_key: modulus_switch_noise_reduction_key.clone(),
+            },
+            Self::MultiBit {
+                seeded_bsk,
+                deterministic_execution,
+            } => ShortintCompressedBootstrappingKey::MultiBit {
+                seeded_bsk: seeded_bsk.copy_out(sections)?,
+                deterministic_execution: *deterministic_execution,
+            },
+        })
+    }
+}
+
+/// Layout of a [`CompressedAtomicPatternServerKey`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedAtomicPatternServerKeyLayoutVersions)]
+pub enum CompressedAtomicPatternServerKeyLayout {
+    Standard {
+        key_switching_key: SeededLweKeyswitchKeyLayout<u64>,
+        bootstrapping_key: ShortintCompressedBootstrappingKeyLayout<u64>,
+        pbs_order: PBSOrder,
+    },
+    KeySwitch32 {
+        key_switching_key: SeededLweKeyswitchKeyLayout<u32>,
+        bootstrapping_key: ShortintCompressedBootstrappingKeyLayout<u32>,
+    },
+}
+
+impl CompressedAtomicPatternServerKeyLayout {
+    pub fn new<'a>(
+        key: &'a CompressedAtomicPatternServerKey,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        match key {
+            CompressedAtomicPatternServerKey::Standard(ap) => Self::Standard {
+                key_switching_key: SeededLweKeyswitchKeyLayout::new(
+                    ap.key_switching_key(),
+                    sections,
+                ),
+                bootstrapping_key: ShortintCompressedBootstrappingKeyLayout::new(
+                    ap.bootstrapping_key(),
+                    sections,
+                ),
+                pbs_order: ap.pbs_order(),
+            },
+            CompressedAtomicPatternServerKey::KeySwitch32(ap) => Self::KeySwitch32 {
+                key_switching_key: SeededLweKeyswitchKeyLayout::new(
+                    ap.key_switching_key(),
+                    sections,
+                ),
+                bootstrapping_key: ShortintCompressedBootstrappingKeyLayout::new(
+                    ap.bootstrapping_key(),
+                    sections,
+                ),
+            },
+        }
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<CompressedAtomicPatternServerKey> {
+        match self {
+            Self::Standard {
+                key_switching_key,
+                bootstrapping_key,
+                pbs_order,
+            } => {
+                let key_switching_key = key_switching_key.copy_out(sections)?;
+                let bootstrapping_key = bootstrapping_key.copy_out(sections)?;
+                check_atomic_pattern_dimensions(
+                    key_switching_key.input_key_lwe_dimension(),
+                    key_switching_key.output_key_lwe_dimension(),
+                    bootstrapping_key.input_lwe_dimension(),
+                    bootstrapping_key.output_lwe_dimension(),
+                )?;
+                if key_switching_key.ciphertext_modulus() != bootstrapping_key.ciphertext_modulus()
+                {
+                    return Err(error!(
+                        "Mismatch between the SeededLweKeyswitchKey and the \
+                        ShortintCompressedBootstrappingKey CiphertextModulus"
+                    ));
+                }
+
+                Ok(CompressedAtomicPatternServerKey::Standard(
+                    CompressedStandardAtomicPatternServerKey::from_raw_parts(
+                        key_switching_key,
+                        bootstrapping_key,
+                        *pbs_order,
+                    ),
+                ))
+            }
+            Self::KeySwitch32 {
+                key_switching_key,
+                bootstrapping_key,
+            } => {
+                let key_switching_key = key_switching_key.copy_out(sections)?;
+                let bootstrapping_key = bootstrapping_key.copy_out(sections)?;
+                check_atomic_pattern_dimensions(
+                    key_switching_key.input_key_lwe_dimension(),
+                    key_switching_key.output_key_lwe_dimension(),
+                    bootstrapping_key.input_lwe_dimension(),
+                    bootstrapping_key.output_lwe_dimension(),
+                )?;
+
+                Ok(CompressedAtomicPatternServerKey::KeySwitch32(
+                    CompressedKS32AtomicPatternServerKey::from_raw_parts(
+                        key_switching_key,
+                        bootstrapping_key,
+                    ),
+                ))
+            }
+        }
+    }
+}
+
+/// Layout of a shortint [`CompressedServerKey`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedServerKeyLayoutVersions)]
+pub struct CompressedServerKeyLayout {
+    pub compressed_ap_server_key: CompressedAtomicPatternServerKeyLayout,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub max_degree: MaxDegree,
+    pub max_noise_level: MaxNoiseLevel,
+}
+
+impl Named for CompressedServerKeyLayout {
+    const NAME: &'static str = "shortint::CompressedServerKeyLayout";
+}
+
+impl Mappable for CompressedServerKey {
+    type Layout = CompressedServerKeyLayout;
+
+    fn layout<'a>(
+        &'a self,
+        sections: &mut SectionsWriter<'a>,
+    ) -> crate::Result<CompressedServerKeyLayout> {
+        Ok(CompressedServerKeyLayout {
+            compressed_ap_server_key: CompressedAtomicPatternServerKeyLayout::new(
+                &self.compressed_ap_server_key,
+                sections,
+            ),
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            max_degree: self.max_degree,
+            max_noise_level: self.max_noise_level,
+        })
+    }
+
+    fn from_layout(
+        layout: &CompressedServerKeyLayout,
+        sections: Sections<'_>,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            compressed_ap_server_key: layout.compressed_ap_server_key.copy_out(sections)?,
+            message_modulus: layout.message_modulus,
+            carry_modulus: layout.carry_modulus,
+            max_degree: layout.max_degree,
+            max_noise_level: layout.max_noise_level,
+        })
+    }
+}
+
+/// Layout of a [`CompressionKey`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(CompressionKeyLayoutVersions)]
+pub struct CompressionKeyLayout {
+    pub packing_key_switching_key: LwePackingKeyswitchKeyLayout<u64>,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub storage_log_modulus: CiphertextModulusLog,
+}
+
+impl CompressionKeyLayout {
+    pub fn new<'a>(key: &'a CompressionKey, sections: &mut SectionsWriter<'a>) -> Self {
+        Self {
+            packing_key_switching_key: LwePackingKeyswitchKeyLayout::new(
+                &key.packing_key_switching_key,
+                sections,
+            ),
+            lwe_per_glwe: key.lwe_per_glwe,
+            storage_log_modulus: key.storage_log_modulus,
+        }
+    }
+
+    pub fn copy_out(&self, sections: Sections<'_>) -> crate::Result<CompressionKey> {
+        Ok(CompressionKey {
+            packing_key_switching_key: self.packing_key_switching_key.copy_out(sections)?,
+            lwe_per_glwe: self.lwe_per_glwe,
+            storage_log_modulus: self.storage_log_modulus,
+        })
+    }
+}
+
+/// Layout of a [`DecompressionKey`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(DecompressionKeyLayoutVersions)]
+pub struct DecompressionKeyLayout {
+    pub blind_rotate_key: FourierLweBootstrapKeyLayout,
+    pub lwe_per_glwe: LweCiphertextCount,
+}
+
+impl DecompressionKeyLayout {
+    pub fn new<'a>(key: &'a DecompressionKey, sections: &mut SectionsWriter<'a>) -> Self {
+        Self {
+            blind_rotate_key: FourierLweBootstrapKeyLayout::new(&key.blind_rotate_key, sections),
+            lwe_per_glwe: key.lwe_per_glwe,
+        }
+    }
+
+    pub fn copy_out(&self, sections: Sections<'_>) -> crate::Result<DecompressionKey> {
+        Ok(DecompressionKey {
+            blind_rotate_key: self.blind_rotate_key.copy_out(sections)?,
+            lwe_per_glwe: self.lwe_per_glwe,
+        })
+    }
+}
+
+/// Layout of a [`CompressedCompressionKey`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedCompressionKeyLayoutVersions)]
+pub struct CompressedCompressionKeyLayout {
+    pub packing_key_switching_key: SeededLwePackingKeyswitchKeyLayout<u64>,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub storage_log_modulus: CiphertextModulusLog,
+}
+
+impl CompressedCompressionKeyLayout {
+    pub fn new<'a>(key: &'a CompressedCompressionKey, sections: &mut SectionsWriter<'a>) -> Self {
+        Self {
+            packing_key_switching_key: SeededLwePackingKeyswitchKeyLayout::new(
+                &key.packing_key_switching_key,
+                sections,
+            ),
+            lwe_per_glwe: key.lwe_per_glwe,
+            storage_log_modulus: key.storage_log_modulus,
+        }
+    }
+
+    pub fn copy_out(&self, sections: Sections<'_>) -> crate::Result<CompressedCompressionKey> {
+        Ok(CompressedCompressionKey {
+            packing_key_switching_key: self.packing_key_switching_key.copy_out(sections)?,
+            lwe_per_glwe: self.lwe_per_glwe,
+            storage_log_modulus: self.storage_log_modulus,
+        })
+    }
+}
+
+/// Layout of a [`CompressedDecompressionKey`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedDecompressionKeyLayoutVersions)]
+pub struct CompressedDecompressionKeyLayout {
+    pub blind_rotate_key: SeededLweBootstrapKeyLayout<u64>,
+    pub lwe_per_glwe: LweCiphertextCount,
+}
+
+impl CompressedDecompressionKeyLayout {
+    pub fn new<'a>(key: &'a CompressedDecompressionKey, sections: &mut SectionsWriter<'a>) -> Self {
+        Self {
+            blind_rotate_key: SeededLweBootstrapKeyLayout::new(&key.blind_rotate_key, sections),
+            lwe_per_glwe: key.lwe_per_glwe,
+        }
+    }
+
+    pub fn copy_out(&self, sections: Sections<'_>) -> crate::Result<CompressedDecompressionKey> {
+        Ok(CompressedDecompressionKey {
+            blind_rotate_key: self.blind_rotate_key.copy_out(sections)?,
+            lwe_per_glwe: self.lwe_per_glwe,
+        })
+    }
+}
+
+/// Layout of a [`Shortint128BootstrappingKey`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(Shortint128BootstrappingKeyLayoutVersions)]
+pub enum Shortint128BootstrappingKeyLayout {
+    Classic {
+        bsk: Fourier128LweBootstrapKeyLayout,
+        modulus_switch_noise_reduction_key: ModulusSwitchConfigurationLayout<u64>,
+    },
+    /// Multi bit noise squashing keys are not split in sections, they are stored in the metadata
+    MultiBit(Shortint128BootstrappingKey),
+}
+
+impl Shortint128BootstrappingKeyLayout {
+    pub fn new<'a>(
+        key: &'a Shortint128BootstrappingKey,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        match key {
+            Shortint128BootstrappingKey::Classic {
+                bsk,
+                modulus_switch_noise_reduction_key,
+            } => Self::Classic {
+                bsk: Fourier128LweBootstrapKeyLayout::new(bsk, sections),
+                modulus_switch_noise_reduction_key: ModulusSwitchConfigurationLayout::new(
+                    modulus_switch_noise_reduction_key,
+                    sections,
+                ),
+            },
+            Shortint128BootstrappingKey::MultiBit { .. } => Self::MultiBit(key.clone()),
+        }
+    }
+
+    pub fn copy_out(&self, sections: Sections<'_>) -> crate::Result<Shortint128BootstrappingKey> {
+        match self {
+            Self::Classic {
+                bsk,
+                modulus_switch_noise_reduction_key,
+            } => Ok(Shortint128BootstrappingKey::Classic {
+                bsk: bsk.copy_out(sections)?,
+                modulus_switch_noise_reduction_key: modulus_switch_noise_reduction_key
+                    .copy_out(sections)?,
+            }),
+            Self::MultiBit(key) => Ok(key.clone()),
+        }
+    }
+}
+
+/// Layout of a [`NoiseSquashingKey`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(NoiseSquashingKeyLayoutVersions)]
+pub struct NoiseSquashingKeyLayout {
+    pub bootstrapping_key: Shortint128BootstrappingKeyLayout,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub output_ciphertext_modulus: CoreCiphertextModulus<u128>,
+}
+
+impl NoiseSquashingKeyLayout {
+    pub fn new<'a>(key: &'a NoiseSquashingKey, sections: &mut SectionsWriter<'a>) -> Self {
+        Self {
+            bootstrapping_key: Shortint128BootstrappingKeyLayout::new(
+                key.bootstrapping_key(),
+                sections,
+            ),
+            message_modulus: key.message_modulus(),
+            carry_modulus: key.carry_modulus(),
+            output_ciphertext_modulus: key.output_ciphertext_modulus(),
+        }
+    }
+
+    pub fn copy_out(&self, sections: Sections<'_>) -> crate::Result<NoiseSquashingKey> {
+        Ok(NoiseSquashingKey::from_raw_parts(
+            self.bootstrapping_key.copy_out(sections)?,
+            self.message_modulus,
+            self.carry_modulus,
+            self.output_ciphertext_modulus,
+        ))
+    }
+}
+
+/// Layout of a [`CompressedShortint128BootstrappingKey`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedShortint128BootstrappingKeyLayoutVersions)]
+pub enum CompressedShortint128BootstrappingKeyLayout {
+    Classic {
+        bsk: SeededLweBootstrapKeyLayout<u128>,
+        modulus_switch_noise_reduction_key: CompressedModulusSwitchConfiguration<u64>,
+    },
+    MultiBit {
+        bsk: SeededLweMultiBitBootstrapKeyLayout<u128>,
+        thread_count: ThreadCount,
+        deterministic_execution: bool,
+    },
+}
+
+impl CompressedShortint128BootstrappingKeyLayout {
+    pub fn new<'a>(
+        key: &'a CompressedShortint128BootstrappingKey,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        match key {
+            CompressedShortint128BootstrappingKey::Classic {
+                bsk,
+                modulus_switch_noise_reduction_key,
+            } => Self::Classic {
+                bsk: SeededLweBootstrapKeyLayout::new(bsk, sections),
+                modulus_switch_noise_reduction_key: modulus_switch_noise_reduction_key.clone(),
+            },
+            CompressedShortint128BootstrappingKey::MultiBit {
+                bsk,
+                thread_count,
+                deterministic_execution,
+            } => Self::MultiBit {
+                bsk: SeededLweMultiBitBootstrapKeyLayout::new(bsk, sections),
+                thread_count: *thread_count,
+                deterministic_execution: *deterministic_execution,
+            },
+        }
+    }
+
+    pub fn copy_out(
+        &self,
+        sections: Sections<'_>,
+    ) -> crate::Result<CompressedShortint128BootstrappingKey> {
+        match self {
+            Self::Classic {
+                bsk,
+                modulus_switch_noise_reduction_key,
+            } => Ok(CompressedShortint128BootstrappingKey::Classic {
+                bsk: bsk.copy_out(sections)?,
+                modulus_switch_noise_reduction_key: modulus_switch_noise_reduction_key.clone(),
+            }),
+            Self::MultiBit {
+                bsk,
+                thread_count,
+                deterministic_execution,
+            } => Ok(CompressedShortint128BootstrappingKey::MultiBit {
+                bsk: bsk.copy_out(sections)?,
+                thread_count: *thread_count,
+                deterministic_execution: *deterministic_execution,
+            }),
+        }
+    }
+}
+
+/// Layout of a [`CompressedNoiseSquashingKey`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedNoiseSquashingKeyLayoutVersions)]
+pub struct CompressedNoiseSquashingKeyLayout {
+    pub bootstrapping_key: CompressedShortint128BootstrappingKeyLayout,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub output_ciphertext_modulus: CoreCiphertextModulus<u128>,
+}
+
+impl CompressedNoiseSquashingKeyLayout {
+    pub fn new<'a>(
+        key: &'a CompressedNoiseSquashingKey,
+        sections: &mut SectionsWriter<'a>,
+    ) -> Self {
+        Self {
+            bootstrapping_key: CompressedShortint128BootstrappingKeyLayout::new(
+                key.bootstrapping_key(),
+                sections,
+            ),
+            message_modulus: key.message_modulus(),
+            carry_modulus: key.carry_modulus(),
+            output_ciphertext_modulus: key.output_ciphertext_modulus(),
+        }
+    }
+
+    pub fn copy_out(&self, sections: Sections<'_>) -> crate::Result<CompressedNoiseSquashingKey> {
+        Ok(CompressedNoiseSquashingKey::from_raw_parts(
+            self.bootstrapping_key.copy_out(sections)?,
+            self.message_modulus,
+            self.carry_modulus,
+            self.output_ciphertext_modulus,
+        ))
+    }
+}
@@ -0,0 +1,165 @@
+use super::shortint::{AtomicPatternServerKeyLayout, ShortintBootstrappingKeyLayout};
+use super::*;
+use crate::shortint::atomic_pattern::AtomicPatternServerKey;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+use crate::shortint::server_key::ShortintBootstrappingKey;
+
+const METADATA_LIMIT: u64 = 1 << 20;
+
+/// Used to check that a key copied out of a mapped file is identical to the original one
+fn serialized<T: Serialize>(object: &T) -> Vec<u8> {
+    bincode::serialize(object).unwrap()
+}
+
+fn to_mapped_bytes<T: Mappable>(object: &T) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    safe_serialize_mapped(object, &mut buffer).unwrap();
+    buffer
+}
+
+#[test]
+fn test_mapped_shortint_server_key() {
+    let (_cks, sks) = crate::shortint::gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let bytes = to_mapped_bytes(&sks);
+    let mapped = Mapped::<crate::shortint::ServerKey>::from_bytes(&bytes, METADATA_LIMIT).unwrap();
+
+    // Components can be viewed without being copied
+    let AtomicPatternServerKey::Standard(ap) = &sks.atomic_pattern else {
+        panic!("Expected a standard atomic pattern");
+    };
+    let AtomicPatternServerKeyLayout::Standard {
+        key_switching_key,
+        bootstrapping_key: ShortintBootstrappingKeyLayout::Classic { bsk, .. },
+        ..
+    } = &mapped.layout().atomic_pattern
+    else {
+        panic!("Expected a standard atomic pattern layout");
+    };
+    let ShortintBootstrappingKey::Classic {
+        bsk: expected_bsk, ..
+    } = &ap.bootstrapping_key
+    else {
+        panic!("Expected a classic bootstrapping key");
+    };
+
+    let ksk_view = key_switching_key.view(mapped.sections()).unwrap();
+    assert_eq!(ksk_view.as_ref(), ap.key_switching_key.as_ref());
+
+    let bsk_view = bsk.view(mapped.sections()).unwrap();
+    assert_eq!(bsk_view, expected_bsk.as_view());
+
+    let loaded = mapped.copy_out().unwrap();
+    assert_eq!(serialized(&loaded), serialized(&sks));
+}
+
+#[test]
+fn test_mapped_shortint_compressed_server_key() {
+    let cks = crate::shortint::ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let compressed_sks = crate::shortint::CompressedServerKey::new(&cks);
+
+    let bytes = to_mapped_bytes(&compressed_sks);
+    let mapped =
+        Mapped::<crate::shortint::CompressedServerKey>::from_bytes(&bytes, METADATA_LIMIT).unwrap();
+
+    let loaded = mapped.copy_out().unwrap();
+    assert_eq!(serialized(&loaded), serialized(&compressed_sks));
+}
+
+#[cfg(feature = "integer")]
+mod hl_api {
+    use super::*;
+    use crate::prelude::*;
+    use crate::shortint::parameters::{
+        COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    };
+    use crate::{
+        set_server_key, ClientKey, CompressedServerKey, Config, ConfigBuilder, FheUint8, ServerKey,
+    };
+
+    fn config() -> Config {
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .enable_noise_squashing(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .build()
+    }
+
+    #[test]
+    fn test_mapped_hl_server_key_file() {
+        let config = config();
+        let cks = ClientKey::generate(config);
+        let sks = ServerKey::new(&cks);
+
+        let path =
+            std::env::temp_dir().join(format!("tfhe_mapped_server_key_{}.bin", std::process::id()));
+        let file = std::fs::File::create(&path).unwrap();
+        safe_serialize_mapped(&sks, std::io::BufWriter::new(file)).unwrap();
+
+        // SAFETY: the file is not modified while it is mapped
+        let mapped = unsafe { Mapped::<ServerKey>::open(&path, METADATA_LIMIT) }.unwrap();
+
+        assert!(mapped.layout().compression_key().is_some());
+        assert!(mapped.layout().decompression_key().is_some());
+        assert!(mapped.layout().noise_squashing_key().is_some());
+
+        let loaded = mapped.copy_out_conformant(&config.into()).unwrap();
+        drop(mapped);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(serialized(&loaded), serialized(&sks));
+
+        set_server_key(loaded);
+        let a = FheUint8::encrypt(17u8, &cks);
+        let b = FheUint8::encrypt(25u8, &cks);
+        let c: u8 = (a + b).decrypt(&cks);
+        assert_eq!(c, 42);
+    }
+
+    #[test]
+    fn test_mapped_hl_compressed_server_key() {
+        let config = config();
+        let cks = ClientKey::generate(config);
+        let compressed_sks = CompressedServerKey::new(&cks);
+
+        let bytes = to_mapped_bytes(&compressed_sks);
+        let mapped = Mapped::<CompressedServerKey>::from_bytes(&bytes, METADATA_LIMIT).unwrap();
+
+        let loaded = mapped.copy_out_conformant(&config.into()).unwrap();
+        assert_eq!(serialized(&loaded), serialized(&compressed_sks));
+    }
+}
+
+#[test]
+fn test_mapped_invalid_files() {
+    let (_cks, sks) = crate::shortint::gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let bytes = to_mapped_bytes(&sks);
+
+    type MappedKey = Mapped<crate::shortint::ServerKey>;
+
+    assert!(MappedKey::from_bytes(&bytes[..PREAMBLE_SIZE - 1], METADATA_LIMIT).is_err());
+
+    let mut bad_magic = bytes.clone();
+    bad_magic[0] ^= 1;
+    assert!(MappedKey::from_bytes(&bad_magic, METADATA_LIMIT).is_err());
+
+    let mut bad_version = bytes.clone();
+    bad_version[MAGIC.len()] ^= 0xff;
+    assert!(MappedKey::from_bytes(&bad_version, METADATA_LIMIT).is_err());
+
+    // Metadata above the limit
+    assert!(MappedKey::from_bytes(&bytes, 8).is_err());
+
+    // The metadata holds the name of the type, opening the key as another type is an error
+    assert!(
+        Mapped::<crate::shortint::CompressedServerKey>::from_bytes(&bytes, METADATA_LIMIT).is_err()
+    );
+
+    // Truncated sections are detected when the key is copied out
+    let truncated = MappedKey::from_bytes(&bytes[..bytes.len() - 1], METADATA_LIMIT).unwrap();
+    assert!(truncated.copy_out().is_err());
+
+    let metadata_only = MappedKey::from_bytes(&bytes[..SECTION_ALIGNMENT], METADATA_LIMIT).unwrap();
+    assert!(metadata_only.copy_out().is_err());
+}
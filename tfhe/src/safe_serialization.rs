@@ -6,6 +6,7 @@
 
 use std::borrow::Cow;
 use std::fmt::Display;
+use std::marker::PhantomData;
 
 use crate::conformance::ParameterSetConformant;
 use crate::named::Named;
@@ -23,6 +24,16 @@ const SERIALIZATION_VERSION: &str = "0.5";
 /// scheme is upgraded.
 const VERSIONING_VERSION: &str = "0.1";
 
+/// The header of a stream of items of type `T` holds the name `Stream<T::NAME>`, so that a stream
+/// cannot be mistaken for a single object.
+const STREAM_NAME_PREFIX: &str = "Stream<";
+
+/// Written before each item of a stream
+const STREAM_ITEM_MARKER: u8 = 1;
+
+/// Written at the end of a stream, followed by the number of items
+const STREAM_END_MARKER: u8 = 0;
+
 /// This is the current version of this crate. This is used to be able to reject unversioned data
 /// if they come from a previous version.
 const CRATE_VERSION: &str = concat!(
@@ -97,8 +108,39 @@ impl SerializationHeader {
         }
     }
 
+    /// Creates the header of a stream of items of type `T`, see [`StreamSerializer`]
+    fn new_stream<T: Named>(versioning_mode: SerializationVersioningMode) -> Self {
+        Self {
+            header_version: Cow::Borrowed(SERIALIZATION_VERSION),
+            versioning_mode,
+            name: Cow::Owned(format!("{STREAM_NAME_PREFIX}{}>", T::NAME)),
+        }
+    }
+
+    /// Checks the validity of the header of a stream of items of type `T`
+    fn validate_stream<T: Named>(&self) -> Result<(), String> {
+        let item_name = self
+            .name
+            .strip_prefix(STREAM_NAME_PREFIX)
+            .and_then(|name| name.strip_suffix('>'))
+            .ok_or_else(|| {
+                format!(
+                    "On deserialization, expected a stream of {}, got type {}",
+                    T::NAME,
+                    self.name
+                )
+            })?;
+
+        self.validate_with_name::<T>(item_name)
+    }
+
     /// Checks the validity of the header
     fn validate<T: Named>(&self) -> Result<(), String> {
+        self.validate_with_name::<T>(&self.name)
+    }
+
+    /// Checks the validity of the header, using `name` as the name of the serialized type
+    fn validate_with_name<T: Named>(&self, name: &str) -> Result<(), String> {
         match &self.versioning_mode {
             SerializationVersioningMode::Versioned { versioning_version } => {
                 // For the moment there is only one versioning scheme, so another value is
@@ -114,23 +156,21 @@ got version {versioning_version}"
             SerializationVersioningMode::Unversioned { crate_version } => {
                 if crate_version != CRATE_VERSION {
                     return Err(format!(
-                "This {} has been saved from TFHE-rs v{crate_version}, without versioning information. \
-Please use the versioned serialization mode for backward compatibility.",
-                self.name
+                "This {name} has been saved from TFHE-rs v{crate_version}, without versioning information. \
+Please use the versioned serialization mode for backward compatibility."
             ));
                 }
             }
         }
 
-        if self.name != T::NAME
+        if name != T::NAME
             && T::BACKWARD_COMPATIBILITY_ALIASES
                 .iter()
-                .all(|alias| self.name != *alias)
+                .all(|alias| name != *alias)
         {
             return Err(format!(
-                "On deserialization, expected type {}, got type {}",
+                "On deserialization, expected type {}, got type {name}",
                 T::NAME,
-                self.name
             ));
         }
 
@@ -272,6 +312,102 @@ impl SerializationConfig {
 
         Ok(())
     }
+
+    /// Starts a stream of objects of type `T` in a [writer](std::io::Write), based on the current
+    /// config.
+    ///
+    /// The header of the stream is written immediately, the items are then written one by one with
+    /// [`StreamSerializer::write`]. The size limit of the config applies to each item
+    /// individually. The written bytes can be deserialized using
+    /// [`DeserializationConfig::deserialize_stream_from`].
+    pub fn serialize_stream_into<T: Serialize + Versionize + Named, W: std::io::Write>(
+        self,
+        mut writer: W,
+    ) -> bincode::Result<StreamSerializer<T, W>> {
+        let header = SerializationHeader::new_stream::<T>(self.versioned.clone());
+
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .serialize_into(&mut writer, &header)?;
+
+        Ok(StreamSerializer {
+            writer,
+            config: self,
+            count: 0,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Writes a stream of objects of the same type, one item at a time.
+///
+/// This is created by [`SerializationConfig::serialize_stream_into`]. Contrary to
+/// [`SerializationConfig::serialize_into`], the whole sequence never has to be in memory.
+///
+/// The stream must be terminated with [`Self::finish`], otherwise it will be detected as
+/// truncated on deserialization.
+pub struct StreamSerializer<T, W> {
+    writer: W,
+    config: SerializationConfig,
+    count: u64,
+    _marker: PhantomData<fn(&T)>,
+}
+
+impl<T: Serialize + Versionize + Named, W: std::io::Write> StreamSerializer<T, W> {
+    /// Writes an item at the end of the stream
+    pub fn write(&mut self, item: &T) -> bincode::Result<()> {
+        let options = bincode::DefaultOptions::new().with_fixint_encoding();
+
+        options.serialize_into(&mut self.writer, &STREAM_ITEM_MARKER)?;
+
+        let options = options.with_limit(0); // Force to explicitly set the limit
+        match self.config.serialized_size_limit {
+            Some(size_limit) => Self::write_item(
+                options.with_limit(size_limit),
+                &mut self.writer,
+                &self.config.versioned,
+                item,
+            )?,
+            None => Self::write_item(
+                options.with_no_limit(),
+                &mut self.writer,
+                &self.config.versioned,
+                item,
+            )?,
+        }
+
+        self.count += 1;
+        Ok(())
+    }
+
+    fn write_item(
+        options: impl Options,
+        writer: &mut W,
+        versioned: &SerializationVersioningMode,
+        item: &T,
+    ) -> bincode::Result<()> {
+        match versioned {
+            SerializationVersioningMode::Versioned { .. } => {
+                options.serialize_into(writer, &item.versionize())
+            }
+            SerializationVersioningMode::Unversioned { .. } => options.serialize_into(writer, item),
+        }
+    }
+
+    /// Returns the number of items written so far
+    pub fn items_written(&self) -> u64 {
+        self.count
+    }
+
+    /// Terminates the stream and returns the inner writer
+    pub fn finish(mut self) -> bincode::Result<W> {
+        let options = bincode::DefaultOptions::new().with_fixint_encoding();
+
+        options.serialize_into(&mut self.writer, &STREAM_END_MARKER)?;
+        options.serialize_into(&mut self.writer, &self.count)?;
+
+        Ok(self.writer)
+    }
 }
 
 /// A configuration used to Serialize *TFHE-rs* objects. This configuration decides
@@ -368,6 +504,32 @@ impl NonConformantDeserializationConfig {
         }
     }
 
+    /// Starts reading a stream written by [`SerializationConfig::serialize_stream_into`] from a
+    /// [reader](std::io::Read).
+    ///
+    /// The header of the stream is read and checked immediately, the items can then be read
+    /// one at a time by iterating on the returned [`StreamDeserializer`]. The size limit of the
+    /// config applies to each item individually. Conformance checks are skipped.
+    pub fn deserialize_stream_from<T: DeserializeOwned + Unversionize + Named, R: std::io::Read>(
+        self,
+        mut reader: R,
+    ) -> Result<StreamDeserializer<'static, T, R>, String> {
+        let header = self.deserialize_header(&mut reader)?;
+
+        if self.validate_header {
+            header.validate_stream::<T>()?;
+        }
+
+        Ok(StreamDeserializer {
+            reader,
+            versioning_mode: header.versioning_mode,
+            serialized_size_limit: self.serialized_size_limit,
+            conformance_check: None,
+            count: 0,
+            done: false,
+        })
+    }
+
     /// Enables the conformance check on an existing config.
     pub fn enable_conformance(self) -> DeserializationConfig {
         DeserializationConfig {
@@ -454,6 +616,139 @@ impl DeserializationConfig {
 
         Ok(deser)
     }
+
+    /// Starts reading a stream written by [`SerializationConfig::serialize_stream_into`] from a
+    /// [reader](std::io::Read).
+    ///
+    /// The header of the stream is read and checked immediately, the items can then be read
+    /// one at a time by iterating on the returned [`StreamDeserializer`]. The size limit of the
+    /// config applies to each item individually, and each item is checked to be conformant with
+    /// `parameter_set`.
+    pub fn deserialize_stream_from<
+        T: DeserializeOwned + Unversionize + Named + ParameterSetConformant,
+        R: std::io::Read,
+    >(
+        self,
+        reader: R,
+        parameter_set: &T::ParameterSet,
+    ) -> Result<StreamDeserializer<'_, T, R>, String> {
+        let stream = self.disable_conformance().deserialize_stream_from(reader)?;
+
+        Ok(StreamDeserializer {
+            conformance_check: Some(Box::new(move |item: &T| item.is_conformant(parameter_set))),
+            ..stream
+        })
+    }
+}
+
+type ConformanceCheck<'params, T> = Box<dyn Fn(&T) -> bool + 'params>;
+
+/// Reads a stream of objects of the same type, one item at a time.
+///
+/// This is created by [`DeserializationConfig::deserialize_stream_from`] or
+/// [`NonConformantDeserializationConfig::deserialize_stream_from`], and iterates over the
+/// deserialized items. An error is returned if an item cannot be deserialized, is not conformant,
+/// or if the stream has been truncated. The iteration stops after the first error.
+pub struct StreamDeserializer<'params, T, R> {
+    reader: R,
+    versioning_mode: SerializationVersioningMode,
+    serialized_size_limit: Option<u64>,
+    conformance_check: Option<ConformanceCheck<'params, T>>,
+    count: u64,
+    done: bool,
+}
+
+impl<T: DeserializeOwned + Unversionize + Named, R: std::io::Read> StreamDeserializer<'_, T, R> {
+    /// Returns the number of items read so far
+    pub fn items_read(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the inner reader. If the stream has been read until the end, the reader is
+    /// positioned right after it.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_item(&mut self) -> Result<Option<T>, String> {
+        let options = bincode::DefaultOptions::new().with_fixint_encoding();
+
+        let marker: u8 = options
+            .deserialize_from(&mut self.reader)
+            .map_err(|err| err.to_string())?;
+
+        match marker {
+            STREAM_ITEM_MARKER => {}
+            STREAM_END_MARKER => {
+                let count: u64 = options
+                    .deserialize_from(&mut self.reader)
+                    .map_err(|err| err.to_string())?;
+
+                if count != self.count {
+                    return Err(format!(
+                        "Invalid stream, {} items were read but {count} were written",
+                        self.count
+                    ));
+                }
+
+                return Ok(None);
+            }
+            _ => return Err(format!("Invalid stream, unexpected item marker {marker}")),
+        }
+
+        let options = options.with_limit(0); // Force to explicitly set the limit
+        let item: T = match self.serialized_size_limit {
+            Some(size_limit) => self.deserialize_item(options.with_limit(size_limit)),
+            None => self.deserialize_item(options.with_no_limit()),
+        }?;
+
+        if let Some(is_conformant) = &self.conformance_check {
+            if !is_conformant(&item) {
+                return Err(format!(
+                    "Deserialized item {} of type {} not conformant with given parameter set",
+                    self.count,
+                    T::NAME
+                ));
+            }
+        }
+
+        self.count += 1;
+        Ok(Some(item))
+    }
+
+    fn deserialize_item(&mut self, options: impl Options) -> Result<T, String> {
+        match self.versioning_mode {
+            SerializationVersioningMode::Versioned { .. } => {
+                let deser_versioned = options
+                    .deserialize_from(&mut self.reader)
+                    .map_err(|err| err.to_string())?;
+
+                T::unversionize(deser_versioned).map_err(|e| e.to_string())
+            }
+            SerializationVersioningMode::Unversioned { .. } => options
+                .deserialize_from(&mut self.reader)
+                .map_err(|err| err.to_string()),
+        }
+    }
+}
+
+impl<T: DeserializeOwned + Unversionize + Named, R: std::io::Read> Iterator
+    for StreamDeserializer<'_, T, R>
+{
+    type Item = Result<T, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.read_item();
+        if !matches!(result, Ok(Some(_))) {
+            self.done = true;
+        }
+
+        result.transpose()
+    }
 }
 
 /// Serialize an object with the default configuration (with size limit and versioning).
@@ -674,6 +969,73 @@ mod test_shortint {
 
         assert!(safe_deserialize::<Baz>(foo_ser.as_slice(), 0x1000).is_err());
     }
+
+    #[test]
+    fn safe_deserialization_ct_stream() {
+        let (ck, _sk) = gen_keys(TEST_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+
+        let msgs = [0u64, 1, 2, 3, 2];
+
+        let config = SerializationConfig::new(1 << 20);
+        let item_size = config.serialized_size(&ck.encrypt(0)).unwrap();
+
+        let mut stream = config
+            .serialize_stream_into::<Ciphertext, _>(vec![])
+            .unwrap();
+        for msg in msgs {
+            stream.write(&ck.encrypt(msg)).unwrap();
+        }
+        assert_eq!(stream.items_written(), msgs.len() as u64);
+        let buffer = stream.finish().unwrap();
+
+        let params =
+            TEST_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128.to_shortint_conformance_param();
+
+        // The limit applies to each item, not to the whole stream
+        let dec: Vec<u64> = DeserializationConfig::new(item_size)
+            .deserialize_stream_from::<Ciphertext, _>(buffer.as_slice(), &params)
+            .unwrap()
+            .map(|ct| ck.decrypt(&ct.unwrap()))
+            .collect();
+        assert_eq!(dec, msgs);
+
+        // Non conformant items are rejected, and the iteration stops after the first error
+        let wrong_params =
+            TEST_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128.to_shortint_conformance_param();
+        let results: Vec<_> = DeserializationConfig::new(1 << 20)
+            .deserialize_stream_from::<Ciphertext, _>(buffer.as_slice(), &wrong_params)
+            .unwrap()
+            .collect();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+
+        // Truncated streams are detected
+        let truncated = &buffer[..buffer.len() - 9];
+        let results: Vec<_> = DeserializationConfig::new(1 << 20)
+            .deserialize_stream_from::<Ciphertext, _>(truncated, &params)
+            .unwrap()
+            .collect();
+        assert_eq!(results.len(), msgs.len() + 1);
+        assert!(results.last().unwrap().is_err());
+
+        // A stream is not a single object
+        assert!(safe_deserialize::<Ciphertext>(buffer.as_slice(), 1 << 20).is_err());
+
+        // Single objects are not streams
+        let mut single = vec![];
+        safe_serialize(&ck.encrypt(1), &mut single, 1 << 20).unwrap();
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_stream_from::<Ciphertext, _>(single.as_slice(), &params)
+            .is_err());
+
+        // Items above the limit are rejected
+        let results: Vec<_> = DeserializationConfig::new(item_size / 2)
+            .deserialize_stream_from::<Ciphertext, _>(buffer.as_slice(), &params)
+            .unwrap()
+            .collect();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
 }
 
 #[cfg(all(test, feature = "integer"))]
@@ -688,9 +1050,108 @@ mod test_integer {
     };
     use crate::{
         set_server_key, CompactCiphertextList, CompactCiphertextListConformanceParams,
-        CompactPublicKey, FheUint8,
+        CompactPublicKey, FheBool, FheBoolConformanceParams, FheUint8, FheUint8ConformanceParams,
     };
 
+    #[test]
+    fn safe_deserialization_ct_stream() {
+        let (client_key, sks) = generate_keys(ConfigBuilder::default().build());
+
+        let msgs = [27u8, 10, 3, 255];
+
+        let mut stream = SerializationConfig::new(1 << 20)
+            .disable_versioning()
+            .serialize_stream_into::<FheUint8, _>(vec![])
+            .unwrap();
+        for msg in msgs {
+            stream.write(&FheUint8::encrypt(msg, &client_key)).unwrap();
+        }
+        let buffer = stream.finish().unwrap();
+
+        let mut bool_stream = SerializationConfig::new(1 << 20)
+            .serialize_stream_into::<FheBool, _>(buffer)
+            .unwrap();
+        for msg in msgs {
+            bool_stream
+                .write(&FheBool::encrypt(msg % 2 == 1, &client_key))
+                .unwrap();
+        }
+        let buffer = bool_stream.finish().unwrap();
+
+        // Streams can be read one after the other from the same reader
+        let mut reader = buffer.as_slice();
+
+        // The name of the items is checked
+        assert!(DeserializationConfig::new(1 << 20)
+            .disable_conformance()
+            .deserialize_stream_from::<FheBool, _>(reader)
+            .is_err());
+
+        assert!(DeserializationConfig::new(1 << 20)
+            .deserialize_stream_from::<FheUint8, _>(
+                reader,
+                &FheUint8ConformanceParams::from(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128)
+            )
+            .unwrap()
+            .all(|ct| ct.is_err()));
+
+        let params = FheUint8ConformanceParams::from(&sks);
+        let mut uint_stream = DeserializationConfig::new(1 << 20)
+            .deserialize_stream_from::<FheUint8, _>(&mut reader, &params)
+            .unwrap();
+        let dec: Vec<u8> = uint_stream
+            .by_ref()
+            .map(|ct| ct.unwrap().decrypt(&client_key))
+            .collect();
+        assert_eq!(uint_stream.items_read(), msgs.len() as u64);
+        assert_eq!(dec, msgs);
+
+        let params = FheBoolConformanceParams::from(&sks);
+        let dec: Vec<bool> = DeserializationConfig::new(1 << 20)
+            .deserialize_stream_from::<FheBool, _>(reader, &params)
+            .unwrap()
+            .map(|ct| ct.unwrap().decrypt(&client_key))
+            .collect();
+        assert_eq!(dec, msgs.iter().map(|msg| msg % 2 == 1).collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "strings")]
+    #[test]
+    fn safe_deserialization_string_stream() {
+        use crate::{FheAsciiString, FheAsciiStringConformanceParams};
+
+        let (client_key, sks) = generate_keys(ConfigBuilder::default().build());
+
+        let msgs = ["tfhe", "", "streaming"];
+
+        let mut stream = SerializationConfig::new(1 << 20)
+            .serialize_stream_into::<FheAsciiString, _>(vec![])
+            .unwrap();
+        for msg in msgs {
+            stream
+                .write(&FheAsciiString::try_encrypt(msg, &client_key).unwrap())
+                .unwrap();
+        }
+        let buffer = stream.finish().unwrap();
+
+        let params = FheAsciiStringConformanceParams::from(&sks);
+        let dec: Vec<String> = DeserializationConfig::new(1 << 20)
+            .deserialize_stream_from::<FheAsciiString, _>(buffer.as_slice(), &params)
+            .unwrap()
+            .map(|ct| ct.unwrap().decrypt(&client_key))
+            .collect();
+        assert_eq!(dec, msgs);
+
+        let params =
+            params.with_len_constraint(ListSizeConstraint::try_size_in_range(0, 4).unwrap());
+        let results: Vec<_> = DeserializationConfig::new(1 << 20)
+            .deserialize_stream_from::<FheAsciiString, _>(buffer.as_slice(), &params)
+            .unwrap()
+            .collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok() && results[1].is_ok() && results[2].is_err());
+    }
+
     #[test]
     fn safe_deserialization_ct_list() {
         let (client_key, sks) = generate_keys(ConfigBuilder::default().build());
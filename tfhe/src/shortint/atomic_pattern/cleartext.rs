@@ -0,0 +1,264 @@
+//! #Warning debug-only
+//!
+//! An atomic pattern that evaluates lookup tables in the clear, to quickly debug and test
+//! programs.
+//!
+//! The server key holds the secret key of the client: lookup tables are applied by decrypting the
+//! input ciphertext, evaluating the table on the cleartext and replacing the encrypted value,
+//! without any keyswitch or bootstrap. The degrees and noise levels of the ciphertexts are still
+//! updated by the [`ServerKey`](crate::shortint::ServerKey) exactly as with a real atomic pattern,
+//! and each skipped PBS is counted by the `pbs-stats` feature.
+//!
+//! This is *NOT safe*: anyone holding such a server key can decrypt all the ciphertexts of the
+//! client.
+
+use tfhe_csprng::seeders::Seed;
+
+use super::{AtomicPattern, AtomicPatternKind, AtomicPatternMut};
+use crate::core_crypto::prelude::{
+    decrypt_lwe_ciphertext, LweCiphertextOwned, LweDimension, LweSecretKeyOwned,
+    MsDecompressionType, Plaintext,
+};
+use crate::shortint::ciphertext::{CompressedModulusSwitchedCiphertext, Degree};
+use crate::shortint::oprf::create_random_from_seed;
+#[cfg(feature = "pbs-stats")]
+use crate::shortint::server_key::pbs_stats::{Ordering, PBS_COUNT};
+#[cfg(feature = "profiling")]
+use crate::shortint::server_key::profiling;
+use crate::shortint::server_key::{
+    evaluate_lookup_table_in_clear, evaluate_many_lookup_table_in_clear, LookupTableOwned,
+    LookupTableSize, ManyLookupTableOwned,
+};
+use crate::shortint::{Ciphertext, CiphertextModulus, ClientKey, PaddingBit, ShortintEncoding};
+
+/// The server key elements of an atomic pattern that evaluates lookup tables in the clear.
+///
+/// Lookup tables have the size of the ones of the client key parameters, so that they give the
+/// same results and degrees as with a real server key.
+///
+/// # Warning
+///
+/// This key contains the secret key used for encryption, it must only be used for tests and
+/// debugging.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CleartextAtomicPatternServerKey {
+    lwe_secret_key: LweSecretKeyOwned<u64>,
+    lookup_table_size: LookupTableSize,
+    ciphertext_modulus: CiphertextModulus,
+    kind: AtomicPatternKind,
+}
+
+impl CleartextAtomicPatternServerKey {
+    /// Creates the server key elements from the client key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext modulus of the parameters is not the native one.
+    pub fn new(cks: &ClientKey) -> Self {
+        let parameters = cks.parameters();
+        let ciphertext_modulus = parameters.ciphertext_modulus();
+
+        assert!(
+            ciphertext_modulus.is_native_modulus(),
+            "The cleartext atomic pattern only supports the native ciphertext modulus"
+        );
+
+        Self {
+            lwe_secret_key: LweSecretKeyOwned::from_container(
+                cks.encryption_key().as_ref().to_vec(),
+            ),
+            lookup_table_size: LookupTableSize::new(
+                parameters.glwe_dimension().to_glwe_size(),
+                parameters.polynomial_size(),
+            ),
+            ciphertext_modulus,
+            kind: parameters.atomic_pattern(),
+        }
+    }
+
+    /// Replaces the value encrypted in `ct` by `new_plaintext`, without adding any noise
+    fn replace_plaintext(&self, ct: &mut LweCiphertextOwned<u64>, new_plaintext: u64) {
+        let plaintext = decrypt_lwe_ciphertext(&self.lwe_secret_key, &*ct);
+        let body = ct.get_mut_body().data;
+
+        *body = body.wrapping_sub(plaintext.0).wrapping_add(new_plaintext);
+    }
+
+    /// Returns the value encrypted in `ct`, including the carries and the padding bit
+    fn decrypt_and_decode(&self, ct: &Ciphertext) -> u64 {
+        let encoding = ShortintEncoding {
+            ciphertext_modulus: self.ciphertext_modulus,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            padding_bit: PaddingBit::Yes,
+        };
+
+        encoding
+            .decode(decrypt_lwe_ciphertext(&self.lwe_secret_key, &ct.ct))
+            .0
+    }
+}
+
+/// Records a PBS that has been skipped, so that statistics are the same as with a real atomic
+/// pattern
+fn record_skipped_pbs() {
+    #[cfg(feature = "pbs-stats")]
+    let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
+
+    #[cfg(feature = "profiling")]
+    profiling::record_blind_rotation(std::time::Duration::ZERO);
+}
+
+impl AtomicPattern for CleartextAtomicPatternServerKey {
+    fn ciphertext_lwe_dimension(&self) -> LweDimension {
+        self.lwe_secret_key.lwe_dimension()
+    }
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.ciphertext_modulus
+    }
+
+    fn ciphertext_decompression_method(&self) -> MsDecompressionType {
+        MsDecompressionType::ClassicPbs
+    }
+
+    fn apply_lookup_table_assign(&self, ct: &mut Ciphertext, acc: &LookupTableOwned) {
+        record_skipped_pbs();
+
+        let value = self.decrypt_and_decode(ct);
+        let result =
+            evaluate_lookup_table_in_clear(value, acc, ct.message_modulus, ct.carry_modulus);
+
+        self.replace_plaintext(&mut ct.ct, result);
+    }
+
+    fn apply_many_lookup_table(
+        &self,
+        ct: &Ciphertext,
+        lut: &ManyLookupTableOwned,
+    ) -> Vec<Ciphertext> {
+        record_skipped_pbs();
+
+        let value = self.decrypt_and_decode(ct);
+
+        evaluate_many_lookup_table_in_clear(value, lut, ct.message_modulus, ct.carry_modulus)
+            .into_iter()
+            .zip(lut.per_function_output_degree.iter())
+            .map(|(result, output_degree)| {
+                let mut output = ct.clone();
+                self.replace_plaintext(&mut output.ct, result);
+                output.degree = *output_degree;
+                output
+            })
+            .collect()
+    }
+
+    fn lookup_table_size(&self) -> LookupTableSize {
+        self.lookup_table_size
+    }
+
+    fn kind(&self) -> AtomicPatternKind {
+        self.kind
+    }
+
+    fn generate_oblivious_pseudo_random(
+        &self,
+        seed: Seed,
+        random_bits_count: u64,
+        full_bits_count: u64,
+    ) -> (LweCiphertextOwned<u64>, Degree) {
+        assert!(
+            random_bits_count <= full_bits_count,
+            "The number of random bits asked for (={random_bits_count}) is bigger than full_bits_count (={full_bits_count})"
+        );
+
+        record_skipped_pbs();
+
+        // As in the real OPRF, the random value is taken from the phase of a ciphertext whose mask
+        // is derived from the seed
+        let mut ct =
+            create_random_from_seed::<u64>(seed, self.ciphertext_lwe_dimension().to_lwe_size());
+        let Plaintext(phase) = decrypt_lwe_ciphertext(&self.lwe_secret_key, &ct);
+
+        let random_value = phase
+            .checked_shr(64 - random_bits_count as u32)
+            .unwrap_or(0);
+        let delta = 1_u64 << (64 - full_bits_count);
+
+        self.replace_plaintext(&mut ct, random_value * delta);
+
+        (ct, Degree::new((1 << random_bits_count) - 1))
+    }
+
+    fn deterministic_execution(&self) -> bool {
+        true
+    }
+
+    fn switch_modulus_and_compress(&self, _ct: &Ciphertext) -> CompressedModulusSwitchedCiphertext {
+        panic!("The cleartext atomic pattern does not support the modulus switch compression")
+    }
+
+    fn decompress_and_apply_lookup_table(
+        &self,
+        _compressed_ct: &CompressedModulusSwitchedCiphertext,
+        _lut: &LookupTableOwned,
+    ) -> Ciphertext {
+        panic!("The cleartext atomic pattern does not support the modulus switch compression")
+    }
+}
+
+impl AtomicPatternMut for CleartextAtomicPatternServerKey {
+    fn set_deterministic_execution(&mut self, _new_deterministic_execution: bool) {
+        // Evaluating lookup tables in the clear is always deterministic
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shortint::atomic_pattern::AtomicPatternParameters;
+    use crate::shortint::parameters::test_params::TEST_PARAM_MESSAGE_2_CARRY_2_KS32_PBS_TUNIFORM_2M128;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use crate::shortint::{gen_keys, ServerKey};
+    use tfhe_csprng::seeders::Seed;
+
+    #[test]
+    fn test_cleartext_ap_matches_real_ap() {
+        for params in [
+            AtomicPatternParameters::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+            AtomicPatternParameters::from(TEST_PARAM_MESSAGE_2_CARRY_2_KS32_PBS_TUNIFORM_2M128),
+        ] {
+            let (cks, sks) = gen_keys(params);
+            let simulation_sks = ServerKey::new_cleartext_simulation(&cks);
+
+            let f = |x: u64| (x * 3 + 1) % 4;
+            let f1 = |x: u64| x % 2;
+            let f2 = |x: u64| x / 2;
+            let lut = sks.generate_lookup_table(f);
+            let many_lut = sks.generate_many_lookup_table(&[&f1, &f2]);
+
+            for msg in 0..4 {
+                let ct = sks.unchecked_scalar_mul(&cks.encrypt(msg), 3);
+
+                let expected = sks.apply_lookup_table(&ct, &lut);
+                let simulated = simulation_sks.apply_lookup_table(&ct, &lut);
+                assert!(!simulated.is_trivial());
+                assert_eq!(cks.decrypt(&simulated), cks.decrypt(&expected));
+                assert_eq!(simulated.degree, expected.degree);
+                assert_eq!(simulated.noise_level(), expected.noise_level());
+
+                let expected = sks.apply_many_lookup_table(&ct, &many_lut);
+                let simulated = simulation_sks.apply_many_lookup_table(&ct, &many_lut);
+                assert_eq!(simulated.len(), expected.len());
+                for (simulated, expected) in simulated.iter().zip(expected.iter()) {
+                    assert_eq!(cks.decrypt(simulated), cks.decrypt(expected));
+                    assert_eq!(simulated.degree, expected.degree);
+                }
+
+                let random =
+                    simulation_sks.generate_oblivious_pseudo_random(Seed(u128::from(msg)), 2);
+                assert!(cks.decrypt_message_and_carry(&random) < 4);
+                assert_eq!(random.degree.get(), 3);
+            }
+        }
+    }
+}
@@ -7,6 +7,8 @@ use super::{
     AtomicPatternMut,
 };
 use crate::conformance::ParameterSetConformant;
+#[cfg(feature = "noise-tracking")]
+use crate::core_crypto::commons::dispersion::Variance;
 use crate::core_crypto::prelude::{
     allocate_and_generate_new_lwe_keyswitch_key, extract_lwe_sample_from_glwe_ciphertext,
     keyswitch_lwe_ciphertext_with_scalar_change, CiphertextModulus as CoreCiphertextModulus,
@@ -17,6 +19,10 @@ use crate::shortint::backward_compatibility::atomic_pattern::KS32AtomicPatternSe
 use crate::shortint::ciphertext::{CompressedModulusSwitchedCiphertext, Degree, NoiseLevel};
 use crate::shortint::client_key::atomic_pattern::KS32AtomicPatternClientKey;
 use crate::shortint::engine::ShortintEngine;
+#[cfg(feature = "noise-tracking")]
+use crate::shortint::noise_tracking::{
+    bootstrap_variance, keyswitch_variance, modulus_switch_variance, AtomicPatternNoise,
+};
 use crate::shortint::oprf::generate_pseudo_random_from_pbs;
 use crate::shortint::parameters::KeySwitch32PBSParameters;
 use crate::shortint::server_key::{
@@ -145,11 +151,13 @@ impl AtomicPattern for KS32AtomicPatternServerKey {
                 self.intermediate_ciphertext_modulus(),
             );
 
-            keyswitch_lwe_ciphertext_with_scalar_change(
-                &self.key_switching_key,
-                &ct.ct,
-                &mut ciphertext_buffer,
-            );
+            apply_keyswitch(|| {
+                keyswitch_lwe_ciphertext_with_scalar_change(
+                    &self.key_switching_key,
+                    &ct.ct,
+                    &mut ciphertext_buffer,
+                )
+            });
 
             apply_programmable_bootstrap(
                 &self.bootstrapping_key,
@@ -207,11 +215,13 @@ impl AtomicPattern for KS32AtomicPatternServerKey {
                     self.intermediate_ciphertext_modulus(),
                 );
 
-                keyswitch_lwe_ciphertext_with_scalar_change(
-                    &self.key_switching_key,
-                    &ct.ct,
-                    &mut ciphertext_buffer,
-                );
+                apply_keyswitch(|| {
+                    keyswitch_lwe_ciphertext_with_scalar_change(
+                        &self.key_switching_key,
+                        &ct.ct,
+                        &mut ciphertext_buffer,
+                    )
+                });
                 switch_modulus_and_compress(ciphertext_buffer.as_view(), &self.bootstrapping_key)
             });
 
@@ -255,6 +265,22 @@ impl AtomicPattern for KS32AtomicPatternServerKey {
             compressed_ct.atomic_pattern,
         )
     }
+
+    #[cfg(feature = "noise-tracking")]
+    fn estimated_noise(&self) -> Option<AtomicPatternNoise> {
+        let modulus = self.ciphertext_modulus().raw_modulus_float();
+        let intermediate_modulus = self
+            .key_switching_key
+            .ciphertext_modulus()
+            .raw_modulus_float();
+        let keyswitch = keyswitch_variance(&self.key_switching_key, modulus);
+        let modulus_switch = modulus_switch_variance(&self.bootstrapping_key, intermediate_modulus);
+
+        Some(AtomicPatternNoise {
+            output_variance: bootstrap_variance(&self.bootstrapping_key, modulus)?,
+            pre_blind_rotation_additive_variance: Variance(keyswitch.0 + modulus_switch.0),
+        })
+    }
 }
 
 impl AtomicPatternMut for KS32AtomicPatternServerKey {
@@ -279,11 +305,13 @@ impl KS32AtomicPatternServerKey {
             );
 
             // Compute a key switch
-            keyswitch_lwe_ciphertext_with_scalar_change(
-                &self.key_switching_key,
-                &ct.ct,
-                &mut ciphertext_buffer,
-            );
+            apply_keyswitch(|| {
+                keyswitch_lwe_ciphertext_with_scalar_change(
+                    &self.key_switching_key,
+                    &ct.ct,
+                    &mut ciphertext_buffer,
+                )
+            });
 
             apply_ms_blind_rotate(
                 &self.bootstrapping_key,
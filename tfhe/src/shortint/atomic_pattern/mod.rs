@@ -4,6 +4,8 @@
 //! For example, in TFHE the standard atomic pattern is the chain of n linear operations, a
 //! Keyswitch and a PBS.
 
+#[cfg(feature = "cleartext-simulation")]
+pub mod cleartext;
 pub mod compressed;
 pub mod ks32;
 pub mod standard;
@@ -23,6 +25,8 @@ use super::backward_compatibility::atomic_pattern::*;
 use super::ciphertext::{CompressedModulusSwitchedCiphertext, Degree};
 use super::client_key::atomic_pattern::AtomicPatternClientKey;
 use super::engine::ShortintEngine;
+#[cfg(feature = "noise-tracking")]
+use super::noise_tracking::AtomicPatternNoise;
 use super::parameters::{DynamicDistribution, KeySwitch32PBSParameters};
 use super::prelude::{DecompositionBaseLog, DecompositionLevelCount};
 use super::server_key::{
@@ -35,6 +39,8 @@ use super::{
     PBSParameters,
 };
 
+#[cfg(feature = "cleartext-simulation")]
+pub use cleartext::*;
 pub use ks32::*;
 pub use standard::*;
 
@@ -128,6 +134,12 @@ pub trait AtomicPattern {
         compressed_ct: &CompressedModulusSwitchedCiphertext,
         lut: &LookupTableOwned,
     ) -> Ciphertext;
+
+    /// Estimates the noise added by the application of the AP, `None` if it cannot be estimated
+    #[cfg(feature = "noise-tracking")]
+    fn estimated_noise(&self) -> Option<AtomicPatternNoise> {
+        None
+    }
 }
 
 pub trait AtomicPatternMut: AtomicPattern {
@@ -254,6 +266,11 @@ impl<T: AtomicPattern> AtomicPattern for &T {
     ) -> Ciphertext {
         (*self).decompress_and_apply_lookup_table(compressed_ct, lut)
     }
+
+    #[cfg(feature = "noise-tracking")]
+    fn estimated_noise(&self) -> Option<AtomicPatternNoise> {
+        (*self).estimated_noise()
+    }
 }
 
 /// The server key materials for all the supported Atomic Patterns
@@ -387,6 +404,15 @@ impl AtomicPattern for AtomicPatternServerKey {
             Self::Dynamic(ap) => ap.decompress_and_apply_lookup_table(compressed_ct, lut),
         }
     }
+
+    #[cfg(feature = "noise-tracking")]
+    fn estimated_noise(&self) -> Option<AtomicPatternNoise> {
+        match self {
+            Self::Standard(ap) => ap.estimated_noise(),
+            Self::KeySwitch32(ap) => ap.estimated_noise(),
+            Self::Dynamic(ap) => ap.estimated_noise(),
+        }
+    }
 }
 
 impl AtomicPatternMut for AtomicPatternServerKey {
@@ -575,6 +601,13 @@ impl From<KS32AtomicPatternServerKey> for AtomicPatternServerKey {
     }
 }
 
+#[cfg(feature = "cleartext-simulation")]
+impl From<CleartextAtomicPatternServerKey> for AtomicPatternServerKey {
+    fn from(value: CleartextAtomicPatternServerKey) -> Self {
+        Self::Dynamic(Box::new(value))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::shortint::parameters::test_params::TEST_PARAM_MESSAGE_2_CARRY_2_KS32_PBS_TUNIFORM_2M128;
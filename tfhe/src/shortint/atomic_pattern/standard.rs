@@ -7,6 +7,8 @@ use super::{
     AtomicPatternMut,
 };
 use crate::conformance::ParameterSetConformant;
+#[cfg(feature = "noise-tracking")]
+use crate::core_crypto::commons::dispersion::Variance;
 use crate::core_crypto::prelude::{
     allocate_and_generate_new_lwe_keyswitch_key, extract_lwe_sample_from_glwe_ciphertext,
     keyswitch_lwe_ciphertext, LweCiphertext, LweCiphertextOwned, LweDimension,
@@ -16,6 +18,10 @@ use crate::shortint::backward_compatibility::atomic_pattern::StandardAtomicPatte
 use crate::shortint::ciphertext::{CompressedModulusSwitchedCiphertext, Degree, NoiseLevel};
 use crate::shortint::client_key::atomic_pattern::StandardAtomicPatternClientKey;
 use crate::shortint::engine::ShortintEngine;
+#[cfg(feature = "noise-tracking")]
+use crate::shortint::noise_tracking::{
+    bootstrap_variance, keyswitch_variance, modulus_switch_variance, AtomicPatternNoise,
+};
 use crate::shortint::oprf::generate_pseudo_random_from_pbs;
 use crate::shortint::server_key::{
     decompress_and_apply_lookup_table, switch_modulus_and_compress, LookupTableOwned,
@@ -152,11 +158,13 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
 
             match self.pbs_order {
                 PBSOrder::KeyswitchBootstrap => {
-                    keyswitch_lwe_ciphertext(
-                        &self.key_switching_key,
-                        &ct.ct,
-                        &mut ciphertext_buffer,
-                    );
+                    apply_keyswitch(|| {
+                        keyswitch_lwe_ciphertext(
+                            &self.key_switching_key,
+                            &ct.ct,
+                            &mut ciphertext_buffer,
+                        )
+                    });
 
                     apply_programmable_bootstrap(
                         &self.bootstrapping_key,
@@ -175,11 +183,13 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
                         buffers,
                     );
 
-                    keyswitch_lwe_ciphertext(
-                        &self.key_switching_key,
-                        &ciphertext_buffer,
-                        &mut ct.ct,
-                    );
+                    apply_keyswitch(|| {
+                        keyswitch_lwe_ciphertext(
+                            &self.key_switching_key,
+                            &ciphertext_buffer,
+                            &mut ct.ct,
+                        )
+                    });
                 }
             }
         });
@@ -234,7 +244,9 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
                     self.ciphertext_modulus(),
                 );
 
-                keyswitch_lwe_ciphertext(&self.key_switching_key, &ct, &mut ct_ksed);
+                apply_keyswitch(|| {
+                    keyswitch_lwe_ciphertext(&self.key_switching_key, &ct, &mut ct_ksed)
+                });
 
                 (ct_ksed, degree)
             }
@@ -249,11 +261,13 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
 
                 let input_ct = match self.pbs_order {
                     PBSOrder::KeyswitchBootstrap => {
-                        keyswitch_lwe_ciphertext(
-                            &self.key_switching_key,
-                            &ct.ct,
-                            &mut ciphertext_buffer,
-                        );
+                        apply_keyswitch(|| {
+                            keyswitch_lwe_ciphertext(
+                                &self.key_switching_key,
+                                &ct.ct,
+                                &mut ciphertext_buffer,
+                            )
+                        });
                         ciphertext_buffer.as_view()
                     }
                     PBSOrder::BootstrapKeyswitch => ct.ct.as_view(),
@@ -305,11 +319,13 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
                         buffers,
                     );
 
-                    keyswitch_lwe_ciphertext(
-                        &self.key_switching_key,
-                        &ciphertext_buffer,
-                        &mut output,
-                    );
+                    apply_keyswitch(|| {
+                        keyswitch_lwe_ciphertext(
+                            &self.key_switching_key,
+                            &ciphertext_buffer,
+                            &mut output,
+                        )
+                    });
                 }
             }
         });
@@ -323,6 +339,24 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
             compressed_ct.atomic_pattern,
         )
     }
+
+    #[cfg(feature = "noise-tracking")]
+    fn estimated_noise(&self) -> Option<AtomicPatternNoise> {
+        let modulus = self.ciphertext_modulus().raw_modulus_float();
+        let bootstrap = bootstrap_variance(&self.bootstrapping_key, modulus)?;
+        let keyswitch = keyswitch_variance(&self.key_switching_key, modulus);
+        let modulus_switch = modulus_switch_variance(&self.bootstrapping_key, modulus);
+
+        let (output_variance, pre_blind_rotation_additive_variance) = match self.pbs_order {
+            PBSOrder::KeyswitchBootstrap => (bootstrap, Variance(keyswitch.0 + modulus_switch.0)),
+            PBSOrder::BootstrapKeyswitch => (Variance(bootstrap.0 + keyswitch.0), modulus_switch),
+        };
+
+        Some(AtomicPatternNoise {
+            output_variance,
+            pre_blind_rotation_additive_variance,
+        })
+    }
 }
 
 impl AtomicPatternMut for StandardAtomicPatternServerKey {
@@ -345,7 +379,9 @@ impl StandardAtomicPatternServerKey {
                 engine.get_buffers(self.intermediate_lwe_dimension(), self.ciphertext_modulus());
 
             // Compute a key switch
-            keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ciphertext_buffer);
+            apply_keyswitch(|| {
+                keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ciphertext_buffer)
+            });
 
             apply_ms_blind_rotate(
                 &self.bootstrapping_key,
@@ -410,11 +446,13 @@ impl StandardAtomicPatternServerKey {
             let mut output_shortint_ct = ct.clone();
 
             // Compute a key switch
-            keyswitch_lwe_ciphertext(
-                &self.key_switching_key,
-                &tmp_lwe_ciphertext,
-                &mut output_shortint_ct.ct,
-            );
+            apply_keyswitch(|| {
+                keyswitch_lwe_ciphertext(
+                    &self.key_switching_key,
+                    &tmp_lwe_ciphertext,
+                    &mut output_shortint_ct.ct,
+                )
+            });
 
             output_shortint_ct.degree = *output_degree;
             output_shortint_ct.set_noise_level_to_nominal();
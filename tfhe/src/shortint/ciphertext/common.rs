@@ -1,4 +1,5 @@
 use super::super::CheckError;
+use crate::core_crypto::commons::dispersion::Variance;
 pub use crate::core_crypto::commons::parameters::PBSOrder;
 use crate::shortint::backward_compatibility::ciphertext::*;
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
@@ -111,6 +112,62 @@ impl std::ops::Mul<u64> for NoiseLevel {
     }
 }
 
+/// This tracks the estimated variance of the noise in a ciphertext, normalized to the torus.
+///
+/// Estimates are only computed when the `noise-tracking` feature is enabled, otherwise the
+/// variance is always unknown. The noise of ciphertexts produced by operations that are not
+/// modeled (e.g. public key encryption, decompression) is unknown.
+///
+/// Linear operations assume the noises of their operands are independent.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct EstimatedNoise(Option<Variance>);
+
+impl EstimatedNoise {
+    pub const UNKNOWN: Self = Self(None);
+
+    /// Creates an estimate with the given variance, the estimate is unknown if the
+    /// `noise-tracking` feature is disabled
+    pub fn new(variance: Variance) -> Self {
+        if cfg!(feature = "noise-tracking") {
+            Self(Some(variance))
+        } else {
+            Self::UNKNOWN
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(Variance(0.0))
+    }
+
+    pub fn variance(&self) -> Option<Variance> {
+        self.0
+    }
+}
+
+impl std::ops::Add for EstimatedNoise {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        match (self.0, rhs.0) {
+            (Some(lhs), Some(rhs)) => Self(Some(Variance(lhs.0 + rhs.0))),
+            _ => Self::UNKNOWN,
+        }
+    }
+}
+
+impl std::ops::Mul<u64> for EstimatedNoise {
+    type Output = Self;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        if rhs == 0 {
+            return Self::zero();
+        }
+
+        let scalar = rhs as f64;
+        Self(self.0.map(|var| Variance(var.0 * scalar * scalar)))
+    }
+}
+
 /// Maximum value that the degree can reach.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize, Versionize)]
 #[versionize(MaxDegreeVersions)]
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use tfhe_versionable::Versionize;
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[derive(Debug, Serialize, Deserialize, Versionize)]
 #[versionize(CiphertextVersions)]
 #[must_use]
 pub struct Ciphertext {
@@ -23,8 +23,34 @@ pub struct Ciphertext {
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
     pub atomic_pattern: AtomicPatternKind,
+    // Estimates are a property of the computation, they are not serialized nor compared
+    #[serde(skip)]
+    estimated_noise: EstimatedNoise,
 }
 
+impl PartialEq for Ciphertext {
+    fn eq(&self, other: &Self) -> bool {
+        let Self {
+            ct,
+            degree,
+            noise_level,
+            message_modulus,
+            carry_modulus,
+            atomic_pattern,
+            estimated_noise: _,
+        } = self;
+
+        *ct == other.ct
+            && *degree == other.degree
+            && *noise_level == other.noise_level
+            && *message_modulus == other.message_modulus
+            && *carry_modulus == other.carry_modulus
+            && *atomic_pattern == other.atomic_pattern
+    }
+}
+
+impl Eq for Ciphertext {}
+
 impl crate::named::Named for Ciphertext {
     const NAME: &'static str = "shortint::Ciphertext";
 }
@@ -40,6 +66,7 @@ impl ParameterSetConformant for Ciphertext {
             message_modulus,
             carry_modulus,
             atomic_pattern,
+            estimated_noise: _,
         } = self;
 
         ct.is_conformant(&param.ct_params)
@@ -65,6 +92,7 @@ impl Clone for Ciphertext {
             carry_modulus: src_carry_modulus,
             atomic_pattern: src_atomic_pattern,
             noise_level: src_noise_level,
+            estimated_noise: src_estimated_noise,
         } = self;
 
         Self {
@@ -74,6 +102,7 @@ impl Clone for Ciphertext {
             carry_modulus: *src_carry_modulus,
             atomic_pattern: *src_atomic_pattern,
             noise_level: *src_noise_level,
+            estimated_noise: *src_estimated_noise,
         }
     }
 
@@ -85,6 +114,7 @@ impl Clone for Ciphertext {
             carry_modulus: dst_carry_modulus,
             atomic_pattern: dst_atomic_pattern,
             noise_level: dst_noise_level,
+            estimated_noise: dst_estimated_noise,
         } = self;
 
         let Self {
@@ -94,6 +124,7 @@ impl Clone for Ciphertext {
             carry_modulus: src_carry_modulus,
             atomic_pattern: src_atomic_pattern,
             noise_level: src_noise_level,
+            estimated_noise: src_estimated_noise,
         } = source;
 
         if dst_ct.ciphertext_modulus() != src_ct.ciphertext_modulus()
@@ -108,6 +139,7 @@ impl Clone for Ciphertext {
         *dst_carry_modulus = *src_carry_modulus;
         *dst_atomic_pattern = *src_atomic_pattern;
         *dst_noise_level = *src_noise_level;
+        *dst_estimated_noise = *src_estimated_noise;
     }
 }
 
@@ -120,6 +152,13 @@ impl Ciphertext {
         carry_modulus: CarryModulus,
         atomic_pattern: AtomicPatternKind,
     ) -> Self {
+        // Only the noise of trivial ciphertexts is known without more context
+        let estimated_noise = if noise_level == NoiseLevel::ZERO {
+            EstimatedNoise::zero()
+        } else {
+            EstimatedNoise::UNKNOWN
+        };
+
         Self {
             ct,
             degree,
@@ -127,6 +166,7 @@ impl Ciphertext {
             message_modulus,
             carry_modulus,
             atomic_pattern,
+            estimated_noise,
         }
     }
     pub fn carry_is_empty(&self) -> bool {
@@ -150,10 +190,26 @@ impl Ciphertext {
             let _ = max_noise_level;
         }
         self.noise_level = noise_level;
+        // Callers changing the noise are responsible for updating the estimate afterward
+        self.estimated_noise = if noise_level == NoiseLevel::ZERO {
+            EstimatedNoise::zero()
+        } else {
+            EstimatedNoise::UNKNOWN
+        };
     }
 
     pub fn set_noise_level_to_nominal(&mut self) {
         self.noise_level = NoiseLevel::NOMINAL;
+        self.estimated_noise = EstimatedNoise::UNKNOWN;
+    }
+
+    /// Returns the estimated variance of the noise of the ciphertext, see [`EstimatedNoise`]
+    pub fn estimated_noise(&self) -> EstimatedNoise {
+        self.estimated_noise
+    }
+
+    pub(crate) fn set_estimated_noise(&mut self, estimated_noise: EstimatedNoise) {
+        self.estimated_noise = estimated_noise;
     }
 
     /// Decrypts a trivial ciphertext
@@ -299,6 +355,7 @@ mod tests {
             carry_modulus: CarryModulus(1),
             atomic_pattern: AtomicPatternKind::Standard(PBSOrder::KeyswitchBootstrap),
             noise_level: NoiseLevel::NOMINAL,
+            estimated_noise: EstimatedNoise::UNKNOWN,
         };
 
         let c2 = Ciphertext {
@@ -311,6 +368,7 @@ mod tests {
             carry_modulus: CarryModulus(2),
             atomic_pattern: AtomicPatternKind::Standard(PBSOrder::BootstrapKeyswitch),
             noise_level: NoiseLevel::NOMINAL,
+            estimated_noise: EstimatedNoise::UNKNOWN,
         };
 
         assert_ne!(c1, c2);
@@ -331,6 +389,7 @@ mod tests {
             carry_modulus: CarryModulus(1),
             atomic_pattern: AtomicPatternKind::Standard(PBSOrder::KeyswitchBootstrap),
             noise_level: NoiseLevel::NOMINAL,
+            estimated_noise: EstimatedNoise::UNKNOWN,
         };
 
         let c2 = Ciphertext {
@@ -343,6 +402,7 @@ mod tests {
             carry_modulus: CarryModulus(2),
             atomic_pattern: AtomicPatternKind::Standard(PBSOrder::BootstrapKeyswitch),
             noise_level: NoiseLevel::NOMINAL,
+            estimated_noise: EstimatedNoise::UNKNOWN,
         };
 
         assert_ne!(c1, c2);
@@ -363,6 +423,7 @@ mod tests {
             carry_modulus: CarryModulus(1),
             atomic_pattern: AtomicPatternKind::Standard(PBSOrder::KeyswitchBootstrap),
             noise_level: NoiseLevel::NOMINAL,
+            estimated_noise: EstimatedNoise::UNKNOWN,
         };
 
         let c2 = Ciphertext {
@@ -375,6 +436,7 @@ mod tests {
             carry_modulus: CarryModulus(2),
             atomic_pattern: AtomicPatternKind::Standard(PBSOrder::BootstrapKeyswitch),
             noise_level: NoiseLevel::NOMINAL,
+            estimated_noise: EstimatedNoise::UNKNOWN,
         };
 
         assert_ne!(c1, c2);
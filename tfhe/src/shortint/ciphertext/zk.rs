@@ -11,8 +11,9 @@ use crate::shortint::parameters::{
 };
 use crate::shortint::{Ciphertext, CompactPublicKey};
 use crate::zk::{
-    CompactPkeCrs, CompactPkeProof, CompactPkeProofConformanceParams, ZkComputeLoad,
-    ZkMSBZeroPaddingBitCount, ZkPkeV2HashMode, ZkVerificationOutcome,
+    CompactPkeCrs, CompactPkeCrsCeremony, CompactPkeProof, CompactPkeProofConformanceParams,
+    CompactPkeZkCurve, ZkComputeLoad, ZkMSBZeroPaddingBitCount, ZkPkeV2HashMode,
+    ZkVerificationOutcome,
 };
 
 use rayon::prelude::*;
@@ -29,6 +30,25 @@ impl CompactPkeCrs {
         params: P,
         max_num_message: LweCiphertextCount,
     ) -> crate::Result<Self>
+    where
+        P: TryInto<CompactPublicKeyEncryptionParameters, Error = E>,
+        crate::Error: From<E>,
+    {
+        Self::from_shortint_params_with_curve(params, max_num_message, CompactPkeZkCurve::default())
+    }
+
+    /// Construct the CRS that corresponds to the given parameters, for proofs built on the given
+    /// curve
+    ///
+    /// max_num_message is how many message a single proof can prove.
+    /// The version of the zk scheme is based on the
+    /// [`CompactPkeZkScheme`](crate::zk::CompactPkeZkScheme) value in the params. Only the V2
+    /// scheme can be used with [`CompactPkeZkCurve::Bn254`].
+    pub fn from_shortint_params_with_curve<P, E>(
+        params: P,
+        max_num_message: LweCiphertextCount,
+        curve: CompactPkeZkCurve,
+    ) -> crate::Result<Self>
     where
         P: TryInto<CompactPublicKeyEncryptionParameters, Error = E>,
         crate::Error: From<E>,
@@ -46,26 +66,32 @@ impl CompactPkeCrs {
         // 1 padding bit for the PBS
         // Note that if we want to we can prove carry bits are 0 should we need it
         crate::shortint::engine::ShortintEngine::with_thread_local_mut(|engine| {
-            match params.zk_scheme {
-                SupportedCompactPkeZkScheme::V1 => Self::new_legacy_v1(
-                    size,
-                    max_num_message,
-                    noise_distribution,
-                    params.ciphertext_modulus,
-                    plaintext_modulus,
-                    ZkMSBZeroPaddingBitCount(1),
-                    &mut engine.random_generator,
-                ),
-                SupportedCompactPkeZkScheme::V2 => Self::new(
+            match (params.zk_scheme, curve) {
+                (SupportedCompactPkeZkScheme::V1, CompactPkeZkCurve::Bls12_446) => {
+                    Self::new_legacy_v1(
+                        size,
+                        max_num_message,
+                        noise_distribution,
+                        params.ciphertext_modulus,
+                        plaintext_modulus,
+                        ZkMSBZeroPaddingBitCount(1),
+                        &mut engine.random_generator,
+                    )
+                }
+                (SupportedCompactPkeZkScheme::V1, CompactPkeZkCurve::Bn254) => {
+                    Err("The Bn254 curve is only available for the V2 zk scheme".into())
+                }
+                (SupportedCompactPkeZkScheme::V2, curve) => Self::new_with_curve(
                     size,
                     max_num_message,
                     noise_distribution,
                     params.ciphertext_modulus,
                     plaintext_modulus,
                     ZkMSBZeroPaddingBitCount(1),
+                    curve,
                     &mut engine.random_generator,
                 ),
-                SupportedCompactPkeZkScheme::ZkNotSupported => {
+                (SupportedCompactPkeZkScheme::ZkNotSupported, _) => {
                     Err("Zk proof of encryption is not supported by the provided parameters".into())
                 }
             }
@@ -73,6 +99,51 @@ impl CompactPkeCrs {
     }
 }
 
+impl CompactPkeCrsCeremony {
+    /// Starts a ceremony for the CRS that corresponds to the given parameters
+    ///
+    /// max_num_message is how many message a single proof can prove.
+    /// The ceremony is only available for parameters that use the V2
+    /// [`CompactPkeZkScheme`](crate::zk::CompactPkeZkScheme).
+    pub fn from_shortint_params<P, E>(
+        params: P,
+        max_num_message: LweCiphertextCount,
+    ) -> crate::Result<Self>
+    where
+        P: TryInto<CompactPublicKeyEncryptionParameters, Error = E>,
+        crate::Error: From<E>,
+    {
+        let params: CompactPublicKeyEncryptionParameters = params.try_into()?;
+
+        match params.zk_scheme {
+            SupportedCompactPkeZkScheme::V2 => {}
+            SupportedCompactPkeZkScheme::V1 => {
+                return Err("The CRS ceremony is only available for the V2 zk scheme".into())
+            }
+            SupportedCompactPkeZkScheme::ZkNotSupported => {
+                return Err(
+                    "Zk proof of encryption is not supported by the provided parameters".into(),
+                )
+            }
+        }
+
+        // Same as the plaintext modulus of the CRS built by `CompactPkeCrs::from_shortint_params`
+        let plaintext_modulus = params.message_modulus.0 * params.carry_modulus.0 * 2;
+
+        crate::shortint::engine::ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new(
+                params.encryption_lwe_dimension,
+                max_num_message,
+                params.encryption_noise_distribution,
+                params.ciphertext_modulus,
+                plaintext_modulus,
+                ZkMSBZeroPaddingBitCount(1),
+                &mut engine.random_generator,
+            )
+        })
+    }
+}
+
 /// A List of CompactCiphertext with their zero-knowledge proofs
 ///
 /// The proofs can only be generated during the encryption with a [CompactPublicKey]
@@ -0,0 +1,308 @@
+//! Module with the definition of the CircuitBootstrappingKey.
+//!
+//! A circuit bootstrapping turns a [`Ciphertext`] encrypting a bit into a GGSW ciphertext in the
+//! Fourier domain. These GGSW ciphertexts are under the GLWE secret key of the [`ClientKey`], they
+//! can select between GLWE ciphertexts with a CMux, e.g. to evaluate a vertical packing.
+//!
+//! The key is generated for a [`CircuitBootstrappingParameters`] set designed for the shortint
+//! parameters of the client key, e.g.
+//! [`CIRCUIT_BOOTSTRAPPING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128`](crate::shortint::parameters::CIRCUIT_BOOTSTRAPPING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+//! for
+//! [`PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128).
+
+use crate::core_crypto::algorithms::lwe_bootstrap_key_conversion::par_convert_standard_lwe_bootstrap_key_to_fourier;
+use crate::core_crypto::algorithms::lwe_bootstrap_key_generation::par_allocate_and_generate_new_lwe_bootstrap_key;
+use crate::core_crypto::algorithms::lwe_circuit_bootstrapping::{
+    circuit_bootstrap_lwe_ciphertext_to_ggsw,
+    circuit_bootstrap_modulus_switched_lwe_ciphertext_to_ggsw,
+};
+use crate::core_crypto::algorithms::lwe_keyswitch::keyswitch_lwe_ciphertext;
+use crate::core_crypto::algorithms::lwe_wopbs::par_allocate_and_generate_new_circuit_bootstrap_lwe_pfpksk_list;
+use crate::core_crypto::commons::parameters::{DeltaLog, GlweSize, PolynomialSize};
+use crate::core_crypto::commons::traits::ContainerMut;
+use crate::core_crypto::entities::{
+    FourierGgswCiphertext, FourierLweBootstrapKeyOwned, LweCiphertext,
+    LwePrivateFunctionalPackingKeyswitchKeyListOwned,
+};
+use crate::core_crypto::fft_impl::fft64::ABox;
+use crate::shortint::atomic_pattern::AtomicPattern;
+use crate::shortint::ciphertext::Ciphertext;
+use crate::shortint::client_key::atomic_pattern::AtomicPatternClientKey;
+use crate::shortint::client_key::ClientKey;
+use crate::shortint::encoding::{compute_delta, PaddingBit};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::CircuitBootstrappingParameters;
+use crate::shortint::server_key::{ServerKey, StandardServerKeyView};
+use crate::shortint::PBSOrder;
+use serde::{Deserialize, Serialize};
+use tfhe_fft::c64;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(dylint_lib = "tfhe_lints", allow(serialize_without_versionize))]
+pub struct CircuitBootstrappingKey {
+    bootstrapping_key: FourierLweBootstrapKeyOwned,
+    pfpksk_list: LwePrivateFunctionalPackingKeyswitchKeyListOwned<u64>,
+    parameters: CircuitBootstrappingParameters,
+}
+
+impl ClientKey {
+    pub fn new_circuit_bootstrapping_key(
+        &self,
+        parameters: CircuitBootstrappingParameters,
+    ) -> CircuitBootstrappingKey {
+        let AtomicPatternClientKey::Standard(std_cks) = &self.atomic_pattern else {
+            panic!("Only the standard atomic pattern supports the circuit bootstrapping")
+        };
+
+        let pbs_parameters = std_cks.parameters;
+
+        assert_eq!(
+            pbs_parameters.message_modulus(),
+            parameters.message_modulus,
+            "Incompatible MessageModulus ClientKey {:?}, CircuitBootstrappingParameters {:?}.",
+            pbs_parameters.message_modulus(),
+            parameters.message_modulus,
+        );
+        assert_eq!(
+            pbs_parameters.carry_modulus(),
+            parameters.carry_modulus,
+            "Incompatible CarryModulus ClientKey {:?}, CircuitBootstrappingParameters {:?}.",
+            pbs_parameters.carry_modulus(),
+            parameters.carry_modulus,
+        );
+
+        // The look-up table of the circuit bootstrapping needs two boxes per level of the output
+        // GGSW ciphertexts in the half torus
+        let cleartext_modulus = parameters.message_modulus.0 * parameters.carry_modulus.0;
+        assert!(
+            2 * parameters.cbs_level.0 as u64 <= cleartext_modulus,
+            "The circuit bootstrapping cannot output GGSW ciphertexts with {:?} with a cleartext \
+            modulus of {cleartext_modulus}",
+            parameters.cbs_level,
+        );
+
+        let ciphertext_modulus = pbs_parameters.ciphertext_modulus();
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let std_bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+                &std_cks.lwe_secret_key,
+                &std_cks.glwe_secret_key,
+                parameters.pbs_base_log,
+                parameters.pbs_level,
+                parameters.glwe_noise_distribution,
+                ciphertext_modulus,
+                &mut engine.encryption_generator,
+            );
+
+            let mut bootstrapping_key = FourierLweBootstrapKeyOwned::new(
+                std_bsk.input_lwe_dimension(),
+                std_bsk.glwe_size(),
+                std_bsk.polynomial_size(),
+                std_bsk.decomposition_base_log(),
+                std_bsk.decomposition_level_count(),
+            );
+
+            par_convert_standard_lwe_bootstrap_key_to_fourier(&std_bsk, &mut bootstrapping_key);
+
+            let pfpksk_list = par_allocate_and_generate_new_circuit_bootstrap_lwe_pfpksk_list(
+                &std_cks.glwe_secret_key.as_lwe_secret_key(),
+                &std_cks.glwe_secret_key,
+                parameters.pfpks_base_log,
+                parameters.pfpks_level,
+                parameters.glwe_noise_distribution,
+                ciphertext_modulus,
+                &mut engine.encryption_generator,
+            );
+
+            CircuitBootstrappingKey {
+                bootstrapping_key,
+                pfpksk_list,
+                parameters,
+            }
+        })
+    }
+}
+
+impl CircuitBootstrappingKey {
+    pub fn new(client_key: &ClientKey, parameters: CircuitBootstrappingParameters) -> Self {
+        client_key.new_circuit_bootstrapping_key(parameters)
+    }
+
+    pub fn parameters(&self) -> CircuitBootstrappingParameters {
+        self.parameters
+    }
+
+    /// The [`GlweSize`] of the output GGSW ciphertexts
+    pub fn glwe_size(&self) -> GlweSize {
+        self.bootstrapping_key.glwe_size()
+    }
+
+    /// The [`PolynomialSize`] of the output GGSW ciphertexts
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.bootstrapping_key.polynomial_size()
+    }
+
+    /// Circuit bootstrap a ciphertext encrypting a bit into a GGSW ciphertext
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checks of [`Self::checked_circuit_bootstrap`] fail.
+    pub fn circuit_bootstrap(
+        &self,
+        ciphertext: &Ciphertext,
+        src_server_key: &ServerKey,
+    ) -> FourierGgswCiphertext<ABox<[c64]>> {
+        self.checked_circuit_bootstrap(ciphertext, src_server_key)
+            .unwrap()
+    }
+
+    /// Circuit bootstrap a ciphertext encrypting a bit into a GGSW ciphertext
+    ///
+    /// The ciphertext must have a degree of at most 1 and a noise level accepted by the
+    /// `src_server_key`, which must use the standard atomic pattern.
+    pub fn checked_circuit_bootstrap(
+        &self,
+        ciphertext: &Ciphertext,
+        src_server_key: &ServerKey,
+    ) -> crate::Result<FourierGgswCiphertext<ABox<[c64]>>> {
+        let ct_noise_level = ciphertext.noise_level();
+        if src_server_key
+            .max_noise_level
+            .validate(ct_noise_level)
+            .is_err()
+        {
+            return Err(crate::error!(
+                "circuit_bootstrap requires the input Ciphertext to have at most {:?} noise \
+                got {:?}.",
+                src_server_key.max_noise_level,
+                ct_noise_level
+            ));
+        }
+
+        if ciphertext.degree.get() > 1 {
+            return Err(crate::error!(
+                "circuit_bootstrap requires the input Ciphertext to encrypt a bit, got {:?}.",
+                ciphertext.degree
+            ));
+        }
+
+        if ciphertext.message_modulus != self.parameters.message_modulus {
+            return Err(crate::error!(
+                "Mismatched MessageModulus between Ciphertext {:?} and CircuitBootstrappingKey \
+                {:?}.",
+                ciphertext.message_modulus,
+                self.parameters.message_modulus,
+            ));
+        }
+
+        if ciphertext.carry_modulus != self.parameters.carry_modulus {
+            return Err(crate::error!(
+                "Mismatched CarryModulus between Ciphertext {:?} and CircuitBootstrappingKey \
+                {:?}.",
+                ciphertext.carry_modulus,
+                self.parameters.carry_modulus,
+            ));
+        }
+
+        let src_server_key: StandardServerKeyView =
+            src_server_key.as_view().try_into().map_err(|_| {
+                crate::error!(
+                    "The circuit bootstrapping is not supported by the selected atomic pattern \
+                    ({:?})",
+                    src_server_key.atomic_pattern.kind()
+                )
+            })?;
+
+        Ok(self.unchecked_circuit_bootstrap(ciphertext, src_server_key))
+    }
+
+    pub fn unchecked_circuit_bootstrap(
+        &self,
+        ciphertext: &Ciphertext,
+        src_server_key: StandardServerKeyView,
+    ) -> FourierGgswCiphertext<ABox<[c64]>> {
+        let mut output_ggsw = FourierGgswCiphertext::new(
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.parameters.cbs_base_log,
+            self.parameters.cbs_level,
+        );
+
+        self.unchecked_circuit_bootstrap_into(ciphertext, src_server_key, &mut output_ggsw);
+
+        output_ggsw
+    }
+
+    /// Circuit bootstrap `ciphertext` into `output_ggsw`, which must have the decomposition
+    /// parameters of the [`CircuitBootstrappingParameters`] of the key
+    pub(crate) fn unchecked_circuit_bootstrap_into<OutputCont>(
+        &self,
+        ciphertext: &Ciphertext,
+        src_server_key: StandardServerKeyView,
+        output_ggsw: &mut FourierGgswCiphertext<OutputCont>,
+    ) where
+        OutputCont: ContainerMut<Element = c64>,
+    {
+        let atomic_pattern = src_server_key.atomic_pattern;
+
+        let lwe_before_ms = match atomic_pattern.pbs_order {
+            // Under the big key, first need to keyswitch
+            PBSOrder::KeyswitchBootstrap => {
+                let mut after_ks_ct = LweCiphertext::new(
+                    0u64,
+                    atomic_pattern.key_switching_key.output_lwe_size(),
+                    atomic_pattern.key_switching_key.ciphertext_modulus(),
+                );
+
+                keyswitch_lwe_ciphertext(
+                    &atomic_pattern.key_switching_key,
+                    &ciphertext.ct,
+                    &mut after_ks_ct,
+                );
+                after_ks_ct
+            }
+            // Under the small key, no need to keyswitch
+            PBSOrder::BootstrapKeyswitch => ciphertext.ct.clone(),
+        };
+
+        let delta = compute_delta(
+            ciphertext.ct.ciphertext_modulus(),
+            self.parameters.message_modulus,
+            self.parameters.carry_modulus,
+            PaddingBit::Yes,
+        );
+        let delta_log = DeltaLog(delta.ilog2() as usize);
+
+        // Using the modulus switch of the server key keeps the failure probability of the shortint
+        // parameters for the decoding of the input bit
+        match atomic_pattern
+            .bootstrapping_key
+            .modulus_switch_configuration()
+        {
+            Some(modulus_switch_configuration) => {
+                let msed_lwe = modulus_switch_configuration.lwe_ciphertext_modulus_switch(
+                    &lwe_before_ms,
+                    self.polynomial_size().to_blind_rotation_input_modulus_log(),
+                );
+
+                circuit_bootstrap_modulus_switched_lwe_ciphertext_to_ggsw(
+                    &msed_lwe,
+                    output_ggsw,
+                    delta_log,
+                    &self.bootstrapping_key,
+                    &self.pfpksk_list,
+                );
+            }
+            None => circuit_bootstrap_lwe_ciphertext_to_ggsw(
+                &lwe_before_ms,
+                output_ggsw,
+                delta_log,
+                &self.bootstrapping_key,
+                &self.pfpksk_list,
+            ),
+        }
+    }
+}
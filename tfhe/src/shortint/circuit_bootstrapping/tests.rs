@@ -0,0 +1,142 @@
+use crate::core_crypto::commons::noise_formulas::lwe_programmable_bootstrap::pbs_variance_132_bits_security_tuniform_fft_mul;
+use crate::core_crypto::commons::test_tools::{
+    gaussian_variance_confidence_interval, torus_modular_diff, variance,
+};
+use crate::core_crypto::prelude::*;
+use crate::shortint::circuit_bootstrapping::CircuitBootstrappingKey;
+use crate::shortint::client_key::atomic_pattern::AtomicPatternClientKey;
+use crate::shortint::encoding::{compute_delta, PaddingBit};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::*;
+use rand::prelude::*;
+use rand::thread_rng;
+
+const NB_TESTS: usize = 10;
+
+// A vertical packing over 16 bits, the biggest table of the integer table lookup, goes through 16
+// CMux before its output is extracted
+const MAX_CMUX_COUNT: usize = 16;
+
+#[test]
+fn test_circuit_bootstrapping_ci_run_filter() {
+    test_circuit_bootstrapping(
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        CIRCUIT_BOOTSTRAPPING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    );
+}
+
+fn test_circuit_bootstrapping(
+    params: ClassicPBSParameters,
+    cbs_params: CircuitBootstrappingParameters,
+) {
+    let keycache_entry = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keycache_entry.client_key(), keycache_entry.server_key());
+    let cbs_key = CircuitBootstrappingKey::new(cks, cbs_params);
+
+    let AtomicPatternClientKey::Standard(std_cks) = &cks.atomic_pattern else {
+        panic!("The circuit bootstrapping requires the standard atomic pattern")
+    };
+    let glwe_secret_key = &std_cks.glwe_secret_key;
+
+    let glwe_size = cbs_key.glwe_size();
+    let polynomial_size = cbs_key.polynomial_size();
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let cleartext_modulus = params.message_modulus.0 * params.carry_modulus.0;
+    let delta = compute_delta(
+        ciphertext_modulus,
+        params.message_modulus,
+        params.carry_modulus,
+        PaddingBit::Yes,
+    );
+
+    let mut rng = thread_rng();
+
+    let id_lut = sks.generate_lookup_table(|x| x);
+
+    let mut noise_samples = Vec::with_capacity(NB_TESTS * 2 * polynomial_size.0);
+
+    for _ in 0..NB_TESTS {
+        for bit in [0, 1] {
+            let mut ct = cks.encrypt(bit);
+
+            // Set ciphertext noise level to nominal
+            sks.apply_lookup_table_assign(&mut ct, &id_lut);
+
+            let ggsw = cbs_key.circuit_bootstrap(&ct, sks);
+
+            let msgs: [Vec<u64>; 2] = std::array::from_fn(|_| {
+                (0..polynomial_size.0)
+                    .map(|_| rng.gen_range(0..cleartext_modulus))
+                    .collect()
+            });
+
+            // Encrypted inputs, so that the CMux sees the uniform masks of a vertical packing
+            let encrypt = |msg: &[u64]| {
+                let plaintext_list = PlaintextList::from_container(
+                    msg.iter().map(|m| m * delta).collect::<Vec<_>>(),
+                );
+                let mut glwe =
+                    GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+
+                ShortintEngine::with_thread_local_mut(|engine| {
+                    encrypt_glwe_ciphertext(
+                        glwe_secret_key,
+                        &mut glwe,
+                        &plaintext_list,
+                        params.glwe_noise_distribution,
+                        &mut engine.encryption_generator,
+                    );
+                });
+
+                glwe
+            };
+
+            let mut glwe_0 = encrypt(&msgs[0]);
+            let mut glwe_1 = encrypt(&msgs[1]);
+
+            cmux_assign(&mut glwe_0, &mut glwe_1, &ggsw);
+
+            let mut decrypted = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+            decrypt_glwe_ciphertext(glwe_secret_key, &glwe_0, &mut decrypted);
+
+            let expected_msgs = &msgs[bit as usize];
+
+            for (plaintext, expected_msg) in decrypted.iter().zip(expected_msgs.iter()) {
+                let decoded = (plaintext.0.wrapping_add(delta / 2) / delta) % cleartext_modulus;
+                assert_eq!(decoded, *expected_msg, "Invalid CMux result for bit {bit}");
+
+                noise_samples.push(torus_modular_diff(
+                    *plaintext.0,
+                    expected_msg * delta,
+                    ciphertext_modulus,
+                ));
+            }
+        }
+    }
+
+    let measured_variance = variance(&noise_samples);
+    let confidence_interval =
+        gaussian_variance_confidence_interval(noise_samples.len() as f64, measured_variance, 0.99);
+
+    // The CMux of a vertical packing add up their noise, the output of the biggest vertical
+    // packing must not be noisier than what the shortint parameters accept as input of a PBS, i.e.
+    // the sum of max_noise_level PBS outputs
+    let max_vertical_packing_variance = confidence_interval.upper_bound().0 * MAX_CMUX_COUNT as f64;
+
+    let pbs_variance = pbs_variance_132_bits_security_tuniform_fft_mul(
+        params.lwe_dimension,
+        params.glwe_dimension,
+        params.polynomial_size,
+        params.pbs_base_log,
+        params.pbs_level,
+        2.0f64.powi(64),
+    );
+    let max_pbs_input_variance = pbs_variance.0 * params.max_noise_level.get() as f64;
+
+    assert!(
+        max_vertical_packing_variance <= max_pbs_input_variance,
+        "The noise of a vertical packing is too high: {max_vertical_packing_variance:?} \
+        (measured CMux variance {measured_variance:?}), max: {max_pbs_input_variance:?}"
+    );
+}
@@ -4,7 +4,7 @@ use super::ShortintEngine;
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::math::random::{Distribution, RandomGenerable};
 use crate::core_crypto::entities::*;
-use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::ciphertext::{Degree, EstimatedNoise, NoiseLevel};
 use crate::shortint::client_key::atomic_pattern::{
     AtomicPatternClientKey, EncryptionAtomicPattern, StandardAtomicPatternClientKey,
 };
@@ -144,14 +144,24 @@ impl ShortintEngine {
             * client_key.parameters().carry_modulus().0)
             / message_modulus.0;
 
-        Ciphertext::new(
+        let mut ciphertext = Ciphertext::new(
             ct,
             Degree::new(message_modulus.0 - 1),
             NoiseLevel::NOMINAL,
             message_modulus,
             CarryModulus(carry_modulus),
             params_atomic_pattern,
-        )
+        );
+        ciphertext.set_estimated_noise(EstimatedNoise::new(
+            encryption_noise_distribution.variance(
+                client_key
+                    .parameters()
+                    .ciphertext_modulus()
+                    .raw_modulus_float(),
+            ),
+        ));
+
+        ciphertext
     }
 
     pub(crate) fn encrypt_with_message_and_carry_modulus<AP: EncryptionAtomicPattern>(
@@ -182,14 +192,24 @@ impl ShortintEngine {
             message_modulus,
         );
 
-        Ciphertext::new(
+        let mut ciphertext = Ciphertext::new(
             ct,
             Degree::new(message_modulus.0 - 1),
             NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus,
             atomic_pattern,
-        )
+        );
+        ciphertext.set_estimated_noise(EstimatedNoise::new(
+            encryption_noise_distribution.variance(
+                client_key
+                    .parameters()
+                    .ciphertext_modulus()
+                    .raw_modulus_float(),
+            ),
+        ));
+
+        ciphertext
     }
 
     pub(crate) fn encrypt_with_message_modulus_compressed<AP: EncryptionAtomicPattern>(
@@ -253,7 +273,7 @@ impl ShortintEngine {
             &mut self.encryption_generator,
         );
 
-        Ciphertext::new(
+        let mut ciphertext = Ciphertext::new(
             ct,
             Degree::new(
                 client_key.parameters().message_modulus().0
@@ -264,7 +284,17 @@ impl ShortintEngine {
             client_key.parameters().message_modulus(),
             client_key.parameters().carry_modulus(),
             atomic_pattern,
-        )
+        );
+        ciphertext.set_estimated_noise(EstimatedNoise::new(
+            encryption_noise_distribution.variance(
+                client_key
+                    .parameters()
+                    .ciphertext_modulus()
+                    .raw_modulus_float(),
+            ),
+        ));
+
+        ciphertext
     }
 
     pub(crate) fn encrypt_without_padding<AP: EncryptionAtomicPattern>(
@@ -288,14 +318,24 @@ impl ShortintEngine {
             &mut self.encryption_generator,
         );
 
-        Ciphertext::new(
+        let mut ciphertext = Ciphertext::new(
             ct,
             Degree::new(client_key.parameters().message_modulus().0 - 1),
             NoiseLevel::NOMINAL,
             client_key.parameters().message_modulus(),
             client_key.parameters().carry_modulus(),
             atomic_pattern,
-        )
+        );
+        ciphertext.set_estimated_noise(EstimatedNoise::new(
+            encryption_noise_distribution.variance(
+                client_key
+                    .parameters()
+                    .ciphertext_modulus()
+                    .raw_modulus_float(),
+            ),
+        ));
+
+        ciphertext
     }
 
     pub(crate) fn encrypt_without_padding_compressed<AP: EncryptionAtomicPattern>(
@@ -354,14 +394,24 @@ impl ShortintEngine {
             &mut self.encryption_generator,
         );
 
-        Ciphertext::new(
+        let mut ciphertext = Ciphertext::new(
             ct,
             Degree::new(message_modulus.0 - 1),
             NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus,
             atomic_pattern,
-        )
+        );
+        ciphertext.set_estimated_noise(EstimatedNoise::new(
+            encryption_noise_distribution.variance(
+                client_key
+                    .parameters()
+                    .ciphertext_modulus()
+                    .raw_modulus_float(),
+            ),
+        ));
+
+        ciphertext
     }
 
     pub(crate) fn encrypt_native_crt_compressed<AP: EncryptionAtomicPattern>(
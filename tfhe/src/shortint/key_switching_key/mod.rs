@@ -15,7 +15,7 @@ use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{
     EncryptionKeyChoice, NoiseLevel, PBSOrder, ShortintKeySwitchingParameters,
 };
-use crate::shortint::server_key::apply_programmable_bootstrap;
+use crate::shortint::server_key::{apply_keyswitch, apply_programmable_bootstrap};
 use crate::shortint::{Ciphertext, ClientKey, CompressedServerKey, MaxNoiseLevel, ServerKey};
 use core::cmp::Ordering;
 use rayon::prelude::*;
@@ -604,11 +604,13 @@ impl<'keys> KeySwitchingKeyView<'keys> {
         };
 
         // The keyswitch
-        keyswitch_lwe_ciphertext(
-            self.key_switching_key_material.key_switching_key,
-            &pre_processed.ct,
-            &mut keyswitched.ct,
-        );
+        apply_keyswitch(|| {
+            keyswitch_lwe_ciphertext(
+                self.key_switching_key_material.key_switching_key,
+                &pre_processed.ct,
+                &mut keyswitched.ct,
+            )
+        });
         keyswitched.degree = pre_processed.degree;
 
         let degree_after_keyswitch = keyswitched.degree;
@@ -637,11 +639,13 @@ impl<'keys> KeySwitchingKeyView<'keys> {
                             self.dest_server_key.max_noise_level,
                         );
 
-                        keyswitch_lwe_ciphertext(
-                            &self.dest_server_key.atomic_pattern.key_switching_key,
-                            &wrong_key_ct.ct,
-                            &mut correct_key_ct.ct,
-                        );
+                        apply_keyswitch(|| {
+                            keyswitch_lwe_ciphertext(
+                                &self.dest_server_key.atomic_pattern.key_switching_key,
+                                &wrong_key_ct.ct,
+                                &mut correct_key_ct.ct,
+                            )
+                        });
 
                         CastCiphertext::CorrectKey(correct_key_ct)
                     }
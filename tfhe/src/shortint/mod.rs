@@ -50,6 +50,7 @@
 pub mod atomic_pattern;
 pub mod backward_compatibility;
 pub mod ciphertext;
+pub mod circuit_bootstrapping;
 pub mod client_key;
 pub(crate) mod encoding;
 pub mod engine;
@@ -58,6 +59,8 @@ pub mod key_switching_key;
 pub mod keycache;
 pub mod list_compression;
 pub mod noise_squashing;
+#[cfg(feature = "noise-tracking")]
+pub mod noise_tracking;
 pub mod oprf;
 pub mod parameters;
 pub mod prelude;
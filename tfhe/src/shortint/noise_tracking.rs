@@ -0,0 +1,373 @@
+//! Noise estimation for the shortint operations, enabled by the `noise-tracking` feature.
+//!
+//! The estimated variance of a ciphertext (see [`EstimatedNoise`]) is propagated through the
+//! linear operations and reset to the variance of a PBS output by the atomic patterns. The
+//! failure probability of each PBS is accumulated in a global [`NoiseReport`] using atomics, so
+//! that the PBS running on different threads do not wait on each other.
+//!
+//! Estimates rely on the [`noise_formulas`](crate::core_crypto::commons::noise_formulas), which
+//! assume the keys are encrypted with the minimal secure noise for 132 bits of security. As the
+//! noise distribution of the keys is not stored in the server key, the largest of the Gaussian
+//! and TUniform estimates is used. The noise reduction techniques of the modulus switch are not
+//! modeled, the standard modulus switch variance is used instead, which overestimates their noise.
+//!
+//! [`EstimatedNoise`]: crate::shortint::ciphertext::EstimatedNoise
+
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::noise_formulas::lwe_keyswitch::{
+    keyswitch_additive_variance_132_bits_security_gaussian,
+    keyswitch_additive_variance_132_bits_security_tuniform,
+};
+use crate::core_crypto::commons::noise_formulas::lwe_multi_bit_programmable_bootstrap::*;
+use crate::core_crypto::commons::noise_formulas::lwe_programmable_bootstrap::{
+    pbs_variance_132_bits_security_gaussian_fft_mul,
+    pbs_variance_132_bits_security_tuniform_fft_mul,
+};
+use crate::core_crypto::commons::noise_formulas::modulus_switch::modulus_switch_additive_variance;
+use crate::core_crypto::commons::noise_formulas::multi_bit_modulus_switch::multi_bit_modulus_switch_additive_variance;
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use crate::core_crypto::entities::LweKeyswitchKeyOwned;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::server_key::ShortintBootstrappingKey;
+use crate::shortint::{Ciphertext, ServerKey};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The noise added by the application of an atomic pattern
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AtomicPatternNoise {
+    /// Variance of the noise of the ciphertexts output by the atomic pattern
+    pub output_variance: Variance,
+    /// Variance added to the noise of the input ciphertext before the blind rotation, by the
+    /// keyswitch and the modulus switch
+    pub pre_blind_rotation_additive_variance: Variance,
+}
+
+impl AtomicPatternNoise {
+    /// Returns the log2 of the probability that the blind rotation of a ciphertext with the
+    /// given noise gives a wrong result
+    pub fn log2_failure_probability(
+        &self,
+        input_variance: Variance,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    ) -> f64 {
+        log2_failure_probability(
+            Variance(input_variance.0 + self.pre_blind_rotation_additive_variance.0),
+            message_modulus,
+            carry_modulus,
+        )
+    }
+}
+
+fn max_variance(gaussian: Variance, tuniform: Variance) -> Variance {
+    Variance(gaussian.0.max(tuniform.0))
+}
+
+type PbsVarianceFormula = fn(
+    LweDimension,
+    GlweDimension,
+    PolynomialSize,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+    f64,
+) -> Variance;
+
+/// Variance of the output of a PBS, `None` if the grouping factor of a multi bit key is not
+/// supported by the formulas
+pub(crate) fn bootstrap_variance<InputScalar: UnsignedInteger>(
+    bootstrapping_key: &ShortintBootstrappingKey<InputScalar>,
+    output_modulus: f64,
+) -> Option<Variance> {
+    let lwe_dimension = bootstrapping_key.input_lwe_dimension();
+    let glwe_dimension = bootstrapping_key.glwe_size().to_glwe_dimension();
+    let polynomial_size = bootstrapping_key.polynomial_size();
+    let base_log = bootstrapping_key.decomposition_base_log();
+    let level_count = bootstrapping_key.decomposition_level_count();
+
+    let (gaussian, tuniform): (PbsVarianceFormula, PbsVarianceFormula) = match bootstrapping_key {
+        ShortintBootstrappingKey::Classic { .. } => (
+            pbs_variance_132_bits_security_gaussian_fft_mul,
+            pbs_variance_132_bits_security_tuniform_fft_mul,
+        ),
+        ShortintBootstrappingKey::MultiBit { fourier_bsk, .. } => {
+            match fourier_bsk.grouping_factor().0 {
+                2 => (
+                    multi_bit_pbs_variance_132_bits_security_gaussian_gf_2_fft_mul,
+                    multi_bit_pbs_variance_132_bits_security_tuniform_gf_2_fft_mul,
+                ),
+                3 => (
+                    multi_bit_pbs_variance_132_bits_security_gaussian_gf_3_fft_mul,
+                    multi_bit_pbs_variance_132_bits_security_tuniform_gf_3_fft_mul,
+                ),
+                4 => (
+                    multi_bit_pbs_variance_132_bits_security_gaussian_gf_4_fft_mul,
+                    multi_bit_pbs_variance_132_bits_security_tuniform_gf_4_fft_mul,
+                ),
+                _ => return None,
+            }
+        }
+    };
+
+    Some(max_variance(
+        gaussian(
+            lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            base_log,
+            level_count,
+            output_modulus,
+        ),
+        tuniform(
+            lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            base_log,
+            level_count,
+            output_modulus,
+        ),
+    ))
+}
+
+/// Variance added by the modulus switch done before the blind rotation
+pub(crate) fn modulus_switch_variance<InputScalar: UnsignedInteger>(
+    bootstrapping_key: &ShortintBootstrappingKey<InputScalar>,
+    input_modulus: f64,
+) -> Variance {
+    let lwe_dimension = bootstrapping_key.input_lwe_dimension();
+    let new_modulus = 2.0 * bootstrapping_key.polynomial_size().0 as f64;
+
+    match bootstrapping_key {
+        ShortintBootstrappingKey::Classic { .. } => {
+            modulus_switch_additive_variance(lwe_dimension, input_modulus, new_modulus)
+        }
+        ShortintBootstrappingKey::MultiBit { fourier_bsk, .. } => {
+            multi_bit_modulus_switch_additive_variance(
+                lwe_dimension,
+                fourier_bsk.grouping_factor().0 as f64,
+                input_modulus,
+                new_modulus,
+            )
+        }
+    }
+}
+
+/// Variance added by a keyswitch
+pub(crate) fn keyswitch_variance<Scalar: UnsignedInteger>(
+    keyswitch_key: &LweKeyswitchKeyOwned<Scalar>,
+    input_modulus: f64,
+) -> Variance {
+    let args = (
+        keyswitch_key.input_key_lwe_dimension(),
+        keyswitch_key.output_key_lwe_dimension(),
+        keyswitch_key.decomposition_base_log(),
+        keyswitch_key.decomposition_level_count(),
+        input_modulus,
+        keyswitch_key.ciphertext_modulus().raw_modulus_float(),
+    );
+
+    max_variance(
+        keyswitch_additive_variance_132_bits_security_gaussian(
+            args.0, args.1, args.2, args.3, args.4, args.5,
+        ),
+        keyswitch_additive_variance_132_bits_security_tuniform(
+            args.0, args.1, args.2, args.3, args.4, args.5,
+        ),
+    )
+}
+
+/// Returns the log2 of the probability that a noise of the given variance prevents the correct
+/// decoding of a message
+///
+/// The decoding fails if the noise is greater than half the encoding scaling factor, i.e.
+/// `1 / (4 * message_modulus * carry_modulus)` on the torus (one bit of padding is used).
+///
+/// pfail = erfc(bound / (sqrt(2) * sigma))
+pub fn log2_failure_probability(
+    variance: Variance,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+) -> f64 {
+    if variance.0 <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+
+    let bound = 1.0 / (4 * message_modulus.0 * carry_modulus.0) as f64;
+    let x = bound / (2.0 * variance.0).sqrt();
+    let p_fail = statrs::function::erf::erfc(x);
+
+    if p_fail > 0.0 {
+        p_fail.log2()
+    } else {
+        // erfc underflowed, use its asymptotic expansion erfc(x) ~ exp(-x^2) / (x * sqrt(pi))
+        (-x * x - (x * core::f64::consts::PI.sqrt()).ln()) * core::f64::consts::LOG2_E
+    }
+}
+
+/// Computes log2(2^a + 2^b) without leaving the log domain
+fn log2_sum(a: f64, b: f64) -> f64 {
+    let (max, min) = if a > b { (a, b) } else { (b, a) };
+    if max == f64::NEG_INFINITY {
+        return max;
+    }
+
+    max + (min - max).exp2().ln_1p() * core::f64::consts::LOG2_E
+}
+
+/// Returns the largest estimated variance of the given blocks, `None` if the noise of one of
+/// them is unknown
+pub fn max_estimated_variance<'a>(
+    blocks: impl IntoIterator<Item = &'a Ciphertext>,
+) -> Option<Variance> {
+    blocks.into_iter().try_fold(Variance(0.0), |max, block| {
+        let variance = block.estimated_noise().variance()?;
+        Some(Variance(max.0.max(variance.0)))
+    })
+}
+
+/// Returns the log2 of the probability that a PBS applied on any of the given blocks gives a
+/// wrong result, `None` if the noise of one of them is unknown
+pub fn log2_failure_probability_of_blocks<'a>(
+    server_key: &ServerKey,
+    blocks: impl IntoIterator<Item = &'a Ciphertext>,
+) -> Option<f64> {
+    blocks
+        .into_iter()
+        .try_fold(f64::NEG_INFINITY, |acc, block| {
+            Some(log2_sum(acc, server_key.log2_failure_probability(block)?))
+        })
+}
+
+/// Summary of the failure probabilities of the PBS computed since the last
+/// [`reset_noise_report`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NoiseReport {
+    /// Number of PBS whose input noise was estimated
+    pub estimated_pbs_count: u64,
+    /// Number of PBS whose input noise was unknown
+    pub unknown_pbs_count: u64,
+    /// log2 of the largest failure probability of a single PBS
+    pub worst_log2_p_fail: f64,
+    /// log2 of the probability that at least one of the estimated PBS failed (union bound)
+    pub log2_p_fail: f64,
+}
+
+impl std::fmt::Display for NoiseReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "PBS count: {} estimated, {} unknown",
+            self.estimated_pbs_count, self.unknown_pbs_count
+        )?;
+        writeln!(f, "worst PBS p-fail: 2^{:.3}", self.worst_log2_p_fail)?;
+        write!(f, "computation p-fail: 2^{:.3}", self.log2_p_fail)
+    }
+}
+
+/// Lock-free accumulator of a [`NoiseReport`]
+///
+/// The log2 of the failure probabilities are stored as the bits of an `f64`
+struct AtomicNoiseReport {
+    estimated_pbs_count: AtomicU64,
+    unknown_pbs_count: AtomicU64,
+    worst_log2_p_fail: AtomicU64,
+    log2_p_fail: AtomicU64,
+}
+
+/// Bits of `f64::NEG_INFINITY`, the log2 of a null probability
+const NEG_INFINITY_BITS: u64 = 0xfff0_0000_0000_0000;
+
+impl AtomicNoiseReport {
+    const fn new() -> Self {
+        Self {
+            estimated_pbs_count: AtomicU64::new(0),
+            unknown_pbs_count: AtomicU64::new(0),
+            worst_log2_p_fail: AtomicU64::new(NEG_INFINITY_BITS),
+            log2_p_fail: AtomicU64::new(NEG_INFINITY_BITS),
+        }
+    }
+
+    fn update_f64(value: &AtomicU64, f: impl Fn(f64) -> f64) {
+        // The closure always returns Some, so the update cannot fail
+        let _ = value.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+            Some(f(f64::from_bits(bits)).to_bits())
+        });
+    }
+
+    fn record(&self, log2_p_fail: Option<f64>) {
+        let Some(log2_p_fail) = log2_p_fail else {
+            self.unknown_pbs_count.fetch_add(1, Ordering::Relaxed);
+            return;
+        };
+
+        self.estimated_pbs_count.fetch_add(1, Ordering::Relaxed);
+        Self::update_f64(&self.worst_log2_p_fail, |worst| worst.max(log2_p_fail));
+        Self::update_f64(&self.log2_p_fail, |acc| log2_sum(acc, log2_p_fail));
+    }
+
+    fn load(&self) -> NoiseReport {
+        NoiseReport {
+            estimated_pbs_count: self.estimated_pbs_count.load(Ordering::Relaxed),
+            unknown_pbs_count: self.unknown_pbs_count.load(Ordering::Relaxed),
+            worst_log2_p_fail: f64::from_bits(self.worst_log2_p_fail.load(Ordering::Relaxed)),
+            log2_p_fail: f64::from_bits(self.log2_p_fail.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn reset(&self) {
+        self.estimated_pbs_count.store(0, Ordering::Relaxed);
+        self.unknown_pbs_count.store(0, Ordering::Relaxed);
+        self.worst_log2_p_fail
+            .store(NEG_INFINITY_BITS, Ordering::Relaxed);
+        self.log2_p_fail.store(NEG_INFINITY_BITS, Ordering::Relaxed);
+    }
+}
+
+static NOISE_REPORT: AtomicNoiseReport = AtomicNoiseReport::new();
+
+pub(crate) fn record_pbs(log2_p_fail: Option<f64>) {
+    NOISE_REPORT.record(log2_p_fail);
+}
+
+/// Returns the report of the PBS computed since the last [`reset_noise_report`]
+///
+/// The fields are read one after the other, PBS running concurrently to this call may only be
+/// partially accounted for.
+pub fn get_noise_report() -> NoiseReport {
+    NOISE_REPORT.load()
+}
+
+pub fn reset_noise_report() {
+    NOISE_REPORT.reset();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_noise_report_union_bound() {
+        let accumulator = AtomicNoiseReport::new();
+        accumulator.record(Some(-130.0));
+        accumulator.record(Some(-130.0));
+        accumulator.record(None);
+
+        let report = accumulator.load();
+
+        assert_eq!(report.estimated_pbs_count, 2);
+        assert_eq!(report.unknown_pbs_count, 1);
+        assert_eq!(report.worst_log2_p_fail, -130.0);
+        assert!((report.log2_p_fail - -129.0).abs() < 1e-9);
+
+        accumulator.reset();
+        assert_eq!(accumulator.load().estimated_pbs_count, 0);
+        assert_eq!(accumulator.load().log2_p_fail, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_neg_infinity_bits() {
+        assert_eq!(f64::from_bits(NEG_INFINITY_BITS), f64::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,51 @@
+//! Parameters of the circuit bootstrapping of shortint ciphertexts
+//!
+//! A circuit bootstrapping turns a shortint ciphertext encrypting a bit into a GGSW ciphertext
+//! under the GLWE secret key of the [`ClientKey`](crate::shortint::ClientKey). The keys it uses
+//! share the secret keys of the client key, so a set of circuit bootstrapping parameters is only
+//! valid for the shortint parameter set it was designed with.
+
+use crate::shortint::parameters::{
+    CarryModulus, DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution,
+    MessageModulus,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(dylint_lib = "tfhe_lints", allow(serialize_without_versionize))]
+pub struct CircuitBootstrappingParameters {
+    /// Decomposition of the bootstrapping key used by the blind rotation of the circuit
+    /// bootstrapping, from the small LWE secret key to the GLWE secret key
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    /// Decomposition of the private functional packing keyswitch keys building the rows of the
+    /// output GGSW ciphertexts
+    pub pfpks_base_log: DecompositionBaseLog,
+    pub pfpks_level: DecompositionLevelCount,
+    /// Decomposition of the output GGSW ciphertexts
+    pub cbs_base_log: DecompositionBaseLog,
+    pub cbs_level: DecompositionLevelCount,
+    pub glwe_noise_distribution: DynamicDistribution<u64>,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+}
+
+/// Circuit bootstrapping parameters for
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128`](super::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+///
+/// The blind rotation uses the modulus switch of the server key, so the input bit is decoded
+/// with the failure probability of the shortint parameters. The output GGSW ciphertexts have a
+/// variance of about 2^-55.6 and a CMux using them adds a variance of about 2^-33.2, the 16 CMux
+/// of a vertical packing over 16 bits therefore add less noise than the sum of two PBS outputs.
+pub const CIRCUIT_BOOTSTRAPPING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128:
+    CircuitBootstrappingParameters = CircuitBootstrappingParameters {
+    pbs_base_log: DecompositionBaseLog(9),
+    pbs_level: DecompositionLevelCount(4),
+    pfpks_base_log: DecompositionBaseLog(12),
+    pfpks_level: DecompositionLevelCount(3),
+    cbs_base_log: DecompositionBaseLog(5),
+    cbs_level: DecompositionLevelCount(4),
+    glwe_noise_distribution: DynamicDistribution::new_t_uniform(17),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(4),
+};
@@ -34,6 +34,7 @@ use tfhe_csprng::seeders::Seeder;
 use tfhe_versionable::Versionize;
 
 pub mod aliases;
+pub mod circuit_bootstrapping;
 pub mod classic;
 pub mod compact_public_key_only;
 #[cfg(tarpaulin)]
@@ -68,6 +69,10 @@ use super::server_key::PBSConformanceParams;
 pub use super::PBSOrder;
 use crate::shortint::ciphertext::MaxDegree;
 pub use crate::shortint::parameters::list_compression::CompressionParameters;
+pub use circuit_bootstrapping::{
+    CircuitBootstrappingParameters,
+    CIRCUIT_BOOTSTRAPPING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+};
 pub use classic::ClassicPBSParameters;
 pub use compact_public_key_only::{
     CastingFunctionsOwned, CastingFunctionsView, CompactCiphertextListExpansionKind,
@@ -384,10 +384,13 @@ pub(crate) fn unchecked_add_assign(
     ct_right: &Ciphertext,
     max_noise_level: MaxNoiseLevel,
 ) {
+    let estimated_noise = ct_left.estimated_noise() + ct_right.estimated_noise();
+
     lwe_ciphertext_add_assign(&mut ct_left.ct, &ct_right.ct);
     ct_left.degree = Degree::new(ct_left.degree.get() + ct_right.degree.get());
     ct_left.set_noise_level(
         ct_left.noise_level() + ct_right.noise_level(),
         max_noise_level,
     );
+    ct_left.set_estimated_noise(estimated_noise);
 }
@@ -11,6 +11,8 @@ mod modulus_switch_noise_reduction;
 mod modulus_switched_compression;
 mod mul;
 mod neg;
+#[cfg(feature = "profiling")]
+pub(crate) mod profiling;
 mod scalar_add;
 mod scalar_bitwise_op;
 mod scalar_div_mod;
@@ -53,6 +55,8 @@ use crate::shortint::engine::{
 use crate::shortint::parameters::{
     CarryModulus, CiphertextConformanceParams, CiphertextModulus, MessageModulus, ModulusSwitchType,
 };
+#[cfg(feature = "noise-tracking")]
+use crate::shortint::{ciphertext::EstimatedNoise, noise_tracking};
 use crate::shortint::{PaddingBit, ShortintEncoding};
 use aligned_vec::ABox;
 use serde::{Deserialize, Serialize};
@@ -559,7 +563,7 @@ impl<'key> TryFrom<ServerKeyView<'key>> for StandardServerKeyView<'key> {
 }
 
 /// The number of elements in a [`LookupTable`] represented by a Glwe ciphertext
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct LookupTableSize {
     glwe_size: GlweSize,
     polynomial_size: PolynomialSize,
@@ -637,6 +641,56 @@ impl ServerKey {
             engine.new_server_key_with_max_degree(cks, max_degree)
         })
     }
+
+    /// Generate an insecure server key that evaluates lookup tables in the clear, see
+    /// [`CleartextAtomicPatternServerKey`](crate::shortint::atomic_pattern::CleartextAtomicPatternServerKey).
+    ///
+    /// # Warning
+    ///
+    /// The returned key contains the secret key of `cks`, it must only be used for tests and
+    /// debugging.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, ServerKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_cleartext_simulation(&cks);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let lut = sks.generate_lookup_table(|x| x * x % 4);
+    /// let ct_res = sks.apply_lookup_table(&ct, &lut);
+    /// assert_eq!(cks.decrypt(&ct_res), 1);
+    /// ```
+    #[cfg(feature = "cleartext-simulation")]
+    pub fn new_cleartext_simulation(cks: &ClientKey) -> Self {
+        let max_degree = MaxDegree::from_msg_carry_modulus(
+            cks.parameters().message_modulus(),
+            cks.parameters().carry_modulus(),
+        );
+
+        Self::new_cleartext_simulation_with_max_degree(cks, max_degree)
+    }
+
+    /// Generate an insecure server key that evaluates lookup tables in the clear, with a chosen
+    /// maximum degree
+    #[cfg(feature = "cleartext-simulation")]
+    pub fn new_cleartext_simulation_with_max_degree(
+        cks: &ClientKey,
+        max_degree: MaxDegree,
+    ) -> Self {
+        let ap_key = super::atomic_pattern::CleartextAtomicPatternServerKey::new(cks);
+
+        Self::from_raw_parts(
+            ap_key.into(),
+            cks.parameters().message_modulus(),
+            cks.parameters().carry_modulus(),
+            max_degree,
+            cks.parameters().max_noise_level(),
+        )
+    }
 }
 
 impl<AP: AtomicPattern> GenericServerKey<AP> {
@@ -904,10 +958,37 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
             return;
         }
 
+        #[cfg(feature = "noise-tracking")]
+        let noise = self.atomic_pattern.estimated_noise();
+        #[cfg(feature = "noise-tracking")]
+        noise_tracking::record_pbs(self.log2_failure_probability(ct));
+
         self.atomic_pattern.apply_lookup_table_assign(ct, acc);
 
         ct.degree = acc.degree;
         ct.set_noise_level_to_nominal();
+
+        #[cfg(feature = "noise-tracking")]
+        if let Some(noise) = noise {
+            ct.set_estimated_noise(EstimatedNoise::new(noise.output_variance));
+        }
+    }
+
+    /// Returns the log2 of the estimated probability that a PBS applied on the given ciphertext
+    /// gives a wrong result
+    ///
+    /// Returns `None` if the noise of the ciphertext or of the atomic pattern is unknown, see
+    /// [`noise_tracking`](crate::shortint::noise_tracking) for the details of the estimation.
+    #[cfg(feature = "noise-tracking")]
+    pub fn log2_failure_probability(&self, ct: &Ciphertext) -> Option<f64> {
+        if ct.is_trivial() {
+            return Some(f64::NEG_INFINITY);
+        }
+
+        let noise = self.atomic_pattern.estimated_noise()?;
+        let input_variance = ct.estimated_noise().variance()?;
+
+        Some(noise.log2_failure_probability(input_variance, ct.message_modulus, ct.carry_modulus))
     }
 
     /// Compute a keyswitch and programmable bootstrap applying several functions on an input
@@ -954,10 +1035,26 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
             return self.trivial_pbs_many_lut(ct, lut);
         }
 
+        #[cfg(feature = "noise-tracking")]
+        noise_tracking::record_pbs(self.log2_failure_probability(ct));
+
+        #[cfg(feature = "profiling")]
+        profiling::record_many_lut();
+
         let mut results = self.atomic_pattern.apply_many_lookup_table(ct, lut);
 
+        #[cfg(feature = "noise-tracking")]
+        let output_noise = self
+            .atomic_pattern
+            .estimated_noise()
+            .map_or(EstimatedNoise::UNKNOWN, |noise| {
+                EstimatedNoise::new(noise.output_variance)
+            });
+
         for ct in results.iter_mut() {
             ct.set_noise_level_to_nominal();
+            #[cfg(feature = "noise-tracking")]
+            ct.set_estimated_noise(output_noise);
         }
 
         results
@@ -1220,24 +1317,13 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
         let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
 
         assert_eq!(ct.noise_level(), NoiseLevel::ZERO);
-        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
         let ct_value = self
             .encoding(PaddingBit::Yes)
             .decode(Plaintext(*ct.ct.get_body().data))
             .0;
 
-        let lut_size = self.atomic_pattern.lookup_table_size();
-        let box_size = lut_size.polynomial_size().0 / modulus_sup as usize;
-        let result = if ct_value >= modulus_sup {
-            // padding bit is 1
-            let ct_value = ct_value % modulus_sup;
-            let index_in_lut = ct_value as usize * box_size;
-            acc.acc.get_body().as_ref()[index_in_lut].wrapping_neg()
-        } else {
-            let index_in_lut = ct_value as usize * box_size;
-            acc.acc.get_body().as_ref()[index_in_lut]
-        };
-        *ct.ct.get_mut_body().data = result;
+        *ct.ct.get_mut_body().data =
+            evaluate_lookup_table_in_clear(ct_value, acc, self.message_modulus, self.carry_modulus);
         ct.degree = acc.degree;
     }
 
@@ -1246,27 +1332,66 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
         let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
 
         assert_eq!(ct.noise_level(), NoiseLevel::ZERO);
-        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
         let ct_value = self
             .encoding(PaddingBit::Yes)
             .decode(Plaintext(*ct.ct.get_body().data))
             .0;
 
-        let lut_size = self.atomic_pattern.lookup_table_size();
-        let box_size = lut_size.polynomial_size().0 / modulus_sup as usize;
-
-        let padding_bit_set = ct_value >= modulus_sup;
-        let first_result_index_in_lut = {
-            let ct_value = ct_value % modulus_sup;
-            ct_value as usize * box_size
-        };
-
-        let function_count = lut.function_count();
-        let mut outputs = Vec::with_capacity(function_count);
+        evaluate_many_lookup_table_in_clear(ct_value, lut, self.message_modulus, self.carry_modulus)
+            .into_iter()
+            .zip(lut.per_function_output_degree.iter())
+            .map(|(result, output_degree)| {
+                let mut shortint_ct = ct.clone();
+                *shortint_ct.ct.get_mut_body().data = result;
+                shortint_ct.degree = *output_degree;
+                shortint_ct
+            })
+            .collect()
+    }
+}
 
-        let polynomial_size = lut.acc.polynomial_size();
+/// Returns the encoded output of the lookup table for a decoded input value, which includes the
+/// padding bit, i.e. what a noiseless PBS would compute
+pub(crate) fn evaluate_lookup_table_in_clear(
+    value: u64,
+    acc: &LookupTableOwned,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+) -> u64 {
+    let modulus_sup = message_modulus.0 * carry_modulus.0;
+    let box_size = acc.acc.polynomial_size().0 / modulus_sup as usize;
+
+    if value >= modulus_sup {
+        // padding bit is 1
+        let value = value % modulus_sup;
+        let index_in_lut = value as usize * box_size;
+        acc.acc.get_body().as_ref()[index_in_lut].wrapping_neg()
+    } else {
+        let index_in_lut = value as usize * box_size;
+        acc.acc.get_body().as_ref()[index_in_lut]
+    }
+}
 
-        for (fn_idx, output_degree) in lut.per_function_output_degree.iter().enumerate() {
+/// Returns the encoded outputs of each function of the lookup table for a decoded input value,
+/// see [`evaluate_lookup_table_in_clear`]
+pub(crate) fn evaluate_many_lookup_table_in_clear(
+    value: u64,
+    lut: &ManyLookupTableOwned,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+) -> Vec<u64> {
+    let modulus_sup = message_modulus.0 * carry_modulus.0;
+    let polynomial_size = lut.acc.polynomial_size();
+    let box_size = polynomial_size.0 / modulus_sup as usize;
+
+    let padding_bit_set = value >= modulus_sup;
+    let first_result_index_in_lut = {
+        let value = value % modulus_sup;
+        value as usize * box_size
+    };
+
+    (0..lut.function_count())
+        .map(|fn_idx| {
             let (index_in_lut, negation_due_to_wrap_around) = {
                 let mut index_in_lut =
                     first_result_index_in_lut + fn_idx * lut.sample_extraction_stride;
@@ -1282,23 +1407,15 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
                 (index_in_lut, negation_due_to_wrap_around)
             };
             let has_to_negate = padding_bit_set ^ negation_due_to_wrap_around;
-            let result = {
-                let mut result = lut.acc.get_body().as_ref()[index_in_lut];
-                if has_to_negate {
-                    result = result.wrapping_neg();
-                }
 
-                result
-            };
-
-            let mut shortint_ct = ct.clone();
-            *shortint_ct.ct.get_mut_body().data = result;
-            shortint_ct.degree = *output_degree;
-            outputs.push(shortint_ct);
-        }
+            let mut result = lut.acc.get_body().as_ref()[index_in_lut];
+            if has_to_negate {
+                result = result.wrapping_neg();
+            }
 
-        outputs
-    }
+            result
+        })
+        .collect()
 }
 
 impl<AP: AtomicPatternMut> GenericServerKey<AP> {
@@ -1456,6 +1573,9 @@ pub(crate) fn apply_standard_blind_rotate<OutputScalar, OutputCont>(
     #[cfg(feature = "pbs-stats")]
     let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
 
+    #[cfg(feature = "profiling")]
+    let start = std::time::Instant::now();
+
     let poly_size = acc.polynomial_size();
 
     let fft = Fft::new(poly_size);
@@ -1473,6 +1593,9 @@ pub(crate) fn apply_standard_blind_rotate<OutputScalar, OutputCont>(
     );
 
     blind_rotate_assign_mem_optimized(msed_lwe_in, acc, fourier_bsk, fft, buffers.stack());
+
+    #[cfg(feature = "profiling")]
+    profiling::record_blind_rotation(start.elapsed());
 }
 
 pub(crate) fn apply_multi_bit_blind_rotate<OutputScalar, OutputCont, KeyCont>(
@@ -1489,6 +1612,9 @@ pub(crate) fn apply_multi_bit_blind_rotate<OutputScalar, OutputCont, KeyCont>(
     #[cfg(feature = "pbs-stats")]
     let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
 
+    #[cfg(feature = "profiling")]
+    let start = std::time::Instant::now();
+
     multi_bit_blind_rotate_assign(
         multi_bit_modulus_switched_input,
         accumulator,
@@ -1496,6 +1622,23 @@ pub(crate) fn apply_multi_bit_blind_rotate<OutputScalar, OutputCont, KeyCont>(
         thread_count,
         deterministic_execution,
     );
+
+    #[cfg(feature = "profiling")]
+    profiling::record_blind_rotation(start.elapsed());
+}
+
+/// Runs the given keyswitch, which is recorded when the `profiling` feature is enabled
+#[inline]
+pub(crate) fn apply_keyswitch<R>(keyswitch: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "profiling")]
+    let start = std::time::Instant::now();
+
+    let result = keyswitch();
+
+    #[cfg(feature = "profiling")]
+    profiling::record_keyswitch(start.elapsed());
+
+    result
 }
 
 pub(crate) fn apply_programmable_bootstrap<InputScalar, InputCont, OutputScalar, OutputCont>(
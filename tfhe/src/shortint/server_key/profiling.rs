@@ -0,0 +1,63 @@
+//! Counters of the blind rotations and keyswitches computed by the server keys, used by the
+//! profiling of the high level API.
+//!
+//! Counters only ever increase, profilers compute the difference between two snapshots.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+static PBS_COUNT: AtomicU64 = AtomicU64::new(0);
+static KEYSWITCH_COUNT: AtomicU64 = AtomicU64::new(0);
+static MANY_LUT_COUNT: AtomicU64 = AtomicU64::new(0);
+static BUSY_NANOS: AtomicU64 = AtomicU64::new(0);
+
+/// Snapshot of the counters
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Counters {
+    pub pbs_count: u64,
+    pub keyswitch_count: u64,
+    pub many_lut_count: u64,
+    pub busy_time: Duration,
+}
+
+impl Counters {
+    pub fn snapshot() -> Self {
+        Self {
+            pbs_count: PBS_COUNT.load(Ordering::Relaxed),
+            keyswitch_count: KEYSWITCH_COUNT.load(Ordering::Relaxed),
+            many_lut_count: MANY_LUT_COUNT.load(Ordering::Relaxed),
+            busy_time: Duration::from_nanos(BUSY_NANOS.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl std::ops::Sub for Counters {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            pbs_count: self.pbs_count - rhs.pbs_count,
+            keyswitch_count: self.keyswitch_count - rhs.keyswitch_count,
+            many_lut_count: self.many_lut_count - rhs.many_lut_count,
+            busy_time: self.busy_time.saturating_sub(rhs.busy_time),
+        }
+    }
+}
+
+/// Records a blind rotation, counted as a PBS
+pub(crate) fn record_blind_rotation(busy_time: Duration) {
+    PBS_COUNT.fetch_add(1, Ordering::Relaxed);
+    BUSY_NANOS.fetch_add(busy_time.as_nanos() as u64, Ordering::Relaxed);
+}
+
+/// Records a keyswitch
+pub(crate) fn record_keyswitch(busy_time: Duration) {
+    KEYSWITCH_COUNT.fetch_add(1, Ordering::Relaxed);
+    BUSY_NANOS.fetch_add(busy_time.as_nanos() as u64, Ordering::Relaxed);
+}
+
+/// Records a PBS evaluating several lookup tables at once, its blind rotation is recorded
+/// separately
+pub(crate) fn record_many_lut() {
+    MANY_LUT_COUNT.fetch_add(1, Ordering::Relaxed);
+}
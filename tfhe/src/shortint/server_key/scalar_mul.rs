@@ -382,7 +382,9 @@ pub(crate) fn unchecked_scalar_mul_assign(
     max_noise_level: MaxNoiseLevel,
 ) {
     let scalar = u64::from(scalar);
+    let estimated_noise = ct.estimated_noise() * scalar;
     ct.set_noise_level(ct.noise_level() * scalar, max_noise_level);
+    ct.set_estimated_noise(estimated_noise);
     ct.degree = Degree::new(ct.degree.get() * scalar);
 
     match scalar {
@@ -399,6 +399,7 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
         ct_right: &Ciphertext,
     ) -> u64 {
         let (neg_right, z) = self.unchecked_neg_with_correcting_term(ct_right);
+        let estimated_noise = ct_left.estimated_noise() + ct_right.estimated_noise();
 
         lwe_ciphertext_add_assign(&mut ct_left.ct, &neg_right.ct);
 
@@ -406,6 +407,7 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
             ct_left.noise_level() + ct_right.noise_level(),
             self.max_noise_level,
         );
+        ct_left.set_estimated_noise(estimated_noise);
         ct_left.degree = Degree::new(ct_left.degree.get() + z);
 
         z
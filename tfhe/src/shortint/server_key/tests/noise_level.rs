@@ -359,3 +359,62 @@ fn test_noise_level_propagation_ci_run_filter() {
         }
     }
 }
+
+#[cfg(feature = "noise-tracking")]
+#[test]
+fn test_estimated_noise_propagation() {
+    use crate::core_crypto::commons::dispersion::Variance;
+    use crate::shortint::atomic_pattern::AtomicPattern;
+    use crate::shortint::parameters::test_params::TEST_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+
+    let params = TEST_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+
+    let keys = KEY_CACHE.get_from_param(params);
+    let (ck, sk) = (keys.client_key(), keys.server_key());
+
+    let variance = |ct: &Ciphertext| ct.estimated_noise().variance().unwrap().0;
+
+    let trivial = sk.create_trivial(1);
+    assert_eq!(variance(&trivial), 0.0);
+    assert_eq!(
+        sk.log2_failure_probability(&trivial),
+        Some(f64::NEG_INFINITY)
+    );
+
+    let ct = ck.encrypt(1);
+    let fresh_variance = ck
+        .parameters()
+        .encryption_noise_distribution()
+        .variance(2.0f64.powi(64))
+        .0;
+    assert_eq!(variance(&ct), fresh_variance);
+
+    let sum = sk.unchecked_add(&ct, &ct);
+    assert_eq!(variance(&sum), 2.0 * fresh_variance);
+
+    let diff = sk.unchecked_sub(&sum, &ct);
+    assert_eq!(variance(&diff), 3.0 * fresh_variance);
+
+    let product = sk.unchecked_scalar_mul(&ct, 3);
+    assert_eq!(variance(&product), 9.0 * fresh_variance);
+
+    let acc = sk.generate_lookup_table(|x| x);
+    let noise = sk.atomic_pattern.estimated_noise().unwrap();
+    let expected_p_fail = noise.log2_failure_probability(
+        Variance(variance(&sum)),
+        params.message_modulus,
+        params.carry_modulus,
+    );
+    assert_eq!(sk.log2_failure_probability(&sum), Some(expected_p_fail));
+
+    let pbs_result = sk.apply_lookup_table(&sum, &acc);
+    assert_eq!(variance(&pbs_result), noise.output_variance.0);
+
+    // The parameters target 2^-128 for ciphertexts at the max noise level, but the estimates
+    // overestimate the modulus switch noise (see the noise_tracking module), so only check that
+    // the estimated failure probability stays negligible
+    let mut worst_case = pbs_result.clone();
+    sk.unchecked_scalar_mul_assign(&mut worst_case, params.max_noise_level.get() as u8);
+    let log2_p_fail = sk.log2_failure_probability(&worst_case).unwrap();
+    assert!(log2_p_fail < -64.0, "{log2_p_fail}");
+}
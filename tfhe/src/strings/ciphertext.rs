@@ -353,7 +353,7 @@ impl FheString {
             }
             bytes.push(clear);
         }
-        Ok(String::from_utf8(bytes).expect("String is not valid ASCII"))
+        Ok(String::from_utf8(bytes).expect("String is not valid UTF-8"))
     }
 
     pub fn chars(&self) -> &[FheAsciiChar] {
@@ -450,7 +450,7 @@ impl FheString {
     }
 }
 
-pub(super) fn num_ascii_blocks(message_modulus: MessageModulus) -> usize {
+pub(crate) fn num_ascii_blocks(message_modulus: MessageModulus) -> usize {
     let message_modulus = message_modulus.0;
 
     assert!(message_modulus.is_power_of_two());
@@ -480,12 +480,33 @@ where
 {
     assert!(str.is_ascii() & !str.contains('\0'));
 
+    trivial_encrypt_bytes(key, encrypt_block, str.as_bytes(), padding)
+}
+
+/// Creates a trivial encryption of the bytes of `str`, each byte being stored as a
+/// [`FheAsciiChar`]
+///
+/// * key: typically a shortint::ClientKey/ServerKey
+/// * encrypt: the method of the `key` used to create a trivial block
+///
+/// The caller is responsible for checking that `bytes` contains no null bytes
+pub(in crate::strings) fn trivial_encrypt_bytes<BlockKey, F>(
+    key: &BlockKey,
+    encrypt_block: &F,
+    bytes: &[u8],
+    padding: Option<u32>,
+) -> FheString
+where
+    BlockKey: KnowsMessageModulus,
+    F: Fn(&BlockKey, u64) -> crate::shortint::Ciphertext,
+{
     let padded = padding.is_some_and(|p| p != 0);
 
     let num_blocks = num_ascii_blocks(key.message_modulus());
 
-    let mut enc_string: Vec<_> = str
-        .bytes()
+    let mut enc_string: Vec<_> = bytes
+        .iter()
+        .copied()
         .map(|char| FheAsciiChar {
             enc_char: encrypt_words_radix_impl(key, char, num_blocks, encrypt_block),
         })
@@ -2,6 +2,7 @@ mod comp;
 mod no_patterns;
 mod pattern;
 mod trim;
+mod utf8;
 
 pub use trim::split_ascii_whitespace;
 
@@ -69,6 +70,19 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         )
     }
 
+    pub fn trivial_encrypt_utf8(&self, str: &str, padding: Option<u32>) -> FheString {
+        let sk = self.inner.borrow();
+
+        assert!(!str.contains('\0'));
+
+        super::ciphertext::trivial_encrypt_bytes(
+            &sk.key,
+            &crate::shortint::ServerKey::create_trivial,
+            str.as_bytes(),
+            padding,
+        )
+    }
+
     // If an iterator is longer than the other, the "excess" characters are ignored. This function
     // performs the equality check by transforming the `str` and `pat` chars into two UInts
     fn asciis_eq<'a, I, U>(&self, str: I, pat: U) -> BooleanBlock
@@ -0,0 +1,413 @@
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+use crate::strings::server_key::ServerKey;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+// Lead byte of the 2-bytes UTF-8 encoding of the Latin-1 Supplement letters (U+00C0..=U+00FF)
+const LATIN_1_LEAD_BYTE: u8 = 0xC3;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    fn byte_in_range(&self, byte: &FheAsciiChar, min: u8, max: u8) -> BooleanBlock {
+        let sk = self.inner();
+
+        let (ge_min, le_max) = rayon::join(
+            || sk.scalar_ge_parallelized(byte.ciphertext(), min),
+            || sk.scalar_le_parallelized(byte.ciphertext(), max),
+        );
+
+        sk.boolean_bitand(&ge_min, &le_max)
+    }
+
+    // Returns true if the byte is a UTF-8 continuation byte (0b10xx_xxxx)
+    fn is_continuation_byte(&self, byte: &FheAsciiChar) -> BooleanBlock {
+        self.byte_in_range(byte, 0x80, 0xBF)
+    }
+
+    // Returns true for each byte that is directly preceded by a Latin-1 lead byte
+    fn follows_latin_1_lead_byte(&self, str: &FheString) -> Vec<BooleanBlock> {
+        let sk = self.inner();
+
+        let is_lead: Vec<_> = str
+            .chars()
+            .par_iter()
+            .map(|byte| sk.scalar_eq_parallelized(byte.ciphertext(), LATIN_1_LEAD_BYTE))
+            .collect();
+
+        std::iter::once(sk.create_trivial_boolean_block(false))
+            .chain(is_lead)
+            .take(str.len())
+            .collect()
+    }
+
+    // Adds (or subtracts) 32 to each byte for which `condition` is true, 32 being the distance
+    // between the lowercase and uppercase letters both in the ASCII and Latin-1 ranges
+    fn shift_case(&self, str: &FheString, conditions: Vec<BooleanBlock>, add: bool) -> FheString {
+        let sk = self.inner();
+
+        let mut result = str.clone();
+
+        result
+            .chars_mut()
+            .par_iter_mut()
+            .zip(conditions)
+            .for_each(|(byte, condition)| {
+                let mut amount: RadixCiphertext = condition.into_radix(self.num_ascii_blocks(), sk);
+                sk.scalar_mul_assign_parallelized(&mut amount, 32u8);
+
+                if add {
+                    sk.add_assign_parallelized(byte.ciphertext_mut(), &amount);
+                } else {
+                    sk.sub_assign_parallelized(byte.ciphertext_mut(), &amount);
+                }
+            });
+
+        result
+    }
+
+    /// Returns the number of Unicode scalar values (chars) of an UTF-8 encoded encrypted string.
+    ///
+    /// Contrary to [`Self::len`], which counts bytes, this counts every byte that is not a
+    /// continuation byte nor a padding null, so the result is always computed homomorphically.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "Zoë";
+    ///
+    /// let enc_s = ck.encrypt_utf8(s, Some(2));
+    ///
+    /// let result = sk.utf8_char_count(&enc_s);
+    /// let count = ck.inner().decrypt_radix::<u16>(&result);
+    ///
+    /// assert_eq!(count, 3);
+    /// ```
+    pub fn utf8_char_count(&self, str: &FheString) -> RadixCiphertext {
+        let sk = self.inner();
+
+        if str.chars().is_empty() {
+            return sk.create_trivial_zero_radix(16);
+        }
+
+        let is_char_start: Vec<RadixCiphertext> = str
+            .chars()
+            .par_iter()
+            .map(|byte| {
+                let (is_continuation, is_not_null) = rayon::join(
+                    || self.is_continuation_byte(byte),
+                    || sk.scalar_ne_parallelized(byte.ciphertext(), 0u8),
+                );
+
+                let is_not_continuation = sk.boolean_bitnot(&is_continuation);
+
+                sk.boolean_bitand(&is_not_continuation, &is_not_null)
+                    .into_radix(16, sk)
+            })
+            .collect();
+
+        sk.sum_ciphertexts_parallelized(is_char_start.iter())
+            .expect("There's at least one byte")
+    }
+
+    /// Returns `true` if the bytes of the encrypted string form a valid UTF-8 sequence.
+    ///
+    /// Padding nulls are ignored, however a multi-bytes sequence truncated by the padding (or by
+    /// the end of the string) makes the string invalid. Overlong encodings, surrogates and code
+    /// points above U+10FFFF are rejected, as in [`std::str::from_utf8`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = ck.encrypt_utf8("Größe", None);
+    ///
+    /// let result = sk.is_valid_utf8(&enc_s);
+    /// let is_valid = ck.inner().decrypt_bool(&result);
+    ///
+    /// assert!(is_valid);
+    /// ```
+    pub fn is_valid_utf8(&self, str: &FheString) -> BooleanBlock {
+        let sk = self.inner();
+
+        let n = str.len();
+        if n == 0 {
+            return sk.create_trivial_boolean_block(true);
+        }
+
+        struct ByteClass {
+            continuation: BooleanBlock,
+            lead_2: BooleanBlock,
+            lead_3: BooleanBlock,
+            lead_4: BooleanBlock,
+            invalid: BooleanBlock,
+            // Flags of the lead bytes which restrict the range of the following byte
+            is_e0: BooleanBlock,
+            is_ed: BooleanBlock,
+            is_f0: BooleanBlock,
+            is_f4: BooleanBlock,
+            // Ranges of a continuation byte, used with the flags above
+            lt_a0: BooleanBlock,
+            lt_90: BooleanBlock,
+        }
+
+        let classes: Vec<_> = str
+            .chars()
+            .par_iter()
+            .map(|byte| {
+                let ct = byte.ciphertext();
+                let lt = |v: u8| sk.scalar_lt_parallelized(ct, v);
+                let eq = |v: u8| sk.scalar_eq_parallelized(ct, v);
+
+                let ((continuation, lead_2), (lead_3, lead_4)) = rayon::join(
+                    || {
+                        rayon::join(
+                            || self.is_continuation_byte(byte),
+                            || self.byte_in_range(byte, 0xC2, 0xDF),
+                        )
+                    },
+                    || {
+                        rayon::join(
+                            || self.byte_in_range(byte, 0xE0, 0xEF),
+                            || self.byte_in_range(byte, 0xF0, 0xF4),
+                        )
+                    },
+                );
+
+                // 0xC0, 0xC1 and 0xF5..=0xFF never appear in valid UTF-8
+                let invalid = sk.boolean_bitor(
+                    &self.byte_in_range(byte, 0xC0, 0xC1),
+                    &sk.scalar_ge_parallelized(ct, 0xF5u8),
+                );
+
+                ByteClass {
+                    continuation,
+                    lead_2,
+                    lead_3,
+                    lead_4,
+                    invalid,
+                    is_e0: eq(0xE0),
+                    is_ed: eq(0xED),
+                    is_f0: eq(0xF0),
+                    is_f4: eq(0xF4),
+                    lt_a0: lt(0xA0),
+                    lt_90: lt(0x90),
+                }
+            })
+            .collect();
+
+        // A byte must be a continuation byte if and only if it is within the span of a preceding
+        // lead byte. The span of the last lead bytes may overflow the string (i + k >= n), in
+        // which case they are truncated sequences.
+        let errors: Vec<BooleanBlock> = (0..n + 3)
+            .into_par_iter()
+            .flat_map(|i| {
+                let mut expects_continuation = sk.create_trivial_boolean_block(false);
+                for k in 1..=i.min(3) {
+                    let Some(class) = classes.get(i - k) else {
+                        continue;
+                    };
+                    let mut spans = class.lead_4.clone();
+                    if k <= 2 {
+                        sk.boolean_bitor_assign(&mut spans, &class.lead_3);
+                    }
+                    if k == 1 {
+                        sk.boolean_bitor_assign(&mut spans, &class.lead_2);
+                    }
+                    sk.boolean_bitor_assign(&mut expects_continuation, &spans);
+                }
+
+                if i >= n {
+                    return vec![expects_continuation];
+                }
+
+                let class = &classes[i];
+                let mismatch = sk.boolean_bitxor(&expects_continuation, &class.continuation);
+
+                let mut errors = vec![mismatch, class.invalid.clone()];
+
+                // Second byte restrictions (overlong encodings, surrogates, > U+10FFFF)
+                if i > 0 {
+                    let prev = &classes[i - 1];
+                    let ge_a0 = sk.boolean_bitnot(&class.lt_a0);
+                    let ge_90 = sk.boolean_bitnot(&class.lt_90);
+
+                    errors.push(sk.boolean_bitand(&prev.is_e0, &class.lt_a0));
+                    errors.push(sk.boolean_bitand(&prev.is_ed, &ge_a0));
+                    errors.push(sk.boolean_bitand(&prev.is_f0, &class.lt_90));
+                    errors.push(sk.boolean_bitand(&prev.is_f4, &ge_90));
+                }
+
+                errors
+            })
+            .collect();
+
+        let no_errors = errors
+            .into_iter()
+            .map(|error| sk.boolean_bitnot(&error).into_raw_parts())
+            .collect();
+
+        BooleanBlock::new_unchecked(sk.are_all_comparisons_block_true(no_errors))
+    }
+
+    /// Returns a new encrypted string with all characters converted to lowercase.
+    ///
+    /// The string is interpreted as UTF-8: in addition to ASCII letters, the uppercase letters of
+    /// the Latin-1 Supplement block (U+00C0..=U+00DE, except U+00D7 '×') are converted. As these
+    /// mappings preserve the encoded length, the result has the same number of bytes as the input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = ck.encrypt_utf8("ÉCOLE", None);
+    ///
+    /// let result = sk.utf8_to_lowercase(&enc_s);
+    /// let lowercased = ck.decrypt_utf8(&result);
+    ///
+    /// assert_eq!(lowercased, "école");
+    /// ```
+    pub fn utf8_to_lowercase(&self, str: &FheString) -> FheString {
+        let sk = self.inner();
+
+        let follows_lead = self.follows_latin_1_lead_byte(str);
+
+        // Returns 1 if the corresponding byte is part of an uppercase character, 0 otherwise
+        let uppercase_bytes: Vec<_> = str
+            .chars()
+            .par_iter()
+            .zip(follows_lead)
+            .map(|(byte, follows_lead)| {
+                let (is_ascii_upper, (is_latin_1_upper, is_times_sign)) = rayon::join(
+                    || self.byte_in_range(byte, b'A', b'Z'),
+                    || {
+                        rayon::join(
+                            || self.byte_in_range(byte, 0x80, 0x9E),
+                            || sk.scalar_eq_parallelized(byte.ciphertext(), 0x97u8),
+                        )
+                    },
+                );
+
+                let mut is_latin_1_upper = sk.boolean_bitand(&is_latin_1_upper, &follows_lead);
+                sk.boolean_bitand_assign(&mut is_latin_1_upper, &sk.boolean_bitnot(&is_times_sign));
+
+                sk.boolean_bitor(&is_ascii_upper, &is_latin_1_upper)
+            })
+            .collect();
+
+        self.shift_case(str, uppercase_bytes, true)
+    }
+
+    /// Returns a new encrypted string with all characters converted to uppercase.
+    ///
+    /// The string is interpreted as UTF-8: in addition to ASCII letters, the lowercase letters of
+    /// the Latin-1 Supplement block (U+00E0..=U+00FE, except U+00F7 '÷') are converted. Letters
+    /// whose uppercase form lies outside this block ('ß', 'ÿ', 'µ') are left untouched, so that
+    /// the result has the same number of bytes as the input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = ck.encrypt_utf8("Noël", None);
+    ///
+    /// let result = sk.utf8_to_uppercase(&enc_s);
+    /// let uppercased = ck.decrypt_utf8(&result);
+    ///
+    /// assert_eq!(uppercased, "NOËL");
+    /// ```
+    pub fn utf8_to_uppercase(&self, str: &FheString) -> FheString {
+        let sk = self.inner();
+
+        let follows_lead = self.follows_latin_1_lead_byte(str);
+
+        // Returns 1 if the corresponding byte is part of a lowercase character, 0 otherwise
+        let lowercase_bytes: Vec<_> = str
+            .chars()
+            .par_iter()
+            .zip(follows_lead)
+            .map(|(byte, follows_lead)| {
+                let (is_ascii_lower, (is_latin_1_lower, is_division_sign)) = rayon::join(
+                    || self.byte_in_range(byte, b'a', b'z'),
+                    || {
+                        rayon::join(
+                            || self.byte_in_range(byte, 0xA0, 0xBE),
+                            || sk.scalar_eq_parallelized(byte.ciphertext(), 0xB7u8),
+                        )
+                    },
+                );
+
+                let mut is_latin_1_lower = sk.boolean_bitand(&is_latin_1_lower, &follows_lead);
+                sk.boolean_bitand_assign(
+                    &mut is_latin_1_lower,
+                    &sk.boolean_bitnot(&is_division_sign),
+                );
+
+                sk.boolean_bitor(&is_ascii_lower, &is_latin_1_lower)
+            })
+            .collect();
+
+        self.shift_case(str, lowercase_bytes, false)
+    }
+
+    /// Returns `true` if two UTF-8 encoded encrypted strings are equal, ignoring the case of the
+    /// ASCII and Latin-1 Supplement letters.
+    ///
+    /// See [`Self::utf8_to_lowercase`] for the set of characters that are case folded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s1 = ck.encrypt_utf8("Ångström", None);
+    /// let enc_s2 = ck.encrypt_utf8("ÅNGSTRÖM", None);
+    ///
+    /// let result = sk.utf8_eq_ignore_case(&enc_s1, &enc_s2);
+    /// let are_equal = ck.inner().decrypt_bool(&result);
+    ///
+    /// assert!(are_equal);
+    /// ```
+    pub fn utf8_eq_ignore_case(&self, lhs: &FheString, rhs: &FheString) -> BooleanBlock {
+        let (lhs, rhs) = rayon::join(
+            || self.utf8_to_lowercase(lhs),
+            || self.utf8_to_lowercase(rhs),
+        );
+
+        self.eq(&lhs, (&rhs).into())
+    }
+}
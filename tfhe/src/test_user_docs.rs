@@ -46,6 +46,11 @@ mod test_cpu_doc {
         "../docs/fhe-computation/advanced-features/zk-pok.md",
         advanced_features_zk_pok
     );
+    #[cfg(feature = "transciphering")]
+    doctest!(
+        "../docs/fhe-computation/advanced-features/transciphering.md",
+        advanced_features_transciphering
+    );
     doctest!(
         "../docs/fhe-computation/advanced-features/upgrade-key-chain.md",
         upgrade_key_chain
@@ -87,6 +92,16 @@ mod test_cpu_doc {
         "../docs/fhe-computation/data-handling/serialization.md",
         data_handling_serialization
     );
+    #[cfg(feature = "mapped-keys")]
+    doctest!(
+        "../docs/fhe-computation/data-handling/mapped-keys.md",
+        data_handling_mapped_keys
+    );
+    #[cfg(feature = "key-wrapping")]
+    doctest!(
+        "../docs/fhe-computation/data-handling/key-wrapping.md",
+        data_handling_key_wrapping
+    );
 
     // OPERATIONS
     doctest!(
@@ -124,6 +139,11 @@ mod test_cpu_doc {
 
     // TOOLING
     doctest!("../docs/fhe-computation/tooling/debug.md", tooling_debug);
+    #[cfg(feature = "cleartext-simulation")]
+    doctest!(
+        "../docs/fhe-computation/tooling/cleartext-simulation.md",
+        tooling_cleartext_simulation
+    );
     doctest!(
         "../docs/fhe-computation/tooling/pbs-stats.md",
         tooling_pbs_stats
@@ -3,24 +3,27 @@ use std::convert::Infallible;
 use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
 use tfhe_zk_pok::backward_compatibility::pke::ProofV0;
 use tfhe_zk_pok::backward_compatibility::{IncompleteProof, SerializablePKEv1PublicParamsV0};
+use tfhe_zk_pok::curve_api::Compressible;
 use tfhe_zk_pok::proofs::pke::Proof;
+use tfhe_zk_pok::proofs::pke_v2::Proof as ProofV2;
 use tfhe_zk_pok::serialization::InvalidSerializedPublicParamsError;
 
 type Curve = tfhe_zk_pok::curve_api::Bls12_446;
 
 use super::{
-    CompactPkeCrs, CompactPkeProof, CompressedCompactPkeCrs, SerializableCompactPkePublicParams,
+    CompactPkeCrs, CompactPkeCrsCeremony, CompactPkeProof, CompressedCompactPkeCrs,
+    SerializableCompactPkePublicParams, ZkCompactPkeV1PublicParams, ZkCompactPkeV2PublicParams,
 };
 
 #[derive(Version)]
 #[repr(transparent)]
 pub struct CompactPkeCrsV0(SerializablePKEv1PublicParamsV0);
 
-impl Upgrade<CompactPkeCrs> for CompactPkeCrsV0 {
+impl Upgrade<CompactPkeCrsV1> for CompactPkeCrsV0 {
     type Error = InvalidSerializedPublicParamsError;
 
-    fn upgrade(self) -> Result<CompactPkeCrs, Self::Error> {
-        Ok(CompactPkeCrs::PkeV1(
+    fn upgrade(self) -> Result<CompactPkeCrsV1, Self::Error> {
+        Ok(CompactPkeCrsV1::PkeV1(
             self.0
                 .upgrade()
                 .unwrap() // update is infallible so it is ok to unwrap
@@ -29,29 +32,66 @@ impl Upgrade<CompactPkeCrs> for CompactPkeCrsV0 {
     }
 }
 
+#[derive(Version)]
+#[allow(clippy::large_enum_variant)]
+pub enum CompactPkeCrsV1 {
+    PkeV1(ZkCompactPkeV1PublicParams),
+    PkeV2(ZkCompactPkeV2PublicParams),
+}
+
+impl Upgrade<CompactPkeCrs> for CompactPkeCrsV1 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<CompactPkeCrs, Self::Error> {
+        Ok(match self {
+            Self::PkeV1(public_params) => CompactPkeCrs::PkeV1(public_params),
+            Self::PkeV2(public_params) => CompactPkeCrs::PkeV2(public_params),
+        })
+    }
+}
+
 #[derive(VersionsDispatch)]
 #[allow(clippy::large_enum_variant)]
 pub enum CompactPkeCrsVersions {
     V0(CompactPkeCrsV0),
-    V1(CompactPkeCrs),
+    V1(CompactPkeCrsV1),
+    V2(CompactPkeCrs),
 }
 
 #[derive(Version)]
 #[repr(transparent)]
 pub struct CompressedCompactPkeCrsV0(SerializableCompactPkePublicParams);
 
-impl Upgrade<CompressedCompactPkeCrs> for CompressedCompactPkeCrsV0 {
+impl Upgrade<CompressedCompactPkeCrsV1> for CompressedCompactPkeCrsV0 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<CompressedCompactPkeCrsV1, Self::Error> {
+        Ok(CompressedCompactPkeCrsV1::PkeV1(self.0))
+    }
+}
+
+#[derive(Version)]
+pub enum CompressedCompactPkeCrsV1 {
+    PkeV1(<ZkCompactPkeV1PublicParams as Compressible>::Compressed),
+    PkeV2(<ZkCompactPkeV2PublicParams as Compressible>::Compressed),
+}
+
+impl Upgrade<CompressedCompactPkeCrs> for CompressedCompactPkeCrsV1 {
     type Error = Infallible;
 
     fn upgrade(self) -> Result<CompressedCompactPkeCrs, Self::Error> {
-        Ok(CompressedCompactPkeCrs::PkeV1(self.0))
+        Ok(match self {
+            Self::PkeV1(compressed_params) => CompressedCompactPkeCrs::PkeV1(compressed_params),
+            Self::PkeV2(compressed_params) => CompressedCompactPkeCrs::PkeV2(compressed_params),
+        })
     }
 }
 
 #[derive(VersionsDispatch)]
 pub enum CompressedCompactPkeCrsVersions {
     V0(CompressedCompactPkeCrsV0),
-    V1(CompressedCompactPkeCrs),
+    V1(CompressedCompactPkeCrsV1),
+    V2(CompressedCompactPkeCrs),
 }
 
 #[derive(Version)]
@@ -70,11 +110,29 @@ impl Upgrade<CompactPkeProofV1> for CompactPkeProofV0 {
 #[repr(transparent)]
 pub struct CompactPkeProofV1(Proof<Curve>);
 
-impl Upgrade<CompactPkeProof> for CompactPkeProofV1 {
+impl Upgrade<CompactPkeProofV2> for CompactPkeProofV1 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<CompactPkeProofV2, Self::Error> {
+        Ok(CompactPkeProofV2::PkeV1(self.0))
+    }
+}
+
+#[derive(Version)]
+#[allow(clippy::large_enum_variant)]
+pub enum CompactPkeProofV2 {
+    PkeV1(Proof<Curve>),
+    PkeV2(ProofV2<Curve>),
+}
+
+impl Upgrade<CompactPkeProof> for CompactPkeProofV2 {
     type Error = Infallible;
 
     fn upgrade(self) -> Result<CompactPkeProof, Self::Error> {
-        Ok(CompactPkeProof::PkeV1(self.0))
+        Ok(match self {
+            Self::PkeV1(proof) => CompactPkeProof::PkeV1(proof),
+            Self::PkeV2(proof) => CompactPkeProof::PkeV2(proof),
+        })
     }
 }
 
@@ -83,5 +141,11 @@ impl Upgrade<CompactPkeProof> for CompactPkeProofV1 {
 pub enum CompactPkeProofVersions {
     V0(CompactPkeProofV0),
     V1(CompactPkeProofV1),
-    V2(CompactPkeProof),
+    V2(CompactPkeProofV2),
+    V3(CompactPkeProof),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompactPkeCrsCeremonyVersions {
+    V0(CompactPkeCrsCeremony),
 }
@@ -0,0 +1,115 @@
+use super::backward_compatibility::CompactPkeCrsCeremonyVersions;
+use super::{CompactPkeCrs, CompactPkeZkScheme, Curve, ZkMSBZeroPaddingBitCount};
+use crate::core_crypto::commons::math::random::BoundedDistribution;
+use crate::core_crypto::prelude::*;
+use crate::named::Named;
+use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use tfhe_versionable::Versionize;
+use tfhe_zk_pok::proofs::pke_v2::ceremony::CrsCeremony;
+use tfhe_zk_pok::proofs::pke_v2::Bound as ZkBound;
+
+/// A multi-party ceremony to generate a [`CompactPkeCrs`].
+///
+/// The CRS is built from a secret that must never be known by anyone. When the CRS is generated
+/// with [`CompactPkeCrs::new`], the party that generates it has to be trusted to erase it. With a
+/// ceremony, the participants re-randomize the CRS one after the other, each one with its own
+/// secret, and the final CRS is secure as long as at least one of them is honest.
+///
+/// Each contribution is recorded with a proof that the CRS has been correctly updated. Before
+/// contributing, a participant should check the state received from the previous one with
+/// [`Self::verify_update`]. Anyone can check the whole transcript with [`Self::verify`].
+///
+/// Only the V2 zk scheme is supported.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(CompactPkeCrsCeremonyVersions)]
+pub struct CompactPkeCrsCeremony {
+    ceremony: CrsCeremony<Curve>,
+}
+
+impl Named for CompactPkeCrsCeremony {
+    const NAME: &'static str = "zk::CompactPkeCrsCeremony";
+}
+
+impl CompactPkeCrsCeremony {
+    /// Starts a new ceremony for a CRS with the given tfhe parameters.
+    ///
+    /// The parameters are the same as the ones of [`CompactPkeCrs::new`]. Until the first
+    /// contribution, the CRS does not hold any secret.
+    pub fn new<Scalar, NoiseDistribution>(
+        lwe_dim: LweDimension,
+        max_num_cleartext: LweCiphertextCount,
+        noise_distribution: NoiseDistribution,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+        plaintext_modulus: Scalar,
+        msbs_zero_padding_bit_count: ZkMSBZeroPaddingBitCount,
+        rng: &mut impl RngCore,
+    ) -> crate::Result<Self>
+    where
+        Scalar: UnsignedInteger + CastInto<u64> + Debug,
+        NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    {
+        let (d, k, b, q, t) = CompactPkeCrs::prepare_crs_parameters(
+            lwe_dim,
+            max_num_cleartext,
+            noise_distribution,
+            ciphertext_modulus,
+            plaintext_modulus,
+            CompactPkeZkScheme::V2,
+        )?;
+
+        // Same bound as the one used by `CompactPkeCrs::new`
+        let ceremony = CrsCeremony::new(
+            d.0,
+            k.0,
+            b.cast_into(),
+            q,
+            t.cast_into(),
+            msbs_zero_padding_bit_count.0,
+            ZkBound::CS,
+            rng,
+        );
+
+        Ok(Self { ceremony })
+    }
+
+    /// Re-randomizes the CRS and adds the contribution to the transcript of the ceremony.
+    ///
+    /// `rng` should be a cryptographically secure generator that is only used by this
+    /// participant.
+    pub fn contribute(&mut self, participant: &str, rng: &mut impl RngCore) {
+        self.ceremony.contribute(participant, rng);
+    }
+
+    /// Names of the participants that have contributed so far, in order
+    pub fn participants(&self) -> impl Iterator<Item = &str> {
+        self.ceremony
+            .contributions()
+            .iter()
+            .map(|contribution| contribution.participant())
+    }
+
+    /// Verifies the transcript of the ceremony and the CRS that it produced
+    pub fn verify(&self, rng: &mut impl RngCore) -> crate::Result<()> {
+        self.ceremony
+            .verify(rng)
+            .map_err(|e| crate::Error::new(format!("Invalid CRS ceremony: {e}")))
+    }
+
+    /// Verifies that `self` has been obtained by adding contributions to the `previous` state of
+    /// the ceremony
+    pub fn verify_update(&self, previous: &Self, rng: &mut impl RngCore) -> crate::Result<()> {
+        self.ceremony
+            .verify_update(&previous.ceremony, rng)
+            .map_err(|e| crate::Error::new(format!("Invalid CRS ceremony update: {e}")))
+    }
+
+    /// Verifies the ceremony and returns the final CRS
+    pub fn finalize(self, rng: &mut impl RngCore) -> crate::Result<CompactPkeCrs> {
+        self.ceremony
+            .finalize(rng)
+            .map(CompactPkeCrs::PkeV2)
+            .map_err(|e| crate::Error::new(format!("Invalid CRS ceremony: {e}")))
+    }
+}
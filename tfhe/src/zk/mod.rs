@@ -1,4 +1,5 @@
 pub mod backward_compatibility;
+mod ceremony;
 
 use crate::conformance::{EnumSet, ParameterSetConformant};
 use crate::core_crypto::commons::math::random::{
@@ -25,10 +26,12 @@ use tfhe_zk_pok::proofs::pke_v2::{
     PkeV2HashMode, Proof as ProofV2, PublicCommit as PublicCommitV2,
 };
 
+pub use ceremony::CompactPkeCrsCeremony;
 pub use tfhe_zk_pok::curve_api::Compressible;
 pub use tfhe_zk_pok::proofs::pke_v2::PkeV2HashMode as ZkPkeV2HashMode;
 pub use tfhe_zk_pok::proofs::ComputeLoad as ZkComputeLoad;
 type Curve = tfhe_zk_pok::curve_api::Bls12_446;
+type CurveBn254 = tfhe_zk_pok::curve_api::Bn254;
 
 #[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
 #[versionize(CompactPkeProofVersions)]
@@ -36,6 +39,7 @@ type Curve = tfhe_zk_pok::curve_api::Bls12_446;
 pub enum CompactPkeProof {
     PkeV1(ProofV1<Curve>),
     PkeV2(ProofV2<Curve>),
+    PkeV2Bn254(ProofV2<CurveBn254>),
 }
 
 impl Named for CompactPkeProof {
@@ -154,7 +158,7 @@ impl CompactPkeV2ProofConformanceParams {
     }
 }
 
-impl ParameterSetConformant for ProofV2<Curve> {
+impl<G: tfhe_zk_pok::curve_api::Curve> ParameterSetConformant for ProofV2<G> {
     type ParameterSet = CompactPkeV2ProofConformanceParams;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
@@ -214,14 +218,20 @@ impl ParameterSetConformant for CompactPkeProof {
             (Self::PkeV2(proof), CompactPkeProofConformanceParams::PkeV2(params)) => {
                 proof.is_conformant(params)
             }
+            (Self::PkeV2Bn254(proof), CompactPkeProofConformanceParams::PkeV2(params)) => {
+                proof.is_conformant(params)
+            }
             (Self::PkeV1(_), CompactPkeProofConformanceParams::PkeV2(_))
-            | (Self::PkeV2(_), CompactPkeProofConformanceParams::PkeV1(_)) => false,
+            | (Self::PkeV2(_) | Self::PkeV2Bn254(_), CompactPkeProofConformanceParams::PkeV1(_)) => {
+                false
+            }
         }
     }
 }
 
 pub type ZkCompactPkeV1PublicParams = tfhe_zk_pok::proofs::pke::PublicParams<Curve>;
 pub type ZkCompactPkeV2PublicParams = tfhe_zk_pok::proofs::pke_v2::PublicParams<Curve>;
+pub type ZkCompactPkeV2Bn254PublicParams = tfhe_zk_pok::proofs::pke_v2::PublicParams<CurveBn254>;
 
 // Keep this to be able to deserialize CRS that were serialized as "CompactPkePublicParams" (TFHE-rs
 // 0.10 and before)
@@ -294,7 +304,9 @@ impl ParameterSetConformant for ZkCompactPkeV1PublicParams {
     }
 }
 
-impl ParameterSetConformant for ZkCompactPkeV2PublicParams {
+impl<G: tfhe_zk_pok::curve_api::Curve> ParameterSetConformant
+    for tfhe_zk_pok::proofs::pke_v2::PublicParams<G>
+{
     type ParameterSet = CompactPkeCrsConformanceParams;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
@@ -344,6 +356,18 @@ pub enum CompactPkeZkScheme {
     V2,
 }
 
+/// The elliptic curve used by the zk proofs.
+///
+/// Proofs built on [`CompactPkeZkCurve::Bn254`] can be checked with the pairing precompiles of
+/// Ethereum, but this curve offers a lower security level than the default one. It is only
+/// available for the V2 [`CompactPkeZkScheme`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CompactPkeZkCurve {
+    #[default]
+    Bls12_446,
+    Bn254,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ZkMSBZeroPaddingBitCount(pub u64);
 
@@ -357,6 +381,7 @@ pub struct ZkMSBZeroPaddingBitCount(pub u64);
 pub enum CompactPkeCrs {
     PkeV1(ZkCompactPkeV1PublicParams),
     PkeV2(ZkCompactPkeV2PublicParams),
+    PkeV2Bn254(ZkCompactPkeV2Bn254PublicParams),
 }
 
 impl Named for CompactPkeCrs {
@@ -377,6 +402,12 @@ impl From<ZkCompactPkeV2PublicParams> for CompactPkeCrs {
     }
 }
 
+impl From<ZkCompactPkeV2Bn254PublicParams> for CompactPkeCrs {
+    fn from(value: ZkCompactPkeV2Bn254PublicParams) -> Self {
+        Self::PkeV2Bn254(value)
+    }
+}
+
 impl CompactPkeCrs {
     /// Compute the bound used by the V1 Scheme from the noise distribution
     fn compute_bound_v1<Scalar, NoiseDistribution>(
@@ -554,6 +585,34 @@ impl CompactPkeCrs {
         msbs_zero_padding_bit_count: ZkMSBZeroPaddingBitCount,
         rng: &mut impl RngCore,
     ) -> crate::Result<Self>
+    where
+        Scalar: UnsignedInteger + CastInto<u64> + Debug,
+        NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    {
+        Self::new_with_curve(
+            lwe_dim,
+            max_num_cleartext,
+            noise_distribution,
+            ciphertext_modulus,
+            plaintext_modulus,
+            msbs_zero_padding_bit_count,
+            CompactPkeZkCurve::default(),
+            rng,
+        )
+    }
+
+    /// Generates a new zk CRS from the tfhe parameters, for proofs built on the given curve.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_curve<Scalar, NoiseDistribution>(
+        lwe_dim: LweDimension,
+        max_num_cleartext: LweCiphertextCount,
+        noise_distribution: NoiseDistribution,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+        plaintext_modulus: Scalar,
+        msbs_zero_padding_bit_count: ZkMSBZeroPaddingBitCount,
+        curve: CompactPkeZkCurve,
+        rng: &mut impl RngCore,
+    ) -> crate::Result<Self>
     where
         Scalar: UnsignedInteger + CastInto<u64> + Debug,
         NoiseDistribution: BoundedDistribution<Scalar::Signed>,
@@ -566,17 +625,27 @@ impl CompactPkeCrs {
             plaintext_modulus,
             CompactPkeZkScheme::V2,
         )?;
-        let public_params = crs_gen_v2(
-            d.0,
-            k.0,
-            b.cast_into(),
-            q,
-            t.cast_into(),
-            msbs_zero_padding_bit_count.0,
-            rng,
-        );
 
-        Ok(Self::PkeV2(public_params))
+        Ok(match curve {
+            CompactPkeZkCurve::Bls12_446 => Self::PkeV2(crs_gen_v2(
+                d.0,
+                k.0,
+                b.cast_into(),
+                q,
+                t.cast_into(),
+                msbs_zero_padding_bit_count.0,
+                rng,
+            )),
+            CompactPkeZkCurve::Bn254 => Self::PkeV2Bn254(crs_gen_v2(
+                d.0,
+                k.0,
+                b.cast_into(),
+                q,
+                t.cast_into(),
+                msbs_zero_padding_bit_count.0,
+                rng,
+            )),
+        })
     }
 
     /// Maximum number of messages that can be proven in a single list using this CRS
@@ -584,6 +653,7 @@ impl CompactPkeCrs {
         match self {
             Self::PkeV1(public_params) => LweCiphertextCount(public_params.k),
             Self::PkeV2(public_params) => LweCiphertextCount(public_params.k),
+            Self::PkeV2Bn254(public_params) => LweCiphertextCount(public_params.k),
         }
     }
 
@@ -592,6 +662,7 @@ impl CompactPkeCrs {
         match self {
             Self::PkeV1(public_params) => LweDimension(public_params.d),
             Self::PkeV2(public_params) => LweDimension(public_params.d),
+            Self::PkeV2Bn254(public_params) => LweDimension(public_params.d),
         }
     }
 
@@ -600,6 +671,7 @@ impl CompactPkeCrs {
         match self {
             Self::PkeV1(public_params) => CiphertextModulus::new(public_params.q as u128),
             Self::PkeV2(public_params) => CiphertextModulus::new(public_params.q as u128),
+            Self::PkeV2Bn254(public_params) => CiphertextModulus::new(public_params.q as u128),
         }
     }
 
@@ -608,6 +680,7 @@ impl CompactPkeCrs {
         match self {
             Self::PkeV1(public_params) => public_params.t,
             Self::PkeV2(public_params) => public_params.t,
+            Self::PkeV2Bn254(public_params) => public_params.t,
         }
     }
 
@@ -616,6 +689,7 @@ impl CompactPkeCrs {
         match self {
             Self::PkeV1(public_params) => public_params.exclusive_max_noise(),
             Self::PkeV2(public_params) => public_params.exclusive_max_noise(),
+            Self::PkeV2Bn254(public_params) => public_params.exclusive_max_noise(),
         }
     }
 
@@ -623,7 +697,15 @@ impl CompactPkeCrs {
     pub fn scheme_version(&self) -> CompactPkeZkScheme {
         match self {
             Self::PkeV1(_) => CompactPkeZkScheme::V1,
-            Self::PkeV2(_) => CompactPkeZkScheme::V2,
+            Self::PkeV2(_) | Self::PkeV2Bn254(_) => CompactPkeZkScheme::V2,
+        }
+    }
+
+    /// Return the elliptic curve used by this CRS
+    pub fn curve(&self) -> CompactPkeZkCurve {
+        match self {
+            Self::PkeV1(_) | Self::PkeV2(_) => CompactPkeZkCurve::Bls12_446,
+            Self::PkeV2Bn254(_) => CompactPkeZkCurve::Bn254,
         }
     }
 
@@ -755,6 +837,29 @@ impl CompactPkeCrs {
 
                 CompactPkeProof::PkeV2(proof)
             }
+            Self::PkeV2Bn254(public_params) => {
+                let (public_commit, private_commit) = commit_v2(
+                    key_mask,
+                    key_body,
+                    ct_mask,
+                    ct_body,
+                    binary_random_vector,
+                    mask_noise,
+                    messages,
+                    body_noise,
+                    public_params,
+                );
+
+                let proof = prove_v2(
+                    (public_params, &public_commit),
+                    &private_commit,
+                    metadata,
+                    load,
+                    &seed,
+                );
+
+                CompactPkeProof::PkeV2Bn254(proof)
+            }
         }
     }
 
@@ -815,9 +920,14 @@ impl CompactPkeCrs {
                 let public_commit = PublicCommitV2::new(key_mask, key_body, ct_mask, ct_body);
                 verify_v2(proof, (public_params, &public_commit), metadata)
             }
+            (Self::PkeV2Bn254(public_params), CompactPkeProof::PkeV2Bn254(proof)) => {
+                let public_commit = PublicCommitV2::new(key_mask, key_body, ct_mask, ct_body);
+                verify_v2(proof, (public_params, &public_commit), metadata)
+            }
 
-            (Self::PkeV1(_), CompactPkeProof::PkeV2(_))
-            | (Self::PkeV2(_), CompactPkeProof::PkeV1(_)) => {
+            (Self::PkeV1(_), CompactPkeProof::PkeV2(_) | CompactPkeProof::PkeV2Bn254(_))
+            | (Self::PkeV2(_), CompactPkeProof::PkeV1(_) | CompactPkeProof::PkeV2Bn254(_))
+            | (Self::PkeV2Bn254(_), CompactPkeProof::PkeV1(_) | CompactPkeProof::PkeV2(_)) => {
                 // Proof is not compatible with the CRS, so we refuse it right there
                 Err(())
             }
@@ -837,6 +947,7 @@ impl ParameterSetConformant for CompactPkeCrs {
         match self {
             Self::PkeV1(public_params) => public_params.is_conformant(parameter_set),
             Self::PkeV2(public_params) => public_params.is_conformant(parameter_set),
+            Self::PkeV2Bn254(public_params) => public_params.is_conformant(parameter_set),
         }
     }
 }
@@ -847,6 +958,7 @@ impl ParameterSetConformant for CompactPkeCrs {
 pub enum CompressedCompactPkeCrs {
     PkeV1(<ZkCompactPkeV1PublicParams as Compressible>::Compressed),
     PkeV2(<ZkCompactPkeV2PublicParams as Compressible>::Compressed),
+    PkeV2Bn254(<ZkCompactPkeV2Bn254PublicParams as Compressible>::Compressed),
 }
 
 // The NAME impl is the same as CompactPkeCrs because once serialized they are represented with the
@@ -864,6 +976,9 @@ impl Compressible for CompactPkeCrs {
         match self {
             Self::PkeV1(public_params) => CompressedCompactPkeCrs::PkeV1(public_params.compress()),
             Self::PkeV2(public_params) => CompressedCompactPkeCrs::PkeV2(public_params.compress()),
+            Self::PkeV2Bn254(public_params) => {
+                CompressedCompactPkeCrs::PkeV2Bn254(public_params.compress())
+            }
         }
     }
 
@@ -875,6 +990,9 @@ impl Compressible for CompactPkeCrs {
             CompressedCompactPkeCrs::PkeV2(compressed_params) => {
                 Self::PkeV2(Compressible::uncompress(compressed_params)?)
             }
+            CompressedCompactPkeCrs::PkeV2Bn254(compressed_params) => {
+                Self::PkeV2Bn254(Compressible::uncompress(compressed_params)?)
+            }
         })
     }
 }
@@ -882,9 +1000,39 @@ impl Compressible for CompactPkeCrs {
 #[cfg(all(test, feature = "shortint"))]
 mod test {
     use super::*;
-    use crate::safe_serialization::{safe_deserialize_conformant, safe_serialize};
+    use crate::safe_serialization::{
+        safe_deserialize, safe_deserialize_conformant, safe_serialize,
+    };
+    use crate::shortint::ciphertext::ProvenCompactCiphertextList;
     use crate::shortint::parameters::*;
-    use crate::shortint::{CarryModulus, MessageModulus};
+    use crate::shortint::{CarryModulus, CompactPrivateKey, CompactPublicKey, MessageModulus};
+    use rand::random;
+    use tfhe_versionable::{Unversionize, VersionizeOwned};
+
+    /// Proves the encryption of random messages with `prove_crs` and checks the proof with
+    /// `verify_crs`
+    fn prove_and_verify(prove_crs: &CompactPkeCrs, verify_crs: &CompactPkeCrs) -> bool {
+        let pke_params = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+        let priv_key = CompactPrivateKey::new(pke_params);
+        let pub_key = CompactPublicKey::new(&priv_key);
+        let metadata = [b'z', b'k'];
+
+        let msgs = (0..4)
+            .map(|_| random::<u64>() % pke_params.message_modulus.0)
+            .collect::<Vec<_>>();
+
+        let proven_ct = pub_key
+            .encrypt_and_prove_slice(
+                &msgs,
+                prove_crs,
+                &metadata,
+                ZkComputeLoad::Proof,
+                pke_params.message_modulus.0,
+            )
+            .unwrap();
+
+        proven_ct.verify(verify_crs, &pub_key, &metadata).is_valid()
+    }
 
     #[test]
     fn test_crs_conformance() {
@@ -957,4 +1105,111 @@ mod test {
             safe_deserialize_conformant(serialized.as_slice(), 1 << 30, &conformance_params)
                 .unwrap();
     }
+
+    #[test]
+    fn test_crs_bn254_serialization() {
+        let params = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+
+        let mut rng = rand::thread_rng();
+
+        let crs = CompactPkeCrs::new_with_curve(
+            params.encryption_lwe_dimension,
+            LweCiphertextCount(4),
+            params.encryption_noise_distribution,
+            params.ciphertext_modulus,
+            params.message_modulus.0 * params.carry_modulus.0 * 2,
+            ZkMSBZeroPaddingBitCount(1),
+            CompactPkeZkCurve::Bn254,
+            &mut rng,
+        )
+        .unwrap();
+
+        assert_eq!(crs.curve(), CompactPkeZkCurve::Bn254);
+
+        let conformance_params =
+            CompactPkeCrsConformanceParams::new(params, LweCiphertextCount(4)).unwrap();
+
+        assert!(crs.is_conformant(&conformance_params));
+
+        let mut serialized = Vec::new();
+        safe_serialize(&crs, &mut serialized, 1 << 30).unwrap();
+
+        let crs_deser: CompactPkeCrs =
+            safe_deserialize_conformant(serialized.as_slice(), 1 << 30, &conformance_params)
+                .unwrap();
+        assert_eq!(crs_deser.curve(), CompactPkeZkCurve::Bn254);
+
+        // Check with compression
+        let mut serialized = Vec::new();
+        safe_serialize(&crs.compress(), &mut serialized, 1 << 30).unwrap();
+
+        let crs_deser: CompactPkeCrs =
+            safe_deserialize_conformant(serialized.as_slice(), 1 << 30, &conformance_params)
+                .unwrap();
+        assert_eq!(crs_deser.curve(), CompactPkeZkCurve::Bn254);
+        assert!(prove_and_verify(&crs, &crs_deser));
+    }
+
+    #[test]
+    fn test_crs_bn254_versioning() {
+        let pke_params = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+
+        let crs = CompactPkeCrs::from_shortint_params_with_curve(
+            pke_params,
+            LweCiphertextCount(4),
+            CompactPkeZkCurve::Bn254,
+        )
+        .unwrap();
+
+        let serialized = bincode::serialize(&crs.versionize()).unwrap();
+
+        let crs_unversioned = CompactPkeCrs::unversionize(
+            bincode::deserialize::<<CompactPkeCrs as VersionizeOwned>::VersionedOwned>(&serialized)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(crs_unversioned.curve(), CompactPkeZkCurve::Bn254);
+        assert!(prove_and_verify(&crs, &crs_unversioned));
+    }
+
+    #[test]
+    fn test_crs_bn254_prove_verify() {
+        let pke_params = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+
+        let crs = CompactPkeCrs::from_shortint_params_with_curve(
+            pke_params,
+            LweCiphertextCount(4),
+            CompactPkeZkCurve::Bn254,
+        )
+        .unwrap();
+        let bls_crs =
+            CompactPkeCrs::from_shortint_params(pke_params, LweCiphertextCount(4)).unwrap();
+
+        let priv_key = CompactPrivateKey::new(pke_params);
+        let pub_key = CompactPublicKey::new(&priv_key);
+        let metadata = [b'b', b'n', b'2', b'5', b'4'];
+
+        let msgs = (0..4)
+            .map(|_| random::<u64>() % pke_params.message_modulus.0)
+            .collect::<Vec<_>>();
+
+        for load in [ZkComputeLoad::Proof, ZkComputeLoad::Verify] {
+            let proven_ct = pub_key
+                .encrypt_and_prove_slice(&msgs, &crs, &metadata, load, pke_params.message_modulus.0)
+                .unwrap();
+
+            assert!(proven_ct.verify(&crs, &pub_key, &metadata).is_valid());
+            assert!(proven_ct.verify(&crs, &pub_key, b"bad").is_invalid());
+            // Proofs built on bn254 are rejected by a crs built on another curve
+            assert!(proven_ct.verify(&bls_crs, &pub_key, &metadata).is_invalid());
+
+            // The proof is kept through a serialization round trip
+            let mut serialized = Vec::new();
+            safe_serialize(&proven_ct, &mut serialized, 1 << 30).unwrap();
+            let proven_ct: ProvenCompactCiphertextList =
+                safe_deserialize(serialized.as_slice(), 1 << 30).unwrap();
+
+            assert!(proven_ct.verify(&crs, &pub_key, &metadata).is_valid());
+        }
+    }
 }
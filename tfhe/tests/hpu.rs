@@ -83,11 +83,57 @@ mod hpu_test {
         let sks_compressed =
             tfhe::integer::CompressedServerKey::new_radix_compressed_server_key(&cks);
 
+        // Register user defined IOp before firmware generation
+        register_user_iop(&hpu_device);
+
         // Init Hpu device with server key and firmware
         tfhe::integer::hpu::init_device(&hpu_device, sks_compressed).expect("Invalid key");
         (std::sync::Mutex::new(hpu_device), cks, key_seed)
     }
 
+    /// Register a set of user defined IOp
+    /// Used to check the whole user IOp flow (i.e. custom Pbs, Fw DSL, translation table)
+    fn register_user_iop(hpu_device: &HpuDevice) {
+        use tfhe_hpu_backend::fw::metavar::VarPos;
+        use tfhe_hpu_backend::fw::program::Program;
+
+        // ANDN: Dst <- SrcA & !SrcB
+        // Operands are packed as (SrcA, SrcB) in a block before the Pbs
+        let digit_params = hpu_asm::DigitParameters {
+            msg_w: hpu_device.params().pbs_params.message_width,
+            carry_w: hpu_device.params().pbs_params.carry_width,
+        };
+        let andn_lut = hpu_asm::dop::pbs_user::register(hpu_asm::PbsUser::new(
+            "UserAndN",
+            hpu_asm::PbsGid(hpu_asm::dop::pbs_user::USER_GID_LB),
+            &digit_params,
+            &[&|params: &hpu_asm::DigitParameters, val| {
+                let msg_mask = params.msg_mask();
+                let a = (val >> params.msg_w) & msg_mask;
+                let b = val & msg_mask;
+                a & !b & msg_mask
+            }],
+        ))
+        .expect("Invalid user Pbs");
+
+        let andn =
+            hpu_asm::AsmIOpcode::register_user("ANDN", 0x40, hpu_asm::iop::IOP_CT_F_2CT.into())
+                .expect("Invalid user IOp");
+        hpu_device.register_user_iop(&andn, move |prog: &mut Program| {
+            let dst = prog.iop_template_var(hpu_asm::OperandKind::Dst, 0);
+            let src_a = prog.iop_template_var(hpu_asm::OperandKind::Src, 0);
+            let src_b = prog.iop_template_var(hpu_asm::OperandKind::Src, 1);
+            prog.push_comment("ANDN Operand::Dst Operand::Src Operand::Src".to_string());
+
+            let props = prog.params();
+            let msg_range = 1 << props.msg_w;
+            let lut = prog.var_from(Some(VarPos::Pbs(andn_lut.clone())));
+            for (pos, (mut d, a, b)) in itertools::izip!(dst, src_a, src_b).enumerate() {
+                d <<= a.mac(msg_range, &b).pbs(&lut, pos == props.blk_w() - 1);
+            }
+        });
+    }
+
     // NB: Currently u55c didn't check for workq overflow.
     // -> Use default value < queue depth to circumvent this limitation
     // NB': This is only for u55c, on V80 user could set HPU_TEST_ITER to whatever value he want
@@ -241,6 +287,27 @@ mod hpu_test {
     };
 }
 
+    /// Reinterpret a cleartext as its two's complement signed counterpart
+    /// Used to describe the behavior of signed IOp
+    trait AsSigned {
+        type Signed;
+        fn as_signed(self) -> Self::Signed;
+    }
+
+    macro_rules! impl_as_signed {
+        ($($unsigned: ty => $signed: ty),+) => {
+            $(
+            impl AsSigned for $unsigned {
+                type Signed = $signed;
+                fn as_signed(self) -> Self::Signed {
+                    self as $signed
+                }
+            }
+            )+
+        };
+    }
+    impl_as_signed!(u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128);
+
     // Define testcase implementation for all supported IOp
     // Alu IOp with Ct x Imm
     hpu_testcase!("ADDS" => [u8, u16, u32, u64, u128]
@@ -348,6 +415,50 @@ mod hpu_test {
     hpu_testcase!("CMP_NEQ" => [u8, u16, u32, u64, u128]
     |ct, imm| [ct[0] != ct[1]]);
 
+    // Scalar bitwise IOp
+    hpu_testcase!("BWS_AND" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0] & imm[0]]);
+    hpu_testcase!("BWS_OR" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0] | imm[0]]);
+    hpu_testcase!("BWS_XOR" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0] ^ imm[0]]);
+
+    // Signed comparison IOp
+    hpu_testcase!("CMP_SGT" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0].as_signed() > ct[1].as_signed()]);
+    hpu_testcase!("CMP_SGTE" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0].as_signed() >= ct[1].as_signed()]);
+    hpu_testcase!("CMP_SLT" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0].as_signed() < ct[1].as_signed()]);
+    hpu_testcase!("CMP_SLTE" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0].as_signed() <= ct[1].as_signed()]);
+
+    // Min/Max IOp
+    hpu_testcase!("MIN" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0].min(ct[1])]);
+    hpu_testcase!("MAX" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0].max(ct[1])]);
+    hpu_testcase!("SMIN" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0].as_signed().min(ct[1].as_signed())]);
+    hpu_testcase!("SMAX" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0].as_signed().max(ct[1].as_signed())]);
+
+    // Signed arithmetic IOp
+    hpu_testcase!("NEG" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0].wrapping_neg()]);
+    hpu_testcase!("ABS" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0].as_signed().wrapping_abs()]);
+    // NB: Division by zero matches the Cpu behavior
+    hpu_testcase!("SDIV" => [u8, u16, u32, u64, u128]
+        |ct, imm| {
+            let (a, b) = (ct[0].as_signed(), ct[1].as_signed());
+            if b == 0 {
+                [if a < 0 { 1 } else { -1 }, a]
+            } else {
+                [a.wrapping_div(b), a.wrapping_rem(b)]
+            }
+    });
+
     // Ternary IOp
     hpu_testcase!("IF_THEN_ZERO" => [u8, u16, u32, u64, u128]
     |ct, imm| [if ct[1] != 0 {ct[0]} else { 0}]);
@@ -384,6 +495,11 @@ mod hpu_test {
     hpu_testcase!("TRAIL1" => [u8, u16, u32, u64, u128]
     |ct, imm| [ct[0].trailing_ones()]);
 
+    // User defined IOp
+    // c.f. register_user_iop
+    hpu_testcase!("ANDN" => [u8, u16, u32, u64, u128]
+    |ct, imm| [ct[0] & !ct[1]]);
+
     // Define a set of test bundle for various size
     // 8bit ciphertext -----------------------------------------
     #[cfg(feature = "hpu")]
@@ -480,6 +596,37 @@ mod hpu_test {
         "trail1"
     ]);
 
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("bitwises"::8 => [
+        "bws_and",
+        "bws_or",
+        "bws_xor"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("minmax"::8 => [
+        "min",
+        "max"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("user"::8 => [
+        "andn"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("signed"::8 => [
+        "cmp_sgt",
+        "cmp_sgte",
+        "cmp_slt",
+        "cmp_slte",
+        "smin",
+        "smax",
+        "neg",
+        "abs",
+        "sdiv"
+    ]);
+
     // 16bit ciphertext -----------------------------------------
     #[cfg(feature = "hpu")]
     hpu_testbundle!("alus"::16 => [
@@ -575,6 +722,37 @@ mod hpu_test {
         "trail1"
     ]);
 
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("bitwises"::16 => [
+        "bws_and",
+        "bws_or",
+        "bws_xor"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("minmax"::16 => [
+        "min",
+        "max"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("user"::16 => [
+        "andn"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("signed"::16 => [
+        "cmp_sgt",
+        "cmp_sgte",
+        "cmp_slt",
+        "cmp_slte",
+        "smin",
+        "smax",
+        "neg",
+        "abs",
+        "sdiv"
+    ]);
+
     // 32bit ciphertext -----------------------------------------
     #[cfg(feature = "hpu")]
     hpu_testbundle!("alus"::32 => [
@@ -670,6 +848,37 @@ mod hpu_test {
         "trail1"
     ]);
 
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("bitwises"::32 => [
+        "bws_and",
+        "bws_or",
+        "bws_xor"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("minmax"::32 => [
+        "min",
+        "max"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("user"::32 => [
+        "andn"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("signed"::32 => [
+        "cmp_sgt",
+        "cmp_sgte",
+        "cmp_slt",
+        "cmp_slte",
+        "smin",
+        "smax",
+        "neg",
+        "abs",
+        "sdiv"
+    ]);
+
     // 64bit ciphertext -----------------------------------------
     #[cfg(feature = "hpu")]
     hpu_testbundle!("alus"::64 => [
@@ -765,6 +974,37 @@ mod hpu_test {
         "trail1"
     ]);
 
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("bitwises"::64 => [
+        "bws_and",
+        "bws_or",
+        "bws_xor"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("minmax"::64 => [
+        "min",
+        "max"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("user"::64 => [
+        "andn"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("signed"::64 => [
+        "cmp_sgt",
+        "cmp_sgte",
+        "cmp_slt",
+        "cmp_slte",
+        "smin",
+        "smax",
+        "neg",
+        "abs",
+        "sdiv"
+    ]);
+
     // 128bit ciphertext -----------------------------------------
     #[cfg(feature = "hpu")]
     hpu_testbundle!("alus"::128 => [
@@ -860,6 +1100,37 @@ mod hpu_test {
         "trail1"
     ]);
 
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("bitwises"::128 => [
+        "bws_and",
+        "bws_or",
+        "bws_xor"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("minmax"::128 => [
+        "min",
+        "max"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("user"::128 => [
+        "andn"
+    ]);
+
+    #[cfg(feature = "hpu")]
+    hpu_testbundle!("signed"::128 => [
+        "cmp_sgt",
+        "cmp_sgte",
+        "cmp_slt",
+        "cmp_slte",
+        "smin",
+        "smax",
+        "neg",
+        "abs",
+        "sdiv"
+    ]);
+
     /// Simple test dedicated to check entities conversion from/to Cpu
     #[cfg(feature = "hpu")]
     #[test]
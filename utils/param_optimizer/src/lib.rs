@@ -0,0 +1,739 @@
+//! Search for shortint parameter sets using the noise formulas of tfhe.
+//!
+//! Given a message/carry modulus, a failure probability and an atomic pattern, the search
+//! enumerates the LWE/GLWE dimensions, polynomial sizes and decomposition parameters, keeps the
+//! candidates whose noise stays below the bound required by the failure probability and returns
+//! the cheapest one according to a relative cost model.
+//!
+//! Noise distributions are always the smallest secure ones given by the formulas of
+//! [`tfhe::core_crypto::commons::noise_formulas::secure_noise`], which target 132 bits of
+//! security. The noise reduction techniques for the modulus switch are not modeled, so generated
+//! parameters use [`ModulusSwitchType::Standard`].
+
+pub mod noise;
+
+use rayon::prelude::*;
+use std::fmt::{Display, Formatter};
+use tfhe::core_crypto::prelude::UnsignedInteger;
+use tfhe::shortint::parameters::{
+    CarryModulus, CiphertextModulus, CiphertextModulus32, ClassicPBSParameters,
+    DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution, EncryptionKeyChoice,
+    GlweDimension, KeySwitch32PBSParameters, LweBskGroupingFactor, LweDimension, MaxNoiseLevel,
+    MessageModulus, ModulusSwitchType, MultiBitPBSParameters, PolynomialSize, StandardDev,
+};
+
+/// Security level targeted by the noise formulas
+pub const FORMULAS_SECURITY_LEVEL: u32 = 132;
+
+const MIN_LWE_DIMENSION: usize = 512;
+const MAX_LWE_DIMENSION: usize = 1536;
+const MAX_GLWE_DIMENSION: usize = 6;
+const MIN_LOG2_POLYNOMIAL_SIZE: usize = 8;
+const MAX_LOG2_POLYNOMIAL_SIZE: usize = 16;
+const MAX_BIG_LWE_DIMENSION: usize = 1 << 16;
+const MAX_PBS_LEVEL: usize = 10;
+const MAX_KS_LEVEL: usize = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AtomicPattern {
+    /// Keyswitch followed by a classic PBS, see [`ClassicPBSParameters`]
+    KsPbs,
+    /// Keyswitch to 32 bits ciphertexts followed by a classic PBS, see
+    /// [`KeySwitch32PBSParameters`]
+    KeySwitch32,
+    /// Keyswitch followed by a multi-bit PBS, see [`MultiBitPBSParameters`]
+    MultiBit { grouping_factor: usize },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoiseDistributionKind {
+    Gaussian,
+    TUniform,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SearchConfig {
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    /// Maximum log2 of the failure probability of a PBS
+    pub log2_p_fail: f64,
+    pub atomic_pattern: AtomicPattern,
+    pub noise_distribution: NoiseDistributionKind,
+    /// Maximum number of ciphertexts added together before a PBS, defaults to the value allowing
+    /// to fill the carry space with additions of messages
+    pub max_noise_level: Option<MaxNoiseLevel>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SearchError {
+    UnsupportedGroupingFactor(usize),
+    InvalidModulus {
+        message_modulus: u64,
+        carry_modulus: u64,
+    },
+    InvalidFailureProbability(f64),
+    NoSolution,
+}
+
+impl Display for SearchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedGroupingFactor(grouping_factor) => write!(
+                f,
+                "Unsupported grouping factor {grouping_factor}, expected 2, 3 or 4"
+            ),
+            Self::InvalidModulus {
+                message_modulus,
+                carry_modulus,
+            } => write!(
+                f,
+                "Invalid message modulus {message_modulus} and carry modulus {carry_modulus}, \
+                expected powers of two with a message modulus of at least 2"
+            ),
+            Self::InvalidFailureProbability(log2_p_fail) => write!(
+                f,
+                "Invalid failure probability 2^{log2_p_fail}, \
+                expected a log2 between -1000 and -1"
+            ),
+            Self::NoSolution => write!(f, "No parameter set satisfies the constraints"),
+        }
+    }
+}
+
+impl std::error::Error for SearchError {}
+
+impl SearchConfig {
+    pub fn new(
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        log2_p_fail: f64,
+        atomic_pattern: AtomicPattern,
+        noise_distribution: NoiseDistributionKind,
+    ) -> Self {
+        Self {
+            message_modulus,
+            carry_modulus,
+            log2_p_fail,
+            atomic_pattern,
+            noise_distribution,
+            max_noise_level: None,
+        }
+    }
+
+    pub fn max_noise_level(&self) -> MaxNoiseLevel {
+        self.max_noise_level.unwrap_or_else(|| {
+            MaxNoiseLevel::new(
+                (self.message_modulus.0 * self.carry_modulus.0 - 1) / (self.message_modulus.0 - 1),
+            )
+        })
+    }
+
+    /// Bound on the noise (normalized to the torus) for a correct decryption: one bit of padding,
+    /// the message and carry bits and one bit which must not be touched by the noise
+    pub fn noise_bound(&self) -> f64 {
+        1.0 / (4 * self.message_modulus.0 * self.carry_modulus.0) as f64
+    }
+
+    pub fn validate(&self) -> Result<(), SearchError> {
+        if let AtomicPattern::MultiBit { grouping_factor } = self.atomic_pattern {
+            if !(2..=4).contains(&grouping_factor) {
+                return Err(SearchError::UnsupportedGroupingFactor(grouping_factor));
+            }
+        }
+
+        if self.message_modulus.0 < 2
+            || !self.message_modulus.0.is_power_of_two()
+            || !self.carry_modulus.0.is_power_of_two()
+        {
+            return Err(SearchError::InvalidModulus {
+                message_modulus: self.message_modulus.0,
+                carry_modulus: self.carry_modulus.0,
+            });
+        }
+
+        if !(-1000.0..=-1.0).contains(&self.log2_p_fail) {
+            return Err(SearchError::InvalidFailureProbability(self.log2_p_fail));
+        }
+
+        Ok(())
+    }
+}
+
+/// The cryptographic parameters of a parameter set, the noise distributions are derived from
+/// them
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Candidate {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+}
+
+/// Variances (normalized to the torus) of the noise of a ciphertext along an atomic pattern
+#[derive(Clone, Copy, Debug)]
+pub struct NoiseReport {
+    pub pbs_variance: f64,
+    /// Variance after adding `max_noise_level` fresh PBS outputs together
+    pub linear_variance: f64,
+    pub keyswitch_variance: f64,
+    pub modulus_switch_variance: f64,
+    /// Variance at the decryption of the modulus switched ciphertext
+    pub total_variance: f64,
+    pub max_variance: f64,
+    pub log2_p_fail: f64,
+    pub cost: f64,
+}
+
+impl Display for NoiseReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "pbs variance:            {:e}", self.pbs_variance)?;
+        writeln!(f, "after linear operations: {:e}", self.linear_variance)?;
+        writeln!(f, "keyswitch variance:      {:e}", self.keyswitch_variance)?;
+        writeln!(
+            f,
+            "modulus switch variance: {:e}",
+            self.modulus_switch_variance
+        )?;
+        writeln!(
+            f,
+            "total variance:          {:e} (max {:e})",
+            self.total_variance, self.max_variance
+        )?;
+        writeln!(f, "log2(p-fail):            {:.3}", self.log2_p_fail)?;
+        write!(f, "algorithmic cost:        {:.0}", self.cost)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub candidate: Candidate,
+    pub noise: NoiseReport,
+}
+
+/// Relative cost of a keyswitch: one multiply-add of an output LWE per decomposition term
+fn keyswitch_cost(big_lwe_dimension: usize, small_lwe_dimension: usize, level: usize) -> f64 {
+    (big_lwe_dimension * level * (small_lwe_dimension + 1)) as f64
+}
+
+/// Relative cost of a PBS, dominated by the FFTs and products of the external products of the
+/// blind rotation
+fn pbs_cost(
+    atomic_pattern: AtomicPattern,
+    lwe_dimension: usize,
+    glwe_dimension: usize,
+    polynomial_size: usize,
+    level: usize,
+) -> f64 {
+    let n = polynomial_size as f64;
+    let fft = n * n.log2();
+    let k_plus_one = (glwe_dimension + 1) as f64;
+    let level = level as f64;
+
+    let external_product =
+        k_plus_one * level * fft + k_plus_one.powi(2) * level * n + k_plus_one * fft;
+
+    match atomic_pattern {
+        AtomicPattern::KsPbs | AtomicPattern::KeySwitch32 => {
+            lwe_dimension as f64 * external_product
+        }
+        AtomicPattern::MultiBit { grouping_factor } => {
+            // Each iteration first builds a GGSW from the 2^grouping_factor - 1 GGSWs of the group
+            let ggsw_sum = ((1 << grouping_factor) - 1) as f64 * k_plus_one.powi(2) * level * n;
+            (lwe_dimension / grouping_factor) as f64 * (external_product + ggsw_sum)
+        }
+    }
+}
+
+fn total_cost(atomic_pattern: AtomicPattern, candidate: &Candidate) -> f64 {
+    let big_lwe_dimension = candidate
+        .glwe_dimension
+        .to_equivalent_lwe_dimension(candidate.polynomial_size)
+        .0;
+
+    keyswitch_cost(
+        big_lwe_dimension,
+        candidate.lwe_dimension.0,
+        candidate.ks_level.0,
+    ) + pbs_cost(
+        atomic_pattern,
+        candidate.lwe_dimension.0,
+        candidate.glwe_dimension.0,
+        candidate.polynomial_size.0,
+        candidate.pbs_level.0,
+    )
+}
+
+/// Computes the noise of the given parameters
+pub fn evaluate(config: &SearchConfig, candidate: &Candidate) -> NoiseReport {
+    let lwe_dimension = candidate.lwe_dimension.0 as f64;
+    let big_lwe_dimension = candidate
+        .glwe_dimension
+        .to_equivalent_lwe_dimension(candidate.polynomial_size)
+        .0 as f64;
+
+    let pbs_variance = lwe_dimension
+        * noise::unit_pbs_variance(
+            config.atomic_pattern,
+            config.noise_distribution,
+            candidate.glwe_dimension.0 as f64,
+            candidate.polynomial_size.0 as f64,
+            2.0f64.powi(candidate.pbs_base_log.0 as i32),
+            candidate.pbs_level.0 as f64,
+        );
+    let linear_variance = (config.max_noise_level().get() as f64).powi(2) * pbs_variance;
+    let keyswitch_variance = noise::keyswitch_variance(
+        config.atomic_pattern,
+        config.noise_distribution,
+        big_lwe_dimension,
+        lwe_dimension,
+        2.0f64.powi(candidate.ks_base_log.0 as i32),
+        candidate.ks_level.0 as f64,
+    );
+    let modulus_switch_variance = noise::modulus_switch_variance(
+        config.atomic_pattern,
+        lwe_dimension,
+        candidate.polynomial_size.0 as f64,
+    );
+    let total_variance = linear_variance + keyswitch_variance + modulus_switch_variance;
+
+    NoiseReport {
+        pbs_variance,
+        linear_variance,
+        keyswitch_variance,
+        modulus_switch_variance,
+        total_variance,
+        max_variance: noise::max_variance_for_p_fail(config.log2_p_fail, config.noise_bound()),
+        log2_p_fail: noise::log2_p_fail(total_variance, config.noise_bound()),
+        cost: total_cost(config.atomic_pattern, candidate),
+    }
+}
+
+/// For each level count, the base log giving the smallest variance and that variance
+fn best_bases(
+    max_level: usize,
+    modulus_log2: usize,
+    variance: impl Fn(f64, f64) -> f64,
+) -> Vec<(DecompositionBaseLog, DecompositionLevelCount, f64)> {
+    (1..=max_level)
+        .filter_map(|level| {
+            (1..=modulus_log2 / level)
+                .map(|base_log| {
+                    (
+                        DecompositionBaseLog(base_log),
+                        DecompositionLevelCount(level),
+                        variance(2.0f64.powi(base_log as i32), level as f64),
+                    )
+                })
+                .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b))
+        })
+        .collect()
+}
+
+/// Returns the cheapest parameters for the given GLWE dimension and polynomial size
+fn optimize_for_glwe(
+    config: &SearchConfig,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+) -> Option<(Candidate, f64)> {
+    let max_variance = noise::max_variance_for_p_fail(config.log2_p_fail, config.noise_bound());
+    let squared_noise_level = (config.max_noise_level().get() as f64).powi(2);
+    let big_lwe_dimension = glwe_dimension.to_equivalent_lwe_dimension(polynomial_size);
+    let small_modulus_log2 = noise::small_lwe_modulus(config.atomic_pattern).log2() as usize;
+
+    let pbs_options = best_bases(MAX_PBS_LEVEL, 64, |base, level| {
+        noise::unit_pbs_variance(
+            config.atomic_pattern,
+            config.noise_distribution,
+            glwe_dimension.0 as f64,
+            polynomial_size.0 as f64,
+            base,
+            level,
+        )
+    });
+
+    let lwe_dimension_step = match config.atomic_pattern {
+        AtomicPattern::KsPbs | AtomicPattern::KeySwitch32 => 1,
+        AtomicPattern::MultiBit { grouping_factor } => grouping_factor,
+    };
+
+    let mut best: Option<(Candidate, f64)> = None;
+
+    for lwe_dimension in (MIN_LWE_DIMENSION..=MAX_LWE_DIMENSION).step_by(lwe_dimension_step) {
+        let modulus_switch_variance = noise::modulus_switch_variance(
+            config.atomic_pattern,
+            lwe_dimension as f64,
+            polynomial_size.0 as f64,
+        );
+        if modulus_switch_variance >= max_variance {
+            continue;
+        }
+
+        let ks_options = best_bases(MAX_KS_LEVEL, small_modulus_log2, |base, level| {
+            noise::keyswitch_variance(
+                config.atomic_pattern,
+                config.noise_distribution,
+                big_lwe_dimension.0 as f64,
+                lwe_dimension as f64,
+                base,
+                level,
+            )
+        });
+
+        for &(pbs_base_log, pbs_level, unit_pbs_variance) in &pbs_options {
+            let linear_variance = squared_noise_level * lwe_dimension as f64 * unit_pbs_variance;
+            let remaining_variance = max_variance - linear_variance - modulus_switch_variance;
+            if remaining_variance <= 0.0 {
+                continue;
+            }
+
+            // The keyswitch cost grows with the level, the first fitting level is the cheapest
+            let Some(&(ks_base_log, ks_level, _)) = ks_options
+                .iter()
+                .find(|(_, _, variance)| *variance < remaining_variance)
+            else {
+                continue;
+            };
+
+            let candidate = Candidate {
+                lwe_dimension: LweDimension(lwe_dimension),
+                glwe_dimension,
+                polynomial_size,
+                pbs_base_log,
+                pbs_level,
+                ks_base_log,
+                ks_level,
+            };
+            let cost = total_cost(config.atomic_pattern, &candidate);
+
+            if best.is_none_or(|(_, best_cost)| cost < best_cost) {
+                best = Some((candidate, cost));
+            }
+        }
+    }
+
+    best
+}
+
+/// Returns the cheapest parameters satisfying the constraints of the config
+pub fn optimize(config: &SearchConfig) -> Result<Solution, SearchError> {
+    config.validate()?;
+
+    let glwe_configs: Vec<_> = (1..=MAX_GLWE_DIMENSION)
+        .flat_map(|glwe_dimension| {
+            (MIN_LOG2_POLYNOMIAL_SIZE..=MAX_LOG2_POLYNOMIAL_SIZE).map(move |log2_polynomial_size| {
+                (
+                    GlweDimension(glwe_dimension),
+                    PolynomialSize(1 << log2_polynomial_size),
+                )
+            })
+        })
+        .filter(|(glwe_dimension, polynomial_size)| {
+            glwe_dimension
+                .to_equivalent_lwe_dimension(*polynomial_size)
+                .0
+                <= MAX_BIG_LWE_DIMENSION
+        })
+        .collect();
+
+    let (candidate, _) = glwe_configs
+        .into_par_iter()
+        .filter_map(|(glwe_dimension, polynomial_size)| {
+            optimize_for_glwe(config, glwe_dimension, polynomial_size)
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .ok_or(SearchError::NoSolution)?;
+
+    Ok(Solution {
+        candidate,
+        noise: evaluate(config, &candidate),
+    })
+}
+
+/// A parameter set ready to be used by shortint
+#[derive(Clone, Copy, Debug)]
+pub enum OptimizedParameters {
+    Classic(ClassicPBSParameters),
+    KeySwitch32(KeySwitch32PBSParameters),
+    MultiBit(MultiBitPBSParameters),
+}
+
+fn secure_noise_distribution<T: UnsignedInteger>(
+    noise_distribution: NoiseDistributionKind,
+    lwe_dimension: usize,
+    modulus: f64,
+) -> DynamicDistribution<T> {
+    match noise_distribution {
+        NoiseDistributionKind::Gaussian => {
+            DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+                noise::secure_gaussian_std_dev(lwe_dimension as f64, modulus),
+            ))
+        }
+        NoiseDistributionKind::TUniform => DynamicDistribution::new_t_uniform(
+            noise::secure_tuniform_bound_log2(lwe_dimension as f64, modulus),
+        ),
+    }
+}
+
+impl Solution {
+    pub fn to_parameters(&self, config: &SearchConfig) -> OptimizedParameters {
+        let candidate = &self.candidate;
+        let lwe_modulus = noise::small_lwe_modulus(config.atomic_pattern);
+        let lwe_noise_distribution = secure_noise_distribution(
+            config.noise_distribution,
+            candidate.lwe_dimension.0,
+            lwe_modulus,
+        );
+        let glwe_noise_distribution = secure_noise_distribution(
+            config.noise_distribution,
+            candidate
+                .glwe_dimension
+                .to_equivalent_lwe_dimension(candidate.polynomial_size)
+                .0,
+            noise::CIPHERTEXT_MODULUS,
+        );
+
+        match config.atomic_pattern {
+            AtomicPattern::KsPbs => OptimizedParameters::Classic(ClassicPBSParameters {
+                lwe_dimension: candidate.lwe_dimension,
+                glwe_dimension: candidate.glwe_dimension,
+                polynomial_size: candidate.polynomial_size,
+                lwe_noise_distribution,
+                glwe_noise_distribution,
+                pbs_base_log: candidate.pbs_base_log,
+                pbs_level: candidate.pbs_level,
+                ks_base_log: candidate.ks_base_log,
+                ks_level: candidate.ks_level,
+                message_modulus: config.message_modulus,
+                carry_modulus: config.carry_modulus,
+                max_noise_level: config.max_noise_level(),
+                log2_p_fail: self.noise.log2_p_fail,
+                ciphertext_modulus: CiphertextModulus::new_native(),
+                encryption_key_choice: EncryptionKeyChoice::Big,
+                modulus_switch_noise_reduction_params: ModulusSwitchType::Standard,
+            }),
+            AtomicPattern::KeySwitch32 => {
+                // The small LWE key encrypts 32 bits ciphertexts
+                let lwe_noise_distribution = secure_noise_distribution(
+                    config.noise_distribution,
+                    candidate.lwe_dimension.0,
+                    lwe_modulus,
+                );
+
+                OptimizedParameters::KeySwitch32(KeySwitch32PBSParameters {
+                    lwe_dimension: candidate.lwe_dimension,
+                    glwe_dimension: candidate.glwe_dimension,
+                    polynomial_size: candidate.polynomial_size,
+                    lwe_noise_distribution,
+                    glwe_noise_distribution,
+                    pbs_base_log: candidate.pbs_base_log,
+                    pbs_level: candidate.pbs_level,
+                    ks_base_log: candidate.ks_base_log,
+                    ks_level: candidate.ks_level,
+                    message_modulus: config.message_modulus,
+                    carry_modulus: config.carry_modulus,
+                    max_noise_level: config.max_noise_level(),
+                    log2_p_fail: self.noise.log2_p_fail,
+                    post_keyswitch_ciphertext_modulus: CiphertextModulus32::new_native(),
+                    ciphertext_modulus: CiphertextModulus::new_native(),
+                    modulus_switch_noise_reduction_params: ModulusSwitchType::Standard,
+                })
+            }
+            AtomicPattern::MultiBit { grouping_factor } => {
+                OptimizedParameters::MultiBit(MultiBitPBSParameters {
+                    lwe_dimension: candidate.lwe_dimension,
+                    glwe_dimension: candidate.glwe_dimension,
+                    polynomial_size: candidate.polynomial_size,
+                    lwe_noise_distribution,
+                    glwe_noise_distribution,
+                    pbs_base_log: candidate.pbs_base_log,
+                    pbs_level: candidate.pbs_level,
+                    ks_base_log: candidate.ks_base_log,
+                    ks_level: candidate.ks_level,
+                    message_modulus: config.message_modulus,
+                    carry_modulus: config.carry_modulus,
+                    max_noise_level: config.max_noise_level(),
+                    log2_p_fail: self.noise.log2_p_fail,
+                    ciphertext_modulus: CiphertextModulus::new_native(),
+                    encryption_key_choice: EncryptionKeyChoice::Big,
+                    grouping_factor: LweBskGroupingFactor(grouping_factor),
+                    deterministic_execution: false,
+                })
+            }
+        }
+    }
+
+    /// Returns the Rust declaration of the parameter set, formatted like the parameters of the
+    /// tfhe crate
+    pub fn to_rust_code(&self, config: &SearchConfig, name: &str) -> String {
+        fn distribution<T: UnsignedInteger>(distribution: DynamicDistribution<T>) -> String {
+            match distribution {
+                DynamicDistribution::Gaussian(gaussian) => format!(
+                    "DynamicDistribution::new_gaussian_from_std_dev(StandardDev({:?}))",
+                    gaussian.std
+                ),
+                DynamicDistribution::TUniform(tuniform) => format!(
+                    "DynamicDistribution::new_t_uniform({})",
+                    tuniform.bound_log2()
+                ),
+            }
+        }
+
+        let candidate = &self.candidate;
+        let (type_name, lwe_noise_distribution, glwe_noise_distribution, extra_fields) =
+            match self.to_parameters(config) {
+                OptimizedParameters::Classic(params) => (
+                    "ClassicPBSParameters",
+                    distribution(params.lwe_noise_distribution),
+                    distribution(params.glwe_noise_distribution),
+                    vec![
+                        "ciphertext_modulus: CiphertextModulus::new_native()".to_string(),
+                        "encryption_key_choice: EncryptionKeyChoice::Big".to_string(),
+                        "modulus_switch_noise_reduction_params: ModulusSwitchType::Standard"
+                            .to_string(),
+                    ],
+                ),
+                OptimizedParameters::KeySwitch32(params) => (
+                    "KeySwitch32PBSParameters",
+                    distribution(params.lwe_noise_distribution),
+                    distribution(params.glwe_noise_distribution),
+                    vec![
+                        "post_keyswitch_ciphertext_modulus: CiphertextModulus32::new_native()"
+                            .to_string(),
+                        "ciphertext_modulus: CiphertextModulus::new_native()".to_string(),
+                        "modulus_switch_noise_reduction_params: ModulusSwitchType::Standard"
+                            .to_string(),
+                    ],
+                ),
+                OptimizedParameters::MultiBit(params) => (
+                    "MultiBitPBSParameters",
+                    distribution(params.lwe_noise_distribution),
+                    distribution(params.glwe_noise_distribution),
+                    vec![
+                        "ciphertext_modulus: CiphertextModulus::new_native()".to_string(),
+                        "encryption_key_choice: EncryptionKeyChoice::Big".to_string(),
+                        format!(
+                            "grouping_factor: LweBskGroupingFactor({})",
+                            params.grouping_factor.0
+                        ),
+                        "deterministic_execution: false".to_string(),
+                    ],
+                ),
+            };
+
+        let mut fields = vec![
+            format!("lwe_dimension: LweDimension({})", candidate.lwe_dimension.0),
+            format!(
+                "glwe_dimension: GlweDimension({})",
+                candidate.glwe_dimension.0
+            ),
+            format!(
+                "polynomial_size: PolynomialSize({})",
+                candidate.polynomial_size.0
+            ),
+            format!("lwe_noise_distribution: {lwe_noise_distribution}"),
+            format!("glwe_noise_distribution: {glwe_noise_distribution}"),
+            format!(
+                "pbs_base_log: DecompositionBaseLog({})",
+                candidate.pbs_base_log.0
+            ),
+            format!(
+                "pbs_level: DecompositionLevelCount({})",
+                candidate.pbs_level.0
+            ),
+            format!(
+                "ks_base_log: DecompositionBaseLog({})",
+                candidate.ks_base_log.0
+            ),
+            format!(
+                "ks_level: DecompositionLevelCount({})",
+                candidate.ks_level.0
+            ),
+            format!(
+                "message_modulus: MessageModulus({})",
+                config.message_modulus.0
+            ),
+            format!("carry_modulus: CarryModulus({})", config.carry_modulus.0),
+            format!(
+                "max_noise_level: MaxNoiseLevel::new({})",
+                config.max_noise_level().get()
+            ),
+            format!("log2_p_fail: {:.3}", self.noise.log2_p_fail),
+        ];
+        fields.extend(extra_fields);
+
+        let mut code = format!(
+            "/// p-fail = 2^{:.3}, algorithmic cost ~ {:.0}, 2-norm = {}\n",
+            self.noise.log2_p_fail,
+            self.noise.cost,
+            config.max_noise_level().get()
+        );
+        code += &format!("pub const {name}: {type_name} = {type_name} {{\n");
+        for field in fields {
+            code += &format!("    {field},\n");
+        }
+        code += "};\n";
+
+        code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_2_2() -> SearchConfig {
+        SearchConfig::new(
+            MessageModulus(4),
+            CarryModulus(4),
+            -64.0,
+            AtomicPattern::KsPbs,
+            NoiseDistributionKind::TUniform,
+        )
+    }
+
+    #[test]
+    fn test_v1_1_parameters_noise() {
+        // V1_1_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128 uses the drift technique to reduce
+        // the modulus switch noise, without it the failure probability is worse than 2^-128
+        // but the set is still far better than 2^-64
+        let candidate = Candidate {
+            lwe_dimension: LweDimension(918),
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(2048),
+            pbs_base_log: DecompositionBaseLog(23),
+            pbs_level: DecompositionLevelCount(1),
+            ks_base_log: DecompositionBaseLog(4),
+            ks_level: DecompositionLevelCount(4),
+        };
+
+        let report = evaluate(&config_2_2(), &candidate);
+        assert_eq!(config_2_2().max_noise_level().get(), 5);
+        assert!(report.log2_p_fail < -64.0, "{report}");
+        assert!(report.log2_p_fail > -128.0, "{report}");
+    }
+
+    #[test]
+    fn test_optimize_satisfies_constraints() {
+        let config = config_2_2();
+        let solution = optimize(&config).unwrap();
+
+        assert!(solution.noise.total_variance <= solution.noise.max_variance);
+        assert!(solution.noise.log2_p_fail <= config.log2_p_fail);
+
+        let code = solution.to_rust_code(&config, "PARAM_TEST");
+        assert!(code.contains("pub const PARAM_TEST: ClassicPBSParameters"));
+    }
+
+    #[test]
+    fn test_invalid_configs() {
+        let mut config = config_2_2();
+        config.atomic_pattern = AtomicPattern::MultiBit { grouping_factor: 5 };
+        assert_eq!(
+            optimize(&config).unwrap_err(),
+            SearchError::UnsupportedGroupingFactor(5)
+        );
+    }
+}
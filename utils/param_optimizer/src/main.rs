@@ -0,0 +1,91 @@
+use clap::{Parser, ValueEnum};
+use param_optimizer::{
+    optimize, AtomicPattern, NoiseDistributionKind, SearchConfig, FORMULAS_SECURITY_LEVEL,
+};
+use tfhe::shortint::parameters::{CarryModulus, MaxNoiseLevel, MessageModulus};
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum AtomicPatternArg {
+    KsPbs,
+    Ks32Pbs,
+    MultiBit,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum NoiseDistributionArg {
+    Tuniform,
+    Gaussian,
+}
+
+/// Search for the cheapest shortint parameter set satisfying a failure probability, using the
+/// noise formulas of tfhe
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Number of message bits
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u32).range(1..=8))]
+    message_bits: u32,
+
+    /// Number of carry bits
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u32).range(0..=8))]
+    carry_bits: u32,
+
+    /// log2 of the maximum failure probability of a PBS
+    #[arg(long, default_value_t = -128.0, allow_negative_numbers = true)]
+    log2_p_fail: f64,
+
+    #[arg(long, value_enum, default_value_t = AtomicPatternArg::KsPbs)]
+    atomic_pattern: AtomicPatternArg,
+
+    /// Grouping factor of the multi-bit PBS
+    #[arg(long, default_value_t = 4)]
+    grouping_factor: usize,
+
+    #[arg(long, value_enum, default_value_t = NoiseDistributionArg::Tuniform)]
+    noise_distribution: NoiseDistributionArg,
+
+    /// Maximum noise level before a PBS, defaults to the value allowing to fill the carries
+    #[arg(long)]
+    max_noise_level: Option<u64>,
+
+    /// Name of the generated constant
+    #[arg(long, default_value = "PARAM_OPTIMIZED")]
+    name: String,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let atomic_pattern = match args.atomic_pattern {
+        AtomicPatternArg::KsPbs => AtomicPattern::KsPbs,
+        AtomicPatternArg::Ks32Pbs => AtomicPattern::KeySwitch32,
+        AtomicPatternArg::MultiBit => AtomicPattern::MultiBit {
+            grouping_factor: args.grouping_factor,
+        },
+    };
+    let noise_distribution = match args.noise_distribution {
+        NoiseDistributionArg::Tuniform => NoiseDistributionKind::TUniform,
+        NoiseDistributionArg::Gaussian => NoiseDistributionKind::Gaussian,
+    };
+
+    let mut config = SearchConfig::new(
+        MessageModulus(1 << args.message_bits),
+        CarryModulus(1 << args.carry_bits),
+        args.log2_p_fail,
+        atomic_pattern,
+        noise_distribution,
+    );
+    config.max_noise_level = args.max_noise_level.map(MaxNoiseLevel::new);
+
+    let solution = match optimize(&config) {
+        Ok(solution) => solution,
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    };
+
+    println!("// Noise formulas target {FORMULAS_SECURITY_LEVEL} bits of security");
+    println!("{}", solution.to_rust_code(&config, &args.name));
+    println!("{}", solution.noise);
+}
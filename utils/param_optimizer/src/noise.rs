@@ -0,0 +1,206 @@
+//! Noise model of the shortint atomic patterns, built on the formulas of
+//! [`tfhe::core_crypto::commons::noise_formulas`].
+//!
+//! All variances are normalized to the torus, i.e. divided by the square of the ciphertext
+//! modulus, which is the convention of the noise formulas.
+
+use crate::{AtomicPattern, NoiseDistributionKind};
+use tfhe::core_crypto::commons::noise_formulas::lwe_keyswitch::{
+    keyswitch_additive_variance_132_bits_security_gaussian_impl,
+    keyswitch_additive_variance_132_bits_security_tuniform_impl,
+};
+use tfhe::core_crypto::commons::noise_formulas::lwe_multi_bit_programmable_bootstrap::*;
+use tfhe::core_crypto::commons::noise_formulas::lwe_programmable_bootstrap::{
+    pbs_variance_132_bits_security_gaussian_fft_mul_impl,
+    pbs_variance_132_bits_security_tuniform_fft_mul_impl,
+};
+use tfhe::core_crypto::commons::noise_formulas::modulus_switch::modulus_switch_additive_variance_impl;
+use tfhe::core_crypto::commons::noise_formulas::multi_bit_modulus_switch::multi_bit_modulus_switch_additive_variance_impl;
+use tfhe::core_crypto::commons::noise_formulas::secure_noise::{
+    minimal_lwe_bound_for_132_bits_security_tuniform_impl,
+    minimal_lwe_variance_for_132_bits_security_gaussian_impl,
+};
+
+/// Modulus of the ciphertexts of every atomic pattern
+pub const CIPHERTEXT_MODULUS: f64 = 18446744073709551616.0;
+
+/// Modulus of the ciphertexts after the keyswitch of [`AtomicPattern::KeySwitch32`]
+pub const KEYSWITCH_32_MODULUS: f64 = 4294967296.0;
+
+/// Returns the modulus of the ciphertexts encrypted under the small LWE key
+pub fn small_lwe_modulus(atomic_pattern: AtomicPattern) -> f64 {
+    match atomic_pattern {
+        AtomicPattern::KsPbs | AtomicPattern::MultiBit { .. } => CIPHERTEXT_MODULUS,
+        AtomicPattern::KeySwitch32 => KEYSWITCH_32_MODULUS,
+    }
+}
+
+/// Variance added by a PBS whose input LWE dimension is 1
+///
+/// The PBS variance is linear in the input LWE dimension, the PBS variance of a parameter set is
+/// `lwe_dimension * unit_pbs_variance(..)`.
+pub fn unit_pbs_variance(
+    atomic_pattern: AtomicPattern,
+    noise_distribution: NoiseDistributionKind,
+    glwe_dimension: f64,
+    polynomial_size: f64,
+    decomposition_base: f64,
+    decomposition_level_count: f64,
+) -> f64 {
+    let formula = match (atomic_pattern, noise_distribution) {
+        (AtomicPattern::KsPbs | AtomicPattern::KeySwitch32, NoiseDistributionKind::Gaussian) => {
+            pbs_variance_132_bits_security_gaussian_fft_mul_impl
+        }
+        (AtomicPattern::KsPbs | AtomicPattern::KeySwitch32, NoiseDistributionKind::TUniform) => {
+            pbs_variance_132_bits_security_tuniform_fft_mul_impl
+        }
+        (AtomicPattern::MultiBit { grouping_factor }, NoiseDistributionKind::Gaussian) => {
+            match grouping_factor {
+                2 => multi_bit_pbs_variance_132_bits_security_gaussian_gf_2_fft_mul_impl,
+                3 => multi_bit_pbs_variance_132_bits_security_gaussian_gf_3_fft_mul_impl,
+                4 => multi_bit_pbs_variance_132_bits_security_gaussian_gf_4_fft_mul_impl,
+                _ => unreachable!("Unsupported grouping factor {grouping_factor}"),
+            }
+        }
+        (AtomicPattern::MultiBit { grouping_factor }, NoiseDistributionKind::TUniform) => {
+            match grouping_factor {
+                2 => multi_bit_pbs_variance_132_bits_security_tuniform_gf_2_fft_mul_impl,
+                3 => multi_bit_pbs_variance_132_bits_security_tuniform_gf_3_fft_mul_impl,
+                4 => multi_bit_pbs_variance_132_bits_security_tuniform_gf_4_fft_mul_impl,
+                _ => unreachable!("Unsupported grouping factor {grouping_factor}"),
+            }
+        }
+    };
+
+    formula(
+        1.0,
+        glwe_dimension,
+        polynomial_size,
+        decomposition_base,
+        decomposition_level_count,
+        CIPHERTEXT_MODULUS,
+    )
+}
+
+/// Variance added by the keyswitch from the big LWE key (of dimension
+/// `glwe_dimension * polynomial_size`) to the small LWE key
+pub fn keyswitch_variance(
+    atomic_pattern: AtomicPattern,
+    noise_distribution: NoiseDistributionKind,
+    big_lwe_dimension: f64,
+    small_lwe_dimension: f64,
+    decomposition_base: f64,
+    decomposition_level_count: f64,
+) -> f64 {
+    let formula = match noise_distribution {
+        NoiseDistributionKind::Gaussian => {
+            keyswitch_additive_variance_132_bits_security_gaussian_impl
+        }
+        NoiseDistributionKind::TUniform => {
+            keyswitch_additive_variance_132_bits_security_tuniform_impl
+        }
+    };
+
+    formula(
+        big_lwe_dimension,
+        small_lwe_dimension,
+        decomposition_base,
+        decomposition_level_count,
+        CIPHERTEXT_MODULUS,
+        small_lwe_modulus(atomic_pattern),
+    )
+}
+
+/// Variance added by the modulus switch to `2 * polynomial_size` done at the start of the PBS
+///
+/// The variance is normalized with respect to the modulus of the small LWE ciphertexts.
+pub fn modulus_switch_variance(
+    atomic_pattern: AtomicPattern,
+    small_lwe_dimension: f64,
+    polynomial_size: f64,
+) -> f64 {
+    let modulus = small_lwe_modulus(atomic_pattern);
+    let new_modulus = 2.0 * polynomial_size;
+
+    match atomic_pattern {
+        AtomicPattern::KsPbs | AtomicPattern::KeySwitch32 => {
+            modulus_switch_additive_variance_impl(small_lwe_dimension, modulus, new_modulus)
+        }
+        AtomicPattern::MultiBit { grouping_factor } => {
+            multi_bit_modulus_switch_additive_variance_impl(
+                small_lwe_dimension,
+                grouping_factor as f64,
+                modulus,
+                new_modulus,
+            )
+        }
+    }
+}
+
+/// Smallest TUniform bound used for keys, the secure bound given by the formulas reaches 0 for
+/// large dimensions, the parameters of tfhe use this bound instead
+pub const MIN_TUNIFORM_BOUND_LOG2: u32 = 3;
+
+/// Returns the log2 of the bound of the smallest secure TUniform distribution
+pub fn secure_tuniform_bound_log2(lwe_dimension: f64, modulus: f64) -> u32 {
+    minimal_lwe_bound_for_132_bits_security_tuniform_impl(lwe_dimension, modulus)
+        .max(MIN_TUNIFORM_BOUND_LOG2)
+}
+
+/// Returns the standard deviation of the smallest secure Gaussian distribution, normalized to
+/// the torus
+pub fn secure_gaussian_std_dev(lwe_dimension: f64, modulus: f64) -> f64 {
+    minimal_lwe_variance_for_132_bits_security_gaussian_impl(lwe_dimension, modulus).sqrt()
+}
+
+/// Returns the log2 of the probability that a centered gaussian noise of variance `variance`
+/// is greater than `bound` in absolute value
+///
+/// pfail = erfc(bound / (sqrt(2) * sigma))
+///
+/// When erfc underflows, its asymptotic expansion erfc(x) ~ exp(-x^2) / (x * sqrt(pi)) is used.
+pub fn log2_p_fail(variance: f64, bound: f64) -> f64 {
+    let x = bound / (2.0 * variance).sqrt();
+    let p_fail = statrs::function::erf::erfc(x);
+
+    if p_fail > 0.0 {
+        p_fail.log2()
+    } else {
+        (-x * x - (x * core::f64::consts::PI.sqrt()).ln()) * core::f64::consts::LOG2_E
+    }
+}
+
+/// Returns the largest variance of a centered gaussian noise for which the probability of
+/// being greater than `bound` in absolute value is at most `2^log2_p_fail`
+pub fn max_variance_for_p_fail(log2_p_fail: f64, bound: f64) -> f64 {
+    let z = core::f64::consts::SQRT_2 * statrs::function::erf::erfc_inv(log2_p_fail.exp2());
+
+    (bound / z).powi(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p_fail_round_trip() {
+        let bound = 1.0 / 64.0;
+        for expected in [-40.0, -64.0, -128.0, -200.0] {
+            let variance = max_variance_for_p_fail(expected, bound);
+            let recomputed = log2_p_fail(variance, bound);
+            assert!(
+                (recomputed - expected).abs() < 1e-6,
+                "{recomputed} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_secure_noise_matches_v1_1_parameters() {
+        // V1_1_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128
+        assert_eq!(secure_tuniform_bound_log2(918.0, CIPHERTEXT_MODULUS), 45);
+        assert_eq!(secure_tuniform_bound_log2(2048.0, CIPHERTEXT_MODULUS), 17);
+        // V1_1_PARAM_MESSAGE_3_CARRY_3_KS_PBS_TUNIFORM_2M128
+        assert_eq!(secure_tuniform_bound_log2(8192.0, CIPHERTEXT_MODULUS), 3);
+    }
+}
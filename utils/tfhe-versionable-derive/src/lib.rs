@@ -7,6 +7,7 @@
 
 mod associated;
 mod dispatch_type;
+mod schema;
 mod transparent;
 mod version_type;
 mod versionize_attribute;
@@ -42,6 +43,15 @@ pub(crate) const UNVERSIONIZE_TRAIT_NAME: &str = crate_full_path!("Unversionize"
 pub(crate) const UNVERSIONIZE_VEC_TRAIT_NAME: &str = crate_full_path!("UnversionizeVec");
 pub(crate) const UPGRADE_TRAIT_NAME: &str = crate_full_path!("Upgrade");
 pub(crate) const UNVERSIONIZE_ERROR_NAME: &str = crate_full_path!("UnversionizeError");
+pub(crate) const VERSION_SHAPE_TRAIT_NAME: &str = crate_full_path!("derived_traits::VersionShape");
+pub(crate) const VERSIONS_DISPATCH_SCHEMA_TRAIT_NAME: &str =
+    crate_full_path!("derived_traits::VersionsDispatchSchema");
+pub(crate) const VERSIONIZE_SCHEMA_TRAIT_NAME: &str = crate_full_path!("VersionizeSchema");
+pub(crate) const TYPE_SCHEMA_NAME: &str = crate_full_path!("schema::TypeSchema");
+pub(crate) const VERSION_SCHEMA_NAME: &str = crate_full_path!("schema::VersionSchema");
+pub(crate) const DATA_SHAPE_NAME: &str = crate_full_path!("schema::DataShape");
+pub(crate) const NAMED_FIELD_NAME: &str = crate_full_path!("schema::NamedField");
+pub(crate) const VARIANT_SCHEMA_NAME: &str = crate_full_path!("schema::VariantSchema");
 
 pub(crate) const SERIALIZE_TRAIT_NAME: &str = "::serde::Serialize";
 pub(crate) const DESERIALIZE_TRAIT_NAME: &str = "::serde::Deserialize";
@@ -92,12 +102,16 @@ fn impl_version_trait(input: &DeriveInput) -> proc_macro2::TokenStream {
 
     let version_impl = syn_unwrap!(version_trait.generate_impl());
 
+    let shape_impl = syn_unwrap!(schema::generate_version_shape_impl(input));
+
     quote! {
         const _: () = {
             #version_types
 
             #[automatically_derived]
             #version_impl
+
+            #shape_impl
         };
     }
 }
@@ -118,12 +132,16 @@ pub fn derive_versions_dispatch(input: TokenStream) -> TokenStream {
 
     let dispatch_impl = syn_unwrap!(dispatch_trait.generate_impl());
 
+    let dispatch_schema_impl = syn_unwrap!(schema::generate_dispatch_schema_impl(&input));
+
     quote! {
         const _: () = {
             #dispatch_types
 
             #[automatically_derived]
             #dispatch_impl
+
+            #dispatch_schema_impl
         };
     }
     .into()
@@ -201,6 +219,14 @@ pub fn derive_versionize(input: TokenStream) -> TokenStream {
             None
         };
 
+    // The schema is only available for types that have their own dispatch enum
+    let versionize_schema_impl = syn_unwrap!(implementor
+        .dispatch_enum()
+        .map(|dispatch_enum| {
+            schema::generate_versionize_schema_impl(&input.ident, &input_generics, dispatch_enum)
+        })
+        .transpose());
+
     // Parse the name of the traits that we will implement
     let versionize_trait: Path = parse_const_str(VERSIONIZE_TRAIT_NAME);
     let versionize_owned_trait: Path = parse_const_str(VERSIONIZE_OWNED_TRAIT_NAME);
@@ -243,6 +269,8 @@ pub fn derive_versionize(input: TokenStream) -> TokenStream {
     quote! {
         #version_trait_impl
 
+        #versionize_schema_impl
+
         #[automatically_derived]
         impl #trait_impl_generics #versionize_trait for #input_ident #ty_generics
         #versionize_trait_where_clause
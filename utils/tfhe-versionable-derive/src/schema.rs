@@ -0,0 +1,187 @@
+//! Generates the impls used to describe the layout of each version of a type, for the
+//! `VersionizeSchema` trait.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Data, DeriveInput, Fields, Generics, Ident, Path, Type};
+
+use crate::versionize_attribute::is_skipped;
+use crate::{
+    add_trait_where_clause, parse_const_str, DATA_SHAPE_NAME, NAMED_FIELD_NAME, TYPE_SCHEMA_NAME,
+    VARIANT_SCHEMA_NAME, VERSIONIZE_SCHEMA_TRAIT_NAME, VERSIONS_DISPATCH_SCHEMA_TRAIT_NAME,
+    VERSION_SCHEMA_NAME, VERSION_SHAPE_TRAIT_NAME,
+};
+
+/// Generates the `VersionShape` impl for a type that derives `Version`, which returns the fields
+/// of the type that are not skipped.
+pub(crate) fn generate_version_shape_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let shape_trait: Path = parse_const_str(VERSION_SHAPE_TRAIT_NAME);
+    let data_shape: Path = parse_const_str(DATA_SHAPE_NAME);
+
+    let shape = match &input.data {
+        Data::Struct(stru) => generate_fields_shape(&stru.fields)?,
+        Data::Enum(enu) => {
+            let variant_schema: Path = parse_const_str(VARIANT_SCHEMA_NAME);
+            let variants = enu
+                .variants
+                .iter()
+                .map(|variant| {
+                    let name = variant.ident.to_string();
+                    // Skipped variants are versioned as units
+                    let shape = if is_skipped(&variant.attrs)? {
+                        quote! { #data_shape::Unit }
+                    } else {
+                        generate_fields_shape(&variant.fields)?
+                    };
+                    Ok(quote! { #variant_schema::new(#name, #shape) })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! { #data_shape::Enum { variants: ::std::vec![#(#variants),*] } }
+        }
+        Data::Union(uni) => generate_fields_shape(&Fields::Named(uni.fields.clone()))?,
+    };
+
+    let input_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #shape_trait for #input_ident #ty_generics #where_clause {
+            fn shape() -> #data_shape {
+                #shape
+            }
+        }
+    })
+}
+
+fn generate_fields_shape(fields: &Fields) -> syn::Result<TokenStream> {
+    let data_shape: Path = parse_const_str(DATA_SHAPE_NAME);
+
+    let kept_fields = fields
+        .iter()
+        .map(|field| Ok((!is_skipped(&field.attrs)?).then_some(field)))
+        .filter_map(Result::transpose)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(match fields {
+        Fields::Named(_) => {
+            let named_field: Path = parse_const_str(NAMED_FIELD_NAME);
+            let fields = kept_fields.iter().map(|field| {
+                // Ok to unwrap since the fields are named
+                let name = field.ident.as_ref().unwrap().to_string();
+                let ty = type_name(&field.ty);
+                quote! { #named_field::new(#name, #ty) }
+            });
+            quote! { #data_shape::Struct { fields: ::std::vec![#(#fields),*] } }
+        }
+        Fields::Unnamed(_) => {
+            let fields = kept_fields.iter().map(|field| type_name(&field.ty));
+            quote! {
+                #data_shape::Tuple { fields: ::std::vec![#(::std::string::String::from(#fields)),*] }
+            }
+        }
+        Fields::Unit => quote! { #data_shape::Unit },
+    })
+}
+
+/// Generates the `VersionsDispatchSchema` impl for a dispatch enum, that lists the variants with
+/// the shape of the version they hold.
+pub(crate) fn generate_dispatch_schema_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let dispatch_schema_trait: Path = parse_const_str(VERSIONS_DISPATCH_SCHEMA_TRAIT_NAME);
+    let shape_trait: Path = parse_const_str(VERSION_SHAPE_TRAIT_NAME);
+    let version_schema: Path = parse_const_str(VERSION_SCHEMA_NAME);
+
+    // The shape of the dispatch enum has already been checked during the generation of the
+    // `VersionsDispatch` impl, so each variant holds a single field.
+    let variants: Vec<(String, &Type)> = match &input.data {
+        Data::Enum(enu) => enu
+            .variants
+            .iter()
+            .filter_map(|variant| {
+                Some((variant.ident.to_string(), &variant.fields.iter().next()?.ty))
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let versions = variants.iter().map(|(name, ty)| {
+        let ty_name = type_name(ty);
+        quote! { #version_schema::new(#name, #ty_name, <#ty as #shape_trait>::shape()) }
+    });
+
+    // Like for the `VersionsDispatch` impl, the versions are bounded instead of relying on the
+    // generics of the enum, so an old version that is never used does not need to be described.
+    let mut generics = input.generics.clone();
+    add_trait_where_clause(
+        &mut generics,
+        variants.iter().map(|(_, ty)| *ty),
+        &[VERSION_SHAPE_TRAIT_NAME],
+    )?;
+
+    let input_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #dispatch_schema_trait for #input_ident #ty_generics #where_clause {
+            fn versions() -> ::std::vec::Vec<#version_schema> {
+                ::std::vec![#(#versions),*]
+            }
+        }
+    })
+}
+
+/// Generates the `VersionizeSchema` impl for a type that derives `Versionize` with a dispatch
+/// enum.
+pub(crate) fn generate_versionize_schema_impl(
+    input_ident: &Ident,
+    input_generics: &Generics,
+    dispatch_enum: &Path,
+) -> syn::Result<TokenStream> {
+    let versionize_schema_trait: Path = parse_const_str(VERSIONIZE_SCHEMA_TRAIT_NAME);
+    let type_schema: Path = parse_const_str(TYPE_SCHEMA_NAME);
+    let name = input_ident.to_string();
+
+    let (_, ty_generics, _) = input_generics.split_for_impl();
+    let dispatch_type: Type = parse_quote! { #dispatch_enum #ty_generics };
+
+    let mut generics = input_generics.clone();
+    add_trait_where_clause(
+        &mut generics,
+        [&dispatch_type],
+        &[VERSIONS_DISPATCH_SCHEMA_TRAIT_NAME],
+    )?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #versionize_schema_trait for #input_ident #ty_generics #where_clause {
+            fn schema() -> #type_schema {
+                #type_schema::from_dispatch::<#dispatch_type>(#name)
+            }
+        }
+    })
+}
+
+/// Returns the name of a type as written in the source, without the spaces added by the
+/// tokenization that are not needed to separate two identifiers (eg: `Vec<u64>` and not
+/// `Vec < u64 >`, but `dyn Trait`).
+fn type_name(ty: &Type) -> String {
+    let tokens = ty.to_token_stream().to_string();
+    let chars: Vec<char> = tokens.chars().collect();
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_' || c == '\'';
+
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(idx, c)| {
+            **c != ' '
+                || (*idx > 0
+                    && *idx < chars.len() - 1
+                    && is_ident_char(chars[idx - 1])
+                    && is_ident_char(chars[idx + 1]))
+        })
+        .map(|(_, c)| *c)
+        .collect()
+}
@@ -49,6 +49,15 @@ impl VersionizeImplementor {
         }
     }
 
+    /// Return the dispatch enum of the type, if it is directly versioned
+    pub(crate) fn dispatch_enum(&self) -> Option<&Path> {
+        match self {
+            Self::Classic(attr) => Some(&attr.dispatch_enum),
+            Self::Convert(_) => None,
+            Self::Transparent(_) => None,
+        }
+    }
+
     /// Return the associated type used in the `Versionize` trait: `MyType::Versioned<'vers>`
     ///
     /// If the type is directly versioned, this will be a type generated by the `VersionDispatch`.
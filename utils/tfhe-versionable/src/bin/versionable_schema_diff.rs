@@ -0,0 +1,57 @@
+//! Compares two [`SchemaRegistry`] exported in json, from two revisions of a crate, and reports the
+//! changes that prevent the new revision from loading data serialized with the old one.
+//!
+//! Usage: `versionable-schema-diff <OLD_SCHEMA> <NEW_SCHEMA>`
+//!
+//! The exit code is 1 if a breaking change is found, and 2 if the schemas could not be loaded.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::process::ExitCode;
+
+use tfhe_versionable::schema::SchemaRegistry;
+
+fn load_registry(path: &Path) -> Result<SchemaRegistry, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {e}", path.display()))?;
+    serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| format!("Failed to parse schema {}: {e}", path.display()))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 3 {
+        eprintln!("Usage: {} <OLD_SCHEMA> <NEW_SCHEMA>", args[0]);
+        return ExitCode::from(2);
+    }
+
+    let registries = load_registry(Path::new(&args[1]))
+        .and_then(|old| load_registry(Path::new(&args[2])).map(|new| (old, new)));
+
+    let (old, new) = match registries {
+        Ok(registries) => registries,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let changes = old.diff(&new);
+
+    for change in changes.iter() {
+        if change.is_breaking() {
+            println!("error: {change}");
+        } else {
+            println!("warning: {change}");
+        }
+    }
+
+    let breaking_count = changes.iter().filter(|change| change.is_breaking()).count();
+    if breaking_count > 0 {
+        println!("{breaking_count} breaking change(s) found");
+        ExitCode::FAILURE
+    } else {
+        println!("No breaking change found");
+        ExitCode::SUCCESS
+    }
+}
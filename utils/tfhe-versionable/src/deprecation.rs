@@ -6,6 +6,8 @@ use std::marker::PhantomData;
 
 use serde::{Deserialize, Serialize};
 
+use crate::derived_traits::VersionShape;
+use crate::schema::DataShape;
 use crate::{UnversionizeError, Upgrade, Version};
 
 /// This trait should be implemented for types that have deprecated versions. You can then use them
@@ -107,6 +109,12 @@ impl<T: Deprecable> Version for Deprecated<T> {
     type Owned = DeprecatedVersion<T>;
 }
 
+impl<T> VersionShape for Deprecated<T> {
+    fn shape() -> DataShape {
+        DataShape::Deprecated
+    }
+}
+
 impl<T: Deprecable> From<Deprecated<T>> for DeprecatedVersion<T> {
     fn from(_value: Deprecated<T>) -> Self {
         Self {
@@ -4,6 +4,7 @@
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+use crate::schema::{DataShape, VersionSchema};
 use crate::UnversionizeError;
 
 /// This trait is used to mark a specific version of a given type
@@ -26,3 +27,15 @@ pub trait VersionsDispatch<Unversioned>: Sized {
         + DeserializeOwned
         + Serialize;
 }
+
+/// This trait is implemented by the `Version` derive macro and describes the layout of a version of
+/// a type.
+pub trait VersionShape {
+    fn shape() -> DataShape;
+}
+
+/// This trait is implemented on the dispatch enum and lists the versions of the type, with their
+/// layout.
+pub trait VersionsDispatchSchema {
+    fn versions() -> Vec<VersionSchema>;
+}
@@ -8,6 +8,7 @@
 
 pub mod deprecation;
 pub mod derived_traits;
+pub mod schema;
 pub mod upgrade;
 
 use aligned_vec::{ABox, AVec};
@@ -22,6 +23,7 @@ use std::num::{NonZero, Wrapping};
 use std::sync::Arc;
 
 pub use derived_traits::{Version, VersionsDispatch};
+pub use schema::VersionizeSchema;
 pub use upgrade::Upgrade;
 
 use serde::de::DeserializeOwned;
@@ -0,0 +1,377 @@
+//! Reflection on the versions of a type, to check the backward compatibility of serialized data
+//! between two revisions of a crate.
+//!
+//! The [`VersionizeSchema`] trait is implemented by the `Versionize` derive macro for the types
+//! that are linked to a dispatch enum. It describes the layout of each version of the type, in the
+//! order of the dispatch enum. The schemas of all the versioned types of a crate can be collected
+//! in a [`SchemaRegistry`], which can be serialized (for example in json) and stored alongside the
+//! sources.
+//!
+//! Two registries can then be compared with [`SchemaRegistry::diff`], or with the
+//! `versionable-schema-diff` tool (enabled by the `schema-diff` feature), to find the changes that
+//! would prevent the new revision from loading data serialized with the old one.
+//!
+//! For example:
+//! ```rust
+//! use tfhe_versionable::schema::{SchemaChange, SchemaRegistry};
+//! use tfhe_versionable::{Version, Versionize, VersionsDispatch};
+//!
+//! mod v0 {
+//!     use tfhe_versionable::{Versionize, VersionsDispatch};
+//!
+//!     #[derive(Versionize)]
+//!     #[versionize(MyStructVersions)]
+//!     pub struct MyStruct {
+//!         pub val: u32,
+//!     }
+//!
+//!     #[derive(VersionsDispatch)]
+//!     #[allow(unused)]
+//!     pub enum MyStructVersions {
+//!         V0(MyStruct),
+//!     }
+//! }
+//!
+//! // The type has been modified without adding a new version
+//! #[derive(Versionize)]
+//! #[versionize(MyStructVersions)]
+//! struct MyStruct {
+//!     val: u64,
+//! }
+//!
+//! #[derive(VersionsDispatch)]
+//! #[allow(unused)]
+//! enum MyStructVersions {
+//!     V0(MyStruct),
+//! }
+//!
+//! let mut old = SchemaRegistry::new();
+//! old.register::<v0::MyStruct>();
+//!
+//! let mut new = SchemaRegistry::new();
+//! new.register::<MyStruct>();
+//!
+//! let changes = old.diff(&new);
+//! assert!(matches!(changes[0], SchemaChange::MissingUpgrade { .. }));
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::derived_traits::VersionsDispatchSchema;
+
+/// This trait is implemented by the `Versionize` derive macro, for types that are directly
+/// versioned using a dispatch enum.
+pub trait VersionizeSchema {
+    /// Returns the description of all the versions of this type
+    fn schema() -> TypeSchema;
+}
+
+/// Description of all the versions of a type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeSchema {
+    pub name: String,
+    /// The versions of the type, in the order of the dispatch enum
+    pub versions: Vec<VersionSchema>,
+}
+
+impl TypeSchema {
+    /// Builds the schema of a type from the dispatch enum that holds its versions
+    pub fn from_dispatch<D: VersionsDispatchSchema>(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            versions: D::versions(),
+        }
+    }
+
+    /// Returns the current version of the type, which is the last one of the dispatch enum
+    pub fn latest(&self) -> Option<&VersionSchema> {
+        self.versions.last()
+    }
+}
+
+/// Description of a single version of a type, which is a variant of the dispatch enum
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionSchema {
+    /// The name of the variant in the dispatch enum
+    pub name: String,
+    /// The type held by the variant
+    pub ty: String,
+    pub shape: DataShape,
+}
+
+impl VersionSchema {
+    pub fn new(name: &str, ty: &str, shape: DataShape) -> Self {
+        Self {
+            name: name.to_string(),
+            ty: ty.to_string(),
+            shape,
+        }
+    }
+}
+
+/// The layout of a version of a type, as it is seen by serde.
+///
+/// Fields are described by the name of their type in the source code. Fields marked with the
+/// `skip` attribute are not part of the shape.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DataShape {
+    Unit,
+    Struct {
+        fields: Vec<NamedField>,
+    },
+    Tuple {
+        fields: Vec<String>,
+    },
+    Enum {
+        variants: Vec<VariantSchema>,
+    },
+    /// The version has been marked with [`Deprecated`](crate::deprecation::Deprecated)
+    Deprecated,
+}
+
+/// A named field of a struct or of an enum variant
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamedField {
+    pub name: String,
+    pub ty: String,
+}
+
+impl NamedField {
+    pub fn new(name: &str, ty: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ty: ty.to_string(),
+        }
+    }
+}
+
+/// A variant of an enum, with its fields
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariantSchema {
+    pub name: String,
+    pub shape: DataShape,
+}
+
+impl VariantSchema {
+    pub fn new(name: &str, shape: DataShape) -> Self {
+        Self {
+            name: name.to_string(),
+            shape,
+        }
+    }
+}
+
+/// A set of type schemas, indexed by the name of the types.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaRegistry {
+    types: BTreeMap<String, TypeSchema>,
+}
+
+impl SchemaRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the schema of `T` to the registry.
+    ///
+    /// For generic types, any instantiation can be used since the fields are described by the
+    /// names used in the declaration of the type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a different type with the same name has already been registered
+    pub fn register<T: VersionizeSchema>(&mut self) -> &mut Self {
+        self.insert(T::schema())
+    }
+
+    /// Adds a schema to the registry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a different type with the same name has already been registered
+    pub fn insert(&mut self, schema: TypeSchema) -> &mut Self {
+        if let Some(registered) = self.types.get(&schema.name) {
+            assert_eq!(
+                registered, &schema,
+                "Two different types named {} have been registered",
+                schema.name
+            );
+        } else {
+            self.types.insert(schema.name.clone(), schema);
+        }
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TypeSchema> {
+        self.types.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TypeSchema> {
+        self.types.values()
+    }
+
+    /// Lists the changes between `self` and a newer revision of the registry that may prevent the
+    /// new revision from loading data serialized with the old one.
+    ///
+    /// Types that are only present in the new registry are ignored.
+    pub fn diff(&self, new: &Self) -> Vec<SchemaChange> {
+        self.iter()
+            .flat_map(|old_schema| match new.get(&old_schema.name) {
+                Some(new_schema) => diff_type(old_schema, new_schema),
+                None => vec![SchemaChange::TypeRemoved {
+                    type_name: old_schema.name.clone(),
+                }],
+            })
+            .collect()
+    }
+}
+
+fn diff_type(old: &TypeSchema, new: &TypeSchema) -> Vec<SchemaChange> {
+    let type_name = &old.name;
+
+    if new.versions.len() < old.versions.len() {
+        return vec![SchemaChange::VersionsRemoved {
+            type_name: type_name.clone(),
+            old_count: old.versions.len(),
+            new_count: new.versions.len(),
+        }];
+    }
+
+    let has_new_version = new.versions.len() > old.versions.len();
+
+    old.versions
+        .iter()
+        .zip(new.versions.iter())
+        .enumerate()
+        .filter_map(|(idx, (old_version, new_version))| {
+            let is_old_latest = idx == old.versions.len() - 1;
+
+            if old_version.name != new_version.name {
+                Some(SchemaChange::VersionRenamed {
+                    type_name: type_name.clone(),
+                    old_name: old_version.name.clone(),
+                    new_name: new_version.name.clone(),
+                })
+            } else if old_version.shape == new_version.shape
+                || old_version.shape == DataShape::Deprecated
+            {
+                None
+            } else if new_version.shape == DataShape::Deprecated {
+                Some(SchemaChange::VersionDeprecated {
+                    type_name: type_name.clone(),
+                    version: new_version.name.clone(),
+                })
+            } else if is_old_latest && !has_new_version {
+                Some(SchemaChange::MissingUpgrade {
+                    type_name: type_name.clone(),
+                    version: new_version.name.clone(),
+                    old_shape: old_version.shape.clone(),
+                    new_shape: new_version.shape.clone(),
+                })
+            } else {
+                Some(SchemaChange::VersionModified {
+                    type_name: type_name.clone(),
+                    version: new_version.name.clone(),
+                    old_shape: old_version.shape.clone(),
+                    new_shape: new_version.shape.clone(),
+                })
+            }
+        })
+        .collect()
+}
+
+/// A difference between two revisions of a [`SchemaRegistry`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SchemaChange {
+    /// The type is not in the new registry anymore
+    TypeRemoved { type_name: String },
+    /// Some versions have been removed from the dispatch enum
+    VersionsRemoved {
+        type_name: String,
+        old_count: usize,
+        new_count: usize,
+    },
+    /// A variant of the dispatch enum has been renamed
+    VersionRenamed {
+        type_name: String,
+        old_name: String,
+        new_name: String,
+    },
+    /// The latest version of the type has been modified in place. A new version should have been
+    /// added to the dispatch enum, with an `Upgrade` from the previous one.
+    MissingUpgrade {
+        type_name: String,
+        version: String,
+        old_shape: DataShape,
+        new_shape: DataShape,
+    },
+    /// A previous version of the type has been modified
+    VersionModified {
+        type_name: String,
+        version: String,
+        old_shape: DataShape,
+        new_shape: DataShape,
+    },
+    /// A version has been explicitly marked as deprecated
+    VersionDeprecated { type_name: String, version: String },
+}
+
+impl SchemaChange {
+    /// Returns true if data serialized with the old revision cannot be loaded by the new one.
+    ///
+    /// Deprecations are not considered breaking since they are explicitly marked by the
+    /// developers.
+    pub fn is_breaking(&self) -> bool {
+        !matches!(self, Self::VersionDeprecated { .. })
+    }
+}
+
+impl Display for SchemaChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeRemoved { type_name } => write!(f, "{type_name}: type has been removed"),
+            Self::VersionsRemoved {
+                type_name,
+                old_count,
+                new_count,
+            } => write!(
+                f,
+                "{type_name}: number of versions decreased from {old_count} to {new_count}"
+            ),
+            Self::VersionRenamed {
+                type_name,
+                old_name,
+                new_name,
+            } => write!(
+                f,
+                "{type_name}: version {old_name} has been renamed into {new_name}"
+            ),
+            Self::MissingUpgrade {
+                type_name,
+                version,
+                old_shape,
+                new_shape,
+            } => write!(
+                f,
+                "{type_name}: latest version {version} has been modified without adding a new \
+version and an `Upgrade` impl (old: {old_shape:?}, new: {new_shape:?})"
+            ),
+            Self::VersionModified {
+                type_name,
+                version,
+                old_shape,
+                new_shape,
+            } => write!(
+                f,
+                "{type_name}: version {version} has been modified (old: {old_shape:?}, new: \
+{new_shape:?})"
+            ),
+            Self::VersionDeprecated { type_name, version } => {
+                write!(f, "{type_name}: version {version} has been deprecated")
+            }
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Test the schema generated by the derive macros and the detection of incompatible changes between
+//! two revisions of the versioned types
+
+use std::convert::Infallible;
+
+use tfhe_versionable::deprecation::{Deprecable, Deprecated};
+use tfhe_versionable::schema::{
+    DataShape, NamedField, SchemaChange, SchemaRegistry, VariantSchema, VersionSchema,
+};
+use tfhe_versionable::{Upgrade, Version, Versionize, VersionizeSchema, VersionsDispatch};
+
+#[allow(dead_code)]
+#[derive(Default)]
+struct NotVersionable(u64);
+
+/// The previous revision of the types
+mod v0 {
+    use tfhe_versionable::{Versionize, VersionsDispatch};
+
+    #[derive(Versionize)]
+    #[versionize(MyStructVersions)]
+    pub(super) struct MyStruct<T> {
+        pub(super) attr: T,
+        pub(super) builtin: u32,
+    }
+
+    #[derive(VersionsDispatch)]
+    #[allow(unused)]
+    pub(super) enum MyStructVersions<T> {
+        V0(MyStruct<T>),
+    }
+
+    #[derive(Versionize)]
+    #[versionize(MyEnumVersions)]
+    pub(super) enum MyEnum {
+        Var0(u32),
+    }
+
+    #[derive(VersionsDispatch)]
+    #[allow(unused)]
+    pub(super) enum MyEnumVersions {
+        V0(MyEnum),
+    }
+
+    #[derive(Versionize)]
+    #[versionize(MyTupleVersions)]
+    pub(super) struct MyTuple(pub(super) u32);
+
+    #[derive(VersionsDispatch)]
+    #[allow(unused)]
+    pub(super) enum MyTupleVersions {
+        V0(MyTuple),
+    }
+
+    #[derive(Versionize)]
+    #[versionize(MyUnitVersions)]
+    pub(super) struct MyUnit;
+
+    #[derive(VersionsDispatch)]
+    #[allow(unused)]
+    pub(super) enum MyUnitVersions {
+        V0(MyUnit),
+    }
+}
+
+// A new version has been correctly added
+#[derive(Version)]
+struct MyStructV0<T> {
+    attr: T,
+    builtin: u32,
+}
+
+#[derive(Versionize)]
+#[versionize(MyStructVersions)]
+struct MyStruct<T> {
+    attr: T,
+    builtin: u64,
+    #[versionize(skip)]
+    #[allow(dead_code)]
+    to_skip: NotVersionable,
+}
+
+impl<T> Upgrade<MyStruct<T>> for MyStructV0<T> {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<MyStruct<T>, Self::Error> {
+        Ok(MyStruct {
+            attr: self.attr,
+            builtin: self.builtin as u64,
+            to_skip: NotVersionable::default(),
+        })
+    }
+}
+
+#[derive(VersionsDispatch)]
+#[allow(unused)]
+enum MyStructVersions<T> {
+    V0(MyStructV0<T>),
+    V1(MyStruct<T>),
+}
+
+// The type is modified in place
+#[derive(Versionize)]
+#[versionize(MyEnumVersions)]
+enum MyEnum {
+    Var0(u32),
+    Var1 { val: u64 },
+}
+
+#[derive(VersionsDispatch)]
+#[allow(unused)]
+enum MyEnumVersions {
+    V0(MyEnum),
+}
+
+// A version is deprecated
+#[derive(Versionize)]
+#[versionize(MyTupleVersions)]
+struct MyTuple(u64);
+
+impl Deprecable for MyTuple {
+    const TYPE_NAME: &'static str = "MyTuple";
+    const MIN_SUPPORTED_APP_VERSION: &'static str = "app v1";
+}
+
+#[derive(VersionsDispatch)]
+#[allow(unused)]
+enum MyTupleVersions {
+    V0(Deprecated<MyTuple>),
+    V1(MyTuple),
+}
+
+#[test]
+fn test_schema_shapes() {
+    let schema = MyStruct::<u64>::schema();
+
+    assert_eq!(schema.name, "MyStruct");
+    assert_eq!(
+        schema.versions,
+        vec![
+            VersionSchema::new(
+                "V0",
+                "MyStructV0<T>",
+                DataShape::Struct {
+                    fields: vec![
+                        NamedField::new("attr", "T"),
+                        NamedField::new("builtin", "u32")
+                    ]
+                }
+            ),
+            VersionSchema::new(
+                "V1",
+                "MyStruct<T>",
+                DataShape::Struct {
+                    fields: vec![
+                        NamedField::new("attr", "T"),
+                        NamedField::new("builtin", "u64")
+                    ]
+                }
+            ),
+        ]
+    );
+
+    assert_eq!(
+        MyEnum::schema().latest().unwrap().shape,
+        DataShape::Enum {
+            variants: vec![
+                VariantSchema::new(
+                    "Var0",
+                    DataShape::Tuple {
+                        fields: vec!["u32".to_string()]
+                    }
+                ),
+                VariantSchema::new(
+                    "Var1",
+                    DataShape::Struct {
+                        fields: vec![NamedField::new("val", "u64")]
+                    }
+                ),
+            ]
+        }
+    );
+
+    let schema = MyTuple::schema();
+    assert_eq!(schema.versions[0].shape, DataShape::Deprecated);
+    assert_eq!(
+        schema.versions[1].shape,
+        DataShape::Tuple {
+            fields: vec!["u64".to_string()]
+        }
+    );
+
+    assert_eq!(v0::MyUnit::schema().versions[0].shape, DataShape::Unit);
+}
+
+#[test]
+fn test_schema_diff() {
+    let mut old = SchemaRegistry::new();
+    old.register::<v0::MyStruct<u64>>()
+        .register::<v0::MyEnum>()
+        .register::<v0::MyTuple>()
+        .register::<v0::MyUnit>();
+
+    let mut new = SchemaRegistry::new();
+    new.register::<MyStruct<u64>>()
+        .register::<MyEnum>()
+        .register::<MyTuple>();
+
+    // The registry can be exported and loaded back
+    let serialized = serde_json::to_string(&old).unwrap();
+    let loaded: SchemaRegistry = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(loaded, old);
+
+    assert!(old.diff(&old).is_empty());
+
+    let changes = old.diff(&new);
+
+    assert_eq!(changes.len(), 3);
+    assert!(matches!(
+        &changes[0],
+        SchemaChange::MissingUpgrade { type_name, version, .. }
+            if type_name == "MyEnum" && version == "V0"
+    ));
+    assert!(matches!(
+        &changes[1],
+        SchemaChange::VersionDeprecated { type_name, version }
+            if type_name == "MyTuple" && version == "V0"
+    ));
+    assert!(matches!(
+        &changes[2],
+        SchemaChange::TypeRemoved { type_name } if type_name == "MyUnit"
+    ));
+
+    assert!(changes[0].is_breaking());
+    assert!(!changes[1].is_breaking());
+
+    // Going back to the previous revision removes versions
+    let changes = new.diff(&old);
+    assert!(changes.iter().any(|change| matches!(
+        change,
+        SchemaChange::VersionsRemoved {
+            type_name,
+            old_count: 2,
+            new_count: 1,
+        } if type_name == "MyStruct"
+    )));
+}
+
+#[test]
+fn test_schema_diff_modified_version() {
+    let mut old = SchemaRegistry::new();
+    old.register::<MyStruct<u64>>();
+
+    // The previous version has been modified
+    let mut schema = MyStruct::<u64>::schema();
+    schema.versions[0].shape = DataShape::Struct {
+        fields: vec![NamedField::new("builtin", "u32")],
+    };
+    let mut new = SchemaRegistry::new();
+    new.insert(schema);
+
+    let changes = old.diff(&new);
+    assert_eq!(changes.len(), 1);
+    assert!(matches!(
+        &changes[0],
+        SchemaChange::VersionModified { type_name, version, .. }
+            if type_name == "MyStruct" && version == "V0"
+    ));
+}